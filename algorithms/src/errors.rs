@@ -46,6 +46,9 @@ pub enum SNARKError {
 
     #[error("Circuit not found")]
     CircuitNotFound,
+
+    #[error("The prover was terminated")]
+    Terminated,
 }
 
 impl From<AHPError> for SNARKError {
@@ -14,23 +14,27 @@
 
 use crate::{
     fft::{DensePolynomial, EvaluationDomain},
+    msm::FixedBase,
     AlgebraicSponge,
 };
 use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{ConstraintFieldError, ToConstraintField, Zero};
-use snarkvm_parameters::mainnet::PowersOfG;
+use snarkvm_fields::{ConstraintFieldError, Field, One, PrimeField, ToConstraintField, Zero};
+use snarkvm_parameters::mainnet::{PowersOfG, MAX_NUM_POWERS};
 use snarkvm_utilities::{
     borrow::Cow,
     error,
     io::{Read, Write},
+    rand::Uniform,
     serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate},
     FromBytes,
     ToBytes,
 };
 
 use crate::srs::{UniversalProver, UniversalVerifier};
-use anyhow::Result;
+use anyhow::{anyhow, ensure, Result};
 use core::ops::{Add, AddAssign};
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
 use rand_core::RngCore;
 use std::{collections::BTreeMap, io, ops::Range, sync::Arc};
 
@@ -60,6 +64,90 @@ impl<E: PairingEngine> UniversalParams<E> {
         Ok(Self { powers, h, prepared_h, prepared_beta_h })
     }
 
+    /// Samples a fresh set of universal parameters with `num_powers` powers of beta, from the given `seed`.
+    ///
+    /// WARNING: These parameters are **insecure**, as the trapdoor is derived from the seed.
+    /// They must only be used for local development networks and testing, never in production.
+    pub fn setup_insecure(num_powers: usize, seed: u64) -> Result<Self> {
+        // Ensure the number of powers is supported.
+        ensure!(num_powers >= 2, "The local SRS requires at least 2 powers");
+
+        // Sample the trapdoor from the seed.
+        let rng = &mut ChaChaRng::seed_from_u64(seed);
+        let beta = E::Fr::rand(rng);
+        let gamma = E::Fr::rand(rng);
+
+        // Initialize the generators.
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        let max_degree = MAX_NUM_POWERS - 1;
+
+        // Compute the exponents `beta^0, ..., beta^{n - 1}` and `beta^{M - n}, ..., beta^{M - 1}`.
+        let mut powers_of_beta = Vec::with_capacity(num_powers);
+        let mut power = E::Fr::one();
+        for _ in 0..num_powers {
+            powers_of_beta.push(power);
+            power *= beta;
+        }
+        let shift = beta.pow([(MAX_NUM_POWERS - num_powers) as u64]);
+        let shifted_powers_of_beta = powers_of_beta.iter().map(|power| *power * shift).collect::<Vec<_>>();
+
+        // Compute the powers of beta G and the shifted powers of beta G.
+        let scalar_bits = E::Fr::size_in_bits();
+        let window_size = FixedBase::get_mul_window_size(2 * num_powers);
+        let g_table = FixedBase::get_window_table(scalar_bits, window_size, g.to_projective());
+        let powers_of_beta_g = E::G1Projective::batch_normalization_into_affine(FixedBase::msm(
+            scalar_bits,
+            window_size,
+            &g_table,
+            &powers_of_beta,
+        ));
+        let shifted_powers_of_beta_g = E::G1Projective::batch_normalization_into_affine(FixedBase::msm(
+            scalar_bits,
+            window_size,
+            &g_table,
+            &shifted_powers_of_beta,
+        ));
+
+        // Determine the supported degree bounds, which are of the form `2^k - 2`.
+        let degree_bounds = (1..=num_powers.trailing_zeros()).map(|k| (1usize << k) - 2).collect::<Vec<_>>();
+
+        // Compute the powers of beta * gamma G, used for hiding and for each degree bound.
+        let gamma_g = g * gamma;
+        let mut powers_of_beta_times_gamma_g = BTreeMap::new();
+        for index in
+            (0..=2).chain(degree_bounds.iter().flat_map(|bound| (max_degree - bound)..=(max_degree - bound + 2)))
+        {
+            powers_of_beta_times_gamma_g.insert(index, (gamma_g * beta.pow([index as u64])).to_affine());
+        }
+
+        // Compute the negative powers of beta H for each degree bound.
+        let beta_inverse = beta.inverse().ok_or_else(|| anyhow!("Failed to invert the trapdoor"))?;
+        let negative_powers_of_beta_h = degree_bounds
+            .iter()
+            .map(|bound| (*bound, (h * beta_inverse.pow([(max_degree - bound) as u64])).to_affine()))
+            .collect::<BTreeMap<_, _>>();
+        let beta_h = (h * beta).to_affine();
+
+        // Construct the local powers.
+        let powers = Arc::new(PowersOfG::<E>::from_local_powers(
+            powers_of_beta_g,
+            shifted_powers_of_beta_g,
+            powers_of_beta_times_gamma_g,
+            negative_powers_of_beta_h,
+            beta_h,
+        )?);
+        let prepared_h = h.prepare();
+        let prepared_beta_h = beta_h.prepare();
+
+        Ok(Self { powers, h, prepared_h, prepared_beta_h })
+    }
+
+    /// Returns `true` if the universal parameters were generated locally, and are thus insecure.
+    pub fn is_insecure(&self) -> bool {
+        self.powers.is_local()
+    }
+
     pub fn download_powers_for(&self, range: Range<usize>) -> Result<()> {
         self.powers.download_powers_for(range)
     }
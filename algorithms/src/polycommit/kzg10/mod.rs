@@ -629,6 +629,37 @@ mod tests {
         batch_check_test_template::<Bls12_377>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn test_insecure_setup() {
+        let rng = &mut TestRng::default();
+
+        let num_powers = 64;
+        let pp = UniversalParams::<Bls12_377>::setup_insecure(num_powers, 0).unwrap();
+        assert!(pp.is_insecure());
+
+        // Ensure the same seed produces the same parameters.
+        let pp_copy = UniversalParams::<Bls12_377>::setup_insecure(num_powers, 0).unwrap();
+        assert_eq!(pp.to_bytes_le().unwrap(), pp_copy.to_bytes_le().unwrap());
+
+        // Ensure the powers are consistent with `beta_h`.
+        let beta_g = pp.power_of_beta_g(1).unwrap();
+        assert_eq!(Bls12_377::pairing(beta_g, pp.h), Bls12_377::pairing(pp.power_of_beta_g(0).unwrap(), pp.beta_h()));
+
+        // Ensure the parameters support committing and opening.
+        let hiding_bound = Some(1);
+        let (ck, vk) = KZG_Bls12_377::trim(&pp, num_powers - 1, hiding_bound);
+        let p = DensePolynomial::rand(num_powers - 1, rng);
+        let (comm, rand) = KZG_Bls12_377::commit(&ck, &(&p).into(), hiding_bound, Some(rng)).unwrap();
+        let point = Fr::rand(rng);
+        let value = p.evaluate(point);
+        let proof = KZG_Bls12_377::open(&ck, &p, point, &rand).unwrap();
+        assert!(KZG_Bls12_377::check(&vk, &comm, point, value, &proof).unwrap());
+
+        // Ensure the parameters do not download powers beyond those generated.
+        assert!(pp.download_powers_for(0..(num_powers + 1)).is_err());
+        assert!(pp.power_of_beta_g(num_powers).is_err());
+    }
+
     #[test]
     fn test_degree_is_too_large() {
         let rng = &mut TestRng::default();
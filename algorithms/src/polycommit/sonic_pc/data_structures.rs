@@ -15,7 +15,7 @@
 use super::{LabeledPolynomial, PolynomialInfo};
 use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use hashbrown::HashMap;
@@ -679,3 +679,37 @@ impl<E: PairingEngine> ToBytes for BatchLCProof<E> {
         CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
     }
 }
+
+/// The accumulated elements of a batch check, whose final product of pairings has been deferred.
+/// Deferred checks from independent proofs can be combined, and verified with a single product of pairings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PairingCheck<E: PairingEngine> {
+    /// The combined commitments, indexed by their degree bound.
+    pub(crate) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The combined witness.
+    pub(crate) combined_witness: E::G1Projective,
+    /// The combined adjusted witness.
+    pub(crate) combined_adjusted_witness: E::G1Projective,
+}
+
+impl<E: PairingEngine> Default for PairingCheck<E> {
+    fn default() -> Self {
+        Self {
+            combined_comms: BTreeMap::new(),
+            combined_witness: E::G1Projective::zero(),
+            combined_adjusted_witness: E::G1Projective::zero(),
+        }
+    }
+}
+
+impl<E: PairingEngine> PairingCheck<E> {
+    /// Adds the given deferred check, scaled by `coefficient`, to `self`.
+    /// Note: The coefficient must be sampled by the verifier, after all of the checks are fixed.
+    pub fn add_scaled(&mut self, other: &Self, coefficient: E::Fr) {
+        for (degree_bound, comm) in &other.combined_comms {
+            *self.combined_comms.entry(*degree_bound).or_insert_with(E::G1Projective::zero) += *comm * coefficient;
+        }
+        self.combined_witness += other.combined_witness * coefficient;
+        self.combined_adjusted_witness += other.combined_adjusted_witness * coefficient;
+    }
+}
@@ -348,6 +348,23 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::batch_check_deferred(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_pairings(vk, pairing_check)
+    }
+
+    /// Performs the batch check, except for the final product of pairings,
+    /// and returns the accumulated elements of the deferred check.
+    pub fn batch_check_deferred<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -368,9 +385,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
         let mut randomizer = E::Fr::one();
 
-        let mut combined_comms = BTreeMap::new();
-        let mut combined_witness = E::G1Projective::zero();
-        let mut combined_adjusted_witness = E::G1Projective::zero();
+        let mut pairing_check = PairingCheck::default();
 
         ensure!(query_to_labels_map.len() == proof.0.len());
         for ((_query_name, (query, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
@@ -389,9 +404,9 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             }
 
             Self::accumulate_elems(
-                &mut combined_comms,
-                &mut combined_witness,
-                &mut combined_adjusted_witness,
+                &mut pairing_check.combined_comms,
+                &mut pairing_check.combined_witness,
+                &mut pairing_check.combined_adjusted_witness,
                 vk,
                 comms_to_combine.into_iter(),
                 *query,
@@ -404,9 +419,18 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
         end_timer!(batch_check_time);
-        result.map_err(Into::into)
+        Ok(pairing_check)
+    }
+
+    /// Returns `true` if the product of pairings for the given deferred check is one.
+    pub fn check_pairings(vk: &UniversalVerifier<E>, pairing_check: PairingCheck<E>) -> Result<bool> {
+        Self::check_elems(
+            vk,
+            pairing_check.combined_comms,
+            pairing_check.combined_witness,
+            pairing_check.combined_adjusted_witness,
+        )
     }
 
     pub fn open_combinations<'a>(
@@ -482,6 +506,32 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::check_combinations_deferred(
+            vk,
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            fs_rng,
+        )?;
+        Self::check_pairings(vk, pairing_check)
+    }
+
+    /// Performs the check of the linear combinations, except for the final product of pairings,
+    /// and returns the accumulated elements of the deferred check.
+    pub fn check_combinations_deferred<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -539,7 +589,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::batch_check_deferred(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
     }
 }
 
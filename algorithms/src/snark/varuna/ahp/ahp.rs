@@ -56,12 +56,31 @@ pub(crate) struct NonZeroDomains<F: PrimeField> {
 }
 
 impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
+    /// The query points of the verifier, and the hiding polynomials that are evaluated at each point
+    /// (either directly, or through one of the linear combinations in `construct_linear_combinations`).
+    /// This must be kept in sync with `verifier::QuerySet`.
+    pub const HIDING_POLYNOMIAL_QUERIES: [(&'static str, &'static [&'static str]); 3] =
+        [("alpha", &[]), ("beta", &["w", "g_1"]), ("gamma", &[])];
     /// The linear combinations that are statically known to evaluate to zero.
     /// These correspond to the virtual commitments as noted in the Aleo varuna protocol docs
     pub const LC_WITH_ZERO_EVAL: [&'static str; 3] = ["matrix_sumcheck", "lineval_sumcheck", "rowcheck_zerocheck"];
 
+    /// Returns the hiding bound of the given committed polynomial, or `None` if it is not hiding.
+    /// The hiding bound is derived from the number of distinct points at which the verifier queries the polynomial.
+    /// Note: Witness polynomials are identified by their name (i.e. `w`), rather than their full label.
+    pub fn hiding_bound(polynomial: &str) -> Option<usize> {
+        // Count the distinct query points of the polynomial.
+        let num_queries =
+            Self::HIDING_POLYNOMIAL_QUERIES.iter().filter(|(_, polynomials)| polynomials.contains(&polynomial)).count();
+        (SM::ZK && num_queries > 0).then_some(num_queries)
+    }
+
+    /// Returns the maximum hiding bound of the committed polynomials, or `None` if the mode is not hiding.
     pub fn zk_bound() -> Option<usize> {
-        SM::ZK.then_some(1)
+        Self::HIDING_POLYNOMIAL_QUERIES
+            .iter()
+            .flat_map(|(_, polynomials)| polynomials.iter().filter_map(|polynomial| Self::hiding_bound(polynomial)))
+            .max()
     }
 
     /// Check that the (formatted) public input is of the form 2^n for some integer n.
@@ -510,4 +529,23 @@ mod tests {
         let last = *poly.coeffs.last().unwrap() * size_as_fe;
         assert_eq!(sum, first + last);
     }
+
+    #[test]
+    fn test_hiding_bounds() {
+        use crate::snark::varuna::{VarunaHidingMode, VarunaNonHidingMode};
+
+        type Hiding = AHPForR1CS<Fr, VarunaHidingMode>;
+        type NonHiding = AHPForR1CS<Fr, VarunaNonHidingMode>;
+
+        // Ensure the hiding polynomials are bounded by their number of distinct query points.
+        assert_eq!(Hiding::hiding_bound("w"), Some(1));
+        assert_eq!(Hiding::hiding_bound("g_1"), Some(1));
+        assert_eq!(Hiding::zk_bound(), Some(1));
+        // Ensure polynomials that are not hiding have no hiding bound.
+        assert_eq!(Hiding::hiding_bound("h_0"), None);
+        assert_eq!(Hiding::hiding_bound("mask_poly"), None);
+        // Ensure the non-hiding mode has no hiding bounds.
+        assert_eq!(NonHiding::hiding_bound("w"), None);
+        assert_eq!(NonHiding::zk_bound(), None);
+    }
 }
@@ -46,8 +46,9 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     ) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
         let mut polynomials = circuits
             .flat_map(|(&circuit_id, &batch_size)| {
-                (0..batch_size)
-                    .flat_map(move |i| [PolynomialInfo::new(witness_label(circuit_id, "w", i), None, Self::zk_bound())])
+                (0..batch_size).flat_map(move |i| {
+                    [PolynomialInfo::new(witness_label(circuit_id, "w", i), None, Self::hiding_bound("w"))]
+                })
             })
             .collect::<Vec<_>>();
         if SM::ZK {
@@ -156,7 +157,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
 
         assert!(w_poly.degree() < variable_domain.size() - input_domain.size());
         end_timer!(w_poly_time);
-        LabeledPolynomial::new(label, w_poly, None, Self::zk_bound())
+        LabeledPolynomial::new(label, w_poly, None, Self::hiding_bound("w"))
     }
 }
 
@@ -57,7 +57,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     /// Output the degree bounds of oracles in the first round.
     pub fn third_round_polynomial_info(variable_domain_size: usize) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
         [
-            PolynomialInfo::new("g_1".into(), Some(variable_domain_size - 2), Self::zk_bound()),
+            PolynomialInfo::new("g_1".into(), Some(variable_domain_size - 2), Self::hiding_bound("g_1")),
             PolynomialInfo::new("h_1".into(), None, None),
         ]
         .into_iter()
@@ -112,7 +112,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         assert!(h_1.degree() <= 2 * max_variable_domain.size() + 2 * zk_bound.unwrap_or(0) - 2);
 
         let oracles = prover::ThirdOracles {
-            g_1: LabeledPolynomial::new("g_1", g_1, max_variable_domain.size() - 2, zk_bound),
+            g_1: LabeledPolynomial::new("g_1", g_1, max_variable_domain.size() - 2, Self::hiding_bound("g_1")),
             h_1: LabeledPolynomial::new("h_1", h_1, None, None),
         };
         assert!(oracles.matches_info(&Self::third_round_polynomial_info(state.max_variable_domain.size())));
@@ -67,6 +67,8 @@ impl<F: PrimeField> FourthMessage<F> {
 }
 
 /// Query set of the verifier.
+/// Note: The hiding bounds of the committed polynomials are derived from `AHPForR1CS::HIDING_POLYNOMIAL_QUERIES`,
+/// which must be updated if the query points of a hiding polynomial change.
 #[derive(Clone, Debug)]
 pub struct QuerySet<F: PrimeField> {
     pub batch_sizes: BTreeMap<CircuitId, usize>,
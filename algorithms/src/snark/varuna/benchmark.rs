@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    r1cs::ConstraintSynthesizer,
+    snark::varuna::{AHPForR1CS, CircuitProvingKey, SNARKMode, UniversalSRS, VarunaSNARK},
+    AlgebraicSponge,
+    SNARKError,
+    SNARK,
+};
+use snarkvm_curves::PairingEngine;
+
+use anyhow::{ensure, Result};
+use rand::{CryptoRng, Rng};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    ops::Deref,
+    time::{Duration, Instant},
+};
+
+/// The timings of each round of the AHP prover, for a single circuit size.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundBenchmark {
+    /// The number of constraints in the circuit.
+    pub num_constraints: usize,
+    /// The number of public and private variables in the circuit.
+    pub num_variables: usize,
+    /// The number of non-zero entries in the largest matrix of the circuit.
+    pub num_non_zero: usize,
+    /// The number of samples each timing is averaged over.
+    pub num_samples: usize,
+    /// The mean time of the first prover round, in nanoseconds.
+    pub first_round_ns: u64,
+    /// The mean time of the second prover round, in nanoseconds.
+    pub second_round_ns: u64,
+    /// The mean time of the third prover round, in nanoseconds.
+    pub third_round_ns: u64,
+    /// The mean time of the fourth prover round, in nanoseconds.
+    pub fourth_round_ns: u64,
+}
+
+impl RoundBenchmark {
+    /// Returns the given benchmarks as a JSON array.
+    pub fn to_json(benchmarks: &[Self]) -> Result<String> {
+        Ok(serde_json::to_string_pretty(benchmarks)?)
+    }
+}
+
+impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK<E, FS, SM> {
+    /// Measures each round of the AHP prover (first to fourth) in isolation, for each of the given circuits.
+    /// The circuits are expected to be of increasing size, and each is benchmarked over `num_samples` runs.
+    ///
+    /// Only the prover round itself is timed; the witness synthesis, the commitments, and the verifier
+    /// messages between rounds are excluded, so the impact of optimizing a single round can be measured.
+    pub fn benchmark_prover_rounds<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_srs: &UniversalSRS<E>,
+        fs_parameters: &FS::Parameters,
+        circuits: &[C],
+        num_samples: usize,
+        rng: &mut R,
+    ) -> Result<Vec<RoundBenchmark>> {
+        // Ensure there is at least one sample.
+        ensure!(num_samples > 0, "The number of benchmark samples must be greater than zero");
+
+        let mut benchmarks = Vec::with_capacity(circuits.len());
+        for circuit in circuits {
+            // Index the circuit.
+            let (proving_key, _) = Self::circuit_setup(universal_srs, circuit)?;
+            // Measure each round of the prover.
+            let mut timings = [Duration::ZERO; 4];
+            for _ in 0..num_samples {
+                let sample = Self::time_prover_rounds(fs_parameters, &proving_key, circuit, rng)?;
+                timings.iter_mut().zip(sample).for_each(|(total, time)| *total += time);
+            }
+            // Compute the mean time of each round.
+            let [first_round_ns, second_round_ns, third_round_ns, fourth_round_ns] =
+                timings.map(|total| u64::try_from(total.as_nanos() / num_samples as u128).unwrap_or(u64::MAX));
+
+            let circuit_info = &proving_key.circuit_verifying_key.circuit_info;
+            benchmarks.push(RoundBenchmark {
+                num_constraints: circuit_info.num_constraints,
+                num_variables: circuit_info.num_public_and_private_variables,
+                num_non_zero: circuit_info
+                    .num_non_zero_a
+                    .max(circuit_info.num_non_zero_b)
+                    .max(circuit_info.num_non_zero_c),
+                num_samples,
+                first_round_ns,
+                second_round_ns,
+                third_round_ns,
+                fourth_round_ns,
+            });
+        }
+        Ok(benchmarks)
+    }
+
+    /// Runs the first four rounds of the AHP prover on the given circuit, and returns the time of each round.
+    fn time_prover_rounds<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        proving_key: &CircuitProvingKey<E, SM>,
+        circuit: &C,
+        rng: &mut R,
+    ) -> Result<[Duration; 4]> {
+        let indexed_circuit = proving_key.circuit.deref();
+        let circuit_id = indexed_circuit.id;
+        let circuit_info = &proving_key.circuit_verifying_key.circuit_info;
+
+        // Synthesize the witness.
+        let mut circuits_to_constraints = BTreeMap::new();
+        circuits_to_constraints.insert(indexed_circuit, std::slice::from_ref(circuit));
+        let prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, rng)?;
+
+        // Initialize the sponge, from which the verifier messages are derived.
+        let batch_sizes = BTreeMap::from([(circuit_id, 1)]);
+        let circuit_infos = BTreeMap::from([(circuit_id, circuit_info)]);
+        let mut sponge = {
+            let padded_public_inputs =
+                prover_state.padded_public_inputs(indexed_circuit).ok_or(SNARKError::CircuitNotFound)?;
+            let inputs_and_batch_sizes = BTreeMap::from([(circuit_id, (1, padded_public_inputs))]);
+            let circuit_commitments = proving_key.circuit_verifying_key.circuit_commitments.as_slice();
+            Self::init_sponge(fs_parameters, &inputs_and_batch_sizes, std::iter::once(circuit_commitments))
+        };
+
+        // First round.
+        let timer = Instant::now();
+        let prover_state = AHPForR1CS::<_, SM>::prover_first_round(prover_state, rng)?;
+        let first_round = timer.elapsed();
+
+        let (verifier_first_message, verifier_state) = AHPForR1CS::<_, SM>::verifier_first_round(
+            &batch_sizes,
+            &circuit_infos,
+            prover_state.max_constraint_domain,
+            prover_state.max_variable_domain,
+            prover_state.max_non_zero_domain,
+            &mut sponge,
+        )?;
+
+        // Second round.
+        let timer = Instant::now();
+        let (_, prover_state) = AHPForR1CS::<_, SM>::prover_second_round(&verifier_first_message, prover_state, rng)?;
+        let second_round = timer.elapsed();
+
+        let (verifier_second_message, verifier_state) =
+            AHPForR1CS::<_, SM>::verifier_second_round(verifier_state, &mut sponge)?;
+
+        // Third round.
+        let timer = Instant::now();
+        let (_, _, prover_state) = AHPForR1CS::<_, SM>::prover_third_round(
+            &verifier_first_message,
+            &verifier_second_message,
+            prover_state,
+            rng,
+        )?;
+        let third_round = timer.elapsed();
+
+        let (verifier_third_message, _) = AHPForR1CS::<_, SM>::verifier_third_round(verifier_state, &mut sponge)?;
+
+        // Fourth round.
+        let timer = Instant::now();
+        AHPForR1CS::<_, SM>::prover_fourth_round(&verifier_second_message, &verifier_third_message, prover_state, rng)?;
+        let fourth_round = timer.elapsed();
+
+        Ok([first_round, second_round, third_round, fourth_round])
+    }
+}
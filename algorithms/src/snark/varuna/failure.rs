@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+
+/// The reason for which a well-formed proof was rejected by the verifier.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VerificationFailure {
+    /// The proof does not match the zero-knowledge mode of the verifier.
+    IncorrectZkMode,
+    /// The evaluations in the proof do not open the committed polynomials.
+    InvalidEvaluations,
+}
+
+impl fmt::Display for VerificationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IncorrectZkMode => write!(f, "The proof does not match the zero-knowledge mode of the verifier"),
+            Self::InvalidEvaluations => write!(f, "The evaluations in the proof do not open the committed polynomials"),
+        }
+    }
+}
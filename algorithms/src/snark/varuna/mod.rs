@@ -23,9 +23,19 @@ pub use data_structures::*;
 mod varuna;
 pub use varuna::*;
 
+/// Implements a benchmark harness for the rounds of the AHP prover.
+#[cfg(feature = "bench")]
+mod benchmark;
+#[cfg(feature = "bench")]
+pub use benchmark::*;
+
 /// Specifies the SNARK mode.
 mod mode;
 pub use mode::*;
 
+/// Specifies the reasons for which a proof may be rejected.
+mod failure;
+pub use failure::*;
+
 #[cfg(test)]
 pub mod tests;
@@ -309,8 +309,10 @@ mod varuna_hiding {
             CircuitVerifyingKey,
             VarunaHidingMode,
             VarunaSNARK,
+            VerificationFailure,
         },
         traits::{AlgebraicSponge, SNARK},
+        SNARKError,
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_utilities::{
@@ -319,7 +321,11 @@ mod varuna_hiding {
         ToBytes,
     };
 
-    use std::str::FromStr;
+    use std::{
+        collections::BTreeMap,
+        str::FromStr,
+        sync::atomic::{AtomicBool, Ordering},
+    };
 
     type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
     type FS = PoseidonSponge<Fq, 2, 1>;
@@ -489,6 +495,155 @@ mod varuna_hiding {
         assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &new_vk, public_inputs, &proof).unwrap());
     }
 
+    #[test]
+    fn test_verify_batch_with_failure_reason() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Prove a random circuit.
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 25, 25, rng);
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+
+        // Returns the verification outcome for the given inputs and proof.
+        let verify = |inputs: Vec<Fr>, proof: &crate::snark::varuna::Proof<Bls12_377>| {
+            let inputs = [inputs];
+            let keys_to_inputs = BTreeMap::from([(&index_vk, &inputs[..])]);
+            VarunaInst::verify_batch_with_failure_reason(universal_verifier, &fs_parameters, &keys_to_inputs, proof)
+                .unwrap()
+        };
+
+        // Ensure the valid proof is accepted.
+        assert_eq!(verify(public_inputs.clone(), &proof), Ok(()));
+
+        // Ensure the proof is rejected for an incorrect public input.
+        let mut fake_inputs = public_inputs.clone();
+        fake_inputs[public_inputs.len() - 1] = Fr::rand(rng);
+        assert_eq!(verify(fake_inputs, &proof), Err(VerificationFailure::InvalidEvaluations));
+
+        // Ensure the proof is rejected at the end, rather than with an error, for a missing mask polynomial.
+        let mut invalid_proof = proof.clone();
+        invalid_proof.commitments.mask_poly = None;
+        assert_eq!(verify(public_inputs, &invalid_proof), Err(VerificationFailure::IncorrectZkMode));
+    }
+
+    #[test]
+    fn test_verify_proofs_with_failure_reason() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Prove two random circuits.
+        let (circuit_a, public_inputs_a) = TestCircuit::gen_rand(2, 25, 25, rng);
+        let (pk_a, vk_a) = VarunaInst::circuit_setup(&universal_srs, &circuit_a).unwrap();
+        let proof_a = VarunaInst::prove(universal_prover, &fs_parameters, &pk_a, &circuit_a, rng).unwrap();
+        let (circuit_b, public_inputs_b) = TestCircuit::gen_rand(3, 50, 25, rng);
+        let (pk_b, vk_b) = VarunaInst::circuit_setup(&universal_srs, &circuit_b).unwrap();
+        let proof_b = VarunaInst::prove(universal_prover, &fs_parameters, &pk_b, &circuit_b, rng).unwrap();
+
+        // Returns the verification outcome for the given inputs, verified together in one batch.
+        let verify = |inputs_a: Vec<Fr>, inputs_b: Vec<Fr>, rng: &mut TestRng| {
+            let (inputs_a, inputs_b) = ([inputs_a], [inputs_b]);
+            let batches = [
+                (BTreeMap::from([(&vk_a, &inputs_a[..])]), &proof_a),
+                (BTreeMap::from([(&vk_b, &inputs_b[..])]), &proof_b),
+            ];
+            VarunaInst::verify_proofs_with_failure_reason(universal_verifier, &fs_parameters, &batches, rng).unwrap()
+        };
+
+        // Ensure the valid proofs are accepted.
+        assert_eq!(verify(public_inputs_a.clone(), public_inputs_b.clone(), rng), Ok(()));
+
+        // Ensure the proofs are rejected if either of them has an incorrect public input.
+        let mut fake_inputs_a = public_inputs_a.clone();
+        fake_inputs_a[public_inputs_a.len() - 1] = Fr::rand(rng);
+        assert_eq!(verify(fake_inputs_a, public_inputs_b.clone(), rng), Err(VerificationFailure::InvalidEvaluations));
+        let mut fake_inputs_b = public_inputs_b.clone();
+        fake_inputs_b[public_inputs_b.len() - 1] = Fr::rand(rng);
+        assert_eq!(verify(public_inputs_a, fake_inputs_b, rng), Err(VerificationFailure::InvalidEvaluations));
+
+        // Ensure an empty list of proofs is an error.
+        let batches: [(BTreeMap<_, &[Vec<Fr>]>, _); 0] = [];
+        assert!(
+            VarunaInst::verify_proofs_with_failure_reason(universal_verifier, &fs_parameters, &batches, rng).is_err()
+        );
+    }
+
+    #[cfg(feature = "bench")]
+    #[test]
+    fn test_benchmark_prover_rounds() {
+        use crate::snark::varuna::RoundBenchmark;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Benchmark the prover rounds across circuit sizes.
+        let circuits = [25, 50].map(|size| TestCircuit::gen_rand(1, size, size, rng).0);
+        let benchmarks =
+            VarunaInst::benchmark_prover_rounds(&universal_srs, &fs_parameters, &circuits, 2, rng).unwrap();
+        assert_eq!(benchmarks.len(), 2);
+        assert!(benchmarks[0].num_constraints < benchmarks[1].num_constraints);
+        assert!(benchmarks.iter().all(|benchmark| benchmark.num_samples == 2));
+
+        // Ensure the benchmarks round-trip through JSON.
+        let json = RoundBenchmark::to_json(&benchmarks).unwrap();
+        assert_eq!(benchmarks, serde_json::from_str::<Vec<RoundBenchmark>>(&json).unwrap());
+
+        // Ensure at least one sample is required.
+        assert!(VarunaInst::benchmark_prover_rounds(&universal_srs, &fs_parameters, &circuits, 0, rng).is_err());
+    }
+
+    #[test]
+    fn test_prove_batch_with_terminator() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let mut keys_to_constraints = BTreeMap::new();
+        keys_to_constraints.insert(&index_pk, std::slice::from_ref(&circuit));
+
+        // Ensure a set terminator halts the prover.
+        let terminator = AtomicBool::new(true);
+        let result = VarunaInst::prove_batch_with_terminator(
+            universal_prover,
+            &fs_parameters,
+            &keys_to_constraints,
+            &terminator,
+            rng,
+        );
+        assert!(matches!(result.unwrap_err().downcast_ref(), Some(SNARKError::Terminated)));
+
+        // Ensure an unset terminator does not affect the proof.
+        terminator.store(false, Ordering::Relaxed);
+        let proof = VarunaInst::prove_batch_with_terminator(
+            universal_prover,
+            &fs_parameters,
+            &keys_to_constraints,
+            &terminator,
+            rng,
+        )
+        .unwrap();
+        assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+    }
+
     #[test]
     fn test_srs_downloads() {
         let rng = &mut TestRng::default();
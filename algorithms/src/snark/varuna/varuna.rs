@@ -20,6 +20,8 @@ use crate::{
         CommitterUnionKey,
         Evaluations,
         LabeledCommitment,
+        LabeledPolynomial,
+        PairingCheck,
         QuerySet,
         Randomness,
         SonicKZG10,
@@ -35,6 +37,7 @@ use crate::{
         Proof,
         SNARKMode,
         UniversalSRS,
+        VerificationFailure,
     },
     srs::UniversalVerifier,
     AlgebraicSponge,
@@ -44,18 +47,29 @@ use crate::{
 use rand::RngCore;
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{cfg_iter, rand::Uniform, to_bytes_le, ToBytes};
 
 use anyhow::{anyhow, bail, ensure, Result};
 use core::marker::PhantomData;
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
-use std::{borrow::Borrow, collections::BTreeMap, ops::Deref, sync::Arc};
+use std::{
+    borrow::Borrow,
+    collections::BTreeMap,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 use crate::srs::UniversalProver;
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 /// The Varuna proof system.
 #[derive(Clone, Debug)]
 pub struct VarunaSNARK<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode>(
@@ -87,8 +101,8 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
             })?;
             let coefficient_support = AHPForR1CS::<E::Fr, SM>::get_degree_bounds(&indexed_circuit.index_info)?;
 
-            // Varuna only needs degree 2 random polynomials.
-            let supported_hiding_bound = 1;
+            // The hiding bound is derived from the number of queries to each hiding polynomial.
+            let supported_hiding_bound = AHPForR1CS::<E::Fr, SM>::zk_bound().unwrap_or(1);
             let supported_lagrange_sizes = [].into_iter(); // TODO: consider removing lagrange_bases_at_beta_g from CommitterKey
             let (committer_key, _) = SonicKZG10::<E, FS>::trim(
                 universal_srs,
@@ -101,16 +115,8 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
             let ck = CommitterUnionKey::union(std::iter::once(&committer_key));
 
             let commit_time = start_timer!(|| format!("Commit to index polynomials for {}", indexed_circuit.id));
-            let setup_rng = None::<&mut dyn RngCore>; // We do not randomize the commitments
-
-            let (mut circuit_commitments, commitment_randomnesses): (_, _) = SonicKZG10::<E, FS>::commit(
-                universal_prover,
-                &ck,
-                indexed_circuit.interpolate_matrix_evals()?.map(Into::into),
-                setup_rng,
-            )?;
-            let empty_randomness = Randomness::<E>::empty();
-            ensure!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
+            let mut circuit_commitments =
+                Self::commit_index_polynomials(universal_prover, &ck, indexed_circuit.interpolate_matrix_evals()?)?;
             end_timer!(commit_time);
 
             circuit_commitments.sort_by(|c1, c2| c1.label().cmp(c2.label()));
@@ -133,6 +139,55 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
         Ok(circuit_keys)
     }
 
+    /// Commits to the index polynomials of a circuit.
+    ///
+    /// Matrices with shared supports have identical `row`, `col`, and `row_col` polynomials,
+    /// so each distinct polynomial is committed to once, and its commitment is reused for its duplicates.
+    /// The distinct polynomials are committed to in order of decreasing degree, so the largest MSMs are scheduled first.
+    fn commit_index_polynomials(
+        universal_prover: &UniversalProver<E>,
+        committer_key: &CommitterUnionKey<E>,
+        polynomials: impl Iterator<Item = LabeledPolynomial<E::Fr>>,
+    ) -> Result<Vec<LabeledCommitment<Commitment<E>>>> {
+        let polynomials = polynomials.collect_vec();
+        let is_duplicate = |a: &LabeledPolynomial<E::Fr>, b: &LabeledPolynomial<E::Fr>| {
+            a.degree_bound() == b.degree_bound() && a.polynomial() == b.polynomial()
+        };
+
+        // Select the distinct polynomials, in order of decreasing degree.
+        let mut distinct: Vec<&LabeledPolynomial<E::Fr>> = Vec::with_capacity(polynomials.len());
+        for polynomial in &polynomials {
+            if !distinct.iter().any(|candidate| is_duplicate(candidate, polynomial)) {
+                distinct.push(polynomial);
+            }
+        }
+        distinct.sort_by_key(|polynomial| core::cmp::Reverse(polynomial.degree()));
+
+        // Commit to the distinct polynomials.
+        let setup_rng = None::<&mut dyn RngCore>; // We do not randomize the commitments
+        let (commitments, commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
+            universal_prover,
+            committer_key,
+            distinct.iter().map(|p| (*p).into()),
+            setup_rng,
+        )?;
+        let empty_randomness = Randomness::<E>::empty();
+        ensure!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
+
+        // Assign each polynomial the commitment of its distinct polynomial.
+        polynomials
+            .iter()
+            .map(|polynomial| {
+                let index = distinct
+                    .iter()
+                    .position(|candidate| is_duplicate(candidate, polynomial))
+                    .ok_or_else(|| anyhow!("Missing commitment for index polynomial '{}'", polynomial.label()))?;
+                let commitment = *commitments[index].commitment();
+                Ok(LabeledCommitment::new(polynomial.label().to_string(), commitment, polynomial.degree_bound()))
+            })
+            .collect()
+    }
+
     fn init_sponge<'a>(
         fs_parameters: &FS::Parameters,
         inputs_and_batch_sizes: &BTreeMap<CircuitId, (usize, &[Vec<E::Fr>])>,
@@ -173,6 +228,14 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
         Self::absorb_with_sums(&commitments, sums, sponge)
     }
 
+    /// Returns an error if the terminator has been set.
+    fn terminate(terminator: &AtomicBool) -> Result<()> {
+        if terminator.load(Ordering::Relaxed) {
+            bail!(SNARKError::Terminated)
+        }
+        Ok(())
+    }
+
     fn absorb_labeled(comms: &[LabeledCommitment<Commitment<E>>], sponge: &mut FS) {
         let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
         Self::absorb(&commitments, sponge);
@@ -333,10 +396,11 @@ where
     /// This is the main entrypoint for creating proofs.
     /// You can find a specification of the prover algorithm in:
     /// https://github.com/AleoHQ/protocol-docs
-    fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
         keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
+        terminator: &AtomicBool,
         zk_rng: &mut R,
     ) -> Result<Self::Proof> {
         let prover_time = start_timer!(|| "Varuna::Prover");
@@ -384,6 +448,8 @@ where
         // --------------------------------------------------------------------
         // First round
 
+        Self::terminate(terminator)?;
+
         let prover_state = AHPForR1CS::<_, SM>::prover_first_round(prover_state, zk_rng)?;
 
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
@@ -413,6 +479,8 @@ where
         // --------------------------------------------------------------------
         // Second round
 
+        Self::terminate(terminator)?;
+
         let (second_oracles, prover_state) =
             AHPForR1CS::<_, SM>::prover_second_round(&verifier_first_message, prover_state, zk_rng)?;
 
@@ -434,6 +502,8 @@ where
         // --------------------------------------------------------------------
         // Third round
 
+        Self::terminate(terminator)?;
+
         let (prover_third_message, third_oracles, prover_state) = AHPForR1CS::<_, SM>::prover_third_round(
             &verifier_first_message,
             &verifier_second_msg,
@@ -463,6 +533,8 @@ where
         // --------------------------------------------------------------------
         // Fourth round
 
+        Self::terminate(terminator)?;
+
         let (prover_fourth_message, fourth_oracles, mut prover_state) =
             AHPForR1CS::<_, SM>::prover_fourth_round(&verifier_second_msg, &verifier_third_msg, prover_state, zk_rng)?;
 
@@ -490,6 +562,8 @@ where
 
         // --------------------------------------------------------------------
         // Fifth round
+        Self::terminate(terminator)?;
+
         let fifth_oracles = AHPForR1CS::<_, SM>::prover_fifth_round(verifier_fourth_msg, prover_state, zk_rng)?;
 
         let fifth_round_comm_time = start_timer!(|| "Committing to fifth round polys");
@@ -569,6 +643,8 @@ where
             ensure!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
         }
 
+        Self::terminate(terminator)?;
+
         // Compute the AHP verifier's query set.
         let (query_set, verifier_state) = AHPForR1CS::<_, SM>::verifier_query_set(verifier_state);
         let lc_s = AHPForR1CS::<_, SM>::construct_linear_combinations(
@@ -628,6 +704,100 @@ where
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
     ) -> Result<bool> {
+        Ok(Self::verify_batch_with_failure_reason(universal_verifier, fs_parameters, keys_to_inputs, proof)?.is_ok())
+    }
+}
+
+impl<E: PairingEngine, FS, SM> VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    /// Verifies the given batch of proofs, and returns the reason for a failure if the proof is invalid.
+    /// An error is returned only if the inputs or the proof are malformed.
+    ///
+    /// The verifier performs the same work for a valid and an invalid proof up to the final pairing check,
+    /// and only combines the individual checks at the end, so the time it takes does not reveal which check failed.
+    pub fn verify_batch_with_failure_reason<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<Result<(), VerificationFailure>> {
+        // Perform the verification, up to the final pairing check.
+        let (proof_has_correct_zk_mode, pairing_check) =
+            Self::verify_batch_deferred(universal_verifier, fs_parameters, keys_to_inputs, proof)?;
+        // Perform the final pairing check.
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_pairings(universal_verifier, pairing_check)?;
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("SonicKZG10::Check failed for the AHP Verifier linear equations");
+        }
+        // Combine the checks, after all of them have been performed.
+        match (proof_has_correct_zk_mode, evaluations_are_correct) {
+            (true, true) => Ok(Ok(())),
+            (false, _) => Ok(Err(VerificationFailure::IncorrectZkMode)),
+            (true, false) => Ok(Err(VerificationFailure::InvalidEvaluations)),
+        }
+    }
+
+    /// Verifies the given proofs, each for its own batch of verifying keys and public inputs,
+    /// and returns the reason for a failure if any of the proofs is invalid.
+    /// An error is returned only if the inputs or any of the proofs are malformed.
+    ///
+    /// The final pairing checks of the proofs are combined with random coefficients sampled from `rng`,
+    /// so the proofs are verified with a single product of pairings. As a result, a failure does not
+    /// identify which of the proofs is invalid.
+    pub fn verify_proofs_with_failure_reason<B: Borrow<[E::Fr]> + Sync, R: Rng + CryptoRng>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        batches: &[(BTreeMap<&CircuitVerifyingKey<E>, &[B]>, &Proof<E>)],
+        rng: &mut R,
+    ) -> Result<Result<(), VerificationFailure>>
+    where
+        FS::Parameters: Sync,
+    {
+        if batches.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
+
+        // Perform the verification of each proof, up to the final pairing check.
+        let deferred_checks = cfg_iter!(batches)
+            .map(|(keys_to_inputs, proof)| {
+                Self::verify_batch_deferred(universal_verifier, fs_parameters, keys_to_inputs, proof)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Combine the deferred pairing checks.
+        // Note: The coefficients are sampled after all of the proofs have been fixed.
+        let mut proofs_have_correct_zk_mode = true;
+        let mut combined_check = PairingCheck::default();
+        for (proof_has_correct_zk_mode, pairing_check) in &deferred_checks {
+            proofs_have_correct_zk_mode &= *proof_has_correct_zk_mode;
+            combined_check.add_scaled(pairing_check, E::Fr::rand(rng));
+        }
+        // Perform the final pairing check, for all of the proofs at once.
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_pairings(universal_verifier, combined_check)?;
+
+        // Combine the checks, after all of them have been performed.
+        match (proofs_have_correct_zk_mode, evaluations_are_correct) {
+            (true, true) => Ok(Ok(())),
+            (false, _) => Ok(Err(VerificationFailure::IncorrectZkMode)),
+            (true, false) => Ok(Err(VerificationFailure::InvalidEvaluations)),
+        }
+    }
+
+    /// Performs the verification of the given batch proof, except for the final pairing check.
+    /// Returns whether the proof has the correct zero-knowledge mode, and the deferred pairing check.
+    fn verify_batch_deferred<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<(bool, PairingCheck<E>)> {
         if keys_to_inputs.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -718,12 +888,13 @@ where
         } else {
             !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
         };
+        // Note: The verifier continues on an incorrect zero-knowledge mode, and rejects the proof at the end.
+        #[cfg(debug_assertions)]
         if !proof_has_correct_zk_mode {
             eprintln!(
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
         }
 
         let verifier_time = start_timer!(|| format!("Varuna::Verify with batch sizes: {:?}", batch_sizes));
@@ -751,7 +922,8 @@ where
         if SM::ZK {
             first_commitments.push(LabeledCommitment::new_with_info(
                 first_round_info.get("mask_poly").ok_or(anyhow!("Missing mask_poly"))?,
-                comms.mask_poly.ok_or(anyhow!("Missing mask_poly"))?,
+                // Note: A missing `mask_poly` is substituted, so the verifier performs the same work.
+                comms.mask_poly.unwrap_or_default(),
             ));
         }
 
@@ -896,8 +1068,8 @@ where
         )?;
         end_timer!(lc_time);
 
-        let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let pc_time = start_timer!(|| "Accumulating linear combinations with PC");
+        let pairing_check = SonicKZG10::<E, FS>::check_combinations_deferred(
             universal_verifier,
             lc_s.values(),
             &commitments,
@@ -908,15 +1080,7 @@ where
         )?;
         end_timer!(pc_time);
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", verifier_state.gamma);
-        }
-
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        end_timer!(verifier_time);
+        Ok((proof_has_correct_zk_mode, pairing_check))
     }
 }
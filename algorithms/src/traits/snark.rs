@@ -18,7 +18,7 @@ use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, FromBytes, ToB
 
 use anyhow::Result;
 use rand::{CryptoRng, Rng};
-use std::{borrow::Borrow, collections::BTreeMap, fmt::Debug};
+use std::{borrow::Borrow, collections::BTreeMap, fmt::Debug, sync::atomic::AtomicBool};
 
 /// Defines trait that describes preparing from an unprepared version to a prepare version.
 pub trait Prepare {
@@ -85,6 +85,23 @@ pub trait SNARK {
         fs_parameters: &Self::FSParameters,
         keys_to_constraints: &BTreeMap<&Self::ProvingKey, &[C]>,
         rng: &mut R,
+    ) -> Result<Self::Proof> {
+        Self::prove_batch_with_terminator(
+            universal_prover,
+            fs_parameters,
+            keys_to_constraints,
+            &AtomicBool::new(false),
+            rng,
+        )
+    }
+
+    /// Proves the given batch, halting between prover rounds once the terminator is set.
+    fn prove_batch_with_terminator<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&Self::ProvingKey, &[C]>,
+        terminator: &AtomicBool,
+        rng: &mut R,
     ) -> Result<Self::Proof>;
 
     fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_circuit::{
+    environment::{prelude::num_traits::One as _, Circuit, Environment, Inject, Mode},
+    types::Field,
+};
+
+use criterion::Criterion;
+
+/// Synthesizes a circuit with many variables and constraints, and ejects its assignment.
+fn synthesize() {
+    let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+    let two = one + one;
+
+    let mut candidate = Field::<Circuit>::new(Mode::Public, one);
+    for _ in 0..10_000 {
+        candidate *= Field::new(Mode::Private, two);
+    }
+    let _assignment = Circuit::eject_assignment_and_reset();
+}
+
+fn arena_mode(c: &mut Criterion) {
+    Circuit::set_arena_mode(false);
+    c.bench_function("synthesize", |b| b.iter(synthesize));
+
+    Circuit::set_arena_mode(true);
+    c.bench_function("synthesize (arena mode)", |b| b.iter(synthesize));
+    Circuit::set_arena_mode(false);
+}
+
+criterion_group! {
+    name = arena;
+    config = Criterion::default().sample_size(10);
+    targets = arena_mode
+}
+
+criterion_main!(arena);
@@ -26,6 +26,7 @@ thread_local! {
     static CONSTRAINT_LIMIT: Cell<Option<u64>> = Cell::new(None);
     pub(super) static CIRCUIT: RefCell<R1CS<Field>> = RefCell::new(R1CS::new());
     static IN_WITNESS: Cell<bool> = Cell::new(false);
+    static ARENA_MODE: Cell<bool> = Cell::new(false);
     static ZERO: LinearCombination<Field> = LinearCombination::zero();
     static ONE: LinearCombination<Field> = LinearCombination::one();
 }
@@ -33,6 +34,21 @@ thread_local! {
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Circuit;
 
+impl Circuit {
+    /// Enables or disables arena mode for the circuit on the current thread.
+    ///
+    /// In arena mode, resetting the circuit between functions clears the variable and constraint storage
+    /// while retaining its allocations, so the next function is synthesized without growing the storage again.
+    pub fn set_arena_mode(enabled: bool) {
+        ARENA_MODE.with(|arena_mode| arena_mode.set(enabled));
+    }
+
+    /// Returns `true` if arena mode is enabled for the circuit on the current thread.
+    pub fn is_arena_mode() -> bool {
+        ARENA_MODE.with(|arena_mode| arena_mode.get())
+    }
+}
+
 impl Environment for Circuit {
     type Affine = <console::MainnetV0 as console::Environment>::Affine;
     type BaseField = Field;
@@ -343,15 +359,21 @@ impl Environment for Circuit {
             Self::set_variable_limit(None);
             // Reset the constraint limit.
             Self::set_constraint_limit(None);
-            // Eject the R1CS instance.
-            let r1cs = circuit.replace(R1CS::<<Self as Environment>::BaseField>::new());
+            // Convert the R1CS instance to an assignment, and reset the circuit.
+            let assignment = match Self::is_arena_mode() {
+                true => {
+                    let assignment = Assignment::from(&*circuit.borrow());
+                    circuit.borrow_mut().clear();
+                    assignment
+                }
+                false => Assignment::from(circuit.replace(R1CS::<<Self as Environment>::BaseField>::new())),
+            };
             assert_eq!(0, circuit.borrow().num_constants());
             assert_eq!(1, circuit.borrow().num_public());
             assert_eq!(0, circuit.borrow().num_private());
             assert_eq!(1, circuit.borrow().num_variables());
             assert_eq!(0, circuit.borrow().num_constraints());
-            // Convert the R1CS instance to an assignment.
-            Assignment::from(r1cs)
+            assignment
         })
     }
 
@@ -364,8 +386,11 @@ impl Environment for Circuit {
             Self::set_variable_limit(None);
             // Reset the constraint limit.
             Self::set_constraint_limit(None);
-            // Reset the circuit.
-            *circuit.borrow_mut() = R1CS::<<Self as Environment>::BaseField>::new();
+            // Reset the circuit, retaining its storage in arena mode.
+            match Self::is_arena_mode() {
+                true => circuit.borrow_mut().clear(),
+                false => *circuit.borrow_mut() = R1CS::<<Self as Environment>::BaseField>::new(),
+            }
             assert_eq!(0, circuit.borrow().num_constants());
             assert_eq!(1, circuit.borrow().num_public());
             assert_eq!(0, circuit.borrow().num_private());
@@ -430,4 +455,31 @@ mod tests {
             assert_eq!(0, Circuit::num_constraints_in_scope());
         })
     }
+
+    #[test]
+    fn test_arena_mode() {
+        // Synthesize the circuit without arena mode.
+        let _candidate = create_example_circuit::<Circuit>();
+        let expected = Circuit::eject_assignment_and_reset();
+
+        // Synthesize the circuit in arena mode, resetting between each synthesis.
+        Circuit::set_arena_mode(true);
+        assert!(Circuit::is_arena_mode());
+        for _ in 0..3 {
+            let _candidate = create_example_circuit::<Circuit>();
+            let candidate = Circuit::eject_assignment_and_reset();
+            assert_eq!(expected.num_public(), candidate.num_public());
+            assert_eq!(expected.num_private(), candidate.num_private());
+            assert_eq!(expected.num_variables(), candidate.num_variables());
+            assert_eq!(expected.num_constraints(), candidate.num_constraints());
+            assert_eq!(expected.num_nonzeros(), candidate.num_nonzeros());
+
+            // Ensure the circuit is also cleared on reset.
+            let _candidate = create_example_circuit::<Circuit>();
+            Circuit::reset();
+            assert_eq!(1, Circuit::num_public());
+            assert_eq!(0, Circuit::num_constraints());
+        }
+        Circuit::set_arena_mode(false);
+    }
 }
@@ -14,6 +14,12 @@
 
 use crate::Index;
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
 
 use indexmap::IndexMap;
 use std::sync::Arc;
@@ -98,6 +104,13 @@ pub struct Assignment<F: PrimeField> {
 impl<F: PrimeField> From<crate::R1CS<F>> for Assignment<F> {
     /// Converts an R1CS to an assignment.
     fn from(r1cs: crate::R1CS<F>) -> Self {
+        Self::from(&r1cs)
+    }
+}
+
+impl<F: PrimeField> From<&crate::R1CS<F>> for Assignment<F> {
+    /// Converts an R1CS to an assignment.
+    fn from(r1cs: &crate::R1CS<F>) -> Self {
         Self {
             public: FromIterator::from_iter(
                 r1cs.to_public_variables().iter().map(|variable| (variable.index(), variable.value())),
@@ -281,6 +294,117 @@ impl<F: PrimeField> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for Assig
     }
 }
 
+impl<F: PrimeField> ToBytes for AssignmentVariable<F> {
+    /// Writes the assignment variable to the writer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Constant(value) => {
+                0u8.write_le(&mut writer)?;
+                value.write_le(&mut writer)
+            }
+            Self::Public(index) => {
+                1u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+            Self::Private(index) => {
+                2u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> FromBytes for AssignmentVariable<F> {
+    /// Reads the assignment variable from the reader.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::Constant(F::read_le(&mut reader)?)),
+            1 => Ok(Self::Public(Index::read_le(&mut reader)?)),
+            2 => Ok(Self::Private(Index::read_le(&mut reader)?)),
+            _ => Err(error("Invalid assignment variable")),
+        }
+    }
+}
+
+impl<F: PrimeField> ToBytes for AssignmentLC<F> {
+    /// Writes the assignment linear combination to the writer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.constant.write_le(&mut writer)?;
+        (self.terms.len() as u64).write_le(&mut writer)?;
+        for (variable, coefficient) in &self.terms {
+            variable.write_le(&mut writer)?;
+            coefficient.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> FromBytes for AssignmentLC<F> {
+    /// Reads the assignment linear combination from the reader.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let constant = F::read_le(&mut reader)?;
+        let num_terms = u64::read_le(&mut reader)?;
+        let mut terms = Vec::new();
+        for _ in 0..num_terms {
+            terms.push((AssignmentVariable::read_le(&mut reader)?, F::read_le(&mut reader)?));
+        }
+        Ok(Self { constant, terms })
+    }
+}
+
+impl<F: PrimeField> ToBytes for Assignment<F> {
+    /// Writes the assignment to the writer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the public and private variables.
+        for variables in [&self.public, &self.private] {
+            (variables.len() as u64).write_le(&mut writer)?;
+            for (index, value) in variables.iter() {
+                index.write_le(&mut writer)?;
+                value.write_le(&mut writer)?;
+            }
+        }
+        // Write the constraints.
+        (self.constraints.len() as u64).write_le(&mut writer)?;
+        for (a, b, c) in self.constraints.iter() {
+            a.write_le(&mut writer)?;
+            b.write_le(&mut writer)?;
+            c.write_le(&mut writer)?;
+        }
+        // Write the number of variables.
+        self.num_variables.write_le(&mut writer)
+    }
+}
+
+impl<F: PrimeField> FromBytes for Assignment<F> {
+    /// Reads the assignment from the reader.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Reads a list of variables.
+        let mut read_variables = || -> IoResult<Arc<[(Index, F)]>> {
+            let num_variables = u64::read_le(&mut reader)?;
+            let mut variables = Vec::new();
+            for _ in 0..num_variables {
+                variables.push((Index::read_le(&mut reader)?, F::read_le(&mut reader)?));
+            }
+            Ok(variables.into())
+        };
+        // Read the public and private variables.
+        let public = read_variables()?;
+        let private = read_variables()?;
+        // Read the constraints.
+        let num_constraints = u64::read_le(&mut reader)?;
+        let mut constraints = Vec::new();
+        for _ in 0..num_constraints {
+            let a = AssignmentLC::read_le(&mut reader)?;
+            let b = AssignmentLC::read_le(&mut reader)?;
+            let c = AssignmentLC::read_le(&mut reader)?;
+            constraints.push((a, b, c));
+        }
+        // Read the number of variables.
+        let num_variables = u64::read_le(&mut reader)?;
+        Ok(Self { public, private, constraints: constraints.into(), num_variables })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use snarkvm_algorithms::{r1cs::ConstraintSynthesizer, AlgebraicSponge, SNARK};
@@ -331,6 +455,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_assignment_bytes() {
+        use snarkvm_utilities::{FromBytes, ToBytes};
+
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        // Ensure the assignment round-trips through bytes.
+        let bytes = assignment.to_bytes_le().unwrap();
+        let candidate = crate::Assignment::<Fr>::read_le(&bytes[..]).unwrap();
+        assert_eq!(bytes, candidate.to_bytes_le().unwrap());
+        assert_eq!(assignment.num_public(), candidate.num_public());
+        assert_eq!(assignment.num_private(), candidate.num_private());
+        assert_eq!(assignment.num_variables(), candidate.num_variables());
+        assert_eq!(assignment.num_constraints(), candidate.num_constraints());
+        assert_eq!(assignment.num_nonzeros(), candidate.num_nonzeros());
+
+        // Ensure the restored assignment is satisfied.
+        let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::new();
+        candidate.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+    }
+
     #[test]
     fn test_varuna() {
         let _candidate_output = create_example_circuit::<Circuit>();
@@ -47,6 +47,18 @@ impl<F: PrimeField> R1CS<F> {
         }
     }
 
+    /// Clears the constraint system, retaining the allocated variable and constraint storage for reuse.
+    pub(crate) fn clear(&mut self) {
+        self.constants.clear();
+        self.public.clear();
+        self.public.push(Variable::Public(Rc::new((0u64, F::one()))));
+        self.private.clear();
+        self.constraints.clear();
+        self.counter = Default::default();
+        self.num_variables = 1u64;
+        self.nonzeros = (0, 0, 0);
+    }
+
     /// Appends the given scope to the current environment.
     pub(crate) fn push_scope<S: Into<String>>(&mut self, name: S) -> Result<(), String> {
         self.counter.push(name)
@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    ///
+    /// Returns `true` if `a` and `b` are equal for every pair `(a, b)` in `pairs`.
+    ///
+    /// This method is equivalent to calling `is_equal` on each pair and combining the results
+    /// with `&`, but costs 2 constraints per non-constant pair, instead of 3 constraints per pair.
+    ///
+    pub fn is_all_equal(pairs: &[(Field<E>, Field<E>)]) -> Boolean<E> {
+        // Compute the differences of the non-constant pairs, and check the constant pairs directly.
+        let mut deltas = Vec::with_capacity(pairs.len());
+        for (a, b) in pairs {
+            match a.is_constant() && b.is_constant() {
+                // If a constant pair is not equal, the result is constant `false`.
+                true => {
+                    if a.eject_value() != b.eject_value() {
+                        return Boolean::constant(false);
+                    }
+                }
+                false => deltas.push(a - b),
+            }
+        }
+
+        // If every pair is constant and equal, the result is constant `true`.
+        if deltas.is_empty() {
+            return Boolean::constant(true);
+        }
+
+        // Batched Equality Enforcement
+        // ----------------------------------------------------------------
+        // Check 1:  delta_i * is_eq = 0, for every i
+        // Check 2:  sum_i (delta_i * multiplier_i) = 1 - is_eq
+        //
+        //
+        // Case 1: every delta_i == 0
+        // ----------------------------------------------------------------
+        // Check 1 holds for any is_eq.
+        // Check 2:  0 = 1 - is_eq
+        // => The constraints are satisfied if and only if is_eq == 1.
+        //
+        //
+        // Case 2: some delta_j != 0
+        // ----------------------------------------------------------------
+        // Check 1:  delta_j * is_eq = 0
+        // => The constraints are only satisfied if is_eq == 0.
+        //
+        // Check 2:  sum_i (delta_i * multiplier_i) = 1
+        // => The honest prover sets multiplier_j = delta_j^(-1), and multiplier_i = 0 for i != j.
+        //
+        //
+        // Observe that in both cases, `is_eq` is uniquely determined and is always 0 or 1.
+
+        // Find the first difference that is nonzero, if one exists.
+        let first_nonzero = deltas.iter().position(|delta| !delta.eject_value().is_zero());
+
+        // Witness a boolean that is `true` if every difference is zero.
+        let is_eq = Boolean::from_variable(E::new_variable(Mode::Private, match first_nonzero {
+            None => E::BaseField::one(),
+            Some(_) => E::BaseField::zero(),
+        }));
+
+        // Assign the expected multipliers as witnesses.
+        let multipliers: Vec<Field<E>> = deltas
+            .iter()
+            .enumerate()
+            .map(|(index, delta)| {
+                Field::new(Mode::Private, match Some(index) == first_nonzero {
+                    true => delta.eject_value().inverse().unwrap_or_else(|_| console::Field::zero()),
+                    false => console::Field::zero(),
+                })
+            })
+            .collect();
+
+        // Check 1: delta_i * is_eq = 0, for every i
+        for delta in &deltas {
+            E::enforce(|| (delta, &is_eq, E::zero()));
+        }
+
+        // Check 2: sum_i (delta_i * multiplier_i) = 1 - is_eq
+        //
+        // Note: the last product is folded into the final constraint, so that no extra witness is required for it.
+        let last = deltas.len() - 1;
+        let mut sum = Field::zero();
+        for (delta, multiplier) in deltas[..last].iter().zip_eq(&multipliers[..last]) {
+            sum += delta * multiplier;
+        }
+        E::enforce(|| (&deltas[last], &multipliers[last], Field::from_boolean(&!is_eq.clone()) - sum));
+
+        // Return `is_eq`.
+        is_eq
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 50;
+    const NUM_PAIRS: usize = 8;
+
+    fn check_is_all_equal(name: &str, expected: bool, pairs: &[(Field<Circuit>, Field<Circuit>)]) {
+        Circuit::scope(name, || {
+            let candidate = Field::is_all_equal(pairs);
+            assert_eq!(expected, candidate.eject_value());
+
+            // Ensure the batched gadget agrees with the unbatched gadget.
+            let unbatched = pairs.iter().fold(true, |acc, (a, b)| acc & (a.eject_value() == b.eject_value()));
+            assert_eq!(unbatched, candidate.eject_value());
+
+            // Ensure the cost is 2 constraints per non-constant pair.
+            let num_variable = pairs.iter().filter(|(a, b)| !(a.is_constant() && b.is_constant())).count();
+            let constant_mismatch =
+                pairs.iter().any(|(a, b)| a.is_constant() && b.is_constant() && a.eject_value() != b.eject_value());
+            match constant_mismatch || num_variable == 0 {
+                true => assert_scope!(0, 0, 0, 0),
+                false => assert_scope!(0, 0, 2 * num_variable as u64, 2 * num_variable as u64),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let values: Vec<console::Field<<Circuit as Environment>::Network>> =
+                (0..NUM_PAIRS).map(|_| Uniform::rand(&mut rng)).collect();
+
+            // Check that equal pairs are equal.
+            let pairs: Vec<(Field<Circuit>, Field<Circuit>)> =
+                values.iter().map(|value| (Field::new(mode_a, *value), Field::new(mode_b, *value))).collect();
+            check_is_all_equal(&format!("All equal {i}"), true, &pairs);
+
+            // Check that a single unequal pair, in any position, is detected.
+            for index in 0..NUM_PAIRS {
+                let pairs: Vec<(Field<Circuit>, Field<Circuit>)> = values
+                    .iter()
+                    .enumerate()
+                    .map(|(j, value)| match j == index {
+                        true => (Field::new(mode_a, *value), Field::new(mode_b, *value + console::Field::one())),
+                        false => (Field::new(mode_a, *value), Field::new(mode_b, *value)),
+                    })
+                    .collect();
+                check_is_all_equal(&format!("Unequal at {index} {i}"), false, &pairs);
+            }
+
+            // Check that fully unequal pairs are not equal.
+            let pairs: Vec<(Field<Circuit>, Field<Circuit>)> = values
+                .iter()
+                .map(|value| (Field::new(mode_a, *value), Field::new(mode_b, Uniform::rand(&mut rng))))
+                .collect();
+            check_is_all_equal(&format!("All unequal {i}"), false, &pairs);
+        }
+    }
+
+    #[test]
+    fn test_constant_is_all_equal_to_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_is_all_equal_to_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_public_is_all_equal_to_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_is_all_equal_to_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+
+    #[test]
+    fn test_is_all_equal_empty() {
+        assert!(Field::<Circuit>::is_all_equal(&[]).eject_value());
+        assert!(Field::<Circuit>::is_all_equal(&[]).is_constant());
+    }
+
+    #[test]
+    fn test_is_all_equal_rejects_dishonest_witness() {
+        let one = console::Field::<<Circuit as Environment>::Network>::one();
+
+        // Enforce the batched constraints with the given (possibly dishonest) witnesses.
+        let enforce = |deltas: Vec<Field<Circuit>>, multipliers: Vec<Field<Circuit>>, is_eq: Boolean<Circuit>| {
+            for delta in &deltas {
+                Circuit::enforce(|| (delta, &is_eq, Circuit::zero()));
+            }
+            let mut sum = Field::zero();
+            for (delta, multiplier) in deltas.iter().zip_eq(&multipliers) {
+                sum += delta * multiplier;
+            }
+            Circuit::enforce(|| (Field::from_boolean(&!is_eq.clone()) - sum, Circuit::one(), Circuit::zero()));
+        };
+
+        // Claim all pairs are equal, when one is not.
+        let deltas = vec![Field::new(Mode::Private, console::Field::zero()), Field::new(Mode::Private, one)];
+        let multipliers = vec![Field::new(Mode::Private, one), Field::new(Mode::Private, console::Field::zero())];
+        enforce(deltas, multipliers, Boolean::new(Mode::Private, true));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Claim a pair is unequal, when all are equal.
+        let deltas =
+            vec![Field::new(Mode::Private, console::Field::zero()), Field::new(Mode::Private, console::Field::zero())];
+        let multipliers = vec![Field::new(Mode::Private, one), Field::new(Mode::Private, one)];
+        enforce(deltas, multipliers, Boolean::new(Mode::Private, false));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -24,6 +24,7 @@ pub mod div;
 pub mod div_unchecked;
 pub mod double;
 pub mod equal;
+pub mod equal_all;
 pub mod inverse;
 pub mod mul;
 pub mod neg;
@@ -33,6 +33,14 @@ impl<N: Network> FromStr for GraphKey<N> {
     }
 }
 
+impl<N: Network> fmt::Debug for GraphKey<N> {
+    /// Writes the account graph key as redacted, to prevent it from leaking into logs.
+    /// Note: Use `Display` to explicitly write the account graph key as a base58 string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GraphKey([REDACTED])")
+    }
+}
+
 impl<N: Network> fmt::Display for GraphKey<N> {
     /// Writes the account graph key as a base58 string.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -21,6 +21,9 @@ pub use snarkvm_console_types::{environment::prelude::*, Address, Field, Group,
 
 mod address;
 
+pub mod secret;
+pub use secret::*;
+
 #[cfg(feature = "compute_key")]
 pub mod compute_key;
 #[cfg(feature = "compute_key")]
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use crate::Secret;
 
 static PRIVATE_KEY_PREFIX: [u8; 11] = [127, 134, 189, 116, 210, 221, 210, 137, 145, 18, 253]; // APrivateKey1
 
@@ -22,7 +23,8 @@ impl<N: Network> FromStr for PrivateKey<N> {
     /// Reads in an account private key from a base58 string.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // Encode the string into base58.
-        let data = bs58::decode(s).into_vec().map_err(|err| anyhow!("{:?}", err))?;
+        let data = Secret::new(bs58::decode(s).into_vec().map_err(|err| anyhow!("{:?}", err))?);
+        let data = data.expose();
         if data.len() != 43 {
             bail!("Invalid account private key length: found {}, expected 43", data.len())
         } else if data[0..11] != PRIVATE_KEY_PREFIX {
@@ -33,15 +35,23 @@ impl<N: Network> FromStr for PrivateKey<N> {
     }
 }
 
+impl<N: Network> fmt::Debug for PrivateKey<N> {
+    /// Writes the account private key as redacted, to prevent it from leaking into logs.
+    /// Note: Use `Display` to explicitly write the account private key as a base58 string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PrivateKey([REDACTED])")
+    }
+}
+
 impl<N: Network> fmt::Display for PrivateKey<N> {
     /// Writes the account private key as a base58 string.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // Write the private key bytes.
-        let mut private_key = [0u8; 43];
-        private_key[0..11].copy_from_slice(&PRIVATE_KEY_PREFIX);
-        self.seed.write_le(&mut private_key[11..43]).map_err(|_| fmt::Error)?;
+        let mut private_key = Secret::new([0u8; 43]);
+        private_key.expose_mut()[0..11].copy_from_slice(&PRIVATE_KEY_PREFIX);
+        self.seed.write_le(&mut private_key.expose_mut()[11..43]).map_err(|_| fmt::Error)?;
         // Encode the private key into base58.
-        write!(f, "{}", bs58::encode(private_key).into_string())
+        write!(f, "{}", bs58::encode(private_key.expose()).into_string())
     }
 }
 
@@ -69,4 +79,19 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_debug() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+        // Ensure the debug representation is redacted.
+        let candidate = format!("{private_key:?}");
+        assert_eq!("PrivateKey([REDACTED])", candidate);
+        assert!(!candidate.contains(&private_key.to_string()));
+        assert!(!candidate.contains(&private_key.seed().to_string()));
+        Ok(())
+    }
 }
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_console_network::prelude::*;
+
+use zeroize::Zeroize;
+
+/// A wrapper for secret values (such as private keys, view keys, and signing randomness).
+///
+/// The secret is redacted when printed with `Debug`, is only accessible through `expose`,
+/// and is zeroized when the wrapper is dropped.
+pub struct Secret<T: Zeroize>(T);
+
+impl<T: Zeroize> Secret<T> {
+    /// Initializes a new secret.
+    pub const fn new(secret: T) -> Self {
+        Self(secret)
+    }
+
+    /// Returns a reference to the secret.
+    ///
+    /// Note: The caller is responsible for ensuring the secret is not copied into logs or error messages.
+    pub const fn expose(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the secret.
+    pub fn expose_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Zeroize> From<T> for Secret<T> {
+    /// Initializes a new secret.
+    fn from(secret: T) -> Self {
+        Self::new(secret)
+    }
+}
+
+impl<T: Zeroize + Clone> Clone for Secret<T> {
+    /// Returns a copy of the secret.
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<T: Zeroize> Debug for Secret<T> {
+    /// Prints the secret as redacted.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Secret([REDACTED])")
+    }
+}
+
+impl<T: Zeroize> Drop for Secret<T> {
+    /// Zeroizes the secret.
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+    use snarkvm_console_types::Scalar;
+
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+
+    type CurrentNetwork = MainnetV0;
+
+    /// A value that records whether it was zeroized.
+    struct Tracked(Arc<AtomicBool>);
+
+    impl Zeroize for Tracked {
+        fn zeroize(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_secret() {
+        let mut rng = TestRng::default();
+
+        // Sample a secret.
+        let scalar = Scalar::<CurrentNetwork>::rand(&mut rng);
+        let secret = Secret::new(scalar);
+        assert_eq!(*secret.expose(), scalar);
+
+        // Ensure the secret is redacted.
+        let candidate = format!("{secret:?}");
+        assert!(candidate.contains("[REDACTED]"));
+        assert!(!candidate.contains(&scalar.to_string()));
+    }
+
+    #[test]
+    fn test_secret_zeroizes_on_drop() {
+        // Ensure the secret is not zeroized while it is alive.
+        let zeroized = Arc::new(AtomicBool::new(false));
+        let secret = Secret::new(Tracked(zeroized.clone()));
+        assert!(!zeroized.load(Ordering::SeqCst));
+
+        // Ensure the secret is zeroized when it is dropped.
+        drop(secret);
+        assert!(zeroized.load(Ordering::SeqCst));
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use crate::Secret;
 
 impl<N: Network> Signature<N> {
     /// Returns a signature `(challenge, response, compute_key)` for a given message and RNG, where:
@@ -25,9 +26,9 @@ impl<N: Network> Signature<N> {
         }
 
         // Sample a random nonce from the scalar field.
-        let nonce = Scalar::rand(rng);
+        let nonce = Secret::new(Scalar::rand(rng));
         // Compute `g_r` as `nonce * G`.
-        let g_r = N::g_scalar_multiply(&nonce);
+        let g_r = N::g_scalar_multiply(nonce.expose());
 
         // Derive the compute key from the private key.
         let compute_key = ComputeKey::try_from(private_key)?;
@@ -47,7 +48,8 @@ impl<N: Network> Signature<N> {
         // Compute the verifier challenge.
         let challenge = N::hash_to_scalar_psd8(&preimage)?;
         // Compute the prover response.
-        let response = nonce - (challenge * private_key.sk_sig());
+        // Note: The nonce is added by reference, so that it is not copied out of the secret.
+        let response = -(challenge * private_key.sk_sig()) + nonce.expose();
 
         // Output the signature.
         Ok(Self { challenge, response, compute_key })
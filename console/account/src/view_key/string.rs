@@ -33,6 +33,14 @@ impl<N: Network> FromStr for ViewKey<N> {
     }
 }
 
+impl<N: Network> fmt::Debug for ViewKey<N> {
+    /// Writes the account view key as redacted, to prevent it from leaking into logs.
+    /// Note: Use `Display` to explicitly write the account view key as a base58 string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ViewKey([REDACTED])")
+    }
+}
+
 impl<N: Network> fmt::Display for ViewKey<N> {
     /// Writes the account view key as a base58 string.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
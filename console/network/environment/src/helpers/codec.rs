@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, bail, ensure, Result};
+use bech32::{FromBase32, ToBase32};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+/// The alphabet of the (standard, padded) base64 encoding.
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The alternate string encodings of a byte-encoded type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    /// The lowercase hexadecimal encoding (decoding is case-insensitive).
+    Hex,
+    /// The standard base64 encoding, with padding.
+    Base64,
+    /// The bech32m encoding, with the given human-readable prefix.
+    Bech32m(&'static str),
+}
+
+impl Encoding {
+    /// Returns the encoding of the given bytes.
+    pub fn encode(&self, bytes: &[u8]) -> Result<String> {
+        match self {
+            Self::Hex => Ok(encode_hex(bytes)),
+            Self::Base64 => Ok(encode_base64(bytes)),
+            Self::Bech32m(hrp) => Ok(bech32::encode(hrp, bytes.to_base32(), bech32::Variant::Bech32m)?),
+        }
+    }
+
+    /// Returns the bytes of the given encoded string.
+    pub fn decode(&self, string: &str) -> Result<Vec<u8>> {
+        match self {
+            Self::Hex => decode_hex(string),
+            Self::Base64 => decode_base64(string),
+            Self::Bech32m(expected_hrp) => {
+                let (hrp, data, variant) = bech32::decode(string)?;
+                ensure!(hrp == *expected_hrp, "Failed to decode bech32m: '{hrp}' is an invalid prefix");
+                ensure!(variant == bech32::Variant::Bech32m, "Found a string that is not bech32m encoded: {string}");
+                Ok(Vec::from_base32(&data)?)
+            }
+        }
+    }
+}
+
+/// A trait for converting a byte-encoded type to and from its alternate string encodings.
+/// The encodings apply to the little-endian byte representation of the type.
+pub trait StringEncoding: Sized {
+    /// Returns the type in the given encoding.
+    fn to_encoding(&self, encoding: Encoding) -> Result<String>;
+
+    /// Returns the type from the given encoded string.
+    fn from_encoding(string: &str, encoding: Encoding) -> Result<Self>;
+
+    /// Returns the type as a hexadecimal string.
+    fn to_hex(&self) -> Result<String> {
+        self.to_encoding(Encoding::Hex)
+    }
+
+    /// Returns the type from a hexadecimal string.
+    fn from_hex(string: &str) -> Result<Self> {
+        Self::from_encoding(string, Encoding::Hex)
+    }
+
+    /// Returns the type as a base64 string.
+    fn to_base64(&self) -> Result<String> {
+        self.to_encoding(Encoding::Base64)
+    }
+
+    /// Returns the type from a base64 string.
+    fn from_base64(string: &str) -> Result<Self> {
+        Self::from_encoding(string, Encoding::Base64)
+    }
+}
+
+impl<T: ToBytes + FromBytes> StringEncoding for T {
+    /// Returns the type in the given encoding.
+    fn to_encoding(&self, encoding: Encoding) -> Result<String> {
+        encoding.encode(&self.to_bytes_le()?)
+    }
+
+    /// Returns the type from the given encoded string.
+    fn from_encoding(string: &str, encoding: Encoding) -> Result<Self> {
+        let bytes = encoding.decode(string)?;
+        // Ensure the decoded bytes are fully consumed.
+        let mut reader = &bytes[..];
+        let candidate = Self::read_le(&mut reader)?;
+        ensure!(reader.is_empty(), "Found {} trailing bytes in the encoded string", reader.len());
+        Ok(candidate)
+    }
+}
+
+/// Returns the lowercase hexadecimal encoding of the given bytes.
+pub fn encode_hex(bytes: &[u8]) -> String {
+    const HEX_ALPHABET: &[u8; 16] = b"0123456789abcdef";
+    bytes
+        .iter()
+        .flat_map(|byte| [HEX_ALPHABET[(byte >> 4) as usize] as char, HEX_ALPHABET[(byte & 0x0f) as usize] as char])
+        .collect()
+}
+
+/// Returns the bytes of the given hexadecimal string, with an optional `0x` prefix.
+pub fn decode_hex(string: &str) -> Result<Vec<u8>> {
+    // Remove the optional prefix.
+    let string = string.strip_prefix("0x").unwrap_or(string);
+    // Ensure the string has an even length.
+    ensure!(string.len() % 2 == 0, "Invalid hex string: found an odd length of {}", string.len());
+    // Returns the value of the given hex digit.
+    let to_nibble = |digit: u8| match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(anyhow!("Invalid hex string: found an invalid character '{}'", digit as char)),
+    };
+    string.as_bytes().chunks(2).map(|pair| Ok((to_nibble(pair[0])? << 4) | to_nibble(pair[1])?)).collect()
+}
+
+/// Returns the standard (padded) base64 encoding of the given bytes.
+pub fn encode_base64(bytes: &[u8]) -> String {
+    let mut string = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        // Pack the chunk into 24 bits.
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (i, byte)| bits | ((*byte as u32) << (16 - 8 * i)));
+        // Write the 6-bit groups covered by the chunk, and pad the remainder.
+        for i in 0..4 {
+            match i <= chunk.len() {
+                true => string.push(BASE64_ALPHABET[((bits >> (18 - 6 * i)) & 0x3f) as usize] as char),
+                false => string.push('='),
+            }
+        }
+    }
+    string
+}
+
+/// Returns the bytes of the given standard (padded) base64 string.
+pub fn decode_base64(string: &str) -> Result<Vec<u8>> {
+    // Ensure the string length is a multiple of 4.
+    ensure!(string.len() % 4 == 0, "Invalid base64 string: found a length of {}", string.len());
+    let mut bytes = Vec::with_capacity(string.len() / 4 * 3);
+    let num_chunks = string.len() / 4;
+    for (index, chunk) in string.as_bytes().chunks(4).enumerate() {
+        // Determine the number of padding characters, which may only appear at the end of the string.
+        let num_padding = chunk.iter().rev().take_while(|character| **character == b'=').count();
+        if num_padding > 2 || (num_padding > 0 && index + 1 != num_chunks) {
+            bail!("Invalid base64 string: found misplaced padding");
+        }
+        // Unpack the 6-bit groups into 24 bits.
+        let mut bits = 0u32;
+        for (i, character) in chunk[..4 - num_padding].iter().enumerate() {
+            let value = BASE64_ALPHABET
+                .iter()
+                .position(|candidate| candidate == character)
+                .ok_or_else(|| anyhow!("Invalid base64 string: found an invalid character '{}'", *character as char))?;
+            bits |= (value as u32) << (18 - 6 * i);
+        }
+        // Write the bytes covered by the chunk.
+        bytes.extend_from_slice(&bits.to_be_bytes()[1..4 - num_padding]);
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::TestRng;
+
+    use rand::Rng;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(encode_hex(&[]), "");
+        assert_eq!(encode_hex(&[0x00, 0xab, 0x1f]), "00ab1f");
+        assert_eq!(decode_hex("00AB1f").unwrap(), vec![0x00, 0xab, 0x1f]);
+        assert_eq!(decode_hex("0x00ab1f").unwrap(), vec![0x00, 0xab, 0x1f]);
+        assert!(decode_hex("0ab").is_err());
+        assert!(decode_hex("0g").is_err());
+    }
+
+    #[test]
+    fn test_base64() {
+        // Check the test vectors from RFC 4648.
+        for (bytes, string) in
+            [("", ""), ("f", "Zg=="), ("fo", "Zm8="), ("foo", "Zm9v"), ("foob", "Zm9vYg=="), ("fooba", "Zm9vYmE=")]
+        {
+            assert_eq!(encode_base64(bytes.as_bytes()), string);
+            assert_eq!(decode_base64(string).unwrap(), bytes.as_bytes());
+        }
+        assert!(decode_base64("Zm9").is_err());
+        assert!(decode_base64("Zg==Zm9v").is_err());
+        assert!(decode_base64("Z===").is_err());
+        assert!(decode_base64("Zm9*").is_err());
+    }
+
+    #[test]
+    fn test_encoding_round_trip() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let length = rng.gen_range(0..64);
+            let bytes = (0..length).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+            for encoding in [Encoding::Hex, Encoding::Base64, Encoding::Bech32m("test")] {
+                let string = encoding.encode(&bytes).unwrap();
+                assert_eq!(encoding.decode(&string).unwrap(), bytes);
+            }
+        }
+        // Ensure the bech32m prefix is checked.
+        let string = Encoding::Bech32m("test").encode(&[1, 2, 3]).unwrap();
+        assert!(Encoding::Bech32m("other").decode(&string).is_err());
+    }
+
+    #[test]
+    fn test_string_encoding() {
+        let value = 0x0123_4567_89ab_cdefu64;
+        assert_eq!(value.to_hex().unwrap(), "efcdab8967452301");
+        assert_eq!(u64::from_hex("efcdab8967452301").unwrap(), value);
+        assert_eq!(u64::from_base64(&value.to_base64().unwrap()).unwrap(), value);
+        // Ensure trailing bytes are rejected.
+        assert!(u64::from_hex("efcdab896745230100").is_err());
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod codec;
+pub use codec::{Encoding, StringEncoding};
+
 mod or_halt;
 pub use or_halt::OrHalt;
 
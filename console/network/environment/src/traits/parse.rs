@@ -13,7 +13,11 @@
 // limitations under the License.
 
 use nom::{
-    error::{convert_error, VerboseError},
+    character::complete::{char, one_of},
+    combinator::recognize,
+    error::{convert_error, VerboseError, VerboseErrorKind},
+    multi::{many0, many1},
+    sequence::terminated,
     Err as NomErr,
     IResult,
 };
@@ -32,6 +36,43 @@ pub fn convert_result<'a, O>(result: ParserResult<'a, O>, input: &'a str) -> Str
     }
 }
 
+/// Parses the decimal digits of a numeric literal, returning the digits without their separators.
+/// The digits may be grouped with `_` separators (e.g. `1_000_000`), which may not lead.
+///
+/// Note: Repeated separators (e.g. `1__000`) are still accepted, as they have always been accepted,
+/// so rejecting them would break the re-parsing of programs that are already deployed.
+/// The formatter never emits them.
+pub fn parse_digits(string: &str) -> ParserResult<String> {
+    // Parse the digits, each followed by any number of separators.
+    let (string, digits) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
+    Ok((string, digits.replace('_', "")))
+}
+
+/// Returns a parser failure for the literal at the start of the given string, with the given message.
+/// The failure is not recoverable, so the message is not discarded by an enclosing `alt`.
+pub fn literal_failure<O>(string: &str, message: &'static str) -> ParserResult<O> {
+    Err(NomErr::Failure(VerboseError { errors: vec![(string, VerboseErrorKind::Context(message))] }))
+}
+
+/// Returns the given decimal digits (with an optional leading `-`), grouped in threes with `_` separators.
+pub fn format_digits(digits: &str) -> String {
+    // Separate the sign from the digits.
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", digits),
+    };
+    // Insert a separator before every group of three digits, counting from the right.
+    let mut output = String::with_capacity(sign.len() + digits.len() + digits.len() / 3);
+    output.push_str(sign);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            output.push('_');
+        }
+        output.push(digit);
+    }
+    output
+}
+
 /// Operations to parse a string literal into an object.
 pub trait Parser: core::fmt::Display + core::str::FromStr {
     /// Parses a string literal into an object.
@@ -39,3 +80,37 @@ pub trait Parser: core::fmt::Display + core::str::FromStr {
     where
         Self: Sized;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_digits() {
+        assert_eq!(("u64", "1000000".to_string()), parse_digits("1_000_000u64").unwrap());
+        assert_eq!(("u64", "1000000".to_string()), parse_digits("1000000u64").unwrap());
+        assert_eq!(("u64", "10".to_string()), parse_digits("1_0_u64").unwrap());
+        // Ensure leading separators are rejected.
+        assert!(parse_digits("_1u64").is_err());
+        // Ensure repeated separators are still accepted, for the programs that are already deployed.
+        assert_eq!(("u64", "1000".to_string()), parse_digits("1__000u64").unwrap());
+        assert_eq!(("u64", "1000".to_string()), parse_digits("1_000__u64").unwrap());
+    }
+
+    #[test]
+    fn test_literal_failure() {
+        let result = literal_failure::<()>("300u8", "Integer literal is out of range for 'u8'");
+        assert!(matches!(result, Err(NomErr::Failure(_))));
+        assert!(convert_result(result, "300u8").contains("Integer literal is out of range for 'u8'"));
+    }
+
+    #[test]
+    fn test_format_digits() {
+        assert_eq!("0", format_digits("0"));
+        assert_eq!("100", format_digits("100"));
+        assert_eq!("1_000", format_digits("1000"));
+        assert_eq!("-1_000_000", format_digits("-1000000"));
+        assert_eq!("-100_000", format_digits("-100000"));
+        assert_eq!("12_345_678", format_digits("12345678"));
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The consensus version, which determines the consensus rules in effect at a given block height.
+/// The block height at which each version takes effect is set per network, in `Network::CONSENSUS_VERSION_HEIGHTS`,
+/// and a version that is not scheduled there does not take effect.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConsensusVersion {
+    /// The consensus rules at genesis.
+    V1 = 1,
+    /// The consensus rules that introduce:
+    /// - struct and record types with default values.
+    /// - struct imports.
+    /// - executions that bundle several top-level calls.
+    V2 = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MainnetV0, Network, TestnetV0};
+
+    /// Ensures the consensus versions start at genesis, and take effect in increasing order.
+    fn check_consensus_version_heights<N: Network>() {
+        let heights = N::CONSENSUS_VERSION_HEIGHTS;
+        assert_eq!(heights[0], (ConsensusVersion::V1, 0));
+        for window in heights.windows(2) {
+            assert!(window[0].0 < window[1].0);
+            assert!(window[0].1 < window[1].1);
+        }
+    }
+
+    #[test]
+    fn test_consensus_version_heights() {
+        check_consensus_version_heights::<MainnetV0>();
+        check_consensus_version_heights::<TestnetV0>();
+    }
+
+    #[cfg(not(feature = "test"))]
+    #[test]
+    fn test_consensus_version() {
+        // Ensure no consensus version beyond genesis is scheduled.
+        assert_eq!(MainnetV0::consensus_version(0), ConsensusVersion::V1);
+        assert_eq!(MainnetV0::consensus_version(u32::MAX), ConsensusVersion::V1);
+        assert_eq!(TestnetV0::consensus_version(u32::MAX), ConsensusVersion::V1);
+    }
+
+    #[cfg(feature = "test")]
+    #[test]
+    fn test_consensus_version() {
+        assert_eq!(MainnetV0::consensus_version(0), ConsensusVersion::V1);
+        assert_eq!(MainnetV0::consensus_version(9), ConsensusVersion::V1);
+        assert_eq!(MainnetV0::consensus_version(10), ConsensusVersion::V2);
+        assert_eq!(MainnetV0::consensus_version(u32::MAX), ConsensusVersion::V2);
+    }
+}
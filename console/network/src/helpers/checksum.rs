@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{prelude::*, MainnetV0, TestnetV0, VarunaVerifyingKey};
+
+/// Returns the pinned checksum of the verifying key with the given name, for the network.
+/// The name is either `inclusion`, or the name of a function in `credits.aleo`.
+pub fn pinned_verifying_key_checksum<N: Network>(name: &str) -> Result<&'static str> {
+    // Retrieve the pinned checksums for the network.
+    let checksums: &[(&str, &str)] = match N::ID {
+        MainnetV0::ID => &snarkvm_parameters::mainnet::VERIFIER_CHECKSUMS,
+        TestnetV0::ID => &snarkvm_parameters::testnet::VERIFIER_CHECKSUMS,
+        _ => bail!("Network '{}' does not pin the checksums of its verifying keys", N::NAME),
+    };
+    // Return the pinned checksum.
+    checksums
+        .iter()
+        .find(|(candidate, _)| *candidate == name)
+        .map(|(_, checksum)| *checksum)
+        .ok_or_else(|| anyhow!("The checksum for the '{name}' verifying key is not pinned for '{}'", N::NAME))
+}
+
+/// Returns the checksum of the given verifying key, in the format of the pinned checksums.
+pub fn verifying_key_checksum<N: Network>(verifying_key: &VarunaVerifyingKey<N>) -> Result<String> {
+    // Prepend the encoded version, as in the parameter files.
+    let mut bytes = vec![1u8];
+    verifying_key.write_le(&mut bytes)?;
+    Ok(snarkvm_parameters::checksum(&bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pinned_verifying_key_checksum() {
+        // Ensure the inclusion verifying key matches its pinned checksum, on each network.
+        let expected = pinned_verifying_key_checksum::<MainnetV0>("inclusion").unwrap();
+        assert_eq!(verifying_key_checksum::<MainnetV0>(MainnetV0::inclusion_verifying_key()).unwrap(), expected);
+        let expected = pinned_verifying_key_checksum::<TestnetV0>("inclusion").unwrap();
+        assert_eq!(verifying_key_checksum::<TestnetV0>(TestnetV0::inclusion_verifying_key()).unwrap(), expected);
+
+        // Ensure the checksums are pinned per network.
+        let mainnet = pinned_verifying_key_checksum::<MainnetV0>("transfer_public").unwrap();
+        let testnet = pinned_verifying_key_checksum::<TestnetV0>("transfer_public").unwrap();
+        assert_ne!(mainnet, testnet);
+
+        // Ensure an unknown verifying key is rejected.
+        assert!(pinned_verifying_key_checksum::<MainnetV0>("unknown").is_err());
+    }
+}
@@ -22,6 +22,9 @@ extern crate lazy_static;
 pub use snarkvm_console_network_environment as environment;
 pub use snarkvm_console_network_environment::*;
 
+mod consensus_version;
+pub use consensus_version::*;
+
 mod helpers;
 pub use helpers::*;
 
@@ -87,6 +90,16 @@ pub trait Network:
     /// The network edition.
     const EDITION: u16;
 
+    /// The block heights at which each consensus version takes effect, in increasing order, starting at genesis.
+    /// A consensus version that is not listed here is not yet scheduled, and does not take effect.
+    #[cfg(not(feature = "test"))]
+    const CONSENSUS_VERSION_HEIGHTS: &'static [(ConsensusVersion, u32)] = &[(ConsensusVersion::V1, 0)];
+    /// The block heights at which each consensus version takes effect, in increasing order, starting at genesis.
+    /// This is deliberately set to a low height for testing purposes only.
+    #[cfg(feature = "test")]
+    const CONSENSUS_VERSION_HEIGHTS: &'static [(ConsensusVersion, u32)] =
+        &[(ConsensusVersion::V1, 0), (ConsensusVersion::V2, 10)];
+
     /// The function name for the inclusion circuit.
     const INCLUSION_FUNCTION_NAME: &'static str;
 
@@ -197,6 +210,9 @@ pub trait Network:
     /// The maximum number of bytes in a transaction.
     // Note: This value must **not** be decreased as it would invalidate existing transactions.
     const MAX_TRANSACTION_SIZE: usize = 128_000; // 128 kB
+    /// The maximum number of bytes in an execution proof, per transition.
+    /// Note: A transition contributes at most `1 + MAX_INPUTS` instances, and at most 2 circuits, to the proof.
+    const MAX_PROOF_BYTES_PER_TRANSITION: usize = 8_192; // 8 kB
 
     /// The state root type.
     type StateRoot: Bech32ID<Field<Self>>;
@@ -209,6 +225,15 @@ pub trait Network:
     /// The transition ID type.
     type TransitionID: Bech32ID<Field<Self>>;
 
+    /// Returns the consensus version in effect at the given block height.
+    fn consensus_version(block_height: u32) -> ConsensusVersion {
+        Self::CONSENSUS_VERSION_HEIGHTS
+            .iter()
+            .rev()
+            .find(|(_, height)| *height <= block_height)
+            .map_or(ConsensusVersion::V1, |(version, _)| *version)
+    }
+
     /// Returns the genesis block bytes.
     fn genesis_bytes() -> &'static [u8];
 
@@ -236,6 +261,15 @@ pub trait Network:
     /// Returns the Varuna universal verifier.
     fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve>;
 
+    /// Overrides the Varuna universal verifier with one derived from a locally-generated universal SRS.
+    /// This is **insecure**, and must only be used for local development networks and testing.
+    /// This method fails if the Varuna universal verifier has already been initialized,
+    /// or if the network does not support an insecure Varuna universal verifier.
+    #[cfg(feature = "dev")]
+    fn set_insecure_varuna_universal_verifier(_verifier: UniversalVerifier<Self::PairingCurve>) -> Result<()> {
+        bail!("Network '{}' does not support an insecure Varuna universal verifier", Self::NAME)
+    }
+
     /// Returns the sponge parameters for Varuna.
     fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self>;
 
@@ -79,6 +79,9 @@ lazy_static! {
     };
 }
 
+/// The Varuna universal verifier, which may be overridden by a locally-generated universal SRS.
+static VARUNA_UNIVERSAL_VERIFIER: OnceCell<UniversalVerifier<<Console as Environment>::PairingCurve>> = OnceCell::new();
+
 pub const TRANSACTION_PREFIX: &str = "at";
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -217,8 +220,7 @@ impl Network for MainnetV0 {
 
     /// Returns the Varuna universal verifier.
     fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
-        static INSTANCE: OnceCell<UniversalVerifier<<Console as Environment>::PairingCurve>> = OnceCell::new();
-        INSTANCE.get_or_init(|| {
+        VARUNA_UNIVERSAL_VERIFIER.get_or_init(|| {
             snarkvm_algorithms::polycommit::kzg10::UniversalParams::load()
                 .expect("Failed to load universal SRS (KZG10).")
                 .to_universal_verifier()
@@ -226,6 +228,14 @@ impl Network for MainnetV0 {
         })
     }
 
+    /// Overrides the Varuna universal verifier with one derived from a locally-generated universal SRS.
+    #[cfg(feature = "dev")]
+    fn set_insecure_varuna_universal_verifier(verifier: UniversalVerifier<Self::PairingCurve>) -> Result<()> {
+        VARUNA_UNIVERSAL_VERIFIER
+            .set(verifier)
+            .map_err(|_| anyhow!("The Varuna universal verifier has already been initialized"))
+    }
+
     /// Returns the sponge parameters used for the sponge in the Varuna SNARK.
     fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self> {
         &VARUNA_FS_PARAMETERS
@@ -80,6 +80,9 @@ lazy_static! {
     };
 }
 
+/// The Varuna universal verifier, which may be overridden by a locally-generated universal SRS.
+static VARUNA_UNIVERSAL_VERIFIER: OnceCell<UniversalVerifier<<Console as Environment>::PairingCurve>> = OnceCell::new();
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TestnetV0;
 
@@ -214,7 +217,20 @@ impl Network for TestnetV0 {
 
     /// Returns the Varuna universal verifier.
     fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
-        MainnetV0::varuna_universal_verifier()
+        VARUNA_UNIVERSAL_VERIFIER.get_or_init(|| {
+            snarkvm_algorithms::polycommit::kzg10::UniversalParams::load()
+                .expect("Failed to load universal SRS (KZG10).")
+                .to_universal_verifier()
+                .expect("Failed to convert universal SRS (KZG10) to the verifier.")
+        })
+    }
+
+    /// Overrides the Varuna universal verifier with one derived from a locally-generated universal SRS.
+    #[cfg(feature = "dev")]
+    fn set_insecure_varuna_universal_verifier(verifier: UniversalVerifier<Self::PairingCurve>) -> Result<()> {
+        VARUNA_UNIVERSAL_VERIFIER
+            .set(verifier)
+            .map_err(|_| anyhow!("The Varuna universal verifier has already been initialized"))
     }
 
     /// Returns the sponge parameters used for the sponge in the Varuna SNARK.
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Applies the given operation to two integer literals of the same type.
+/// The operation receives the primitive values, and returns `None` if it overflows or divides by zero.
+macro_rules! integer_operation {
+    ($first:expr, $second:expr, $opcode:literal, |$a:ident, $b:ident| $operation:expr) => {{
+        let output = match ($first, $second) {
+            (Literal::I8($a), Literal::I8($b)) => integer_operation!(@apply I8, $a, $b, $operation),
+            (Literal::I16($a), Literal::I16($b)) => integer_operation!(@apply I16, $a, $b, $operation),
+            (Literal::I32($a), Literal::I32($b)) => integer_operation!(@apply I32, $a, $b, $operation),
+            (Literal::I64($a), Literal::I64($b)) => integer_operation!(@apply I64, $a, $b, $operation),
+            (Literal::I128($a), Literal::I128($b)) => integer_operation!(@apply I128, $a, $b, $operation),
+            (Literal::U8($a), Literal::U8($b)) => integer_operation!(@apply U8, $a, $b, $operation),
+            (Literal::U16($a), Literal::U16($b)) => integer_operation!(@apply U16, $a, $b, $operation),
+            (Literal::U32($a), Literal::U32($b)) => integer_operation!(@apply U32, $a, $b, $operation),
+            (Literal::U64($a), Literal::U64($b)) => integer_operation!(@apply U64, $a, $b, $operation),
+            (Literal::U128($a), Literal::U128($b)) => integer_operation!(@apply U128, $a, $b, $operation),
+            _ => bail!("'{}' is not defined for '{}' and '{}'", $opcode, $first.to_type(), $second.to_type()),
+        };
+        output.ok_or_else(|| anyhow!("'{}' overflowed or divided by zero on '{}' and '{}'", $opcode, $first, $second))
+    }};
+    (@apply $variant:ident, $a:ident, $b:ident, $operation:expr) => {{
+        let ($a, $b) = (**$a, **$b);
+        $operation.map(|output| Literal::$variant($variant::new(output)))
+    }};
+}
+
+impl<N: Network> Literal<N> {
+    /// Returns `self + other`, following the semantics of the `add` instruction.
+    pub fn checked_add(&self, other: &Self) -> Result<Self> {
+        match (self, other) {
+            (Self::Field(a), Self::Field(b)) => Ok(Self::Field(*a + *b)),
+            (Self::Group(a), Self::Group(b)) => Ok(Self::Group(*a + *b)),
+            (Self::Scalar(a), Self::Scalar(b)) => Ok(Self::Scalar(*a + *b)),
+            _ => integer_operation!(self, other, "add", |a, b| a.checked_add(b)),
+        }
+    }
+
+    /// Returns `self + other`, wrapping around at the boundary of the type,
+    /// following the semantics of the `add.w` instruction.
+    pub fn wrapping_add(&self, other: &Self) -> Result<Self> {
+        integer_operation!(self, other, "add.w", |a, b| Some(a.wrapping_add(b)))
+    }
+
+    /// Returns `self - other`, following the semantics of the `sub` instruction.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self> {
+        match (self, other) {
+            (Self::Field(a), Self::Field(b)) => Ok(Self::Field(*a - *b)),
+            (Self::Group(a), Self::Group(b)) => Ok(Self::Group(*a - *b)),
+            _ => integer_operation!(self, other, "sub", |a, b| a.checked_sub(b)),
+        }
+    }
+
+    /// Returns `self - other`, wrapping around at the boundary of the type,
+    /// following the semantics of the `sub.w` instruction.
+    pub fn wrapping_sub(&self, other: &Self) -> Result<Self> {
+        integer_operation!(self, other, "sub.w", |a, b| Some(a.wrapping_sub(b)))
+    }
+
+    /// Returns `self * other`, following the semantics of the `mul` instruction.
+    pub fn checked_mul(&self, other: &Self) -> Result<Self> {
+        match (self, other) {
+            (Self::Field(a), Self::Field(b)) => Ok(Self::Field(*a * *b)),
+            (Self::Group(a), Self::Scalar(b)) => Ok(Self::Group(*a * *b)),
+            (Self::Scalar(a), Self::Group(b)) => Ok(Self::Group(*b * *a)),
+            _ => integer_operation!(self, other, "mul", |a, b| a.checked_mul(b)),
+        }
+    }
+
+    /// Returns `self * other`, wrapping around at the boundary of the type,
+    /// following the semantics of the `mul.w` instruction.
+    pub fn wrapping_mul(&self, other: &Self) -> Result<Self> {
+        integer_operation!(self, other, "mul.w", |a, b| Some(a.wrapping_mul(b)))
+    }
+
+    /// Returns `self / other`, following the semantics of the `div` instruction.
+    pub fn checked_div(&self, other: &Self) -> Result<Self> {
+        match (self, other) {
+            (Self::Field(a), Self::Field(b)) => match b.is_zero() {
+                true => bail!("'div' divided by zero on '{self}' and '{other}'"),
+                false => Ok(Self::Field(*a / *b)),
+            },
+            _ => integer_operation!(self, other, "div", |a, b| a.checked_div(b)),
+        }
+    }
+
+    /// Returns `self / other`, wrapping around at the boundary of the type,
+    /// following the semantics of the `div.w` instruction.
+    pub fn wrapping_div(&self, other: &Self) -> Result<Self> {
+        integer_operation!(self, other, "div.w", |a, b| (b != 0).then(|| a.wrapping_div(b)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Parses the given literal.
+    fn literal(string: &str) -> Literal<CurrentNetwork> {
+        Literal::from_str(string).unwrap()
+    }
+
+    #[test]
+    fn test_integer_arithmetic() {
+        // Ensure the checked operations match the instruction semantics.
+        assert_eq!(literal("1u8").checked_add(&literal("2u8")).unwrap(), literal("3u8"));
+        assert!(literal("255u8").checked_add(&literal("1u8")).is_err());
+        assert!(literal("0u8").checked_sub(&literal("1u8")).is_err());
+        assert_eq!(literal("-3i16").checked_mul(&literal("4i16")).unwrap(), literal("-12i16"));
+        assert!(literal("-128i8").checked_div(&literal("-1i8")).is_err());
+        assert!(literal("1u64").checked_div(&literal("0u64")).is_err());
+
+        // Ensure the wrapping operations match the instruction semantics.
+        assert_eq!(literal("255u8").wrapping_add(&literal("1u8")).unwrap(), literal("0u8"));
+        assert_eq!(literal("0u8").wrapping_sub(&literal("1u8")).unwrap(), literal("255u8"));
+        assert_eq!(literal("-128i8").wrapping_mul(&literal("-1i8")).unwrap(), literal("-128i8"));
+        assert_eq!(literal("-128i8").wrapping_div(&literal("-1i8")).unwrap(), literal("-128i8"));
+        assert!(literal("1u64").wrapping_div(&literal("0u64")).is_err());
+
+        // Ensure mismatched types are rejected.
+        assert!(literal("1u8").checked_add(&literal("1u16")).is_err());
+        assert!(literal("1field").wrapping_add(&literal("1field")).is_err());
+        assert!(literal("true").checked_add(&literal("true")).is_err());
+    }
+
+    #[test]
+    fn test_field_group_scalar_arithmetic() {
+        let rng = &mut TestRng::default();
+
+        // Sample the operands.
+        let (a, b) = (Field::<CurrentNetwork>::rand(rng), Field::<CurrentNetwork>::rand(rng));
+        let (g, s) = (Group::<CurrentNetwork>::rand(rng), Scalar::<CurrentNetwork>::rand(rng));
+
+        // Ensure the field operations match.
+        assert_eq!(Literal::Field(a).checked_add(&Literal::Field(b)).unwrap(), Literal::Field(a + b));
+        assert_eq!(Literal::Field(a).checked_sub(&Literal::Field(b)).unwrap(), Literal::Field(a - b));
+        assert_eq!(Literal::Field(a).checked_mul(&Literal::Field(b)).unwrap(), Literal::Field(a * b));
+        assert_eq!(Literal::Field(a).checked_div(&Literal::Field(b)).unwrap(), Literal::Field(a / b));
+        assert!(Literal::Field(a).checked_div(&Literal::Field(Field::zero())).is_err());
+
+        // Ensure the group and scalar operations match.
+        assert_eq!(Literal::Group(g).checked_mul(&Literal::Scalar(s)).unwrap(), Literal::Group(g * s));
+        assert_eq!(Literal::Scalar(s).checked_mul(&Literal::Group(g)).unwrap(), Literal::Group(g * s));
+        assert_eq!(Literal::Scalar(s).checked_add(&Literal::Scalar(s)).unwrap(), Literal::Scalar(s + s));
+        assert!(Literal::Scalar(s).checked_sub(&Literal::Scalar(s)).is_err());
+    }
+}
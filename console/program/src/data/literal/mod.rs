@@ -15,6 +15,7 @@
 pub use cast::Cast;
 pub use cast_lossy::CastLossy;
 
+mod arithmetic;
 mod bytes;
 mod cast;
 mod cast_lossy;
@@ -14,6 +14,11 @@
 
 use super::*;
 
+impl<N: Network> RecordType<N> {
+    /// The flag in the number of entries, which indicates the record type has optional entries.
+    const HAS_DEFAULTS_FLAG: u16 = 1 << 15;
+}
+
 impl<N: Network> FromBytes for RecordType<N> {
     /// Reads a record type from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
@@ -23,7 +28,10 @@ impl<N: Network> FromBytes for RecordType<N> {
         let owner = PublicOrPrivate::read_le(&mut reader)?;
 
         // Read the number of entries.
+        // Note: The most significant bit is set if the record type has optional entries.
         let num_entries = u16::read_le(&mut reader)?;
+        let has_defaults = num_entries & Self::HAS_DEFAULTS_FLAG != 0;
+        let num_entries = num_entries & !Self::HAS_DEFAULTS_FLAG;
         // Ensure the number of entries is within the maximum limit.
         if num_entries as usize > N::MAX_DATA_ENTRIES {
             return Err(error(format!(
@@ -44,6 +52,29 @@ impl<N: Network> FromBytes for RecordType<N> {
             };
         }
 
+        // Read the default values.
+        let mut defaults = IndexMap::new();
+        if has_defaults {
+            // Read the number of default values.
+            let num_defaults = u16::read_le(&mut reader)?;
+            // Ensure the number of default values is within the number of entries.
+            if num_defaults == 0 || num_defaults > num_entries {
+                return Err(error(format!("Invalid number of default values in record '{name}'")));
+            }
+            for _ in 0..num_defaults {
+                // Read the identifier.
+                let identifier = Identifier::read_le(&mut reader)?;
+                // Read the default value.
+                let default = Literal::read_le(&mut reader)?;
+                // Insert the default value, and ensure the default values have no duplicate names.
+                if defaults.insert(identifier, default).is_some() {
+                    return Err(error(format!("Duplicate default value in record '{name}'")));
+                }
+            }
+        }
+        // Ensure the default values are well-formed.
+        Self::check_defaults(&entries, &defaults).map_err(|e| error(e.to_string()))?;
+
         // Prepare the reserved entry names.
         let reserved = [Identifier::from_str("owner").map_err(|e| error(e.to_string()))?];
         // Ensure the entries has no duplicate names.
@@ -55,7 +86,7 @@ impl<N: Network> FromBytes for RecordType<N> {
             return Err(error("Failed to parse record: too many entries"));
         }
 
-        Ok(Self { name, owner, entries })
+        Ok(Self { name, owner, entries, defaults })
     }
 }
 
@@ -73,7 +104,12 @@ impl<N: Network> ToBytes for RecordType<N> {
         self.owner.write_le(&mut writer)?;
 
         // Write the number of entries.
-        u16::try_from(self.entries.len()).or_halt_with::<N>("Record length exceeds u16").write_le(&mut writer)?;
+        // Note: The most significant bit is set if the record type has optional entries.
+        let num_entries = u16::try_from(self.entries.len()).or_halt_with::<N>("Record length exceeds u16");
+        match self.defaults.is_empty() {
+            true => num_entries.write_le(&mut writer)?,
+            false => (num_entries | Self::HAS_DEFAULTS_FLAG).write_le(&mut writer)?,
+        }
         // Write the entries as bytes.
         for (identifier, value_type) in &self.entries {
             // Write the identifier.
@@ -81,6 +117,18 @@ impl<N: Network> ToBytes for RecordType<N> {
             // Write the value type to the buffer.
             value_type.write_le(&mut writer)?;
         }
+
+        // Write the default values, if any.
+        if !self.defaults.is_empty() {
+            // Write the number of default values.
+            u16::try_from(self.defaults.len()).or_halt_with::<N>("Record length exceeds u16").write_le(&mut writer)?;
+            for (identifier, default) in &self.defaults {
+                // Write the identifier.
+                identifier.write_le(&mut writer)?;
+                // Write the default value.
+                default.write_le(&mut writer)?;
+            }
+        }
         Ok(())
     }
 }
@@ -99,6 +147,13 @@ mod tests {
         )?;
         let candidate = RecordType::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
         assert_eq!(expected, candidate);
+
+        // Ensure a record type with optional entries round-trips.
+        let expected = RecordType::<CurrentNetwork>::from_str(
+            "record message:\n    owner as address.public;\n    first as field.constant;\n    second as u8.private default 7u8;",
+        )?;
+        let candidate = RecordType::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
         Ok(())
     }
 }
@@ -16,13 +16,13 @@ mod entry_type;
 pub use entry_type::EntryType;
 
 mod helpers;
-use helpers::PublicOrPrivate;
+pub use helpers::PublicOrPrivate;
 
 mod bytes;
 mod parse;
 mod serialize;
 
-use crate::Identifier;
+use crate::{Identifier, Literal, PlaintextType};
 use snarkvm_console_network::prelude::*;
 
 use indexmap::IndexMap;
@@ -36,9 +36,38 @@ pub struct RecordType<N: Network> {
     owner: PublicOrPrivate,
     /// The name and value type for the entries in data.
     entries: IndexMap<Identifier<N>, EntryType<N>>,
+    /// The default values for the optional entries in data.
+    defaults: IndexMap<Identifier<N>, Literal<N>>,
 }
 
 impl<N: Network> RecordType<N> {
+    /// Initializes a new record type with the given name, owner visibility, and entries.
+    /// Each entry is given as `(name, entry type, optional default value)`.
+    pub fn new(
+        name: Identifier<N>,
+        owner: PublicOrPrivate,
+        entries: Vec<(Identifier<N>, EntryType<N>, Option<Literal<N>>)>,
+    ) -> Result<Self> {
+        // Prepare the reserved entry names.
+        let reserved = [Identifier::from_str("owner")?];
+        // Ensure the entries has no duplicate names.
+        ensure!(
+            !has_duplicates(entries.iter().map(|(identifier, _, _)| identifier).chain(reserved.iter())),
+            "Duplicate entry type found in record '{name}'"
+        );
+        // Ensure the number of members is within the maximum limit.
+        ensure!(entries.len() <= N::MAX_DATA_ENTRIES, "Record '{name}' has too many entries");
+        // Split the default values from the entries.
+        let defaults = IndexMap::<Identifier<N>, Literal<N>>::from_iter(
+            entries.iter().filter_map(|(identifier, _, default)| Some((*identifier, default.clone()?))),
+        );
+        let entries = IndexMap::from_iter(entries.into_iter().map(|(identifier, entry, _)| (identifier, entry)));
+        // Ensure the default values are well-formed.
+        Self::check_defaults(&entries, &defaults)?;
+        // Return the record type.
+        Ok(Self { name, owner, entries, defaults })
+    }
+
     /// Returns the name of the record type.
     pub const fn name(&self) -> &Identifier<N> {
         &self.name
@@ -53,6 +82,52 @@ impl<N: Network> RecordType<N> {
     pub const fn entries(&self) -> &IndexMap<Identifier<N>, EntryType<N>> {
         &self.entries
     }
+
+    /// Returns the default values for the optional entries of the record type.
+    pub const fn defaults(&self) -> &IndexMap<Identifier<N>, Literal<N>> {
+        &self.defaults
+    }
+
+    /// Returns the default value for the given entry, if the entry is optional.
+    pub fn default_for(&self, entry_name: &Identifier<N>) -> Option<&Literal<N>> {
+        self.defaults.get(entry_name)
+    }
+
+    /// Returns the number of required entries (i.e. entries without a default value) of the record type.
+    /// Note: Optional entries always follow the required entries.
+    pub fn num_required_entries(&self) -> usize {
+        self.entries.len().saturating_sub(self.defaults.len())
+    }
+}
+
+impl<N: Network> RecordType<N> {
+    /// Ensures the default values are well-formed for the given entries.
+    /// A default value must be a literal of the entry type, and the optional entries must follow the required entries.
+    fn check_defaults(
+        entries: &IndexMap<Identifier<N>, EntryType<N>>,
+        defaults: &IndexMap<Identifier<N>, Literal<N>>,
+    ) -> Result<()> {
+        // Ensure each default value corresponds to an entry of the same type.
+        for (entry_name, default) in defaults {
+            match entries.get(entry_name).map(|entry_type| entry_type.plaintext_type()) {
+                Some(PlaintextType::Literal(literal_type)) => ensure!(
+                    *literal_type == default.to_type(),
+                    "The default value of '{entry_name}' must be a '{literal_type}', found '{default}'"
+                ),
+                Some(plaintext_type) => {
+                    bail!("The entry '{entry_name}' of type '{plaintext_type}' cannot have a default")
+                }
+                None => bail!("The default value of '{entry_name}' does not correspond to an entry"),
+            }
+        }
+        // Ensure the optional entries follow the required entries.
+        let num_required_entries = entries.len().saturating_sub(defaults.len());
+        ensure!(
+            entries.keys().skip(num_required_entries).all(|entry_name| defaults.contains_key(entry_name)),
+            "The optional entries of a record must follow its required entries"
+        );
+        Ok(())
+    }
 }
 
 impl<N: Network> TypeName for RecordType<N> {
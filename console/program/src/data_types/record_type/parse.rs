@@ -20,11 +20,12 @@ impl<N: Network> Parser for RecordType<N> {
     ///   record message:
     ///       owner as address.private;
     ///       user_defined as u64.public;
+    ///       optional as u64.public default 0u64;
     /// ```
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         /// Parses a string into a tuple.
-        fn parse_entry<N: Network>(string: &str) -> ParserResult<(Identifier<N>, EntryType<N>)> {
+        fn parse_entry<N: Network>(string: &str) -> ParserResult<(Identifier<N>, EntryType<N>, Option<Literal<N>>)> {
             // Parse the whitespace and comments from the string.
             let (string, _) = Sanitizer::parse(string)?;
             // Parse the identifier from the string.
@@ -37,12 +38,20 @@ impl<N: Network> Parser for RecordType<N> {
             let (string, _) = Sanitizer::parse_whitespaces(string)?;
             // Parse the value type from the string.
             let (string, value_type) = EntryType::parse(string)?;
+            // Parse the optional default value from the string.
+            let (string, default) = opt(map(
+                pair(
+                    pair(Sanitizer::parse_whitespaces, tag("default")),
+                    pair(Sanitizer::parse_whitespaces, Literal::parse),
+                ),
+                |(_, (_, default))| default,
+            ))(string)?;
             // Parse the whitespace from the string.
             let (string, _) = Sanitizer::parse_whitespaces(string)?;
             // Parse the semicolon ';' keyword from the string.
             let (string, _) = tag(";")(string)?;
-            // Return the identifier and value type.
-            Ok((string, (identifier, value_type)))
+            // Return the identifier, value type, and default value.
+            Ok((string, (identifier, value_type, default)))
         }
 
         // Parse the whitespace and comments from the string.
@@ -83,18 +92,25 @@ impl<N: Network> Parser for RecordType<N> {
             // Prepare the reserved entry names.
             let reserved = [Identifier::from_str("owner").map_err(|e| error(e.to_string()))?];
             // Ensure the entries has no duplicate names.
-            if has_duplicates(entries.iter().map(|(identifier, _)| identifier).chain(reserved.iter())) {
+            if has_duplicates(entries.iter().map(|(identifier, _, _)| identifier).chain(reserved.iter())) {
                 return Err(error(format!("Duplicate entry type found in record '{name}'")));
             }
             // Ensure the number of members is within the maximum limit.
             if entries.len() > N::MAX_DATA_ENTRIES {
                 return Err(error("Failed to parse record: too many entries"));
             }
-            Ok(entries)
+            // Split the default values from the entries.
+            let defaults = IndexMap::<Identifier<N>, Literal<N>>::from_iter(
+                entries.iter().filter_map(|(identifier, _, default)| Some((*identifier, default.clone()?))),
+            );
+            let entries = IndexMap::from_iter(entries.into_iter().map(|(identifier, entry, _)| (identifier, entry)));
+            // Ensure the default values are well-formed.
+            Self::check_defaults(&entries, &defaults).map_err(|e| error(e.to_string()))?;
+            Ok((entries, defaults))
         })(string)?;
 
         // Return the record type.
-        Ok((string, Self { name, owner, entries: IndexMap::from_iter(entries) }))
+        Ok((string, Self { name, owner, entries: entries.0, defaults: entries.1 }))
     }
 }
 
@@ -127,7 +143,10 @@ impl<N: Network> Display for RecordType<N> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "{} {}:", Self::type_name(), self.name)?;
         write!(f, "\n    owner as address.{};", self.owner)?;
-        self.entries.iter().try_for_each(|(entry_name, entry_type)| write!(f, "\n    {entry_name} as {entry_type};"))
+        self.entries.iter().try_for_each(|(entry_name, entry_type)| match self.defaults.get(entry_name) {
+            Some(default) => write!(f, "\n    {entry_name} as {entry_type} default {default};"),
+            None => write!(f, "\n    {entry_name} as {entry_type};"),
+        })
     }
 }
 
@@ -147,6 +166,7 @@ mod tests {
                 Identifier::from_str("first")?,
                 EntryType::from_str("field.constant")?,
             )]),
+            defaults: IndexMap::new(),
         };
 
         let (remainder, candidate) = RecordType::<CurrentNetwork>::parse(
@@ -200,6 +220,28 @@ record message:
         assert_eq!(expected, format!("{message}"));
     }
 
+    #[test]
+    fn test_parse_defaults() -> Result<()> {
+        let expected = "record message:\n    owner as address.private;\n    first as field.private;\n    second as u8.public default 1u8;\n    third as boolean.private default false;";
+        let message = RecordType::<CurrentNetwork>::from_str(expected)?;
+        assert_eq!(expected, format!("{message}"));
+        assert_eq!(message.num_required_entries(), 1);
+        assert_eq!(message.default_for(&Identifier::from_str("first")?), None);
+        assert_eq!(message.default_for(&Identifier::from_str("second")?), Some(&Literal::from_str("1u8")?));
+
+        // The default value must match the entry type.
+        let candidate = RecordType::<CurrentNetwork>::from_str(
+            "record message:\n    owner as address.private;\n    first as u8.private default 1u16;",
+        );
+        assert!(candidate.is_err());
+        // The optional entries must follow the required entries.
+        let candidate = RecordType::<CurrentNetwork>::from_str(
+            "record message:\n    owner as address.private;\n    first as u8.private default 1u8;\n    second as field.private;",
+        );
+        assert!(candidate.is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_display_fails() {
         // Duplicate identifier.
@@ -14,6 +14,11 @@
 
 use super::*;
 
+impl<N: Network> StructType<N> {
+    /// The flag in the number of members, which indicates the struct type has optional members.
+    const HAS_DEFAULTS_FLAG: u16 = 1 << 15;
+}
+
 impl<N: Network> FromBytes for StructType<N> {
     /// Reads a struct type from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
@@ -21,7 +26,10 @@ impl<N: Network> FromBytes for StructType<N> {
         let name = Identifier::read_le(&mut reader)?;
 
         // Read the number of members.
+        // Note: The most significant bit is set if the struct type has optional members.
         let num_members = u16::read_le(&mut reader)?;
+        let has_defaults = num_members & Self::HAS_DEFAULTS_FLAG != 0;
+        let num_members = num_members & !Self::HAS_DEFAULTS_FLAG;
         // Ensure the number of members is within the maximum limit.
         if num_members as usize > N::MAX_STRUCT_ENTRIES {
             return Err(error(format!(
@@ -42,7 +50,30 @@ impl<N: Network> FromBytes for StructType<N> {
             };
         }
 
-        Ok(Self { name, members })
+        // Read the default values.
+        let mut defaults = IndexMap::new();
+        if has_defaults {
+            // Read the number of default values.
+            let num_defaults = u16::read_le(&mut reader)?;
+            // Ensure the number of default values is within the number of members.
+            if num_defaults == 0 || num_defaults > num_members {
+                return Err(error(format!("Invalid number of default values in struct '{name}'")));
+            }
+            for _ in 0..num_defaults {
+                // Read the identifier.
+                let identifier = Identifier::read_le(&mut reader)?;
+                // Read the default value.
+                let default = Literal::read_le(&mut reader)?;
+                // Insert the default value, and ensure the default values have no duplicate names.
+                if defaults.insert(identifier, default).is_some() {
+                    return Err(error(format!("Duplicate default value in struct '{name}'")));
+                }
+            }
+        }
+        // Ensure the default values are well-formed.
+        Self::check_defaults(&members, &defaults).map_err(|e| error(e.to_string()))?;
+
+        Ok(Self { name, members, defaults })
     }
 }
 
@@ -58,7 +89,12 @@ impl<N: Network> ToBytes for StructType<N> {
         self.name.write_le(&mut writer)?;
 
         // Write the number of members.
-        u16::try_from(self.members.len()).or_halt_with::<N>("Struct length exceeds u16").write_le(&mut writer)?;
+        // Note: The most significant bit is set if the struct type has optional members.
+        let num_members = u16::try_from(self.members.len()).or_halt_with::<N>("Struct length exceeds u16");
+        match self.defaults.is_empty() {
+            true => num_members.write_le(&mut writer)?,
+            false => (num_members | Self::HAS_DEFAULTS_FLAG).write_le(&mut writer)?,
+        }
         // Write the members as bytes.
         for (identifier, plaintext_type) in &self.members {
             // Write the identifier.
@@ -66,6 +102,18 @@ impl<N: Network> ToBytes for StructType<N> {
             // Write the plaintext type to the buffer.
             plaintext_type.write_le(&mut writer)?;
         }
+
+        // Write the default values, if any.
+        if !self.defaults.is_empty() {
+            // Write the number of default values.
+            u16::try_from(self.defaults.len()).or_halt_with::<N>("Struct length exceeds u16").write_le(&mut writer)?;
+            for (identifier, default) in &self.defaults {
+                // Write the identifier.
+                identifier.write_le(&mut writer)?;
+                // Write the default value.
+                default.write_le(&mut writer)?;
+            }
+        }
         Ok(())
     }
 }
@@ -83,6 +131,13 @@ mod tests {
             StructType::<CurrentNetwork>::from_str("struct message:\n    first as field;\n    second as field;")?;
         let candidate = StructType::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
         assert_eq!(expected, candidate);
+
+        // Ensure a struct type with optional members round-trips.
+        let expected = StructType::<CurrentNetwork>::from_str(
+            "struct message:\n    first as field;\n    second as u8 default 1u8;",
+        )?;
+        let candidate = StructType::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
         Ok(())
     }
 }
@@ -16,7 +16,7 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::{Identifier, PlaintextType};
+use crate::{Identifier, Literal, PlaintextType};
 use snarkvm_console_network::prelude::*;
 
 use indexmap::IndexMap;
@@ -27,9 +27,44 @@ pub struct StructType<N: Network> {
     name: Identifier<N>,
     /// The name and type for the members of the struct.
     members: IndexMap<Identifier<N>, PlaintextType<N>>,
+    /// The default values for the optional members of the struct.
+    defaults: IndexMap<Identifier<N>, Literal<N>>,
 }
 
 impl<N: Network> StructType<N> {
+    /// Initializes a new struct type with the given name and members.
+    pub fn new(name: Identifier<N>, members: Vec<(Identifier<N>, PlaintextType<N>)>) -> Result<Self> {
+        // Initialize the struct type without default values.
+        let members = members.into_iter().map(|(identifier, member)| (identifier, member, None)).collect();
+        Self::new_with_defaults(name, members)
+    }
+
+    /// Initializes a new struct type with the given name and members.
+    /// Each member is given as `(name, plaintext type, optional default value)`.
+    pub fn new_with_defaults(
+        name: Identifier<N>,
+        members: Vec<(Identifier<N>, PlaintextType<N>, Option<Literal<N>>)>,
+    ) -> Result<Self> {
+        // Ensure the struct has at least one member.
+        ensure!(!members.is_empty(), "Struct '{name}' must have at least one member");
+        // Ensure the members has no duplicate names.
+        ensure!(
+            !has_duplicates(members.iter().map(|(identifier, _, _)| identifier)),
+            "Duplicate identifier found in struct '{name}'"
+        );
+        // Ensure the number of members is within the maximum limit.
+        ensure!(members.len() <= N::MAX_STRUCT_ENTRIES, "Struct '{name}' has too many members");
+        // Split the default values from the members.
+        let defaults = IndexMap::<Identifier<N>, Literal<N>>::from_iter(
+            members.iter().filter_map(|(identifier, _, default)| Some((*identifier, default.clone()?))),
+        );
+        let members = IndexMap::from_iter(members.into_iter().map(|(identifier, member, _)| (identifier, member)));
+        // Ensure the default values are well-formed.
+        Self::check_defaults(&members, &defaults)?;
+        // Return the struct type.
+        Ok(Self { name, members, defaults })
+    }
+
     /// Returns the name of the struct type.
     #[inline]
     pub const fn name(&self) -> &Identifier<N> {
@@ -41,6 +76,48 @@ impl<N: Network> StructType<N> {
     pub const fn members(&self) -> &IndexMap<Identifier<N>, PlaintextType<N>> {
         &self.members
     }
+
+    /// Returns the default values for the optional members of the struct type.
+    #[inline]
+    pub const fn defaults(&self) -> &IndexMap<Identifier<N>, Literal<N>> {
+        &self.defaults
+    }
+
+    /// Returns the default value for the given member, if the member is optional.
+    #[inline]
+    pub fn default_for(&self, member_name: &Identifier<N>) -> Option<&Literal<N>> {
+        self.defaults.get(member_name)
+    }
+}
+
+impl<N: Network> StructType<N> {
+    /// Ensures the default values are well-formed for the given members.
+    /// A default value must be a literal of the member type, and the optional members must follow the required members.
+    fn check_defaults(
+        members: &IndexMap<Identifier<N>, PlaintextType<N>>,
+        defaults: &IndexMap<Identifier<N>, Literal<N>>,
+    ) -> Result<()> {
+        // Ensure each default value corresponds to a member of the same type.
+        for (member_name, default) in defaults {
+            match members.get(member_name) {
+                Some(PlaintextType::Literal(literal_type)) => ensure!(
+                    *literal_type == default.to_type(),
+                    "The default value of '{member_name}' must be a '{literal_type}', found '{default}'"
+                ),
+                Some(plaintext_type) => {
+                    bail!("The member '{member_name}' of type '{plaintext_type}' cannot have a default")
+                }
+                None => bail!("The default value of '{member_name}' does not correspond to a member"),
+            }
+        }
+        // Ensure the optional members follow the required members.
+        let num_required_members = members.len().saturating_sub(defaults.len());
+        ensure!(
+            members.keys().skip(num_required_members).all(|member_name| defaults.contains_key(member_name)),
+            "The optional members of a struct must follow its required members"
+        );
+        Ok(())
+    }
 }
 
 impl<N: Network> TypeName for StructType<N> {
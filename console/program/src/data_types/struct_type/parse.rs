@@ -20,11 +20,14 @@ impl<N: Network> Parser for StructType<N> {
     ///   struct message:
     ///       owner as address;
     ///       amount as u64;
+    ///       nonce as u64 default 0u64;
     /// ```
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         /// Parses a string into a tuple.
-        fn parse_tuple<N: Network>(string: &str) -> ParserResult<(Identifier<N>, PlaintextType<N>)> {
+        fn parse_tuple<N: Network>(
+            string: &str,
+        ) -> ParserResult<(Identifier<N>, PlaintextType<N>, Option<Literal<N>>)> {
             // Parse the whitespace and comments from the string.
             let (string, _) = Sanitizer::parse(string)?;
             // Parse the identifier from the string.
@@ -37,12 +40,20 @@ impl<N: Network> Parser for StructType<N> {
             let (string, _) = Sanitizer::parse_whitespaces(string)?;
             // Parse the plaintext type from the string.
             let (string, plaintext_type) = PlaintextType::parse(string)?;
+            // Parse the optional default value from the string.
+            let (string, default) = opt(map(
+                pair(
+                    pair(Sanitizer::parse_whitespaces, tag("default")),
+                    pair(Sanitizer::parse_whitespaces, Literal::parse),
+                ),
+                |(_, (_, default))| default,
+            ))(string)?;
             // Parse the whitespace from the string.
             let (string, _) = Sanitizer::parse_whitespaces(string)?;
             // Parse the semicolon ';' keyword from the string.
             let (string, _) = tag(";")(string)?;
-            // Return the identifier and plaintext type.
-            Ok((string, (identifier, plaintext_type)))
+            // Return the identifier, plaintext type, and default value.
+            Ok((string, (identifier, plaintext_type, default)))
         }
 
         // Parse the whitespace and comments from the string.
@@ -60,17 +71,24 @@ impl<N: Network> Parser for StructType<N> {
         // Parse the members from the string.
         let (string, members) = map_res(many1(parse_tuple), |members| {
             // Ensure the members has no duplicate names.
-            if has_duplicates(members.iter().map(|(identifier, _)| identifier)) {
+            if has_duplicates(members.iter().map(|(identifier, _, _)| identifier)) {
                 return Err(error(format!("Duplicate identifier found in struct '{name}'")));
             }
             // Ensure the number of members is within the maximum limit.
             if members.len() > N::MAX_STRUCT_ENTRIES {
                 return Err(error("Failed to parse struct: too many members"));
             }
-            Ok(members)
+            // Split the default values from the members.
+            let defaults = IndexMap::<Identifier<N>, Literal<N>>::from_iter(
+                members.iter().filter_map(|(identifier, _, default)| Some((*identifier, default.clone()?))),
+            );
+            let members = IndexMap::from_iter(members.into_iter().map(|(identifier, member, _)| (identifier, member)));
+            // Ensure the default values are well-formed.
+            Self::check_defaults(&members, &defaults).map_err(|e| error(e.to_string()))?;
+            Ok((members, defaults))
         })(string)?;
         // Return the struct.
-        Ok((string, Self { name, members: IndexMap::from_iter(members) }))
+        Ok((string, Self { name, members: members.0, defaults: members.1 }))
     }
 }
 
@@ -104,7 +122,10 @@ impl<N: Network> Display for StructType<N> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let mut output = format!("{} {}:\n", Self::type_name(), self.name);
         for (identifier, plaintext_type) in &self.members {
-            output += &format!("    {identifier} as {plaintext_type};\n");
+            match self.defaults.get(identifier) {
+                Some(default) => output += &format!("    {identifier} as {plaintext_type} default {default};\n"),
+                None => output += &format!("    {identifier} as {plaintext_type};\n"),
+            }
         }
         output.pop(); // trailing newline
         write!(f, "{output}")
@@ -126,6 +147,7 @@ mod tests {
                 (Identifier::from_str("sender")?, PlaintextType::from_str("address")?),
                 (Identifier::from_str("amount")?, PlaintextType::from_str("u64")?),
             ]),
+            defaults: IndexMap::new(),
         };
 
         let (remainder, candidate) = StructType::<CurrentNetwork>::parse(
@@ -179,6 +201,28 @@ struct message:
         assert_eq!(expected, format!("{message}"));
     }
 
+    #[test]
+    fn test_parse_defaults() -> Result<()> {
+        let expected = "struct message:\n    first as field;\n    second as u8 default 1u8;\n    third as boolean default false;";
+        let message = StructType::<CurrentNetwork>::from_str(expected)?;
+        assert_eq!(expected, format!("{message}"));
+        assert_eq!(message.default_for(&Identifier::from_str("first")?), None);
+        assert_eq!(message.default_for(&Identifier::from_str("second")?), Some(&Literal::from_str("1u8")?));
+
+        // The default value must match the member type.
+        let candidate = StructType::<CurrentNetwork>::from_str("struct message:\n    first as u8 default 1u16;");
+        assert!(candidate.is_err());
+        // The default value must be a literal.
+        let candidate = StructType::<CurrentNetwork>::from_str("struct message:\n    first as [u8; 2u32] default 1u8;");
+        assert!(candidate.is_err());
+        // The optional members must follow the required members.
+        let candidate = StructType::<CurrentNetwork>::from_str(
+            "struct message:\n    first as u8 default 1u8;\n    second as field;",
+        );
+        assert!(candidate.is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_display_fails() {
         // Duplicate identifier.
@@ -50,3 +50,5 @@ pub use response::*;
 
 pub mod state_path;
 pub use state_path::*;
+
+pub mod test_vectors;
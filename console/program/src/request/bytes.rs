@@ -27,7 +27,11 @@ impl<N: Network> FromBytes for Request<N> {
         // Read the signer.
         let signer = FromBytes::read_le(&mut reader)?;
         // Read the network ID.
-        let network_id = FromBytes::read_le(&mut reader)?;
+        let network_id: U16<N> = FromBytes::read_le(&mut reader)?;
+        // Ensure the network ID is correct, to reject a request from another network.
+        if *network_id != N::ID {
+            return Err(error(format!("Invalid network ID. Expected {}, found {}", N::ID, *network_id)));
+        }
         // Read the program ID.
         let program_id = FromBytes::read_le(&mut reader)?;
         // Read the function name.
@@ -116,6 +120,9 @@ impl<N: Network> ToBytes for Request<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
 
     #[test]
     fn test_bytes() {
@@ -125,6 +132,13 @@ mod tests {
             // Check the byte representation.
             let expected_bytes = expected.to_bytes_le().unwrap();
             assert_eq!(expected, Request::read_le(&expected_bytes[..]).unwrap());
+
+            // Ensure a request from another network is rejected.
+            // Note: The network ID is written after the version and the signer.
+            let mut candidate_bytes = expected_bytes.clone();
+            let offset = 1 + expected.signer().to_bytes_le().unwrap().len();
+            candidate_bytes[offset..offset + 2].copy_from_slice(&(CurrentNetwork::ID + 1).to_le_bytes());
+            assert!(Request::<CurrentNetwork>::read_le(&candidate_bytes[..]).is_err());
         }
     }
 }
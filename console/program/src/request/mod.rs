@@ -18,11 +18,13 @@ pub use input_id::InputID;
 mod bytes;
 mod serialize;
 mod sign;
+pub use sign::NonceDerivation;
+
 mod string;
 mod verify;
 
 use crate::{compute_function_id, Identifier, Plaintext, ProgramID, Record, Value, ValueType};
-use snarkvm_console_account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey};
+use snarkvm_console_account::{Address, ComputeKey, GraphKey, PrivateKey, Secret, Signature, ViewKey};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -47,12 +47,22 @@ impl<'de, N: Network> Deserialize<'de> for Request<N> {
             true => {
                 // Parse the request from a string into a value.
                 let mut request = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the signer.
+                let signer = DeserializeExt::take_from_value::<D>(&mut request, "signer")?;
+                // Retrieve the network ID.
+                let network_id: U16<N> = DeserializeExt::take_from_value::<D>(&mut request, "network")?;
+                // Ensure the network ID is correct, to reject a request from another network.
+                if *network_id != N::ID {
+                    return Err(de::Error::custom(format!(
+                        "Invalid network ID. Expected {}, found {}",
+                        N::ID,
+                        *network_id
+                    )));
+                }
                 // Recover the request.
                 Ok(Self::from((
-                    // Retrieve the signer.
-                    DeserializeExt::take_from_value::<D>(&mut request, "signer")?,
-                    // Retrieve the network ID.
-                    DeserializeExt::take_from_value::<D>(&mut request, "network")?,
+                    signer,
+                    network_id,
                     // Retrieve the program ID.
                     DeserializeExt::take_from_value::<D>(&mut request, "program")?,
                     // Retrieve the function name.
@@ -14,6 +14,18 @@
 
 use super::*;
 
+/// The scheme used to derive the nonce of a request signature.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NonceDerivation {
+    /// The nonce is derived from the signing key, the request, and fresh randomness, in the style of RFC 6979 (section 3.6).
+    /// The nonce remains secret and unique per request even if the RNG is weak, and distinct RNG outputs
+    /// ensure identical requests do not reuse a nonce.
+    #[default]
+    Deterministic,
+    /// The nonce is sampled from the RNG alone.
+    Random,
+}
+
 impl<N: Network> Request<N> {
     /// Returns the request for a given private key, program ID, function name, inputs, input types, and RNG, where:
     ///     challenge := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
@@ -27,6 +39,32 @@ impl<N: Network> Request<N> {
         root_tvk: Option<Field<N>>,
         is_root: bool,
         rng: &mut R,
+    ) -> Result<Self> {
+        Self::sign_with_nonce_derivation(
+            private_key,
+            program_id,
+            function_name,
+            inputs,
+            input_types,
+            root_tvk,
+            is_root,
+            NonceDerivation::default(),
+            rng,
+        )
+    }
+
+    /// Returns the request for a given private key, program ID, function name, inputs, input types, and RNG,
+    /// using the given scheme to derive the signature nonce.
+    pub fn sign_with_nonce_derivation<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        root_tvk: Option<Field<N>>,
+        is_root: bool,
+        nonce_derivation: NonceDerivation,
+        rng: &mut R,
     ) -> Result<Self> {
         // Ensure the number of inputs matches the number of input types.
         if input_types.len() != inputs.len() {
@@ -38,7 +76,7 @@ impl<N: Network> Request<N> {
         }
 
         // Retrieve `sk_sig`.
-        let sk_sig = private_key.sk_sig();
+        let sk_sig = Secret::new(private_key.sk_sig());
 
         // Derive the compute key.
         let compute_key = ComputeKey::try_from(private_key)?;
@@ -52,17 +90,51 @@ impl<N: Network> Request<N> {
         // Derive `sk_tag` from the graph key.
         let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
 
-        // Sample a random nonce.
-        let nonce = Field::<N>::rand(rng);
+        // Retrieve the network ID.
+        let network_id = U16::new(N::ID);
+        // Compute the function ID.
+        let function_id = compute_function_id(&network_id, &program_id, &function_name)?;
+
+        // Prepare the inputs.
+        let prepared_inputs = inputs
+            .zip_eq(input_types)
+            .enumerate()
+            .map(|(index, (input, input_type))| {
+                input.try_into().map_err(|_| {
+                    anyhow!("Failed to parse input #{index} ('{input_type}') for '{program_id}/{function_name}'")
+                })
+            })
+            .collect::<Result<Vec<Value<N>>>>()?;
+
+        // Compute the nonce.
+        let nonce = match nonce_derivation {
+            NonceDerivation::Deterministic => {
+                // Construct the preimage as `(domain || sk_sig || function ID || root_tvk || is_root || inputs || entropy)`.
+                let mut preimage = vec![Field::new_domain_separator("AleoRequestNonce0"), sk_sig.expose().to_field()?];
+                preimage.push(function_id);
+                preimage.push(root_tvk.unwrap_or_else(Field::zero));
+                preimage.push(if is_root { Field::one() } else { Field::zero() });
+                for input in &prepared_inputs {
+                    preimage.extend(input.to_fields()?);
+                }
+                preimage.push(Field::<N>::rand(rng));
+                // Hash the preimage to the nonce.
+                Secret::new(N::hash_psd8(&preimage)?)
+            }
+            // Sample a random nonce.
+            NonceDerivation::Random => Secret::new(Field::<N>::rand(rng)),
+        };
         // Compute a `r` as `HashToScalar(sk_sig || nonce)`. Note: This is the transition secret key `tsk`.
-        let r = N::hash_to_scalar_psd4(&[N::serial_number_domain(), sk_sig.to_field()?, nonce])?;
+        // Note: The preimage is wrapped, so that its copies of `sk_sig` and the nonce are zeroized once it is dropped.
+        let preimage = Secret::new([N::serial_number_domain(), sk_sig.expose().to_field()?, *nonce.expose()]);
+        let r = Secret::new(N::hash_to_scalar_psd4(preimage.expose())?);
         // Compute `g_r` as `r * G`. Note: This is the transition public key `tpk`.
-        let g_r = N::g_scalar_multiply(&r);
+        let g_r = N::g_scalar_multiply(r.expose());
 
         // Derive the signer from the compute key.
         let signer = Address::try_from(compute_key)?;
         // Compute the transition view key `tvk` as `r * signer`.
-        let tvk = (*signer * r).to_x_coordinate();
+        let tvk = (*signer * r.expose()).to_x_coordinate();
         // Compute the transition commitment `tcm` as `Hash(tvk)`.
         let tcm = N::hash_psd2(&[tvk])?;
         // Compute the signer commitment `scm` as `Hash(signer || root_tvk)`.
@@ -71,30 +143,16 @@ impl<N: Network> Request<N> {
         // Compute 'is_root' as a field element.
         let is_root = if is_root { Field::<N>::one() } else { Field::<N>::zero() };
 
-        // Retrieve the network ID.
-        let network_id = U16::new(N::ID);
-        // Compute the function ID.
-        let function_id = compute_function_id(&network_id, &program_id, &function_name)?;
-
         // Construct the hash input as `(r * G, pk_sig, pr_sig, signer, [tvk, tcm, function ID, input IDs])`.
-        let mut message = Vec::with_capacity(9 + 2 * inputs.len());
+        let mut message = Vec::with_capacity(9 + 2 * prepared_inputs.len());
         message.extend([g_r, pk_sig, pr_sig, *signer].map(|point| point.to_x_coordinate()));
         message.extend([tvk, tcm, function_id, is_root]);
 
-        // Initialize a vector to store the prepared inputs.
-        let mut prepared_inputs = Vec::with_capacity(inputs.len());
         // Initialize a vector to store the input IDs.
-        let mut input_ids = Vec::with_capacity(inputs.len());
-
-        // Prepare the inputs.
-        for (index, (input, input_type)) in inputs.zip_eq(input_types).enumerate() {
-            // Prepare the input.
-            let input = input.try_into().map_err(|_| {
-                anyhow!("Failed to parse input #{index} ('{input_type}') for '{program_id}/{function_name}'")
-            })?;
-            // Store the prepared input.
-            prepared_inputs.push(input.clone());
+        let mut input_ids = Vec::with_capacity(prepared_inputs.len());
 
+        // Compute the input IDs.
+        for (index, (input, input_type)) in prepared_inputs.iter().zip_eq(input_types).enumerate() {
             match input_type {
                 // A constant input is hashed (using `tcm`) to a field element.
                 ValueType::Constant(..) => {
@@ -148,7 +206,7 @@ impl<N: Network> Request<N> {
                     // Compute the input view key as `Hash(function ID || tvk || index)`.
                     let input_view_key = N::hash_psd4(&[function_id, tvk, index])?;
                     // Compute the ciphertext.
-                    let ciphertext = match &input {
+                    let ciphertext = match input {
                         Value::Plaintext(plaintext) => plaintext.encrypt_symmetric(input_view_key)?,
                         // Ensure the input is a plaintext.
                         Value::Record(..) => bail!("Expected a plaintext input, found a record input"),
@@ -165,7 +223,7 @@ impl<N: Network> Request<N> {
                 // A record input is computed to its serial number.
                 ValueType::Record(record_name) => {
                     // Retrieve the record.
-                    let record = match &input {
+                    let record = match input {
                         Value::Record(record) => record,
                         // Ensure the input is a record.
                         Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
@@ -180,9 +238,9 @@ impl<N: Network> Request<N> {
                     // Compute the generator `H` as `HashToGroup(commitment)`.
                     let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
                     // Compute `h_r` as `r * H`.
-                    let h_r = h * r;
+                    let h_r = h * r.expose();
                     // Compute `gamma` as `sk_sig * H`.
-                    let gamma = h * sk_sig;
+                    let gamma = h * sk_sig.expose();
 
                     // Compute the `serial_number` from `gamma`.
                     let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
@@ -225,7 +283,7 @@ impl<N: Network> Request<N> {
         // Compute `challenge` as `HashToScalar(r * G, pk_sig, pr_sig, signer, [tvk, tcm, function ID, input IDs])`.
         let challenge = N::hash_to_scalar_psd8(&message)?;
         // Compute `response` as `r - challenge * sk_sig`.
-        let response = r - challenge * sk_sig;
+        let response = *r.expose() - challenge * *sk_sig.expose();
 
         Ok(Self {
             signer,
@@ -242,3 +300,68 @@ impl<N: Network> Request<N> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Signs a request for `token.aleo/transfer` on the given public input.
+    fn sign_transfer(
+        private_key: &PrivateKey<CurrentNetwork>,
+        amount: &str,
+        nonce_derivation: NonceDerivation,
+        rng: &mut TestRng,
+    ) -> Request<CurrentNetwork> {
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+        let inputs = [Value::<CurrentNetwork>::from_str(amount).unwrap()];
+        let input_types = [ValueType::from_str("u64.public").unwrap()];
+        let request = Request::sign_with_nonce_derivation(
+            private_key,
+            program_id,
+            function_name,
+            inputs.into_iter(),
+            &input_types,
+            None,
+            true,
+            nonce_derivation,
+            rng,
+        )
+        .unwrap();
+        assert!(request.verify(&input_types, true));
+        request
+    }
+
+    #[test]
+    fn test_sign_identical_requests_do_not_reuse_nonce() {
+        let rng = &mut TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Ensure identical requests are signed with distinct nonces.
+        let request_0 = sign_transfer(&private_key, "100u64", NonceDerivation::Deterministic, rng);
+        let request_1 = sign_transfer(&private_key, "100u64", NonceDerivation::Deterministic, rng);
+        assert_ne!(request_0.tvk(), request_1.tvk());
+        assert_ne!(request_0.signature(), request_1.signature());
+    }
+
+    #[test]
+    fn test_sign_with_weak_rng() {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut TestRng::default()).unwrap();
+
+        // Ensure the deterministic nonce differs across requests, even if the RNG repeats its output.
+        let request_0 = sign_transfer(&private_key, "100u64", NonceDerivation::Deterministic, &mut TestRng::fixed(1));
+        let request_1 = sign_transfer(&private_key, "200u64", NonceDerivation::Deterministic, &mut TestRng::fixed(1));
+        assert_ne!(request_0.tvk(), request_1.tvk());
+        // Ensure the deterministic nonce is reproducible for an identical request and RNG output.
+        let request_2 = sign_transfer(&private_key, "100u64", NonceDerivation::Deterministic, &mut TestRng::fixed(1));
+        assert_eq!(request_0, request_2);
+
+        // Ensure the random nonce is reused across requests if the RNG repeats its output.
+        let request_0 = sign_transfer(&private_key, "100u64", NonceDerivation::Random, &mut TestRng::fixed(1));
+        let request_1 = sign_transfer(&private_key, "200u64", NonceDerivation::Random, &mut TestRng::fixed(1));
+        assert_eq!(request_0.tvk(), request_1.tvk());
+    }
+}
@@ -20,6 +20,12 @@ impl<N: Network> Request<N> {
     /// Verifies (challenge == challenge') && (address == address') && (serial_numbers == serial_numbers') where:
     ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
     pub fn verify(&self, input_types: &[ValueType<N>], is_root: bool) -> bool {
+        // Ensure the request is for this network.
+        if *self.network_id != N::ID {
+            eprintln!("Invalid network ID in request. Expected {}, found {}", N::ID, *self.network_id);
+            return false;
+        }
+
         // Verify the transition public key, transition view key, and transition commitment are well-formed.
         {
             // Compute the transition commitment `tcm` as `Hash(tvk)`.
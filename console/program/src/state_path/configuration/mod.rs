@@ -23,6 +23,8 @@ pub const HEADER_DEPTH: u8 = 3;
 pub const FINALIZE_ID_DEPTH: u8 = TRANSACTION_DEPTH + 4; // '+ 4' is to support 16 finalize operations per transition.
 /// The depth of the Merkle tree for finalize operations in a block.
 pub const FINALIZE_OPERATIONS_DEPTH: u8 = TRANSACTIONS_DEPTH;
+/// The depth of the Merkle tree for the confirmed entries in a mapping.
+pub const MAPPING_DEPTH: u8 = 32;
 /// The depth of the Merkle tree for the ratifications in a block.
 pub const RATIFICATIONS_DEPTH: u8 = 16;
 /// The depth the Merkle tree for the subdag certificates in a block.
@@ -46,6 +48,11 @@ pub type HeaderTree<N> = BHPMerkleTree<N, HEADER_DEPTH>;
 /// The Merkle path for the block header.
 pub type HeaderPath<N> = MerklePath<N, HEADER_DEPTH>;
 
+/// The Merkle tree for the confirmed entries in a mapping.
+pub type MappingTree<N> = BHPMerkleTree<N, MAPPING_DEPTH>;
+/// The Merkle path for an entry in a mapping.
+pub type MappingPath<N> = MerklePath<N, MAPPING_DEPTH>;
+
 /// The Merkle tree for ratifications in a block.
 pub type RatificationsTree<N> = BHPMerkleTree<N, RATIFICATIONS_DEPTH>;
 /// The Merkle path for a ratification in a block.
@@ -0,0 +1,281 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Identifier, Plaintext, ProgramID, Record};
+use snarkvm_console_account::{Address, ComputeKey, PrivateKey, Signature, ViewKey};
+use snarkvm_console_network::Network;
+use snarkvm_console_types::prelude::*;
+
+use indexmap::IndexMap;
+
+/// A test vector for a cryptographic primitive, with its inputs, intermediate values, and outputs.
+/// Each value is given in its canonical string encoding, and bit strings are given as `0`s and `1`s (little-endian).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestVector {
+    /// The name of the primitive.
+    primitive: String,
+    /// The inputs to the primitive.
+    inputs: IndexMap<String, String>,
+    /// The intermediate values of the primitive.
+    intermediates: IndexMap<String, String>,
+    /// The outputs of the primitive.
+    outputs: IndexMap<String, String>,
+}
+
+impl TestVector {
+    /// Initializes a new test vector for the given primitive.
+    fn new(primitive: &str) -> Self {
+        Self {
+            primitive: primitive.to_string(),
+            inputs: Default::default(),
+            intermediates: Default::default(),
+            outputs: Default::default(),
+        }
+    }
+
+    /// Adds the given input to the test vector.
+    fn input(mut self, name: &str, value: impl ToString) -> Self {
+        self.inputs.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Adds the given intermediate value to the test vector.
+    fn intermediate(mut self, name: &str, value: impl ToString) -> Self {
+        self.intermediates.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Adds the given output to the test vector.
+    fn output(mut self, name: &str, value: impl ToString) -> Self {
+        self.outputs.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Returns the name of the primitive.
+    pub fn primitive(&self) -> &str {
+        &self.primitive
+    }
+
+    /// Returns the inputs to the primitive.
+    pub const fn inputs(&self) -> &IndexMap<String, String> {
+        &self.inputs
+    }
+
+    /// Returns the intermediate values of the primitive.
+    pub const fn intermediates(&self) -> &IndexMap<String, String> {
+        &self.intermediates
+    }
+
+    /// Returns the outputs of the primitive.
+    pub const fn outputs(&self) -> &IndexMap<String, String> {
+        &self.outputs
+    }
+
+    /// Returns the test vector as a JSON value.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "primitive": self.primitive,
+            "inputs": self.inputs,
+            "intermediates": self.intermediates,
+            "outputs": self.outputs,
+        })
+    }
+}
+
+/// Returns the given test vectors as a pretty-printed JSON array.
+pub fn test_vectors_to_json(test_vectors: &[TestVector]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&test_vectors.iter().map(TestVector::to_json).collect::<Vec<_>>())?)
+}
+
+/// Returns `num_vectors` test vectors for each cryptographic primitive of the network.
+pub fn generate_test_vectors<N: Network, R: Rng + CryptoRng>(
+    num_vectors: usize,
+    rng: &mut R,
+) -> Result<Vec<TestVector>> {
+    let mut test_vectors = Vec::new();
+    for _ in 0..num_vectors {
+        test_vectors.extend(poseidon_test_vectors::<N, R>(rng)?);
+        test_vectors.extend(bhp_test_vectors::<N, R>(rng)?);
+        test_vectors.extend(pedersen_test_vectors::<N, R>(rng)?);
+        test_vectors.push(signature_test_vector::<N, R>(rng)?);
+        test_vectors.push(record_test_vector::<N, R>(rng)?);
+    }
+    Ok(test_vectors)
+}
+
+/// Returns the given bits as a string of `0`s and `1`s.
+fn bits_to_string(bits: &[bool]) -> String {
+    bits.iter().map(|bit| if *bit { '1' } else { '0' }).collect()
+}
+
+/// Returns the given field elements as a comma-separated string.
+fn fields_to_string<N: Network>(fields: &[Field<N>]) -> String {
+    fields.iter().map(|field| field.to_string()).join(", ")
+}
+
+/// Returns the test vectors for the Poseidon hash functions.
+fn poseidon_test_vectors<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<Vec<TestVector>> {
+    let input = (0..4).map(|_| Field::<N>::rand(rng)).collect::<Vec<_>>();
+    let num_outputs = 3u16;
+    Ok(vec![
+        TestVector::new("hash.psd2").input("input", fields_to_string(&input)).output("output", N::hash_psd2(&input)?),
+        TestVector::new("hash.psd4").input("input", fields_to_string(&input)).output("output", N::hash_psd4(&input)?),
+        TestVector::new("hash.psd8").input("input", fields_to_string(&input)).output("output", N::hash_psd8(&input)?),
+        TestVector::new("hash_many.psd2")
+            .input("input", fields_to_string(&input))
+            .input("num_outputs", num_outputs)
+            .output("output", fields_to_string(&N::hash_many_psd2(&input, num_outputs))),
+        TestVector::new("hash_to_scalar.psd2")
+            .input("input", fields_to_string(&input))
+            .output("output", N::hash_to_scalar_psd2(&input)?),
+    ])
+}
+
+/// Returns the test vectors for the BHP hash functions and commitment schemes.
+fn bhp_test_vectors<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<Vec<TestVector>> {
+    let input = (0..512).map(|_| rng.gen::<bool>()).collect::<Vec<_>>();
+    let randomizer = Scalar::<N>::rand(rng);
+    Ok(vec![
+        TestVector::new("hash.bhp256").input("input", bits_to_string(&input)).output("output", N::hash_bhp256(&input)?),
+        TestVector::new("hash.bhp512").input("input", bits_to_string(&input)).output("output", N::hash_bhp512(&input)?),
+        TestVector::new("hash.bhp768").input("input", bits_to_string(&input)).output("output", N::hash_bhp768(&input)?),
+        TestVector::new("hash.bhp1024")
+            .input("input", bits_to_string(&input))
+            .output("output", N::hash_bhp1024(&input)?),
+        TestVector::new("commit.bhp256")
+            .input("input", bits_to_string(&input))
+            .input("randomizer", randomizer)
+            .output("output", N::commit_bhp256(&input, &randomizer)?),
+        TestVector::new("commit.bhp512")
+            .input("input", bits_to_string(&input))
+            .input("randomizer", randomizer)
+            .output("output", N::commit_bhp512(&input, &randomizer)?),
+    ])
+}
+
+/// Returns the test vectors for the Pedersen hash functions and commitment schemes.
+fn pedersen_test_vectors<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<Vec<TestVector>> {
+    let input = (0..128).map(|_| rng.gen::<bool>()).collect::<Vec<_>>();
+    let randomizer = Scalar::<N>::rand(rng);
+    Ok(vec![
+        TestVector::new("hash.ped64")
+            .input("input", bits_to_string(&input[..64]))
+            .output("output", N::hash_ped64(&input[..64])?),
+        TestVector::new("hash.ped128").input("input", bits_to_string(&input)).output("output", N::hash_ped128(&input)?),
+        TestVector::new("commit.ped64")
+            .input("input", bits_to_string(&input[..64]))
+            .input("randomizer", randomizer)
+            .output("output", N::commit_ped64(&input[..64], &randomizer)?),
+        TestVector::new("commit.ped128")
+            .input("input", bits_to_string(&input))
+            .input("randomizer", randomizer)
+            .output("output", N::commit_ped128(&input, &randomizer)?),
+    ])
+}
+
+/// Returns the test vector for an account signature, including the account key derivation.
+fn signature_test_vector<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<TestVector> {
+    // Derive the account keys from a seed.
+    let seed = Field::<N>::rand(rng);
+    let private_key = PrivateKey::<N>::try_from(seed)?;
+    let compute_key = ComputeKey::try_from(&private_key)?;
+    let view_key = ViewKey::try_from(&private_key)?;
+    let address = Address::try_from(&compute_key)?;
+    // Sign the message.
+    let message = (0..4).map(|_| Field::<N>::rand(rng)).collect::<Vec<_>>();
+    let signature = Signature::sign(&private_key, &message, rng)?;
+    ensure!(signature.verify(&address, &message), "Failed to verify the signature test vector");
+
+    Ok(TestVector::new("signature")
+        .input("seed", seed)
+        .input("message", fields_to_string(&message))
+        .intermediate("private_key", private_key)
+        .intermediate("sk_sig", private_key.sk_sig())
+        .intermediate("r_sig", private_key.r_sig())
+        .intermediate("pk_sig", compute_key.pk_sig())
+        .intermediate("pr_sig", compute_key.pr_sig())
+        .intermediate("sk_prf", compute_key.sk_prf())
+        .intermediate("view_key", view_key)
+        .intermediate("address", address)
+        .output("challenge", signature.challenge())
+        .output("response", signature.response())
+        .output("signature", signature))
+}
+
+/// Returns the test vector for a record encryption and commitment.
+fn record_test_vector<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<TestVector> {
+    // Sample the record owner.
+    let private_key = PrivateKey::<N>::new(rng)?;
+    let view_key = ViewKey::try_from(&private_key)?;
+    let address = Address::try_from(&private_key)?;
+    // Construct the record, with the nonce derived from the randomizer.
+    let randomizer = Scalar::<N>::rand(rng);
+    let nonce = N::g_scalar_multiply(&randomizer);
+    let amount = rng.gen::<u64>();
+    let record = Record::<N, Plaintext<N>>::from_str(&format!(
+        "{{ owner: {address}.private, amount: {amount}u64.private, _nonce: {nonce}.public }}"
+    ))?;
+    // Compute the record view key, ciphertext, and commitment.
+    let record_view_key = (*address * randomizer).to_x_coordinate();
+    let ciphertext = record.encrypt(randomizer)?;
+    ensure!(ciphertext.decrypt(&view_key)? == record, "Failed to decrypt the record test vector");
+    let program_id = ProgramID::<N>::from_str("token.aleo")?;
+    let record_name = Identifier::<N>::from_str("token")?;
+    let commitment = record.to_commitment(&program_id, &record_name)?;
+
+    Ok(TestVector::new("record")
+        .input("view_key", view_key)
+        .input("record", record)
+        .input("randomizer", randomizer)
+        .input("program_id", program_id)
+        .input("record_name", record_name)
+        .intermediate("nonce", nonce)
+        .intermediate("record_view_key", record_view_key)
+        .output("ciphertext", ciphertext)
+        .output("commitment", commitment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_generate_test_vectors() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the test vectors are generated for each primitive.
+        let test_vectors = generate_test_vectors::<CurrentNetwork, _>(2, rng).unwrap();
+        assert_eq!(test_vectors.len(), 2 * 17);
+        assert!(test_vectors.iter().all(|test_vector| !test_vector.outputs().is_empty()));
+
+        // Ensure the test vectors are serialized as a JSON array.
+        let json = test_vectors_to_json(&test_vectors).unwrap();
+        let candidate: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(candidate.as_array().unwrap().len(), test_vectors.len());
+        assert_eq!(candidate[0]["primitive"], "hash.psd2");
+
+        // Ensure the test vectors are reproducible from the same seed.
+        let expected = generate_test_vectors::<CurrentNetwork, _>(1, &mut TestRng::fixed(1)).unwrap();
+        let candidate = generate_test_vectors::<CurrentNetwork, _>(1, &mut TestRng::fixed(1)).unwrap();
+        assert_eq!(expected, candidate);
+
+        // Ensure the BHP hash of the test vector input matches its output.
+        let test_vector = test_vectors.iter().find(|test_vector| test_vector.primitive() == "hash.bhp256").unwrap();
+        let input = test_vector.inputs()["input"].chars().map(|bit| bit == '1').collect::<Vec<_>>();
+        assert_eq!(test_vector.outputs()["output"], CurrentNetwork::hash_bhp256(&input).unwrap().to_string());
+    }
+}
@@ -56,6 +56,19 @@ impl<E: Environment> FromStr for Address<E> {
     }
 }
 
+impl<E: Environment> Address<E> {
+    /// Returns an account address from its bech32m string, with the `aleo` prefix.
+    /// This is the default string encoding of an address; see `StringEncoding` for the alternate encodings.
+    pub fn from_bech32m(address: &str) -> Result<Self> {
+        Self::from_str(address)
+    }
+
+    /// Returns the bech32m string of the account address, with the `aleo` prefix.
+    pub fn to_bech32m(&self) -> String {
+        self.to_string()
+    }
+}
+
 impl<E: Environment> Debug for Address<E> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         Display::fmt(self, f)
@@ -121,6 +134,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_alternate_encodings() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new address.
+            let expected = Address::<CurrentEnvironment>::rand(&mut rng);
+
+            // Check the bech32m, hex, and base64 encodings.
+            assert_eq!(expected, Address::from_bech32m(&expected.to_bech32m())?);
+            assert_eq!(expected, Address::from_hex(&expected.to_hex()?)?);
+            assert_eq!(expected, Address::from_base64(&expected.to_base64()?)?);
+            assert_eq!(
+                expected,
+                Address::from_encoding(&expected.to_encoding(Encoding::Bech32m("test"))?, Encoding::Bech32m("test"))?
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_display() -> Result<()> {
         let mut rng = TestRng::default();
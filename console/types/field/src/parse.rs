@@ -18,13 +18,19 @@ impl<E: Environment> Parser for Field<E> {
     /// Parses a string into a field circuit.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        // Retain the start of the literal, to report errors at its span.
+        let literal = string;
         // Parse the optional negative sign '-' from the string.
         let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.is_some())(string)?;
         // Parse the digits from the string.
-        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
-        // Parse the value from the string.
-        let (string, value): (&str, E::Field) =
-            map_res(tag(Self::type_name()), |_| primitive.replace('_', "").parse())(string)?;
+        let (string, primitive) = parse_digits(string)?;
+        // Parse the type from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the value, ensuring it is canonical (i.e. less than the modulus).
+        let value: E::Field = match primitive.parse() {
+            Ok(value) if value.to_string().trim_start_matches('0') == primitive.trim_start_matches('0') => value,
+            _ => return literal_failure(literal, "Field literal is not canonical (exceeds the modulus)"),
+        };
         // Negate the value if the negative sign was present.
         let value = match negation {
             true => -value,
@@ -60,8 +66,12 @@ impl<E: Environment> Debug for Field<E> {
 }
 
 impl<E: Environment> Display for Field<E> {
+    /// Writes the field, with `_` digit separators in the alternate format (`{:#}`).
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.field, Self::type_name())
+        match f.alternate() {
+            true => write!(f, "{}{}", format_digits(&self.field.to_string()), Self::type_name()),
+            false => write!(f, "{}{}", self.field, Self::type_name()),
+        }
     }
 }
 
@@ -94,6 +104,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_non_canonical() {
+        // The modulus of the field.
+        let modulus = "8444461749428370424248824938781546531375899335154063827935233455917409239041";
+
+        // Ensure the value below the modulus parses, including with leading zeros.
+        assert!(Field::<CurrentEnvironment>::parse(
+            "8444461749428370424248824938781546531375899335154063827935233455917409239040field"
+        )
+        .is_ok());
+        assert!(Field::<CurrentEnvironment>::parse("007field").is_ok());
+        // Ensure the modulus and values above it fail, reporting the span of the literal.
+        for string in [format!("{modulus}field"), format!("-{modulus}field"), format!("1{modulus}field")] {
+            let error = Field::<CurrentEnvironment>::parse(&string).unwrap_err().to_string();
+            assert!(
+                error.starts_with("Parsing Failure") && error.contains(&format!("{string:?}")),
+                "Expected a non-canonical field literal to fail"
+            );
+        }
+    }
+
+    #[test]
+    fn test_alternate_encodings() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new field.
+            let expected = Field::<CurrentEnvironment>::new(Uniform::rand(rng));
+
+            // Check the hex and base64 encodings.
+            assert_eq!(expected, Field::from_hex(&expected.to_hex()?)?);
+            assert_eq!(expected, Field::from_base64(&expected.to_base64()?)?);
+        }
+        // Ensure a hex string of the wrong length fails.
+        assert!(Field::<CurrentEnvironment>::from_hex("00").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_display() {
         /// Attempts to construct a field from the given element,
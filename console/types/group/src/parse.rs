@@ -18,21 +18,33 @@ impl<E: Environment> Parser for Group<E> {
     /// Parses a string into a group circuit.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        // Retain the start of the literal, to report errors at its span.
+        let literal = string;
         // Parse the optional negative sign '-' from the string.
         let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.is_some())(string)?;
         // Parse the digits from the string.
-        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
-        // Parse the group from the string.
-        let (string, group): (&str, Self) = map_res(tag(Self::type_name()), |_| {
-            let x_coordinate = primitive.replace('_', "").parse()?;
-            // Recover and negate the group element if the negative sign was present.
-            match negation {
-                true => Ok(-Group::from_x_coordinate(Field::new(x_coordinate))?),
-                false => Group::from_x_coordinate(Field::new(x_coordinate)),
+        let (string, primitive) = parse_digits(string)?;
+        // Parse the type from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the x-coordinate, ensuring it is canonical (i.e. less than the modulus).
+        let x_coordinate: E::Field = match primitive.parse() {
+            Ok(x_coordinate)
+                if x_coordinate.to_string().trim_start_matches('0') == primitive.trim_start_matches('0') =>
+            {
+                x_coordinate
             }
-        })(string)?;
-
-        Ok((string, group))
+            _ => return literal_failure(literal, "Group literal is not canonical (exceeds the modulus)"),
+        };
+        // Recover the group element, ensuring the x-coordinate is on the curve and in the subgroup.
+        let group = match Group::from_x_coordinate(Field::new(x_coordinate)) {
+            Ok(group) => group,
+            Err(_) => return literal_failure(literal, "Group literal is not a valid x-coordinate"),
+        };
+        // Negate the group element if the negative sign was present.
+        match negation {
+            true => Ok((string, -group)),
+            false => Ok((string, group)),
+        }
     }
 }
 
@@ -61,8 +73,13 @@ impl<E: Environment> Debug for Group<E> {
 }
 
 impl<E: Environment> Display for Group<E> {
+    /// Writes the x-coordinate of the group element, with `_` digit separators in the alternate format (`{:#}`).
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.group.to_affine().to_x_coordinate(), Self::type_name())
+        let x_coordinate = self.group.to_affine().to_x_coordinate();
+        match f.alternate() {
+            true => write!(f, "{}{}", format_digits(&x_coordinate.to_string()), Self::type_name()),
+            false => write!(f, "{}{}", x_coordinate, Self::type_name()),
+        }
     }
 }
 
@@ -95,6 +112,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_invalid() {
+        // Ensure an x-coordinate that exceeds the modulus fails.
+        let string = "8444461749428370424248824938781546531375899335154063827935233455917409239041group";
+        let error = Group::<CurrentEnvironment>::parse(string).unwrap_err().to_string();
+        assert!(
+            error.starts_with("Parsing Failure") && error.contains(&format!("{string:?}")),
+            "Expected a non-canonical group literal to fail"
+        );
+        // Ensure an x-coordinate that does not recover a group element fails.
+        let x_coordinate =
+            (1u64..).find(|x| Group::<CurrentEnvironment>::from_x_coordinate(Field::from_u64(*x)).is_err()).unwrap();
+        let string = format!("{x_coordinate}group");
+        let error = Group::<CurrentEnvironment>::parse(&string).unwrap_err().to_string();
+        assert!(
+            error.starts_with("Parsing Failure") && error.contains(&format!("{string:?}")),
+            "Expected an invalid group literal to fail"
+        );
+    }
+
     #[test]
     fn test_display() {
         /// Attempts to construct a group from the given element,
@@ -18,16 +18,21 @@ impl<E: Environment, I: IntegerType> Parser for Integer<E, I> {
     /// Parses a string into a integer circuit.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        // Retain the start of the literal, to report errors at its span.
+        let literal = string;
         // Parse the negative sign '-' from the string.
         let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.unwrap_or_default().to_string())(string)?;
         // Parse the digits from the string.
-        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
+        let (string, primitive) = parse_digits(string)?;
         // Combine the sign and primitive.
-        let primitive = negation + primitive;
-        // Parse the value from the string.
-        let (string, value) = map_res(tag(Self::type_name()), |_| primitive.replace('_', "").parse())(string)?;
-
-        Ok((string, Integer::new(value)))
+        let primitive = negation + &primitive;
+        // Parse the type from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the value, ensuring it is within the range of the type.
+        match primitive.parse() {
+            Ok(value) => Ok((string, Integer::new(value))),
+            Err(_) => literal_failure(literal, "Integer literal is out of range for its type"),
+        }
     }
 }
 
@@ -56,8 +61,12 @@ impl<E: Environment, I: IntegerType> Debug for Integer<E, I> {
 }
 
 impl<E: Environment, I: IntegerType> Display for Integer<E, I> {
+    /// Writes the integer, with `_` digit separators in the alternate format (`{:#}`).
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.integer, Self::type_name())
+        match f.alternate() {
+            true => write!(f, "{}{}", format_digits(&self.integer.to_string()), Self::type_name()),
+            false => write!(f, "{}{}", self.integer, Self::type_name()),
+        }
     }
 }
 
@@ -90,6 +99,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_digit_separators() -> Result<()> {
+        // Ensure digit separators are accepted.
+        assert_eq!(U64::<CurrentEnvironment>::new(1_000_000), U64::from_str("1_000_000u64")?);
+        assert_eq!(I64::<CurrentEnvironment>::new(-1_000_000), I64::from_str("-1_000_000i64")?);
+        assert_eq!(U64::<CurrentEnvironment>::new(10_000), U64::from_str("10_000_u64")?);
+        // Ensure repeated digit separators are still accepted, for the programs that are already deployed.
+        assert_eq!(U64::<CurrentEnvironment>::new(1_000), U64::from_str("1__000u64")?);
+        // Ensure leading digit separators are rejected.
+        assert!(U64::<CurrentEnvironment>::from_str("_1000u64").is_err());
+        assert!(I64::<CurrentEnvironment>::from_str("-_1000i64").is_err());
+
+        // Ensure the alternate format emits the digit separators, and round-trips.
+        let expected = I64::<CurrentEnvironment>::new(-1_234_567);
+        assert_eq!("-1_234_567i64", format!("{expected:#}"));
+        assert_eq!(expected, I64::from_str(&format!("{expected:#}"))?);
+        assert_eq!("-1234567i64", format!("{expected}"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_out_of_range() {
+        // Ensure the bounds of the type parse.
+        assert!(U8::<CurrentEnvironment>::parse("255u8").is_ok());
+        assert!(I8::<CurrentEnvironment>::parse("-128i8").is_ok());
+        // Ensure values outside the range of the type fail, reporting the span of the literal.
+        for string in ["256u8", "300u8", "-1u8"] {
+            let error = U8::<CurrentEnvironment>::parse(string).unwrap_err().to_string();
+            assert!(
+                error.starts_with("Parsing Failure") && error.contains(&format!("{string:?}")),
+                "Expected an out-of-range integer literal to fail"
+            );
+        }
+        for string in ["128i8", "-129i8"] {
+            let error = I8::<CurrentEnvironment>::parse(string).unwrap_err().to_string();
+            assert!(
+                error.starts_with("Parsing Failure") && error.contains(&format!("{string:?}")),
+                "Expected an out-of-range integer literal to fail"
+            );
+        }
+        // Ensure the remainder after the literal is preserved.
+        assert_eq!(" rest", U8::<CurrentEnvironment>::parse("255u8 rest").unwrap().0);
+    }
+
     #[test]
     fn test_display() {
         /// Attempts to construct a integer from the given element,
@@ -18,13 +18,19 @@ impl<E: Environment> Parser for Scalar<E> {
     /// Parses a string into a scalar circuit.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        // Retain the start of the literal, to report errors at its span.
+        let literal = string;
         // Parse the optional negative sign '-' from the string.
         let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.is_some())(string)?;
         // Parse the digits from the string.
-        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
-        // Parse the value from the string.
-        let (string, value): (&str, E::Scalar) =
-            map_res(tag(Self::type_name()), |_| primitive.replace('_', "").parse())(string)?;
+        let (string, primitive) = parse_digits(string)?;
+        // Parse the type from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the value, ensuring it is canonical (i.e. less than the modulus).
+        let value: E::Scalar = match primitive.parse() {
+            Ok(value) if value.to_string().trim_start_matches('0') == primitive.trim_start_matches('0') => value,
+            _ => return literal_failure(literal, "Scalar literal is not canonical (exceeds the modulus)"),
+        };
         // Negate the value if the negative sign was present.
         let value = match negation {
             true => -value,
@@ -60,8 +66,12 @@ impl<E: Environment> Debug for Scalar<E> {
 }
 
 impl<E: Environment> Display for Scalar<E> {
+    /// Writes the scalar, with `_` digit separators in the alternate format (`{:#}`).
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.scalar, Self::type_name())
+        match f.alternate() {
+            true => write!(f, "{}{}", format_digits(&self.scalar.to_string()), Self::type_name()),
+            false => write!(f, "{}{}", self.scalar, Self::type_name()),
+        }
     }
 }
 
@@ -94,6 +104,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_non_canonical() {
+        // The modulus of the scalar field.
+        let modulus = "2111115437357092606062206234695386632838870926408408195193685246394721360383";
+
+        // Ensure the value below the modulus parses.
+        assert!(Scalar::<CurrentEnvironment>::parse(
+            "2111115437357092606062206234695386632838870926408408195193685246394721360382scalar"
+        )
+        .is_ok());
+        // Ensure the modulus and values above it fail, reporting the span of the literal.
+        for string in [format!("{modulus}scalar"), format!("-{modulus}scalar")] {
+            let error = Scalar::<CurrentEnvironment>::parse(&string).unwrap_err().to_string();
+            assert!(
+                error.starts_with("Parsing Failure") && error.contains(&format!("{string:?}")),
+                "Expected a non-canonical scalar literal to fail"
+            );
+        }
+    }
+
     #[test]
     fn test_display() {
         /// Attempts to construct a scalar from the given element,
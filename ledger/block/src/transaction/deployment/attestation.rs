@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::{
+    account::{Address, PrivateKey, Signature},
+    types::Field,
+};
+
+/// The domain separator for deployment attestations.
+const ATTESTATION_DOMAIN: &str = "AleoDeploymentAttestation0";
+
+/// An attestation to a deployment, signed by an attester (e.g. an independent key-synthesis party).
+/// The attester asserts that the verifying keys and certificates in the deployment are correct for its program.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Attestation<N: Network> {
+    /// The address of the attester.
+    attester: Address<N>,
+    /// The signature of the attester on the deployment ID.
+    signature: Signature<N>,
+}
+
+impl<N: Network> Attestation<N> {
+    /// Initializes a new attestation.
+    pub const fn new(attester: Address<N>, signature: Signature<N>) -> Self {
+        Self { attester, signature }
+    }
+
+    /// Returns the address of the attester.
+    pub const fn attester(&self) -> &Address<N> {
+        &self.attester
+    }
+
+    /// Returns the signature of the attester.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+
+    /// Returns `true` if the attestation is valid for the given deployment ID.
+    pub fn verify(&self, deployment_id: Field<N>) -> bool {
+        self.signature.verify(&self.attester, &Self::message(deployment_id))
+    }
+
+    /// Returns the message signed by the attester, for the given deployment ID.
+    fn message(deployment_id: Field<N>) -> [Field<N>; 2] {
+        [Field::new_domain_separator(ATTESTATION_DOMAIN), deployment_id]
+    }
+}
+
+impl<N: Network> Deployment<N> {
+    /// Returns an attestation to this deployment, signed with the given private key.
+    pub fn attest<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Attestation<N>> {
+        // Compute the deployment ID.
+        let deployment_id = self.to_deployment_id()?;
+        // Sign the deployment ID.
+        let signature = Signature::sign(private_key, &Attestation::message(deployment_id), rng)?;
+        // Return the attestation.
+        Ok(Attestation::new(Address::try_from(private_key)?, signature))
+    }
+
+    /// Ensures the deployment is attested to by at least `threshold` of the given attesters.
+    ///
+    /// Attestations from attesters that are not in the given set are ignored,
+    /// and each attester is counted at most once.
+    pub fn verify_attestations(
+        &self,
+        attestations: &[Attestation<N>],
+        attesters: &[Address<N>],
+        threshold: usize,
+    ) -> Result<()> {
+        // Ensure the threshold is well-formed.
+        ensure!(threshold > 0, "The attestation threshold must be at least 1");
+        ensure!(
+            threshold <= attesters.len(),
+            "The attestation threshold ({threshold}) exceeds the number of attesters ({})",
+            attesters.len()
+        );
+
+        // Compute the deployment ID.
+        let deployment_id = self.to_deployment_id()?;
+
+        // Collect the distinct attesters with a valid attestation.
+        let mut attested = Vec::with_capacity(attestations.len());
+        for attestation in attestations {
+            // Skip attestations from unknown or repeated attesters.
+            if !attesters.contains(attestation.attester()) || attested.contains(attestation.attester()) {
+                continue;
+            }
+            // Ensure the attestation is valid.
+            ensure!(
+                attestation.verify(deployment_id),
+                "Invalid attestation from '{}' for program '{}'",
+                attestation.attester(),
+                self.program_id()
+            );
+            attested.push(*attestation.attester());
+        }
+
+        // Ensure the threshold is met.
+        ensure!(
+            attested.len() >= threshold,
+            "Deployment for program '{}' has {} of the required {threshold} attestations",
+            self.program_id(),
+            attested.len()
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for Attestation<N> {
+    /// Reads the attestation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid attestation version"));
+        }
+        // Read the attester.
+        let attester = Address::read_le(&mut reader)?;
+        // Read the signature.
+        let signature = Signature::read_le(&mut reader)?;
+        // Return the attestation.
+        Ok(Self::new(attester, signature))
+    }
+}
+
+impl<N: Network> ToBytes for Attestation<N> {
+    /// Writes the attestation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the attester.
+        self.attester.write_le(&mut writer)?;
+        // Write the signature.
+        self.signature.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> Debug for Attestation<N> {
+    /// Prints the attestation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Attestation {{ attester: {}, signature: {} }}", self.attester, self.signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_verify_attestations() {
+        let rng = &mut TestRng::default();
+
+        // Sample a deployment.
+        let deployment = test_helpers::sample_deployment(rng);
+
+        // Sample the attesters.
+        let private_keys = (0..3).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect::<Vec<_>>();
+        let attesters =
+            private_keys.iter().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>();
+
+        // Attest to the deployment with the first two attesters.
+        let attestations = private_keys[..2]
+            .iter()
+            .map(|private_key| deployment.attest(private_key, rng).unwrap())
+            .collect::<Vec<_>>();
+
+        // Check the byte representation.
+        for attestation in &attestations {
+            let bytes = attestation.to_bytes_le().unwrap();
+            assert_eq!(*attestation, Attestation::read_le(&bytes[..]).unwrap());
+        }
+
+        // Ensure the threshold is met.
+        assert!(deployment.verify_attestations(&attestations, &attesters, 1).is_ok());
+        assert!(deployment.verify_attestations(&attestations, &attesters, 2).is_ok());
+        // Ensure the threshold is not met.
+        assert!(deployment.verify_attestations(&attestations, &attesters, 3).is_err());
+        // Ensure a repeated attestation is counted once.
+        let repeated = vec![attestations[0], attestations[0]];
+        assert!(deployment.verify_attestations(&repeated, &attesters, 2).is_err());
+        // Ensure an attestation from an unknown attester is ignored.
+        assert!(deployment.verify_attestations(&attestations, &attesters[1..], 2).is_err());
+        // Ensure a malformed threshold fails.
+        assert!(deployment.verify_attestations(&attestations, &attesters, 0).is_err());
+        assert!(deployment.verify_attestations(&attestations, &attesters, 4).is_err());
+
+        // Ensure an attestation with a mismatched signature fails.
+        let forged = Attestation::new(attesters[2], *attestations[0].signature());
+        assert!(deployment.verify_attestations(&[forged], &attesters, 1).is_err());
+    }
+}
@@ -16,18 +16,77 @@ use super::*;
 
 impl<N: Network> FromBytes for Deployment<N> {
     /// Reads the deployment from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    /// Note: Only the canonical encoding (version 1) is accepted. See `Deployment::read_le_compressed`.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_internal(reader, false)
+    }
+}
+
+impl<N: Network> ToBytes for Deployment<N> {
+    /// Writes the deployment to a buffer.
+    /// Note: The canonical encoding stores the program bytes (version 1). See `Deployment::write_le_compressed`.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_internal(writer, false)
+    }
+}
+
+impl<N: Network> Deployment<N> {
+    /// Reads the deployment from a buffer, with the compressed program text (version 2).
+    /// Note: This encoding is only for transport, and is not accepted by `Deployment::read_le`.
+    pub fn read_le_compressed<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_internal(reader, true)
+    }
+
+    /// Returns the deployment from the given bytes, with the compressed program text (version 2).
+    pub fn from_compressed_bytes_le(bytes: &[u8]) -> Result<Self> {
+        Ok(Self::read_le_compressed(bytes)?)
+    }
+
+    /// Writes the deployment to a buffer, with the compressed program text (version 2).
+    /// Note: This encoding is only for transport, and is not accepted by `Deployment::read_le`.
+    pub fn write_le_compressed<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_internal(writer, true)
+    }
+
+    /// Returns the bytes of the deployment, with the compressed program text (version 2).
+    pub fn to_compressed_bytes_le(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write_le_compressed(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Reads the deployment from a buffer, with the program bytes (version 1), or the compressed program (version 2).
+    fn read_le_internal<R: Read>(mut reader: R, is_compressed: bool) -> IoResult<Self> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
+        // Ensure the version matches the encoding.
+        let expected_version = match is_compressed {
+            true => 2u8,
+            false => 1u8,
+        };
+        if version != expected_version {
             return Err(error("Invalid deployment version"));
         }
 
         // Read the edition.
         let edition = u16::read_le(&mut reader)?;
         // Read the program.
-        let program = Program::read_le(&mut reader)?;
+        let program = match is_compressed {
+            false => Program::read_le(&mut reader)?,
+            true => {
+                // Read the number of compressed bytes.
+                let num_bytes = u32::read_le(&mut reader)? as usize;
+                // Ensure the number of compressed bytes does not exceed the maximum program size.
+                if num_bytes > N::MAX_PROGRAM_SIZE {
+                    return Err(error("Compressed program exceeds N::MAX_PROGRAM_SIZE"));
+                }
+                // Read the compressed bytes.
+                let mut compressed = vec![0u8; num_bytes];
+                reader.read_exact(&mut compressed)?;
+                // Decompress the program.
+                Program::from_compressed_bytes(&compressed).map_err(|e| error(e.to_string()))?
+            }
+        };
 
         // Read the number of entries in the bundle.
         let num_entries = u16::read_le(&mut reader)?;
@@ -47,17 +106,29 @@ impl<N: Network> FromBytes for Deployment<N> {
         // Return the deployment.
         Self::new(edition, program, verifying_keys).map_err(|err| error(format!("{err}")))
     }
-}
 
-impl<N: Network> ToBytes for Deployment<N> {
-    /// Writes the deployment to a buffer.
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+    /// Writes the deployment to a buffer, with the program bytes (version 1), or the compressed program (version 2).
+    fn write_le_internal<W: Write>(&self, mut writer: W, is_compressed: bool) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        let version = match is_compressed {
+            true => 2u8,
+            false => 1u8,
+        };
+        version.write_le(&mut writer)?;
         // Write the edition.
         self.edition.write_le(&mut writer)?;
         // Write the program.
-        self.program.write_le(&mut writer)?;
+        match is_compressed {
+            false => self.program.write_le(&mut writer)?,
+            true => {
+                // Compress the program.
+                let compressed = self.program.to_compressed_bytes().map_err(|e| error(e.to_string()))?;
+                // Write the number of compressed bytes.
+                (u32::try_from(compressed.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+                // Write the compressed program.
+                writer.write_all(&compressed)?;
+            }
+        }
         // Write the number of entries in the bundle.
         (u16::try_from(self.verifying_keys.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
         // Write each entry.
@@ -76,6 +147,9 @@ impl<N: Network> ToBytes for Deployment<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
 
     #[test]
     fn test_bytes() -> Result<()> {
@@ -89,4 +163,49 @@ mod tests {
         assert_eq!(expected, Deployment::read_le(&expected_bytes[..])?);
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_version_1() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new deployment.
+        let expected = test_helpers::sample_deployment(rng);
+
+        // Write the deployment with the uncompressed program.
+        let mut expected_bytes = vec![1u8];
+        expected.edition().write_le(&mut expected_bytes)?;
+        expected.program().write_le(&mut expected_bytes)?;
+        u16::try_from(expected.verifying_keys().len())?.write_le(&mut expected_bytes)?;
+        for (function_name, (verifying_key, certificate)) in expected.verifying_keys() {
+            function_name.write_le(&mut expected_bytes)?;
+            verifying_key.write_le(&mut expected_bytes)?;
+            certificate.write_le(&mut expected_bytes)?;
+        }
+        // Ensure version 1 is the canonical encoding.
+        assert_eq!(expected.to_bytes_le()?, expected_bytes);
+        assert_eq!(expected, Deployment::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_version_2() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new deployment.
+        let expected = test_helpers::sample_deployment(rng);
+
+        // Ensure the compressed encoding is read, and is smaller by the size saved on the program.
+        let compressed_bytes = expected.to_compressed_bytes_le()?;
+        assert_eq!(compressed_bytes[0], 2);
+        assert_eq!(expected, Deployment::from_compressed_bytes_le(&compressed_bytes)?);
+        let program_size = expected.program().to_bytes_le()?.len();
+        let compressed_program_size = expected.program().to_compressed_bytes()?.len();
+        assert_eq!(expected.to_bytes_le()?.len() + compressed_program_size + 4, compressed_bytes.len() + program_size);
+        assert_eq!(expected.compressed_size_in_bytes()?, compressed_bytes.len() as u64);
+
+        // Ensure each encoding is only accepted by its own reader.
+        assert!(Deployment::<CurrentNetwork>::read_le(&compressed_bytes[..]).is_err());
+        assert!(Deployment::<CurrentNetwork>::from_compressed_bytes_le(&expected.to_bytes_le()?).is_err());
+        Ok(())
+    }
 }
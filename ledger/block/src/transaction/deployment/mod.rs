@@ -14,6 +14,9 @@
 
 #![allow(clippy::type_complexity)]
 
+mod attestation;
+pub use attestation::*;
+
 mod bytes;
 mod serialize;
 mod string;
@@ -104,6 +107,11 @@ impl<N: Network> Deployment<N> {
         Ok(u64::try_from(self.to_bytes_le()?.len())?)
     }
 
+    /// Returns the size in bytes, with the compressed program text.
+    pub fn compressed_size_in_bytes(&self) -> Result<u64> {
+        Ok(u64::try_from(self.to_compressed_bytes_le()?.len())?)
+    }
+
     /// Returns the edition.
     pub const fn edition(&self) -> u16 {
         self.edition
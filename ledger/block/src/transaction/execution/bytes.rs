@@ -14,9 +14,9 @@
 
 use super::*;
 
-impl<N: Network> FromBytes for Execution<N> {
-    /// Reads the execution from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+impl<N: Network> Execution<N> {
+    /// Reads the version and number of transitions of an execution from a buffer.
+    pub(super) fn read_header<R: Read>(mut reader: R) -> IoResult<u8> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
@@ -24,14 +24,11 @@ impl<N: Network> FromBytes for Execution<N> {
             return Err(error("Invalid execution version"));
         }
         // Read the number of transitions.
-        let num_transitions = u8::read_le(&mut reader)?;
-        // Ensure the number of transitions is nonzero.
-        if num_transitions == 0 {
-            return Err(error("Execution (from 'read_le') has no transitions"));
-        }
-        // Read the transitions.
-        let transitions =
-            (0..num_transitions).map(|_| Transition::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        u8::read_le(&mut reader)
+    }
+
+    /// Reads the global state root and proof of an execution from a buffer.
+    pub(super) fn read_footer<R: Read>(mut reader: R) -> IoResult<(N::StateRoot, Option<Proof<N>>)> {
         // Read the global state root.
         let global_state_root = N::StateRoot::read_le(&mut reader)?;
         // Read the proof variant.
@@ -42,6 +39,50 @@ impl<N: Network> FromBytes for Execution<N> {
             1 => Some(Proof::read_le(&mut reader)?),
             _ => return Err(error(format!("Invalid proof variant '{proof_variant}'"))),
         };
+        Ok((global_state_root, proof))
+    }
+
+    /// Writes the version and number of transitions of an execution to a buffer.
+    pub(super) fn write_header<W: Write>(num_transitions: usize, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of transitions.
+        (u8::try_from(num_transitions).map_err(|e| error(e.to_string()))?).write_le(&mut writer)
+    }
+
+    /// Writes the global state root and proof of an execution to a buffer.
+    pub(super) fn write_footer<W: Write>(
+        global_state_root: &N::StateRoot,
+        proof: Option<&Proof<N>>,
+        mut writer: W,
+    ) -> IoResult<()> {
+        // Write the global state root.
+        global_state_root.write_le(&mut writer)?;
+        // Write the proof.
+        match proof {
+            None => 0u8.write_le(&mut writer),
+            Some(proof) => {
+                1u8.write_le(&mut writer)?;
+                proof.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<N: Network> FromBytes for Execution<N> {
+    /// Reads the execution from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version and number of transitions.
+        let num_transitions = Self::read_header(&mut reader)?;
+        // Ensure the number of transitions is nonzero.
+        if num_transitions == 0 {
+            return Err(error("Execution (from 'read_le') has no transitions"));
+        }
+        // Read the transitions.
+        let transitions =
+            (0..num_transitions).map(|_| Transition::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the global state root and proof.
+        let (global_state_root, proof) = Self::read_footer(&mut reader)?;
         // Return the new `Execution` instance.
         Self::from(transitions.into_iter(), global_state_root, proof).map_err(|e| error(e.to_string()))
     }
@@ -50,25 +91,14 @@ impl<N: Network> FromBytes for Execution<N> {
 impl<N: Network> ToBytes for Execution<N> {
     /// Writes the execution to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
-        // Write the number of transitions.
-        (u8::try_from(self.transitions.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+        // Write the version and number of transitions.
+        Self::write_header(self.transitions.len(), &mut writer)?;
         // Write the transitions.
         for transition in self.transitions.values() {
             transition.write_le(&mut writer)?;
         }
-        // Write the global state root.
-        self.global_state_root.write_le(&mut writer)?;
-        // Write the proof.
-        match self.proof {
-            None => 0u8.write_le(&mut writer)?,
-            Some(ref proof) => {
-                1u8.write_le(&mut writer)?;
-                proof.write_le(&mut writer)?;
-            }
-        }
-        Ok(())
+        // Write the global state root and proof.
+        Self::write_footer(&self.global_state_root, self.proof.as_ref(), &mut writer)
     }
 }
 
@@ -0,0 +1,452 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Input, Output};
+use console::program::Identifier;
+
+use indexmap::IndexSet;
+
+/// The version of the compressed execution encoding.
+const COMPRESSED_EXECUTION_VERSION: u8 = 1;
+/// The variant of an input or output that is written in full.
+const ENTRY_LITERAL: u8 = 0;
+/// The variant of an input or output that refers to an identical, previously-written input or output.
+const ENTRY_REFERENCE: u8 = 1;
+
+impl<N: Network> Execution<N> {
+    /// Returns the compressed wire encoding of the execution.
+    ///
+    /// The encoding begins with a header, which deduplicates the program IDs and the `(program ID, function name)`
+    /// pairs that identify each verifying key, in order of first use. Each transition then refers to its pair by index,
+    /// and each input or output that is byte-identical to a previous one is written as a reference to it.
+    /// The encoding is canonical, and may be decoded incrementally with a `CompressedExecutionReader`.
+    pub fn to_compressed_bytes(&self) -> Result<Vec<u8>> {
+        // Collect the program IDs and function locators, in order of first use.
+        let mut program_ids = IndexSet::new();
+        let mut locators = IndexSet::new();
+        for transition in self.transitions.values() {
+            program_ids.insert(*transition.program_id());
+            locators.insert((*transition.program_id(), *transition.function_name()));
+        }
+
+        let mut writer = Vec::new();
+        // Write the version.
+        COMPRESSED_EXECUTION_VERSION.write_le(&mut writer)?;
+        // Write the program IDs.
+        u8::try_from(program_ids.len())?.write_le(&mut writer)?;
+        for program_id in &program_ids {
+            program_id.write_le(&mut writer)?;
+        }
+        // Write the function locators, as a program index and function name.
+        u8::try_from(locators.len())?.write_le(&mut writer)?;
+        for (program_id, function_name) in &locators {
+            let Some(program_index) = program_ids.get_index_of(program_id) else {
+                bail!("Program '{program_id}' is missing from the compressed execution")
+            };
+            u8::try_from(program_index)?.write_le(&mut writer)?;
+            function_name.write_le(&mut writer)?;
+        }
+
+        // Write the number of transitions.
+        u8::try_from(self.transitions.len())?.write_le(&mut writer)?;
+        // Initialize the tables of previously-written inputs and outputs.
+        let mut inputs = IndexSet::new();
+        let mut outputs = IndexSet::new();
+        // Write the transitions.
+        for transition in self.transitions.values() {
+            // Write the locator index.
+            let Some(locator_index) = locators.get_index_of(&(*transition.program_id(), *transition.function_name()))
+            else {
+                bail!("Transition '{}' is missing from the compressed execution", transition.id())
+            };
+            u8::try_from(locator_index)?.write_le(&mut writer)?;
+            // Write the transition ID.
+            transition.id().write_le(&mut writer)?;
+            // Write the inputs.
+            u8::try_from(transition.inputs().len())?.write_le(&mut writer)?;
+            for input in transition.inputs() {
+                write_entry(&mut writer, &mut inputs, input.to_bytes_le()?)?;
+            }
+            // Write the outputs.
+            u8::try_from(transition.outputs().len())?.write_le(&mut writer)?;
+            for output in transition.outputs() {
+                write_entry(&mut writer, &mut outputs, output.to_bytes_le()?)?;
+            }
+            // Write the transition public key, transition commitment, and signer commitment.
+            transition.tpk().write_le(&mut writer)?;
+            transition.tcm().write_le(&mut writer)?;
+            transition.scm().write_le(&mut writer)?;
+        }
+
+        // Write the global state root.
+        self.global_state_root.write_le(&mut writer)?;
+        // Write the proof.
+        match self.proof {
+            None => 0u8.write_le(&mut writer)?,
+            Some(ref proof) => {
+                1u8.write_le(&mut writer)?;
+                proof.write_le(&mut writer)?;
+            }
+        }
+        Ok(writer)
+    }
+
+    /// Returns an execution from its compressed wire encoding.
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut source = bytes;
+        // Read the transitions.
+        let mut reader = CompressedExecutionReader::<N, _>::new(&mut source)?;
+        let transitions = reader.by_ref().collect::<IoResult<Vec<_>>>()?;
+        // Read the global state root and proof.
+        let (global_state_root, proof) = reader.finish()?;
+        // Ensure there are no trailing bytes.
+        ensure!(source.is_empty(), "The compressed execution has {} trailing bytes", source.len());
+        // Return the execution.
+        Self::from(transitions.into_iter(), global_state_root, proof)
+    }
+}
+
+/// Writes the given input or output bytes, or a reference to an identical, previously-written input or output.
+fn write_entry<W: Write>(mut writer: W, entries: &mut IndexSet<Vec<u8>>, entry: Vec<u8>) -> IoResult<()> {
+    match entries.get_index_of(&entry) {
+        Some(index) => {
+            ENTRY_REFERENCE.write_le(&mut writer)?;
+            (u16::try_from(index).map_err(|e| error(e.to_string()))?).write_le(&mut writer)
+        }
+        None => {
+            ENTRY_LITERAL.write_le(&mut writer)?;
+            writer.write_all(&entry)?;
+            entries.insert(entry);
+            Ok(())
+        }
+    }
+}
+
+/// Reads an input or output, or a reference to an identical, previously-read input or output.
+fn read_entry<R: Read, T: Clone + FromBytes + ToBytes>(
+    mut reader: R,
+    entries: &mut IndexMap<Vec<u8>, T>,
+) -> IoResult<T> {
+    match u8::read_le(&mut reader)? {
+        ENTRY_LITERAL => {
+            // Read the entry.
+            let entry = T::read_le(&mut reader)?;
+            // Ensure the entry is not a duplicate, which must be written as a reference.
+            let bytes = entry.to_bytes_le().map_err(|e| error(e.to_string()))?;
+            match entries.insert(bytes, entry.clone()) {
+                None => Ok(entry),
+                Some(_) => Err(error("Compressed execution contains a duplicate entry that is not a reference")),
+            }
+        }
+        ENTRY_REFERENCE => {
+            // Read the index of the referenced entry.
+            let index = u16::read_le(&mut reader)?;
+            match entries.get_index(index as usize) {
+                Some((_, entry)) => Ok(entry.clone()),
+                None => Err(error(format!("Compressed execution refers to a missing entry at index {index}"))),
+            }
+        }
+        variant => Err(error(format!("Invalid compressed execution entry variant '{variant}'"))),
+    }
+}
+
+/// A streaming reader for a compressed execution, which yields each transition as it is read from the source.
+///
+/// The transitions are yielded by iterating the reader, after which `finish` reads the global state root and proof.
+pub struct CompressedExecutionReader<N: Network, R: Read> {
+    /// The input source.
+    reader: R,
+    /// The function locators, as `(program ID, function name)`.
+    locators: Vec<(ProgramID<N>, Identifier<N>)>,
+    /// The number of function locators used so far.
+    num_locators_used: usize,
+    /// The number of transitions in the execution.
+    num_transitions: usize,
+    /// The IDs of the transitions read so far.
+    transition_ids: IndexSet<N::TransitionID>,
+    /// The inputs read so far, keyed by their bytes.
+    inputs: IndexMap<Vec<u8>, Input<N>>,
+    /// The outputs read so far, keyed by their bytes.
+    outputs: IndexMap<Vec<u8>, Output<N>>,
+    /// Whether the reader has encountered an error.
+    is_poisoned: bool,
+}
+
+impl<N: Network, R: Read> CompressedExecutionReader<N, R> {
+    /// Initializes a new streaming reader, reading the compressed execution header from the source.
+    pub fn new(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != COMPRESSED_EXECUTION_VERSION {
+            return Err(error("Invalid compressed execution version"));
+        }
+
+        // Read the program IDs.
+        let num_programs = u8::read_le(&mut reader)? as usize;
+        let mut program_ids = IndexSet::with_capacity(num_programs);
+        for _ in 0..num_programs {
+            if !program_ids.insert(ProgramID::<N>::read_le(&mut reader)?) {
+                return Err(error("Compressed execution contains a duplicate program ID"));
+            }
+        }
+
+        // Read the function locators.
+        let num_locators = u8::read_le(&mut reader)? as usize;
+        let mut locators = IndexSet::with_capacity(num_locators);
+        let mut num_programs_used = 0;
+        for _ in 0..num_locators {
+            // Read the program index.
+            let program_index = u8::read_le(&mut reader)? as usize;
+            // Ensure the program IDs are used in order of first use.
+            if program_index > num_programs_used {
+                return Err(error("Compressed execution program IDs are not in order of first use"));
+            }
+            let Some(program_id) = program_ids.get_index(program_index) else {
+                return Err(error(format!(
+                    "Compressed execution refers to a missing program at index {program_index}"
+                )));
+            };
+            if program_index == num_programs_used {
+                num_programs_used += 1;
+            }
+            // Read the function name.
+            let function_name = Identifier::<N>::read_le(&mut reader)?;
+            if !locators.insert((*program_id, function_name)) {
+                return Err(error("Compressed execution contains a duplicate function locator"));
+            }
+        }
+        // Ensure every program ID is used.
+        if num_programs_used != program_ids.len() {
+            return Err(error("Compressed execution contains an unused program ID"));
+        }
+
+        // Read the number of transitions.
+        let num_transitions = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of transitions is nonzero.
+        if num_transitions == 0 {
+            return Err(error("Execution (from 'CompressedExecutionReader') has no transitions"));
+        }
+
+        // Return the reader.
+        Ok(Self {
+            reader,
+            locators: locators.into_iter().collect(),
+            num_locators_used: 0,
+            num_transitions,
+            transition_ids: IndexSet::with_capacity(num_transitions),
+            inputs: Default::default(),
+            outputs: Default::default(),
+            is_poisoned: false,
+        })
+    }
+
+    /// Returns the number of transitions in the execution.
+    pub fn num_transitions(&self) -> usize {
+        self.num_transitions
+    }
+
+    /// Returns the IDs of the transitions read so far.
+    pub fn transition_ids(&self) -> &IndexSet<N::TransitionID> {
+        &self.transition_ids
+    }
+
+    /// Reads the next transition from the source.
+    fn read_transition(&mut self) -> IoResult<Transition<N>> {
+        // Read the locator index.
+        let locator_index = u8::read_le(&mut self.reader)? as usize;
+        // Ensure the function locators are used in order of first use.
+        if locator_index > self.num_locators_used {
+            return Err(error("Compressed execution function locators are not in order of first use"));
+        }
+        let Some((program_id, function_name)) = self.locators.get(locator_index).copied() else {
+            return Err(error(format!("Compressed execution refers to a missing locator at index {locator_index}")));
+        };
+        if locator_index == self.num_locators_used {
+            self.num_locators_used += 1;
+        }
+
+        // Read the transition ID.
+        let transition_id = N::TransitionID::read_le(&mut self.reader)?;
+        // Read the inputs.
+        let num_inputs = u8::read_le(&mut self.reader)?;
+        let inputs =
+            (0..num_inputs).map(|_| read_entry(&mut self.reader, &mut self.inputs)).collect::<IoResult<Vec<_>>>()?;
+        // Read the outputs.
+        let num_outputs = u8::read_le(&mut self.reader)?;
+        let outputs =
+            (0..num_outputs).map(|_| read_entry(&mut self.reader, &mut self.outputs)).collect::<IoResult<Vec<_>>>()?;
+        // Read the transition public key, transition commitment, and signer commitment.
+        let tpk = FromBytes::read_le(&mut self.reader)?;
+        let tcm = FromBytes::read_le(&mut self.reader)?;
+        let scm = FromBytes::read_le(&mut self.reader)?;
+
+        // Construct the transition.
+        let transition = Transition::new(program_id, function_name, inputs, outputs, tpk, tcm, scm)
+            .map_err(|e| error(e.to_string()))?;
+        // Ensure the transition ID matches the expected ID.
+        if transition_id != *transition.id() {
+            return Err(error("Transition ID is incorrect, possible data corruption"));
+        }
+        // Ensure the transition is not a duplicate.
+        if !self.transition_ids.insert(transition_id) {
+            return Err(error(format!(
+                "Execution (from 'CompressedExecutionReader') has a duplicate transition '{transition_id}'"
+            )));
+        }
+        Ok(transition)
+    }
+
+    /// Reads the global state root and proof, once all of the transitions have been read.
+    pub fn finish(mut self) -> IoResult<(N::StateRoot, Option<Proof<N>>)> {
+        // Ensure the reader did not encounter an error.
+        if self.is_poisoned {
+            return Err(error("Execution (from 'CompressedExecutionReader') failed to read a transition"));
+        }
+        // Ensure all of the transitions were read.
+        if self.transition_ids.len() != self.num_transitions {
+            return Err(error(format!(
+                "Execution (from 'CompressedExecutionReader') expected {} transitions, read {}",
+                self.num_transitions,
+                self.transition_ids.len()
+            )));
+        }
+        // Ensure every function locator is used.
+        if self.num_locators_used != self.locators.len() {
+            return Err(error("Compressed execution contains an unused function locator"));
+        }
+        // Read the global state root.
+        let global_state_root = N::StateRoot::read_le(&mut self.reader)?;
+        // Read the proof variant.
+        let proof_variant = u8::read_le(&mut self.reader)?;
+        // Read the proof.
+        let proof = match proof_variant {
+            0 => None,
+            1 => Some(Proof::read_le(&mut self.reader)?),
+            _ => return Err(error(format!("Invalid proof variant '{proof_variant}'"))),
+        };
+        Ok((global_state_root, proof))
+    }
+}
+
+impl<N: Network, R: Read> Iterator for CompressedExecutionReader<N, R> {
+    type Item = IoResult<Transition<N>>;
+
+    /// Reads the next transition, or returns `None` once all transitions have been read or an error occurred.
+    fn next(&mut self) -> Option<Self::Item> {
+        // Stop if all of the transitions have been read, or if a previous read failed.
+        if self.is_poisoned || self.transition_ids.len() >= self.num_transitions {
+            return None;
+        }
+        // Read the next transition.
+        let result = self.read_transition();
+        self.is_poisoned = result.is_err();
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_compressed_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new execution.
+        let expected = crate::transaction::execution::test_helpers::sample_execution(rng);
+
+        // Check the compressed byte representation.
+        let compressed = expected.to_compressed_bytes()?;
+        assert_eq!(expected, Execution::from_compressed_bytes(&compressed)?);
+
+        // Stream the execution from the compressed bytes.
+        let mut reader = CompressedExecutionReader::<CurrentNetwork, _>::new(&compressed[..])?;
+        assert_eq!(reader.num_transitions(), expected.len());
+        for (candidate, transition) in reader.by_ref().zip_eq(expected.transitions()) {
+            assert_eq!(&candidate?, transition);
+        }
+        let (global_state_root, proof) = reader.finish()?;
+        assert_eq!(global_state_root, expected.global_state_root());
+        assert_eq!(proof.as_ref(), expected.proof());
+        Ok(())
+    }
+
+    #[test]
+    fn test_compressed_bytes_deduplicates_shared_structure() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new execution.
+        let execution = crate::transaction::execution::test_helpers::sample_execution(rng);
+        let transition = execution.peek()?;
+
+        // Construct a second transition, which shares the program, function, inputs, and outputs of the first.
+        let sibling = Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            transition.outputs().to_vec(),
+            *transition.tpk(),
+            Field::rand(rng),
+            *transition.scm(),
+        )?;
+        let expected = Execution::from([transition.clone(), sibling].into_iter(), execution.global_state_root(), None)?;
+
+        // Ensure the shared structure is written once, such that the sibling only adds its
+        // locator index, transition ID, input and output references, and commitments.
+        let compressed = expected.to_compressed_bytes()?;
+        let single = Execution::from([transition.clone()].into_iter(), execution.global_state_root(), None)?;
+        let num_entries = transition.inputs().len() + transition.outputs().len();
+        let expected_increase = 1
+            + transition.id().to_bytes_le()?.len()
+            + 2
+            + 3 * num_entries
+            + transition.tpk().to_bytes_le()?.len()
+            + transition.tcm().to_bytes_le()?.len()
+            + transition.scm().to_bytes_le()?.len();
+        assert_eq!(compressed.len(), single.to_compressed_bytes()?.len() + expected_increase);
+        assert!(compressed.len() < expected.to_bytes_le()?.len());
+        assert_eq!(expected, Execution::from_compressed_bytes(&compressed)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compressed_bytes_rejects_malformed() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new execution.
+        let expected = crate::transaction::execution::test_helpers::sample_execution(rng);
+        let compressed = expected.to_compressed_bytes()?;
+
+        // Ensure an invalid version is rejected.
+        let mut candidate = compressed.clone();
+        candidate[0] = 0;
+        assert!(Execution::<CurrentNetwork>::from_compressed_bytes(&candidate).is_err());
+        // Ensure trailing bytes are rejected.
+        let mut candidate = compressed.clone();
+        candidate.push(0);
+        assert!(Execution::<CurrentNetwork>::from_compressed_bytes(&candidate).is_err());
+        // Ensure a truncated source is rejected.
+        assert!(Execution::<CurrentNetwork>::from_compressed_bytes(&compressed[..compressed.len() / 2]).is_err());
+        // Ensure the uncompressed bytes are rejected.
+        assert!(Execution::<CurrentNetwork>::from_compressed_bytes(&expected.to_bytes_le()?).is_err());
+        // Ensure a reader that has not read all of the transitions cannot finish.
+        let reader = CompressedExecutionReader::<CurrentNetwork, _>::new(&compressed[..])?;
+        assert!(reader.finish().is_err());
+        Ok(())
+    }
+}
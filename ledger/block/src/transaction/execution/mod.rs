@@ -12,12 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod summary;
+pub use summary::*;
+
+mod stream;
+pub use stream::*;
+
+mod compress;
+pub use compress::*;
+
 mod bytes;
 mod serialize;
 mod string;
 
 use crate::{Transaction, Transition};
-use console::{account::Field, network::prelude::*, program::ProgramID};
+use console::{
+    account::{Field, ViewKey},
+    network::prelude::*,
+    program::ProgramID,
+};
 use synthesizer_snark::Proof;
 
 use indexmap::IndexMap;
@@ -71,6 +84,23 @@ impl<N: Network> Execution<N> {
     pub fn to_execution_id(&self) -> Result<Field<N>> {
         Ok(*Transaction::execution_tree(self, &None)?.root())
     }
+
+    /// Returns the canonical ID of the execution, which binds all of its content.
+    ///
+    /// Unlike the execution ID, which only commits to the transition IDs (in order),
+    /// this ID also commits to the full transitions, the global state root, and the proof,
+    /// so any change to the byte representation of the execution changes this ID.
+    ///
+    /// Note: This ID is a reference for off-chain tooling only. It is not stored or checked by the ledger,
+    /// and consensus continues to identify executions by their transition IDs and execution ID.
+    pub fn to_id(&self) -> Result<Field<N>> {
+        // Hash the canonical byte representation of the execution.
+        let digest = N::hash_sha3_256(&self.to_bytes_le()?.to_bits_le())?;
+        // Bind the digest to the execution ID.
+        let mut preimage = self.to_execution_id()?.to_bits_le();
+        preimage.extend(digest);
+        N::hash_bhp1024(&preimage)
+    }
 }
 
 impl<N: Network> Execution<N> {
@@ -143,6 +173,17 @@ impl<N: Network> Execution<N> {
     }
 }
 
+impl<N: Network> Execution<N> {
+    /// Returns the transition view keys of the execution, as a map of `transition ID` to `tvk`,
+    /// if the given view key belongs to the signer of every transition in the execution.
+    pub fn to_transition_view_keys(&self, view_key: &ViewKey<N>) -> Result<IndexMap<N::TransitionID, Field<N>>> {
+        self.transitions
+            .iter()
+            .map(|(transition_id, transition)| Ok((*transition_id, transition.to_transition_view_key(view_key)?)))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 pub mod test_helpers {
     use super::*;
@@ -159,3 +200,88 @@ pub mod test_helpers {
         if let Transaction::Execute(_, execution, _) = transaction { execution } else { unreachable!() }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        account::{Address, PrivateKey},
+        program::{compute_function_id, Literal, Plaintext},
+        types::U16,
+    };
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_to_transition_view_keys() {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution and its private key.
+        // Note: The execution calls `credits.aleo/transfer_public_to_private`, with a private receiver.
+        let (_, transaction, private_key) = crate::test_helpers::sample_genesis_block_and_components(rng);
+        let execution = transaction.execution().unwrap();
+        let view_key = ViewKey::try_from(private_key).unwrap();
+
+        // Derive the transition view keys.
+        let tvks = execution.to_transition_view_keys(&view_key).unwrap();
+        assert_eq!(tvks.len(), execution.len());
+
+        // Ensure the transition view key decrypts the private input of the transition.
+        let transition = execution.peek().unwrap();
+        let tvk = tvks.get(transition.id()).unwrap();
+        let ciphertext = match &transition.inputs()[0] {
+            crate::Input::Private(_, Some(ciphertext)) => ciphertext,
+            _ => panic!("Expected a private input"),
+        };
+        let function_id =
+            compute_function_id(&U16::new(CurrentNetwork::ID), transition.program_id(), transition.function_name())
+                .unwrap();
+        let input_view_key = CurrentNetwork::hash_psd4(&[function_id, *tvk, Field::from_u16(0)]).unwrap();
+        let expected = Plaintext::from(Literal::Address(Address::try_from(private_key).unwrap()));
+        assert_eq!(ciphertext.decrypt_symmetric(input_view_key).unwrap(), expected);
+
+        // Ensure an unrelated view key is rejected.
+        let view_key = ViewKey::<CurrentNetwork>::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        assert!(execution.to_transition_view_keys(&view_key).is_err());
+        assert!(transition.to_transition_view_key(&view_key).is_err());
+    }
+
+    #[test]
+    fn test_to_id_binds_all_bytes() {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution.
+        let execution = test_helpers::sample_execution(rng);
+        let expected_id = execution.to_id().unwrap();
+        // Ensure the ID is deterministic.
+        assert_eq!(expected_id, execution.to_id().unwrap());
+
+        // Ensure any byte-level mutation either fails to deserialize, or changes the ID.
+        let bytes = execution.to_bytes_le().unwrap();
+        for _ in 0..128 {
+            // Flip a random bit in a random byte.
+            let mut mutated = bytes.clone();
+            let index = rng.gen_range(0..mutated.len());
+            mutated[index] ^= 1 << rng.gen_range(0..8);
+            // Check the mutated execution.
+            if let Ok(candidate) = Execution::<CurrentNetwork>::read_le(&mutated[..]) {
+                assert_ne!(expected_id, candidate.to_id().unwrap(), "Mutation at byte {index} preserved the ID");
+            }
+        }
+
+        // Ensure the global state root is bound to the ID.
+        let candidate = Execution::from(
+            execution.transitions().cloned(),
+            <CurrentNetwork as Network>::StateRoot::default(),
+            execution.proof().cloned(),
+        )
+        .unwrap();
+        assert_eq!(execution.to_execution_id().unwrap(), candidate.to_execution_id().unwrap());
+        assert_ne!(expected_id, candidate.to_id().unwrap());
+
+        // Ensure the proof is bound to the ID.
+        let candidate = Execution::from(execution.transitions().cloned(), execution.global_state_root(), None).unwrap();
+        assert_eq!(execution.to_execution_id().unwrap(), candidate.to_execution_id().unwrap());
+        assert_ne!(expected_id, candidate.to_id().unwrap());
+    }
+}
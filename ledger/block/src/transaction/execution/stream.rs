@@ -0,0 +1,278 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexSet;
+
+/// A streaming writer for an execution, which writes each transition to the sink as it becomes available.
+///
+/// The bytes written are identical to those of `Execution::write_le`,
+/// so the output may be read back with either `Execution::read_le` or an `ExecutionReader`.
+///
+/// If the execution cannot be completed (e.g. proving fails), the writer must be abandoned with `abort`,
+/// and the bytes written so far discarded by the caller.
+pub struct ExecutionWriter<N: Network, W: Write> {
+    /// The output sink.
+    writer: CountingWriter<W>,
+    /// The number of transitions in the execution.
+    num_transitions: usize,
+    /// The IDs of the transitions written so far.
+    transition_ids: IndexSet<N::TransitionID>,
+}
+
+impl<N: Network, W: Write> ExecutionWriter<N, W> {
+    /// Initializes a new streaming writer for an execution with the given number of transitions.
+    pub fn new(writer: W, num_transitions: usize) -> IoResult<Self> {
+        // Ensure the number of transitions is nonzero.
+        if num_transitions == 0 {
+            return Err(error("Execution (from 'ExecutionWriter') has no transitions"));
+        }
+        // Write the version and number of transitions.
+        let mut writer = CountingWriter { writer, num_bytes: 0 };
+        Execution::<N>::write_header(num_transitions, &mut writer)?;
+        // Return the writer.
+        Ok(Self { writer, num_transitions, transition_ids: IndexSet::with_capacity(num_transitions) })
+    }
+
+    /// Returns the number of transitions written so far.
+    pub fn num_written(&self) -> usize {
+        self.transition_ids.len()
+    }
+
+    /// Returns the number of bytes written to the sink so far.
+    pub fn num_bytes_written(&self) -> usize {
+        self.writer.num_bytes
+    }
+
+    /// Writes the next transition to the sink.
+    pub fn write_transition(&mut self, transition: &Transition<N>) -> IoResult<()> {
+        // Ensure the number of transitions is not exceeded.
+        if self.transition_ids.len() >= self.num_transitions {
+            return Err(error(format!(
+                "Execution (from 'ExecutionWriter') exceeds {} transitions",
+                self.num_transitions
+            )));
+        }
+        // Ensure the transition is not a duplicate.
+        if !self.transition_ids.insert(*transition.id()) {
+            return Err(error(format!(
+                "Execution (from 'ExecutionWriter') has a duplicate transition '{}'",
+                transition.id()
+            )));
+        }
+        // Write the transition.
+        transition.write_le(&mut self.writer)
+    }
+
+    /// Writes the global state root and proof, and returns the sink.
+    pub fn finish(mut self, global_state_root: N::StateRoot, proof: Option<&Proof<N>>) -> IoResult<W> {
+        // Ensure all of the transitions were written.
+        if self.transition_ids.len() != self.num_transitions {
+            return Err(error(format!(
+                "Execution (from 'ExecutionWriter') expected {} transitions, found {}",
+                self.num_transitions,
+                self.transition_ids.len()
+            )));
+        }
+        // Write the global state root and proof.
+        Execution::<N>::write_footer(&global_state_root, proof, &mut self.writer)?;
+        Ok(self.writer.writer)
+    }
+
+    /// Abandons the execution, and returns the sink with the number of bytes written to it by this writer.
+    ///
+    /// The bytes written so far do not form a valid execution, so the caller must discard them,
+    /// e.g. by truncating the sink to its length before the writer was initialized.
+    pub fn abort(self) -> (W, usize) {
+        (self.writer.writer, self.writer.num_bytes)
+    }
+}
+
+/// A sink that counts the number of bytes written to it.
+struct CountingWriter<W: Write> {
+    /// The output sink.
+    writer: W,
+    /// The number of bytes written to the sink.
+    num_bytes: usize,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    /// Writes the given buffer to the sink, and counts the bytes written.
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        let num_bytes = self.writer.write(buf)?;
+        self.num_bytes += num_bytes;
+        Ok(num_bytes)
+    }
+
+    /// Flushes the sink.
+    fn flush(&mut self) -> IoResult<()> {
+        self.writer.flush()
+    }
+}
+
+/// A streaming reader for an execution, which yields each transition as it is read from the source.
+///
+/// The transitions are yielded by iterating the reader, after which `finish` reads the global state root and proof.
+pub struct ExecutionReader<N: Network, R: Read> {
+    /// The input source.
+    reader: R,
+    /// The number of transitions in the execution.
+    num_transitions: usize,
+    /// The IDs of the transitions read so far.
+    transition_ids: IndexSet<N::TransitionID>,
+    /// Whether the reader has encountered an error.
+    is_poisoned: bool,
+}
+
+impl<N: Network, R: Read> ExecutionReader<N, R> {
+    /// Initializes a new streaming reader, reading the execution header from the source.
+    pub fn new(mut reader: R) -> IoResult<Self> {
+        // Read the version and number of transitions.
+        let num_transitions = Execution::<N>::read_header(&mut reader)? as usize;
+        // Ensure the number of transitions is nonzero.
+        if num_transitions == 0 {
+            return Err(error("Execution (from 'ExecutionReader') has no transitions"));
+        }
+        // Return the reader.
+        Ok(Self {
+            reader,
+            num_transitions,
+            transition_ids: IndexSet::with_capacity(num_transitions),
+            is_poisoned: false,
+        })
+    }
+
+    /// Returns the number of transitions in the execution.
+    pub fn num_transitions(&self) -> usize {
+        self.num_transitions
+    }
+
+    /// Returns the IDs of the transitions read so far.
+    pub fn transition_ids(&self) -> &IndexSet<N::TransitionID> {
+        &self.transition_ids
+    }
+
+    /// Reads the next transition from the source.
+    fn read_transition(&mut self) -> IoResult<Transition<N>> {
+        // Read the transition.
+        let transition = Transition::<N>::read_le(&mut self.reader)?;
+        // Ensure the transition is not a duplicate.
+        if !self.transition_ids.insert(*transition.id()) {
+            return Err(error(format!(
+                "Execution (from 'ExecutionReader') has a duplicate transition '{}'",
+                transition.id()
+            )));
+        }
+        Ok(transition)
+    }
+
+    /// Reads the global state root and proof, once all of the transitions have been read.
+    pub fn finish(mut self) -> IoResult<(N::StateRoot, Option<Proof<N>>)> {
+        // Ensure the reader did not encounter an error.
+        if self.is_poisoned {
+            return Err(error("Execution (from 'ExecutionReader') failed to read a transition"));
+        }
+        // Ensure all of the transitions were read.
+        if self.transition_ids.len() != self.num_transitions {
+            return Err(error(format!(
+                "Execution (from 'ExecutionReader') expected {} transitions, read {}",
+                self.num_transitions,
+                self.transition_ids.len()
+            )));
+        }
+        // Read the global state root and proof.
+        Execution::<N>::read_footer(&mut self.reader)
+    }
+}
+
+impl<N: Network, R: Read> Iterator for ExecutionReader<N, R> {
+    type Item = IoResult<Transition<N>>;
+
+    /// Reads the next transition, or returns `None` once all transitions have been read or an error occurred.
+    fn next(&mut self) -> Option<Self::Item> {
+        // Stop if all of the transitions have been read, or if a previous read failed.
+        if self.is_poisoned || self.transition_ids.len() >= self.num_transitions {
+            return None;
+        }
+        // Read the next transition.
+        let result = self.read_transition();
+        self.is_poisoned = result.is_err();
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_stream() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new execution.
+        let expected = crate::transaction::execution::test_helpers::sample_execution(rng);
+
+        // Stream the execution to bytes.
+        let mut writer = ExecutionWriter::<CurrentNetwork, _>::new(Vec::new(), expected.len())?;
+        for transition in expected.transitions() {
+            writer.write_transition(transition)?;
+        }
+        let bytes = writer.finish(expected.global_state_root(), expected.proof())?;
+        // Ensure the bytes match the standard byte representation.
+        assert_eq!(expected.to_bytes_le()?, bytes);
+
+        // Stream the execution from bytes.
+        let mut reader = ExecutionReader::<CurrentNetwork, _>::new(&bytes[..])?;
+        assert_eq!(reader.num_transitions(), expected.len());
+        let transitions = reader.by_ref().collect::<IoResult<Vec<_>>>()?;
+        let (global_state_root, proof) = reader.finish()?;
+        assert_eq!(expected, Execution::from(transitions.into_iter(), global_state_root, proof)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_rejects_malformed() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new execution.
+        let expected = crate::transaction::execution::test_helpers::sample_execution(rng);
+        let transition = expected.peek()?;
+
+        // Ensure an empty execution is rejected.
+        assert!(ExecutionWriter::<CurrentNetwork, _>::new(Vec::new(), 0).is_err());
+        // Ensure finishing early is rejected.
+        let writer = ExecutionWriter::<CurrentNetwork, _>::new(Vec::new(), expected.len() + 1)?;
+        assert!(writer.finish(expected.global_state_root(), expected.proof()).is_err());
+        // Ensure a duplicate transition is rejected.
+        let mut writer = ExecutionWriter::<CurrentNetwork, _>::new(Vec::new(), 2)?;
+        writer.write_transition(transition)?;
+        assert!(writer.write_transition(transition).is_err());
+        // Ensure an aborted writer returns the sink, with the number of bytes written.
+        let (bytes, num_bytes) = writer.abort();
+        assert_eq!(bytes.len(), num_bytes);
+        assert_eq!(num_bytes, 2 + transition.to_bytes_le()?.len());
+
+        // Ensure a reader that has not read all of the transitions cannot finish.
+        let bytes = expected.to_bytes_le()?;
+        let reader = ExecutionReader::<CurrentNetwork, _>::new(&bytes[..])?;
+        assert!(reader.finish().is_err());
+        // Ensure a truncated source is rejected.
+        let mut reader = ExecutionReader::<CurrentNetwork, _>::new(&bytes[..bytes.len() / 2])?;
+        assert!(reader.by_ref().collect::<IoResult<Vec<_>>>().is_err() || reader.finish().is_err());
+        Ok(())
+    }
+}
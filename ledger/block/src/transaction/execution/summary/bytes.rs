@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for ExecutionSummary<N> {
+    /// Reads the execution summary from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid execution summary version"));
+        }
+        // Read the number of functions.
+        let num_functions = u8::read_le(&mut reader)?;
+        // Read the functions.
+        let functions = (0..num_functions).map(|_| Locator::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the consumed records.
+        let consumed_records = read_records(&mut reader)?;
+        // Read the created records.
+        let created_records = read_records(&mut reader)?;
+        // Read the microcredits received.
+        let microcredits_received = u64::read_le(&mut reader)?;
+        // Read the microcredits spent.
+        let microcredits_spent = u64::read_le(&mut reader)?;
+        // Return the execution summary.
+        Ok(Self::new(functions, consumed_records, created_records, microcredits_received, microcredits_spent))
+    }
+}
+
+impl<N: Network> ToBytes for ExecutionSummary<N> {
+    /// Writes the execution summary to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of functions.
+        u8::try_from(self.functions.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the functions.
+        for function in &self.functions {
+            function.write_le(&mut writer)?;
+        }
+        // Write the consumed records.
+        write_records(&self.consumed_records, &mut writer)?;
+        // Write the created records.
+        write_records(&self.created_records, &mut writer)?;
+        // Write the microcredits received.
+        self.microcredits_received.write_le(&mut writer)?;
+        // Write the microcredits spent.
+        self.microcredits_spent.write_le(&mut writer)
+    }
+}
+
+/// Reads a map of `commitment` to `record` from a buffer.
+fn read_records<N: Network, R: Read>(mut reader: R) -> IoResult<IndexMap<Field<N>, Record<N, Plaintext<N>>>> {
+    // Read the number of records.
+    let num_records = u16::read_le(&mut reader)?;
+    // Read the records.
+    (0..num_records)
+        .map(|_| Ok((Field::read_le(&mut reader)?, Record::read_le(&mut reader)?)))
+        .collect::<IoResult<IndexMap<_, _>>>()
+}
+
+/// Writes a map of `commitment` to `record` to a buffer.
+fn write_records<N: Network, W: Write>(
+    records: &IndexMap<Field<N>, Record<N, Plaintext<N>>>,
+    mut writer: W,
+) -> IoResult<()> {
+    // Write the number of records.
+    u16::try_from(records.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+    // Write the records.
+    for (commitment, record) in records {
+        commitment.write_le(&mut writer)?;
+        record.write_le(&mut writer)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution summary.
+        let expected = crate::transaction::execution::summary::test_helpers::sample_execution_summary(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, ExecutionSummary::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
@@ -0,0 +1,271 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use super::*;
+use crate::Output;
+use console::{
+    account::{Address, GraphKey, ViewKey},
+    program::{Argument, Entry, Future, Identifier, Literal, Locator, Plaintext, Record},
+};
+
+use indexmap::IndexSet;
+
+/// A summary of an execution, from the perspective of the owner of a view key.
+///
+/// The summary lists the programs and functions that were invoked, the records of the owner that were
+/// consumed and created, and the movement of `credits.aleo` microcredits into and out of the owner's account.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ExecutionSummary<N: Network> {
+    /// The program and function of each transition, in execution order.
+    functions: Vec<Locator<N>>,
+    /// The records of the owner that were consumed, as a map of `commitment` to `record`.
+    consumed_records: IndexMap<Field<N>, Record<N, Plaintext<N>>>,
+    /// The records of the owner that were created, as a map of `commitment` to `record`.
+    created_records: IndexMap<Field<N>, Record<N, Plaintext<N>>>,
+    /// The number of microcredits received by the owner, from records and public balance transfers.
+    microcredits_received: u64,
+    /// The number of microcredits spent by the owner, from records and public balance transfers.
+    microcredits_spent: u64,
+}
+
+impl<N: Network> ExecutionSummary<N> {
+    /// Initializes a new execution summary.
+    pub fn new(
+        functions: Vec<Locator<N>>,
+        consumed_records: IndexMap<Field<N>, Record<N, Plaintext<N>>>,
+        created_records: IndexMap<Field<N>, Record<N, Plaintext<N>>>,
+        microcredits_received: u64,
+        microcredits_spent: u64,
+    ) -> Self {
+        Self { functions, consumed_records, created_records, microcredits_received, microcredits_spent }
+    }
+
+    /// Analyzes the given execution, from the perspective of the owner of the given view key.
+    ///
+    /// The inputs of a transition only reveal the tags of the consumed records, so the consumed records
+    /// are identified from the given `known_records` (a map of `commitment` to `record`) of the owner.
+    /// The created records are identified and decrypted with the view key.
+    pub fn analyze(
+        execution: &Execution<N>,
+        view_key: &ViewKey<N>,
+        known_records: &IndexMap<Field<N>, Record<N, Plaintext<N>>>,
+    ) -> Result<Self> {
+        // Derive the address and graph key of the owner.
+        let address = view_key.to_address();
+        let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
+        // Compute the tag of each known record.
+        let tags = known_records
+            .keys()
+            .map(|commitment| Ok((Record::<N, Plaintext<N>>::tag(sk_tag, *commitment)?, *commitment)))
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        // Prepare the 'credits.aleo' program ID.
+        let credits_program_id = ProgramID::from_str("credits.aleo")?;
+
+        let mut functions = Vec::with_capacity(execution.len());
+        let mut consumed_records = IndexMap::new();
+        let mut created_records = IndexMap::new();
+        let mut microcredits_received = 0u64;
+        let mut microcredits_spent = 0u64;
+
+        for transition in execution.transitions() {
+            // Determine if the transition moves microcredits.
+            let is_credits = transition.program_id() == &credits_program_id;
+            // Store the program and function.
+            functions.push(Locator::new(*transition.program_id(), *transition.function_name()));
+
+            // Collect the consumed records of the owner.
+            for tag in transition.tags() {
+                if let Some((commitment, record)) = tags.get(tag).and_then(|cm| known_records.get_key_value(cm)) {
+                    if is_credits {
+                        microcredits_spent = add_microcredits(microcredits_spent, microcredits(record)?)?;
+                    }
+                    consumed_records.insert(*commitment, record.clone());
+                }
+            }
+
+            // Collect the created records of the owner.
+            for (commitment, record) in transition.records() {
+                if record.is_owner(view_key) {
+                    let record = record.decrypt(view_key)?;
+                    if is_credits {
+                        microcredits_received = add_microcredits(microcredits_received, microcredits(&record)?)?;
+                    }
+                    created_records.insert(*commitment, record);
+                }
+            }
+
+            // Collect the public balance transfers of the owner.
+            if is_credits {
+                for future in transition.outputs().iter().filter_map(Output::future) {
+                    if let Some((sender, receiver, amount)) = public_transfer(future) {
+                        if sender == Some(address) {
+                            microcredits_spent = add_microcredits(microcredits_spent, amount)?;
+                        }
+                        if receiver == Some(address) {
+                            microcredits_received = add_microcredits(microcredits_received, amount)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Self::new(functions, consumed_records, created_records, microcredits_received, microcredits_spent))
+    }
+
+    /// Returns the program and function of each transition, in execution order.
+    pub fn functions(&self) -> &[Locator<N>] {
+        &self.functions
+    }
+
+    /// Returns the unique programs involved in the execution, in execution order.
+    pub fn programs(&self) -> impl '_ + Iterator<Item = &ProgramID<N>> {
+        self.functions.iter().map(Locator::program_id).collect::<IndexSet<_>>().into_iter()
+    }
+
+    /// Returns the records of the owner that were consumed, as a map of `commitment` to `record`.
+    pub const fn consumed_records(&self) -> &IndexMap<Field<N>, Record<N, Plaintext<N>>> {
+        &self.consumed_records
+    }
+
+    /// Returns the records of the owner that were created, as a map of `commitment` to `record`.
+    pub const fn created_records(&self) -> &IndexMap<Field<N>, Record<N, Plaintext<N>>> {
+        &self.created_records
+    }
+
+    /// Returns the number of microcredits received by the owner.
+    pub const fn microcredits_received(&self) -> u64 {
+        self.microcredits_received
+    }
+
+    /// Returns the number of microcredits spent by the owner.
+    pub const fn microcredits_spent(&self) -> u64 {
+        self.microcredits_spent
+    }
+
+    /// Returns the net change in microcredits for the owner.
+    pub fn net_microcredits(&self) -> i128 {
+        i128::from(self.microcredits_received) - i128::from(self.microcredits_spent)
+    }
+}
+
+impl<N: Network> Execution<N> {
+    /// Returns a summary of the execution, from the perspective of the owner of the given view key.
+    /// See `ExecutionSummary::analyze` for details.
+    pub fn summarize(
+        &self,
+        view_key: &ViewKey<N>,
+        known_records: &IndexMap<Field<N>, Record<N, Plaintext<N>>>,
+    ) -> Result<ExecutionSummary<N>> {
+        ExecutionSummary::analyze(self, view_key, known_records)
+    }
+}
+
+/// Returns the number of microcredits in the given `credits.aleo` record.
+fn microcredits<N: Network>(record: &Record<N, Plaintext<N>>) -> Result<u64> {
+    match record.data().get(&Identifier::from_str("microcredits")?) {
+        Some(Entry::Private(Plaintext::Literal(Literal::U64(microcredits), _))) => Ok(**microcredits),
+        _ => bail!("Invalid 'credits.aleo' record (missing microcredits)"),
+    }
+}
+
+/// Returns the sum of the given microcredits, or an error on overflow.
+fn add_microcredits(total: u64, microcredits: u64) -> Result<u64> {
+    total.checked_add(microcredits).ok_or_else(|| anyhow!("Overflow in the execution summary microcredits"))
+}
+
+/// Returns the `(sender, receiver, amount)` of a `credits.aleo` public balance transfer, if the future is one.
+fn public_transfer<N: Network>(future: &Future<N>) -> Option<(Option<Address<N>>, Option<Address<N>>, u64)> {
+    // Returns the argument as an address.
+    let to_address = |argument: &Argument<N>| match argument {
+        Argument::Plaintext(Plaintext::Literal(Literal::Address(address), _)) => Some(*address),
+        _ => None,
+    };
+    // Returns the argument as a u64.
+    let to_u64 = |argument: &Argument<N>| match argument {
+        Argument::Plaintext(Plaintext::Literal(Literal::U64(amount), _)) => Some(**amount),
+        _ => None,
+    };
+
+    match (future.function_name().to_string().as_str(), future.arguments()) {
+        ("transfer_public" | "transfer_public_as_signer", [sender, receiver, amount]) => {
+            Some((Some(to_address(sender)?), Some(to_address(receiver)?), to_u64(amount)?))
+        }
+        ("transfer_private_to_public", [receiver, amount]) => {
+            Some((None, Some(to_address(receiver)?), to_u64(amount)?))
+        }
+        ("transfer_public_to_private" | "fee_public", [sender, amount]) => {
+            Some((Some(to_address(sender)?), None, to_u64(amount)?))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Samples the summary of a random execution, for the owner of the execution.
+    pub(crate) fn sample_execution_summary(rng: &mut TestRng) -> ExecutionSummary<CurrentNetwork> {
+        // Sample the execution and its private key.
+        let (_, transaction, private_key) = crate::test_helpers::sample_genesis_block_and_components(rng);
+        let execution = transaction.execution().unwrap();
+        // Summarize the execution.
+        execution.summarize(&ViewKey::try_from(private_key).unwrap(), &IndexMap::new()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_analyze() {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution and its private key.
+        // Note: The execution calls `credits.aleo/transfer_public_to_private` with 100 credits, to the same account.
+        let (_, transaction, private_key) = crate::test_helpers::sample_genesis_block_and_components(rng);
+        let execution = transaction.execution().unwrap();
+        let view_key = ViewKey::try_from(private_key).unwrap();
+
+        // Summarize the execution.
+        let summary = execution.summarize(&view_key, &IndexMap::new()).unwrap();
+        assert_eq!(summary.functions().len(), 1);
+        assert_eq!(summary.functions()[0].to_string(), "credits.aleo/transfer_public_to_private");
+        assert_eq!(summary.programs().map(ToString::to_string).collect::<Vec<_>>(), vec!["credits.aleo"]);
+        assert!(summary.consumed_records().is_empty());
+        assert_eq!(summary.created_records().len(), 1);
+        assert_eq!(summary.microcredits_received(), 100_000_000);
+        assert_eq!(summary.microcredits_spent(), 100_000_000);
+        assert_eq!(summary.net_microcredits(), 0);
+
+        // Ensure an unrelated account sees no activity.
+        let view_key = ViewKey::<CurrentNetwork>::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        let summary = execution.summarize(&view_key, &IndexMap::new()).unwrap();
+        assert_eq!(summary.functions().len(), 1);
+        assert!(summary.consumed_records().is_empty());
+        assert!(summary.created_records().is_empty());
+        assert_eq!(summary.net_microcredits(), 0);
+    }
+}
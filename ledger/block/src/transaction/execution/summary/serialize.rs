@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for ExecutionSummary<N> {
+    /// Serializes the execution summary into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut summary = serializer.serialize_struct("ExecutionSummary", 5)?;
+                summary.serialize_field("functions", &self.functions)?;
+                summary.serialize_field("consumed_records", &self.consumed_records)?;
+                summary.serialize_field("created_records", &self.created_records)?;
+                summary.serialize_field("microcredits_received", &self.microcredits_received)?;
+                summary.serialize_field("microcredits_spent", &self.microcredits_spent)?;
+                summary.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ExecutionSummary<N> {
+    /// Deserializes the execution summary from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the execution summary from a string into a value.
+                let mut summary = serde_json::Value::deserialize(deserializer)?;
+                // Recover the execution summary.
+                Ok(Self::new(
+                    // Retrieve the functions.
+                    DeserializeExt::take_from_value::<D>(&mut summary, "functions")?,
+                    // Retrieve the consumed records.
+                    DeserializeExt::take_from_value::<D>(&mut summary, "consumed_records")?,
+                    // Retrieve the created records.
+                    DeserializeExt::take_from_value::<D>(&mut summary, "created_records")?,
+                    // Retrieve the microcredits received.
+                    DeserializeExt::take_from_value::<D>(&mut summary, "microcredits_received")?,
+                    // Retrieve the microcredits spent.
+                    DeserializeExt::take_from_value::<D>(&mut summary, "microcredits_spent")?,
+                ))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "execution summary"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution summary.
+        let expected = crate::transaction::execution::summary::test_helpers::sample_execution_summary(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, ExecutionSummary::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution summary.
+        let expected = crate::transaction::execution::summary::test_helpers::sample_execution_summary(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, ExecutionSummary::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
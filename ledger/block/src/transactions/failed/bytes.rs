@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use super::*;
+
+impl<N: Network> FromBytes for FailedExecution<N> {
+    /// Reads the failed execution from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid failed execution version"));
+        }
+        // Read the execution.
+        let execution = Execution::read_le(&mut reader)?;
+        // Read the fee.
+        let fee = Fee::read_le(&mut reader)?;
+        // Read the storage cost.
+        let storage_cost = u64::read_le(&mut reader)?;
+        // Read the finalize cost.
+        let finalize_cost = u64::read_le(&mut reader)?;
+        // Return the failed execution.
+        Self::new(execution, fee, storage_cost, finalize_cost).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for FailedExecution<N> {
+    /// Writes the failed execution to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the execution.
+        self.execution.write_le(&mut writer)?;
+        // Write the fee.
+        self.fee.write_le(&mut writer)?;
+        // Write the storage cost.
+        self.storage_cost.write_le(&mut writer)?;
+        // Write the finalize cost.
+        self.finalize_cost.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        let rng = &mut TestRng::default();
+
+        for is_fee_private in [true, false] {
+            let expected =
+                crate::transactions::failed::test_helpers::sample_failed_execution(is_fee_private, 1_000, rng).unwrap();
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, FailedExecution::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
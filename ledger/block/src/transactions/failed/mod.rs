@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use super::*;
+
+use crate::{Execution, Fee};
+
+/// A container for an execution whose proofs are valid, but whose finalize scope aborted.
+/// It holds the storage cost and the finalize cost of the execution, which its fee must cover.
+/// Note: The fee of a rejected execution is consumed in full, so there is no refund of the remainder.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FailedExecution<N: Network> {
+    /// The failed execution.
+    execution: Execution<N>,
+    /// The fee for the execution.
+    fee: Fee<N>,
+    /// The storage cost, in microcredits.
+    storage_cost: u64,
+    /// The finalize cost, in microcredits.
+    finalize_cost: u64,
+}
+
+impl<N: Network> FailedExecution<N> {
+    /// Initializes a new failed execution.
+    pub fn new(execution: Execution<N>, fee: Fee<N>, storage_cost: u64, finalize_cost: u64) -> Result<Self> {
+        // Ensure the fee is for the given execution.
+        ensure!(
+            fee.deployment_or_execution_id()? == execution.to_execution_id()?,
+            "The fee does not correspond to the failed execution"
+        );
+        // Ensure the storage cost matches the size of the execution.
+        let expected_storage_cost = execution.size_in_bytes()?;
+        ensure!(
+            storage_cost == expected_storage_cost,
+            "The storage cost of the failed execution is incorrect. Expected {expected_storage_cost}, but found {storage_cost}"
+        );
+        // Compute the total cost.
+        let total_cost = storage_cost
+            .checked_add(finalize_cost)
+            .ok_or_else(|| anyhow!("The total cost overflowed for a failed execution"))?;
+        // Ensure the total cost does not exceed the fee amount.
+        let fee_amount = *fee.amount()?;
+        ensure!(
+            total_cost <= fee_amount,
+            "The total cost ({total_cost}) exceeds the fee amount ({fee_amount}) of the failed execution"
+        );
+        // Return the failed execution.
+        Ok(Self { execution, fee, storage_cost, finalize_cost })
+    }
+
+    /// Returns the failed execution.
+    pub const fn execution(&self) -> &Execution<N> {
+        &self.execution
+    }
+
+    /// Returns the fee.
+    pub const fn fee(&self) -> &Fee<N> {
+        &self.fee
+    }
+
+    /// Returns the storage cost, in microcredits.
+    pub const fn storage_cost(&self) -> u64 {
+        self.storage_cost
+    }
+
+    /// Returns the finalize cost, in microcredits.
+    pub const fn finalize_cost(&self) -> u64 {
+        self.finalize_cost
+    }
+
+    /// Returns the total cost (storage cost + finalize cost), in microcredits.
+    pub fn total_cost(&self) -> u64 {
+        // Note: This is safe, as the sum is checked to not overflow upon initialization.
+        self.storage_cost.saturating_add(self.finalize_cost)
+    }
+
+    /// Ensures the consumed finalize cost matches the given finalize cost,
+    /// which is expected to be recomputed locally from the execution.
+    pub fn check_finalize_cost(&self, expected_finalize_cost: u64) -> Result<()> {
+        ensure!(
+            self.finalize_cost == expected_finalize_cost,
+            "The finalize cost of the failed execution is incorrect. Expected {expected_finalize_cost}, but found {}",
+            self.finalize_cost
+        );
+        Ok(())
+    }
+
+    /// Returns the failed execution ID.
+    pub fn to_id(&self) -> Result<Field<N>> {
+        self.execution.to_execution_id()
+    }
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples a failed execution, with the given finalize cost.
+    pub(crate) fn sample_failed_execution(
+        is_fee_private: bool,
+        finalize_cost: u64,
+        rng: &mut TestRng,
+    ) -> Result<FailedExecution<CurrentNetwork>> {
+        // Sample an execute transaction.
+        let (execution, fee) =
+            match crate::transaction::test_helpers::sample_execution_transaction_with_fee(is_fee_private, rng) {
+                Transaction::Execute(_, execution, Some(fee)) => (execution, fee),
+                _ => unreachable!(),
+            };
+        // Compute the storage cost.
+        let storage_cost = execution.size_in_bytes()?;
+        // Return the failed execution.
+        FailedExecution::new(execution, fee, storage_cost, finalize_cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failed_execution_cost() {
+        let rng = &mut TestRng::default();
+
+        for is_fee_private in [true, false] {
+            // Sample a failed execution that consumes no finalize cost.
+            let failed = test_helpers::sample_failed_execution(is_fee_private, 0, rng).unwrap();
+            let fee_amount = *failed.fee().amount().unwrap();
+
+            // Ensure the total cost is the storage cost, and is covered by the fee amount.
+            assert_eq!(failed.total_cost(), failed.storage_cost());
+            assert!(failed.total_cost() <= fee_amount);
+            assert!(failed.check_finalize_cost(0).is_ok());
+            assert!(failed.check_finalize_cost(1).is_err());
+
+            // Ensure the storage cost must match the size of the execution.
+            let execution = failed.execution().clone();
+            let fee = failed.fee().clone();
+            let storage_cost = failed.storage_cost();
+            assert!(FailedExecution::new(execution.clone(), fee.clone(), storage_cost + 1, 0).is_err());
+
+            // Ensure the total cost may not exceed the fee amount.
+            let remaining = fee_amount - storage_cost;
+            let failed = FailedExecution::new(execution.clone(), fee.clone(), storage_cost, remaining).unwrap();
+            assert_eq!(failed.total_cost(), fee_amount);
+            assert!(FailedExecution::new(execution, fee, storage_cost, remaining + 1).is_err());
+        }
+    }
+}
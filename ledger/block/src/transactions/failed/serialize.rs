@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use super::*;
+
+impl<N: Network> Serialize for FailedExecution<N> {
+    /// Serializes the failed execution into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut object = serializer.serialize_struct("FailedExecution", 4)?;
+                object.serialize_field("execution", &self.execution)?;
+                object.serialize_field("fee", &self.fee)?;
+                object.serialize_field("storage_cost", &self.storage_cost)?;
+                object.serialize_field("finalize_cost", &self.finalize_cost)?;
+                object.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for FailedExecution<N> {
+    /// Deserializes the failed execution from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the failed execution from a string into a value.
+                let mut object = serde_json::Value::deserialize(deserializer)?;
+
+                // Parse the execution.
+                let execution: Execution<N> = DeserializeExt::take_from_value::<D>(&mut object, "execution")?;
+                // Parse the fee.
+                let fee: Fee<N> = DeserializeExt::take_from_value::<D>(&mut object, "fee")?;
+                // Parse the storage cost.
+                let storage_cost: u64 = DeserializeExt::take_from_value::<D>(&mut object, "storage_cost")?;
+                // Parse the finalize cost.
+                let finalize_cost: u64 = DeserializeExt::take_from_value::<D>(&mut object, "finalize_cost")?;
+
+                // Return the failed execution.
+                Self::new(execution, fee, storage_cost, finalize_cost).map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "failed execution"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        let rng = &mut TestRng::default();
+
+        for is_fee_private in [true, false] {
+            let expected =
+                crate::transactions::failed::test_helpers::sample_failed_execution(is_fee_private, 1_000, rng).unwrap();
+
+            // Serialize
+            let expected_string = expected.to_string();
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+            assert_eq!(expected_string, candidate_string);
+
+            // Deserialize
+            assert_eq!(expected, FailedExecution::from_str(&expected_string).unwrap());
+        }
+    }
+}
@@ -15,6 +15,9 @@
 pub mod confirmed;
 pub use confirmed::*;
 
+pub mod failed;
+pub use failed::*;
+
 pub mod rejected;
 pub use rejected::*;
 
@@ -24,6 +24,7 @@ mod serialize;
 mod string;
 
 use console::{
+    account::ViewKey,
     network::prelude::*,
     program::{
         compute_function_id,
@@ -79,6 +80,10 @@ impl<N: Network> Transition<N> {
         scm: Field<N>,
     ) -> Result<Self> {
         // Compute the transition ID.
+        // Note: The network ID is not hashed into the transition ID, as this would change the ID of every existing
+        // transition (including those in the genesis block). Instead, the transition is bound to its network through
+        // the function ID, which commits to the network ID, and to which the request signature, the input and output
+        // IDs, and the proof commit.
         let function_tree = Self::function_tree(&inputs, &outputs)?;
         let id = N::hash_bhp512(&(*function_tree.root(), tcm).to_bits_le())?;
         // Return the transition.
@@ -394,6 +399,19 @@ impl<N: Network> Transition<N> {
     }
 }
 
+impl<N: Network> Transition<N> {
+    /// Returns the transition view key, if the given view key belongs to the signer of this transition.
+    /// Note: The transition view key only decrypts the private inputs and outputs of this transition.
+    pub fn to_transition_view_key(&self, view_key: &ViewKey<N>) -> Result<Field<N>> {
+        // Compute the transition view key `tvk` as `view_key * tpk`.
+        let tvk = (self.tpk * **view_key).to_x_coordinate();
+        // Ensure the transition commitment matches, i.e. the view key belongs to the signer.
+        ensure!(N::hash_psd2(&[tvk])? == self.tcm, "The view key is not the signer of transition '{}'", self.id);
+        // Return the transition view key.
+        Ok(tvk)
+    }
+}
+
 impl<N: Network> Transition<N> {
     /* Input */
 
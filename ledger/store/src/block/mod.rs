@@ -1191,6 +1191,26 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn find_block_height_from_solution_id(&self, solution_id: &SolutionID<N>) -> Result<Option<u32>> {
         self.storage.find_block_height_from_solution_id(solution_id)
     }
+
+    /// Returns the transaction ID that produced the given record `commitment`.
+    pub fn find_transaction_id_from_commitment(&self, commitment: &Field<N>) -> Result<Option<N::TransactionID>> {
+        match self.storage.transition_store().find_transition_id_from_commitment(commitment)? {
+            Some(transition_id) => {
+                self.storage.transaction_store().find_transaction_id_from_transition_id(&transition_id)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the transaction ID that consumed the given record `serial number`.
+    pub fn find_transaction_id_from_serial_number(&self, serial_number: &Field<N>) -> Result<Option<N::TransactionID>> {
+        match self.storage.transition_store().find_transition_id_from_serial_number(serial_number)? {
+            Some(transition_id) => {
+                self.storage.transaction_store().find_transaction_id_from_transition_id(&transition_id)
+            }
+            None => Ok(None),
+        }
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -1199,6 +1219,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         (*self.tree.read().root()).into()
     }
 
+    /// Returns the current block height, or `0` if the block store is empty.
+    pub fn current_block_height(&self) -> u32 {
+        u32::try_from(self.tree.read().number_of_leaves()).map_or(u32::MAX, |num_leaves| num_leaves.saturating_sub(1))
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.storage.get_state_root(block_height)
@@ -1444,6 +1469,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_find_transaction_id_from_commitment_and_serial_number() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        assert!(block.transactions().commitments().count() > 0, "This test must be run with at least one record.");
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+        for commitment in block.transactions().commitments() {
+            // Ensure the transaction ID is not found.
+            assert_eq!(None, block_store.find_transaction_id_from_commitment(commitment).unwrap());
+        }
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        for transaction in block.transactions().iter() {
+            for commitment in transaction.commitments() {
+                // Find the transaction ID that produced the record.
+                let candidate = block_store.find_transaction_id_from_commitment(commitment).unwrap();
+                assert_eq!(Some(transaction.id()), candidate);
+                // Ensure the commitment is not found as a serial number.
+                assert_eq!(None, block_store.find_transaction_id_from_serial_number(commitment).unwrap());
+            }
+            for serial_number in transaction.serial_numbers() {
+                // Find the transaction ID that consumed the record.
+                let candidate = block_store.find_transaction_id_from_serial_number(serial_number).unwrap();
+                assert_eq!(Some(transaction.id()), candidate);
+            }
+        }
+
+        // Ensure an unknown commitment or serial number is not found.
+        let unknown = Field::rand(rng);
+        assert_eq!(None, block_store.find_transaction_id_from_commitment(&unknown).unwrap());
+        assert_eq!(None, block_store.find_transaction_id_from_serial_number(&unknown).unwrap());
+    }
+
     #[test]
     fn test_get_transaction() {
         let rng = &mut TestRng::default();
@@ -111,6 +111,7 @@ pub enum DeploymentMap {
     Program = DataID::DeploymentProgramMap as u16,
     VerifyingKey = DataID::DeploymentVerifyingKeyMap as u16,
     Certificate = DataID::DeploymentCertificateMap as u16,
+    ContentHash = DataID::DeploymentContentHashMap as u16,
 }
 
 /// The RocksDB map prefix for execution-related entries.
@@ -292,6 +293,8 @@ enum DataID {
     // Program
     ProgramIDMap,
     KeyValueMap,
+    // Deployment (continued)
+    DeploymentContentHashMap,
 
     // Testing
     #[cfg(test)]
@@ -23,6 +23,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{Identifier, ProgramID, ProgramOwner},
+    types::Field,
 };
 use ledger_block::{Deployment, Fee, Transaction};
 use synthesizer_program::Program;
@@ -49,6 +50,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     type VerifyingKeyMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     /// The mapping of `(program ID, function name, edition)` to `certificate`.
     type CertificateMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    /// The mapping of `program content hash` to `(program ID, edition)`.
+    type ContentHashMap: for<'a> Map<'a, Field<N>, (ProgramID<N>, u16)>;
     /// The fee storage.
     type FeeStorage: FeeStorage<N>;
 
@@ -69,6 +72,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     fn verifying_key_map(&self) -> &Self::VerifyingKeyMap;
     /// Returns the certificate map.
     fn certificate_map(&self) -> &Self::CertificateMap;
+    /// Returns the content hash map.
+    fn content_hash_map(&self) -> &Self::ContentHashMap;
     /// Returns the fee storage.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage>;
 
@@ -86,6 +91,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().start_atomic();
         self.verifying_key_map().start_atomic();
         self.certificate_map().start_atomic();
+        self.content_hash_map().start_atomic();
         self.fee_store().start_atomic();
     }
 
@@ -98,6 +104,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             || self.program_map().is_atomic_in_progress()
             || self.verifying_key_map().is_atomic_in_progress()
             || self.certificate_map().is_atomic_in_progress()
+            || self.content_hash_map().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
     }
 
@@ -110,6 +117,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().atomic_checkpoint();
         self.verifying_key_map().atomic_checkpoint();
         self.certificate_map().atomic_checkpoint();
+        self.content_hash_map().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
     }
 
@@ -122,6 +130,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().clear_latest_checkpoint();
         self.verifying_key_map().clear_latest_checkpoint();
         self.certificate_map().clear_latest_checkpoint();
+        self.content_hash_map().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
     }
 
@@ -134,6 +143,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().atomic_rewind();
         self.verifying_key_map().atomic_rewind();
         self.certificate_map().atomic_rewind();
+        self.content_hash_map().atomic_rewind();
         self.fee_store().atomic_rewind();
     }
 
@@ -146,6 +156,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().abort_atomic();
         self.verifying_key_map().abort_atomic();
         self.certificate_map().abort_atomic();
+        self.content_hash_map().abort_atomic();
         self.fee_store().abort_atomic();
     }
 
@@ -158,6 +169,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().finish_atomic()?;
         self.verifying_key_map().finish_atomic()?;
         self.certificate_map().finish_atomic()?;
+        self.content_hash_map().finish_atomic()?;
         self.fee_store().finish_atomic()
     }
 
@@ -181,6 +193,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         let program = deployment.program();
         // Retrieve the program ID.
         let program_id = *program.id();
+        // Compute the program content hash.
+        let content_hash = program.to_content_hash()?;
 
         atomic_batch_scope!(self, {
             // Store the program ID.
@@ -194,6 +208,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             self.owner_map().insert((program_id, edition), *owner)?;
             // Store the program.
             self.program_map().insert((program_id, edition), program.clone())?;
+            // Store the program content hash.
+            self.content_hash_map().insert(content_hash, (program_id, edition))?;
 
             // Store the verifying keys and certificates.
             for (function_name, (verifying_key, certificate)) in deployment.verifying_keys() {
@@ -227,6 +243,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             Some(program) => cow_to_cloned!(program),
             None => bail!("Failed to locate program '{program_id}' for transaction '{transaction_id}'"),
         };
+        // Compute the program content hash.
+        let content_hash = program.to_content_hash()?;
 
         atomic_batch_scope!(self, {
             // Remove the program ID.
@@ -240,6 +258,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             self.owner_map().remove(&(program_id, edition))?;
             // Remove the program.
             self.program_map().remove(&(program_id, edition))?;
+            // Remove the program content hash.
+            self.content_hash_map().remove(&content_hash)?;
 
             // Remove the verifying keys and certificates.
             for function_name in program.functions().keys() {
@@ -256,6 +276,32 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Indexes the content hash of every stored program that is missing from the content hash map.
+    /// This backfills stores that were written before the content hash map was introduced.
+    fn backfill_content_hashes(&self) -> Result<()> {
+        // If every program is already indexed, return early.
+        if self.content_hash_map().len_confirmed() == self.program_map().len_confirmed() {
+            return Ok(());
+        }
+
+        // Compute the content hashes of the programs that are not indexed.
+        let mut missing = Vec::new();
+        for (program_id_and_edition, program) in self.program_map().iter_confirmed() {
+            let content_hash = program.to_content_hash()?;
+            if !self.content_hash_map().contains_key_confirmed(&content_hash)? {
+                missing.push((content_hash, cow_to_copied!(program_id_and_edition)));
+            }
+        }
+
+        atomic_batch_scope!(self, {
+            // Store the missing program content hashes.
+            for (content_hash, program_id_and_edition) in missing {
+                self.content_hash_map().insert(content_hash, program_id_and_edition)?;
+            }
+            Ok(())
+        })
+    }
+
     /// Returns the transaction ID that contains the given `program ID`.
     fn find_transaction_id_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
         // Check if the program ID is for 'credits.aleo'.
@@ -285,6 +331,15 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.fee_store().find_transaction_id_from_transition_id(transition_id)
     }
 
+    /// Returns the `(program ID, edition)` for the given program `content hash`.
+    /// Note: 'credits.aleo' is not indexed, as it is not deployed.
+    fn find_program_id_from_content_hash(&self, content_hash: &Field<N>) -> Result<Option<(ProgramID<N>, u16)>> {
+        match self.content_hash_map().get_confirmed(content_hash)? {
+            Some(program_id_and_edition) => Ok(Some(cow_to_copied!(program_id_and_edition))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the program ID for the given `transaction ID`.
     fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         // Retrieve the program ID.
@@ -330,6 +385,20 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the program for the given program `content hash`.
+    fn get_program_from_content_hash(&self, content_hash: &Field<N>) -> Result<Option<Program<N>>> {
+        // Retrieve the program ID and edition.
+        let (program_id, edition) = match self.find_program_id_from_content_hash(content_hash)? {
+            Some(program_id_and_edition) => program_id_and_edition,
+            None => return Ok(None),
+        };
+        // Retrieve the program.
+        match self.program_map().get_confirmed(&(program_id, edition))? {
+            Some(program) => Ok(Some(cow_to_cloned!(program))),
+            None => bail!("Failed to get program '{program_id}' (edition {edition}) for content hash '{content_hash}'"),
+        }
+    }
+
     /// Returns the verifying key for the given `program ID` and `function name`.
     fn get_verifying_key(
         &self,
@@ -499,6 +568,8 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn open(fee_store: FeeStore<N, D::FeeStorage>) -> Result<Self> {
         // Initialize the deployment storage.
         let storage = D::open(fee_store)?;
+        // Index the content hashes of any programs stored before the content hash map was introduced.
+        storage.backfill_content_hashes()?;
         // Return the deployment store.
         Ok(Self { storage, _phantom: PhantomData })
     }
@@ -585,6 +656,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_program(program_id)
     }
 
+    /// Returns the program for the given program `content hash`.
+    pub fn get_program_from_content_hash(&self, content_hash: &Field<N>) -> Result<Option<Program<N>>> {
+        self.storage.get_program_from_content_hash(content_hash)
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
@@ -622,6 +698,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     ) -> Result<Option<N::TransactionID>> {
         self.storage.find_transaction_id_from_transition_id(transition_id)
     }
+
+    /// Returns the `(program ID, edition)` for the given program `content hash`.
+    pub fn find_program_id_from_content_hash(&self, content_hash: &Field<N>) -> Result<Option<(ProgramID<N>, u16)>> {
+        self.storage.find_program_id_from_content_hash(content_hash)
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
@@ -629,6 +710,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn contains_program_id(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.storage.edition_map().contains_key_confirmed(program_id)
     }
+
+    /// Returns `true` if the given program content hash exists.
+    pub fn contains_program_content_hash(&self, content_hash: &Field<N>) -> Result<bool> {
+        self.storage.content_hash_map().contains_key_confirmed(content_hash)
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
@@ -758,4 +844,58 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_find_program_from_content_hash() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transactions.
+        let transaction_0 = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let transaction_1 = ledger_test_helpers::sample_deployment_transaction(false, rng);
+        let transactions = vec![transaction_0, transaction_1];
+
+        for transaction in transactions {
+            let transaction_id = transaction.id();
+            let program = match transaction {
+                Transaction::Deploy(_, _, ref deployment, _) => deployment.program().clone(),
+                _ => panic!("Incorrect transaction type"),
+            };
+            let content_hash = program.to_content_hash().unwrap();
+
+            // Initialize a new transition store.
+            let transition_store = TransitionStore::open(None).unwrap();
+            // Initialize a new fee store.
+            let fee_store = FeeStore::open(transition_store).unwrap();
+            // Initialize a new deployment store.
+            let deployment_store = DeploymentMemory::open(fee_store).unwrap();
+
+            // Ensure the program is not found.
+            let candidate = deployment_store.get_program_from_content_hash(&content_hash).unwrap();
+            assert_eq!(None, candidate);
+
+            // Insert the deployment.
+            deployment_store.insert(&transaction).unwrap();
+
+            // Find the program.
+            assert!(deployment_store.contains_program_content_hash(&content_hash).unwrap());
+            let candidate = deployment_store.get_program_from_content_hash(&content_hash).unwrap();
+            assert_eq!(Some(program.clone()), candidate);
+
+            // Drop the index entry, as in a store written before the content hash map was introduced.
+            deployment_store.storage.content_hash_map().remove(&content_hash).unwrap();
+            assert!(!deployment_store.contains_program_content_hash(&content_hash).unwrap());
+            // Ensure the backfill restores the index entry.
+            deployment_store.storage.backfill_content_hashes().unwrap();
+            let candidate = deployment_store.get_program_from_content_hash(&content_hash).unwrap();
+            assert_eq!(Some(program), candidate);
+
+            // Remove the deployment.
+            deployment_store.remove(&transaction_id).unwrap();
+
+            // Ensure the program is not found.
+            assert!(!deployment_store.contains_program_content_hash(&content_hash).unwrap());
+            let candidate = deployment_store.get_program_from_content_hash(&content_hash).unwrap();
+            assert_eq!(None, candidate);
+        }
+    }
 }
@@ -31,6 +31,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{Identifier, ProgramID},
+    types::Field,
 };
 use ledger_block::{Deployment, Execution, Transaction};
 use synthesizer_program::Program;
@@ -390,6 +391,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().get_program(program_id)
     }
 
+    /// Returns the program for the given program `content hash`.
+    pub fn get_program_from_content_hash(&self, content_hash: &Field<N>) -> Result<Option<Program<N>>> {
+        self.storage.deployment_store().get_program_from_content_hash(content_hash)
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
@@ -415,6 +421,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().find_transaction_id_from_program_id(program_id)
     }
 
+    /// Returns the `(program ID, edition)` for the given program `content hash`.
+    pub fn find_program_id_from_content_hash(&self, content_hash: &Field<N>) -> Result<Option<(ProgramID<N>, u16)>> {
+        self.storage.deployment_store().find_program_id_from_content_hash(content_hash)
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     pub fn find_transaction_id_from_transition_id(
         &self,
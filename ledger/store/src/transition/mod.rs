@@ -388,6 +388,26 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         // Throw an error.
         bail!("Failed to find the transition ID for the given input or output ID '{id}'")
     }
+
+    /// Returns the transition ID that produced the given record `commitment`.
+    pub fn find_transition_id_from_commitment(&self, commitment: &Field<N>) -> Result<Option<N::TransitionID>> {
+        // Ensure the commitment is a record output.
+        if !self.outputs.contains_commitment(commitment)? {
+            return Ok(None);
+        }
+        // Note: The output ID of a record output is its commitment.
+        self.outputs.find_transition_id(commitment)
+    }
+
+    /// Returns the transition ID that consumed the given record `serial number`.
+    pub fn find_transition_id_from_serial_number(&self, serial_number: &Field<N>) -> Result<Option<N::TransitionID>> {
+        // Ensure the serial number is a record input.
+        if !self.inputs.contains_serial_number(serial_number)? {
+            return Ok(None);
+        }
+        // Note: The input ID of a record input is its serial number.
+        self.inputs.find_transition_id(serial_number)
+    }
 }
 
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
@@ -14,14 +14,23 @@
 
 #![forbid(unsafe_code)]
 
-const GAUGE_NAMES: [&str; 1] = [committee::TOTAL_STAKE];
+const COUNTER_NAMES: [&str; 1] = [process::REJECTED_BY_LIMITS];
+const GAUGE_NAMES: [&str; 2] = [committee::TOTAL_STAKE, process::PROGRAMS];
 
 pub mod committee {
     pub const TOTAL_STAKE: &str = "snarkvm_ledger_committee_total_stake";
 }
 
+pub mod process {
+    pub const PROGRAMS: &str = "snarkvm_synthesizer_process_programs";
+    pub const REJECTED_BY_LIMITS: &str = "snarkvm_synthesizer_process_rejected_by_limits";
+}
+
 /// Registers all snarkVM metrics.
 pub fn register_metrics() {
+    for name in COUNTER_NAMES {
+        register_counter(name);
+    }
     for name in GAUGE_NAMES {
         register_gauge(name);
     }
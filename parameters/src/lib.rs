@@ -37,3 +37,8 @@ pub mod testnet;
 pub mod prelude {
     pub use crate::errors::*;
 }
+
+/// Returns the checksum of the given bytes, in the format of the pinned parameter checksums.
+pub fn checksum(bytes: &[u8]) -> String {
+    checksum!(bytes)
+}
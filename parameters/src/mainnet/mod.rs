@@ -152,6 +152,26 @@ macro_rules! insert_key {
 impl_remote!(InclusionProver, REMOTE_URL, "resources/", "inclusion", "prover");
 impl_local!(InclusionVerifier, "resources/", "inclusion", "verifier");
 
+/// The pinned checksums of the verifying keys, for the inclusion circuit and each function in `credits.aleo`.
+/// Each checksum is the SHA-256 digest of the verifying key bytes, as encoded in the parameter files.
+pub const VERIFIER_CHECKSUMS: [(&str, &str); 15] = [
+    ("inclusion", "2b6fdf5a869db365620a44fc3991e3f807a4e670bb976277ad327f3179866553"),
+    ("bond_public", "f4d689b5252ef4243abd7ee8acea3e6e9d0fb137d85908b6a04661d58490d469"),
+    ("unbond_public", "a6106dcfefffd65e46488c7dc6228f9c379c35497c62779f2e186f2d9908eff9"),
+    ("unbond_delegator_as_validator", "34f8fb353c7c1a4e590b52000d78bf28100328f6250eb6c9ef11c9dc0a2bb066"),
+    ("claim_unbond_public", "e53699624b4677f8b1826dcc54f6980aa6400170032244a9082e5bee6c89d39d"),
+    ("set_validator_state", "a224e3690b8c789b026fa5c93d2aada9148f4d45c756c492fb59accb128be724"),
+    ("transfer_private", "118d42c3a97e01506e233aaadec6956b8de1a187b46ee59e6a266c1e85f14d6f"),
+    ("transfer_public", "5facdc35d2205f3a90a7a2eb89fa1418beabc6f6393e18d6498c60f02a3185e3"),
+    ("transfer_public_as_signer", "18e99de56f2098b27318dc9b92eee1743792837c27a4a7001ef19a42c7dbb798"),
+    ("transfer_private_to_public", "be9a2abeeb2c9c5dcea11582f1869e0b1447b45ebfad5582604903ce338da405"),
+    ("transfer_public_to_private", "472004f0fd523239675d32b5e1ebea61cd6855b5f41126e70e05b83ec6a2a1a4"),
+    ("join", "70691d33b4028fd2813edcb9468fa702239b93adab56fc8d3e9ea0636668a844"),
+    ("split", "1e576c6838e48ae946724a64e0831bc6bb0c6adc125b469e52da098008c69f06"),
+    ("fee_private", "099648c5dcbb0eb8c7f1031195273f5e1375831cda977711f487fbed7e33bb20"),
+    ("fee_public", "1e8cc6ab1de9ec21d1559434c516698af40895df3e3d6afb0615495d0201955b"),
+];
+
 /// The function name for the inclusion circuit.
 pub const NETWORK_INCLUSION_FUNCTION_NAME: &str = "inclusion";
 
@@ -193,4 +213,31 @@ mod tests {
         InclusionProver::load_bytes().expect("Failed to load inclusion prover");
         InclusionVerifier::load_bytes().expect("Failed to load inclusion verifier");
     }
+
+    #[wasm_bindgen_test]
+    fn test_verifier_checksums() {
+        let verifiers = [
+            ("inclusion", InclusionVerifier::load_bytes().unwrap()),
+            ("bond_public", BondPublicVerifier::load_bytes().unwrap()),
+            ("unbond_public", UnbondPublicVerifier::load_bytes().unwrap()),
+            ("unbond_delegator_as_validator", UnbondDelegatorAsValidatorVerifier::load_bytes().unwrap()),
+            ("claim_unbond_public", ClaimUnbondPublicVerifier::load_bytes().unwrap()),
+            ("set_validator_state", SetValidatorStateVerifier::load_bytes().unwrap()),
+            ("transfer_private", TransferPrivateVerifier::load_bytes().unwrap()),
+            ("transfer_public", TransferPublicVerifier::load_bytes().unwrap()),
+            ("transfer_public_as_signer", TransferPublicAsSignerVerifier::load_bytes().unwrap()),
+            ("transfer_private_to_public", TransferPrivateToPublicVerifier::load_bytes().unwrap()),
+            ("transfer_public_to_private", TransferPublicToPrivateVerifier::load_bytes().unwrap()),
+            ("join", JoinVerifier::load_bytes().unwrap()),
+            ("split", SplitVerifier::load_bytes().unwrap()),
+            ("fee_private", FeePrivateVerifier::load_bytes().unwrap()),
+            ("fee_public", FeePublicVerifier::load_bytes().unwrap()),
+        ];
+        // Ensure each verifying key matches its pinned checksum.
+        assert_eq!(VERIFIER_CHECKSUMS.len(), verifiers.len());
+        for ((name, expected), (candidate_name, bytes)) in VERIFIER_CHECKSUMS.iter().zip(verifiers.iter()) {
+            assert_eq!(name, candidate_name);
+            assert_eq!(*expected, crate::checksum(bytes), "Mismatching checksum for the {name} verifier");
+        }
+    }
 }
@@ -47,7 +47,7 @@ const NUM_POWERS_27: usize = 1 << 27;
 const NUM_POWERS_28: usize = 1 << 28;
 
 /// The maximum degree supported by the SRS.
-const MAX_NUM_POWERS: usize = NUM_POWERS_28;
+pub const MAX_NUM_POWERS: usize = NUM_POWERS_28;
 
 lazy_static::lazy_static! {
     static ref POWERS_OF_BETA_G_15: Vec<u8> = Degree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
@@ -74,6 +74,8 @@ pub struct PowersOfG<E: PairingEngine> {
     prepared_negative_powers_of_beta_h: Arc<BTreeMap<usize, <E::G2Affine as PairingCurve>::Prepared>>,
     /// beta * h
     beta_h: E::G2Affine,
+    /// Indicates whether the powers were generated locally, in which case no powers are downloaded.
+    is_local: bool,
 }
 
 impl<E: PairingEngine> PowersOfG<E> {
@@ -101,11 +103,69 @@ impl<E: PairingEngine> PowersOfG<E> {
             negative_powers_of_beta_h,
             prepared_negative_powers_of_beta_h,
             beta_h,
+            is_local: false,
         })
     }
 
+    /// Initializes an instance of the powers from locally-generated group elements.
+    ///
+    /// The `powers_of_beta_g` are the powers `0..n`, and the `shifted_powers_of_beta_g` are the
+    /// powers `(MAX_NUM_POWERS - n)..MAX_NUM_POWERS`. As the trapdoor of such powers is known
+    /// to whoever generated them, the resulting instance is **insecure** and must only be used
+    /// for local development and testing. The instance never downloads additional powers.
+    pub fn from_local_powers(
+        powers_of_beta_g: Vec<E::G1Affine>,
+        shifted_powers_of_beta_g: Vec<E::G1Affine>,
+        powers_of_beta_times_gamma_g: BTreeMap<usize, E::G1Affine>,
+        negative_powers_of_beta_h: BTreeMap<usize, E::G2Affine>,
+        beta_h: E::G2Affine,
+    ) -> Result<Self> {
+        // Ensure the number of powers is a power of two.
+        let num_powers = powers_of_beta_g.len();
+        ensure!(num_powers.is_power_of_two(), "The number of local powers ({num_powers}) must be a power of two");
+        // Ensure the normal and shifted powers do not overlap.
+        ensure!(num_powers <= MAX_NUM_POWERS / 2, "The number of local powers ({num_powers}) is too large");
+        // Ensure the number of shifted powers matches the number of normal powers.
+        ensure!(
+            shifted_powers_of_beta_g.len() == num_powers,
+            "Incorrect number of shifted powers in the local SRS ({} != {num_powers})",
+            shifted_powers_of_beta_g.len()
+        );
+
+        // Compute the prepared negative powers of beta_h.
+        let prepared_negative_powers_of_beta_h: Arc<BTreeMap<usize, <E::G2Affine as PairingCurve>::Prepared>> =
+            Arc::new(negative_powers_of_beta_h.iter().map(|(d, affine)| (*d, affine.prepare())).collect());
+
+        // Return the powers.
+        Ok(Self {
+            powers_of_beta_g: RwLock::new(PowersOfBetaG { powers_of_beta_g, shifted_powers_of_beta_g }),
+            powers_of_beta_times_gamma_g,
+            negative_powers_of_beta_h,
+            prepared_negative_powers_of_beta_h,
+            beta_h,
+            is_local: true,
+        })
+    }
+
+    /// Returns `true` if the powers were generated locally.
+    pub fn is_local(&self) -> bool {
+        self.is_local
+    }
+
+    /// Ensures the powers in `range` are available, if the powers were generated locally.
+    fn ensure_local_powers_for(&self, range: &Range<usize>) -> Result<()> {
+        if self.is_local && !range.is_empty() {
+            ensure!(
+                self.powers_of_beta_g.read().contains_powers(range),
+                "The local SRS does not contain the powers {range:?}, and they cannot be downloaded"
+            );
+        }
+        Ok(())
+    }
+
     /// Download the powers of beta G specified by `range`.
     pub fn download_powers_for(&self, range: Range<usize>) -> Result<()> {
+        self.ensure_local_powers_for(&range)?;
         self.powers_of_beta_g.write().download_powers_for(&range)
     }
 
@@ -126,11 +186,13 @@ impl<E: PairingEngine> PowersOfG<E> {
 
     /// Returns the `index`-th power of beta * G.
     pub fn power_of_beta_g(&self, index: usize) -> Result<E::G1Affine> {
+        self.ensure_local_powers_for(&(index..(index + 1)))?;
         self.powers_of_beta_g.write().power(index)
     }
 
     /// Returns the powers of `beta * G` that lie within `range`.
     pub fn powers_of_beta_g(&self, range: Range<usize>) -> Result<Vec<E::G1Affine>> {
+        self.ensure_local_powers_for(&range)?;
         Ok(self.powers_of_beta_g.write().powers(range)?.to_vec())
     }
 
@@ -191,6 +253,7 @@ impl<E: PairingEngine> CanonicalDeserialize for PowersOfG<E> {
             negative_powers_of_beta_h,
             prepared_negative_powers_of_beta_h,
             beta_h,
+            is_local: false,
         };
         if let Validate::Yes = validate {
             powers.check()?;
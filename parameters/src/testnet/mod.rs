@@ -99,6 +99,26 @@ macro_rules! insert_testnet_key {
 impl_remote!(InclusionProver, REMOTE_URL, "resources/", "inclusion", "prover");
 impl_local!(InclusionVerifier, "resources/", "inclusion", "verifier");
 
+/// The pinned checksums of the verifying keys, for the inclusion circuit and each function in `credits.aleo`.
+/// Each checksum is the SHA-256 digest of the verifying key bytes, as encoded in the parameter files.
+pub const VERIFIER_CHECKSUMS: [(&str, &str); 15] = [
+    ("inclusion", "2b6fdf5a869db365620a44fc3991e3f807a4e670bb976277ad327f3179866553"),
+    ("bond_public", "198a9aa7d4ed09343dc54df66dcfc089f5548d9c3201849d1b7cece1ed5b2b47"),
+    ("unbond_public", "31e48623ea0e9e59eca4b9fc05ab4bb13f7593d60e7c418398f71a396307c042"),
+    ("unbond_delegator_as_validator", "bb883d677bc912d6cc83eaf08bac4cc6882fe3cb08e77259af8ea1985b2df3be"),
+    ("claim_unbond_public", "e20ae8d0b9afa3b1f9bd9e808183e43095dfc4e3782c3b6668beaf696820e808"),
+    ("set_validator_state", "caa6cb2dd3f5cabf1ba15f7548f1e5eb3a9d0e6daca94393489966ddbdb4ba2f"),
+    ("transfer_private", "1291734485f70e932337080b7724a3ad49a965c5e2120ed3e47521c75cbf8c83"),
+    ("transfer_public", "48e7e65bbc371bc417766ebdd937aee0307f47c6bebef47ba97f580c56777869"),
+    ("transfer_public_as_signer", "3576013fded7b170dce276997beee3c564e0585fcaa11ec332f30fbb6e28e933"),
+    ("transfer_private_to_public", "c7a95f998d30fd36d0696d1bf19379a6d34092110da995b5b719c5538ec700d7"),
+    ("transfer_public_to_private", "4c2d2288a21e533a3ad5108086f57831b8c6759a152021599bca015b5b0c2586"),
+    ("join", "5a29b215f24c13d91850cba1dda64b2b2e54247d780db6bf6a6d8bd88af35ead"),
+    ("split", "53eab92841839522ee7eac56ea33d6b337de02c75ac6c34e2a6caed4aa1a1700"),
+    ("fee_private", "ca38afe2a01f5eb564afd6c45a412ebec5ffecf1084c548329a360b0db8a177e"),
+    ("fee_public", "8bc7651f85713f62b3a71fa001240e8fcf4ad322589cf2603c964c9426ede96a"),
+];
+
 /// The function name for the inclusion circuit.
 pub const NETWORK_INCLUSION_FUNCTION_NAME: &str = "inclusion";
 
@@ -135,4 +155,31 @@ mod tests {
         InclusionProver::load_bytes().expect("Failed to load inclusion prover");
         InclusionVerifier::load_bytes().expect("Failed to load inclusion verifier");
     }
+
+    #[wasm_bindgen_test]
+    fn test_verifier_checksums() {
+        let verifiers = [
+            ("inclusion", InclusionVerifier::load_bytes().unwrap()),
+            ("bond_public", BondPublicVerifier::load_bytes().unwrap()),
+            ("unbond_public", UnbondPublicVerifier::load_bytes().unwrap()),
+            ("unbond_delegator_as_validator", UnbondDelegatorAsValidatorVerifier::load_bytes().unwrap()),
+            ("claim_unbond_public", ClaimUnbondPublicVerifier::load_bytes().unwrap()),
+            ("set_validator_state", SetValidatorStateVerifier::load_bytes().unwrap()),
+            ("transfer_private", TransferPrivateVerifier::load_bytes().unwrap()),
+            ("transfer_public", TransferPublicVerifier::load_bytes().unwrap()),
+            ("transfer_public_as_signer", TransferPublicAsSignerVerifier::load_bytes().unwrap()),
+            ("transfer_private_to_public", TransferPrivateToPublicVerifier::load_bytes().unwrap()),
+            ("transfer_public_to_private", TransferPublicToPrivateVerifier::load_bytes().unwrap()),
+            ("join", JoinVerifier::load_bytes().unwrap()),
+            ("split", SplitVerifier::load_bytes().unwrap()),
+            ("fee_private", FeePrivateVerifier::load_bytes().unwrap()),
+            ("fee_public", FeePublicVerifier::load_bytes().unwrap()),
+        ];
+        // Ensure each verifying key matches its pinned checksum.
+        assert_eq!(VERIFIER_CHECKSUMS.len(), verifiers.len());
+        for ((name, expected), (candidate_name, bytes)) in VERIFIER_CHECKSUMS.iter().zip(verifiers.iter()) {
+            assert_eq!(name, candidate_name);
+            assert_eq!(*expected, crate::checksum(bytes), "Mismatching checksum for the {name} verifier");
+        }
+    }
 }
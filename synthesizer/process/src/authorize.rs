@@ -29,6 +29,40 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)
     }
 
+    /// Authorizes the given top-level calls, as `(program ID, function name, inputs)`, into a single authorization.
+    ///
+    /// The calls execute in the given order, atomically, into a single execution, so that a single fee covers all of them
+    /// (e.g. an 'approve' followed by a 'transfer'). Each call is signed independently with the given private key.
+    ///
+    /// Note: The signature of a call does not commit to the other calls in the bundle, so whoever holds the
+    /// authorization can split or reorder the calls before proving. Only the resulting execution is bound as a whole,
+    /// as its execution ID commits to the transition IDs (in order), and the fee commits to the execution ID.
+    #[inline]
+    pub fn authorize_bundle<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        calls: impl IntoIterator<Item = (ProgramID<N>, Identifier<N>, Vec<Value<N>>)>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        let timer = timer!("Process::authorize_bundle");
+
+        // Authorize each call.
+        let authorizations = calls
+            .into_iter()
+            .map(|(program_id, function_name, inputs)| {
+                self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs.into_iter(), rng)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Authorize the calls");
+
+        // Bundle the authorizations.
+        let authorization = Authorization::bundle(authorizations)?;
+        finish!(timer, "Bundle the authorizations");
+
+        // Return the authorization.
+        Ok(authorization)
+    }
+
     /// Authorizes the fee given the credits record, the fee amount (in microcredits),
     /// and the deployment or execution ID.
     #[inline]
@@ -0,0 +1,272 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The placeholder in a benchmark input that is replaced with the address of the caller.
+pub const CALLER: &str = "{caller}";
+
+/// A call to a function in a benchmark workload.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BenchmarkCall {
+    /// The program ID.
+    program_id: &'static str,
+    /// The function name.
+    function_name: &'static str,
+    /// The inputs, where each occurrence of `CALLER` is replaced with the address of the caller.
+    inputs: &'static [&'static str],
+}
+
+impl BenchmarkCall {
+    /// Initializes a new benchmark call.
+    pub const fn new(program_id: &'static str, function_name: &'static str, inputs: &'static [&'static str]) -> Self {
+        Self { program_id, function_name, inputs }
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &'static str {
+        self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &'static str {
+        self.function_name
+    }
+
+    /// Returns the inputs, where each occurrence of `CALLER` is replaced with the address of the caller.
+    pub const fn inputs(&self) -> &'static [&'static str] {
+        self.inputs
+    }
+}
+
+/// A representative workload, consisting of the programs to deploy and the calls to execute.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Workload {
+    /// The name of the workload.
+    name: &'static str,
+    /// The programs to deploy, in the order of their imports.
+    programs: &'static [&'static str],
+    /// The calls to execute, in order, once every program is deployed.
+    calls: &'static [BenchmarkCall],
+}
+
+impl Workload {
+    /// Initializes a new workload.
+    pub const fn new(name: &'static str, programs: &'static [&'static str], calls: &'static [BenchmarkCall]) -> Self {
+        Self { name, programs, calls }
+    }
+
+    /// Returns the name of the workload.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the programs to deploy, in the order of their imports.
+    pub const fn programs(&self) -> &'static [&'static str] {
+        self.programs
+    }
+
+    /// Returns the calls to execute, in order, once every program is deployed.
+    pub const fn calls(&self) -> &'static [BenchmarkCall] {
+        self.calls
+    }
+}
+
+/// The benchmark corpus, in the order in which the workloads are run.
+pub const CORPUS: &[Workload] = &[TOKEN_WORKLOAD, NFT_WORKLOAD, DEX_WORKLOAD, CALL_CHAIN_WORKLOAD];
+
+/// A token with private records and public balances.
+pub const TOKEN_WORKLOAD: Workload = Workload::new("token", &[TOKEN_PROGRAM], &[
+    BenchmarkCall::new("bench_token.aleo", "mint_private", &[CALLER, "100u64"]),
+    BenchmarkCall::new("bench_token.aleo", "mint_public", &[CALLER, "100u64"]),
+    BenchmarkCall::new("bench_token.aleo", "transfer_public", &[CALLER, "10u64"]),
+]);
+
+/// A collection of unique tokens, tracked in a public mapping.
+pub const NFT_WORKLOAD: Workload = Workload::new("nft", &[NFT_PROGRAM], &[
+    BenchmarkCall::new("bench_nft.aleo", "mint", &[CALLER, "1field", "7scalar"]),
+    BenchmarkCall::new("bench_nft.aleo", "prove_ownership", &["1field", "7scalar"]),
+]);
+
+/// A constant-product exchange, with 128-bit arithmetic.
+pub const DEX_WORKLOAD: Workload =
+    Workload::new("dex", &[DEX_PROGRAM], &[BenchmarkCall::new("bench_dex.aleo", "swap", &[
+        "1000u128",
+        "1000000u128",
+        "2000000u128",
+    ])]);
+
+/// A chain of calls across four programs.
+pub const CALL_CHAIN_WORKLOAD: Workload =
+    Workload::new("call_chain", &[CHAIN_0_PROGRAM, CHAIN_1_PROGRAM, CHAIN_2_PROGRAM, CHAIN_3_PROGRAM], &[
+        BenchmarkCall::new("bench_chain_3.aleo", "step", &["1field"]),
+    ]);
+
+const TOKEN_PROGRAM: &str = r"
+program bench_token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function mint_private:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async mint_public r0 r1 into r2;
+    output r2 as bench_token.aleo/mint_public.future;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+
+function transfer_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async transfer_public self.caller r0 r1 into r2;
+    output r2 as bench_token.aleo/transfer_public.future;
+
+finalize transfer_public:
+    input r0 as address.public;
+    input r1 as address.public;
+    input r2 as u64.public;
+    get.or_use account[r0] 0u64 into r3;
+    sub r3 r2 into r4;
+    set r4 into account[r0];
+    get.or_use account[r1] 0u64 into r5;
+    add r5 r2 into r6;
+    set r6 into account[r1];
+";
+
+const NFT_PROGRAM: &str = r"
+program bench_nft.aleo;
+
+record nft:
+    owner as address.private;
+    data as field.private;
+    edition as scalar.private;
+
+mapping owners:
+    key as field.public;
+    value as address.public;
+
+function mint:
+    input r0 as address.private;
+    input r1 as field.private;
+    input r2 as scalar.private;
+    hash.bhp256 r1 into r3 as field;
+    cast r0 r1 r2 into r4 as nft.record;
+    async mint r3 r0 into r5;
+    output r4 as nft.record;
+    output r5 as bench_nft.aleo/mint.future;
+
+finalize mint:
+    input r0 as field.public;
+    input r1 as address.public;
+    contains owners[r0] into r2;
+    assert.eq r2 false;
+    set r1 into owners[r0];
+
+function prove_ownership:
+    input r0 as field.private;
+    input r1 as scalar.private;
+    commit.bhp256 r0 r1 into r2 as field;
+    output r2 as field.public;
+";
+
+const DEX_PROGRAM: &str = r"
+program bench_dex.aleo;
+
+mapping reserves:
+    key as u8.public;
+    value as u128.public;
+
+function swap:
+    input r0 as u128.public;
+    input r1 as u128.public;
+    input r2 as u128.public;
+    mul r0 997u128 into r3;
+    mul r3 r2 into r4;
+    mul r1 1000u128 into r5;
+    add r5 r3 into r6;
+    div r4 r6 into r7;
+    async swap r0 r7 into r8;
+    output r7 as u128.public;
+    output r8 as bench_dex.aleo/swap.future;
+
+finalize swap:
+    input r0 as u128.public;
+    input r1 as u128.public;
+    get.or_use reserves[0u8] 1000000u128 into r2;
+    add r2 r0 into r3;
+    set r3 into reserves[0u8];
+    get.or_use reserves[1u8] 2000000u128 into r4;
+    sub r4 r1 into r5;
+    set r5 into reserves[1u8];
+";
+
+const CHAIN_0_PROGRAM: &str = r"
+program bench_chain_0.aleo;
+
+function step:
+    input r0 as field.private;
+    hash.psd2 r0 into r1 as field;
+    output r1 as field.private;
+";
+
+const CHAIN_1_PROGRAM: &str = r"
+import bench_chain_0.aleo;
+
+program bench_chain_1.aleo;
+
+function step:
+    input r0 as field.private;
+    call bench_chain_0.aleo/step r0 into r1;
+    hash.psd2 r1 into r2 as field;
+    output r2 as field.private;
+";
+
+const CHAIN_2_PROGRAM: &str = r"
+import bench_chain_1.aleo;
+
+program bench_chain_2.aleo;
+
+function step:
+    input r0 as field.private;
+    call bench_chain_1.aleo/step r0 into r1;
+    hash.psd2 r1 into r2 as field;
+    output r2 as field.private;
+";
+
+const CHAIN_3_PROGRAM: &str = r"
+import bench_chain_2.aleo;
+
+program bench_chain_3.aleo;
+
+function step:
+    input r0 as field.private;
+    call bench_chain_2.aleo/step r0 into r1;
+    hash.psd2 r1 into r2 as field;
+    output r2 as field.private;
+";
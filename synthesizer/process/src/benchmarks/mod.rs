@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod corpus;
+pub use corpus::*;
+
+mod report;
+pub use report::*;
+
+use crate::{Process, Stack};
+use console::{
+    account::{Address, PrivateKey},
+    network::prelude::*,
+    program::Value,
+};
+use ledger_query::Query;
+use ledger_store::{helpers::memory::BlockMemory, BlockStore};
+use synthesizer_program::Program;
+
+use std::time::{Duration, Instant};
+
+impl<N: Network> Process<N> {
+    /// Runs every workload in the benchmark corpus end-to-end, and returns the timings of each phase.
+    /// The programs in the corpus are added to the process, so this method may only be called once per process.
+    pub fn run_benchmark_corpus<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &mut self,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<CorpusReport> {
+        let workloads = CORPUS
+            .iter()
+            .map(|workload| self.run_benchmark_workload::<A, R>(workload, private_key, rng))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(CorpusReport::new(workloads))
+    }
+
+    /// Runs the given workload end-to-end, and returns the timings of each phase.
+    ///
+    /// Each program is deployed, verified, and added to the process, in order.
+    /// Then, each call is authorized, executed, proven, and verified.
+    ///
+    /// Note: As there is no ledger state, the finalize scopes of the calls are not run,
+    /// and the calls may not consume records.
+    pub fn run_benchmark_workload<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &mut self,
+        workload: &Workload,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<WorkloadReport> {
+        // Initialize a vector for the timings.
+        let mut timings = Vec::new();
+
+        for source in workload.programs() {
+            // Parse the program.
+            let program = Program::<N>::from_str(source)?;
+            let program_id = program.id().to_string();
+
+            // Compute the stack, and deploy the program.
+            // Note: The stack is deployed directly, so that it retains the synthesized proving keys.
+            let start = Instant::now();
+            let stack = Stack::new(self, &program)?;
+            let deployment = stack.deploy::<A, R>(rng)?;
+            timings.push(PhaseTiming::new(Phase::Deploy, program_id.clone(), start.elapsed()));
+
+            // Verify the deployment.
+            let start = Instant::now();
+            self.verify_deployment::<A, R>(&deployment, rng)?;
+            timings.push(PhaseTiming::new(Phase::VerifyDeployment, program_id, start.elapsed()));
+
+            // Add the stack to the process.
+            self.add_stack(stack);
+        }
+
+        // Initialize an empty block store, to provide the global state root.
+        let block_store = BlockStore::<N, BlockMemory<N>>::open(None)?;
+        // Retrieve the address of the caller.
+        let caller = Address::try_from(private_key)?.to_string();
+
+        for call in workload.calls() {
+            let locator = format!("{}/{}", call.program_id(), call.function_name());
+
+            // Prepare the inputs.
+            let inputs = call
+                .inputs()
+                .iter()
+                .map(|input| Value::<N>::from_str(&input.replace(CALLER, &caller)))
+                .collect::<Result<Vec<_>>>()?;
+
+            // Authorize the call.
+            let start = Instant::now();
+            let authorization =
+                self.authorize::<A, R>(private_key, call.program_id(), call.function_name(), inputs.iter(), rng)?;
+            timings.push(PhaseTiming::new(Phase::Authorize, locator.clone(), start.elapsed()));
+
+            // Execute the call.
+            let start = Instant::now();
+            let (_, mut trace) = self.execute::<A, R>(authorization, rng)?;
+            timings.push(PhaseTiming::new(Phase::Execute, locator.clone(), start.elapsed()));
+
+            // Prepare the trace, and prove the execution.
+            let start = Instant::now();
+            trace.prepare(Query::from(&block_store))?;
+            let execution = trace.prove_execution::<A, R>(&locator, rng)?;
+            timings.push(PhaseTiming::new(Phase::Prove, locator.clone(), start.elapsed()));
+
+            // Verify the execution.
+            let start = Instant::now();
+            self.verify_execution(&execution)?;
+            timings.push(PhaseTiming::new(Phase::VerifyExecution, locator, start.elapsed()));
+        }
+
+        Ok(WorkloadReport::new(workload.name().to_string(), timings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        network::MainnetV0,
+        program::{Identifier, ProgramID},
+    };
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_corpus_is_well_formed() {
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+        for workload in CORPUS {
+            // Ensure every program parses, and can be added to the process in order.
+            let mut programs = Vec::new();
+            for source in workload.programs() {
+                let program = Program::<CurrentNetwork>::from_str(source).unwrap();
+                process.add_program(&program).unwrap();
+                programs.push(program);
+            }
+            // Ensure every call refers to a function in the workload with the matching number of inputs.
+            for call in workload.calls() {
+                let program_id = ProgramID::from_str(call.program_id()).unwrap();
+                let program = programs.iter().find(|program| *program.id() == program_id).unwrap();
+                let function = program.get_function(&Identifier::from_str(call.function_name()).unwrap()).unwrap();
+                assert_eq!(
+                    function.inputs().len(),
+                    call.inputs().len(),
+                    "Mismatched inputs for '{}'",
+                    call.function_name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_benchmark_workload() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process and the caller.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        let private_key = PrivateKey::new(rng).unwrap();
+
+        // Run the call chain workload.
+        let report = process.run_benchmark_workload::<CurrentAleo, _>(&CALL_CHAIN_WORKLOAD, &private_key, rng).unwrap();
+        assert_eq!(report.name(), "call_chain");
+
+        // Ensure each program was deployed and verified, and each call was authorized, executed, proven, and verified.
+        let num_programs = CALL_CHAIN_WORKLOAD.programs().len();
+        let num_calls = CALL_CHAIN_WORKLOAD.calls().len();
+        assert_eq!(report.timings().len(), 2 * num_programs + 4 * num_calls);
+
+        // Ensure the report is machine-readable.
+        let json = report.to_json();
+        assert_eq!(json["name"], "call_chain");
+        assert_eq!(json["timings"].as_array().unwrap().len(), report.timings().len());
+        assert_eq!(json["timings"][0]["phase"], "deploy");
+        assert_eq!(json["timings"][0]["target"], "bench_chain_0.aleo");
+        assert!(json["total_duration_us"].is_u64());
+    }
+}
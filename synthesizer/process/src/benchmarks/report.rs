@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A phase of a benchmark workload.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// Computing the stack, synthesizing the circuit keys, and certifying them.
+    Deploy,
+    /// Verifying the deployment.
+    VerifyDeployment,
+    /// Authorizing a call.
+    Authorize,
+    /// Executing a call, including its synthesis.
+    Execute,
+    /// Preparing the inclusion assignments and proving the execution.
+    Prove,
+    /// Verifying the execution.
+    VerifyExecution,
+}
+
+impl Phase {
+    /// Returns the name of the phase, as it appears in the report.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Deploy => "deploy",
+            Self::VerifyDeployment => "verify_deployment",
+            Self::Authorize => "authorize",
+            Self::Execute => "execute",
+            Self::Prove => "prove",
+            Self::VerifyExecution => "verify_execution",
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The time taken by a single phase of a benchmark workload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PhaseTiming {
+    /// The phase.
+    phase: Phase,
+    /// The program ID or locator that the phase ran on.
+    target: String,
+    /// The time taken by the phase.
+    duration: Duration,
+}
+
+impl PhaseTiming {
+    /// Initializes a new phase timing.
+    pub const fn new(phase: Phase, target: String, duration: Duration) -> Self {
+        Self { phase, target, duration }
+    }
+
+    /// Returns the phase.
+    pub const fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// Returns the program ID or locator that the phase ran on.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Returns the time taken by the phase.
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the timing as a JSON object.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "phase": self.phase.as_str(),
+            "target": self.target,
+            "duration_us": duration_in_micros(self.duration),
+        })
+    }
+}
+
+/// The timings of a benchmark workload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkloadReport {
+    /// The name of the workload.
+    name: String,
+    /// The timings of each phase, in the order they ran.
+    timings: Vec<PhaseTiming>,
+}
+
+impl WorkloadReport {
+    /// Initializes a new workload report.
+    pub const fn new(name: String, timings: Vec<PhaseTiming>) -> Self {
+        Self { name, timings }
+    }
+
+    /// Returns the name of the workload.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the timings of each phase, in the order they ran.
+    pub fn timings(&self) -> &[PhaseTiming] {
+        &self.timings
+    }
+
+    /// Returns the total time taken by the given phase.
+    pub fn phase_duration(&self, phase: Phase) -> Duration {
+        self.timings.iter().filter(|timing| timing.phase == phase).map(PhaseTiming::duration).sum()
+    }
+
+    /// Returns the total time taken by the workload.
+    pub fn total_duration(&self) -> Duration {
+        self.timings.iter().map(PhaseTiming::duration).sum()
+    }
+
+    /// Returns the report as a JSON object.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "name": self.name,
+            "total_duration_us": duration_in_micros(self.total_duration()),
+            "timings": self.timings.iter().map(PhaseTiming::to_json).collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// The timings of every workload in the benchmark corpus.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CorpusReport {
+    /// The reports of each workload, in the order they ran.
+    workloads: Vec<WorkloadReport>,
+}
+
+impl CorpusReport {
+    /// Initializes a new corpus report.
+    pub const fn new(workloads: Vec<WorkloadReport>) -> Self {
+        Self { workloads }
+    }
+
+    /// Returns the reports of each workload, in the order they ran.
+    pub fn workloads(&self) -> &[WorkloadReport] {
+        &self.workloads
+    }
+
+    /// Returns the report as a JSON object.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "workloads": self.workloads.iter().map(WorkloadReport::to_json).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl Display for CorpusReport {
+    /// Writes the report as a JSON string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_json())
+    }
+}
+
+/// Returns the given duration in microseconds, saturating at `u64::MAX`.
+fn duration_in_micros(duration: Duration) -> u64 {
+    u64::try_from(duration.as_micros()).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::HashSet;
+
+/// The call tree of a top-level call in an execution, rooted at the transition of the top-level call.
+///
+/// The canonical ordering of the transitions in an execution is the post-order of its call trees, in the order of
+/// the top-level calls, i.e. each transition is preceded by the transitions it calls, in the order they are called.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallTree<N: Network> {
+    /// The transition ID.
+    transition_id: N::TransitionID,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The subtrees of the transitions called by this transition, in the order they are called.
+    children: Vec<CallTree<N>>,
+}
+
+impl<N: Network> CallTree<N> {
+    /// Initializes the call tree rooted at the given transition, from the given call graph.
+    fn new(
+        root: &Transition<N>,
+        call_graph: &HashMap<N::TransitionID, Vec<N::TransitionID>>,
+        transitions: &HashMap<N::TransitionID, &Transition<N>>,
+    ) -> Result<Self> {
+        // Retrieve the children of the root.
+        let Some(child_ids) = call_graph.get(root.id()) else {
+            bail!("Transition '{}' is missing from the call graph", root.id())
+        };
+        // Construct the subtree of each child.
+        let children = child_ids
+            .iter()
+            .map(|child_id| match transitions.get(child_id) {
+                Some(child) => Self::new(child, call_graph, transitions),
+                None => bail!("Transition '{child_id}' is missing from the execution"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Return the call tree.
+        Ok(Self {
+            transition_id: *root.id(),
+            program_id: *root.program_id(),
+            function_name: *root.function_name(),
+            children,
+        })
+    }
+
+    /// Returns the transition ID of the root.
+    pub const fn transition_id(&self) -> &N::TransitionID {
+        &self.transition_id
+    }
+
+    /// Returns the program ID of the root.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name of the root.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the subtrees of the transitions called by the root, in the order they are called.
+    pub fn children(&self) -> &[CallTree<N>] {
+        &self.children
+    }
+
+    /// Returns the number of transitions in the call tree.
+    pub fn len(&self) -> usize {
+        1 + self.children.iter().map(|child| child.len()).sum::<usize>()
+    }
+
+    /// Returns `false`, as a call tree always contains its root.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the transition IDs in canonical (post-order) order.
+    pub fn to_post_order(&self) -> Vec<N::TransitionID> {
+        let mut transition_ids = Vec::with_capacity(self.len());
+        self.extend_post_order(&mut transition_ids);
+        transition_ids
+    }
+
+    /// Appends the transition IDs of the call tree to the given list, in post-order.
+    fn extend_post_order(&self, transition_ids: &mut Vec<N::TransitionID>) {
+        for child in &self.children {
+            child.extend_post_order(transition_ids);
+        }
+        transition_ids.push(self.transition_id);
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Reconstructs the call tree of the given execution.
+    ///
+    /// This method errors if the execution does not consist of exactly one top-level call,
+    /// or if the transitions are not in canonical (call-tree post-order) order.
+    pub fn construct_call_tree(&self, execution: &Execution<N>) -> Result<CallTree<N>> {
+        // Construct the call trees.
+        let mut call_trees = self.construct_call_trees(execution)?;
+        // Ensure there is exactly one top-level call.
+        ensure!(call_trees.len() == 1, "Expected 1 top-level call in the execution, found {}", call_trees.len());
+        // Return the call tree.
+        call_trees.pop().ok_or_else(|| anyhow!("Missing the call tree of the execution"))
+    }
+
+    /// Reconstructs the call trees of the given execution, one for each top-level call, in execution order.
+    ///
+    /// This method errors if the transitions are not in canonical (call-tree post-order) order.
+    pub fn construct_call_trees(&self, execution: &Execution<N>) -> Result<Vec<CallTree<N>>> {
+        self.construct_call_trees_from(&execution.transitions().collect::<Vec<_>>())
+    }
+
+    /// Reconstructs the call trees of the given transitions, and ensures they are in canonical order.
+    pub(crate) fn construct_call_trees_from(&self, transitions: &[&Transition<N>]) -> Result<Vec<CallTree<N>>> {
+        // Construct the call graph.
+        let call_graph = self.construct_call_graph_from(transitions)?;
+        // Construct the call trees from the call graph.
+        Self::call_trees_from_graph(transitions, &call_graph)
+    }
+
+    /// Constructs the call trees of the given transitions from their call graph, and ensures they are in canonical order.
+    /// Each top-level call is the root of one call tree, and the call trees are returned in execution order.
+    pub(crate) fn call_trees_from_graph(
+        transitions: &[&Transition<N>],
+        call_graph: &HashMap<N::TransitionID, Vec<N::TransitionID>>,
+    ) -> Result<Vec<CallTree<N>>> {
+        // Ensure the transitions are not empty.
+        ensure!(!transitions.is_empty(), "Cannot construct the call tree of an empty execution");
+        // Collect the transition IDs that are called by another transition.
+        let children = call_graph.values().flatten().collect::<HashSet<_>>();
+        // Index the transitions by their transition ID.
+        let transition_map = transitions.iter().map(|transition| (*transition.id(), *transition)).collect();
+        // Construct a call tree for each top-level call, which is a transition that is not called by another transition.
+        let call_trees = transitions
+            .iter()
+            .filter(|transition| !children.contains(transition.id()))
+            .map(|root| CallTree::new(root, call_graph, &transition_map))
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure the transitions are in canonical order.
+        let call_tree_ids = call_trees.iter().flat_map(|call_tree| call_tree.to_post_order()).collect::<Vec<_>>();
+        ensure_canonical_order(&call_tree_ids, transitions)?;
+        Ok(call_trees)
+    }
+}
+
+/// Ensures the given transitions are in the given canonical order.
+fn ensure_canonical_order<N: Network>(expected: &[N::TransitionID], transitions: &[&Transition<N>]) -> Result<()> {
+    // Ensure the number of transitions matches.
+    ensure!(
+        expected.len() == transitions.len(),
+        "Expected {} transitions in the call trees, found {}",
+        expected.len(),
+        transitions.len()
+    );
+    // Ensure each transition is in its canonical position.
+    for (index, (expected, transition)) in expected.iter().zip_eq(transitions).enumerate() {
+        ensure!(expected == transition.id(), "Transition {index} is not in canonical (call-tree post-order) order")
+    }
+    Ok(())
+}
@@ -0,0 +1,316 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use circuit::Assignment;
+use console::program::InputID;
+
+use std::collections::HashSet;
+
+/// A completed transition in an execution checkpoint.
+#[derive(Clone)]
+struct CheckpointEntry<N: Network> {
+    /// The input IDs of the transition.
+    input_ids: Vec<InputID<N>>,
+    /// The transition.
+    transition: Transition<N>,
+    /// The circuit assignment of the transition.
+    assignment: Assignment<N::Field>,
+    /// The call metrics of the transition.
+    metrics: CallMetrics<N>,
+}
+
+/// A checkpoint of an execution, which records the transitions (and circuit assignments) of its completed
+/// top-level calls, so that an interrupted execution can be resumed without synthesizing them again.
+///
+/// A checkpoint is taken after each top-level call, as the circuit of a call is only complete once its nested
+/// calls are complete. The checkpoint contains the witnesses of the completed transitions, so it must be stored
+/// as securely as the private inputs of the execution.
+#[derive(Clone)]
+pub struct ExecutionCheckpoint<N: Network> {
+    /// The authorization of the execution, including the requests of the completed calls.
+    authorization: Authorization<N>,
+    /// The completed transitions, in the order they were executed.
+    entries: Vec<CheckpointEntry<N>>,
+}
+
+impl<N: Network> ExecutionCheckpoint<N> {
+    /// Initializes a new checkpoint for the given authorization, with no completed transitions.
+    pub fn new(authorization: &Authorization<N>) -> Self {
+        Self { authorization: authorization.replicate(), entries: Vec::new() }
+    }
+
+    /// Returns the authorization of the execution.
+    pub const fn authorization(&self) -> &Authorization<N> {
+        &self.authorization
+    }
+
+    /// Returns the completed transitions, in the order they were executed.
+    pub fn transitions(&self) -> impl '_ + Iterator<Item = &Transition<N>> {
+        self.entries.iter().map(|entry| &entry.transition)
+    }
+
+    /// Returns the number of completed transitions.
+    pub fn num_completed(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if every request in the authorization has been executed.
+    pub fn is_complete(&self) -> bool {
+        self.entries.len() == self.authorization.len()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Executes the remaining top-level calls of the given checkpoint, and returns the trace of the execution.
+    ///
+    /// After each top-level call, the updated checkpoint is given to `on_checkpoint`, which may persist it.
+    /// If the execution is interrupted, it can be resumed by calling this method on the last persisted checkpoint.
+    /// Unlike `Process::execute`, the top-level calls are executed in order, and the response is not returned,
+    /// as the outputs of the execution are contained in its transitions.
+    #[inline]
+    pub fn execute_with_checkpoints<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        mut checkpoint: ExecutionCheckpoint<N>,
+        mut on_checkpoint: impl FnMut(&ExecutionCheckpoint<N>) -> Result<()>,
+        rng: &mut R,
+    ) -> Result<Trace<N>> {
+        let timer = timer!("Process::execute_with_checkpoints");
+
+        // Ensure the authorization has not expired.
+        self.ensure_authorization_is_not_expired(&checkpoint.authorization)?;
+
+        // Retrieve the requests.
+        let requests = checkpoint.authorization.to_vec_deque();
+        // Ensure the completed transitions correspond to the leading requests of the authorization.
+        let num_completed = checkpoint.entries.len();
+        ensure!(num_completed <= requests.len(), "The checkpoint has more transitions than requests");
+        let expected_tcms = requests.iter().take(num_completed).map(|request| *request.tcm()).collect::<HashSet<_>>();
+        let completed_tcms = checkpoint.entries.iter().map(|entry| *entry.transition.tcm()).collect::<HashSet<_>>();
+        ensure!(expected_tcms == completed_tcms, "The checkpoint does not match its authorization");
+
+        // Restore the trace of the completed transitions.
+        let mut trace = Trace::new();
+        for entry in &checkpoint.entries {
+            // Retrieve the stack.
+            let stack = self.get_stack(entry.transition.program_id())?;
+            let function_name = entry.transition.function_name();
+            // If the proving key does not exist, then synthesize it.
+            if !stack.contains_proving_key(function_name) {
+                stack.synthesize_from_assignment(function_name, &entry.assignment)?;
+            }
+            // Insert the transition.
+            let proving_key = stack.get_proving_key(function_name)?;
+            trace.insert_transition(
+                &entry.input_ids,
+                &entry.transition,
+                (proving_key, entry.assignment.clone()),
+                entry.metrics,
+            )?;
+        }
+        lap!(timer, "Restore {num_completed} transitions");
+
+        // Initialize an authorization for the remaining requests.
+        let mut remaining = requests.into_iter().skip(num_completed);
+        if let Some(request) = remaining.next() {
+            let authorization = Authorization::new(request);
+            remaining.for_each(|request| authorization.push(request));
+
+            // Execute each remaining top-level call, in order.
+            while !authorization.is_empty() {
+                // Retrieve the top-level request (without popping it).
+                let request = authorization.peek_next()?;
+                // Initialize the trace for the top-level call.
+                let call_trace = Arc::new(RwLock::new(Trace::new()));
+                // Initialize the call stack.
+                let call_stack = CallStack::execute(authorization.clone(), call_trace.clone())?;
+                // Execute the circuit.
+                let stack = self.get_stack(request.program_id())?;
+                stack.execute_function::<A, R>(call_stack, None, None, rng)?;
+                // Extract the trace.
+                let call_trace = Arc::try_unwrap(call_trace)
+                    .map_err(|_| anyhow!("The trace of '{}' is still in use", request.function_name()))?
+                    .into_inner();
+                lap!(timer, "Execute '{}/{}'", request.program_id(), request.function_name());
+
+                // Record the transitions of the top-level call.
+                for (input_ids, transition, (proving_key, assignment), metrics) in call_trace.into_transitions()? {
+                    trace.insert_transition(&input_ids, &transition, (proving_key, assignment.clone()), metrics)?;
+                    checkpoint.entries.push(CheckpointEntry { input_ids, transition, assignment, metrics });
+                }
+                // Hand the checkpoint to the caller.
+                on_checkpoint(&checkpoint)?;
+            }
+        }
+
+        // Ensure the trace is not empty.
+        ensure!(!trace.transitions().is_empty(), "The checkpointed execution is empty");
+        // Ensure the transitions are in canonical (call-tree post-order) order.
+        self.construct_call_trees_from(&trace.transitions().iter().collect::<Vec<_>>())?;
+        lap!(timer, "Check the transition order");
+
+        finish!(timer);
+        Ok(trace)
+    }
+}
+
+impl<N: Network> FromBytes for CheckpointEntry<N> {
+    /// Reads the checkpoint entry from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the input IDs.
+        let num_input_ids = u8::read_le(&mut reader)?;
+        let input_ids = (0..num_input_ids).map(|_| InputID::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the transition.
+        let transition = Transition::read_le(&mut reader)?;
+        // Read the assignment.
+        let assignment = Assignment::read_le(&mut reader)?;
+        // Read the call metrics.
+        let metrics = CallMetrics::read_le(&mut reader)?;
+        Ok(Self { input_ids, transition, assignment, metrics })
+    }
+}
+
+impl<N: Network> ToBytes for CheckpointEntry<N> {
+    /// Writes the checkpoint entry to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the input IDs.
+        u8::try_from(self.input_ids.len()).map_err(error)?.write_le(&mut writer)?;
+        self.input_ids.iter().try_for_each(|input_id| input_id.write_le(&mut writer))?;
+        // Write the transition.
+        self.transition.write_le(&mut writer)?;
+        // Write the assignment.
+        self.assignment.write_le(&mut writer)?;
+        // Write the call metrics.
+        self.metrics.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for ExecutionCheckpoint<N> {
+    /// Reads the execution checkpoint from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid execution checkpoint version"));
+        }
+        // Read the authorization.
+        let authorization = Authorization::read_le(&mut reader)?;
+        // Read the entries.
+        let num_entries = u8::read_le(&mut reader)?;
+        if num_entries as usize > authorization.len() {
+            return Err(error("The execution checkpoint has more transitions than requests"));
+        }
+        let entries = (0..num_entries).map(|_| CheckpointEntry::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the execution checkpoint.
+        Ok(Self { authorization, entries })
+    }
+}
+
+impl<N: Network> ToBytes for ExecutionCheckpoint<N> {
+    /// Writes the execution checkpoint to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the authorization.
+        self.authorization.write_le(&mut writer)?;
+        // Write the entries.
+        u8::try_from(self.entries.len()).map_err(error)?.write_le(&mut writer)?;
+        self.entries.iter().try_for_each(|entry| entry.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_execute_with_checkpoints() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program checkpoint.aleo;
+
+function double:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+        // Sample a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Bundle three top-level calls into one authorization.
+        let authorizations = ["1u64", "2u64", "3u64"]
+            .into_iter()
+            .map(|input| {
+                process.authorize::<CurrentAleo, _>(&private_key, program.id(), "double", [input].into_iter(), rng)
+            })
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        let authorization = Authorization::bundle(authorizations).unwrap();
+
+        // Interrupt the execution after the second top-level call, persisting each checkpoint.
+        let mut persisted = Vec::new();
+        let result = process.execute_with_checkpoints::<CurrentAleo, _>(
+            ExecutionCheckpoint::new(&authorization),
+            |checkpoint| {
+                persisted = checkpoint.to_bytes_le()?;
+                ensure!(checkpoint.num_completed() < 2, "Interrupted");
+                Ok(())
+            },
+            rng,
+        );
+        assert!(result.is_err());
+
+        // Resume the execution from the persisted checkpoint.
+        let checkpoint = ExecutionCheckpoint::<CurrentNetwork>::read_le(&persisted[..]).unwrap();
+        assert_eq!(checkpoint.num_completed(), 2);
+        assert!(!checkpoint.is_complete());
+        let mut num_checkpoints = 0;
+        let trace = process
+            .execute_with_checkpoints::<CurrentAleo, _>(
+                checkpoint,
+                |checkpoint| {
+                    num_checkpoints += 1;
+                    assert!(checkpoint.is_complete());
+                    Ok(())
+                },
+                rng,
+            )
+            .unwrap();
+        // Ensure only the remaining top-level call was executed.
+        assert_eq!(num_checkpoints, 1);
+
+        // Ensure the resumed execution matches an uninterrupted execution.
+        let (_, expected) = process.execute::<CurrentAleo, _>(authorization.replicate(), rng).unwrap();
+        assert_eq!(trace.transitions(), expected.transitions());
+
+        // Ensure a checkpoint for another authorization is rejected.
+        let checkpoint = ExecutionCheckpoint::<CurrentNetwork>::read_le(&persisted[..]).unwrap();
+        let mut other = ExecutionCheckpoint::new(&authorization);
+        other.entries = checkpoint.entries[1..].to_vec();
+        assert!(process.execute_with_checkpoints::<CurrentAleo, _>(other, |_| Ok(()), rng).is_err());
+    }
+}
@@ -16,9 +16,9 @@ use crate::{Process, Stack, StackProgramTypes};
 
 use console::{
     prelude::*,
-    program::{FinalizeType, Identifier, LiteralType, PlaintextType},
+    program::{FinalizeType, Identifier, LiteralType, PlaintextType, ProgramID},
 };
-use ledger_block::{Deployment, Execution};
+use ledger_block::{Deployment, Execution, FailedExecution, Fee};
 use synthesizer_program::{CastType, Command, Finalize, Instruction, Operand, StackProgram};
 
 /// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, synthesis cost, namespace cost)).
@@ -27,13 +27,26 @@ pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (
     let size_in_bytes = deployment.size_in_bytes()?;
     // Retrieve the program ID.
     let program_id = deployment.program_id();
-    // Determine the number of characters in the program ID.
-    let num_characters = u32::try_from(program_id.name().to_string().len())?;
     // Compute the number of combined variables in the program.
     let num_combined_variables = deployment.num_combined_variables()?;
     // Compute the number of combined constraints in the program.
     let num_combined_constraints = deployment.num_combined_constraints()?;
 
+    // Compute the deployment cost.
+    deployment_cost_from_counts(size_in_bytes, program_id, num_combined_variables, num_combined_constraints)
+}
+
+/// Returns the *minimum* cost in microcredits to publish a deployment of the given size and circuit counts
+/// (total cost, (storage cost, synthesis cost, namespace cost)).
+pub(crate) fn deployment_cost_from_counts<N: Network>(
+    size_in_bytes: u64,
+    program_id: &ProgramID<N>,
+    num_combined_variables: u64,
+    num_combined_constraints: u64,
+) -> Result<(u64, (u64, u64, u64))> {
+    // Determine the number of characters in the program ID.
+    let num_characters = u32::try_from(program_id.name().to_string().len())?;
+
     // Compute the storage cost in microcredits.
     let storage_cost = size_in_bytes
         .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
@@ -62,11 +75,11 @@ pub fn execution_cost<N: Network>(process: &Process<N>, execution: &Execution<N>
     // Compute the storage cost in microcredits.
     let storage_cost = execution.size_in_bytes()?;
 
-    // Get the root transition.
-    let transition = execution.peek()?;
-
-    // Get the finalize cost for the root transition.
-    let finalize_cost = process.get_stack(transition.program_id())?.get_finalize_cost(transition.function_name())?;
+    // Get the finalize cost for the root transition of each top-level call.
+    let finalize_cost = process.construct_call_trees(execution)?.iter().try_fold(0u64, |cost, call_tree| {
+        let finalize_cost = process.get_stack(call_tree.program_id())?.get_finalize_cost(call_tree.function_name())?;
+        cost.checked_add(finalize_cost).ok_or(anyhow!("The finalize cost computation overflowed for an execution"))
+    })?;
 
     // Compute the total cost in microcredits.
     let total_cost = storage_cost
@@ -76,6 +89,34 @@ pub fn execution_cost<N: Network>(process: &Process<N>, execution: &Execution<N>
     Ok((total_cost, (storage_cost, finalize_cost)))
 }
 
+/// Returns the failed execution for the given execution and fee, whose finalize scope aborted.
+/// The failed execution holds the *minimum* cost of the execution, which the fee must cover.
+/// Note: Finalize is not metered, so the finalize cost is the full *minimum* finalize cost of the execution.
+pub fn failed_execution<N: Network>(
+    process: &Process<N>,
+    execution: &Execution<N>,
+    fee: &Fee<N>,
+) -> Result<FailedExecution<N>> {
+    // Compute the cost of the execution.
+    let (_, (storage_cost, finalize_cost)) = execution_cost(process, execution)?;
+    // Return the failed execution.
+    FailedExecution::new(execution.clone(), fee.clone(), storage_cost, finalize_cost)
+}
+
+/// Ensures the given failed execution holds the expected costs.
+pub fn check_failed_execution<N: Network>(process: &Process<N>, failed: &FailedExecution<N>) -> Result<()> {
+    // Compute the expected cost of the failed execution.
+    let (_, (storage_cost, finalize_cost)) = execution_cost(process, failed.execution())?;
+    // Ensure the storage cost matches.
+    ensure!(
+        failed.storage_cost() == storage_cost,
+        "The storage cost of the failed execution is incorrect. Expected {storage_cost}, but found {}",
+        failed.storage_cost()
+    );
+    // Ensure the finalize cost matches.
+    failed.check_finalize_cost(finalize_cost)
+}
+
 /// Finalize costs for compute heavy operations, derived as:
 /// `BASE_COST + (PER_BYTE_COST * SIZE_IN_BYTES)`.
 
@@ -98,12 +139,12 @@ const SET_BASE_COST: u64 = 10_000;
 const SET_PER_BYTE_COST: u64 = 100;
 
 /// A helper function to determine the plaintext type in bytes.
-fn plaintext_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &PlaintextType<N>) -> Result<u64> {
+pub(crate) fn plaintext_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &PlaintextType<N>) -> Result<u64> {
     match plaintext_type {
         PlaintextType::Literal(literal_type) => Ok(literal_type.size_in_bytes::<N>() as u64),
         PlaintextType::Struct(struct_name) => {
             // Retrieve the struct from the stack.
-            let struct_ = stack.program().get_struct(struct_name)?;
+            let struct_ = stack.get_struct(struct_name)?;
             // Retrieve the size of the struct name.
             let size_of_name = struct_.name().to_bytes_le()?.len() as u64;
             // Retrieve the size of all the members of the struct.
@@ -169,6 +210,8 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::AssertNeq(_)) => Ok(500),
         Command::Instruction(Instruction::Async(_)) => bail!("'async' is not supported in finalize"),
         Command::Instruction(Instruction::Call(_)) => bail!("'call' is not supported in finalize"),
+        Command::Instruction(Instruction::CallView(_)) => bail!("'call.view' is not supported in finalize"),
+        Command::Instruction(Instruction::CallHost(_)) => bail!("'call.host' is not supported in finalize"),
         Command::Instruction(Instruction::Cast(cast)) => match cast.cast_type() {
             CastType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
             CastType::Plaintext(plaintext_type) => Ok(plaintext_size_in_bytes(stack, plaintext_type)?
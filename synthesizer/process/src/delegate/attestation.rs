@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An attestation by a prover of the software version and verifying keys that produced a proof.
+///
+/// Each verifying key is identified by its checksum, which allows a client to detect
+/// a prover that used keys which differ from the ones it expects.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProverAttestation<N: Network> {
+    /// The address of the prover.
+    prover: Address<N>,
+    /// The snarkVM version of the prover.
+    version: String,
+    /// The checksums of the verifying keys used, for each function.
+    checksums: IndexMap<Locator<N>, Field<N>>,
+}
+
+impl<N: Network> ProverAttestation<N> {
+    /// The snarkVM version of this library.
+    pub const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+
+    /// Initializes a new prover attestation from the given checksums.
+    pub fn from(prover: Address<N>, version: String, checksums: IndexMap<Locator<N>, Field<N>>) -> Result<Self> {
+        // Ensure the version is well-formed.
+        ensure!(!version.is_empty(), "The prover attestation version is empty");
+        ensure!(version.len() <= u8::MAX as usize, "The prover attestation version is too long");
+        // Ensure the checksums are not empty.
+        ensure!(!checksums.is_empty(), "The prover attestation contains no verifying keys");
+        Ok(Self { prover, version, checksums })
+    }
+
+    /// Initializes a new prover attestation, for the verifying keys in the given process
+    /// that correspond to the transitions of the given execution and fee.
+    pub fn new(
+        process: &Process<N>,
+        prover: Address<N>,
+        execution: &Execution<N>,
+        fee: Option<&Fee<N>>,
+    ) -> Result<Self> {
+        let mut checksums = IndexMap::new();
+        for transition in execution.transitions().chain(fee.map(|fee| fee.transition())) {
+            // Retrieve the program ID and function name.
+            let (program_id, function_name) = (transition.program_id(), transition.function_name());
+            // Compute the checksum of the verifying key, if it has not been computed.
+            if let indexmap::map::Entry::Vacant(entry) = checksums.entry(Locator::new(*program_id, *function_name)) {
+                let verifying_key = process.get_verifying_key(*program_id, *function_name)?;
+                entry.insert(Self::checksum(program_id, function_name, &verifying_key)?);
+            }
+        }
+        Self::from(prover, Self::VERSION.to_string(), checksums)
+    }
+
+    /// Returns the checksum of the given verifying key.
+    pub fn checksum(
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<Field<N>> {
+        N::hash_bhp1024(&to_bits_le![program_id, function_name, verifying_key.to_bytes_le()?])
+    }
+
+    /// Returns the address of the prover.
+    pub const fn prover(&self) -> &Address<N> {
+        &self.prover
+    }
+
+    /// Returns the snarkVM version of the prover.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the checksums of the verifying keys used, for each function.
+    pub const fn checksums(&self) -> &IndexMap<Locator<N>, Field<N>> {
+        &self.checksums
+    }
+
+    /// Returns the digest of the attestation, which is signed by the prover.
+    pub fn to_digest(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Ensures the attested verifying keys match the verifying keys in the given process.
+    pub fn verify(&self, process: &Process<N>) -> Result<()> {
+        for (locator, checksum) in &self.checksums {
+            // Retrieve the verifying key.
+            let verifying_key = process.get_verifying_key(*locator.program_id(), *locator.resource())?;
+            // Ensure the checksum matches.
+            ensure!(
+                *checksum == Self::checksum(locator.program_id(), locator.resource(), &verifying_key)?,
+                "The prover attested to a different verifying key for '{locator}'"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for ProverAttestation<N> {
+    /// Reads the prover attestation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let attestation_version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if attestation_version != 1 {
+            return Err(error("Invalid prover attestation version"));
+        }
+        // Read the prover.
+        let prover = Address::read_le(&mut reader)?;
+        // Read the snarkVM version.
+        let version_length = u8::read_le(&mut reader)?;
+        let mut version = vec![0u8; version_length as usize];
+        reader.read_exact(&mut version)?;
+        let version = String::from_utf8(version).map_err(error)?;
+        // Read the checksums.
+        let num_checksums = u16::read_le(&mut reader)?;
+        let checksums = (0..num_checksums)
+            .map(|_| Ok((Locator::read_le(&mut reader)?, Field::read_le(&mut reader)?)))
+            .collect::<IoResult<IndexMap<_, _>>>()?;
+        // Return the prover attestation.
+        Self::from(prover, version, checksums).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for ProverAttestation<N> {
+    /// Writes the prover attestation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the prover.
+        self.prover.write_le(&mut writer)?;
+        // Write the snarkVM version.
+        u8::try_from(self.version.len()).map_err(error)?.write_le(&mut writer)?;
+        writer.write_all(self.version.as_bytes())?;
+        // Write the checksums.
+        u16::try_from(self.checksums.len()).map_err(error)?.write_le(&mut writer)?;
+        for (locator, checksum) in &self.checksums {
+            locator.write_le(&mut writer)?;
+            checksum.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Serialize for ProverAttestation<N> {
+    /// Serializes the prover attestation into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut attestation = serializer.serialize_struct("ProverAttestation", 3)?;
+                attestation.serialize_field("prover", &self.prover)?;
+                attestation.serialize_field("version", &self.version)?;
+                attestation.serialize_field("checksums", &self.checksums)?;
+                attestation.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProverAttestation<N> {
+    /// Deserializes the prover attestation from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the prover attestation from a string into a value.
+                let mut attestation = serde_json::Value::deserialize(deserializer)?;
+                // Recover the prover attestation.
+                Self::from(
+                    // Retrieve the prover.
+                    DeserializeExt::take_from_value::<D>(&mut attestation, "prover")?,
+                    // Retrieve the snarkVM version.
+                    DeserializeExt::take_from_value::<D>(&mut attestation, "version")?,
+                    // Retrieve the checksums.
+                    DeserializeExt::take_from_value::<D>(&mut attestation, "checksums")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "prover attestation"),
+        }
+    }
+}
+
+impl<N: Network> FromStr for ProverAttestation<N> {
+    type Err = Error;
+
+    /// Initializes the prover attestation from a JSON-string.
+    fn from_str(attestation: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(attestation)?)
+    }
+}
+
+impl<N: Network> Debug for ProverAttestation<N> {
+    /// Prints the prover attestation as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ProverAttestation<N> {
+    /// Displays the prover attestation as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
@@ -0,0 +1,323 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An authorization, encrypted to the address of a remote prover.
+///
+/// # Secrecy
+/// The authorization is encrypted under a key shared with the prover, i.e. `(address * randomizer).x`,
+/// such that only the holder of the view key of the prover may decrypt it, and carries a checksum under
+/// the same key, such that any change to the ciphertext in transit is detected. The sender is not authenticated.
+/// Observe the ciphertext reveals the size of the authorization, and thus roughly its number of requests.
+///
+/// Once decrypted, the prover learns everything it needs to prove the authorization, namely for each request:
+/// the signer, the function, the inputs in plaintext (including input records), and the transition view key,
+/// with which it may decrypt the private inputs and outputs of the transition. The prover also learns the tag
+/// secret key of the signer, with which it may recognize the tags (and thus the spends) of the signer's records.
+///
+/// The prover does **not** learn the private key, nor the view key, of the signer. As each request is signed,
+/// the prover cannot alter the inputs, nor construct new requests on behalf of the signer, and as the outputs
+/// are committed to by the transition commitments, a client may check the returned execution with
+/// `Authorization::check_execution`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptedAuthorization<N: Network> {
+    /// The nonce of the shared key, i.e. `G * randomizer`.
+    nonce: Group<N>,
+    /// The number of bytes in the authorization.
+    num_bytes: u32,
+    /// The encrypted authorization.
+    ciphertext: Vec<Field<N>>,
+    /// The checksum of the ciphertext, under the shared key.
+    checksum: Field<N>,
+}
+
+impl<N: Network> EncryptedAuthorization<N> {
+    /// The number of randomizers that are derived in each block.
+    const BLOCK_SIZE: usize = 4096;
+    /// The version of the wire format.
+    pub const VERSION: u8 = 1;
+
+    /// Initializes a new encrypted authorization from its components, without verification.
+    pub const fn from(nonce: Group<N>, num_bytes: u32, ciphertext: Vec<Field<N>>, checksum: Field<N>) -> Self {
+        Self { nonce, num_bytes, ciphertext, checksum }
+    }
+
+    /// Encrypts the given authorization to the given prover.
+    pub fn encrypt<R: Rng + CryptoRng>(
+        authorization: &Authorization<N>,
+        prover: &Address<N>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Serialize the authorization.
+        let bytes = authorization.to_bytes_le()?;
+        let num_bytes = u32::try_from(bytes.len())?;
+
+        // Sample the randomizer, and compute the nonce.
+        let randomizer = Scalar::rand(rng);
+        let nonce = N::g_scalar_multiply(&randomizer);
+        // Compute the shared key.
+        let key = (**prover * randomizer).to_x_coordinate();
+
+        // Pack the bytes into field elements.
+        let plaintext = bytes
+            .chunks(Self::bytes_per_field())
+            .map(|chunk| Field::from_bits_le(&chunk.to_bits_le()))
+            .collect::<Result<Vec<_>>>()?;
+        // Encrypt the field elements.
+        let ciphertext = plaintext
+            .into_iter()
+            .zip_eq(Self::randomizers(key, bytes.len().div_ceil(Self::bytes_per_field())))
+            .map(|(plaintext, randomizer)| plaintext + randomizer)
+            .collect::<Vec<_>>();
+        // Compute the checksum.
+        let checksum = Self::compute_checksum(key, num_bytes, &ciphertext)?;
+
+        Ok(Self { nonce, num_bytes, ciphertext, checksum })
+    }
+
+    /// Decrypts the authorization with the given view key of the prover.
+    pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Authorization<N>> {
+        // Compute the shared key.
+        let key = (self.nonce * **view_key).to_x_coordinate();
+        // Ensure the checksum is valid.
+        ensure!(
+            self.checksum == Self::compute_checksum(key, self.num_bytes, &self.ciphertext)?,
+            "The encrypted authorization is not for this view key, or has been modified"
+        );
+        // Ensure the number of field elements matches the number of bytes.
+        let num_bytes = self.num_bytes as usize;
+        ensure!(
+            self.ciphertext.len() == num_bytes.div_ceil(Self::bytes_per_field()),
+            "The encrypted authorization is malformed"
+        );
+
+        // Decrypt the field elements, and unpack the bytes.
+        let mut bytes = Vec::with_capacity(self.ciphertext.len() * Self::bytes_per_field());
+        for (ciphertext, randomizer) in self.ciphertext.iter().zip_eq(Self::randomizers(key, self.ciphertext.len())) {
+            bytes.extend_from_slice(&(*ciphertext - randomizer).to_bytes_le()?[..Self::bytes_per_field()]);
+        }
+        bytes.truncate(num_bytes);
+        // Deserialize the authorization.
+        Ok(Authorization::read_le(&bytes[..])?)
+    }
+
+    /// Returns the nonce of the shared key.
+    pub const fn nonce(&self) -> &Group<N> {
+        &self.nonce
+    }
+
+    /// Returns the number of bytes in the authorization.
+    pub const fn num_bytes(&self) -> u32 {
+        self.num_bytes
+    }
+
+    /// Returns the encrypted authorization.
+    pub fn ciphertext(&self) -> &[Field<N>] {
+        &self.ciphertext
+    }
+
+    /// Returns the checksum of the ciphertext.
+    pub const fn checksum(&self) -> &Field<N> {
+        &self.checksum
+    }
+}
+
+impl<N: Network> EncryptedAuthorization<N> {
+    /// Returns the number of bytes that are packed into each field element.
+    fn bytes_per_field() -> usize {
+        Field::<N>::size_in_data_bits() / 8
+    }
+
+    /// Returns the given number of randomizers, derived from the given shared key.
+    fn randomizers(key: Field<N>, num_randomizers: usize) -> Vec<Field<N>> {
+        // Note: The randomizers are derived in blocks, as each derivation is bounded to `u16::MAX` randomizers.
+        (0..num_randomizers)
+            .step_by(Self::BLOCK_SIZE)
+            .flat_map(|start| {
+                let block = Field::from_u32(start as u32 / Self::BLOCK_SIZE as u32);
+                let size = Self::BLOCK_SIZE.min(num_randomizers - start) as u16;
+                N::hash_many_psd8(&[N::encryption_domain(), key, block], size)
+            })
+            .collect()
+    }
+
+    /// Returns the checksum of the given ciphertext, under the given shared key.
+    fn compute_checksum(key: Field<N>, num_bytes: u32, ciphertext: &[Field<N>]) -> Result<Field<N>> {
+        let mut preimage = Vec::with_capacity(ciphertext.len() + 2);
+        preimage.push(key);
+        preimage.push(Field::from_u32(num_bytes));
+        preimage.extend_from_slice(ciphertext);
+        N::hash_psd8(&preimage)
+    }
+}
+
+impl<N: Network> FromBytes for EncryptedAuthorization<N> {
+    /// Reads the encrypted authorization from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != Self::VERSION {
+            return Err(error("Invalid encrypted authorization version"));
+        }
+        // Read the nonce.
+        let nonce = Group::read_le(&mut reader)?;
+        // Read the number of bytes.
+        let num_bytes = u32::read_le(&mut reader)?;
+        // Read the ciphertext.
+        let num_fields = (num_bytes as usize).div_ceil(Self::bytes_per_field());
+        let ciphertext = (0..num_fields).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the checksum.
+        let checksum = Field::read_le(&mut reader)?;
+        // Return the encrypted authorization.
+        Ok(Self::from(nonce, num_bytes, ciphertext, checksum))
+    }
+}
+
+impl<N: Network> ToBytes for EncryptedAuthorization<N> {
+    /// Writes the encrypted authorization to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of field elements matches the number of bytes.
+        if self.ciphertext.len() != (self.num_bytes as usize).div_ceil(Self::bytes_per_field()) {
+            return Err(error("The encrypted authorization is malformed"));
+        }
+        // Write the version.
+        Self::VERSION.write_le(&mut writer)?;
+        // Write the nonce.
+        self.nonce.write_le(&mut writer)?;
+        // Write the number of bytes.
+        self.num_bytes.write_le(&mut writer)?;
+        // Write the ciphertext.
+        self.ciphertext.iter().try_for_each(|field| field.write_le(&mut writer))?;
+        // Write the checksum.
+        self.checksum.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> Serialize for EncryptedAuthorization<N> {
+    /// Serializes the encrypted authorization into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut encrypted = serializer.serialize_struct("EncryptedAuthorization", 5)?;
+                encrypted.serialize_field("version", &Self::VERSION)?;
+                encrypted.serialize_field("nonce", &self.nonce)?;
+                encrypted.serialize_field("num_bytes", &self.num_bytes)?;
+                encrypted.serialize_field("ciphertext", &self.ciphertext)?;
+                encrypted.serialize_field("checksum", &self.checksum)?;
+                encrypted.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for EncryptedAuthorization<N> {
+    /// Deserializes the encrypted authorization from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the encrypted authorization from a string into a value.
+                let mut encrypted = serde_json::Value::deserialize(deserializer)?;
+                // Ensure the version is valid.
+                let version: u8 = DeserializeExt::take_from_value::<D>(&mut encrypted, "version")?;
+                if version != Self::VERSION {
+                    return Err(de::Error::custom("Invalid encrypted authorization version"));
+                }
+                // Recover the encrypted authorization.
+                Ok(Self::from(
+                    // Retrieve the nonce.
+                    DeserializeExt::take_from_value::<D>(&mut encrypted, "nonce")?,
+                    // Retrieve the number of bytes.
+                    DeserializeExt::take_from_value::<D>(&mut encrypted, "num_bytes")?,
+                    // Retrieve the ciphertext.
+                    DeserializeExt::take_from_value::<D>(&mut encrypted, "ciphertext")?,
+                    // Retrieve the checksum.
+                    DeserializeExt::take_from_value::<D>(&mut encrypted, "checksum")?,
+                ))
+            }
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "encrypted authorization")
+            }
+        }
+    }
+}
+
+impl<N: Network> FromStr for EncryptedAuthorization<N> {
+    type Err = Error;
+
+    /// Initializes the encrypted authorization from a JSON-string.
+    fn from_str(encrypted: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(encrypted)?)
+    }
+}
+
+impl<N: Network> Debug for EncryptedAuthorization<N> {
+    /// Prints the encrypted authorization as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for EncryptedAuthorization<N> {
+    /// Displays the encrypted authorization as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_encrypted_authorization() {
+        let rng = &mut TestRng::default();
+
+        // Sample an authorization, and the prover.
+        let authorization = crate::stack::authorization::test_helpers::sample_authorization(rng);
+        let prover_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let prover_view_key = ViewKey::try_from(&prover_private_key).unwrap();
+        let prover = Address::try_from(&prover_private_key).unwrap();
+
+        // Encrypt the authorization to the prover, and ensure the prover decrypts it.
+        let encrypted = EncryptedAuthorization::encrypt(&authorization, &prover, rng).unwrap();
+        assert_eq!(encrypted.num_bytes() as usize, authorization.to_bytes_le().unwrap().len());
+        assert_eq!(encrypted.decrypt(&prover_view_key).unwrap(), authorization);
+
+        // Ensure another view key cannot decrypt it.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(encrypted.decrypt(&other_view_key).is_err());
+
+        // Ensure a modified ciphertext is rejected.
+        let mut ciphertext = encrypted.ciphertext().to_vec();
+        ciphertext[0] += Field::one();
+        let modified =
+            EncryptedAuthorization::from(*encrypted.nonce(), encrypted.num_bytes(), ciphertext, *encrypted.checksum());
+        assert!(modified.decrypt(&prover_view_key).is_err());
+
+        // Ensure the encrypted authorization round-trips through bytes and JSON.
+        assert_eq!(encrypted, EncryptedAuthorization::read_le(&encrypted.to_bytes_le().unwrap()[..]).unwrap());
+        assert_eq!(encrypted, EncryptedAuthorization::from_str(&encrypted.to_string()).unwrap());
+        assert_eq!(encrypted, bincode::deserialize(&bincode::serialize(&encrypted).unwrap()).unwrap());
+
+        // Ensure an unknown version is rejected.
+        let mut bytes = encrypted.to_bytes_le().unwrap();
+        bytes[0] = EncryptedAuthorization::<CurrentNetwork>::VERSION + 1;
+        assert!(EncryptedAuthorization::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+    }
+}
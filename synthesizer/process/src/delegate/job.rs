@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A request for a remote prover to prove an authorization.
+///
+/// The job binds the authorization (and optional fee authorization) to the constraints on its usage,
+/// namely the block height after which the job expires, and the prover that is allowed to fulfill it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProvingJob<N: Network> {
+    /// The authorization to prove.
+    authorization: Authorization<N>,
+    /// The fee authorization to prove, if any.
+    fee_authorization: Option<Authorization<N>>,
+    /// The block height after which the job expires, if any.
+    expiration_height: Option<u32>,
+    /// The prover that is allowed to fulfill the job, if any.
+    prover: Option<Address<N>>,
+}
+
+impl<N: Network> ProvingJob<N> {
+    /// Initializes a new proving job.
+    pub fn new(
+        authorization: Authorization<N>,
+        fee_authorization: Option<Authorization<N>>,
+        expiration_height: Option<u32>,
+        prover: Option<Address<N>>,
+    ) -> Result<Self> {
+        // Ensure the authorization is not empty.
+        ensure!(!authorization.transitions().is_empty(), "The proving job authorization is empty");
+        // Ensure the fee authorization is for a fee.
+        if let Some(fee_authorization) = &fee_authorization {
+            ensure!(
+                fee_authorization.is_fee_private() || fee_authorization.is_fee_public(),
+                "The proving job fee authorization is not for a fee"
+            );
+        }
+        Ok(Self { authorization, fee_authorization, expiration_height, prover })
+    }
+
+    /// Returns the authorization to prove.
+    pub const fn authorization(&self) -> &Authorization<N> {
+        &self.authorization
+    }
+
+    /// Returns the fee authorization to prove, if any.
+    pub const fn fee_authorization(&self) -> Option<&Authorization<N>> {
+        self.fee_authorization.as_ref()
+    }
+
+    /// Returns the block height after which the job expires, if any.
+    pub const fn expiration_height(&self) -> Option<u32> {
+        self.expiration_height
+    }
+
+    /// Returns the prover that is allowed to fulfill the job, if any.
+    pub const fn prover(&self) -> Option<&Address<N>> {
+        self.prover.as_ref()
+    }
+
+    /// Returns the job ID, which commits to the authorizations and the constraints on their usage.
+    pub fn to_job_id(&self) -> Result<Field<N>> {
+        // Compute the execution ID of the authorization.
+        let execution_id = self.authorization.to_execution_id()?;
+        // Compute the execution ID of the fee authorization, if it exists.
+        let fee_id = match &self.fee_authorization {
+            Some(fee_authorization) => fee_authorization.to_execution_id()?,
+            None => Field::zero(),
+        };
+        // Retrieve the prover, if it exists.
+        let prover = match &self.prover {
+            Some(prover) => prover.to_field()?,
+            None => Field::zero(),
+        };
+        // Compute the job ID.
+        N::hash_bhp1024(&to_bits_le![
+            execution_id,
+            fee_id,
+            self.expiration_height.is_some(),
+            self.expiration_height.unwrap_or_default(),
+            self.prover.is_some(),
+            prover
+        ])
+    }
+
+    /// Ensures the given prover is allowed to fulfill the job, at the given block height.
+    pub fn check(&self, prover: &Address<N>, block_height: u32) -> Result<()> {
+        // Ensure the prover is allowed to fulfill the job.
+        if let Some(expected_prover) = &self.prover {
+            ensure!(expected_prover == prover, "Prover '{prover}' is not allowed to fulfill the proving job");
+        }
+        // Ensure the job has not expired.
+        if let Some(expiration_height) = self.expiration_height {
+            ensure!(
+                block_height <= expiration_height,
+                "The proving job expired at block {expiration_height} (current block is {block_height})"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for ProvingJob<N> {
+    /// Reads the proving job from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid proving job version"));
+        }
+        // Read the authorization.
+        let authorization = Authorization::read_le(&mut reader)?;
+        // Read the fee authorization.
+        let fee_authorization = read_option(&mut reader)?;
+        // Read the expiration height.
+        let expiration_height = read_option(&mut reader)?;
+        // Read the prover.
+        let prover = read_option(&mut reader)?;
+        // Return the proving job.
+        Self::new(authorization, fee_authorization, expiration_height, prover).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for ProvingJob<N> {
+    /// Writes the proving job to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the authorization.
+        self.authorization.write_le(&mut writer)?;
+        // Write the fee authorization.
+        write_option(&self.fee_authorization, &mut writer)?;
+        // Write the expiration height.
+        write_option(&self.expiration_height, &mut writer)?;
+        // Write the prover.
+        write_option(&self.prover, &mut writer)
+    }
+}
+
+impl<N: Network> Serialize for ProvingJob<N> {
+    /// Serializes the proving job into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut job = serializer.serialize_struct("ProvingJob", 4)?;
+                job.serialize_field("authorization", &self.authorization)?;
+                job.serialize_field("fee_authorization", &self.fee_authorization)?;
+                job.serialize_field("expiration_height", &self.expiration_height)?;
+                job.serialize_field("prover", &self.prover)?;
+                job.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProvingJob<N> {
+    /// Deserializes the proving job from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the proving job from a string into a value.
+                let mut job = serde_json::Value::deserialize(deserializer)?;
+                // Recover the proving job.
+                Self::new(
+                    // Retrieve the authorization.
+                    DeserializeExt::take_from_value::<D>(&mut job, "authorization")?,
+                    // Retrieve the fee authorization.
+                    DeserializeExt::take_from_value::<D>(&mut job, "fee_authorization")?,
+                    // Retrieve the expiration height.
+                    DeserializeExt::take_from_value::<D>(&mut job, "expiration_height")?,
+                    // Retrieve the prover.
+                    DeserializeExt::take_from_value::<D>(&mut job, "prover")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proving job"),
+        }
+    }
+}
+
+impl<N: Network> FromStr for ProvingJob<N> {
+    type Err = Error;
+
+    /// Initializes the proving job from a JSON-string.
+    fn from_str(job: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(job)?)
+    }
+}
+
+impl<N: Network> Debug for ProvingJob<N> {
+    /// Prints the proving job as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ProvingJob<N> {
+    /// Displays the proving job as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_proving_job() {
+        let rng = &mut TestRng::default();
+
+        // Sample a fee authorization, and use it as the authorization to prove.
+        let authorization = crate::stack::authorization::test_helpers::sample_authorization(rng);
+        let prover = Address::<CurrentNetwork>::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        let other = Address::<CurrentNetwork>::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+
+        // Construct the proving job.
+        let job = ProvingJob::new(authorization.clone(), Some(authorization.clone()), Some(10), Some(prover)).unwrap();
+        assert!(job.check(&prover, 10).is_ok());
+        assert!(job.check(&prover, 11).is_err());
+        assert!(job.check(&other, 10).is_err());
+
+        // Ensure the job ID commits to the constraints.
+        let unconstrained = ProvingJob::new(authorization.clone(), Some(authorization), None, None).unwrap();
+        assert!(unconstrained.check(&other, u32::MAX).is_ok());
+        assert_ne!(job.to_job_id().unwrap(), unconstrained.to_job_id().unwrap());
+
+        // Ensure the job round-trips through bytes and JSON.
+        assert_eq!(job, ProvingJob::read_le(&job.to_bytes_le().unwrap()[..]).unwrap());
+        assert_eq!(job, ProvingJob::from_str(&job.to_string()).unwrap());
+        assert_eq!(job, bincode::deserialize(&bincode::serialize(&job).unwrap()).unwrap());
+        assert_eq!(job.to_job_id().unwrap(), ProvingJob::from_str(&job.to_string()).unwrap().to_job_id().unwrap());
+    }
+}
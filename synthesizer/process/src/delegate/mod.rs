@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod attestation;
+pub use attestation::*;
+
+mod encrypted;
+pub use encrypted::*;
+
+mod job;
+pub use job::*;
+
+mod result;
+pub use result::*;
+
+use crate::{Authorization, Process};
+use console::{
+    account::{Address, PrivateKey, Signature, ViewKey},
+    network::prelude::*,
+    program::{Identifier, Locator, ProgramID},
+    types::{Field, Group, Scalar},
+};
+use ledger_block::{Execution, Fee, Transaction};
+use synthesizer_snark::VerifyingKey;
+
+use indexmap::IndexMap;
+
+/// Reads an optional value from a buffer.
+fn read_option<T: FromBytes, R: Read>(mut reader: R) -> IoResult<Option<T>> {
+    match u8::read_le(&mut reader)? {
+        0 => Ok(None),
+        1 => Ok(Some(T::read_le(&mut reader)?)),
+        variant => Err(error(format!("Invalid option variant '{variant}'"))),
+    }
+}
+
+/// Writes an optional value to a buffer.
+fn write_option<T: ToBytes, W: Write>(value: &Option<T>, mut writer: W) -> IoResult<()> {
+    match value {
+        None => 0u8.write_le(&mut writer),
+        Some(value) => {
+            1u8.write_le(&mut writer)?;
+            value.write_le(&mut writer)
+        }
+    }
+}
@@ -0,0 +1,350 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The result of a proving job, signed by the prover.
+///
+/// The signature binds the job ID, the execution ID, the fee transition ID, and the prover attestation,
+/// such that a client can hold the prover accountable for the keys and version it attested to.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProvingResult<N: Network> {
+    /// The ID of the proving job.
+    job_id: Field<N>,
+    /// The proven execution.
+    execution: Execution<N>,
+    /// The proven fee, if any.
+    fee: Option<Fee<N>>,
+    /// The attestation of the prover.
+    attestation: ProverAttestation<N>,
+    /// The signature of the prover.
+    signature: Signature<N>,
+}
+
+impl<N: Network> ProvingResult<N> {
+    /// Initializes a new proving result, signed by the given prover.
+    pub fn new<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        job: &ProvingJob<N>,
+        execution: Execution<N>,
+        fee: Option<Fee<N>>,
+        attestation: ProverAttestation<N>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Ensure the attestation is from the prover.
+        ensure!(
+            attestation.prover() == &Address::try_from(private_key)?,
+            "The prover attestation is not from the signer of the proving result"
+        );
+        // Ensure the execution and fee fulfill the job.
+        Self::check_fulfillment(job, &execution, fee.as_ref())?;
+        // Compute the job ID.
+        let job_id = job.to_job_id()?;
+        // Sign the result.
+        let signature =
+            Signature::sign(private_key, &Self::message(job_id, &execution, fee.as_ref(), &attestation)?, rng)?;
+        Ok(Self { job_id, execution, fee, attestation, signature })
+    }
+
+    /// Initializes a new proving result from its components, without verification.
+    pub const fn from(
+        job_id: Field<N>,
+        execution: Execution<N>,
+        fee: Option<Fee<N>>,
+        attestation: ProverAttestation<N>,
+        signature: Signature<N>,
+    ) -> Self {
+        Self { job_id, execution, fee, attestation, signature }
+    }
+
+    /// Returns the ID of the proving job.
+    pub const fn job_id(&self) -> &Field<N> {
+        &self.job_id
+    }
+
+    /// Returns the proven execution.
+    pub const fn execution(&self) -> &Execution<N> {
+        &self.execution
+    }
+
+    /// Returns the proven fee, if any.
+    pub const fn fee(&self) -> Option<&Fee<N>> {
+        self.fee.as_ref()
+    }
+
+    /// Returns the attestation of the prover.
+    pub const fn attestation(&self) -> &ProverAttestation<N> {
+        &self.attestation
+    }
+
+    /// Returns the signature of the prover.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+
+    /// Ensures the proving result fulfills the given job at the given block height, and is signed by the prover.
+    ///
+    /// Note: This method does not verify the proofs, which is the responsibility of `Process::verify_execution`
+    /// and `Process::verify_fee`, and does not check the attested keys, see `ProverAttestation::verify`.
+    pub fn verify(&self, job: &ProvingJob<N>, block_height: u32) -> Result<()> {
+        // Ensure the result is for the given job.
+        ensure!(self.job_id == job.to_job_id()?, "The proving result is not for the given proving job");
+        // Ensure the prover is allowed to fulfill the job, at the given block height.
+        job.check(self.attestation.prover(), block_height)?;
+        // Ensure the execution and fee fulfill the job.
+        Self::check_fulfillment(job, &self.execution, self.fee.as_ref())?;
+        // Ensure the signature is valid.
+        let message = Self::message(self.job_id, &self.execution, self.fee.as_ref(), &self.attestation)?;
+        ensure!(self.signature.verify(self.attestation.prover(), &message), "The proving result signature is invalid");
+        Ok(())
+    }
+
+    /// Returns the transaction, and consumes `self`.
+    pub fn into_transaction(self) -> Result<Transaction<N>> {
+        Transaction::from_execution(self.execution, self.fee)
+    }
+}
+
+impl<N: Network> ProvingResult<N> {
+    /// Ensures the given execution and fee are the proven form of the authorizations in the job.
+    fn check_fulfillment(job: &ProvingJob<N>, execution: &Execution<N>, fee: Option<&Fee<N>>) -> Result<()> {
+        // Ensure the execution matches the authorization.
+        job.authorization().check_execution(execution)?;
+        // Ensure the fee matches the fee authorization.
+        match (job.fee_authorization(), fee) {
+            (Some(fee_authorization), Some(fee)) => ensure!(
+                fee_authorization.transitions().contains_key(fee.transition_id()),
+                "The fee does not match the proving job fee authorization"
+            ),
+            (None, None) => (),
+            _ => bail!("The fee does not match the proving job fee authorization"),
+        }
+        Ok(())
+    }
+
+    /// Returns the message signed by the prover.
+    fn message(
+        job_id: Field<N>,
+        execution: &Execution<N>,
+        fee: Option<&Fee<N>>,
+        attestation: &ProverAttestation<N>,
+    ) -> Result<Vec<Field<N>>> {
+        // Retrieve the fee transition ID, if it exists.
+        let fee_id = match fee {
+            Some(fee) => **fee.transition_id(),
+            None => Field::zero(),
+        };
+        Ok(vec![job_id, execution.to_execution_id()?, fee_id, attestation.to_digest()?])
+    }
+}
+
+impl<N: Network> FromBytes for ProvingResult<N> {
+    /// Reads the proving result from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid proving result version"));
+        }
+        // Read the job ID.
+        let job_id = Field::read_le(&mut reader)?;
+        // Read the execution.
+        let execution = Execution::read_le(&mut reader)?;
+        // Read the fee.
+        let fee = read_option(&mut reader)?;
+        // Read the attestation.
+        let attestation = ProverAttestation::read_le(&mut reader)?;
+        // Read the signature.
+        let signature = Signature::read_le(&mut reader)?;
+        // Return the proving result.
+        Ok(Self::from(job_id, execution, fee, attestation, signature))
+    }
+}
+
+impl<N: Network> ToBytes for ProvingResult<N> {
+    /// Writes the proving result to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the job ID.
+        self.job_id.write_le(&mut writer)?;
+        // Write the execution.
+        self.execution.write_le(&mut writer)?;
+        // Write the fee.
+        write_option(&self.fee, &mut writer)?;
+        // Write the attestation.
+        self.attestation.write_le(&mut writer)?;
+        // Write the signature.
+        self.signature.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> Serialize for ProvingResult<N> {
+    /// Serializes the proving result into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut result = serializer.serialize_struct("ProvingResult", 5)?;
+                result.serialize_field("job_id", &self.job_id)?;
+                result.serialize_field("execution", &self.execution)?;
+                result.serialize_field("fee", &self.fee)?;
+                result.serialize_field("attestation", &self.attestation)?;
+                result.serialize_field("signature", &self.signature)?;
+                result.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProvingResult<N> {
+    /// Deserializes the proving result from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the proving result from a string into a value.
+                let mut result = serde_json::Value::deserialize(deserializer)?;
+                // Recover the proving result.
+                Ok(Self::from(
+                    // Retrieve the job ID.
+                    DeserializeExt::take_from_value::<D>(&mut result, "job_id")?,
+                    // Retrieve the execution.
+                    DeserializeExt::take_from_value::<D>(&mut result, "execution")?,
+                    // Retrieve the fee.
+                    DeserializeExt::take_from_value::<D>(&mut result, "fee")?,
+                    // Retrieve the attestation.
+                    DeserializeExt::take_from_value::<D>(&mut result, "attestation")?,
+                    // Retrieve the signature.
+                    DeserializeExt::take_from_value::<D>(&mut result, "signature")?,
+                ))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proving result"),
+        }
+    }
+}
+
+impl<N: Network> FromStr for ProvingResult<N> {
+    type Err = Error;
+
+    /// Initializes the proving result from a JSON-string.
+    fn from_str(result: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(result)?)
+    }
+}
+
+impl<N: Network> Debug for ProvingResult<N> {
+    /// Prints the proving result as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ProvingResult<N> {
+    /// Displays the proving result as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ledger_query::Query;
+    use ledger_store::{helpers::memory::BlockMemory, BlockStore};
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_proving_result() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+
+        // Initialize the accounts.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let prover_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let prover = Address::try_from(&prover_private_key).unwrap();
+
+        // Construct the process.
+        let process = crate::test_helpers::sample_process(&program);
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                "compute",
+                ["5u32", "10u32"].into_iter(),
+                rng,
+            )
+            .unwrap();
+
+        // Construct the proving job.
+        let job = ProvingJob::new(authorization.replicate(), None, Some(100), Some(prover)).unwrap();
+
+        // Prove the job, as the prover.
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        trace.prepare(Query::from(BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap())).unwrap();
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+        assert!(job.authorization().check_execution(&execution).is_ok());
+        // Ensure the execution does not match the requests of another authorization.
+        let other_authorization = process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                "compute",
+                ["6u32", "10u32"].into_iter(),
+                rng,
+            )
+            .unwrap();
+        assert!(other_authorization.check_execution(&execution).is_err());
+        let attestation = ProverAttestation::new(&process, prover, &execution, None).unwrap();
+        assert_eq!(attestation.version(), ProverAttestation::<CurrentNetwork>::VERSION);
+        assert!(attestation.verify(&process).is_ok());
+
+        // Sign the proving result.
+        let result =
+            ProvingResult::new(&prover_private_key, &job, execution.clone(), None, attestation.clone(), rng).unwrap();
+        assert!(result.verify(&job, 100).is_ok());
+        assert!(process.verify_execution(result.execution()).is_ok());
+
+        // Ensure the result is rejected after the job expires.
+        assert!(result.verify(&job, 101).is_err());
+        // Ensure the result is rejected for a different job.
+        let other_job = ProvingJob::new(job.authorization().clone(), None, None, Some(prover)).unwrap();
+        assert!(result.verify(&other_job, 100).is_err());
+        // Ensure a result from a different prover is rejected.
+        assert!(ProvingResult::new(&caller_private_key, &job, execution, None, attestation, rng).is_err());
+
+        // Ensure the result round-trips through bytes and JSON.
+        let candidate = ProvingResult::from_str(&result.to_string()).unwrap();
+        assert_eq!(result, candidate);
+        assert_eq!(result, ProvingResult::read_le(&result.to_bytes_le().unwrap()[..]).unwrap());
+        assert!(candidate.verify(&job, 100).is_ok());
+        assert!(result.into_transaction().is_ok());
+    }
+}
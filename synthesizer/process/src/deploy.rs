@@ -14,6 +14,8 @@
 
 use super::*;
 
+use rand::rngs::OsRng;
+
 impl<N: Network> Process<N> {
     /// Deploys the given program ID, if it does not exist.
     #[inline]
@@ -24,6 +26,9 @@ impl<N: Network> Process<N> {
     ) -> Result<Deployment<N>> {
         let timer = timer!("Process::deploy");
 
+        // Ensure the process can accept the program.
+        self.check_program_limit(program.id())?;
+
         // Compute the stack.
         let stack = Stack::new(self, program)?;
         lap!(timer, "Compute the stack");
@@ -37,6 +42,54 @@ impl<N: Network> Process<N> {
         deployment
     }
 
+    /// Returns the estimated deployment cost of the given program, without generating circuit keys or certificates.
+    #[inline]
+    pub fn profile_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<DeploymentProfile<N>> {
+        let timer = timer!("Process::profile_deployment");
+
+        // Compute the stack.
+        let stack = Stack::new(self, program)?;
+        lap!(timer, "Compute the stack");
+
+        // Profile the deployment.
+        let profile = stack.profile_deployment::<A, R>(rng);
+        lap!(timer, "Profile the deployment");
+
+        finish!(timer);
+
+        profile
+    }
+
+    /// Returns the estimated deployment cost of the given program, with the constraint and variable counts
+    /// and the estimated fee of each function, without generating circuit keys or certificates.
+    #[inline]
+    pub fn estimate_deployment<A: circuit::Aleo<Network = N>>(
+        &self,
+        program: &Program<N>,
+    ) -> Result<DeploymentEstimate<N>> {
+        let timer = timer!("Process::estimate_deployment");
+
+        // Ensure the process can accept the program.
+        self.check_program_limit(program.id())?;
+
+        // Profile the deployment.
+        // Note: The sampled inputs only determine the witness, not the constraint and variable counts.
+        let profile = self.profile_deployment::<A, _>(program, &mut OsRng)?;
+        lap!(timer, "Profile the deployment");
+
+        // Estimate the deployment cost.
+        let estimate = DeploymentEstimate::new(program, profile);
+        lap!(timer, "Estimate the deployment cost");
+
+        finish!(timer);
+
+        estimate
+    }
+
     /// Adds the newly-deployed program.
     /// This method assumes the given deployment **is valid**.
     #[inline]
@@ -60,4 +113,24 @@ impl<N: Network> Process<N> {
 
         Ok(())
     }
+
+    /// Attaches the given developer-facing names to the registers of the given program in the process.
+    /// The names are reported in error messages, and may be inspected by the stepper.
+    /// As they are not part of the deployment, they are shipped beside it (e.g. with the program source).
+    ///
+    /// Note: The programs that import the given program keep their own copy of its stack,
+    /// so the names are only reported for the registers of the given program when it is called directly.
+    pub fn set_register_aliases(
+        &mut self,
+        program_id: impl TryInto<ProgramID<N>>,
+        register_aliases: &RegisterAliases<N>,
+    ) -> Result<()> {
+        // Retrieve a copy of the stack.
+        let mut stack = self.get_stack(program_id)?.as_ref().clone();
+        // Attach the register names.
+        stack.set_register_aliases(register_aliases)?;
+        // Replace the stack.
+        self.add_stack(stack);
+        Ok(())
+    }
 }
@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Process, Stack};
+use console::{network::prelude::*, program::Identifier};
+use synthesizer_program::{Program, StackProgram};
+use synthesizer_snark::{Certificate, VerifyingKey};
+
+use aleo_std::prelude::{finish, lap, timer};
+
+/// A verifier that checks a deployment incrementally, one function at a time.
+///
+/// The verifying keys and certificates are consumed as they are checked, so only one
+/// function's circuit is held in memory at a time. The functions must be given in the
+/// order they are declared in the program, as in `Deployment::check_is_ordered`.
+pub struct DeploymentVerifier<N: Network> {
+    /// The stack of the program being deployed.
+    stack: Stack<N>,
+    /// The index of the next function to verify.
+    index: usize,
+    /// The number of combined variables of the verified functions.
+    num_combined_variables: u64,
+    /// The number of combined constraints of the verified functions.
+    num_combined_constraints: u64,
+}
+
+impl<N: Network> DeploymentVerifier<N> {
+    /// Returns the program being deployed.
+    pub fn program(&self) -> &Program<N> {
+        self.stack.program()
+    }
+
+    /// Returns the name of the next function to verify, or `None` if every function has been verified.
+    pub fn next_function(&self) -> Option<&Identifier<N>> {
+        self.stack.program().functions().get_index(self.index).map(|(function_name, _)| function_name)
+    }
+
+    /// Returns the number of functions that have been verified.
+    pub const fn num_verified(&self) -> usize {
+        self.index
+    }
+
+    /// Verifies the given verifying key and certificate, for the next function in the program.
+    pub fn verify_next<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &mut self,
+        function_name: &Identifier<N>,
+        verifying_key: &VerifyingKey<N>,
+        certificate: &Certificate<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("DeploymentVerifier::verify_next");
+
+        // Retrieve the program ID.
+        let program_id = *self.stack.program_id();
+        // Ensure the function is the next function in the program.
+        match self.next_function() {
+            Some(expected) => ensure!(
+                expected == function_name,
+                "Expected the verifying key for '{program_id}/{expected}', found '{function_name}'"
+            ),
+            None => bail!("Program '{program_id}' has no function left to verify, found '{function_name}'"),
+        }
+
+        // Check that the number of combined variables does not exceed the deployment limit.
+        let num_combined_variables = self
+            .num_combined_variables
+            .checked_add(verifying_key.num_variables())
+            .ok_or_else(|| anyhow!("Overflow when counting variables for '{program_id}'"))?;
+        ensure!(num_combined_variables <= N::MAX_DEPLOYMENT_VARIABLES);
+        // Check that the number of combined constraints does not exceed the deployment limit.
+        let num_combined_constraints = self
+            .num_combined_constraints
+            .checked_add(verifying_key.circuit_info.num_constraints as u64)
+            .ok_or_else(|| anyhow!("Overflow when counting constraints for '{program_id}'"))?;
+        ensure!(num_combined_constraints <= N::MAX_DEPLOYMENT_CONSTRAINTS);
+        lap!(timer, "Check the deployment limits");
+
+        // Ensure the verifying key is well-formed and the certificate is valid.
+        self.stack.verify_function_deployment::<A, R>(function_name, verifying_key, certificate, rng)?;
+        lap!(timer, "Verify the certificate for {function_name}");
+
+        // Advance to the next function.
+        self.index += 1;
+        self.num_combined_variables = num_combined_variables;
+        self.num_combined_constraints = num_combined_constraints;
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Ensures every function in the program has been verified.
+    pub fn finish(self) -> Result<()> {
+        let program_id = self.stack.program_id();
+        match self.next_function() {
+            Some(function_name) => bail!("The verifying key for '{program_id}/{function_name}' is missing"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns a verifier for a deployment of the given program, at the given edition.
+    ///
+    /// This is the streaming counterpart of `Process::verify_deployment`, for deployments that are too large
+    /// to hold in memory. The verifying keys and certificates are then given to `DeploymentVerifier::verify_next`,
+    /// in the order of the functions in the program, followed by `DeploymentVerifier::finish`.
+    #[inline]
+    pub fn start_deployment_verification(&self, edition: u16, program: &Program<N>) -> Result<DeploymentVerifier<N>> {
+        let timer = timer!("Process::start_deployment_verification");
+
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the edition is correct.
+        ensure!(edition == N::EDITION, "Deployed the wrong edition (expected '{}', found '{edition}').", N::EDITION);
+        // Ensure the program does not already exist in the process.
+        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
+
+        // Ensure the program is well-formed, by computing the stack.
+        let stack = Stack::new(self, program)?;
+        lap!(timer, "Compute the stack");
+
+        // Ensure the program contains functions.
+        ensure!(!program.functions().is_empty(), "No functions present in the deployment for program '{program_id}'");
+        // Ensure the program does not create unspendable records.
+        stack.check_record_owners()?;
+
+        finish!(timer);
+        Ok(DeploymentVerifier { stack, index: 0, num_combined_variables: 0, num_combined_constraints: 0 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_deployment_verifier() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program with two functions.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program streaming.aleo;
+
+function add_one:
+    input r0 as u8.private;
+    add r0 1u8 into r1;
+    output r1 as u8.private;
+
+function mul_two:
+    input r0 as u64.public;
+    mul r0 2u64 into r1;
+    output r1 as u64.public;",
+        )?;
+
+        // Initialize the process, and deploy the program.
+        let process = Process::<CurrentNetwork>::load()?;
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng)?;
+        let verifying_keys = deployment.verifying_keys();
+
+        // Ensure the streamed deployment is verified.
+        let mut verifier = process.start_deployment_verification(deployment.edition(), &program)?;
+        for (function_name, (verifying_key, certificate)) in verifying_keys {
+            assert_eq!(verifier.next_function(), Some(function_name));
+            verifier.verify_next::<CurrentAleo, _>(function_name, verifying_key, certificate, rng)?;
+        }
+        assert_eq!(verifier.num_verified(), 2);
+        assert_eq!(verifier.next_function(), None);
+        verifier.finish()?;
+
+        // Ensure an incomplete stream is rejected.
+        let mut verifier = process.start_deployment_verification(deployment.edition(), &program)?;
+        let (function_name, (verifying_key, certificate)) = &verifying_keys[0];
+        verifier.verify_next::<CurrentAleo, _>(function_name, verifying_key, certificate, rng)?;
+        assert!(verifier.finish().is_err());
+
+        // Ensure an out-of-order stream is rejected.
+        let mut verifier = process.start_deployment_verification(deployment.edition(), &program)?;
+        let (function_name, (verifying_key, certificate)) = &verifying_keys[1];
+        assert!(verifier.verify_next::<CurrentAleo, _>(function_name, verifying_key, certificate, rng).is_err());
+
+        // Ensure a verifying key for the wrong function is rejected.
+        let mut verifier = process.start_deployment_verification(deployment.edition(), &program)?;
+        let (verifying_key, certificate) = &verifying_keys[1].1;
+        let function_name = &verifying_keys[0].0;
+        assert!(verifier.verify_next::<CurrentAleo, _>(function_name, verifying_key, certificate, rng).is_err());
+        assert_eq!(verifier.num_verified(), 0);
+
+        // Ensure the wrong edition is rejected.
+        assert!(process.start_deployment_verification(deployment.edition() + 1, &program).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::time::Duration;
+
+/// The estimated number of domain elements processed per second by the prover, used to estimate the proving time.
+/// Note: This is a rough estimate for a commodity multi-core machine, and should be calibrated for the target hardware.
+pub const ESTIMATED_PROVING_ELEMENTS_PER_SECOND: u64 = 100_000;
+
+/// The resource usage of a single transition in a dry run.
+#[derive(Copy, Clone, Debug)]
+pub struct TransitionUsage<N: Network> {
+    /// The call metrics of the transition.
+    metrics: CallMetrics<N>,
+    /// The number of constraints in the circuit.
+    num_constraints: u64,
+    /// The number of constant, public, and private variables in the circuit.
+    num_variables: u64,
+    /// The number of nonzero entries in the `A`, `B`, and `C` matrices.
+    num_nonzeros: (u64, u64, u64),
+}
+
+impl<N: Network> TransitionUsage<N> {
+    /// Returns the call metrics of the transition.
+    pub const fn metrics(&self) -> &CallMetrics<N> {
+        &self.metrics
+    }
+
+    /// Returns the number of constraints in the circuit.
+    pub const fn num_constraints(&self) -> u64 {
+        self.num_constraints
+    }
+
+    /// Returns the number of constant, public, and private variables in the circuit.
+    pub const fn num_variables(&self) -> u64 {
+        self.num_variables
+    }
+
+    /// Returns the number of nonzero entries in the `A`, `B`, and `C` matrices.
+    pub const fn num_nonzeros(&self) -> (u64, u64, u64) {
+        self.num_nonzeros
+    }
+
+    /// Returns the size of the largest evaluation domain used to prove the circuit.
+    pub fn domain_size(&self) -> u64 {
+        let (a, b, c) = self.num_nonzeros;
+        let max_size = [self.num_constraints, self.num_variables, a, b, c].into_iter().max().unwrap_or(0);
+        max_size.checked_next_power_of_two().unwrap_or(u64::MAX)
+    }
+}
+
+/// The resource usage of a dry run, which executes a function end-to-end without producing proofs.
+#[derive(Clone, Debug)]
+pub struct DryRun<N: Network> {
+    /// The resource usage of each transition, in the order of the transitions in the execution.
+    transitions: Vec<TransitionUsage<N>>,
+}
+
+impl<N: Network> DryRun<N> {
+    /// Returns the resource usage of each transition, in the order of the transitions in the execution.
+    pub fn transitions(&self) -> &[TransitionUsage<N>] {
+        &self.transitions
+    }
+
+    /// Returns the number of transitions.
+    pub fn num_transitions(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// Returns the number of combined constraints across all transitions.
+    pub fn num_constraints(&self) -> u64 {
+        self.transitions.iter().fold(0u64, |total, usage| total.saturating_add(usage.num_constraints))
+    }
+
+    /// Returns the number of combined variables across all transitions.
+    pub fn num_variables(&self) -> u64 {
+        self.transitions.iter().fold(0u64, |total, usage| total.saturating_add(usage.num_variables))
+    }
+
+    /// Returns the estimated time to prove the execution, with `ESTIMATED_PROVING_ELEMENTS_PER_SECOND`.
+    pub fn estimated_proving_time(&self) -> Duration {
+        self.estimated_proving_time_with(ESTIMATED_PROVING_ELEMENTS_PER_SECOND)
+    }
+
+    /// Returns the estimated time to prove the execution, given the number of domain elements proven per second.
+    /// Note: This excludes the inclusion proof for input records, and the time to synthesize missing circuit keys.
+    pub fn estimated_proving_time_with(&self, elements_per_second: u64) -> Duration {
+        let num_elements = self.transitions.iter().fold(0u64, |total, usage| total.saturating_add(usage.domain_size()));
+        Duration::from_secs_f64(num_elements as f64 / elements_per_second.max(1) as f64)
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Executes the given authorization end-to-end, without synthesizing circuit keys or producing proofs,
+    /// and returns the response and the resource usage of the execution.
+    #[inline]
+    pub fn dry_run<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, DryRun<N>)> {
+        let timer = timer!("Process::dry_run");
+
+        // This is the root request and does not have a caller.
+        let caller = None;
+        // This is the root request and we do not have a root_tvk to pass on.
+        let root_tvk = None;
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+
+        // Execute each top-level call, in order.
+        let mut response = None;
+        while !authorization.is_empty() {
+            // Retrieve the top-level request (without popping it).
+            let request = authorization.peek_next()?;
+            // Initialize the call stack.
+            let call_stack = CallStack::dry_run(authorization.clone(), assignments.clone())?;
+            // Retrieve the stack.
+            let stack = self.get_stack(request.program_id())?;
+            // Execute the circuit.
+            response = Some(stack.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?);
+            lap!(timer, "Execute the function");
+        }
+        // Ensure there was at least one top-level call.
+        let Some(response) = response else { bail!("Expected the authorization to contain a request") };
+
+        // Construct the resource usage of each transition.
+        let transitions = assignments
+            .read()
+            .iter()
+            .map(|(assignment, metrics)| TransitionUsage {
+                metrics: *metrics,
+                num_constraints: assignment.num_constraints(),
+                num_variables: assignment.num_variables(),
+                num_nonzeros: assignment.num_nonzeros(),
+            })
+            .collect();
+
+        finish!(timer);
+        Ok((response, DryRun { transitions }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_dry_run() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a child program.
+        let child = Program::<CurrentNetwork>::from_str(
+            r"
+program dry_run_child.aleo;
+
+function double:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+        // Initialize a program that calls the child program twice.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import dry_run_child.aleo;
+
+program dry_run.aleo;
+
+function quadruple:
+    input r0 as u64.private;
+    call dry_run_child.aleo/double r0 into r1;
+    call dry_run_child.aleo/double r1 into r2;
+    output r2 as u64.private;",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("quadruple").unwrap();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+        process.add_program(&child).unwrap();
+        process.add_program(&program).unwrap();
+        // Initialize a caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, ["3u64"].into_iter(), rng)
+            .unwrap();
+        let num_transitions = authorization.len();
+
+        // Dry run the authorization.
+        let (response, dry_run) = process.dry_run::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), &[Value::from_str("12u64").unwrap()]);
+        assert_eq!(num_transitions, 3);
+        assert_eq!(dry_run.num_transitions(), num_transitions);
+        assert!(dry_run.num_constraints() > 0);
+        assert!(dry_run.num_variables() > 0);
+        assert!(dry_run.estimated_proving_time() > Duration::ZERO);
+        // Ensure the root transition is the last transition.
+        let root = dry_run.transitions().last().unwrap();
+        assert_eq!(root.metrics().function_name, function_name);
+        assert!(root.domain_size().is_power_of_two());
+
+        // Ensure the dry run did not synthesize any circuit keys.
+        for program in [&child, &program] {
+            let stack = process.get_stack(program.id()).unwrap();
+            assert!(program.functions().keys().all(|function_name| !stack.contains_proving_key(function_name)));
+        }
+    }
+}
@@ -13,27 +13,357 @@
 // limitations under the License.
 
 use super::*;
+use synthesizer_program::Opcode;
 
 impl<N: Network> Process<N> {
     /// Evaluates a program function on the given request.
+    ///
+    /// If the authorization bundles several top-level calls, they are evaluated in order,
+    /// and the response of the last top-level call is returned.
     #[inline]
     pub fn evaluate<A: circuit::Aleo<Network = N>>(&self, authorization: Authorization<N>) -> Result<Response<N>> {
-        let timer = timer!("Process::evaluate");
+        self.evaluate_with_options::<A>(authorization, EvaluateOptions::default())
+    }
+
+    /// Evaluates a program function on the given request, in constant time.
+    ///
+    /// The instructions are evaluated in time that is independent of the values (but not the types) of their
+    /// operands, for services that evaluate secret inputs. The evaluation fails if the function (or any closure
+    /// or function it calls) contains an instruction that cannot yet be evaluated in constant time.
+    /// See `Process::constant_time_report` to determine these instructions ahead of time.
+    #[inline]
+    pub fn evaluate_constant_time<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+    ) -> Result<Response<N>> {
+        self.evaluate_with_options::<A>(authorization, EvaluateOptions::default().with_constant_time())
+    }
+
+    /// Evaluates a program function on the given request, within the given resource limits.
+    ///
+    /// This is intended for services that evaluate untrusted programs, and halts the evaluation
+    /// if a value or the registers of a closure or function exceed their size limits,
+    /// or if the evaluation exceeds its timeout (which is checked before each instruction).
+    #[inline]
+    pub fn evaluate_with_limits<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        limits: EvaluationLimits,
+    ) -> Result<Response<N>> {
+        self.evaluate_with_options::<A>(authorization, EvaluateOptions::default().with_limits(limits))
+    }
+
+    /// Evaluates a program function on the given request, invoking the given observer before each instruction.
+    ///
+    /// The observer is invoked on the instructions of every closure and function that is called,
+    /// which allows tooling, such as an instruction debugger, to inspect the evaluation.
+    #[inline]
+    pub fn evaluate_with_observer<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        observer: Arc<dyn EvaluationObserver<N>>,
+    ) -> Result<Response<N>> {
+        self.evaluate_with_options::<A>(authorization, EvaluateOptions::default().with_observer(observer))
+    }
+
+    /// Evaluates a program function on the given request, with the given evaluation options.
+    ///
+    /// The options may combine constant-time evaluation, resource limits, and an observer.
+    /// Note: The clock for the timeout starts when the limits are set, and spans all of the top-level calls.
+    #[inline]
+    pub fn evaluate_with_options<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        options: EvaluateOptions<N>,
+    ) -> Result<Response<N>> {
+        let timer = timer!("Process::evaluate_with_options");
+        // Evaluate each top-level call, in order.
+        let call_stack = CallStack::evaluate_with_options(authorization.clone(), options)?;
+        let response = self.evaluate_top_level_calls::<A>(&authorization, call_stack);
+        finish!(timer);
+        response
+    }
 
+    /// Evaluates each top-level call in the given authorization, in order, on the given call stack,
+    /// and returns the response of the last top-level call.
+    ///
+    /// Note: The call stack must share the given authorization, as each evaluation consumes its requests.
+    fn evaluate_top_level_calls<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: &Authorization<N>,
+        call_stack: CallStack<N>,
+    ) -> Result<Response<N>> {
         // Retrieve the top-level request (without popping it).
         let request = authorization.peek_next()?;
 
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Evaluating '{}/{}'...", request.program_id(), request.function_name()).dimmed());
 
-        // Retrieve the stack.
-        let stack = self.get_stack(request.program_id())?;
-        // Evaluate the function.
-        let response = stack.evaluate_function::<A>(CallStack::evaluate(authorization)?, None);
-        lap!(timer, "Evaluate the function");
+        // Evaluate each top-level call, in order.
+        let mut response = None;
+        while !authorization.is_empty() {
+            // Retrieve the top-level request (without popping it).
+            let request = authorization.peek_next()?;
+            // Retrieve the stack.
+            let stack = self.get_stack(request.program_id())?;
+            // Evaluate the function.
+            response = Some(stack.evaluate_function::<A>(call_stack.clone(), None)?);
+        }
 
-        finish!(timer);
+        // Return the response of the last top-level call.
+        response.ok_or_else(|| anyhow!("Evaluation of '{}/{}' is empty", request.program_id(), request.function_name()))
+    }
 
-        response
+    /// Returns the opcodes that cannot yet be evaluated in constant time, for each closure and function in the program.
+    /// Closures and functions that can be evaluated in constant time are omitted.
+    ///
+    /// Note: Calls to external programs are not followed; their reports must be retrieved separately.
+    pub fn constant_time_report(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+    ) -> Result<IndexMap<Identifier<N>, IndexSet<Opcode>>> {
+        // Retrieve the program.
+        let program = self.get_program(program_id)?;
+
+        // Returns the opcodes of the given instructions that cannot yet be evaluated in constant time.
+        let collect = |instructions: &[Instruction<N>]| {
+            instructions
+                .iter()
+                .filter(|instruction| !instruction.is_constant_time())
+                .map(|instruction| instruction.opcode())
+                .collect::<IndexSet<_>>()
+        };
+
+        // Construct the report.
+        let closures = program.closures().iter().map(|(name, closure)| (*name, collect(closure.instructions())));
+        let functions = program.functions().iter().map(|(name, function)| (*name, collect(function.instructions())));
+        Ok(closures.chain(functions).filter(|(_, opcodes)| !opcodes.is_empty()).collect())
+    }
+
+    /// Queries a pure program function on the given inputs, and returns the outputs.
+    ///
+    /// This is a read-only "view call", which evaluates the function without a private key, records, or circuit keys.
+    /// The function must be pure (see `Stack::ensure_pure_function`), so that its outputs only depend on its inputs.
+    /// As such, the outputs are memoized, until the program is redeployed to the process.
+    #[inline]
+    pub fn query_function<A: circuit::Aleo<Network = N>>(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+    ) -> Result<Vec<Value<N>>> {
+        // Prepare the program ID.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Prepare the inputs.
+        let inputs = inputs
+            .map(|input| input.try_into().map_err(|_| anyhow!("Failed to parse input for '{function_name}'")))
+            .collect::<Result<Vec<_>>>()?;
+
+        // If the query is memoized, return the memoized outputs.
+        let key = Self::to_query_key(program_id, function_name, &inputs)?;
+        if let Some(outputs) = self.get_memoized_query(&key) {
+            return Ok(outputs);
+        }
+        // Query the function.
+        let outputs = self.get_stack(program_id)?.query_function::<A>(function_name, inputs.into_iter())?;
+        // Memoize the outputs.
+        self.memoize_query(key, outputs.clone());
+        Ok(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_evaluate_constant_time() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program constant_time.aleo;
+
+closure select:
+    input r0 as boolean;
+    input r1 as u64;
+    input r2 as u64;
+    ternary r0 r1 r2 into r3;
+    output r3 as u64;
+
+function compare:
+    input r0 as field.private;
+    input r1 as field.private;
+    input r2 as u64.private;
+    is.eq r0 r1 into r3;
+    call select r3 r2 0u64 into r4;
+    assert.neq r0 0field;
+    output r4 as u64.private;
+
+function sum:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;",
+        )
+        .unwrap();
+
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Ensure the report lists the opcodes that cannot yet be evaluated in constant time.
+        let report = process.constant_time_report(program.id()).unwrap();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.get(&Identifier::from_str("sum").unwrap()).unwrap().len(), 1);
+
+        // Sample a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Evaluates the given function in constant time, and in the default mode.
+        let evaluate = |function_name: &str, inputs: &[&str], rng: &mut TestRng| {
+            let authorization = process.authorize::<CurrentAleo, _>(
+                &private_key,
+                program.id(),
+                function_name,
+                inputs.iter().copied(),
+                rng,
+            )?;
+            let expected = process.evaluate::<CurrentAleo>(authorization.replicate())?;
+            let candidate = process.evaluate_constant_time::<CurrentAleo>(authorization)?;
+            assert_eq!(expected.outputs(), candidate.outputs());
+            Ok::<_, Error>(candidate.outputs().to_vec())
+        };
+
+        // Ensure the constant-time evaluation matches the default evaluation.
+        let outputs = evaluate("compare", &["1field", "1field", "5u64"], rng).unwrap();
+        assert_eq!(outputs, vec![Value::from_str("5u64").unwrap()]);
+        let outputs = evaluate("compare", &["1field", "2field", "5u64"], rng).unwrap();
+        assert_eq!(outputs, vec![Value::from_str("0u64").unwrap()]);
+        // Ensure a failed assertion is rejected.
+        assert!(evaluate("compare", &["0field", "0field", "5u64"], rng).is_err());
+
+        // Ensure an instruction that cannot yet be evaluated in constant time is rejected.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, program.id(), "sum", ["1u64", "2u64"].into_iter(), rng)
+            .unwrap();
+        assert!(process.evaluate::<CurrentAleo>(authorization.replicate()).is_ok());
+        assert!(process.evaluate_constant_time::<CurrentAleo>(authorization).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_record_with_optional_entries() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program optional_entries.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+    memo as field.private default 7field;
+
+function mint:
+    input r0 as u64.private;
+    cast self.signer r0 into r1 as token.record;
+    output r1 as token.record;
+
+function read:
+    input r0 as token.record;
+    output r0.memo as field.private;",
+        )
+        .unwrap();
+
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Sample a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = console::account::Address::try_from(&private_key).unwrap();
+
+        // Ensure casting to a record without its optional entry uses the default value.
+        let authorization =
+            process.authorize::<CurrentAleo, _>(&private_key, program.id(), "mint", ["5u64"].into_iter(), rng).unwrap();
+        let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+        let Value::Record(record) = &response.outputs()[0] else { panic!("Expected a record") };
+        assert_eq!(record.data().len(), 2);
+        assert_eq!(
+            record.find(&[Identifier::<CurrentNetwork>::from_str("memo").unwrap()]).unwrap().to_string(),
+            "7field.private"
+        );
+
+        // Ensure a record without its optional entry (i.e. created before the entry was added) can be read.
+        let record = format!("{{ owner: {address}.private, amount: 5u64.private, _nonce: 0group.public }}");
+        let authorization =
+            process.authorize::<CurrentAleo, _>(&private_key, program.id(), "read", [record].into_iter(), rng).unwrap();
+        let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+        assert_eq!(response.outputs(), &[Value::from_str("7field").unwrap()]);
+    }
+
+    #[test]
+    fn test_query_function() {
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program query.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+closure double:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function quote:
+    input r0 as u64.public;
+    input r1 as u64.private;
+    call double r0 into r2;
+    add r2 r1 into r3;
+    output r3 as u64.public;
+
+function whoami:
+    input r0 as u64.public;
+    output self.caller as address.public;
+
+function mint:
+    input r0 as u64.public;
+    cast self.signer r0 into r1 as token.record;
+    output r1 as token.record;",
+        )
+        .unwrap();
+
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Ensure a pure function can be queried.
+        let outputs =
+            process.query_function::<CurrentAleo>(program.id(), "quote", ["2u64", "3u64"].into_iter()).unwrap();
+        assert_eq!(outputs, vec![Value::from_str("7u64").unwrap()]);
+
+        // Ensure the inputs are checked.
+        assert!(process.query_function::<CurrentAleo>(program.id(), "quote", ["2u64"].into_iter()).is_err());
+        assert!(process.query_function::<CurrentAleo>(program.id(), "quote", ["2u64", "3field"].into_iter()).is_err());
+
+        // Ensure functions that are not pure are rejected.
+        assert!(process.query_function::<CurrentAleo>(program.id(), "whoami", ["1u64"].into_iter()).is_err());
+        assert!(process.query_function::<CurrentAleo>(program.id(), "mint", ["1u64"].into_iter()).is_err());
+
+        // Ensure functions that output the signer or caller are rejected before evaluation.
+        let stack = process.get_stack(program.id()).unwrap();
+        assert!(stack.ensure_pure_function(&Identifier::from_str("quote").unwrap()).is_ok());
+        assert!(stack.ensure_pure_function(&Identifier::from_str("whoami").unwrap()).is_err());
     }
 }
@@ -14,16 +14,89 @@
 
 use super::*;
 
+use console::program::InputID;
+use ledger_query::QueryTrait;
+
+use parking_lot::Mutex;
+use rand::{rngs::StdRng, SeedableRng};
+use std::collections::HashSet;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 impl<N: Network> Process<N> {
     /// Executes the given authorization.
+    ///
+    /// If the authorization bundles several top-level calls, the circuits of independent calls are synthesized
+    /// concurrently, and their transitions are appended in order into a single trace.
+    /// The response of the last top-level call is returned.
+    ///
+    /// Note: Only the synthesis is concurrent. The trace is still proven with a single batch proof.
     #[inline]
     pub fn execute<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
         &self,
         authorization: Authorization<N>,
         rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        self.execute_with_cancellation::<A, R>(authorization, &CancellationToken::default(), rng)
+    }
+
+    /// Executes the given authorization, halting with `ExecutionCancelled` once the given token is cancelled.
+    ///
+    /// The token is checked between instructions, and is kept by the returned trace,
+    /// so that proving the trace is also halted between the prover rounds once cancelled.
+    #[inline]
+    pub fn execute_with_cancellation<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        cancellation: &CancellationToken,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        // Initialize the trace.
+        let mut trace = Trace::new();
+        trace.set_cancellation_token(cancellation.clone());
+        self.execute_into_trace::<A, R>(authorization, trace, rng)
+    }
+
+    /// Executes the given authorization, streaming each transition to the given sink as soon as it is completed,
+    /// so that the transitions may be written to disk or sent over the network while the execution is in progress.
+    ///
+    /// The transitions are streamed in the order of the returned trace; with an `ExecutionWriter` as the sink,
+    /// the execution is completed with `Trace::prove_execution_for_sink`. The trace does not keep the sink.
+    ///
+    /// Note: Streaming does not reduce the peak memory of the execution. The trace still retains every transition,
+    /// along with its assignment, as they are needed to compute the inclusion proofs and the batch proof.
+    #[inline]
+    pub fn execute_with_sink<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        sink: Arc<Mutex<dyn TransitionSink<N>>>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        // Initialize the trace.
+        let mut trace = Trace::new();
+        trace.set_transition_sink(sink);
+        // Execute the authorization.
+        let (response, mut trace) = self.execute_into_trace::<A, R>(authorization, trace, rng)?;
+        // Remove the sink from the trace.
+        trace.clear_transition_sink();
+        Ok((response, trace))
+    }
+
+    /// Executes the given authorization into the given (empty) trace.
+    fn execute_into_trace<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        trace: Trace<N>,
+        rng: &mut R,
     ) -> Result<(Response<N>, Trace<N>)> {
         let timer = timer!("Process::execute");
 
+        // Ensure the authorization has not expired, nor been revoked.
+        // Note: This must be checked before the requests of the authorization are consumed.
+        self.ensure_authorization_is_not_expired(&authorization)?;
+        self.ensure_authorization_is_not_revoked(&authorization)?;
+
         // Retrieve the main request (without popping it).
         let request = authorization.peek_next()?;
         // Construct the locator.
@@ -36,32 +109,270 @@ impl<N: Network> Process<N> {
         let caller = None;
         // This is the root request and we do not have a root_tvk to pass on.
         let root_tvk = None;
-        // Initialize the trace.
-        let trace = Arc::new(RwLock::new(Trace::new()));
-        // Initialize the call stack.
-        let call_stack = CallStack::execute(authorization, trace.clone())?;
-        lap!(timer, "Initialize call stack");
+        // Ensure the trace is empty.
+        ensure!(trace.transitions().is_empty(), "The trace of '{locator}' must be empty");
+        let trace = Arc::new(RwLock::new(trace));
+
+        // Split the authorization into its top-level calls.
+        let calls = self.split_top_level_calls(&authorization)?;
 
-        // Retrieve the stack.
-        let stack = self.get_stack(request.program_id())?;
-        // Execute the circuit.
-        let response = stack.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
-        lap!(timer, "Execute the function");
+        // Execute each top-level call.
+        let mut response = None;
+        match calls.len() > 1 {
+            // If there are several top-level calls, synthesize the independent calls concurrently.
+            true => {
+                response = Some(self.execute_top_level_calls::<A, R>(calls, &trace, rng)?);
+                // Consume the requests of the authorization, as they are executed.
+                while !authorization.is_empty() {
+                    authorization.next()?;
+                }
+                lap!(timer, "Execute the top-level calls");
+            }
+            // Otherwise, execute each top-level call, in order.
+            false => {
+                while !authorization.is_empty() {
+                    // Retrieve the top-level request (without popping it).
+                    let request = authorization.peek_next()?;
+                    // Initialize the call stack.
+                    let call_stack = CallStack::execute(authorization.clone(), trace.clone())?;
+                    // Retrieve the stack.
+                    let stack = self.get_stack(request.program_id())?;
+                    // Execute the circuit.
+                    response = Some(stack.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?);
+                    lap!(timer, "Execute the function");
+                }
+            }
+        }
+        // Retrieve the response of the last top-level call.
+        let Some(response) = response else { bail!("Execution of '{locator}' is empty") };
 
         // Extract the trace.
         let trace = Arc::try_unwrap(trace).unwrap().into_inner();
         // Ensure the trace is not empty.
         ensure!(!trace.transitions().is_empty(), "Execution of '{locator}' is empty");
+        // Ensure the transitions are in canonical (call-tree post-order) order.
+        self.construct_call_trees_from(&trace.transitions().iter().collect::<Vec<_>>())?;
+        lap!(timer, "Check the transition order");
 
         finish!(timer);
         Ok((response, trace))
     }
+
+    /// Executes and proves the given authorizations, bounding the concurrency with the given parallelism settings.
+    /// At most `max_concurrent_executions` authorizations are executed at once,
+    /// and all of them are executed and proven in the thread pool of `num_threads` threads.
+    ///
+    /// Note: The authorizations must not be fees, which are proven with `Trace::prove_fee`.
+    #[inline]
+    pub fn execute_batch<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorizations: Vec<Authorization<N>>,
+        parallelism: &ProvingParallelism,
+        query: impl QueryTrait<N> + Clone + Sync,
+        rng: &mut R,
+    ) -> Result<Vec<(Response<N>, Execution<N>)>> {
+        let timer = timer!("Process::execute_batch");
+
+        // Ensure the batch is not empty.
+        ensure!(!authorizations.is_empty(), "Cannot execute an empty batch of authorizations");
+        // Sample an RNG for each execution.
+        let mut pending = authorizations
+            .into_iter()
+            .map(|authorization| (authorization, StdRng::from_seed(rng.gen())))
+            .collect::<Vec<_>>();
+
+        let mut executions = Vec::with_capacity(pending.len());
+        while !pending.is_empty() {
+            // Retrieve the next authorizations to execute concurrently.
+            let num_executions = parallelism.max_concurrent_executions().min(pending.len());
+            let batch = pending.drain(..num_executions).collect::<Vec<_>>();
+            // Execute and prove the authorizations.
+            let batch_executions = parallelism.install(|| {
+                cfg_into_iter!(batch)
+                    .map(|(authorization, mut rng)| {
+                        // Retrieve the locator of the root request.
+                        let request = authorization.peek_next()?;
+                        let locator = Locator::new(*request.program_id(), *request.function_name()).to_string();
+                        // Execute the authorization.
+                        let (response, mut trace) = self.execute::<A, _>(authorization, &mut rng)?;
+                        // Prepare the assignments.
+                        trace.prepare(query.clone())?;
+                        // Compute the proof and construct the execution.
+                        let execution = trace.prove_execution::<A, _>(&locator, &mut rng)?;
+                        Ok((response, execution))
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })?;
+            executions.extend(batch_executions);
+        }
+        lap!(timer, "Execute and prove the authorizations");
+
+        finish!(timer);
+        Ok(executions)
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Splits the given authorization into the authorizations of its top-level calls, in order.
+    ///
+    /// If the authorization contains a single top-level call, or does not contain a transition for each request,
+    /// it is not split, and an empty list is returned.
+    fn split_top_level_calls(&self, authorization: &Authorization<N>) -> Result<Vec<Authorization<N>>> {
+        // Retrieve the requests and transitions.
+        let mut requests = authorization.to_vec_deque();
+        let transitions = authorization.transitions();
+        // If the requests do not each have a transition, do not split the authorization.
+        if requests.len() != transitions.len() {
+            return Ok(Vec::new());
+        }
+
+        let mut calls = Vec::new();
+        while let Some(root) = requests.front() {
+            // Determine the number of requests in the top-level call, including its nested calls.
+            let num_requests = self.get_stack(root.program_id())?.get_number_of_calls(root.function_name())?;
+            ensure!(
+                num_requests <= requests.len(),
+                "The authorization is missing requests for '{}/{}'",
+                root.program_id(),
+                root.function_name()
+            );
+            // Retrieve the requests of the top-level call.
+            let call_requests = requests.drain(..num_requests).collect::<Vec<_>>();
+            // Retrieve the transition for each request.
+            let call_transitions = call_requests
+                .iter()
+                .map(|request| {
+                    transitions.values().find(|transition| transition.tcm() == request.tcm()).cloned().ok_or_else(
+                        || anyhow!("The authorization is missing the transition for '{}'", request.function_name()),
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+            // Construct the authorization for the top-level call.
+            calls.push(Authorization::try_from((call_requests, call_transitions))?);
+        }
+
+        match calls.len() > 1 {
+            true => Ok(calls),
+            false => Ok(Vec::new()),
+        }
+    }
+
+    /// Synthesizes the given top-level calls, concurrently where they are independent,
+    /// and appends their transitions and assignments to the trace in order.
+    /// Returns the response of the last top-level call.
+    ///
+    /// Note: This does *not* prove the top-level calls, which are proven together from the trace.
+    fn execute_top_level_calls<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        calls: Vec<Authorization<N>>,
+        trace: &Arc<RwLock<Trace<N>>>,
+        rng: &mut R,
+    ) -> Result<Response<N>> {
+        // Sample an RNG for each top-level call.
+        let mut rngs = (0..calls.len()).map(|_| Some(StdRng::from_seed(rng.gen()))).collect::<Vec<_>>();
+        // Retrieve the cancellation token.
+        let cancellation = trace.read().cancellation_token().clone();
+        // Initialize a slot for the response and trace of each top-level call.
+        let mut results = (0..calls.len()).map(|_| None).collect::<Vec<_>>();
+
+        // Synthesize each wave of independent top-level calls concurrently.
+        for wave in Self::schedule_top_level_calls(&calls) {
+            let wave_rngs = wave.iter().map(|index| rngs[*index].take()).collect::<Option<Vec<_>>>();
+            let Some(wave_rngs) = wave_rngs else { bail!("A top-level call is scheduled more than once") };
+            let outputs = cfg_into_iter!(wave.clone())
+                .zip_eq(wave_rngs)
+                .map(|(index, mut rng)| {
+                    // Initialize the trace for the top-level call.
+                    let mut call_trace = Trace::new();
+                    call_trace.set_cancellation_token(cancellation.clone());
+                    let call_trace = Arc::new(RwLock::new(call_trace));
+                    // Initialize the call stack.
+                    let request = calls[index].peek_next()?;
+                    let call_stack = CallStack::execute(calls[index].clone(), call_trace.clone())?;
+                    // Execute the circuit.
+                    let stack = self.get_stack(request.program_id())?;
+                    let response = stack.execute_function::<A, _>(call_stack, None, None, &mut rng)?;
+                    // Extract the trace.
+                    let call_trace = Arc::try_unwrap(call_trace)
+                        .map_err(|_| anyhow!("The trace of '{}' is still in use", request.function_name()))?
+                        .into_inner();
+                    Ok((response, call_trace))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            for (index, output) in wave.into_iter().zip_eq(outputs) {
+                results[index] = Some(output);
+            }
+        }
+
+        // Append the trace of each top-level call, in order.
+        let mut response = None;
+        for result in results {
+            let Some((call_response, call_trace)) = result else { bail!("A top-level call was not executed") };
+            trace.write().append(call_trace)?;
+            response = Some(call_response);
+        }
+        response.ok_or_else(|| anyhow!("There are no top-level calls to execute"))
+    }
+
+    /// Returns the waves of top-level calls that may be synthesized concurrently, as indices, in order.
+    /// A top-level call is scheduled after every earlier top-level call that outputs a record it consumes.
+    fn schedule_top_level_calls(calls: &[Authorization<N>]) -> Vec<Vec<usize>> {
+        // Collect the commitments of the records output by each top-level call.
+        let outputs = calls
+            .iter()
+            .map(|call| {
+                call.transitions()
+                    .values()
+                    .flat_map(|transition| transition.commitments().copied().collect::<Vec<_>>())
+                    .collect::<HashSet<_>>()
+            })
+            .collect::<Vec<_>>();
+        // Collect the commitments of the records consumed by each top-level call.
+        let inputs = calls
+            .iter()
+            .map(|call| {
+                call.to_vec_deque()
+                    .iter()
+                    .flat_map(|request| {
+                        request
+                            .input_ids()
+                            .iter()
+                            .filter_map(|input_id| match input_id {
+                                InputID::Record(commitment, ..) => Some(*commitment),
+                                _ => None,
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // Assign each top-level call to the wave after its latest dependency.
+        let mut levels = vec![0usize; calls.len()];
+        for j in 0..calls.len() {
+            for i in 0..j {
+                if inputs[j].iter().any(|commitment| outputs[i].contains(commitment)) {
+                    levels[j] = levels[j].max(levels[i] + 1);
+                }
+            }
+        }
+
+        // Group the top-level calls by wave, in order.
+        let num_waves = levels.iter().max().map_or(0, |level| level + 1);
+        let mut waves = vec![Vec::new(); num_waves];
+        for (index, level) in levels.into_iter().enumerate() {
+            waves[level].push(index);
+        }
+        waves
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use console::types::Address;
+    use ledger_query::Query;
+    use ledger_store::{helpers::memory::BlockMemory, BlockStore};
 
     type CurrentNetwork = console::network::MainnetV0;
     type CurrentAleo = circuit::AleoV0;
@@ -159,4 +470,123 @@ mod tests {
         let transition = trace.transitions()[0].clone();
         assert!(transition.is_fee_public(), "Transition must be for 'credits.aleo/fee_public'");
     }
+
+    #[test]
+    fn test_execute_batch() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+
+        // Compute the authorizations.
+        let authorizations = (0..3)
+            .map(|_| {
+                process
+                    .authorize::<CurrentAleo, _>(
+                        &private_key,
+                        "credits.aleo",
+                        "transfer_public",
+                        [address.to_string(), "1u64".to_string()].into_iter(),
+                        rng,
+                    )
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure an empty batch fails.
+        let parallelism = ProvingParallelism::new(2, 2).unwrap();
+        let query = Query::from(block_store);
+        assert!(process.execute_batch::<CurrentAleo, _>(vec![], &parallelism, query.clone(), rng).is_err());
+
+        // Execute and prove the authorizations.
+        let executions = process.execute_batch::<CurrentAleo, _>(authorizations, &parallelism, query, rng).unwrap();
+        // Ensure each execution contains 1 transition, and is valid.
+        assert_eq!(executions.len(), 3);
+        for (response, execution) in executions {
+            assert_eq!(response.outputs().len(), 1);
+            assert_eq!(execution.len(), 1);
+            assert!(process.verify_execution(&execution).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_execute_independent_top_level_calls() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program that mints and burns records.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program bundle.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    cast self.caller r0 into r1 as token.record;
+    output r1 as token.record;
+
+function burn:
+    input r0 as token.record;
+    output r0.amount as u64.private;",
+        )
+        .unwrap();
+
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Sample the caller.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = console::account::ViewKey::try_from(&private_key).unwrap();
+
+        // Authorize a mint.
+        let mint = process
+            .authorize::<CurrentAleo, _>(
+                &private_key,
+                program.id(),
+                "mint",
+                [Value::<CurrentNetwork>::from_str("5u64").unwrap()].iter(),
+                rng,
+            )
+            .unwrap();
+        // Authorize a burn of the minted record, which depends on the mint.
+        let record =
+            mint.transitions().values().next().unwrap().records().next().unwrap().1.decrypt(&view_key).unwrap();
+        let burn = process
+            .authorize::<CurrentAleo, _>(&private_key, program.id(), "burn", [Value::Record(record)].iter(), rng)
+            .unwrap();
+        // Authorize another mint, which is independent.
+        let other = process
+            .authorize::<CurrentAleo, _>(
+                &private_key,
+                program.id(),
+                "mint",
+                [Value::<CurrentNetwork>::from_str("7u64").unwrap()].iter(),
+                rng,
+            )
+            .unwrap();
+
+        // Bundle the authorizations.
+        let authorization = Authorization::bundle([mint, burn, other]).unwrap();
+
+        // Ensure the bundle is split into its top-level calls, and the independent calls are scheduled together.
+        let calls = process.split_top_level_calls(&authorization).unwrap();
+        assert_eq!(calls.len(), 3);
+        assert_eq!(Process::schedule_top_level_calls(&calls), vec![vec![0, 2], vec![1]]);
+
+        // Execute the bundle, and ensure the transitions are in the order of the authorization.
+        let expected = authorization.transitions().keys().copied().collect::<Vec<_>>();
+        let (response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(trace.transitions().iter().map(|transition| *transition.id()).collect::<Vec<_>>(), expected);
+        // Ensure the response is for the last top-level call.
+        assert_eq!(response.outputs().len(), 1);
+    }
 }
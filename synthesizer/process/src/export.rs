@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Process;
+
+use circuit::{environment::AssignmentVariable, Assignment};
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+
+use std::collections::BTreeMap;
+
+/// The identifier of the JSON sparse-matrix format for exported constraint systems.
+pub const CONSTRAINT_SYSTEM_JSON_FORMAT: &str = "aleo-r1cs-sparse-json-v1";
+
+/// Exports the constraint system of the given assignment into the JSON sparse-matrix format:
+///
+/// ```text
+/// {
+///   "format": "aleo-r1cs-sparse-json-v1",
+///   "num_public": <the number of public variables, including the constant `one` at column 0>,
+///   "num_private": <the number of private variables>,
+///   "num_constraints": <the number of constraints (rows)>,
+///   "A": [[<row>, <column>, "<coefficient>"], ...],
+///   "B": [[<row>, <column>, "<coefficient>"], ...],
+///   "C": [[<row>, <column>, "<coefficient>"], ...],
+///   "witness": ["<value>", ...]
+/// }
+/// ```
+///
+/// Each constraint `i` enforces `<A_i, z> * <B_i, z> = <C_i, z>` over the base field,
+/// where `z` is the full variable vector. Public variable `j` is in column `j`,
+/// and private variable `j` is in column `num_public + j`. The constant term of a linear combination
+/// is a coefficient on column 0. Entries are sorted by row then column, entries in the same cell are summed,
+/// and zero entries are omitted. Coefficients and values are decimal strings.
+/// The `witness` holds the value of each column, and is only present if `include_witness` is `true`.
+pub fn export_constraint_system_json<N: Network>(
+    assignment: &Assignment<N::Field>,
+    include_witness: bool,
+) -> Result<serde_json::Value> {
+    // Retrieve the number of public variables.
+    let num_public = assignment.num_public();
+
+    // Returns the column of the given variable.
+    let to_column = |variable: &AssignmentVariable<N::Field>| match variable {
+        AssignmentVariable::Constant(_) => bail!("Constant variables may not appear in the terms of a constraint"),
+        AssignmentVariable::Public(index) => Ok(*index),
+        AssignmentVariable::Private(index) => {
+            num_public.checked_add(*index).ok_or_else(|| anyhow!("The column index overflowed"))
+        }
+    };
+
+    // Initialize the sparse matrices.
+    let mut matrices = [Vec::new(), Vec::new(), Vec::new()];
+    for (row, (a, b, c)) in assignment.constraints().iter().enumerate() {
+        for (matrix, lc) in matrices.iter_mut().zip_eq([a, b, c]) {
+            // Accumulate the entries of the row, with the constant term on column 0.
+            let mut entries = BTreeMap::<u64, N::Field>::new();
+            entries.insert(0, lc.constant());
+            for (variable, coefficient) in lc.terms() {
+                *entries.entry(to_column(variable)?).or_insert(N::Field::zero()) += *coefficient;
+            }
+            // Append the nonzero entries to the matrix, in order of column.
+            for (column, coefficient) in entries.into_iter().filter(|(_, coefficient)| !coefficient.is_zero()) {
+                matrix.push(serde_json::json!([row, column, coefficient.to_string()]));
+            }
+        }
+    }
+    let [a, b, c] = matrices;
+
+    // Construct the exported constraint system.
+    let mut object = serde_json::json!({
+        "format": CONSTRAINT_SYSTEM_JSON_FORMAT,
+        "num_public": num_public,
+        "num_private": assignment.num_private(),
+        "num_constraints": assignment.num_constraints(),
+        "A": a,
+        "B": b,
+        "C": c,
+    });
+
+    // If specified, include the witness.
+    if include_witness {
+        let witness = assignment
+            .public_inputs()
+            .iter()
+            .chain(assignment.private_inputs().iter())
+            .map(|(_, value)| value.to_string())
+            .collect::<Vec<_>>();
+        object["witness"] = serde_json::json!(witness);
+    }
+
+    Ok(object)
+}
+
+impl<N: Network> Process<N> {
+    /// Synthesizes the circuit for the given function on sampled inputs, and exports its constraint system
+    /// as a JSON string in the sparse-matrix format described in `export_constraint_system_json`.
+    #[inline]
+    pub fn export_constraint_system<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        include_witness: bool,
+        rng: &mut R,
+    ) -> Result<String> {
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Synthesize the circuit.
+        let assignment = self.get_stack(program_id)?.synthesize_assignment::<A, R>(&function_name, rng)?;
+        // Export the constraint system.
+        Ok(serde_json::to_string(&export_constraint_system_json::<N>(&assignment, include_witness)?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::network::AleoV0;
+    use console::network::MainnetV0;
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_export_constraint_system_json() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    mul r0 r1 into r2;
+    add r2 r1 into r3;
+    output r3 as field.private;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let process = crate::test_helpers::sample_process(&program);
+        let stack = process.get_stack(program.id()).unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Synthesize the circuit.
+        let assignment = stack.synthesize_assignment::<CurrentAleo, _>(&function_name, rng).unwrap();
+        let exported = export_constraint_system_json::<CurrentNetwork>(&assignment, true).unwrap();
+
+        // Check the header.
+        assert_eq!(exported["format"], CONSTRAINT_SYSTEM_JSON_FORMAT);
+        let num_public = exported["num_public"].as_u64().unwrap();
+        let num_private = exported["num_private"].as_u64().unwrap();
+        let num_constraints = exported["num_constraints"].as_u64().unwrap();
+        assert_eq!(num_public, assignment.num_public());
+        assert_eq!(num_private, assignment.num_private());
+        assert_eq!(num_constraints, assignment.num_constraints());
+
+        // Parse the witness.
+        let witness = exported["witness"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| <CurrentNetwork as Environment>::Field::from_str(value.as_str().unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(witness.len() as u64, num_public + num_private);
+        assert_eq!(witness[0].to_string(), "1");
+
+        // Evaluates each row of the given matrix against the witness.
+        let evaluate = |matrix: &serde_json::Value| {
+            let mut rows = vec![<CurrentNetwork as Environment>::Field::zero(); num_constraints as usize];
+            for entry in matrix.as_array().unwrap() {
+                let row = entry[0].as_u64().unwrap() as usize;
+                let column = entry[1].as_u64().unwrap() as usize;
+                let coefficient = <CurrentNetwork as Environment>::Field::from_str(entry[2].as_str().unwrap()).unwrap();
+                assert!(!coefficient.is_zero());
+                rows[row] += coefficient * witness[column];
+            }
+            rows
+        };
+
+        // Ensure the exported constraint system is satisfied by the witness.
+        let (a, b, c) = (evaluate(&exported["A"]), evaluate(&exported["B"]), evaluate(&exported["C"]));
+        for ((a, b), c) in a.iter().zip_eq(&b).zip_eq(&c) {
+            assert_eq!(*a * *b, *c);
+        }
+
+        // Ensure the witness is omitted, if not requested.
+        let exported = export_constraint_system_json::<CurrentNetwork>(&assignment, false).unwrap();
+        assert!(exported.get("witness").is_none());
+
+        // Ensure the process exports the same constraint system.
+        let string = process.export_constraint_system::<CurrentAleo, _>(program.id(), "compute", false, rng).unwrap();
+        let candidate: serde_json::Value = serde_json::from_str(&string).unwrap();
+        assert_eq!(candidate["num_constraints"], exported["num_constraints"]);
+        assert_eq!(candidate["A"].as_array().unwrap().len(), exported["A"].as_array().unwrap().len());
+    }
+}
@@ -88,28 +88,42 @@ impl<N: Network> Process<N> {
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
-        // Ensure the number of transitions matches the program function.
-        // Retrieve the root transition (without popping it).
-        let transition = execution.peek()?;
-        // Retrieve the stack.
-        let stack = self.get_stack(transition.program_id())?;
-        // Ensure the number of calls matches the number of transitions.
-        let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
-        ensure!(
-            number_of_calls == execution.len(),
-            "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
-            execution.len()
-        );
-        lap!(timer, "Verify the number of transitions");
-
         // Construct the call graph.
         let call_graph = self.construct_call_graph(execution)?;
+        // Construct the call trees, one for each top-level call.
+        let call_trees = Self::call_trees_from_graph(&execution.transitions().collect::<Vec<_>>(), &call_graph)?;
+
+        // Ensure the number of transitions matches the program function, for each top-level call.
+        for call_tree in &call_trees {
+            // Retrieve the stack.
+            let stack = self.get_stack(call_tree.program_id())?;
+            // Ensure the number of calls matches the number of transitions.
+            let number_of_calls = stack.get_number_of_calls(call_tree.function_name())?;
+            ensure!(
+                number_of_calls == call_tree.len(),
+                "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
+                call_tree.len()
+            );
+        }
+        lap!(timer, "Verify the number of transitions");
 
         atomic_batch_scope!(store, {
-            // Finalize the root transition.
+            // Initialize a list for the finalize operations.
+            let mut finalize_operations = Vec::new();
+
+            // Finalize each top-level call, in order. If any of them fails, the execution is reverted as a whole.
             // Note that this will result in all the remaining transitions being finalized, since the number
-            // of calls matches the number of transitions.
-            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph)?;
+            // of calls matches the number of transitions for each top-level call.
+            for call_tree in &call_trees {
+                // Retrieve the root transition.
+                let Some(transition) = execution.get_transition(call_tree.transition_id()) else {
+                    bail!("Transition '{}' is missing from the execution", call_tree.transition_id())
+                };
+                // Retrieve the stack.
+                let stack = self.get_stack(transition.program_id())?;
+                // Finalize the root transition.
+                finalize_operations.extend(finalize_transition(state, store, stack, transition, call_graph.clone())?);
+            }
 
             /* Finalize the fee. */
 
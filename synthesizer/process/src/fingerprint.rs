@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::network::{pinned_verifying_key_checksum, verifying_key_checksum};
+
+impl<N: Network> Process<N> {
+    /// Returns the fingerprint of the parameters loaded in the process.
+    ///
+    /// The fingerprint is a hash over the Varuna universal verifier, the inclusion verifying key,
+    /// and the fingerprint of the `credits.aleo` stack (see `Stack::fingerprint`). Operators may publish
+    /// and monitor the fingerprint to attest that they are running the canonical parameters for the network.
+    #[inline]
+    pub fn parameter_fingerprint(&self) -> Result<Field<N>> {
+        // Initialize the preimage with the Varuna universal verifier.
+        let mut preimage = N::varuna_universal_verifier().vk.to_bytes_le()?;
+        // Append the inclusion verifying key.
+        preimage.extend(N::inclusion_verifying_key().to_bytes_le()?);
+        // Append the fingerprint of the 'credits.aleo' verifying keys.
+        preimage.extend(self.get_stack("credits.aleo")?.fingerprint()?.to_bytes_le()?);
+        // Return the fingerprint.
+        N::hash_bhp1024(&preimage.to_bits_le())
+    }
+
+    /// Ensures the inclusion and `credits.aleo` verifying keys match the checksums pinned for the network.
+    #[inline]
+    pub(crate) fn check_parameter_integrity(&self) -> Result<()> {
+        // Ensure the inclusion verifying key matches its pinned checksum.
+        let expected = pinned_verifying_key_checksum::<N>("inclusion")?;
+        let candidate = verifying_key_checksum::<N>(N::inclusion_verifying_key())?;
+        ensure!(
+            candidate == expected,
+            "The inclusion verifying key does not match its pinned checksum (expected '{expected}', found '{candidate}')"
+        );
+
+        // Ensure each 'credits.aleo' verifying key matches its pinned checksum.
+        let stack = self.get_stack("credits.aleo")?;
+        for function_name in stack.program().functions().keys() {
+            let expected = pinned_verifying_key_checksum::<N>(&function_name.to_string())?;
+            let candidate = verifying_key_checksum::<N>(&stack.get_verifying_key(function_name)?)?;
+            ensure!(
+                candidate == expected,
+                "The verifying key for 'credits.aleo/{function_name}' does not match its pinned checksum (expected '{expected}', found '{candidate}')"
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_parameter_fingerprint() {
+        // Load the process, which checks the parameter integrity.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        process.check_parameter_integrity().unwrap();
+
+        // Ensure the fingerprint is deterministic.
+        let fingerprint = process.parameter_fingerprint().unwrap();
+        assert_eq!(fingerprint, Process::<CurrentNetwork>::load().unwrap().parameter_fingerprint().unwrap());
+
+        // Ensure a mismatched verifying key is rejected.
+        let stack = process.get_stack("credits.aleo").unwrap();
+        let join = Identifier::from_str("join").unwrap();
+        let split = Identifier::from_str("split").unwrap();
+        stack.insert_verifying_key(&join, stack.get_verifying_key(&split).unwrap()).unwrap();
+        let error = process.check_parameter_integrity().unwrap_err();
+        assert!(error.to_string().contains("credits.aleo/join"));
+        assert_ne!(fingerprint, process.parameter_fingerprint().unwrap());
+    }
+}
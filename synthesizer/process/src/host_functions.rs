@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::network::MainnetV0;
+use synthesizer_program::HostFunction;
+
+/// The registry of host functions, shared by the stacks in a process.
+///
+/// Host functions let an embedder extend the instruction set with domain-specific primitives
+/// (invoked with `call.host`), without forking it. As the host functions are part of the semantics
+/// of the programs that call them, every process that deploys, executes, or verifies such a program
+/// must register the same host functions, **before** the program is added.
+///
+/// Host functions are only enabled with the `host-functions` feature, and never on mainnet. Otherwise,
+/// no host function may be registered, and any program that uses `call.host` is rejected.
+#[derive(Clone, Default)]
+pub struct HostFunctions<N: Network> {
+    /// The mapping of host function names to host functions.
+    functions: Arc<RwLock<IndexMap<Identifier<N>, Arc<HostFunction<N>>>>>,
+}
+
+impl<N: Network> HostFunctions<N> {
+    /// Returns `true` if a host function with the given name is registered.
+    pub fn contains(&self, name: &Identifier<N>) -> bool {
+        self.functions.read().contains_key(name)
+    }
+
+    /// Returns the host function with the given name.
+    pub fn get(&self, name: &Identifier<N>) -> Result<Arc<HostFunction<N>>> {
+        self.functions.read().get(name).cloned().ok_or_else(|| anyhow!("Host function '{name}' is not registered"))
+    }
+
+    /// Returns the names of the registered host functions.
+    pub fn names(&self) -> Vec<Identifier<N>> {
+        self.functions.read().keys().copied().collect()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the registry of host functions, which is shared by the stacks in the process.
+    #[inline]
+    pub const fn host_functions(&self) -> &HostFunctions<N> {
+        &self.host_functions
+    }
+
+    /// Registers the given host function, so that programs added afterwards may invoke it with `call.host`.
+    ///
+    /// # Errors
+    /// This method will halt if host functions are not enabled (see the `host-functions` feature),
+    /// if the network is mainnet, or if a host function with the same name is already registered,
+    /// as programs that were type-checked against it may already be in the process.
+    pub fn register_host_function(&self, host_function: HostFunction<N>) -> Result<()> {
+        // Ensure host functions are enabled.
+        ensure_host_functions_are_enabled::<N>()?;
+        let mut functions = self.host_functions.functions.write();
+        // Ensure the host function is not already registered.
+        ensure!(
+            !functions.contains_key(host_function.name()),
+            "Host function '{}' is already registered",
+            host_function.name()
+        );
+        functions.insert(*host_function.name(), Arc::new(host_function));
+        Ok(())
+    }
+}
+
+/// Ensures host functions are enabled, i.e. the `host-functions` feature is set, and the network is not mainnet.
+pub(crate) fn ensure_host_functions_are_enabled<N: Network>() -> Result<()> {
+    ensure!(cfg!(feature = "host-functions"), "Host functions are not enabled (requires the 'host-functions' feature)");
+    // Note: Every node must agree on the semantics of a program, which host functions leave to the embedder.
+    ensure!(N::ID != MainnetV0::ID, "Host functions are not enabled on mainnet");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::program::PlaintextType;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[cfg(feature = "host-functions")]
+    #[test]
+    fn test_register_host_function() {
+        use console::network::TestnetV0;
+
+        /// Returns a host function that returns its input.
+        fn sample_host_function<N: Network>() -> HostFunction<N> {
+            let name = Identifier::from_str("identity").unwrap();
+            let field_type = PlaintextType::from_str("field").unwrap();
+            HostFunction::new(name, vec![field_type.clone()], vec![field_type], |inputs| Ok(inputs.to_vec())).unwrap()
+        }
+
+        // Ensure host functions cannot be registered on mainnet.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        let error = process.register_host_function(sample_host_function()).unwrap_err();
+        assert!(error.to_string().contains("mainnet"));
+
+        let process = Process::<TestnetV0>::load().unwrap();
+        let name = Identifier::from_str("identity").unwrap();
+
+        // Ensure the host function is registered.
+        assert!(!process.host_functions().contains(&name));
+        assert!(process.host_functions().get(&name).is_err());
+        process.register_host_function(sample_host_function()).unwrap();
+        assert!(process.host_functions().contains(&name));
+        assert_eq!(process.host_functions().names(), vec![name]);
+
+        // Ensure the registry is shared with the stacks.
+        let stack = process.get_stack("credits.aleo").unwrap();
+        assert_eq!(stack.get_host_function(&name).unwrap().name(), &name);
+
+        // Ensure the host function cannot be registered twice.
+        assert!(process.register_host_function(sample_host_function()).is_err());
+    }
+
+    #[cfg(not(feature = "host-functions"))]
+    #[test]
+    fn test_host_functions_are_disabled() {
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        let name = Identifier::from_str("identity").unwrap();
+        let field_type = PlaintextType::from_str("field").unwrap();
+        let host_function =
+            HostFunction::new(name, vec![field_type.clone()], vec![field_type], |inputs| Ok(inputs.to_vec())).unwrap();
+
+        // Ensure the host function cannot be registered.
+        let error = process.register_host_function(host_function).unwrap_err();
+        assert!(error.to_string().contains("host-functions"));
+        assert!(!process.host_functions().contains(&name));
+
+        // Ensure a program that uses `call.host` is rejected.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program host_disabled.aleo;
+
+function compute:
+    input r0 as field.private;
+    call.host identity r0 into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+        let error = process.add_program(&program).unwrap_err();
+        assert!(format!("{error:?}").contains("host-functions"));
+    }
+}
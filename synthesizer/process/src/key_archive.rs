@@ -0,0 +1,220 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+use sha2::{Digest, Sha256};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+/// The magic bytes at the start of a key archive.
+const KEY_ARCHIVE_MAGIC: &[u8; 8] = b"aleokeys";
+/// The version of the key archive format.
+const KEY_ARCHIVE_VERSION: u8 = 1;
+
+impl<N: Network> Stack<N> {
+    /// Exports the proving and verifying keys of every function in the program into a single archive at the given path,
+    /// so that the keys may be synthesized once, and distributed to other machines.
+    ///
+    /// The archive is laid out as `magic || version || checksum || deflate(body)`, where the checksum is the SHA-256 hash
+    /// of the body, and the body is `program ID || program checksum || (function name, verifying key, proving key)*`.
+    ///
+    /// # Errors
+    /// This method will halt if the keys of any function in the program have not been synthesized (see `synthesize_all_keys`).
+    pub fn export_keys(&self, path: impl AsRef<Path>) -> Result<()> {
+        // Ensure the program contains at least one function.
+        ensure!(!self.program().functions().is_empty(), "Program '{}' has no functions to export", self.program_id());
+        ensure!(
+            self.program().functions().len() <= u16::MAX as usize,
+            "Program '{}' has too many functions to export",
+            self.program_id()
+        );
+
+        // Write the body.
+        let mut body = Vec::new();
+        self.program_id().write_le(&mut body)?;
+        body.extend_from_slice(&self.program_checksum()?);
+        (self.program().functions().len() as u16).write_le(&mut body)?;
+        for function_name in self.program().functions().keys() {
+            // Ensure the keys of the function have been synthesized.
+            ensure!(
+                self.contains_proving_key(function_name) && self.contains_verifying_key(function_name),
+                "The keys for '{}/{function_name}' have not been synthesized",
+                self.program_id()
+            );
+            function_name.write_le(&mut body)?;
+            self.get_verifying_key(function_name)?.write_le(&mut body)?;
+            self.get_proving_key(function_name)?.write_le(&mut body)?;
+        }
+
+        // Write the archive.
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(KEY_ARCHIVE_MAGIC)?;
+        KEY_ARCHIVE_VERSION.write_le(&mut writer)?;
+        writer.write_all(&Sha256::digest(&body))?;
+        let mut encoder = DeflateEncoder::new(writer, Compression::default());
+        encoder.write_all(&body)?;
+        encoder.finish()?.flush()?;
+        Ok(())
+    }
+
+    /// Imports the proving and verifying keys of the program from the archive at the given path (see `export_keys`).
+    ///
+    /// The archive is checked in full before any key is inserted, namely that it is intact, that it is for this program,
+    /// that each proving key matches its verifying key, and that each verifying key matches the one already in the stack,
+    /// if one exists (e.g. from the deployment).
+    pub fn import_keys(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut reader = BufReader::new(File::open(path)?);
+
+        // Read the header.
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        ensure!(&magic == KEY_ARCHIVE_MAGIC, "The file is not a key archive");
+        let version = u8::read_le(&mut reader)?;
+        ensure!(version == KEY_ARCHIVE_VERSION, "Unsupported key archive version '{version}'");
+        let mut checksum = [0u8; 32];
+        reader.read_exact(&mut checksum)?;
+
+        // Read the body, and ensure it matches the checksum.
+        let mut body = Vec::new();
+        DeflateDecoder::new(reader).read_to_end(&mut body)?;
+        ensure!(Sha256::digest(&body)[..] == checksum[..], "The key archive is corrupted (checksum mismatch)");
+
+        // Ensure the archive is for this program.
+        let mut body = &body[..];
+        let program_id = ProgramID::<N>::read_le(&mut body)?;
+        ensure!(&program_id == self.program_id(), "The key archive is for '{program_id}', not '{}'", self.program_id());
+        let mut program_checksum = [0u8; 32];
+        body.read_exact(&mut program_checksum)?;
+        ensure!(
+            program_checksum == self.program_checksum()?,
+            "The key archive is for a different edition of '{program_id}'"
+        );
+
+        // Read and check the keys.
+        let num_functions = u16::read_le(&mut body)?;
+        let mut keys = Vec::with_capacity(num_functions as usize);
+        for _ in 0..num_functions {
+            let function_name = Identifier::<N>::read_le(&mut body)?;
+            let verifying_key = VerifyingKey::<N>::read_le(&mut body)?;
+            let proving_key = ProvingKey::<N>::read_le(&mut body)?;
+            // Ensure the function exists.
+            ensure!(
+                self.program().contains_function(&function_name),
+                "Function '{function_name}' does not exist in program '{program_id}'"
+            );
+            // Ensure the proving key matches the verifying key.
+            ensure!(
+                proving_key.circuit_verifying_key == *verifying_key,
+                "The proving key for '{program_id}/{function_name}' does not match its verifying key"
+            );
+            // Ensure the verifying key matches the existing verifying key, if one exists.
+            if self.contains_verifying_key(&function_name) {
+                ensure!(
+                    self.get_verifying_key(&function_name)? == verifying_key,
+                    "The verifying key for '{program_id}/{function_name}' does not match the existing verifying key"
+                );
+            }
+            keys.push((function_name, verifying_key, proving_key));
+        }
+        ensure!(body.is_empty(), "The key archive contains trailing bytes");
+
+        // Insert the keys.
+        for (function_name, verifying_key, proving_key) in keys {
+            self.insert_verifying_key(&function_name, verifying_key)?;
+            self.insert_proving_key(&function_name, proving_key)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the SHA-256 checksum of the program.
+    fn program_checksum(&self) -> Result<[u8; 32]> {
+        Ok(Sha256::digest(self.program().to_bytes_le()?).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns a process with the given program, whose keys are not yet synthesized.
+    fn sample_process(program: &str) -> (Process<CurrentNetwork>, ProgramID<CurrentNetwork>) {
+        let program = Program::<CurrentNetwork>::from_str(program).unwrap();
+        let mut process = Process::load().unwrap();
+        process.add_program(&program).unwrap();
+        (process, *program.id())
+    }
+
+    #[test]
+    fn test_export_and_import_keys() {
+        let rng = &mut TestRng::default();
+        let program = r"
+program archive.aleo;
+
+function double:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+
+function square:
+    input r0 as u32.private;
+    mul r0 r0 into r1;
+    output r1 as u32.private;";
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("archive.keys");
+
+        // Ensure the keys cannot be exported before they are synthesized.
+        let (process, program_id) = sample_process(program);
+        let stack = process.get_stack(program_id).unwrap();
+        assert!(stack.export_keys(&path).is_err());
+
+        // Synthesize and export the keys.
+        assert!(stack.synthesize_all_keys::<CurrentAleo, _>(|_| (), rng).failures().is_empty());
+        stack.export_keys(&path).unwrap();
+
+        // Import the keys into another process.
+        let (other_process, _) = sample_process(program);
+        let other_stack = other_process.get_stack(program_id).unwrap();
+        other_stack.import_keys(&path).unwrap();
+        for function_name in stack.program().functions().keys() {
+            assert_eq!(
+                other_stack.get_verifying_key(function_name).unwrap(),
+                stack.get_verifying_key(function_name).unwrap()
+            );
+            assert!(other_stack.contains_proving_key(function_name));
+        }
+
+        // Ensure the archive is rejected by another program.
+        let (other_process, other_program_id) = sample_process(&program.replace("archive.aleo", "other.aleo"));
+        assert!(other_process.get_stack(other_program_id).unwrap().import_keys(&path).is_err());
+
+        // Ensure a corrupted archive is rejected.
+        let mut bytes = std::fs::read(&path).unwrap();
+        let index = bytes.len() / 2;
+        bytes[index] ^= 1;
+        std::fs::write(&path, bytes).unwrap();
+        let (other_process, _) = sample_process(program);
+        let other_stack = other_process.get_stack(program_id).unwrap();
+        assert!(other_stack.import_keys(&path).is_err());
+        assert!(!other_stack.contains_verifying_key(&Identifier::from_str("double").unwrap()));
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use parking_lot::Mutex;
+use rand::{rngs::StdRng, SeedableRng};
+use std::{
+    future::Future,
+    panic::{catch_unwind, AssertUnwindSafe},
+    pin::Pin,
+    task::{Context, Poll, Waker},
+    thread::JoinHandle,
+};
+
+/// A handle to the synthesis of the circuit keys for a function, running on a background thread.
+///
+/// The handle may be polled with `is_finished`, blocked on with `wait`, or awaited as a future.
+/// The circuit keys are available to the stack (and its clones) as soon as the synthesis finishes.
+pub struct KeySynthesisHandle<N: Network> {
+    /// The function whose circuit keys are synthesized.
+    locator: Locator<N>,
+    /// The state of the synthesis.
+    state: Arc<Mutex<KeySynthesisState>>,
+    /// The worker thread, if the synthesis was not already finished when it started.
+    worker: Option<JoinHandle<()>>,
+}
+
+/// The state of a key synthesis.
+#[derive(Default)]
+struct KeySynthesisState {
+    /// The result of the synthesis, once it finishes and until it is taken.
+    result: Option<Result<()>>,
+    /// Whether the synthesis is finished.
+    is_finished: bool,
+    /// The waker of the task awaiting the synthesis, if any.
+    waker: Option<Waker>,
+}
+
+impl<N: Network> KeySynthesisHandle<N> {
+    /// Returns the function whose circuit keys are synthesized.
+    pub const fn locator(&self) -> &Locator<N> {
+        &self.locator
+    }
+
+    /// Returns `true` if the synthesis is finished.
+    pub fn is_finished(&self) -> bool {
+        self.state.lock().is_finished
+    }
+
+    /// Blocks until the synthesis is finished, and returns its result.
+    pub fn wait(mut self) -> Result<()> {
+        if let Some(worker) = self.worker.take() {
+            if worker.join().is_err() {
+                bail!("The key synthesis worker for '{}' panicked", self.locator)
+            }
+        }
+        self.take_result()
+    }
+
+    /// Takes the result of the finished synthesis.
+    fn take_result(&self) -> Result<()> {
+        match self.state.lock().result.take() {
+            Some(result) => result,
+            None => bail!("The result of the key synthesis for '{}' was already taken", self.locator),
+        }
+    }
+}
+
+impl<N: Network> Future for KeySynthesisHandle<N> {
+    type Output = Result<()>;
+
+    /// Resolves once the synthesis is finished, with its result.
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock();
+        match state.is_finished {
+            true => Poll::Ready(state.result.take().unwrap_or_else(|| {
+                Err(anyhow!("The result of the key synthesis for '{}' was already taken", self.locator))
+            })),
+            false => {
+                // Register the waker, to be woken once the synthesis finishes.
+                state.waker = Some(context.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Synthesizes the proving key and verifying key for the given function name on a background thread,
+    /// and returns a handle to the synthesis.
+    ///
+    /// If the circuit keys already exist, the returned handle is already finished.
+    /// If the same function is synthesized concurrently (e.g. by an `execute`), the keys are only synthesized once.
+    pub fn synthesize_key_in_background<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<KeySynthesisHandle<N>> {
+        // Ensure the function exists.
+        ensure!(
+            self.program().contains_function(function_name),
+            "Function '{function_name}' does not exist in program '{}'.",
+            self.program_id()
+        );
+        // Construct the locator.
+        let locator = Locator::new(*self.program_id(), *function_name);
+        // Initialize the state.
+        let state = Arc::new(Mutex::new(KeySynthesisState::default()));
+
+        // If the circuit keys already exist, return a finished handle.
+        if self.contains_proving_key(function_name) && self.contains_verifying_key(function_name) {
+            *state.lock() = KeySynthesisState { result: Some(Ok(())), is_finished: true, waker: None };
+            return Ok(KeySynthesisHandle { locator, state, worker: None });
+        }
+
+        // Spawn the worker.
+        let stack = self.clone();
+        let function_name = *function_name;
+        let mut rng = StdRng::from_seed(rng.gen());
+        let worker_state = state.clone();
+        let worker = std::thread::spawn(move || {
+            // Synthesize the circuit keys, converting a panic into an error, so that awaiting tasks are always woken.
+            let result = catch_unwind(AssertUnwindSafe(|| stack.synthesize_key::<A, _>(&function_name, &mut rng)))
+                .unwrap_or_else(|_| Err(anyhow!("The key synthesis for '{locator}' panicked")));
+            // Store the result, and wake the awaiting task.
+            let mut state = worker_state.lock();
+            state.result = Some(result);
+            state.is_finished = true;
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+
+        Ok(KeySynthesisHandle { locator, state, worker: Some(worker) })
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Synthesizes the proving key and verifying key for the given program ID and function name
+    /// on a background thread, and returns a handle to the synthesis.
+    #[inline]
+    pub fn synthesize_key_in_background<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<KeySynthesisHandle<N>> {
+        self.get_stack(program_id)?.synthesize_key_in_background::<A, R>(function_name, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+    use std::{
+        task::Wake,
+        thread::{self, Thread},
+    };
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// A waker that unparks the thread that is blocked on a future.
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Blocks the current thread on the given future.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut context = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    fn sample_process() -> (Process<CurrentNetwork>, ProgramID<CurrentNetwork>, Identifier<CurrentNetwork>) {
+        // Initialize a program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program background.aleo;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;",
+        )
+        .unwrap();
+        // Initialize the process.
+        let mut process = Process::load().unwrap();
+        process.add_program(&program).unwrap();
+        (process, *program.id(), Identifier::from_str("compute").unwrap())
+    }
+
+    #[test]
+    fn test_synthesize_key_in_background_wait() {
+        let rng = &mut TestRng::default();
+        let (process, program_id, function_name) = sample_process();
+
+        // Synthesize the circuit keys in the background, and wait for them.
+        let handle = process.synthesize_key_in_background::<CurrentAleo, _>(&program_id, &function_name, rng).unwrap();
+        assert_eq!(handle.locator(), &Locator::new(program_id, function_name));
+        handle.wait().unwrap();
+
+        // Ensure the circuit keys are available to the process.
+        let stack = process.get_stack(program_id).unwrap();
+        assert!(stack.contains_proving_key(&function_name));
+        assert!(stack.contains_verifying_key(&function_name));
+
+        // Ensure a second synthesis is already finished.
+        let handle = process.synthesize_key_in_background::<CurrentAleo, _>(&program_id, &function_name, rng).unwrap();
+        assert!(handle.is_finished());
+        handle.wait().unwrap();
+    }
+
+    #[test]
+    fn test_synthesize_key_in_background_await() {
+        let rng = &mut TestRng::default();
+        let (process, program_id, function_name) = sample_process();
+
+        // Synthesize the circuit keys in the background, and await them.
+        let handle = process.synthesize_key_in_background::<CurrentAleo, _>(&program_id, &function_name, rng).unwrap();
+        block_on(handle).unwrap();
+
+        // Ensure the circuit keys are available to the process.
+        assert!(process.get_stack(program_id).unwrap().contains_proving_key(&function_name));
+    }
+
+    #[test]
+    fn test_synthesize_key_in_background_missing_function() {
+        let rng = &mut TestRng::default();
+        let (process, program_id, _) = sample_process();
+
+        // Ensure a missing function is rejected.
+        let function_name = Identifier::from_str("missing").unwrap();
+        assert!(process.synthesize_key_in_background::<CurrentAleo, _>(&program_id, &function_name, rng).is_err());
+    }
+}
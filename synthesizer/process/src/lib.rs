@@ -18,9 +18,59 @@
 // TODO (howardwu): Update the return type on `execute` after stabilizing the interface.
 #![allow(clippy::type_complexity)]
 
+mod call_tree;
+pub use call_tree::*;
+
+mod checkpoint;
+pub use checkpoint::*;
+
 mod cost;
 pub use cost::*;
 
+mod delegate;
+pub use delegate::*;
+
+mod deployment_verifier;
+pub use deployment_verifier::*;
+
+mod dry_run;
+pub use dry_run::*;
+
+mod export;
+pub use export::*;
+
+mod host_functions;
+pub use host_functions::*;
+
+mod key_archive;
+
+mod key_synthesis;
+pub use key_synthesis::*;
+
+mod limits;
+pub use limits::*;
+
+mod mapping_proof;
+pub use mapping_proof::*;
+
+mod parallelism;
+pub use parallelism::*;
+
+mod profile;
+pub use profile::*;
+
+mod proving_key_cache;
+pub use proving_key_cache::*;
+
+mod record_opening;
+pub use record_opening::*;
+
+mod resolver;
+pub use resolver::*;
+
+mod snapshot;
+pub use snapshot::*;
+
 mod stack;
 pub use stack::*;
 
@@ -30,11 +80,25 @@ pub use trace::*;
 mod traits;
 pub use traits::*;
 
+mod transcript;
+pub use transcript::*;
+
+mod warm_up;
+pub use warm_up::*;
+
+#[cfg(feature = "benchmarks")]
+pub mod benchmarks;
+
 mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
 mod finalize;
+mod fingerprint;
+mod query_cache;
+mod reevaluate;
+mod revocation;
+mod upgrade;
 mod verify_deployment;
 mod verify_execution;
 mod verify_fee;
@@ -66,10 +130,12 @@ use synthesizer_program::{
 use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::{collections::HashMap, sync::Arc};
 
+use query_cache::QueryKey;
+
 #[cfg(feature = "aleo-cli")]
 use colored::Colorize;
 
@@ -79,16 +145,88 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The set of revoked authorizations, as execution IDs.
+    revoked_authorizations: Arc<RwLock<IndexSet<Field<N>>>>,
+    /// The latest block height, if known, against which authorization expirations are checked.
+    latest_block_height: Arc<RwLock<Option<u32>>>,
+    /// The memoized outputs of queries to pure functions.
+    query_cache: Arc<RwLock<IndexMap<QueryKey<N>, Vec<Value<N>>>>>,
+    /// The registry of host functions, which is shared by the stacks in the process.
+    host_functions: HostFunctions<N>,
+    /// The mapping of program content hashes to program IDs.
+    content_hashes: IndexMap<Field<N>, ProgramID<N>>,
+    /// The mapping of upgraded program IDs to their current edition.
+    editions: IndexMap<ProgramID<N>, u16>,
+    /// The stacks of the previous editions of upgraded programs, as `((program ID, edition), stack)`.
+    historical_stacks: IndexMap<(ProgramID<N>, u16), Arc<Stack<N>>>,
+    /// The maximum number of programs that may be deployed to the process.
+    max_programs: usize,
+    /// The maximum call depth of a function, as the number of functions in its longest call chain.
+    max_call_depth: usize,
+    /// The maximum number of instructions of the closures to inline into the functions of new stacks, if enabled.
+    closure_inlining: Option<usize>,
+    /// The proving key cache, which is shared by the stacks in the process.
+    proving_key_cache: ProvingKeyCache<N>,
 }
 
 impl<N: Network> Process<N> {
     /// Initializes a new process.
     #[inline]
     pub fn setup<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(rng: &mut R) -> Result<Self> {
+        Self::setup_with_srs::<A, R>(UniversalSRS::load()?, rng)
+    }
+
+    /// Initializes a new process, using a universal SRS generated locally from the given `seed`.
+    ///
+    /// WARNING: The resulting process is **insecure**, as the trapdoor of the universal SRS is
+    /// derived from the seed. It must only be used for local development networks and testing.
+    /// As this installs the Varuna universal verifier for the network, it must be called before
+    /// any proof is verified. This method is only available with the `dev` feature.
+    #[cfg(feature = "dev")]
+    #[inline]
+    pub fn setup_insecure<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        seed: u64,
+        num_powers: usize,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Generate the universal SRS.
+        let universal_srs = UniversalSRS::setup_insecure(num_powers, seed)?;
+        // Install the universal verifier for the generated universal SRS.
+        N::set_insecure_varuna_universal_verifier(universal_srs.to_universal_verifier()?)?;
+        // Initialize the process.
+        Self::setup_with_srs::<A, R>(universal_srs, rng)
+    }
+
+    /// Initializes a new process with the given universal SRS, without any programs.
+    #[inline]
+    fn new_empty(universal_srs: UniversalSRS<N>) -> Self {
+        Self {
+            universal_srs: Arc::new(universal_srs),
+            stacks: IndexMap::new(),
+            revoked_authorizations: Default::default(),
+            latest_block_height: Default::default(),
+            query_cache: Default::default(),
+            host_functions: Default::default(),
+            content_hashes: IndexMap::new(),
+            editions: IndexMap::new(),
+            historical_stacks: IndexMap::new(),
+            max_programs: Self::MAX_PROGRAMS,
+            max_call_depth: Self::MAX_CALL_DEPTH,
+            closure_inlining: None,
+            proving_key_cache: Default::default(),
+        }
+    }
+
+    /// Initializes a new process with the given universal SRS.
+    #[inline]
+    fn setup_with_srs<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        universal_srs: UniversalSRS<N>,
+        rng: &mut R,
+    ) -> Result<Self> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self::new_empty(universal_srs);
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -131,8 +269,104 @@ impl<N: Network> Process<N> {
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
     pub fn add_stack(&mut self, stack: Stack<N>) {
+        self.insert_stack(Arc::new(stack))
+    }
+
+    /// Inserts the given stack into the process, replacing the existing stack of the program, if any.
+    #[inline]
+    fn insert_stack(&mut self, stack: Arc<Stack<N>>) {
+        // Invalidate the memoized queries to the program, if the program has changed.
+        if self.stacks.get(stack.program_id()).map_or(true, |existing| **existing != *stack) {
+            self.invalidate_memoized_queries(stack.program_id());
+        }
+        // Remove the content hash of the existing program, if any.
+        if let Some(existing) = self.stacks.get(stack.program_id()) {
+            self.content_hashes.shift_remove(&existing.content_hash());
+        }
+        // Index the content hash of the program.
+        self.content_hashes.insert(stack.content_hash(), *stack.program_id());
         // Add the stack to the process.
-        self.stacks.insert(*stack.program_id(), Arc::new(stack));
+        self.stacks.insert(*stack.program_id(), stack);
+        #[cfg(feature = "metrics")]
+        metrics::gauge(metrics::process::PROGRAMS, self.stacks.len() as f64);
+    }
+
+    /// Adds a new program to the process, ensuring its imports match the given content hashes.
+    /// This allows a program to reference the exact contents of its imports, rather than their program IDs.
+    #[inline]
+    pub fn add_program_with_pinned_imports(
+        &mut self,
+        program: &Program<N>,
+        import_hashes: &IndexMap<ProgramID<N>, Field<N>>,
+    ) -> Result<()> {
+        for (import_id, import_hash) in import_hashes {
+            // Ensure the pinned import is imported by the program.
+            ensure!(program.contains_import(import_id), "Program '{}' does not import '{import_id}'", program.id());
+            // Ensure the import in the process matches the pinned content hash.
+            let content_hash = self.get_stack(*import_id)?.content_hash();
+            ensure!(
+                content_hash == *import_hash,
+                "Import '{import_id}' has content hash '{content_hash}', but '{import_hash}' was pinned"
+            );
+        }
+        // Add the program.
+        self.add_program(program)
+    }
+
+    /// Removes the program with the given ID from the process, and returns the IDs of the evicted programs.
+    ///
+    /// The stack and its circuit keys are evicted, along with any imports that are no longer imported
+    /// by a remaining program, so that long-running services can bound their memory.
+    /// A program cannot be removed while it is imported by another program, and 'credits.aleo' is never removed.
+    #[inline]
+    pub fn remove_program(&mut self, program_id: &ProgramID<N>) -> Result<Vec<ProgramID<N>>> {
+        // Initialize the 'credits.aleo' program ID.
+        let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &credits_program_id, "Cannot remove the program '{credits_program_id}'");
+        // Ensure the program exists.
+        ensure!(self.contains_program(program_id), "Program '{program_id}' does not exist");
+        // Ensure the program is not imported by another program.
+        if let Some(dependent) = self.stacks.values().find(|stack| stack.program().contains_import(program_id)) {
+            bail!("Cannot remove program '{program_id}', as it is imported by '{}'", dependent.program_id())
+        }
+
+        // Evict the program, followed by any imports that are no longer imported.
+        let mut evicted = Vec::new();
+        let mut pending = vec![*program_id];
+        while let Some(program_id) = pending.pop() {
+            // Skip 'credits.aleo', programs that were already evicted, and programs that are still imported.
+            if program_id == credits_program_id
+                || !self.contains_program(&program_id)
+                || self.stacks.values().any(|stack| stack.program().contains_import(&program_id))
+            {
+                continue;
+            }
+            // Remove the stack from the process.
+            let stack = match self.stacks.shift_remove(&program_id) {
+                Some(stack) => stack,
+                None => continue,
+            };
+            // Remove the content hash of the program.
+            self.content_hashes.shift_remove(&stack.content_hash());
+            // Invalidate the memoized queries to the program.
+            self.invalidate_memoized_queries(&program_id);
+            // Remove the editions of the program.
+            self.editions.shift_remove(&program_id);
+            self.historical_stacks.retain(|(id, _), _| id != &program_id);
+            // Evict the circuit keys, in case the stack is still held elsewhere.
+            for function_name in stack.program().functions().keys() {
+                stack.remove_proving_key(function_name);
+                stack.remove_verifying_key(function_name);
+            }
+            // Consider the imports of the program for eviction.
+            pending.extend(stack.program().imports().keys().copied());
+            evicted.push(program_id);
+        }
+        #[cfg(feature = "metrics")]
+        metrics::gauge(metrics::process::PROGRAMS, self.stacks.len() as f64);
+
+        Ok(evicted)
     }
 }
 
@@ -143,7 +377,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self::new_empty(UniversalSRS::load()?);
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -171,6 +405,10 @@ impl<N: Network> Process<N> {
         // Add the stack to the process.
         process.add_stack(stack);
 
+        // Ensure the loaded verifying keys match the checksums pinned for the network.
+        process.check_parameter_integrity()?;
+        lap!(timer, "Check parameter integrity");
+
         finish!(timer, "Process::load");
         // Return the process.
         Ok(process)
@@ -181,7 +419,7 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self::new_empty(UniversalSRS::load()?);
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -202,6 +440,21 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the maximum number of instructions of the closures that are inlined into new stacks, if enabled.
+    #[inline]
+    pub const fn closure_inlining(&self) -> Option<usize> {
+        self.closure_inlining
+    }
+
+    /// Sets the maximum number of instructions of the closures to inline into the functions of the programs
+    /// that are added afterwards (see `Stack::inline_closures`), or disables inlining with `None`.
+    /// Note: Inlining skips the register initialization of each closure call when evaluating and synthesizing,
+    /// but the inlined functions synthesize the same circuits, so it does not reduce the number of constraints.
+    #[inline]
+    pub fn set_closure_inlining(&mut self, max_closure_instructions: Option<usize>) {
+        self.closure_inlining = max_closure_instructions;
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -227,6 +480,34 @@ impl<N: Network> Process<N> {
         Ok(self.get_stack(program_id)?.program())
     }
 
+    /// Returns `true` if the process contains the program with the given content hash.
+    #[inline]
+    pub fn contains_program_hash(&self, content_hash: &Field<N>) -> bool {
+        self.content_hashes.contains_key(content_hash)
+    }
+
+    /// Returns the stack for the given program content hash.
+    #[inline]
+    pub fn get_stack_by_hash(&self, content_hash: &Field<N>) -> Result<&Arc<Stack<N>>> {
+        // Retrieve the program ID.
+        let program_id = self
+            .content_hashes
+            .get(content_hash)
+            .ok_or_else(|| anyhow!("Program with content hash '{content_hash}' does not exist"))?;
+        // Retrieve the stack.
+        let stack = self.get_stack(*program_id)?;
+        // Ensure the content hash matches.
+        ensure!(stack.content_hash() == *content_hash, "Mismatching content hash for program '{program_id}'");
+        // Return the stack.
+        Ok(stack)
+    }
+
+    /// Returns the program for the given program content hash.
+    #[inline]
+    pub fn get_program_by_hash(&self, content_hash: &Field<N>) -> Result<&Program<N>> {
+        Ok(self.get_stack_by_hash(content_hash)?.program())
+    }
+
     /// Returns the proving key for the given program ID and function name.
     #[inline]
     pub fn get_proving_key(
@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A rejection by the process, because one of its internal limits has been reached.
+/// The rejection is returned as an `anyhow::Error`, from which it may be recovered with `downcast_ref`.
+#[derive(Clone, PartialEq, Eq)]
+pub enum ProcessLimitError<N: Network> {
+    /// The process already tracks the maximum number of programs.
+    Programs { program_id: ProgramID<N>, limit: usize },
+    /// The stack of the program already tracks the maximum number of external stacks.
+    ExternalStacks { program_id: ProgramID<N>, limit: usize },
+    /// The stack of the program already tracks the maximum number of functions and closures.
+    Functions { program_id: ProgramID<N>, limit: usize },
+}
+
+impl<N: Network> ProcessLimitError<N> {
+    /// Returns the ID of the rejected program.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        match self {
+            Self::Programs { program_id, .. } => program_id,
+            Self::ExternalStacks { program_id, .. } => program_id,
+            Self::Functions { program_id, .. } => program_id,
+        }
+    }
+
+    /// Returns the limit that was reached.
+    pub const fn limit(&self) -> usize {
+        match self {
+            Self::Programs { limit, .. } => *limit,
+            Self::ExternalStacks { limit, .. } => *limit,
+            Self::Functions { limit, .. } => *limit,
+        }
+    }
+
+    /// Records the rejection, and returns it as an error.
+    pub(crate) fn reject(self) -> Error {
+        #[cfg(feature = "metrics")]
+        metrics::increment_counter(metrics::process::REJECTED_BY_LIMITS);
+        self.into()
+    }
+}
+
+impl<N: Network> Display for ProcessLimitError<N> {
+    /// Prints the rejection as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Programs { program_id, limit } => {
+                write!(f, "Cannot add program '{program_id}', as the process already contains {limit} programs")
+            }
+            Self::ExternalStacks { program_id, limit } => {
+                write!(f, "Program '{program_id}' cannot have more than {limit} external stacks")
+            }
+            Self::Functions { program_id, limit } => {
+                write!(f, "Program '{program_id}' cannot have more than {limit} functions and closures")
+            }
+        }
+    }
+}
+
+impl<N: Network> Debug for ProcessLimitError<N> {
+    /// Prints the rejection as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> std::error::Error for ProcessLimitError<N> {}
+
+impl<N: Network> Process<N> {
+    /// The default maximum call depth of a function, which allows a call into every program in the maximum program depth.
+    pub const MAX_CALL_DEPTH: usize = N::MAX_PROGRAM_DEPTH + 1;
+    /// The default maximum number of programs that may be tracked by the process.
+    pub const MAX_PROGRAMS: usize = 1 << 20;
+
+    /// Returns the maximum number of programs that may be tracked by the process.
+    #[inline]
+    pub const fn max_programs(&self) -> usize {
+        self.max_programs
+    }
+
+    /// Sets the maximum number of programs that may be tracked by the process.
+    /// Note: The limit only applies to local deployments (i.e. `Process::deploy`), as it is local to this process.
+    /// Deployments that are verified or finalized, and programs that are loaded from storage, are never rejected,
+    /// so that processes with different limits cannot disagree on the validity of a deployment.
+    #[inline]
+    pub fn set_max_programs(&mut self, max_programs: usize) {
+        self.max_programs = max_programs;
+    }
+
+    /// Returns the maximum call depth of a function, as the number of functions in its longest call chain.
+    #[inline]
+    pub const fn max_call_depth(&self) -> usize {
+        self.max_call_depth
+    }
+
+    /// Sets the maximum call depth of a function, as the number of functions in its longest call chain.
+    /// Note: The limit applies whenever a stack is initialized, including when programs are loaded from storage.
+    #[inline]
+    pub fn set_max_call_depth(&mut self, max_call_depth: usize) {
+        self.max_call_depth = max_call_depth;
+    }
+
+    /// Ensures the process can accept the local deployment of the given program.
+    #[inline]
+    pub(crate) fn check_program_limit(&self, program_id: &ProgramID<N>) -> Result<()> {
+        match self.stacks.len() < self.max_programs {
+            true => Ok(()),
+            false => Err(ProcessLimitError::Programs { program_id: *program_id, limit: self.max_programs }.reject()),
+        }
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// The maximum number of external stacks that may be tracked by a stack.
+    pub const MAX_EXTERNAL_STACKS: usize = N::MAX_IMPORTS;
+    /// The maximum number of functions and closures that may be tracked by a stack.
+    pub const MAX_FUNCTIONS: usize = N::MAX_FUNCTIONS + N::MAX_CLOSURES;
+
+    /// Ensures the stack can track another external stack.
+    #[inline]
+    pub(crate) fn check_external_stack_limit(&self) -> Result<()> {
+        match self.external_stacks.len() < Self::MAX_EXTERNAL_STACKS {
+            true => Ok(()),
+            false => Err(ProcessLimitError::ExternalStacks {
+                program_id: *self.program_id(),
+                limit: Self::MAX_EXTERNAL_STACKS,
+            }
+            .reject()),
+        }
+    }
+
+    /// Ensures the stack can track another function or closure.
+    #[inline]
+    pub(crate) fn check_function_limit(&self) -> Result<()> {
+        match self.register_types.len() < Self::MAX_FUNCTIONS {
+            true => Ok(()),
+            false => {
+                Err(ProcessLimitError::Functions { program_id: *self.program_id(), limit: Self::MAX_FUNCTIONS }
+                    .reject())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_max_programs() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        assert_eq!(process.max_programs(), Process::<CurrentNetwork>::MAX_PROGRAMS);
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program limited.aleo;
+
+function compute:
+    input r0 as u32.public;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+        )
+        .unwrap();
+
+        // Deploy the program, while the process is below capacity.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+        // Ensure the local deployment is rejected once the process is at capacity.
+        process.set_max_programs(process.stacks.len());
+        let error = process.deploy::<CurrentAleo, _>(&program, rng).unwrap_err();
+        let rejection = error.downcast_ref::<ProcessLimitError<CurrentNetwork>>().unwrap();
+        assert_eq!(rejection, &ProcessLimitError::Programs { program_id: *program.id(), limit: 1 });
+
+        // Ensure the deployment is still verified, as the limit is local to the process.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+
+        // Ensure the program can still be loaded directly, e.g. from storage.
+        process.add_program(&program).unwrap();
+        assert!(process.contains_program(program.id()));
+
+        // Ensure new deployments are accepted once the limit is raised.
+        process.set_max_programs(Process::<CurrentNetwork>::MAX_PROGRAMS);
+        assert!(process.check_program_limit(&ProgramID::from_str("unlimited.aleo").unwrap()).is_ok());
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Process;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, MappingPath, MappingTree, Plaintext, ProgramID, Value, MAPPING_DEPTH},
+    types::Field,
+};
+use ledger_store::{FinalizeStorage, FinalizeStore};
+use synthesizer_program::{StackMatches, StackProgram};
+
+/// A Merkle proof of an entry in a mapping, against the root of the Merkle tree
+/// over the confirmed entries of the mapping, ordered by key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingProof<N: Network> {
+    /// The root of the mapping tree.
+    root: Field<N>,
+    /// The Merkle path of the entry.
+    path: MappingPath<N>,
+}
+
+impl<N: Network> MappingProof<N> {
+    /// Initializes a new mapping proof.
+    pub const fn new(root: Field<N>, path: MappingPath<N>) -> Self {
+        Self { root, path }
+    }
+
+    /// Returns the root of the mapping tree.
+    pub const fn root(&self) -> &Field<N> {
+        &self.root
+    }
+
+    /// Returns the Merkle path of the entry.
+    pub const fn path(&self) -> &MappingPath<N> {
+        &self.path
+    }
+
+    /// Returns `true` if the given key and value are an entry in the mapping tree.
+    pub fn verify(&self, key: &Plaintext<N>, value: &Value<N>) -> bool {
+        N::verify_merkle_path_bhp(&self.path, &self.root, &to_mapping_leaf(key, value))
+    }
+}
+
+/// Returns the leaf of the mapping tree for the given key and value, as `key || value`.
+fn to_mapping_leaf<N: Network>(key: &Plaintext<N>, value: &Value<N>) -> Vec<bool> {
+    let mut leaf = key.to_bits_le();
+    false.write_bits_le(&mut leaf); // Separator.
+    value.write_bits_le(&mut leaf);
+    leaf
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the confirmed value for the given key in the given mapping, if it exists.
+    /// The key and value are checked against the declared types of the mapping.
+    pub fn get_mapping_value<P: FinalizeStorage<N>>(
+        &self,
+        store: &FinalizeStore<N, P>,
+        program_id: impl TryInto<ProgramID<N>>,
+        mapping_name: impl TryInto<Identifier<N>>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Prepare the mapping name.
+        let mapping_name = mapping_name.try_into().map_err(|_| anyhow!("Invalid mapping name"))?;
+        // Retrieve the mapping.
+        let mapping = stack.program().get_mapping(&mapping_name)?;
+
+        // Ensure the key matches the declared key type.
+        stack.matches_plaintext(key, mapping.key().plaintext_type())?;
+
+        // Retrieve the value.
+        let Some(value) = store.get_value_confirmed(*stack.program_id(), mapping_name, key)? else {
+            return Ok(None);
+        };
+        // Ensure the value matches the declared value type.
+        match &value {
+            Value::Plaintext(plaintext) => stack.matches_plaintext(plaintext, mapping.value().plaintext_type())?,
+            _ => bail!("The value in mapping '{}/{mapping_name}' is not a plaintext", stack.program_id()),
+        }
+        Ok(Some(value))
+    }
+
+    /// Returns the confirmed value for the given key in the given mapping, if it exists,
+    /// along with a Merkle proof of the entry against the root of the mapping tree.
+    /// The key and value are checked against the declared types of the mapping.
+    pub fn get_mapping_value_with_proof<P: FinalizeStorage<N>>(
+        &self,
+        store: &FinalizeStore<N, P>,
+        program_id: impl TryInto<ProgramID<N>>,
+        mapping_name: impl TryInto<Identifier<N>>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<(Value<N>, MappingProof<N>)>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Retrieve the program ID.
+        let program_id = *stack.program_id();
+        // Prepare the mapping name.
+        let mapping_name = mapping_name.try_into().map_err(|_| anyhow!("Invalid mapping name"))?;
+
+        // Retrieve the value.
+        let Some(value) = self.get_mapping_value(store, program_id, mapping_name, key)? else {
+            return Ok(None);
+        };
+
+        // Retrieve the confirmed entries, ordered by key.
+        let mut entries = store
+            .get_mapping_confirmed(program_id, mapping_name)?
+            .into_iter()
+            .map(|(key, value)| Ok((key.to_bytes_le()?, key, value)))
+            .collect::<Result<Vec<_>>>()?;
+        entries.sort_unstable_by(|(a, ..), (b, ..)| a.cmp(b));
+
+        // Find the index of the entry.
+        let Some(index) = entries.iter().position(|(_, candidate, _)| candidate == key) else {
+            bail!("The key '{key}' is missing from the entries of mapping '{program_id}/{mapping_name}'")
+        };
+        // Compute the leaves.
+        let leaves = entries.iter().map(|(_, key, value)| to_mapping_leaf(key, value)).collect::<Vec<_>>();
+        // Compute the mapping tree.
+        let tree: MappingTree<N> = N::merkle_tree_bhp::<MAPPING_DEPTH>(&leaves)?;
+        // Compute the Merkle path of the entry.
+        let path = tree.prove(index, &leaves[index])?;
+
+        Ok(Some((value, MappingProof::new(*tree.root(), path))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+    use ledger_store::helpers::memory::FinalizeMemory;
+    use synthesizer_program::{FinalizeStoreTrait, Program};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_get_mapping_value() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+        let program_id = *program.id();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Construct the process.
+        let process = crate::test_helpers::sample_process(&program);
+
+        // Initialize a new finalize store, and populate the mapping.
+        let store = FinalizeStore::<CurrentNetwork, FinalizeMemory<_>>::open(None).unwrap();
+        store.initialize_mapping(program_id, mapping_name).unwrap();
+        let rng = &mut TestRng::default();
+        let entries = (0..5u64)
+            .map(|i| {
+                let private_key = console::account::PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+                let address = console::account::Address::try_from(&private_key).unwrap();
+                let key = Plaintext::from_str(&address.to_string()).unwrap();
+                let value = Value::from_str(&format!("{i}u64")).unwrap();
+                (key, value)
+            })
+            .collect::<Vec<_>>();
+        for (key, value) in &entries {
+            store.insert_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+        }
+
+        for (key, value) in &entries {
+            // Ensure the value is returned.
+            let candidate = process.get_mapping_value(&store, program_id, mapping_name, key).unwrap();
+            assert_eq!(candidate.as_ref(), Some(value));
+
+            // Ensure the proof verifies against the entry.
+            let (candidate, proof) =
+                process.get_mapping_value_with_proof(&store, program_id, mapping_name, key).unwrap().unwrap();
+            assert_eq!(&candidate, value);
+            assert!(proof.verify(key, value));
+            // Ensure the proof does not verify against a different value.
+            assert!(!proof.verify(key, &Value::from_str("100u64").unwrap()));
+        }
+
+        // Ensure every proof is against the same root.
+        let roots = entries
+            .iter()
+            .map(|(key, _)| {
+                *process.get_mapping_value_with_proof(&store, program_id, mapping_name, key).unwrap().unwrap().1.root()
+            })
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(roots.len(), 1);
+
+        // Ensure a missing key returns `None`.
+        let private_key = console::account::PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let missing =
+            Plaintext::from_str(&console::account::Address::try_from(&private_key).unwrap().to_string()).unwrap();
+        assert!(process.get_mapping_value(&store, program_id, mapping_name, &missing).unwrap().is_none());
+        assert!(process.get_mapping_value_with_proof(&store, program_id, mapping_name, &missing).unwrap().is_none());
+
+        // Ensure a key of the wrong type is rejected.
+        let invalid = Plaintext::from_str("1u64").unwrap();
+        assert!(process.get_mapping_value(&store, program_id, mapping_name, &invalid).is_err());
+        // Ensure a missing mapping is rejected.
+        assert!(process.get_mapping_value(&store, program_id, "missing", &entries[0].0).is_err());
+    }
+}
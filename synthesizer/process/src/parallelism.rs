@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+#[cfg(not(feature = "serial"))]
+use std::sync::Arc;
+
+/// The parallelism settings for executing and proving a batch of authorizations.
+///
+/// Hosted provers may use these settings to bound the resources consumed by any one caller.
+/// The thread pool is built once, and reused by every batch that is run with these settings.
+#[derive(Clone, Debug)]
+pub struct ProvingParallelism {
+    /// The number of threads used to execute and prove the batch.
+    num_threads: usize,
+    /// The maximum number of executions that are run concurrently.
+    max_concurrent_executions: usize,
+    /// The thread pool, or `None` to use the global thread pool.
+    #[cfg(not(feature = "serial"))]
+    thread_pool: Option<Arc<rayon::ThreadPool>>,
+}
+
+impl ProvingParallelism {
+    /// Initializes new parallelism settings.
+    pub fn new(num_threads: usize, max_concurrent_executions: usize) -> Result<Self> {
+        // Ensure the number of threads is nonzero.
+        ensure!(num_threads > 0, "The number of proving threads must be nonzero");
+        // Ensure the execution concurrency is nonzero.
+        ensure!(max_concurrent_executions > 0, "The maximum number of concurrent executions must be nonzero");
+        // Build the thread pool.
+        #[cfg(not(feature = "serial"))]
+        let thread_pool = Some(Arc::new(rayon::ThreadPoolBuilder::new().num_threads(num_threads).build()?));
+        // Return the parallelism settings.
+        Ok(Self {
+            num_threads,
+            max_concurrent_executions,
+            #[cfg(not(feature = "serial"))]
+            thread_pool,
+        })
+    }
+
+    /// Initializes parallelism settings that execute and prove everything on a single thread.
+    pub fn sequential() -> Result<Self> {
+        Self::new(1, 1)
+    }
+
+    /// Returns the number of threads used to execute and prove the batch.
+    pub const fn num_threads(&self) -> usize {
+        self.num_threads
+    }
+
+    /// Returns the maximum number of executions that are run concurrently.
+    pub const fn max_concurrent_executions(&self) -> usize {
+        self.max_concurrent_executions
+    }
+
+    /// Runs the given closure in the thread pool.
+    #[cfg(not(feature = "serial"))]
+    pub(crate) fn install<T: Send>(&self, f: impl FnOnce() -> T + Send) -> T {
+        match &self.thread_pool {
+            Some(thread_pool) => thread_pool.install(f),
+            None => f(),
+        }
+    }
+
+    /// Runs the given closure on the current thread.
+    #[cfg(feature = "serial")]
+    pub(crate) fn install<T: Send>(&self, f: impl FnOnce() -> T + Send) -> T {
+        f()
+    }
+}
+
+impl PartialEq for ProvingParallelism {
+    /// Returns `true` if the settings bound the batch in the same way.
+    fn eq(&self, other: &Self) -> bool {
+        self.num_threads == other.num_threads && self.max_concurrent_executions == other.max_concurrent_executions
+    }
+}
+
+impl Eq for ProvingParallelism {}
+
+impl Default for ProvingParallelism {
+    /// Returns parallelism settings that use the global thread pool, one execution at a time.
+    fn default() -> Self {
+        #[cfg(not(feature = "serial"))]
+        let num_threads = rayon::current_num_threads();
+        #[cfg(feature = "serial")]
+        let num_threads = 1;
+
+        Self {
+            num_threads,
+            max_concurrent_executions: 1,
+            #[cfg(not(feature = "serial"))]
+            thread_pool: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proving_parallelism() {
+        // Ensure zero concurrency is rejected.
+        assert!(ProvingParallelism::new(0, 1).is_err());
+        assert!(ProvingParallelism::new(1, 0).is_err());
+
+        // Ensure the settings are retained.
+        let parallelism = ProvingParallelism::new(4, 2).unwrap();
+        assert_eq!(parallelism.num_threads(), 4);
+        assert_eq!(parallelism.max_concurrent_executions(), 2);
+
+        // Ensure the closures run in the thread pool.
+        assert_eq!(parallelism.install(|| 1 + 1), 2);
+        #[cfg(not(feature = "serial"))]
+        {
+            assert_eq!(parallelism.install(rayon::current_num_threads), 4);
+            // Ensure clones share the same thread pool.
+            let clone = parallelism.clone();
+            assert!(Arc::ptr_eq(parallelism.thread_pool.as_ref().unwrap(), clone.thread_pool.as_ref().unwrap()));
+        }
+
+        // Ensure the defaults run one execution at a time.
+        assert_eq!(ProvingParallelism::default().max_concurrent_executions(), 1);
+        assert_eq!(ProvingParallelism::sequential().unwrap(), ProvingParallelism::new(1, 1).unwrap());
+    }
+}
@@ -0,0 +1,254 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::cost::deployment_cost_from_counts;
+use console::{network::prelude::*, program::Identifier};
+use synthesizer_program::Program;
+
+use indexmap::IndexMap;
+use std::time::Duration;
+
+/// The number of bytes in a serialized (compressed) field element.
+const FIELD_SIZE_IN_BYTES: u64 = 32;
+/// The number of bytes in a serialized (compressed) group element.
+const GROUP_SIZE_IN_BYTES: u64 = 48;
+/// The number of bytes in a serialized `usize`.
+const USIZE_SIZE_IN_BYTES: u64 = 8;
+/// The number of indexed polynomial commitments in a verifying key (`row`, `col`, `row_col`, `row_col_val` for `A`, `B`, `C`).
+const NUM_CIRCUIT_COMMITMENTS: u64 = 12;
+/// The number of bytes in a serialized certificate (the version, and the length-prefixed evaluation proof).
+const CERTIFICATE_SIZE_IN_BYTES: u64 = 1 + USIZE_SIZE_IN_BYTES + GROUP_SIZE_IN_BYTES + 1;
+
+/// The estimated cost of deploying a single function, obtained without generating its circuit keys.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionProfile {
+    /// The number of constraints, including the constraint added by the prover for hiding.
+    num_constraints: u64,
+    /// The number of constant, public, and private variables.
+    num_variables: u64,
+    /// The number of public variables.
+    num_public: u64,
+    /// The number of private variables.
+    num_private: u64,
+    /// The number of nonzero entries in the `A`, `B`, and `C` matrices.
+    num_nonzeros: (u64, u64, u64),
+    /// The time taken to synthesize the circuit.
+    synthesis_time: Duration,
+}
+
+impl FunctionProfile {
+    /// Initializes a new function profile.
+    pub const fn new(
+        num_constraints: u64,
+        num_variables: u64,
+        num_public: u64,
+        num_private: u64,
+        num_nonzeros: (u64, u64, u64),
+        synthesis_time: Duration,
+    ) -> Self {
+        Self { num_constraints, num_variables, num_public, num_private, num_nonzeros, synthesis_time }
+    }
+
+    /// Returns the number of constraints, including the constraint added by the prover for hiding.
+    pub const fn num_constraints(&self) -> u64 {
+        self.num_constraints
+    }
+
+    /// Returns the number of constant, public, and private variables.
+    pub const fn num_variables(&self) -> u64 {
+        self.num_variables
+    }
+
+    /// Returns the number of public variables.
+    pub const fn num_public(&self) -> u64 {
+        self.num_public
+    }
+
+    /// Returns the number of private variables.
+    pub const fn num_private(&self) -> u64 {
+        self.num_private
+    }
+
+    /// Returns the number of nonzero entries in the `A`, `B`, and `C` matrices.
+    pub const fn num_nonzeros(&self) -> (u64, u64, u64) {
+        self.num_nonzeros
+    }
+
+    /// Returns the time taken to synthesize the circuit.
+    /// Note: This does not include the time to index the circuit into a proving key.
+    pub const fn synthesis_time(&self) -> Duration {
+        self.synthesis_time
+    }
+
+    /// Returns the estimated size of the verifying key in bytes.
+    pub const fn estimated_verifying_key_size(&self) -> u64 {
+        // The circuit info, the length-prefixed circuit commitments, and the circuit ID.
+        6 * USIZE_SIZE_IN_BYTES
+            + USIZE_SIZE_IN_BYTES
+            + NUM_CIRCUIT_COMMITMENTS * GROUP_SIZE_IN_BYTES
+            + FIELD_SIZE_IN_BYTES
+    }
+
+    /// Returns the estimated size of the certificate in bytes.
+    pub const fn estimated_certificate_size(&self) -> u64 {
+        CERTIFICATE_SIZE_IN_BYTES
+    }
+
+    /// Returns the estimated size in bytes of the function in a deployment, excluding the function name.
+    pub const fn estimated_deployment_size(&self) -> u64 {
+        // The deployment stores the versioned verifying key with its number of variables, and the certificate.
+        1 + self.estimated_verifying_key_size() + USIZE_SIZE_IN_BYTES + CERTIFICATE_SIZE_IN_BYTES
+    }
+
+    /// Returns the estimated cost in microcredits to deploy the function (total cost, (storage cost, synthesis cost)).
+    /// Note: This excludes the costs shared by all functions, which are the program bytes and the namespace.
+    pub fn estimated_cost<N: Network>(&self) -> (u64, (u64, u64)) {
+        // Compute the storage cost in microcredits.
+        let storage_cost = self.estimated_deployment_size().saturating_mul(N::DEPLOYMENT_FEE_MULTIPLIER);
+        // Compute the synthesis cost in microcredits.
+        let synthesis_cost =
+            self.num_variables.saturating_add(self.num_constraints).saturating_mul(N::SYNTHESIS_FEE_MULTIPLIER);
+        // Compute the total cost in microcredits.
+        (storage_cost.saturating_add(synthesis_cost), (storage_cost, synthesis_cost))
+    }
+
+    /// Returns the estimated size of the proving key in bytes.
+    /// Note: This excludes the committer key, which is trimmed from the universal SRS.
+    pub fn estimated_proving_key_size(&self) -> u64 {
+        let (a, b, c) = self.num_nonzeros;
+        // Estimate the size of the indexed circuit, for each of the `A`, `B`, and `C` matrices.
+        let circuit_size = [a, b, c].into_iter().fold(0u64, |size, num_nonzeros| {
+            // The sparse matrix stores a `(field, index)` pair per nonzero entry, and a length per row.
+            let matrix_size = num_nonzeros
+                .saturating_mul(FIELD_SIZE_IN_BYTES + USIZE_SIZE_IN_BYTES)
+                .saturating_add(self.num_constraints.saturating_mul(USIZE_SIZE_IN_BYTES));
+            // The arithmetization stores the `row`, `col`, and `row_col_val` evaluations over the nonzero domain.
+            let arith_size = 3u64
+                .saturating_mul(num_nonzeros.checked_next_power_of_two().unwrap_or(u64::MAX))
+                .saturating_mul(FIELD_SIZE_IN_BYTES);
+            size.saturating_add(matrix_size).saturating_add(arith_size)
+        });
+        // The proving key contains the verifying key and the indexed circuit.
+        self.estimated_verifying_key_size().saturating_add(circuit_size)
+    }
+}
+
+/// The estimated cost of deploying a program, obtained without generating its circuit keys or certificates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentProfile<N: Network> {
+    /// The mapping of function names to their profiles.
+    functions: IndexMap<Identifier<N>, FunctionProfile>,
+}
+
+impl<N: Network> DeploymentProfile<N> {
+    /// Initializes a new deployment profile.
+    pub const fn new(functions: IndexMap<Identifier<N>, FunctionProfile>) -> Self {
+        Self { functions }
+    }
+
+    /// Returns the mapping of function names to their profiles.
+    pub const fn functions(&self) -> &IndexMap<Identifier<N>, FunctionProfile> {
+        &self.functions
+    }
+
+    /// Returns the profile for the given function name.
+    pub fn get_function(&self, function_name: &Identifier<N>) -> Result<&FunctionProfile> {
+        self.functions.get(function_name).ok_or_else(|| anyhow!("Function '{function_name}' is not in the profile"))
+    }
+
+    /// Returns the number of combined constraints across all functions.
+    pub fn num_combined_constraints(&self) -> u64 {
+        self.functions.values().fold(0u64, |total, profile| total.saturating_add(profile.num_constraints()))
+    }
+
+    /// Returns the number of combined variables across all functions.
+    pub fn num_combined_variables(&self) -> u64 {
+        self.functions.values().fold(0u64, |total, profile| total.saturating_add(profile.num_variables()))
+    }
+
+    /// Returns `true` if the combined constraints and variables are within the deployment limits.
+    pub fn is_within_limits(&self) -> bool {
+        self.num_combined_constraints() <= N::MAX_DEPLOYMENT_CONSTRAINTS
+            && self.num_combined_variables() <= N::MAX_DEPLOYMENT_VARIABLES
+    }
+
+    /// Returns the total time taken to synthesize the circuits.
+    pub fn synthesis_time(&self) -> Duration {
+        self.functions.values().map(FunctionProfile::synthesis_time).sum()
+    }
+}
+
+/// The estimated cost of deploying a program, including the estimated fee of each function,
+/// obtained without generating its circuit keys or certificates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentEstimate<N: Network> {
+    /// The deployment profile.
+    profile: DeploymentProfile<N>,
+    /// The estimated size of the deployment in bytes.
+    size_in_bytes: u64,
+    /// The estimated cost in microcredits (total cost, (storage cost, synthesis cost, namespace cost)).
+    cost: (u64, (u64, u64, u64)),
+}
+
+impl<N: Network> DeploymentEstimate<N> {
+    /// Initializes a new deployment estimate, for the given program and its deployment profile.
+    pub fn new(program: &Program<N>, profile: DeploymentProfile<N>) -> Result<Self> {
+        // Ensure the profile contains every function in the program.
+        ensure!(
+            program.functions().keys().eq(profile.functions().keys()),
+            "The profile does not match the functions in '{}'",
+            program.id()
+        );
+
+        // Estimate the size of the deployment, which is the version, the edition, the program,
+        // and the function names with their verifying keys and certificates.
+        let program_size = u64::try_from(program.to_bytes_le()?.len())?;
+        let mut size_in_bytes = program_size.saturating_add(1 + 2 + 2);
+        for (function_name, function) in profile.functions() {
+            let name_size = u64::try_from(function_name.to_bytes_le()?.len())?;
+            size_in_bytes =
+                size_in_bytes.saturating_add(name_size).saturating_add(function.estimated_deployment_size());
+        }
+
+        // Compute the estimated deployment cost.
+        let cost = deployment_cost_from_counts(
+            size_in_bytes,
+            program.id(),
+            profile.num_combined_variables(),
+            profile.num_combined_constraints(),
+        )?;
+
+        Ok(Self { profile, size_in_bytes, cost })
+    }
+
+    /// Returns the deployment profile.
+    pub const fn profile(&self) -> &DeploymentProfile<N> {
+        &self.profile
+    }
+
+    /// Returns the estimated size of the deployment in bytes.
+    pub const fn size_in_bytes(&self) -> u64 {
+        self.size_in_bytes
+    }
+
+    /// Returns the estimated cost in microcredits to deploy the program (total cost, (storage cost, synthesis cost, namespace cost)).
+    pub const fn cost(&self) -> (u64, (u64, u64, u64)) {
+        self.cost
+    }
+
+    /// Returns the estimated cost in microcredits to deploy each function (total cost, (storage cost, synthesis cost)).
+    pub fn function_costs(&self) -> IndexMap<Identifier<N>, (u64, (u64, u64))> {
+        self.profile.functions().iter().map(|(name, function)| (*name, function.estimated_cost::<N>())).collect()
+    }
+}
@@ -0,0 +1,480 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use parking_lot::Mutex;
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// The counter used to assign each stack a unique scope in the proving key cache.
+static NEXT_SCOPE: AtomicU64 = AtomicU64::new(0);
+/// The counter used to assign each spilled proving key a unique file name.
+static NEXT_SPILL: AtomicU64 = AtomicU64::new(0);
+
+/// The configuration of a proving key cache.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProvingKeyCacheConfig {
+    /// The maximum number of bytes of proving keys to keep in memory.
+    memory_budget: usize,
+    /// The directory that the least-recently-used proving keys are spilled to.
+    spill_directory: PathBuf,
+}
+
+impl ProvingKeyCacheConfig {
+    /// Initializes a new proving key cache configuration.
+    pub fn new(memory_budget: usize, spill_directory: impl Into<PathBuf>) -> Self {
+        Self { memory_budget, spill_directory: spill_directory.into() }
+    }
+
+    /// Returns the maximum number of bytes of proving keys to keep in memory.
+    pub const fn memory_budget(&self) -> usize {
+        self.memory_budget
+    }
+
+    /// Returns the directory that the least-recently-used proving keys are spilled to.
+    pub fn spill_directory(&self) -> &Path {
+        &self.spill_directory
+    }
+}
+
+/// A least-recently-used cache of proving keys, shared by the stacks in a process.
+///
+/// By default, every proving key is kept in memory. Once a memory budget is configured, the
+/// least-recently-used proving keys are spilled to disk, and are reloaded when they are next used.
+/// The lock on the cache is never held while a proving key is read, written, or deserialized, and a
+/// spilled proving key is only reloaded if the file matches the SHA-256 digest recorded when it was spilled.
+/// Note: The most-recently-used proving key is always kept in memory, even if it exceeds the budget.
+#[derive(Clone)]
+pub struct ProvingKeyCache<N: Network> {
+    /// The state of the cache.
+    state: Arc<Mutex<CacheState<N>>>,
+}
+
+impl<N: Network> Default for ProvingKeyCache<N> {
+    /// Initializes a new proving key cache, which keeps every proving key in memory.
+    fn default() -> Self {
+        Self { state: Arc::new(Mutex::new(CacheState::default())) }
+    }
+}
+
+impl<N: Network> ProvingKeyCache<N> {
+    /// Configures the cache to spill the least-recently-used proving keys to disk, once the memory budget is exceeded.
+    pub fn configure(&self, config: ProvingKeyCacheConfig) -> Result<()> {
+        // Ensure the spill directory exists.
+        fs::create_dir_all(config.spill_directory())?;
+
+        let evicted = {
+            let mut state = self.state.lock();
+            // Measure the proving keys in memory, as they are not measured while the cache is unbounded.
+            if state.config.is_none() {
+                for (proving_key, size) in state.resident.values_mut() {
+                    *size = proving_key.to_bytes_le()?.len();
+                }
+                state.resident_bytes = state.resident.values().map(|(_, size)| size).sum();
+            }
+            // Set the configuration.
+            state.config = Some(config);
+            // Evict the proving keys that exceed the memory budget.
+            state.evict()
+        };
+        // Spill the evicted proving keys to disk.
+        self.spill(evicted)
+    }
+
+    /// Returns the configuration of the cache, if it is bounded.
+    pub fn config(&self) -> Option<ProvingKeyCacheConfig> {
+        self.state.lock().config.clone()
+    }
+
+    /// Returns the number of bytes of proving keys in memory, if the cache is bounded.
+    pub fn resident_bytes(&self) -> usize {
+        self.state.lock().resident_bytes
+    }
+
+    /// Returns the number of proving keys in memory.
+    pub fn num_resident(&self) -> usize {
+        self.state.lock().resident.len()
+    }
+
+    /// Returns the number of proving keys on disk.
+    pub fn num_spilled(&self) -> usize {
+        self.state.lock().spilled.len()
+    }
+
+    /// Returns a new, empty scope for the proving keys of a stack.
+    pub(crate) fn new_scope(&self) -> StackProvingKeys<N> {
+        StackProvingKeys { cache: self.clone(), scope: NEXT_SCOPE.fetch_add(1, Ordering::Relaxed) }
+    }
+
+    /// Returns the proving key for the given key, reloading it from disk if it was spilled.
+    fn get(&self, key: &CacheKey<N>) -> Result<Option<ProvingKey<N>>> {
+        // Retrieve the proving key from memory, or the file that it was spilled to.
+        let spilled = {
+            let mut state = self.state.lock();
+            // If the proving key is in memory, mark it as the most-recently-used.
+            if let Some(entry) = state.resident.shift_remove(key) {
+                let proving_key = entry.0.clone();
+                state.resident.insert(*key, entry);
+                return Ok(Some(proving_key));
+            }
+            // If the proving key is being spilled, it is still in memory.
+            if let Some((proving_key, _)) = state.spilling.get(key) {
+                return Ok(Some(proving_key.clone()));
+            }
+            match state.spilled.get(key) {
+                Some(spilled) => spilled.clone(),
+                None => return Ok(None),
+            }
+        };
+
+        // Reload the proving key from disk, and ensure it matches its digest.
+        let (path, digest) = &spilled;
+        let bytes = fs::read(path)?;
+        ensure!(
+            Sha256::digest(&bytes)[..] == digest[..],
+            "The spilled proving key at '{}' is corrupted (digest mismatch)",
+            path.display()
+        );
+        let proving_key = ProvingKey::from_bytes_le(&bytes)?;
+
+        let evicted = {
+            let mut state = self.state.lock();
+            // Insert the proving key into memory, unless it was removed, replaced, or reloaded in the meantime.
+            // Note: The file is kept, so that the proving key is not written again when it is next evicted.
+            match state.spilled.get(key) == Some(&spilled) && !state.resident.contains_key(key) {
+                true => {
+                    state.resident.insert(*key, (proving_key.clone(), bytes.len()));
+                    state.resident_bytes = state.resident_bytes.saturating_add(bytes.len());
+                    // Evict the proving keys that exceed the memory budget.
+                    state.evict()
+                }
+                false => Vec::new(),
+            }
+        };
+        // Spill the evicted proving keys to disk.
+        self.spill(evicted)?;
+        Ok(Some(proving_key))
+    }
+
+    /// Inserts the given proving key for the given key, replacing any previous proving key.
+    fn insert(&self, key: CacheKey<N>, proving_key: ProvingKey<N>) -> Result<()> {
+        // Measure the proving key, if the cache is bounded.
+        let is_bounded = self.state.lock().config.is_some();
+        let size = match is_bounded {
+            true => proving_key.to_bytes_le()?.len(),
+            false => 0,
+        };
+
+        let (previous, evicted) = {
+            let mut state = self.state.lock();
+            // Remove the previous proving key, if it exists.
+            let previous = state.remove(&key);
+            // Insert the proving key into memory.
+            state.resident.insert(key, (proving_key, size));
+            state.resident_bytes = state.resident_bytes.saturating_add(size);
+            // Evict the proving keys that exceed the memory budget.
+            (previous, state.evict())
+        };
+        // Remove the file of the previous proving key, if it was spilled.
+        remove_spill_file(previous);
+        // Spill the evicted proving keys to disk.
+        self.spill(evicted)
+    }
+
+    /// Removes the proving key for the given key, from memory and from disk.
+    fn remove(&self, key: &CacheKey<N>) {
+        let path = self.state.lock().remove(key);
+        remove_spill_file(path);
+    }
+
+    /// Writes the given evicted proving keys to disk.
+    ///
+    /// Until its file is written, an evicted proving key is still served from memory.
+    /// If a write fails, the proving key is returned to memory, and the first error is returned.
+    fn spill(&self, evicted: Vec<(CacheKey<N>, PathBuf)>) -> Result<()> {
+        let mut result = Ok(());
+        for (key, path) in evicted {
+            // Retrieve the proving key that is being spilled.
+            let Some(proving_key) = self.state.lock().spilling.get(&key).map(|(proving_key, _)| proving_key.clone())
+            else {
+                continue;
+            };
+            // Write the proving key to disk, and compute its digest.
+            let digest = proving_key.to_bytes_le().and_then(|bytes| {
+                fs::write(&path, &bytes)?;
+                Ok(<[u8; 32]>::from(Sha256::digest(&bytes)))
+            });
+
+            let mut state = self.state.lock();
+            let spilling = state.spilling.shift_remove(&key);
+            match (spilling, digest) {
+                // If the proving key was written, track its file.
+                (Some(_), Ok(digest)) => {
+                    state.spilled.insert(key, (path, digest));
+                }
+                // If the write failed, return the proving key to memory.
+                (Some((proving_key, size)), Err(error)) => {
+                    state.resident.insert(key, (proving_key, size));
+                    state.resident_bytes = state.resident_bytes.saturating_add(size);
+                    if result.is_ok() {
+                        result = Err(error);
+                    }
+                }
+                // If the proving key was removed in the meantime, remove its file.
+                (None, _) => {
+                    drop(state);
+                    remove_spill_file(Some(path));
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Removes the given file of a spilled proving key, if it exists.
+fn remove_spill_file(path: Option<PathBuf>) {
+    if let Some(path) = path {
+        // Note: A failure to remove the file only leaks disk space, as the key is no longer tracked.
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// The key of a proving key in the cache, as `(scope, function name)`.
+type CacheKey<N> = (u64, Identifier<N>);
+
+/// The state of a proving key cache.
+struct CacheState<N: Network> {
+    /// The configuration, if the cache is bounded.
+    config: Option<ProvingKeyCacheConfig>,
+    /// The proving keys in memory, with their size in bytes, from least to most recently used.
+    resident: IndexMap<CacheKey<N>, (ProvingKey<N>, usize)>,
+    /// The number of bytes of proving keys in memory.
+    resident_bytes: usize,
+    /// The evicted proving keys that are being written to disk, with their size in bytes.
+    spilling: IndexMap<CacheKey<N>, (ProvingKey<N>, usize)>,
+    /// The paths and SHA-256 digests of the proving keys on disk.
+    spilled: IndexMap<CacheKey<N>, (PathBuf, [u8; 32])>,
+}
+
+impl<N: Network> Default for CacheState<N> {
+    fn default() -> Self {
+        Self {
+            config: None,
+            resident: IndexMap::new(),
+            resident_bytes: 0,
+            spilling: IndexMap::new(),
+            spilled: IndexMap::new(),
+        }
+    }
+}
+
+impl<N: Network> CacheState<N> {
+    /// Returns `true` if the proving key for the given key is in memory or on disk.
+    fn contains(&self, key: &CacheKey<N>) -> bool {
+        self.resident.contains_key(key) || self.spilling.contains_key(key) || self.spilled.contains_key(key)
+    }
+
+    /// Removes the proving key for the given key, and returns the path of its file, if it was spilled.
+    /// Note: The caller removes the file, so that the lock is not held during disk I/O.
+    fn remove(&mut self, key: &CacheKey<N>) -> Option<PathBuf> {
+        if let Some((_, size)) = self.resident.shift_remove(key) {
+            self.resident_bytes = self.resident_bytes.saturating_sub(size);
+        }
+        self.spilling.shift_remove(key);
+        self.spilled.shift_remove(key).map(|(path, _)| path)
+    }
+
+    /// Evicts the least-recently-used proving keys from memory, until the memory budget is met.
+    /// Returns the keys that must be written to disk, with the path of their file (see `ProvingKeyCache::spill`).
+    fn evict(&mut self) -> Vec<(CacheKey<N>, PathBuf)> {
+        let Some(config) = &self.config else { return Vec::new() };
+        let mut evicted = Vec::new();
+        // Evict the least-recently-used proving keys, keeping the most-recently-used proving key in memory.
+        while self.resident_bytes > config.memory_budget && self.resident.len() > 1 {
+            let Some((key, (proving_key, size))) = self.resident.shift_remove_index(0) else { break };
+            self.resident_bytes = self.resident_bytes.saturating_sub(size);
+            // Write the proving key to disk, if it is not already there.
+            if !self.spilled.contains_key(&key) {
+                let name = format!("{}-{}.prover", std::process::id(), NEXT_SPILL.fetch_add(1, Ordering::Relaxed));
+                evicted.push((key, config.spill_directory.join(name)));
+                self.spilling.insert(key, (proving_key, size));
+            }
+        }
+        evicted
+    }
+}
+
+/// The proving keys of a stack, stored in the proving key cache of the process.
+/// The proving keys are removed from the cache once every clone of the stack is dropped.
+pub(crate) struct StackProvingKeys<N: Network> {
+    /// The proving key cache.
+    cache: ProvingKeyCache<N>,
+    /// The scope of the stack in the cache.
+    scope: u64,
+}
+
+impl<N: Network> StackProvingKeys<N> {
+    /// Returns `true` if the proving key for the given function name exists.
+    pub(crate) fn contains(&self, function_name: &Identifier<N>) -> bool {
+        self.cache.state.lock().contains(&(self.scope, *function_name))
+    }
+
+    /// Returns the proving key for the given function name, if it exists.
+    pub(crate) fn get(&self, function_name: &Identifier<N>) -> Result<Option<ProvingKey<N>>> {
+        self.cache.get(&(self.scope, *function_name))
+    }
+
+    /// Inserts the given proving key for the given function name.
+    pub(crate) fn insert(&self, function_name: &Identifier<N>, proving_key: ProvingKey<N>) -> Result<()> {
+        self.cache.insert((self.scope, *function_name), proving_key)
+    }
+
+    /// Removes the proving key for the given function name.
+    pub(crate) fn remove(&self, function_name: &Identifier<N>) {
+        self.cache.remove(&(self.scope, *function_name))
+    }
+}
+
+impl<N: Network> Drop for StackProvingKeys<N> {
+    /// Removes the proving keys of the stack from the cache.
+    fn drop(&mut self) {
+        let paths: Vec<_> = {
+            let mut state = self.cache.state.lock();
+            let keys: Vec<_> = state
+                .resident
+                .keys()
+                .chain(state.spilling.keys())
+                .chain(state.spilled.keys())
+                .filter(|(scope, _)| *scope == self.scope)
+                .copied()
+                .collect();
+            keys.iter().filter_map(|key| state.remove(key)).collect()
+        };
+        for path in paths {
+            remove_spill_file(Some(path));
+        }
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the proving key cache, which is shared by the stacks in the process.
+    #[inline]
+    pub const fn proving_key_cache(&self) -> &ProvingKeyCache<N> {
+        &self.proving_key_cache
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_proving_key_cache_spills_to_disk() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program with two functions.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program cache.aleo;
+
+function add:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;
+
+function mul:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    mul r0 r1 into r2;
+    output r2 as u64.private;",
+        )
+        .unwrap();
+        let add = Identifier::from_str("add").unwrap();
+        let mul = Identifier::from_str("mul").unwrap();
+
+        // Initialize the process, and synthesize the proving keys.
+        let mut process = Process::load().unwrap();
+        process.add_program(&program).unwrap();
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &add, rng).unwrap();
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &mul, rng).unwrap();
+        let expected_add = process.get_proving_key(*program.id(), add).unwrap().to_bytes_le().unwrap();
+        let expected_mul = process.get_proving_key(*program.id(), mul).unwrap().to_bytes_le().unwrap();
+
+        // Bound the cache, so that only the most-recently-used proving key is kept in memory.
+        let directory = tempfile::tempdir().unwrap();
+        let cache = process.proving_key_cache();
+        cache.configure(ProvingKeyCacheConfig::new(1, directory.path())).unwrap();
+        assert_eq!(cache.num_resident(), 1);
+        assert_eq!(cache.num_spilled(), 1);
+
+        // Ensure the spilled proving key is reloaded from disk, and the other proving key is spilled.
+        assert_eq!(process.get_proving_key(*program.id(), add).unwrap().to_bytes_le().unwrap(), expected_add);
+        assert_eq!(cache.num_resident(), 1);
+        assert_eq!(cache.num_spilled(), 2);
+        assert_eq!(process.get_proving_key(*program.id(), mul).unwrap().to_bytes_le().unwrap(), expected_mul);
+
+        // Ensure a corrupted spilled proving key is not reloaded.
+        for entry in fs::read_dir(directory.path()).unwrap() {
+            fs::write(entry.unwrap().path(), [0u8; 32]).unwrap();
+        }
+        let error = process.get_proving_key(*program.id(), add).unwrap_err();
+        assert!(error.to_string().contains("digest mismatch"), "{error}");
+
+        // Ensure the spilled proving keys are still found by the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+        assert!(stack.contains_proving_key(&add));
+        assert!(stack.contains_proving_key(&mul));
+
+        // Ensure removing a proving key removes it from disk.
+        stack.remove_proving_key(&add);
+        assert!(!stack.contains_proving_key(&add));
+        assert_eq!(fs::read_dir(directory.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_proving_key_cache_removes_dropped_stacks() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program cache.aleo;
+
+function add:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;",
+        )
+        .unwrap();
+
+        // Initialize the process, and deploy the program, which synthesizes the proving key in a temporary stack.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+        // Ensure the proving key of the temporary stack is removed from the cache.
+        assert_eq!(process.proving_key_cache().num_resident(), 0);
+        assert_eq!(process.proving_key_cache().num_spilled(), 0);
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The maximum number of memoized queries, after which the oldest queries are evicted.
+pub const MAX_MEMOIZED_QUERIES: usize = 4096;
+
+/// The key of a memoized query, as the program ID, function name, and the bytes of the inputs.
+pub(crate) type QueryKey<N> = (ProgramID<N>, Identifier<N>, Vec<u8>);
+
+impl<N: Network> Process<N> {
+    /// Returns the number of memoized queries.
+    #[inline]
+    pub fn num_memoized_queries(&self) -> usize {
+        self.query_cache.read().len()
+    }
+
+    /// Removes the memoized queries to the given program, and to the programs that `call.view` it.
+    /// This is invoked whenever the stack of the program is (re)added to the process.
+    ///
+    /// Note: A viewed function cannot make calls of its own (see `CallView::to_closure`),
+    /// so only the programs that directly view the given program are affected.
+    #[inline]
+    pub fn invalidate_memoized_queries(&self, program_id: &ProgramID<N>) {
+        // Retrieve the programs that view the given program.
+        let viewers = self
+            .stacks
+            .values()
+            .filter(|stack| Self::views_program(stack.program(), program_id))
+            .map(|stack| *stack.program_id())
+            .collect::<IndexSet<_>>();
+        self.query_cache.write().retain(|(id, _, _), _| id != program_id && !viewers.contains(id));
+    }
+
+    /// Removes all memoized queries.
+    #[inline]
+    pub fn clear_memoized_queries(&self) {
+        self.query_cache.write().clear();
+    }
+
+    /// Returns `true` if the given program contains a `call.view` to the given program ID.
+    fn views_program(program: &Program<N>, program_id: &ProgramID<N>) -> bool {
+        program
+            .functions()
+            .values()
+            .flat_map(|function| function.instructions())
+            .chain(program.closures().values().flat_map(|closure| closure.instructions()))
+            .any(|instruction| {
+                matches!(instruction, Instruction::CallView(call) if call.locator().program_id() == program_id)
+            })
+    }
+
+    /// Returns the key of the query to the given function with the given inputs.
+    #[inline]
+    pub(crate) fn to_query_key(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<QueryKey<N>> {
+        Ok((program_id, function_name, inputs.to_bytes_le()?))
+    }
+
+    /// Returns the memoized outputs of the given query, if they exist.
+    #[inline]
+    pub(crate) fn get_memoized_query(&self, key: &QueryKey<N>) -> Option<Vec<Value<N>>> {
+        self.query_cache.read().get(key).cloned()
+    }
+
+    /// Memoizes the outputs of the given query, evicting the oldest query if the cache is full.
+    #[inline]
+    pub(crate) fn memoize_query(&self, key: QueryKey<N>, outputs: Vec<Value<N>>) {
+        let mut query_cache = self.query_cache.write();
+        if query_cache.len() >= MAX_MEMOIZED_QUERIES {
+            query_cache.shift_remove_index(0);
+        }
+        query_cache.insert(key, outputs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns a program with a pure `price` function that scales its input by the given factor.
+    fn sample_program(factor: u64) -> Program<CurrentNetwork> {
+        Program::from_str(&format!(
+            r"
+program oracle.aleo;
+
+function price:
+    input r0 as u64.public;
+    mul r0 {factor}u64 into r1;
+    output r1 as u64.public;"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_memoized_queries() {
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        let program = sample_program(2);
+        process.add_program(&program).unwrap();
+        assert_eq!(process.num_memoized_queries(), 0);
+
+        // Ensure the query is memoized.
+        let outputs = process.query_function::<CurrentAleo>(program.id(), "price", ["3u64"].into_iter()).unwrap();
+        assert_eq!(outputs, vec![Value::from_str("6u64").unwrap()]);
+        assert_eq!(process.num_memoized_queries(), 1);
+        // Ensure a repeated query is served from the cache.
+        let outputs = process.query_function::<CurrentAleo>(program.id(), "price", ["3u64"].into_iter()).unwrap();
+        assert_eq!(outputs, vec![Value::from_str("6u64").unwrap()]);
+        assert_eq!(process.num_memoized_queries(), 1);
+        // Ensure queries with different inputs are memoized separately.
+        process.query_function::<CurrentAleo>(program.id(), "price", ["4u64"].into_iter()).unwrap();
+        assert_eq!(process.num_memoized_queries(), 2);
+        // Ensure failed queries are not memoized.
+        assert!(process.query_function::<CurrentAleo>(program.id(), "price", ["4field"].into_iter()).is_err());
+        assert_eq!(process.num_memoized_queries(), 2);
+
+        // Redeploy the program, and ensure its memoized queries are invalidated.
+        let program = sample_program(5);
+        let stack = Stack::new(&Process::load().unwrap(), &program).unwrap();
+        process.add_stack(stack);
+        assert_eq!(process.num_memoized_queries(), 0);
+        let outputs = process.query_function::<CurrentAleo>(program.id(), "price", ["3u64"].into_iter()).unwrap();
+        assert_eq!(outputs, vec![Value::from_str("15u64").unwrap()]);
+
+        // Ensure the memoized queries can be cleared.
+        process.clear_memoized_queries();
+        assert_eq!(process.num_memoized_queries(), 0);
+    }
+
+    #[test]
+    fn test_memoized_queries_of_viewers() {
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        let program = sample_program(2);
+        process.add_program(&program).unwrap();
+        // Add a program that views the `price` function.
+        let viewer = Program::<CurrentNetwork>::from_str(
+            r"
+import oracle.aleo;
+
+program viewer.aleo;
+
+function quote:
+    input r0 as u64.public;
+    call.view oracle.aleo/price r0 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+        process.add_program(&viewer).unwrap();
+
+        // Memoize a query to each program.
+        process.query_function::<CurrentAleo>(program.id(), "price", ["3u64"].into_iter()).unwrap();
+        let outputs = process.query_function::<CurrentAleo>(viewer.id(), "quote", ["3u64"].into_iter()).unwrap();
+        assert_eq!(outputs, vec![Value::from_str("6u64").unwrap()]);
+        assert_eq!(process.num_memoized_queries(), 2);
+
+        // Redeploy the viewed program, and ensure the queries to its viewer are invalidated as well.
+        let stack = Stack::new(&Process::load().unwrap(), &sample_program(5)).unwrap();
+        process.add_stack(stack);
+        assert_eq!(process.num_memoized_queries(), 0);
+    }
+}
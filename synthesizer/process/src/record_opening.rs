@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::types::{Address, Group};
+use synthesizer_program::StackMatches;
+use synthesizer_snark::Proof;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+/// A proof of knowledge of a record opening that matches a public record commitment,
+/// produced without executing any program function.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RecordOpeningProof<N: Network> {
+    /// The program ID of the record.
+    program_id: ProgramID<N>,
+    /// The record name.
+    record_name: Identifier<N>,
+    /// The record commitment.
+    commitment: Field<N>,
+    /// The proof.
+    proof: Proof<N>,
+}
+
+impl<N: Network> RecordOpeningProof<N> {
+    /// Returns the program ID of the record.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the record name.
+    pub const fn record_name(&self) -> &Identifier<N> {
+        &self.record_name
+    }
+
+    /// Returns the record commitment.
+    pub const fn commitment(&self) -> &Field<N> {
+        &self.commitment
+    }
+
+    /// Returns the proof.
+    pub const fn proof(&self) -> &Proof<N> {
+        &self.proof
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns a proof that the given record opens the record commitment for the given program ID and record name.
+    /// The record remains private, while the program ID, record name, and commitment are public.
+    #[inline]
+    pub fn prove_record_opening<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+        record: &Record<N, Plaintext<N>>,
+        rng: &mut R,
+    ) -> Result<RecordOpeningProof<N>> {
+        // Ensure the record matches the record type.
+        self.get_stack(program_id)?.matches_record(record, record_name)?;
+        // Compute the record commitment.
+        let commitment = record.to_commitment(program_id, record_name)?;
+        // Synthesize the record opening.
+        let assignment = Self::synthesize_record_opening::<A>(program_id, record_name, record, commitment)?;
+        // Compute the proof.
+        let (proving_key, _) = self.record_opening_keys::<A>(program_id, record_name)?;
+        let proof = proving_key.prove(&Self::record_opening_name(program_id, record_name), &assignment, rng)?;
+        // Return the record opening proof.
+        Ok(RecordOpeningProof { program_id: *program_id, record_name: *record_name, commitment, proof })
+    }
+
+    /// Verifies the given record opening proof.
+    /// Note: This does *not* check that the record commitment exists in the ledger.
+    #[inline]
+    pub fn verify_record_opening<A: circuit::Aleo<Network = N>>(
+        &self,
+        record_opening: &RecordOpeningProof<N>,
+    ) -> Result<()> {
+        // Retrieve the verifying key.
+        let (_, verifying_key) =
+            self.record_opening_keys::<A>(&record_opening.program_id, &record_opening.record_name)?;
+        // Construct the public inputs.
+        let inputs = [N::Field::one(), *record_opening.commitment];
+        // Verify the proof.
+        let name = Self::record_opening_name(&record_opening.program_id, &record_opening.record_name);
+        ensure!(verifying_key.verify(&name, &inputs, &record_opening.proof), "Record opening proof is invalid");
+        Ok(())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the name of the record opening circuit for the given program ID and record name.
+    fn record_opening_name(program_id: &ProgramID<N>, record_name: &Identifier<N>) -> String {
+        format!("{program_id}/{record_name} (record opening)")
+    }
+
+    /// Returns the proving and verifying key of the record opening circuit for the given program ID and record name.
+    /// The circuit depends only on the record type, so the keys are derived from a sampled record of that type.
+    fn record_opening_keys<A: circuit::Aleo<Network = N>>(
+        &self,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+    ) -> Result<(ProvingKey<N>, VerifyingKey<N>)> {
+        // Sample a record of the record type, deterministically.
+        let rng = &mut StdRng::from_seed([0u8; 32]);
+        let burner_address = Address::try_from(PrivateKey::<N>::new(rng)?)?;
+        let record = self.get_stack(program_id)?.sample_record(&burner_address, record_name, Group::rand(rng), rng)?;
+        // Synthesize the record opening for the sampled record.
+        let commitment = record.to_commitment(program_id, record_name)?;
+        let assignment = Self::synthesize_record_opening::<A>(program_id, record_name, &record, commitment)?;
+        // Derive the circuit keys.
+        self.universal_srs.to_circuit_key(&Self::record_opening_name(program_id, record_name), &assignment)
+    }
+
+    /// Synthesizes the record opening circuit, which enforces that the private record opens the public commitment.
+    fn synthesize_record_opening<A: circuit::Aleo<Network = N>>(
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+        record: &Record<N, Plaintext<N>>,
+        commitment: Field<N>,
+    ) -> Result<circuit::Assignment<N::Field>> {
+        use circuit::Inject;
+
+        // Ensure the circuit environment is clean.
+        A::reset();
+
+        // Inject the program ID as `Mode::Constant`.
+        let program_id = circuit::ProgramID::<A>::constant(*program_id);
+        // Inject the record name as `Mode::Constant`.
+        let record_name = circuit::Identifier::<A>::constant(*record_name);
+        // Inject the commitment as `Mode::Public`.
+        let commitment = circuit::Field::<A>::new(circuit::Mode::Public, commitment);
+        // Inject the record as `Mode::Private`.
+        let record = circuit::Record::<A, circuit::Plaintext<A>>::new(circuit::Mode::Private, record.clone());
+
+        // Enforce the record opens the commitment.
+        A::assert_eq(record.to_commitment(&program_id, &record_name), commitment);
+
+        // Ensure the circuit is satisfied.
+        if !A::is_satisfied() {
+            A::reset();
+            bail!("The record does not open the record commitment")
+        }
+        // Eject the assignment and reset the circuit environment.
+        Ok(A::eject_assignment_and_reset())
+    }
+}
+
+impl<N: Network> FromBytes for RecordOpeningProof<N> {
+    /// Reads the record opening proof from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid record opening proof version"));
+        }
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the record name.
+        let record_name = Identifier::read_le(&mut reader)?;
+        // Read the commitment.
+        let commitment = Field::read_le(&mut reader)?;
+        // Read the proof.
+        let proof = Proof::read_le(&mut reader)?;
+        // Return the record opening proof.
+        Ok(Self { program_id, record_name, commitment, proof })
+    }
+}
+
+impl<N: Network> ToBytes for RecordOpeningProof<N> {
+    /// Writes the record opening proof to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the record name.
+        self.record_name.write_le(&mut writer)?;
+        // Write the commitment.
+        self.commitment.write_le(&mut writer)?;
+        // Write the proof.
+        self.proof.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_record_opening() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let record_name = Identifier::from_str("credits").unwrap();
+
+        // Sample a credits record.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let owner = Address::try_from(private_key).unwrap();
+        let record = Record::<CurrentNetwork, Plaintext<_>>::from_str(&format!(
+            "{{ owner: {owner}.private, microcredits: 1_000_000u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+
+        // Prove the record opening.
+        let opening = process.prove_record_opening::<CurrentAleo, _>(&program_id, &record_name, &record, rng).unwrap();
+        assert_eq!(*opening.commitment(), record.to_commitment(&program_id, &record_name).unwrap());
+        // Ensure the record opening verifies.
+        process.verify_record_opening::<CurrentAleo>(&opening).unwrap();
+
+        // Ensure the record opening round-trips through bytes.
+        let bytes = opening.to_bytes_le().unwrap();
+        let candidate = RecordOpeningProof::<CurrentNetwork>::read_le(&bytes[..]).unwrap();
+        assert!(candidate == opening);
+
+        // Ensure the record opening fails to verify for a different commitment.
+        let mut invalid = opening.clone();
+        invalid.commitment = Field::rand(rng);
+        assert!(process.verify_record_opening::<CurrentAleo>(&invalid).is_err());
+
+        // Ensure a record that does not match the record type is rejected.
+        let record_name = Identifier::from_str("committee_state").unwrap();
+        assert!(process.prove_record_opening::<CurrentAleo, _>(&program_id, &record_name, &record, rng).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use ledger_block::Output;
+
+impl<N: Network> Process<N> {
+    /// Re-evaluates the transitions of the given execution, and returns the outputs of each transition.
+    ///
+    /// This does not verify the proof of the execution. Instead, it re-runs each transition through
+    /// the evaluator, and ensures the outputs match those recorded in the execution, for indexers and
+    /// debuggers that trust the proof but need the semantic values of the execution.
+    ///
+    /// As a transition does not reveal its signer, caller, or the plaintexts of its private inputs,
+    /// only transitions of pure functions (see `Stack::ensure_pure_function`) with public or constant
+    /// inputs can be re-evaluated. Private outputs are returned, but cannot be checked against the execution.
+    #[inline]
+    pub fn reevaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        execution: &Execution<N>,
+    ) -> Result<IndexMap<N::TransitionID, Vec<Value<N>>>> {
+        let timer = timer!("Process::reevaluate");
+
+        // Re-evaluate each transition.
+        let mut outputs = IndexMap::with_capacity(execution.len());
+        for transition in execution.transitions() {
+            outputs.insert(*transition.id(), self.reevaluate_transition::<A>(transition)?);
+            lap!(timer, "Re-evaluate transition {}", transition.id());
+        }
+
+        finish!(timer);
+        Ok(outputs)
+    }
+
+    /// Re-evaluates the given transition, and returns its outputs.
+    /// See `Process::reevaluate` for the transitions that can be re-evaluated.
+    #[inline]
+    pub fn reevaluate_transition<A: circuit::Aleo<Network = N>>(
+        &self,
+        transition: &Transition<N>,
+    ) -> Result<Vec<Value<N>>> {
+        // Construct the locator.
+        let locator = Locator::new(*transition.program_id(), *transition.function_name());
+
+        // Recover the inputs of the transition.
+        let inputs = transition
+            .inputs()
+            .iter()
+            .enumerate()
+            .map(|(index, input)| match input {
+                Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext)) => {
+                    Ok(Value::Plaintext(plaintext.clone()))
+                }
+                Input::Constant(..) | Input::Public(..) => bail!("Input {index} of '{locator}' is missing its value"),
+                _ => bail!("Cannot re-evaluate '{locator}', as input {index} is not public or constant"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Evaluate the function.
+        // Note: This fails if the function is not pure, as its signer and caller are unknown.
+        let stack = self.get_stack(transition.program_id())?;
+        let outputs = stack
+            .query_function::<A>(*transition.function_name(), inputs.into_iter())
+            .map_err(|error| anyhow!("Cannot re-evaluate '{locator}': {error}"))?;
+
+        // Ensure the outputs match the transition.
+        ensure!(
+            outputs.len() == transition.outputs().len(),
+            "Expected {} outputs from '{locator}', found {}",
+            transition.outputs().len(),
+            outputs.len()
+        );
+        for (index, (output, value)) in transition.outputs().iter().zip_eq(&outputs).enumerate() {
+            match output {
+                Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => ensure!(
+                    Value::Plaintext(plaintext.clone()) == *value,
+                    "Output {index} of '{locator}' does not match the execution, expected '{plaintext}' but found '{value}'"
+                ),
+                // Note: The ciphertext of a private output cannot be recomputed without the transition view key.
+                Output::Private(..) => (),
+                _ => bail!("Output {index} of '{locator}' cannot be checked against the execution"),
+            }
+        }
+        Ok(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_reevaluate() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program reevaluate.aleo;
+
+function scale:
+    input r0 as u64.public;
+    input r1 as u64.constant;
+    mul r0 r1 into r2;
+    output r2 as u64.public;
+    output r2 as u64.private;
+
+function hide:
+    input r0 as u64.private;
+    output r0 as u64.public;",
+        )
+        .unwrap();
+
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+        // Sample a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Returns an (unproven) execution of the given function, by evaluating its authorization.
+        let sample_execution = |function_name: &str, inputs: &[&str], rng: &mut TestRng| {
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&private_key, program.id(), function_name, inputs.iter().copied(), rng)
+                .unwrap();
+            process.evaluate::<CurrentAleo>(authorization.clone()).unwrap();
+            Execution::from(authorization.transitions().into_values(), Default::default(), None).unwrap()
+        };
+
+        // Ensure the execution is re-evaluated.
+        let execution = sample_execution("scale", &["3u64", "5u64"], rng);
+        let outputs = process.reevaluate::<CurrentAleo>(&execution).unwrap();
+        let transition_id = execution.transitions().next().unwrap().id();
+        let expected = Value::from_str("15u64").unwrap();
+        assert_eq!(outputs.get(transition_id).unwrap(), &vec![expected.clone(), expected]);
+
+        // Ensure a transition with a private input cannot be re-evaluated.
+        let execution = sample_execution("hide", &["3u64"], rng);
+        assert!(process.reevaluate::<CurrentAleo>(&execution).is_err());
+
+        // Ensure a transition whose outputs do not match is rejected.
+        let execution = sample_execution("scale", &["3u64", "5u64"], rng);
+        let mut modified = Process::<CurrentNetwork>::load().unwrap();
+        modified.add_program(&Program::from_str(&program.to_string().replace("mul", "add")).unwrap()).unwrap();
+        assert!(modified.reevaluate::<CurrentAleo>(&execution).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A source of programs (such as a directory, a registry, or a node), which resolves
+/// the imports of a program that are missing from the process.
+pub trait ProgramResolver<N: Network> {
+    /// Returns the program with the given program ID, or `None` if the program cannot be found.
+    fn resolve(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>>;
+}
+
+impl<N: Network, F: Fn(&ProgramID<N>) -> Result<Option<Program<N>>>> ProgramResolver<N> for F {
+    /// Returns the program with the given program ID, or `None` if the program cannot be found.
+    fn resolve(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
+        self(program_id)
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Adds a new program to the process, after adding the imports that are missing from the process,
+    /// as fetched from the given resolver. Returns the IDs of the imports that were added, in the order they were added.
+    #[inline]
+    pub fn add_program_with_resolver(
+        &mut self,
+        program: &Program<N>,
+        resolver: &impl ProgramResolver<N>,
+    ) -> Result<Vec<ProgramID<N>>> {
+        // Add the missing imports.
+        let added = self.resolve_imports(program, resolver)?;
+        // Add the program.
+        self.add_program(program)?;
+        Ok(added)
+    }
+
+    /// Deploys the given program, after adding the imports that are missing from the process,
+    /// as fetched from the given resolver.
+    ///
+    /// Note: The resolved imports are added to the process as-is, so they must already be deployed.
+    #[inline]
+    pub fn deploy_with_resolver<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &mut self,
+        program: &Program<N>,
+        resolver: &impl ProgramResolver<N>,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        // Add the missing imports.
+        self.resolve_imports(program, resolver)?;
+        // Deploy the program.
+        self.deploy::<A, R>(program, rng)
+    }
+
+    /// Adds the imports of the given program that are missing from the process, as fetched from the given resolver.
+    /// Returns the IDs of the imports that were added, in the order they were added.
+    fn resolve_imports(
+        &mut self,
+        program: &Program<N>,
+        resolver: &impl ProgramResolver<N>,
+    ) -> Result<Vec<ProgramID<N>>> {
+        let timer = timer!("Process::resolve_imports");
+
+        // Initialize a list for the added imports.
+        let mut added = Vec::new();
+        // Initialize the chain of programs that are being resolved.
+        let mut chain = vec![*program.id()];
+        // Resolve the imports, depth-first, so that every import is added after its own imports.
+        self.resolve_imports_of(program, resolver, &mut chain, &mut added)?;
+        lap!(timer, "Resolve {} imports", added.len());
+
+        finish!(timer);
+        Ok(added)
+    }
+
+    /// Adds the imports of the given program that are missing from the process, as fetched from the given resolver.
+    /// The given chain contains the programs that are being resolved, from the root program to the given program.
+    fn resolve_imports_of(
+        &mut self,
+        program: &Program<N>,
+        resolver: &impl ProgramResolver<N>,
+        chain: &mut Vec<ProgramID<N>>,
+        added: &mut Vec<ProgramID<N>>,
+    ) -> Result<()> {
+        for import_id in program.imports().keys() {
+            // Skip imports that already exist in the process.
+            if self.contains_program(import_id) {
+                continue;
+            }
+            // Ensure the imports do not form a cycle.
+            if chain.contains(import_id) {
+                let cycle = chain.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(" -> ");
+                bail!("Cannot resolve the imports of '{}', as they form a cycle: {cycle} -> {import_id}", chain[0])
+            }
+            // Ensure the import chain does not exceed the maximum program depth.
+            ensure!(
+                chain.len() <= N::MAX_PROGRAM_DEPTH,
+                "Cannot resolve '{import_id}', as the imports of '{}' exceed the maximum depth of {}",
+                chain[0],
+                N::MAX_PROGRAM_DEPTH
+            );
+
+            // Fetch the import.
+            let Some(import) = resolver.resolve(import_id)? else {
+                bail!("Failed to resolve the import '{import_id}' of '{}'", program.id())
+            };
+            // Ensure the resolver returned the requested program.
+            ensure!(
+                import.id() == import_id,
+                "Resolver returned program '{}' for the import '{import_id}'",
+                import.id()
+            );
+
+            // Resolve the imports of the import.
+            chain.push(*import_id);
+            self.resolve_imports_of(&import, resolver, chain, added)?;
+            chain.pop();
+
+            // Add the import.
+            self.add_program(&import)?;
+            added.push(*import_id);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_add_program_with_resolver() {
+        // Initialize a chain of programs, where each program imports the previous one.
+        let programs = (0..3)
+            .map(|i| {
+                let import = match i {
+                    0 => String::new(),
+                    _ => format!("import resolve_{}.aleo;", i - 1),
+                };
+                Program::<CurrentNetwork>::from_str(&format!(
+                    r"{import}
+program resolve_{i}.aleo;
+
+function compute:
+    input r0 as u8.private;
+    output r0 as u8.private;"
+                ))
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        // Initialize a resolver over the programs.
+        let resolver = |program_id: &ProgramID<CurrentNetwork>| -> Result<Option<Program<CurrentNetwork>>> {
+            Ok(programs.iter().find(|program| program.id() == program_id).cloned())
+        };
+
+        // Ensure a program with missing imports cannot be added without a resolver.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        assert!(process.add_program(&programs[2]).is_err());
+
+        // Ensure the missing imports are resolved, in order.
+        let added = process.add_program_with_resolver(&programs[2], &resolver).unwrap();
+        assert_eq!(added, vec![*programs[0].id(), *programs[1].id()]);
+        assert!(process.contains_program(programs[2].id()));
+
+        // Ensure imports that already exist are not resolved again.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&programs[0]).unwrap();
+        let added = process.add_program_with_resolver(&programs[2], &resolver).unwrap();
+        assert_eq!(added, vec![*programs[1].id()]);
+
+        // Ensure a missing import is rejected.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        let empty = |_: &ProgramID<CurrentNetwork>| -> Result<Option<Program<CurrentNetwork>>> { Ok(None) };
+        assert!(process.add_program_with_resolver(&programs[2], &empty).is_err());
+        assert!(!process.contains_program(programs[2].id()));
+
+        // Ensure a resolver that returns the wrong program is rejected.
+        let wrong = |_: &ProgramID<CurrentNetwork>| -> Result<Option<Program<CurrentNetwork>>> {
+            Ok(Some(programs[2].clone()))
+        };
+        assert!(process.add_program_with_resolver(&programs[2], &wrong).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Process<N> {
+    /// Revokes the given authorization, and returns its digest (the execution ID).
+    /// A revoked authorization is rejected by `execute`, and any execution derived from it is rejected
+    /// by `ensure_execution_is_not_revoked`, which a node may apply before admitting the execution to its mempool.
+    ///
+    /// This allows a user to cancel a delegated proving job they no longer want broadcast.
+    /// Note: The revocations are local to the process, and are not part of consensus, so `verify_execution`
+    /// accepts an execution derived from a revoked authorization.
+    #[inline]
+    pub fn revoke_authorization(&self, authorization: &Authorization<N>) -> Result<Field<N>> {
+        // Compute the digest of the authorization.
+        let digest = authorization.to_execution_id()?;
+        // Revoke the digest.
+        self.revoke_authorization_digest(digest);
+        Ok(digest)
+    }
+
+    /// Revokes the authorization with the given digest (the execution ID).
+    #[inline]
+    pub fn revoke_authorization_digest(&self, digest: Field<N>) {
+        self.revoked_authorizations.write().insert(digest);
+    }
+
+    /// Reinstates the authorization with the given digest, returning `true` if it was revoked.
+    #[inline]
+    pub fn reinstate_authorization_digest(&self, digest: &Field<N>) -> bool {
+        self.revoked_authorizations.write().shift_remove(digest)
+    }
+
+    /// Returns `true` if the authorization with the given digest is revoked.
+    #[inline]
+    pub fn is_revoked(&self, digest: &Field<N>) -> bool {
+        self.revoked_authorizations.read().contains(digest)
+    }
+
+    /// Returns the digests of the revoked authorizations.
+    #[inline]
+    pub fn revoked_authorizations(&self) -> Vec<Field<N>> {
+        self.revoked_authorizations.read().iter().copied().collect()
+    }
+
+    /// Ensures the given authorization is not revoked.
+    #[inline]
+    pub(crate) fn ensure_authorization_is_not_revoked(&self, authorization: &Authorization<N>) -> Result<()> {
+        // If there are no revoked authorizations, return early.
+        if self.revoked_authorizations.read().is_empty() {
+            return Ok(());
+        }
+        // Compute the digest of the authorization.
+        let digest = authorization.to_execution_id()?;
+        // Ensure the digest is not revoked.
+        ensure!(!self.is_revoked(&digest), "Authorization '{digest}' is revoked");
+        Ok(())
+    }
+
+    /// Ensures the given execution is not derived from a revoked authorization.
+    /// Note: This is a local policy check (e.g. for a mempool), and is not part of `verify_execution`.
+    #[inline]
+    pub fn ensure_execution_is_not_revoked(&self, execution: &Execution<N>) -> Result<()> {
+        // If there are no revoked authorizations, return early.
+        if self.revoked_authorizations.read().is_empty() {
+            return Ok(());
+        }
+        // Compute the execution ID.
+        let execution_id = execution.to_execution_id()?;
+        // Ensure the execution ID is not revoked.
+        ensure!(!self.is_revoked(&execution_id), "Execution '{execution_id}' is derived from a revoked authorization");
+        Ok(())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the latest block height, if known, against which authorization expirations are checked.
+    #[inline]
+    pub fn latest_block_height(&self) -> Option<u32> {
+        *self.latest_block_height.read()
+    }
+
+    /// Sets the latest block height, against which authorization expirations are checked.
+    #[inline]
+    pub fn set_latest_block_height(&self, block_height: u32) {
+        *self.latest_block_height.write() = Some(block_height);
+    }
+
+    /// Ensures the given authorization has not expired, at the latest block height and the current time.
+    /// Note: The expiration is not part of the execution, so it is only enforced before proving.
+    #[inline]
+    pub(crate) fn ensure_authorization_is_not_expired(&self, authorization: &Authorization<N>) -> Result<()> {
+        // If the authorization does not expire, return early.
+        if authorization.expiration().is_none() {
+            return Ok(());
+        }
+        // Retrieve the current UNIX timestamp, in seconds.
+        let timestamp = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs();
+        // Ensure the authorization has not expired.
+        authorization.check_expiration(self.latest_block_height(), i64::try_from(timestamp)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_revoke_authorization() {
+        // Initialize the program used by the sample execution.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let process = crate::test_helpers::sample_process(&program);
+        // Retrieve a sample execution.
+        let execution = crate::test_helpers::sample_execution();
+        let execution_id = execution.to_execution_id().unwrap();
+
+        // Ensure the execution verifies.
+        assert!(process.verify_execution(&execution).is_ok());
+
+        // Revoke the authorization, and ensure the execution is rejected by the local check.
+        process.revoke_authorization_digest(execution_id);
+        assert!(process.is_revoked(&execution_id));
+        assert_eq!(process.revoked_authorizations(), vec![execution_id]);
+        assert!(process.ensure_execution_is_not_revoked(&execution).is_err());
+        // Ensure the execution still verifies, as revocations are not part of consensus.
+        assert!(process.verify_execution(&execution).is_ok());
+
+        // Reinstate the authorization, and ensure the execution passes the local check.
+        assert!(process.reinstate_authorization_digest(&execution_id));
+        assert!(!process.is_revoked(&execution_id));
+        assert!(process.ensure_execution_is_not_revoked(&execution).is_ok());
+    }
+
+    #[test]
+    fn test_execute_rejects_revoked_authorization() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Sample a fee authorization.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let authorization =
+            process.authorize_fee_public::<CurrentAleo, _>(&private_key, 1_000_000, 0, Field::rand(rng), rng).unwrap();
+
+        // Revoke the authorization, and ensure it is rejected, without consuming its requests.
+        let digest = process.revoke_authorization(&authorization).unwrap();
+        assert!(process.is_revoked(&digest));
+        assert!(process.execute::<CurrentAleo, _>(authorization.clone(), rng).is_err());
+        assert_eq!(authorization.len(), 1);
+    }
+
+    #[test]
+    fn test_execute_rejects_expired_authorization() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Sample a fee authorization, which expires at block 10.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let authorization =
+            process.authorize_fee_public::<CurrentAleo, _>(&private_key, 1_000_000, 0, Field::rand(rng), rng).unwrap();
+        authorization.set_expiration(&private_key, Expiration::BlockHeight(10), rng).unwrap();
+
+        // Ensure the authorization is rejected while the block height is unknown.
+        assert_eq!(process.latest_block_height(), None);
+        assert!(process.ensure_authorization_is_not_expired(&authorization).is_err());
+        // Ensure the authorization is accepted until block 10.
+        process.set_latest_block_height(10);
+        assert!(process.ensure_authorization_is_not_expired(&authorization).is_ok());
+        // Ensure the authorization is rejected after block 10, without consuming its requests.
+        process.set_latest_block_height(11);
+        assert!(process.execute::<CurrentAleo, _>(authorization.clone(), rng).is_err());
+        assert_eq!(authorization.len(), 1);
+    }
+}
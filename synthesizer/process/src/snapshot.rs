@@ -0,0 +1,265 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The stack of a single program in a process snapshot.
+#[derive(Clone, PartialEq, Eq)]
+struct SnapshotEntry<N: Network> {
+    /// The program.
+    program: Program<N>,
+    /// The snapshot hash of the stack, which commits to the program, external stacks, and register types.
+    snapshot_hash: Field<N>,
+    /// The cached verifying keys of the program, if they were included in the snapshot.
+    verifying_keys: IndexMap<Identifier<N>, VerifyingKey<N>>,
+}
+
+/// A snapshot of the programs in a process, which allows a node to persist and restore
+/// its process across restarts, instead of re-adding every program from storage.
+///
+/// The stacks are re-initialized on restore, and must reproduce the snapshot hash
+/// (and thus the register types) that was recorded for each program.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProcessSnapshot<N: Network> {
+    /// The stacks of the process, in the order they were added.
+    entries: Vec<SnapshotEntry<N>>,
+}
+
+impl<N: Network> ProcessSnapshot<N> {
+    /// Returns the program IDs in the snapshot, in the order they were added.
+    pub fn program_ids(&self) -> impl '_ + Iterator<Item = &ProgramID<N>> {
+        self.entries.iter().map(|entry| entry.program.id())
+    }
+
+    /// Returns the number of programs in the snapshot.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the snapshot contains no programs.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns a snapshot of the programs in the process, optionally including their cached verifying keys.
+    /// Note: Proving keys are never included, as they are managed by the proving key cache.
+    #[inline]
+    pub fn snapshot(&self, include_verifying_keys: bool) -> Result<ProcessSnapshot<N>> {
+        let entries = self
+            .stacks
+            .values()
+            .map(|stack| {
+                // Retrieve the cached verifying keys, if requested.
+                let verifying_keys = match include_verifying_keys {
+                    true => stack
+                        .program()
+                        .functions()
+                        .keys()
+                        .filter(|function_name| stack.contains_verifying_key(function_name))
+                        .map(|function_name| Ok((*function_name, stack.get_verifying_key(function_name)?)))
+                        .collect::<Result<IndexMap<_, _>>>()?,
+                    false => IndexMap::new(),
+                };
+                Ok(SnapshotEntry {
+                    program: stack.program().clone(),
+                    snapshot_hash: stack.snapshot_hash()?,
+                    verifying_keys,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ProcessSnapshot { entries })
+    }
+
+    /// Initializes a new process, and restores the given snapshot into it.
+    #[inline]
+    pub fn from_snapshot(snapshot: &ProcessSnapshot<N>) -> Result<Self> {
+        let mut process = Self::load()?;
+        process.restore_snapshot(snapshot)?;
+        Ok(process)
+    }
+
+    /// Restores the programs and verifying keys in the given snapshot into the process.
+    ///
+    /// Programs that already exist in the process (such as 'credits.aleo') are not re-added,
+    /// but must match the snapshot. As the snapshot is loaded from storage, the program limit does not apply.
+    #[inline]
+    pub fn restore_snapshot(&mut self, snapshot: &ProcessSnapshot<N>) -> Result<()> {
+        let timer = timer!("Process::restore_snapshot");
+
+        for entry in &snapshot.entries {
+            // Retrieve the program ID.
+            let program_id = entry.program.id();
+            // Initialize the stack, unless the program already exists in the process.
+            let stack = match self.contains_program(program_id) {
+                true => None,
+                false => Some(Stack::new(self, &entry.program)?),
+            };
+            // Ensure the stack matches the snapshot.
+            let snapshot_hash = match &stack {
+                Some(stack) => stack.snapshot_hash()?,
+                None => self.get_stack(program_id)?.snapshot_hash()?,
+            };
+            ensure!(snapshot_hash == entry.snapshot_hash, "Program '{program_id}' does not match its snapshot hash");
+            // Add the stack to the process.
+            if let Some(stack) = stack {
+                self.add_stack(stack);
+            }
+            // Insert the verifying keys.
+            for (function_name, verifying_key) in &entry.verifying_keys {
+                self.insert_verifying_key(program_id, function_name, verifying_key.clone())?;
+            }
+            lap!(timer, "Restore '{program_id}'");
+        }
+
+        finish!(timer);
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for SnapshotEntry<N> {
+    /// Reads the snapshot entry from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the program.
+        let program = Program::read_le(&mut reader)?;
+        // Read the snapshot hash.
+        let snapshot_hash = Field::read_le(&mut reader)?;
+        // Read the verifying keys.
+        let num_verifying_keys = u16::read_le(&mut reader)?;
+        let mut verifying_keys = IndexMap::with_capacity(num_verifying_keys as usize);
+        for _ in 0..num_verifying_keys {
+            let function_name = Identifier::read_le(&mut reader)?;
+            let verifying_key = VerifyingKey::read_le(&mut reader)?;
+            if verifying_keys.insert(function_name, verifying_key).is_some() {
+                return Err(error(format!("Duplicate verifying key for '{function_name}' in the snapshot")));
+            }
+        }
+        Ok(Self { program, snapshot_hash, verifying_keys })
+    }
+}
+
+impl<N: Network> ToBytes for SnapshotEntry<N> {
+    /// Writes the snapshot entry to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the program.
+        self.program.write_le(&mut writer)?;
+        // Write the snapshot hash.
+        self.snapshot_hash.write_le(&mut writer)?;
+        // Write the verifying keys.
+        u16::try_from(self.verifying_keys.len()).map_err(error)?.write_le(&mut writer)?;
+        for (function_name, verifying_key) in &self.verifying_keys {
+            function_name.write_le(&mut writer)?;
+            verifying_key.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for ProcessSnapshot<N> {
+    /// Reads the process snapshot from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid process snapshot version"));
+        }
+        // Read the entries.
+        let num_entries = u32::read_le(&mut reader)?;
+        let entries = (0..num_entries).map(|_| SnapshotEntry::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the process snapshot.
+        Ok(Self { entries })
+    }
+}
+
+impl<N: Network> ToBytes for ProcessSnapshot<N> {
+    /// Writes the process snapshot to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the entries.
+        u32::try_from(self.entries.len()).map_err(error)?.write_le(&mut writer)?;
+        self.entries.iter().try_for_each(|entry| entry.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_process_snapshot() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program, and a program that imports it.
+        let program_a = Program::<CurrentNetwork>::from_str(
+            r"
+program snapshot_a.aleo;
+
+function compute:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+        )?;
+        let program_b = Program::<CurrentNetwork>::from_str(
+            r"
+import snapshot_a.aleo;
+
+program snapshot_b.aleo;
+
+function compute:
+    input r0 as u8.private;
+    call snapshot_a.aleo/compute r0 into r1;
+    output r1 as u8.private;",
+        )?;
+
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load()?;
+        process.add_program(&program_a)?;
+        process.add_program(&program_b)?;
+        // Synthesize the keys of the first program.
+        let function_name = Identifier::from_str("compute")?;
+        process.synthesize_key::<CurrentAleo, _>(program_a.id(), &function_name, rng)?;
+
+        // Ensure the snapshot round-trips through bytes.
+        let snapshot = process.snapshot(true)?;
+        assert_eq!(snapshot.len(), 3);
+        assert!(snapshot == ProcessSnapshot::from_bytes_le(&snapshot.to_bytes_le()?)?);
+
+        // Ensure the restored process matches the original process.
+        let restored = Process::from_snapshot(&ProcessSnapshot::from_bytes_le(&snapshot.to_bytes_le()?)?)?;
+        for program_id in snapshot.program_ids() {
+            let expected = process.get_stack(program_id)?.snapshot_hash()?;
+            assert_eq!(restored.get_stack(program_id)?.snapshot_hash()?, expected);
+        }
+        assert!(
+            restored.get_verifying_key(program_a.id(), function_name)?
+                == process.get_verifying_key(program_a.id(), function_name)?
+        );
+        assert!(!restored.get_stack(program_b.id())?.contains_verifying_key(&function_name));
+
+        // Ensure the verifying keys are omitted, if not requested.
+        let restored = Process::from_snapshot(&process.snapshot(false)?)?;
+        assert!(!restored.get_stack(program_a.id())?.contains_verifying_key(&function_name));
+
+        // Ensure a snapshot with a mismatching snapshot hash is rejected.
+        let mut snapshot = process.snapshot(false)?;
+        snapshot.entries[2].snapshot_hash = Field::zero();
+        assert!(Process::from_snapshot(&snapshot).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,378 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::{PublicOrPrivate, Register};
+
+use serde_json::{json, Value as JsonValue};
+
+/// The identifier of the JSON format for exported program ABIs.
+pub const ABI_JSON_FORMAT: &str = "aleo-abi-json-v1";
+
+impl<N: Network> Stack<N> {
+    /// Returns the ABI of the program, as a JSON description of its interface:
+    ///
+    /// ```text
+    /// {
+    ///   "format": "aleo-abi-json-v1",
+    ///   "program": "<program ID>",
+    ///   "imports": ["<program ID>", ...],
+    ///   "structs": [{
+    ///     "name": "<name>",
+    ///     "members": [{ "name": "<name>", "type": "<plaintext type>" }, ...],
+    ///     "defaults": [{ "name": "<name>", "value": "<literal>" }, ...]
+    ///   }, ...],
+    ///   "records": [{
+    ///     "name": "<name>",
+    ///     "owner": "public" | "private",
+    ///     "entries": [{ "name": "<name>", "type": "<plaintext type>", "visibility": "constant" | "public" | "private" }, ...],
+    ///     "defaults": [{ "name": "<name>", "value": "<literal>" }, ...]
+    ///   }, ...],
+    ///   "mappings": [{ "name": "<name>", "key": "<plaintext type>", "value": "<plaintext type>" }, ...],
+    ///   "closures": [{ "name": "<name>", "inputs": [<register>, ...], "outputs": [<register>, ...] }, ...],
+    ///   "functions": [{
+    ///     "name": "<name>",
+    ///     "inputs": [<register>, ...],
+    ///     "outputs": [<register>, ...],
+    ///     "finalize": null | { "inputs": [<register>, ...] }
+    ///   }, ...]
+    /// }
+    /// ```
+    ///
+    /// Each `<register>` is `{ "register": "<register>", "name": "<name>" | null, "type": <type> }`,
+    /// where `name` is the developer-facing name of the register, if one was attached, and each `<type>` is
+    /// `{ "kind": "plaintext" | "record" | "external_record" | "future", "type": "<type>", "visibility": ... }`.
+    /// The `visibility` of a function input or output is `constant`, `public`, `private`, or `record`,
+    /// and is `null` for closure and finalize registers. Outputs whose operand is not a register have a `null` register.
+    pub fn to_abi(&self) -> Result<JsonValue> {
+        let program = &self.program;
+
+        // Describe the structs.
+        let structs = program
+            .structs()
+            .values()
+            .map(|struct_| {
+                let members = struct_
+                    .members()
+                    .iter()
+                    .map(|(name, plaintext_type)| json!({ "name": name.to_string(), "type": plaintext_type.to_string() }))
+                    .collect::<Vec<_>>();
+                let defaults = struct_
+                    .defaults()
+                    .iter()
+                    .map(|(name, literal)| json!({ "name": name.to_string(), "value": literal.to_string() }))
+                    .collect::<Vec<_>>();
+                json!({ "name": struct_.name().to_string(), "members": members, "defaults": defaults })
+            })
+            .collect::<Vec<_>>();
+
+        // Describe the records.
+        let records = program
+            .records()
+            .values()
+            .map(|record_type| {
+                let owner = match record_type.owner() {
+                    PublicOrPrivate::Public => "public",
+                    PublicOrPrivate::Private => "private",
+                };
+                let entries = record_type
+                    .entries()
+                    .iter()
+                    .map(|(name, entry_type)| {
+                        let (plaintext_type, visibility) = match entry_type {
+                            EntryType::Constant(plaintext_type) => (plaintext_type, "constant"),
+                            EntryType::Public(plaintext_type) => (plaintext_type, "public"),
+                            EntryType::Private(plaintext_type) => (plaintext_type, "private"),
+                        };
+                        json!({ "name": name.to_string(), "type": plaintext_type.to_string(), "visibility": visibility })
+                    })
+                    .collect::<Vec<_>>();
+                let defaults = record_type
+                    .defaults()
+                    .iter()
+                    .map(|(name, literal)| json!({ "name": name.to_string(), "value": literal.to_string() }))
+                    .collect::<Vec<_>>();
+                json!({
+                    "name": record_type.name().to_string(),
+                    "owner": owner,
+                    "entries": entries,
+                    "defaults": defaults,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Describe the mappings.
+        let mappings = program
+            .mappings()
+            .values()
+            .map(|mapping| {
+                json!({
+                    "name": mapping.name().to_string(),
+                    "key": mapping.key().plaintext_type().to_string(),
+                    "value": mapping.value().plaintext_type().to_string(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Describe the closures.
+        let mut closures = Vec::with_capacity(program.closures().len());
+        for closure in program.closures().values() {
+            let register_types = self.get_register_types(closure.name())?;
+            let inputs = closure
+                .inputs()
+                .iter()
+                .map(|input| {
+                    describe_register(
+                        register_types,
+                        Some(input.register()),
+                        register_type_to_json(input.register_type()),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let outputs = closure
+                .outputs()
+                .iter()
+                .map(|output| {
+                    describe_register(
+                        register_types,
+                        operand_register(output.operand()),
+                        register_type_to_json(output.register_type()),
+                    )
+                })
+                .collect::<Vec<_>>();
+            closures.push(json!({ "name": closure.name().to_string(), "inputs": inputs, "outputs": outputs }));
+        }
+
+        // Describe the functions.
+        let mut functions = Vec::with_capacity(program.functions().len());
+        for function in program.functions().values() {
+            let register_types = self.get_register_types(function.name())?;
+            let inputs = function
+                .inputs()
+                .iter()
+                .map(|input| {
+                    describe_register(register_types, Some(input.register()), value_type_to_json(input.value_type()))
+                })
+                .collect::<Vec<_>>();
+            let outputs = function
+                .outputs()
+                .iter()
+                .map(|output| {
+                    describe_register(
+                        register_types,
+                        operand_register(output.operand()),
+                        value_type_to_json(output.value_type()),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let finalize = match function.finalize_logic() {
+                Some(finalize) => {
+                    let finalize_inputs = finalize
+                        .inputs()
+                        .iter()
+                        .map(|input| {
+                            json!({
+                                "register": input.register().to_string(),
+                                "name": JsonValue::Null,
+                                "type": finalize_type_to_json(input.finalize_type()),
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    json!({ "inputs": finalize_inputs })
+                }
+                None => JsonValue::Null,
+            };
+            functions.push(json!({
+                "name": function.name().to_string(),
+                "inputs": inputs,
+                "outputs": outputs,
+                "finalize": finalize,
+            }));
+        }
+
+        Ok(json!({
+            "format": ABI_JSON_FORMAT,
+            "program": program.id().to_string(),
+            "imports": program.imports().keys().map(|import| import.to_string()).collect::<Vec<_>>(),
+            "structs": structs,
+            "records": records,
+            "mappings": mappings,
+            "closures": closures,
+            "functions": functions,
+        }))
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the ABI of the given program, as a JSON string.
+    /// See [`Stack::to_abi`] for the format.
+    pub fn export_abi(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<String> {
+        Ok(self.get_stack(program_id)?.to_abi()?.to_string())
+    }
+}
+
+/// Returns the register of the given operand, if it is a register.
+fn operand_register<N: Network>(operand: &Operand<N>) -> Option<&Register<N>> {
+    match operand {
+        Operand::Register(register) => Some(register),
+        _ => None,
+    }
+}
+
+/// Returns the JSON description of the given register, with its developer-facing name and type.
+fn describe_register<N: Network>(
+    register_types: &RegisterTypes<N>,
+    register: Option<&Register<N>>,
+    type_: JsonValue,
+) -> JsonValue {
+    json!({
+        "register": register.map(|register| register.to_string()),
+        "name": register.and_then(|register| register_types.get_alias(register)).map(|alias| alias.to_string()),
+        "type": type_,
+    })
+}
+
+/// Returns the JSON description of the given value type.
+fn value_type_to_json<N: Network>(value_type: &ValueType<N>) -> JsonValue {
+    let (kind, type_, visibility) = match value_type {
+        ValueType::Constant(plaintext_type) => ("plaintext", plaintext_type.to_string(), "constant"),
+        ValueType::Public(plaintext_type) => ("plaintext", plaintext_type.to_string(), "public"),
+        ValueType::Private(plaintext_type) => ("plaintext", plaintext_type.to_string(), "private"),
+        ValueType::Record(identifier) => ("record", identifier.to_string(), "record"),
+        ValueType::ExternalRecord(locator) => ("external_record", locator.to_string(), "record"),
+        ValueType::Future(locator) => ("future", locator.to_string(), "future"),
+    };
+    json!({ "kind": kind, "type": type_, "visibility": visibility })
+}
+
+/// Returns the JSON description of the given register type.
+fn register_type_to_json<N: Network>(register_type: &RegisterType<N>) -> JsonValue {
+    let (kind, type_) = match register_type {
+        RegisterType::Plaintext(plaintext_type) => ("plaintext", plaintext_type.to_string()),
+        RegisterType::Record(identifier) => ("record", identifier.to_string()),
+        RegisterType::ExternalRecord(locator) => ("external_record", locator.to_string()),
+        RegisterType::Future(locator) => ("future", locator.to_string()),
+    };
+    json!({ "kind": kind, "type": type_, "visibility": JsonValue::Null })
+}
+
+/// Returns the JSON description of the given finalize type.
+fn finalize_type_to_json<N: Network>(finalize_type: &FinalizeType<N>) -> JsonValue {
+    let (kind, type_) = match finalize_type {
+        FinalizeType::Plaintext(plaintext_type) => ("plaintext", plaintext_type.to_string()),
+        FinalizeType::Future(locator) => ("future", locator.to_string()),
+    };
+    json!({ "kind": kind, "type": type_, "visibility": JsonValue::Null })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_to_abi() -> Result<()> {
+        // Initialize a program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program abi.aleo;
+
+struct point:
+    x as u32;
+    y as u32;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+    tag as field.public;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+closure double:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.private;
+    call double r1 into r2;
+    cast r0 r2 0field into r3 as token.record;
+    output r3 as token.record;
+    output r0 as address.public;
+    async mint r0 r2 into r4;
+    output r4 as abi.aleo/mint.future;
+
+finalize mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use balances[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into balances[r0];",
+        )?;
+
+        // Initialize the stack, and name an input register.
+        let process = Process::<CurrentNetwork>::load()?;
+        let mut stack = Stack::new(&process, &program)?;
+        let function_name = Identifier::from_str("mint")?;
+        stack.set_register_aliases(&IndexMap::from([(
+            function_name,
+            IndexMap::from([(1, Identifier::from_str("amount")?)]),
+        )]))?;
+
+        // Ensure the ABI describes the program.
+        let abi = stack.to_abi()?;
+        assert_eq!(abi["format"], ABI_JSON_FORMAT);
+        assert_eq!(abi["program"], "abi.aleo");
+        assert_eq!(abi["structs"][0]["members"][1], json!({ "name": "y", "type": "u32" }));
+        assert_eq!(abi["structs"][0]["defaults"], json!([]));
+        assert_eq!(abi["records"][0]["owner"], "private");
+        assert_eq!(abi["records"][0]["entries"][1], json!({ "name": "tag", "type": "field", "visibility": "public" }));
+        assert_eq!(abi["mappings"][0], json!({ "name": "balances", "key": "address", "value": "u64" }));
+        assert_eq!(
+            abi["closures"][0]["outputs"][0]["type"],
+            json!({ "kind": "plaintext", "type": "u64", "visibility": null })
+        );
+
+        // Ensure the function inputs, outputs, and finalize inputs are described.
+        let function = &abi["functions"][0];
+        assert_eq!(
+            function["inputs"][1],
+            json!({
+                "register": "r1",
+                "name": "amount",
+                "type": { "kind": "plaintext", "type": "u64", "visibility": "private" },
+            })
+        );
+        assert_eq!(
+            function["outputs"][0]["type"],
+            json!({ "kind": "record", "type": "token", "visibility": "record" })
+        );
+        assert_eq!(function["outputs"][2]["type"]["kind"], "future");
+        assert_eq!(
+            function["finalize"]["inputs"][1]["type"],
+            json!({ "kind": "plaintext", "type": "u64", "visibility": null })
+        );
+
+        // Ensure the process exports the same ABI.
+        let mut process = process;
+        process.add_program(&program)?;
+        let exported = process.export_abi(program.id())?;
+        assert_eq!(serde_json::from_str::<JsonValue>(&exported)?["functions"][0]["name"], "mint");
+        Ok(())
+    }
+}
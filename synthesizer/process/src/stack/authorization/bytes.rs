@@ -20,7 +20,7 @@ impl<N: Network> FromBytes for Authorization<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid authorization version"));
         }
 
@@ -43,8 +43,18 @@ impl<N: Network> FromBytes for Authorization<N> {
         let transitions =
             (0..num_transitions).map(|_| Transition::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
 
+        // Read the expiration, which is only present in version 2.
+        let expiration = match version {
+            1 => None,
+            _ => Some((Expiration::read_le(&mut reader)?, Signature::read_le(&mut reader)?)),
+        };
+
+        // Initialize the authorization.
+        let authorization = Self::try_from((requests, transitions)).map_err(error)?;
+        // Set the expiration.
+        *authorization.expiration.write() = expiration;
         // Return the new `Authorization` instance.
-        Self::try_from((requests, transitions)).map_err(error)
+        Ok(authorization)
     }
 }
 
@@ -54,9 +64,14 @@ impl<N: Network> ToBytes for Authorization<N> {
         // Acquire the read locks.
         let requests = self.requests.read();
         let transitions = self.transitions.read();
+        let expiration = self.expiration.read();
 
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Note: Authorizations without an expiration are written in the original format, for compatibility.
+        match expiration.is_some() {
+            true => 2u8.write_le(&mut writer)?,
+            false => 1u8.write_le(&mut writer)?,
+        }
         // Write the number of requests.
         u8::try_from(requests.len()).map_err(error)?.write_le(&mut writer)?;
         // Write the requests.
@@ -64,7 +79,13 @@ impl<N: Network> ToBytes for Authorization<N> {
         // Write the number of transitions.
         u8::try_from(transitions.len()).map_err(error)?.write_le(&mut writer)?;
         // Write the transitions.
-        transitions.values().try_for_each(|transition| transition.write_le(&mut writer))
+        transitions.values().try_for_each(|transition| transition.write_le(&mut writer))?;
+        // Write the expiration, if one is set.
+        if let Some((expiration, signature)) = &*expiration {
+            expiration.write_le(&mut writer)?;
+            signature.write_le(&mut writer)?;
+        }
+        Ok(())
     }
 }
 
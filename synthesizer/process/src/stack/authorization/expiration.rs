@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::account::{Address, PrivateKey};
+
+/// The point after which an authorization expires, and may no longer be executed.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Expiration {
+    /// The authorization expires after the given block height.
+    BlockHeight(u32),
+    /// The authorization expires after the given UNIX timestamp, in seconds.
+    Timestamp(i64),
+}
+
+impl Expiration {
+    /// Returns the expiration as field elements, for the signed message.
+    fn to_fields<N: Network>(self) -> [Field<N>; 2] {
+        match self {
+            Self::BlockHeight(height) => [Field::from_u8(0), Field::from_u32(height)],
+            Self::Timestamp(timestamp) => [Field::from_u8(1), Field::from_u64(timestamp as u64)],
+        }
+    }
+
+    /// Ensures the expiration has not passed, at the given block height (if known) and UNIX timestamp.
+    pub fn check(&self, block_height: Option<u32>, timestamp: i64) -> Result<()> {
+        match self {
+            Self::BlockHeight(expiration_height) => {
+                let Some(block_height) = block_height else {
+                    bail!("The authorization expires at block {expiration_height}, but the block height is unknown")
+                };
+                ensure!(
+                    block_height <= *expiration_height,
+                    "The authorization expired at block {expiration_height} (current block is {block_height})"
+                );
+            }
+            Self::Timestamp(expiration_timestamp) => ensure!(
+                timestamp <= *expiration_timestamp,
+                "The authorization expired at timestamp {expiration_timestamp} (current timestamp is {timestamp})"
+            ),
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Authorization<N> {
+    /// Sets the expiration of the authorization, signed by the signer of the first request.
+    ///
+    /// The signature commits to the expiration and to the transition commitments of the requests,
+    /// so that the expiration cannot be changed or moved to another authorization.
+    ///
+    /// Note: The expiration is not part of the request signatures, so a holder of the authorization can strip it,
+    /// and it is not enforced by verifiers. It only stops an honest prover from executing a stale authorization.
+    /// Note: The expiration must be set after every request is added to the authorization.
+    pub fn set_expiration<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        expiration: Expiration,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Ensure the private key belongs to the signer of the first request.
+        let signer = *self.peek_next()?.signer();
+        ensure!(Address::try_from(private_key)? == signer, "The expiration must be signed by the signer '{signer}'");
+        // Sign the expiration.
+        let signature = Signature::sign(private_key, &self.to_expiration_message(expiration), rng)?;
+        // Set the expiration.
+        *self.expiration.write() = Some((expiration, signature));
+        Ok(())
+    }
+
+    /// Returns the expiration of the authorization, if one is set.
+    pub fn expiration(&self) -> Option<Expiration> {
+        self.expiration.read().map(|(expiration, _)| expiration)
+    }
+
+    /// Ensures the expiration of the authorization, if one is set, is validly signed
+    /// and has not passed, at the given block height (if known) and UNIX timestamp.
+    ///
+    /// Note: This must be checked before the requests of the authorization are consumed.
+    pub fn check_expiration(&self, block_height: Option<u32>, timestamp: i64) -> Result<()> {
+        // Retrieve the expiration, if one is set.
+        let Some((expiration, signature)) = *self.expiration.read() else {
+            return Ok(());
+        };
+        // Ensure the expiration is signed by the signer of the first request.
+        let signer = *self.peek_next()?.signer();
+        ensure!(
+            signature.verify(&signer, &self.to_expiration_message(expiration)),
+            "The expiration of the authorization is not signed by '{signer}'"
+        );
+        // Ensure the expiration has not passed.
+        expiration.check(block_height, timestamp)
+    }
+
+    /// Returns the message that is signed for the given expiration.
+    fn to_expiration_message(&self, expiration: Expiration) -> Vec<Field<N>> {
+        let requests = self.requests.read();
+        let mut message = Vec::with_capacity(2 + requests.len());
+        message.extend(expiration.to_fields());
+        message.extend(requests.iter().map(|request| *request.tcm()));
+        message
+    }
+}
+
+impl FromBytes for Expiration {
+    /// Reads the expiration from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::BlockHeight(u32::read_le(&mut reader)?)),
+            1 => Ok(Self::Timestamp(i64::read_le(&mut reader)?)),
+            _ => Err(error("Invalid expiration variant")),
+        }
+    }
+}
+
+impl ToBytes for Expiration {
+    /// Writes the expiration to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::BlockHeight(height) => {
+                0u8.write_le(&mut writer)?;
+                height.write_le(&mut writer)
+            }
+            Self::Timestamp(timestamp) => {
+                1u8.write_le(&mut writer)?;
+                timestamp.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl Serialize for Expiration {
+    /// Serializes the expiration into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Expiration {
+    /// Deserializes the expiration from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "expiration"),
+        }
+    }
+}
+
+impl FromStr for Expiration {
+    type Err = Error;
+
+    /// Parses the expiration from a string, as `block_height:{height}` or `timestamp:{timestamp}`.
+    fn from_str(expiration: &str) -> Result<Self, Self::Err> {
+        match expiration.split_once(':') {
+            Some(("block_height", height)) => Ok(Self::BlockHeight(height.parse()?)),
+            Some(("timestamp", timestamp)) => Ok(Self::Timestamp(timestamp.parse()?)),
+            _ => bail!("Invalid expiration '{expiration}'"),
+        }
+    }
+}
+
+impl Debug for Expiration {
+    /// Prints the expiration as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Expiration {
+    /// Displays the expiration as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::BlockHeight(height) => write!(f, "block_height:{height}"),
+            Self::Timestamp(timestamp) => write!(f, "timestamp:{timestamp}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns a fee authorization, signed by the given private key.
+    fn sample_authorization(
+        process: &Process<CurrentNetwork>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Authorization<CurrentNetwork> {
+        process.authorize_fee_public::<CurrentAleo, _>(private_key, 1_000_000, 0, Field::rand(rng), rng).unwrap()
+    }
+
+    #[test]
+    fn test_expiration() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load()?;
+        // Sample the signer, and an authorization without an expiration.
+        let private_key = PrivateKey::new(rng)?;
+        let authorization = sample_authorization(&process, &private_key, rng);
+        assert_eq!(authorization.expiration(), None);
+        authorization.check_expiration(None, 0)?;
+
+        // Ensure only the signer may set the expiration.
+        assert!(authorization.set_expiration(&PrivateKey::new(rng)?, Expiration::BlockHeight(10), rng).is_err());
+        assert_eq!(authorization.expiration(), None);
+
+        // Ensure a block height expiration is enforced.
+        authorization.set_expiration(&private_key, Expiration::BlockHeight(10), rng)?;
+        assert_eq!(authorization.expiration(), Some(Expiration::BlockHeight(10)));
+        authorization.check_expiration(Some(10), 0)?;
+        assert!(authorization.check_expiration(Some(11), 0).is_err());
+        assert!(authorization.check_expiration(None, 0).is_err());
+
+        // Ensure a timestamp expiration is enforced.
+        authorization.set_expiration(&private_key, Expiration::Timestamp(1_000), rng)?;
+        authorization.check_expiration(None, 1_000)?;
+        assert!(authorization.check_expiration(None, 1_001).is_err());
+        assert_eq!(Expiration::from_str(&Expiration::Timestamp(1_000).to_string())?, Expiration::Timestamp(1_000));
+
+        // Ensure the expiration round-trips through bytes and JSON.
+        let bytes = authorization.to_bytes_le()?;
+        assert_eq!(authorization, Authorization::read_le(&bytes[..])?);
+        assert_eq!(authorization, Authorization::from_str(&authorization.to_string())?);
+
+        // Ensure the expiration cannot be moved to another authorization.
+        let other = sample_authorization(&process, &private_key, rng);
+        *other.expiration.write() = *authorization.expiration.read();
+        assert!(other.check_expiration(None, 0).is_err());
+        Ok(())
+    }
+}
@@ -16,8 +16,16 @@ mod bytes;
 mod serialize;
 mod string;
 
-use console::{network::prelude::*, program::Request, types::Field};
-use ledger_block::{Transaction, Transition};
+mod expiration;
+pub use expiration::*;
+
+mod multi_party;
+
+mod partial;
+pub use partial::*;
+
+use console::{account::Signature, network::prelude::*, program::Request, types::Field};
+use ledger_block::{Execution, Transaction, Transition};
 
 use indexmap::IndexMap;
 use parking_lot::RwLock;
@@ -29,12 +37,18 @@ pub struct Authorization<N: Network> {
     requests: Arc<RwLock<VecDeque<Request<N>>>>,
     /// The authorized transitions.
     transitions: Arc<RwLock<IndexMap<N::TransitionID, Transition<N>>>>,
+    /// The expiration of the authorization, and its signature, if one is set.
+    expiration: Arc<RwLock<Option<(Expiration, Signature<N>)>>>,
 }
 
 impl<N: Network> Authorization<N> {
     /// Initialize a new `Authorization` instance, with the given request.
     pub fn new(request: Request<N>) -> Self {
-        Self { requests: Arc::new(RwLock::new(VecDeque::from(vec![request]))), transitions: Default::default() }
+        Self {
+            requests: Arc::new(RwLock::new(VecDeque::from(vec![request]))),
+            transitions: Default::default(),
+            expiration: Default::default(),
+        }
     }
 
     /// Returns a new and independent replica of the authorization.
@@ -42,10 +56,65 @@ impl<N: Network> Authorization<N> {
         Self {
             requests: Arc::new(RwLock::new(self.requests.read().clone())),
             transitions: Arc::new(RwLock::new(self.transitions.read().clone())),
+            expiration: Arc::new(RwLock::new(*self.expiration.read())),
         }
     }
 }
 
+impl<N: Network> Authorization<N> {
+    /// Initializes a new `Authorization` instance, bundling the top-level calls of the given authorizations.
+    ///
+    /// The bundled calls execute in the given order, atomically, as a single execution.
+    /// If any call fails to execute or finalize, the whole execution fails, and a single fee covers all of them.
+    ///
+    /// Note: This method only bundles calls from a single signer, but this is not a consensus rule.
+    /// An execution that bundles calls from different signers is valid, as each top-level call is signed
+    /// by its own signer, and only runs calls that its signer authorized.
+    pub fn bundle(authorizations: impl IntoIterator<Item = Authorization<N>>) -> Result<Self> {
+        // Initialize a list for the requests and transitions.
+        let mut requests: Vec<Request<N>> = Vec::new();
+        let mut transitions = Vec::new();
+        // Append the requests and transitions of each authorization.
+        for authorization in authorizations {
+            // Ensure the authorization is not empty.
+            ensure!(!authorization.is_empty(), "Cannot bundle an empty authorization");
+            // Ensure the authorization is not for a fee.
+            ensure!(
+                !(authorization.is_fee_private() || authorization.is_fee_public()),
+                "Cannot bundle a fee authorization"
+            );
+            // Ensure the authorization does not expire, as its expiration does not sign the bundle.
+            ensure!(authorization.expiration().is_none(), "Cannot bundle an authorization with an expiration");
+            // Ensure the authorization is signed by the same signer as the rest of the bundle.
+            // Note: The signer is hidden in an execution, so verification does not enforce this
+            // (see `Process::verify_execution`), and a bundle with mixed signers remains valid.
+            let signer = *authorization.peek_next()?.signer();
+            if let Some(first) = requests.first() {
+                ensure!(*first.signer() == signer, "Cannot bundle authorizations from different signers");
+            }
+            requests.extend(authorization.to_vec_deque());
+            transitions.extend(authorization.transitions().into_values());
+        }
+        // Ensure there is at least one request.
+        ensure!(!requests.is_empty(), "Cannot bundle an empty list of authorizations");
+        // Ensure the transitions fit in a transaction.
+        // Note: Observe we hold back 1 for the fee.
+        ensure!(
+            transitions.len() < Transaction::<N>::MAX_TRANSITIONS,
+            "The bundled authorization exceeds the maximum number of transitions ({})",
+            Transaction::<N>::MAX_TRANSITIONS
+        );
+        // Initialize the authorization.
+        let authorization = Self::try_from((requests, transitions))?;
+        // Ensure the transitions are unique.
+        ensure!(
+            authorization.len() == authorization.transitions.read().len(),
+            "Cannot bundle an authorization more than once"
+        );
+        Ok(authorization)
+    }
+}
+
 impl<N: Network> TryFrom<(Vec<Request<N>>, Vec<Transition<N>>)> for Authorization<N> {
     type Error = Error;
 
@@ -72,6 +141,7 @@ impl<N: Network> TryFrom<(Vec<Request<N>>, Vec<Transition<N>>)> for Authorizatio
             transitions: Arc::new(RwLock::new(IndexMap::from_iter(
                 transitions.into_iter().map(|transition| (*transition.id(), transition)),
             ))),
+            expiration: Default::default(),
         })
     }
 }
@@ -168,6 +238,11 @@ impl<N: Network> Authorization<N> {
         Ok(())
     }
 
+    /// Returns `true` if the authorization contains a transition with the given transition commitment.
+    pub fn contains_transition_commitment(&self, tcm: &Field<N>) -> bool {
+        self.transitions.read().values().any(|transition| transition.tcm() == tcm)
+    }
+
     /// Returns the transitions in the authorization.
     pub fn transitions(&self) -> IndexMap<N::TransitionID, Transition<N>> {
         self.transitions.read().clone()
@@ -181,6 +256,35 @@ impl<N: Network> Authorization<N> {
         }
         Ok(*Transaction::transitions_tree(transitions.values(), &None)?.root())
     }
+
+    /// Ensures the given execution is the proven form of the requests in the authorization,
+    /// i.e. that it contains, in order, one transition for each request, for the same function and transition commitments.
+    /// If the transitions of the authorization have been computed, this also ensures the execution contains exactly
+    /// those transitions (and thus the same outputs), by comparing the execution IDs.
+    ///
+    /// Note: This method does not verify the proof, which is the responsibility of `Process::verify_execution`.
+    pub fn check_execution(&self, execution: &Execution<N>) -> Result<()> {
+        let requests = self.requests.read();
+        // Ensure the number of requests and transitions matches.
+        ensure!(
+            requests.len() == execution.len(),
+            "The execution contains {} transitions, but the authorization contains {} requests",
+            execution.len(),
+            requests.len()
+        );
+        // Ensure each transition corresponds to its request.
+        for (index, (request, transition)) in requests.iter().zip_eq(execution.transitions()).enumerate() {
+            ensure_request_and_transition_matches(index, request, transition)?;
+        }
+        // Ensure the execution contains the transitions of the authorization, if they have been computed.
+        if !self.transitions.read().is_empty() {
+            ensure!(
+                execution.to_execution_id()? == self.to_execution_id()?,
+                "The execution does not match the transitions in the authorization"
+            );
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network> PartialEq for Authorization<N> {
@@ -191,7 +295,9 @@ impl<N: Network> PartialEq for Authorization<N> {
         let self_transitions = self.transitions.read();
         let other_transitions = other.transitions.read();
 
-        *self_requests == *other_requests && *self_transitions == *other_transitions
+        *self_requests == *other_requests
+            && *self_transitions == *other_transitions
+            && *self.expiration.read() == *other.expiration.read()
     }
 }
 
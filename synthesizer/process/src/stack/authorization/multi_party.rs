@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Process;
+
+impl<N: Network> Authorization<N> {
+    /// Appends the given request to the authorization, without its transition.
+    ///
+    /// This allows the top-level calls of an authorization to be signed by different parties,
+    /// such as a custody service co-signing a transfer. Each top-level request must be followed by the
+    /// (pre-order) requests of the functions it calls, which must be signed by the same signer.
+    /// Once every party has added its requests, `finalize_partial_requests` must be called before execution.
+    pub fn add_partial_request(&self, process: &Process<N>, request: Request<N>) -> Result<()> {
+        // Ensure the number of requests does not exceed the maximum.
+        // Note that one transition is reserved for the fee.
+        ensure!(
+            self.len() + 1 < Transaction::<N>::MAX_TRANSITIONS,
+            "Authorization exceeds the maximum number of requests"
+        );
+        // Retrieve the top-level request whose call tree is incomplete, if any.
+        let pending_root = self.pending_root(process)?;
+        // Ensure the request is signed by the same signer as its top-level request.
+        if let Some(root) = &pending_root {
+            ensure!(
+                root.signer() == request.signer(),
+                "The requests of a top-level call must use the same signer, expected '{}' but found '{}'",
+                root.signer(),
+                request.signer()
+            );
+        }
+        // Ensure the request matches its function signature, and that its signature is valid.
+        process.get_stack(request.program_id())?.check_request(&request, pending_root.is_none())?;
+        // Append the request.
+        self.push(request);
+        Ok(())
+    }
+
+    /// Validates the signatures of all requests, and computes the transitions that are missing from the authorization.
+    ///
+    /// This method fails if the call tree of a top-level request is incomplete,
+    /// or if the requests of a top-level call are not signed by the same signer.
+    pub fn finalize_partial_requests<A: circuit::Aleo<Network = N>>(&self, process: &Process<N>) -> Result<()> {
+        // Retrieve the requests.
+        let requests = self.to_vec_deque();
+        // Ensure the authorization contains a request.
+        ensure!(!requests.is_empty(), "Cannot finalize an authorization without requests");
+        // Ensure the call tree of every top-level request is complete.
+        if let Some(root) = self.pending_root(process)? {
+            bail!("The call tree of '{}/{}' is missing requests", root.program_id(), root.function_name())
+        }
+
+        // Validate the signature of each request, against the signer of its top-level request.
+        let mut index = 0;
+        while index < requests.len() {
+            // Retrieve the top-level request, and the number of requests in its call tree.
+            let root = &requests[index];
+            let num_calls = process.get_stack(root.program_id())?.get_number_of_calls(root.function_name())?;
+            for (offset, request) in requests.range(index..index + num_calls).enumerate() {
+                ensure!(
+                    root.signer() == request.signer(),
+                    "The request for '{}/{}' is not signed by the signer of its top-level call",
+                    request.program_id(),
+                    request.function_name()
+                );
+                process.get_stack(request.program_id())?.check_request(request, offset == 0)?;
+            }
+            index += num_calls;
+        }
+
+        // Compute the transitions.
+        let transitions = Self::compute_transitions::<A>(process, &Vec::from(requests))?;
+
+        // Store the transitions.
+        *self.transitions.write() = transitions.into_iter().map(|transition| (*transition.id(), transition)).collect();
+        Ok(())
+    }
+
+    /// Returns the last top-level request, if the requests of its call tree have not all been added.
+    fn pending_root(&self, process: &Process<N>) -> Result<Option<Request<N>>> {
+        let requests = self.requests.read();
+        let mut index = 0;
+        while index < requests.len() {
+            // Retrieve the top-level request, and the number of requests in its call tree.
+            let root = &requests[index];
+            let num_calls = process.get_stack(root.program_id())?.get_number_of_calls(root.function_name())?;
+            // If the call tree is incomplete, return the top-level request.
+            if index + num_calls > requests.len() {
+                return Ok(Some(root.clone()));
+            }
+            index += num_calls;
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        account::{Address, PrivateKey},
+        program::{Identifier, ProgramID, Value},
+    };
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns a request for `credits.aleo/transfer_public`, signed by the given private key.
+    fn sample_request(
+        process: &Process<CurrentNetwork>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        amount: &str,
+        rng: &mut TestRng,
+    ) -> Request<CurrentNetwork> {
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer_public").unwrap();
+        let input_types = process.get_program(program_id).unwrap().get_function(&function_name).unwrap().input_types();
+        let recipient = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        let inputs = [Value::from_str(&recipient.to_string()).unwrap(), Value::from_str(amount).unwrap()];
+        Request::sign(private_key, program_id, function_name, inputs.iter(), &input_types, None, true, rng).unwrap()
+    }
+
+    #[test]
+    fn test_multi_party_authorization() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Sample the private keys of the two parties.
+        let owner_private_key = PrivateKey::new(rng).unwrap();
+        let custodian_private_key = PrivateKey::new(rng).unwrap();
+
+        // Construct the authorization.
+        let authorization = Authorization::try_from((vec![], vec![])).unwrap();
+        // Ensure an empty authorization cannot be finalized.
+        assert!(authorization.finalize_partial_requests::<CurrentAleo>(&process).is_err());
+
+        // Ensure a request with a mismatched input type is rejected.
+        let request = sample_request(&process, &owner_private_key, "1u32", rng);
+        assert!(authorization.add_partial_request(&process, request).is_err());
+        assert!(authorization.is_empty());
+
+        // Add the requests of each party.
+        let owner_request = sample_request(&process, &owner_private_key, "1u64", rng);
+        let custodian_request = sample_request(&process, &custodian_private_key, "2u64", rng);
+        authorization.add_partial_request(&process, owner_request.clone()).unwrap();
+        authorization.add_partial_request(&process, custodian_request.clone()).unwrap();
+        assert_eq!(authorization.len(), 2);
+        assert!(authorization.transitions().is_empty());
+
+        // Finalize the authorization.
+        authorization.finalize_partial_requests::<CurrentAleo>(&process).unwrap();
+        assert_eq!(authorization.to_vec_deque(), VecDeque::from(vec![owner_request.clone(), custodian_request]));
+        assert_eq!(authorization.transitions().len(), 2);
+        assert!(authorization.contains_transition_commitment(owner_request.tcm()));
+
+        // Ensure the finalized authorization round-trips through bytes.
+        let bytes = authorization.to_bytes_le().unwrap();
+        assert_eq!(authorization, Authorization::read_le(&bytes[..]).unwrap());
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{CallStack, Process};
+use console::program::ProgramID;
+use synthesizer_program::{Operand, StackEvaluate, StackProgram};
+
+/// An authorization that is constructed incrementally, one request at a time.
+///
+/// Each request is validated against its function signature as soon as it is added,
+/// and the partial state may be serialized and resumed later. Once all requests are added,
+/// the partial authorization is converted into an `Authorization` via `into_authorization`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PartialAuthorization<N: Network> {
+    /// The validated requests, in the order they were added.
+    requests: Vec<Request<N>>,
+}
+
+impl<N: Network> PartialAuthorization<N> {
+    /// Initializes a new (empty) partial authorization.
+    pub const fn new() -> Self {
+        Self { requests: Vec::new() }
+    }
+
+    /// Returns the requests in the partial authorization.
+    pub fn requests(&self) -> &[Request<N>] {
+        &self.requests
+    }
+
+    /// Returns the number of requests in the partial authorization.
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Returns `true` if the partial authorization is empty.
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+}
+
+impl<N: Network> PartialAuthorization<N> {
+    /// Validates the given request against its function signature, and appends it to the partial authorization.
+    ///
+    /// The first request is the root request. Subsequent requests must be the (pre-order) function calls
+    /// made by the root request, and must be signed by the same signer.
+    pub fn add_request(&mut self, process: &Process<N>, request: Request<N>) -> Result<()> {
+        // Ensure the number of requests does not exceed the maximum.
+        // Note that one transition is reserved for the fee.
+        ensure!(
+            self.requests.len() + 1 < Transaction::<N>::MAX_TRANSITIONS,
+            "Partial authorization exceeds the maximum number of requests"
+        );
+        // Determine if this is the root request.
+        let is_root = self.requests.is_empty();
+        // Ensure the request is signed by the same signer as the root request.
+        if let Some(root) = self.requests.first() {
+            ensure!(root.signer() == request.signer(), "The requests in an authorization must use the same signer");
+        }
+        // Ensure the request matches its function signature.
+        process.get_stack(request.program_id())?.check_request(&request, is_root)?;
+        // Append the request.
+        self.requests.push(request);
+        Ok(())
+    }
+
+    /// Returns the `Authorization`, by evaluating the requests to compute their transitions.
+    ///
+    /// This method fails if the requests do not form a complete call tree for the root request.
+    pub fn into_authorization<A: circuit::Aleo<Network = N>>(self, process: &Process<N>) -> Result<Authorization<N>> {
+        // Ensure the partial authorization contains a request.
+        ensure!(!self.requests.is_empty(), "Cannot complete an authorization without requests");
+        // Retrieve the number of requests.
+        let num_requests = self.requests.len();
+
+        // Ensure every request is called by the root request.
+        let root = &self.requests[0];
+        let num_calls = process.get_stack(root.program_id())?.get_number_of_calls(root.function_name())?;
+        ensure!(
+            num_requests <= num_calls,
+            "The authorization contains requests that are not called by the root request"
+        );
+
+        // Compute the transitions.
+        let transitions = Authorization::compute_transitions::<A>(process, &self.requests)?;
+
+        // Return the authorization.
+        Ok(Authorization {
+            requests: Arc::new(RwLock::new(VecDeque::from(self.requests))),
+            transitions: Arc::new(RwLock::new(
+                transitions.into_iter().map(|transition| (*transition.id(), transition)).collect(),
+            )),
+            expiration: Default::default(),
+        })
+    }
+}
+
+impl<N: Network> Authorization<N> {
+    /// Returns the transitions for the given requests, which must form complete call trees
+    /// (i.e. each top-level request is followed by the requests of the functions it calls, in pre-order).
+    ///
+    /// Each transition is computed by evaluating the call tree of its request on its own,
+    /// so that the evaluation of an authorization never has to fill in missing transitions.
+    pub(super) fn compute_transitions<A: circuit::Aleo<Network = N>>(
+        process: &Process<N>,
+        requests: &[Request<N>],
+    ) -> Result<Vec<Transition<N>>> {
+        let mut transitions = Vec::with_capacity(requests.len());
+        // The program ID of each enclosing request, and the end of its call tree.
+        let mut enclosing: Vec<(ProgramID<N>, usize)> = Vec::new();
+        for (index, request) in requests.iter().enumerate() {
+            // Remove the enclosing requests whose call trees end before this request.
+            while matches!(enclosing.last(), Some((_, end)) if *end <= index) {
+                enclosing.pop();
+            }
+            // The caller is the program of the closest enclosing request, if any.
+            let caller = enclosing.last().map(|(program_id, _)| *program_id);
+
+            // Retrieve the stack, and the number of requests in the call tree of the request.
+            let stack = process.get_stack(request.program_id())?;
+            let num_calls = stack.get_number_of_calls(request.function_name())?;
+            ensure!(
+                index + num_calls <= requests.len(),
+                "The call tree of '{}/{}' is missing requests",
+                request.program_id(),
+                request.function_name()
+            );
+
+            // Evaluate the call tree of the request.
+            let authorization = Authorization {
+                requests: Arc::new(RwLock::new(requests[index..index + num_calls].iter().cloned().collect())),
+                transitions: Default::default(),
+                expiration: Default::default(),
+            };
+            let response = stack.evaluate_function::<A>(CallStack::evaluate(authorization.clone())?, caller)?;
+            // Ensure every request in the call tree was called.
+            ensure!(authorization.is_empty(), "The call tree of '{}' has unused requests", request.function_name());
+
+            // Construct the transition.
+            let function = stack.get_function_ref(request.function_name())?;
+            let output_registers = function
+                .outputs()
+                .iter()
+                .map(|output| match output.operand() {
+                    Operand::Register(register) => Some(register.clone()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            transitions.push(Transition::from(request, &response, &function.output_types(), &output_registers)?);
+            enclosing.push((*request.program_id(), index + num_calls));
+        }
+        Ok(transitions)
+    }
+}
+
+impl<N: Network> Default for PartialAuthorization<N> {
+    /// Returns an empty partial authorization.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> FromBytes for PartialAuthorization<N> {
+    /// Reads the partial authorization from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid partial authorization version"));
+        }
+        // Read the number of requests.
+        let num_requests = u8::read_le(&mut reader)?;
+        // Read the requests.
+        let requests = (0..num_requests).map(|_| Request::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the partial authorization.
+        Ok(Self { requests })
+    }
+}
+
+impl<N: Network> ToBytes for PartialAuthorization<N> {
+    /// Writes the partial authorization to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of requests.
+        u8::try_from(self.requests.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the requests.
+        self.requests.iter().try_for_each(|request| request.write_le(&mut writer))
+    }
+}
+
+impl<N: Network> Serialize for PartialAuthorization<N> {
+    /// Serializes the partial authorization into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut authorization = serializer.serialize_struct("PartialAuthorization", 1)?;
+                authorization.serialize_field("requests", &self.requests)?;
+                authorization.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for PartialAuthorization<N> {
+    /// Deserializes the partial authorization from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the partial authorization from a string into a value.
+                let mut authorization = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the requests.
+                let requests = DeserializeExt::take_from_value::<D>(&mut authorization, "requests")?;
+                // Recover the partial authorization.
+                Ok(Self { requests })
+            }
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "partial authorization")
+            }
+        }
+    }
+}
+
+impl<N: Network> FromStr for PartialAuthorization<N> {
+    type Err = Error;
+
+    /// Initializes the partial authorization from a JSON-string.
+    fn from_str(authorization: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(authorization)?)
+    }
+}
+
+impl<N: Network> Debug for PartialAuthorization<N> {
+    /// Prints the partial authorization as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for PartialAuthorization<N> {
+    /// Displays the partial authorization as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        account::PrivateKey,
+        program::{Identifier, ProgramID, Value},
+    };
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns a signed request for `credits.aleo/transfer_public`.
+    fn sample_request(
+        process: &Process<CurrentNetwork>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        inputs: &[&str],
+        rng: &mut TestRng,
+    ) -> Request<CurrentNetwork> {
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer_public").unwrap();
+        let input_types = process.get_program(program_id).unwrap().get_function(&function_name).unwrap().input_types();
+        let inputs = inputs.iter().map(|input| Value::from_str(input).unwrap()).collect::<Vec<_>>();
+        Request::sign(private_key, program_id, function_name, inputs.iter(), &input_types, None, true, rng).unwrap()
+    }
+
+    #[test]
+    fn test_partial_authorization() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let recipient = console::account::Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+
+        // Construct the partial authorization.
+        let mut partial = PartialAuthorization::new();
+        assert!(partial.is_empty());
+
+        // Ensure a request with a mismatched input type is rejected immediately.
+        let request = sample_request(&process, &private_key, &[&recipient.to_string(), "1u32"], rng);
+        assert!(partial.add_request(&process, request).is_err());
+        assert!(partial.is_empty());
+
+        // Add a valid request.
+        let request = sample_request(&process, &private_key, &[&recipient.to_string(), "1u64"], rng);
+        partial.add_request(&process, request.clone()).unwrap();
+        assert_eq!(partial.len(), 1);
+
+        // Serialize and resume the partial authorization.
+        let bytes = partial.to_bytes_le().unwrap();
+        let partial = PartialAuthorization::read_le(&bytes[..]).unwrap();
+        let partial = PartialAuthorization::<CurrentNetwork>::from_str(&partial.to_string()).unwrap();
+        assert_eq!(partial.requests(), &[request.clone()]);
+
+        // Complete the authorization.
+        let authorization = partial.into_authorization::<CurrentAleo>(&process).unwrap();
+        assert_eq!(authorization.len(), 1);
+        assert_eq!(authorization.transitions().len(), 1);
+        let transition = authorization.transitions().values().next().unwrap().clone();
+        ensure_request_and_transition_matches(0, &request, &transition).unwrap();
+    }
+
+    #[test]
+    fn test_partial_authorization_requires_requests() {
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Ensure an empty partial authorization cannot be completed.
+        let partial = PartialAuthorization::<CurrentNetwork>::new();
+        assert!(partial.into_authorization::<CurrentAleo>(&process).is_err());
+    }
+}
@@ -19,12 +19,21 @@ impl<N: Network> Serialize for Authorization<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut authorization = serializer.serialize_struct("Authorization", 2)?;
+                let expiration = *self.expiration.read();
+                let num_fields = match expiration.is_some() {
+                    true => 4,
+                    false => 2,
+                };
+                let mut authorization = serializer.serialize_struct("Authorization", num_fields)?;
                 authorization.serialize_field("requests", &self.requests.read().clone())?;
                 authorization.serialize_field(
                     "transitions",
                     &self.transitions.read().values().collect::<Vec<&Transition<N>>>(),
                 )?;
+                if let Some((expiration, signature)) = expiration {
+                    authorization.serialize_field("expiration", &expiration)?;
+                    authorization.serialize_field("expiration_signature", &signature)?;
+                }
                 authorization.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -43,8 +52,23 @@ impl<'de, N: Network> Deserialize<'de> for Authorization<N> {
                 let requests: Vec<_> = DeserializeExt::take_from_value::<D>(&mut authorization, "requests")?;
                 // Retrieve the transitions.
                 let transitions: Vec<_> = DeserializeExt::take_from_value::<D>(&mut authorization, "transitions")?;
+                // Retrieve the expiration, if one is set.
+                let expiration: Option<Expiration> = serde_json::from_value(
+                    authorization.get_mut("expiration").unwrap_or(&mut serde_json::Value::Null).take(),
+                )
+                .map_err(de::Error::custom)?;
+                let expiration = match expiration {
+                    Some(expiration) => Some((
+                        expiration,
+                        DeserializeExt::take_from_value::<D>(&mut authorization, "expiration_signature")?,
+                    )),
+                    None => None,
+                };
                 // Recover the authorization.
-                Self::try_from((requests, transitions)).map_err(de::Error::custom)
+                let authorization = Self::try_from((requests, transitions)).map_err(de::Error::custom)?;
+                // Set the expiration.
+                *authorization.expiration.write() = expiration;
+                Ok(authorization)
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "authorization"),
         }
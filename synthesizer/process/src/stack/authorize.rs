@@ -33,6 +33,20 @@ impl<N: Network> Stack<N> {
         // Retrieve the input types.
         let input_types = self.get_function(&function_name)?.input_types();
         lap!(timer, "Retrieve the input types");
+        // Prepare the inputs, with their struct members and record entries in the declared order.
+        let inputs = inputs
+            .enumerate()
+            .map(|(index, input)| {
+                let input = input
+                    .try_into()
+                    .map_err(|_| anyhow!("Failed to parse input #{index} for '{program_id}/{function_name}'"))?;
+                match input_types.get(index) {
+                    Some(input_type) => self.normalize_input(input, input_type),
+                    None => Ok(input),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Prepare the inputs");
         // Set is_root to true.
         let is_root = true;
 
@@ -41,8 +55,16 @@ impl<N: Network> Stack<N> {
         // This is the root request and we do not have a root_tvk to pass on.
         let root_tvk = None;
         // Compute the request.
-        let request =
-            Request::sign(private_key, program_id, function_name, inputs, &input_types, root_tvk, is_root, rng)?;
+        let request = Request::sign(
+            private_key,
+            program_id,
+            function_name,
+            inputs.into_iter(),
+            &input_types,
+            root_tvk,
+            is_root,
+            rng,
+        )?;
         lap!(timer, "Compute the request");
         // Initialize the authorization.
         let authorization = Authorization::new(request.clone());
@@ -55,4 +77,44 @@ impl<N: Network> Stack<N> {
         // Return the authorization.
         Ok(authorization)
     }
+
+    /// Checks that the given request is well-formed for a function in this program.
+    /// This ensures the request matches the function signature, and that its signature is valid.
+    #[inline]
+    pub fn check_request(&self, request: &Request<N>, is_root: bool) -> Result<()> {
+        // Ensure the network ID matches.
+        ensure!(
+            **request.network_id() == N::ID,
+            "Network ID mismatch. Expected {}, but found {}",
+            N::ID,
+            request.network_id()
+        );
+        // Ensure the program ID matches.
+        ensure!(
+            request.program_id() == self.program.id(),
+            "Expected a request for program '{}', found '{}'",
+            self.program.id(),
+            request.program_id()
+        );
+        // Retrieve the function.
+        let function = self.get_function_ref(request.function_name())?;
+        // Ensure the number of inputs matches the number of input statements.
+        ensure!(
+            function.inputs().len() == request.inputs().len(),
+            "Function '{}' expects {} inputs, but {} were provided",
+            function.name(),
+            function.inputs().len(),
+            request.inputs().len()
+        );
+        // Retrieve the input types.
+        let input_types = function.input_types();
+        // Ensure the inputs match their expected types.
+        request.inputs().iter().zip_eq(&input_types).try_for_each(|(input, input_type)| {
+            // Ensure the input matches the input type in the function.
+            self.matches_value_type(input, input_type)
+        })?;
+        // Ensure the request is well-formed.
+        ensure!(request.verify(&input_types, is_root), "Request for '{}' is invalid", function.name());
+        Ok(())
+    }
 }
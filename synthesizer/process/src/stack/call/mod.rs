@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod view;
+
 use crate::{stack::Address, CallStack, Registers, RegistersCall, StackEvaluate, StackExecute};
 use aleo_std::prelude::{finish, lap, timer};
 use console::{
@@ -45,13 +47,11 @@ pub trait CallTrait<N: Network> {
     fn execute<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
         &self,
         stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
-        registers: &mut (
-                 impl RegistersCall<N>
-                 + RegistersSigner<N>
-                 + RegistersSignerCircuit<N, A>
-                 + RegistersLoadCircuit<N, A>
-                 + RegistersStoreCircuit<N, A>
-             ),
+        registers: &mut (impl RegistersCall<N>
+                  + RegistersSigner<N>
+                  + RegistersSignerCircuit<N, A>
+                  + RegistersLoadCircuit<N, A>
+                  + RegistersStoreCircuit<N, A>),
         rng: &mut R,
     ) -> Result<()>;
 }
@@ -138,13 +138,11 @@ impl<N: Network> CallTrait<N> for Call<N> {
     fn execute<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
-        registers: &mut (
-                 impl RegistersCall<N>
-                 + RegistersSigner<N>
-                 + RegistersSignerCircuit<N, A>
-                 + RegistersLoadCircuit<N, A>
-                 + RegistersStoreCircuit<N, A>
-             ),
+        registers: &mut (impl RegistersCall<N>
+                  + RegistersSigner<N>
+                  + RegistersSignerCircuit<N, A>
+                  + RegistersLoadCircuit<N, A>
+                  + RegistersStoreCircuit<N, A>),
         rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("Call::execute");
@@ -349,8 +347,8 @@ impl<N: Network> CallTrait<N> for Call<N> {
                     CallStack::Evaluate(..) => {
                         bail!("Cannot 'execute' a function in 'evaluate' mode.")
                     }
-                    // If the circuit is in execute mode, then evaluate and execute the instructions.
-                    CallStack::Execute(authorization, ..) => {
+                    // If the circuit is in execute or dry-run mode, then evaluate and execute the instructions.
+                    CallStack::Execute(authorization, ..) | CallStack::DryRun(authorization, ..) => {
                         // Retrieve the next request (without popping it).
                         let request = authorization.peek_next()?;
                         // Ensure the inputs match the original inputs.
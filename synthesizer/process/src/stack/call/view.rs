@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use synthesizer_program::CallView;
+
+impl<N: Network> CallTrait<N> for CallView<N> {
+    /// Evaluates the instruction.
+    ///
+    /// The external function is evaluated as a closure on the external stack, without a request or transition.
+    #[inline]
+    fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        let timer = timer!("CallView::evaluate");
+
+        // Load the operands values.
+        let inputs: Vec<_> = self.operands().iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+
+        // Retrieve the external stack, and the function as a closure.
+        let substack = stack.get_external_stack(self.locator().program_id())?;
+        let closure = CallView::to_closure(substack.program().get_function_ref(self.locator().resource())?)?;
+        lap!(timer, "Retrieved the substack and function");
+
+        // Evaluate the function, and load the outputs.
+        // Note: The function cannot read the signer or caller, which are inherited from the current transition.
+        let outputs = substack.evaluate_closure::<A>(
+            &closure,
+            &inputs,
+            registers.call_stack(),
+            registers.signer()?,
+            registers.caller()?,
+            registers.tvk()?,
+        )?;
+        lap!(timer, "Computed outputs");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in outputs.into_iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store(stack, register, output)?;
+        }
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Executes the instruction.
+    ///
+    /// The external function is synthesized into the circuit of the current transition, as a closure would be,
+    /// so that it neither requires a proof nor produces a transition of its own.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersCall<N>
+                  + RegistersSigner<N>
+                  + RegistersSignerCircuit<N, A>
+                  + RegistersLoadCircuit<N, A>
+                  + RegistersStoreCircuit<N, A>),
+        _rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("CallView::execute");
+
+        // Load the operands values.
+        let inputs: Vec<_> =
+            self.operands().iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+
+        // Retrieve the external stack, and the function as a closure.
+        let substack = stack.get_external_stack(self.locator().program_id())?;
+        let closure = CallView::to_closure(substack.program().get_function_ref(self.locator().resource())?)?;
+        lap!(timer, "Retrieve the substack and function");
+
+        // Execute the function, and load the outputs.
+        let outputs = substack.execute_closure(
+            &closure,
+            &inputs,
+            registers.call_stack(),
+            registers.signer_circuit()?,
+            registers.caller_circuit()?,
+            registers.tvk_circuit()?,
+        )?;
+        lap!(timer, "Execute the function");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in outputs.into_iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store_circuit(stack, register, output)?;
+        }
+        lap!(timer, "Assigned the outputs to registers");
+
+        finish!(timer);
+
+        Ok(())
+    }
+}
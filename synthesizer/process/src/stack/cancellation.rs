@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A token to cancel an execution, which may be shared with another thread.
+/// The execution is halted between instructions, and between the rounds of the prover.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    /// The flag that is set once the execution is cancelled.
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Initializes a new cancellation token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the execution.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the execution was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Ensures the execution was not cancelled.
+    pub fn ensure_not_cancelled(&self) -> Result<()> {
+        match self.is_cancelled() {
+            true => Err(ExecutionCancelled.into()),
+            false => Ok(()),
+        }
+    }
+
+    /// Returns the flag of the token, as the terminator of the prover.
+    pub(crate) fn terminator(&self) -> &AtomicBool {
+        &self.cancelled
+    }
+}
+
+/// The rejection of a cancelled execution.
+/// The rejection is returned as an `anyhow::Error`, from which it may be recovered with `downcast_ref`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionCancelled;
+
+impl Display for ExecutionCancelled {
+    /// Prints the rejection as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "The execution was cancelled")
+    }
+}
+
+impl std::error::Error for ExecutionCancelled {}
+
+impl<N: Network> CallStack<N> {
+    /// Returns the cancellation token of the call stack.
+    /// Only executions may be cancelled, so the token of any other call stack is never cancelled.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        match self {
+            CallStack::Execute(_, trace) => trace.read().cancellation_token().clone(),
+            _ => CancellationToken::default(),
+        }
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use crate::{DeploymentProfile, FunctionProfile};
 
 use rand::{rngs::StdRng, SeedableRng};
 
@@ -24,6 +25,8 @@ impl<N: Network> Stack<N> {
 
         // Ensure the program contains functions.
         ensure!(!self.program.functions().is_empty(), "Program '{}' has no functions", self.program.id());
+        // Ensure the program does not create unspendable records.
+        self.check_record_owners()?;
 
         // Initialize a vector for the verifying keys and certificates.
         let mut verifying_keys = Vec::with_capacity(self.program.functions().len());
@@ -53,6 +56,46 @@ impl<N: Network> Stack<N> {
         Deployment::new(N::EDITION, self.program.clone(), verifying_keys)
     }
 
+    /// Returns the estimated deployment cost of each function in the program,
+    /// by synthesizing each circuit *without* generating its circuit keys or certificate.
+    #[inline]
+    pub fn profile_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        rng: &mut R,
+    ) -> Result<DeploymentProfile<N>> {
+        let timer = timer!("Stack::profile_deployment");
+
+        // Ensure the program contains functions.
+        ensure!(!self.program.functions().is_empty(), "Program '{}' has no functions", self.program.id());
+
+        // Initialize a map for the function profiles.
+        let mut functions = IndexMap::with_capacity(self.program.functions().len());
+
+        for function_name in self.program.functions().keys() {
+            // Synthesize the circuit.
+            let start = Instant::now();
+            let assignment = self.synthesize_assignment::<A, R>(function_name, rng)?;
+            let synthesis_time = start.elapsed();
+            lap!(timer, "Synthesize the circuit for {function_name}");
+
+            // Construct the function profile.
+            // Note: The constraint count includes the constraint added after synthesis that makes the Varuna zerocheck hiding.
+            let profile = FunctionProfile::new(
+                assignment.num_constraints().saturating_add(1),
+                assignment.num_variables(),
+                assignment.num_public(),
+                assignment.num_private(),
+                assignment.num_nonzeros(),
+                synthesis_time,
+            );
+            functions.insert(*function_name, profile);
+        }
+
+        finish!(timer);
+
+        Ok(DeploymentProfile::new(functions))
+    }
+
     /// Checks each function in the program on the given verifying key and certificate.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
@@ -68,6 +111,17 @@ impl<N: Network> Stack<N> {
         deployment.check_is_ordered()?;
         // Ensure the program in the stack and deployment matches.
         ensure!(&self.program == deployment.program(), "The stack program does not match the deployment program");
+        // Ensure the program does not create unspendable records.
+        self.check_record_owners()?;
+        // Ensure the verifying keys do not require unsupported proof-system features.
+        for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+            if let Err(error) = verifying_key.features().check_supported() {
+                bail!(
+                    "The verifying key for function '{function_name}' in '{}' is incompatible: {error}",
+                    self.program.id()
+                )
+            }
+        }
 
         // Check Verifying Keys //
 
@@ -167,7 +221,10 @@ impl<N: Network> Stack<N> {
                     Some((assignment, _metrics)) => {
                         // Ensure the certificate is valid.
                         if !certificate.verify(&function_name.to_string(), assignment, verifying_key) {
-                            bail!("The certificate for function '{function_name}' is invalid in '{program_id}'")
+                            bail!(
+                                "The certificate for function '{function_name}' is invalid in '{program_id}' for {}",
+                                SynthesizerBinding::current::<N>()
+                            )
                         }
                     }
                 };
@@ -179,4 +236,95 @@ impl<N: Network> Stack<N> {
 
         Ok(())
     }
+
+    /// Checks the given function in the program on the given verifying key and certificate.
+    /// This performs the checks of `Stack::verify_deployment` for a single function.
+    #[inline]
+    pub(crate) fn verify_function_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        verifying_key: &VerifyingKey<N>,
+        certificate: &Certificate<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = self.program.id();
+        // Ensure the verifying key does not require unsupported proof-system features.
+        if let Err(error) = verifying_key.features().check_supported() {
+            bail!("The verifying key for function '{function_name}' in '{program_id}' is incompatible: {error}")
+        }
+
+        // Retrieve the function.
+        let function = self.program.get_function_ref(function_name)?;
+        // Initialize a burner private key.
+        let burner_private_key = PrivateKey::new(rng)?;
+        // Compute the burner address.
+        let burner_address = Address::try_from(&burner_private_key)?;
+        // Retrieve the input types.
+        let input_types = function.input_types();
+        // Sample the inputs.
+        let inputs = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                ValueType::ExternalRecord(locator) => {
+                    // Retrieve the external stack.
+                    let stack = self.get_external_stack(locator.program_id())?;
+                    // Sample the input.
+                    stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
+                }
+                _ => self.sample_value(&burner_address, input_type, rng),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // The `root_tvk` is `None` when verifying the deployment of an individual circuit.
+        let root_tvk = None;
+        // The `caller` is `None` when verifying the deployment of an individual circuit.
+        let caller = None;
+
+        // Compute the request, with a burner private key.
+        let request = Request::sign(
+            &burner_private_key,
+            *program_id,
+            *function_name,
+            inputs.into_iter(),
+            &input_types,
+            root_tvk,
+            true,
+            rng,
+        )?;
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the constraint limit. Account for the constraint added after synthesis that makes the Varuna zerocheck hiding.
+        let Some(constraint_limit) = verifying_key.circuit_info.num_constraints.checked_sub(1) else {
+            // Since a deployment must always pay non-zero fee, it must always have at least one constraint.
+            bail!("The constraint limit of 0 for function '{function_name}' is invalid");
+        };
+        // Initialize the call stack.
+        let call_stack = CallStack::CheckDeployment(
+            vec![request],
+            burner_private_key,
+            assignments.clone(),
+            Some(constraint_limit as u64),
+            Some(verifying_key.num_variables()),
+        );
+
+        // Synthesize the circuit.
+        if let Err(err) = self.execute_function::<A, _>(call_stack, caller, root_tvk, rng) {
+            bail!("Failed to synthesize the circuit for '{function_name}': {err}")
+        }
+        // Check the certificate.
+        match assignments.read().last() {
+            None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
+            Some((assignment, _metrics)) => {
+                // Ensure the certificate is valid.
+                if !certificate.verify(&function_name.to_string(), assignment, verifying_key) {
+                    bail!(
+                        "The certificate for function '{function_name}' is invalid in '{program_id}' for {}",
+                        SynthesizerBinding::current::<N>()
+                    )
+                }
+            }
+        };
+        Ok(())
+    }
 }
@@ -54,11 +54,8 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Evaluate the instructions.
-        for instruction in closure.instructions() {
-            // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
-            }
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            self.evaluate_instruction(&mut registers, closure.name(), index, instruction)?;
         }
         lap!(timer, "Evaluate the instructions");
 
@@ -107,18 +104,20 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
         // Retrieve the next request, based on the call stack mode.
         let (request, call_stack) = match &call_stack {
-            CallStack::Evaluate(authorization) => (authorization.next()?, call_stack),
-            // If the evaluation is performed in the `Execute` mode, create a new `Evaluate` mode.
+            CallStack::Evaluate(authorization, _) => (authorization.next()?, call_stack),
+            // If the evaluation is performed in the `Execute` or `DryRun` mode, create a new `Evaluate` mode.
             // This is done to ensure that evaluation during execution is performed consistently.
-            CallStack::Execute(authorization, _) => {
+            CallStack::Execute(authorization, _) | CallStack::DryRun(authorization, _) => {
                 // Note: We need to replicate the authorization, so that 'execute' can call 'authorization.next()?'.
                 // This way, the authorization remains unmodified in this 'evaluate' scope.
                 let authorization = authorization.replicate();
                 let request = authorization.next()?;
-                let call_stack = CallStack::Evaluate(authorization);
+                let call_stack = CallStack::evaluate(authorization)?;
                 (request, call_stack)
             }
-            _ => bail!("Illegal operation: call stack must be `Evaluate` or `Execute` in `evaluate_function`."),
+            _ => bail!(
+                "Illegal operation: call stack must be `Evaluate`, `Execute`, or `DryRun` in `evaluate_function`."
+            ),
         };
         lap!(timer, "Retrieve the next request");
 
@@ -131,7 +130,11 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         );
 
         // Retrieve the function, inputs, and transition view key.
-        let function = self.get_function(request.function_name())?;
+        // Note: If an observer is set, the function is evaluated as written, as the observer is invoked on its instructions.
+        let (function, register_types) = match call_stack.evaluation_observer() {
+            Some(_) => (self.get_function(request.function_name())?, self.get_register_types(request.function_name())?),
+            None => self.get_executable_function(request.function_name())?,
+        };
         let inputs = request.inputs();
         let signer = *request.signer();
         let (is_root, caller) = match caller {
@@ -155,7 +158,7 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         lap!(timer, "Perform input checks");
 
         // Initialize the registers.
-        let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(function.name())?.clone());
+        let mut registers = Registers::<N, A>::new(call_stack, register_types.clone());
         // Set the transition signer.
         registers.set_signer(signer);
         // Set the transition caller.
@@ -176,19 +179,8 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Evaluate the instructions.
-        // Note: We handle the `call` instruction separately, as it requires special handling.
-        for instruction in function.instructions() {
-            // Evaluate the instruction.
-            let result = match instruction {
-                // If the instruction is a `call` instruction, we need to handle it separately.
-                Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
-                // Otherwise, evaluate the instruction normally.
-                _ => instruction.evaluate(self, &mut registers),
-            };
-            // If the evaluation fails, bail and return the error.
-            if let Err(error) = result {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
-            }
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            self.evaluate_instruction(&mut registers, function.name(), index, instruction)?;
         }
         lap!(timer, "Evaluate the instructions");
 
@@ -249,3 +241,49 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         response
     }
 }
+
+impl<N: Network> Stack<N> {
+    /// Evaluates the instruction at the given index of the given closure or function, in console mode.
+    ///
+    /// This is the single dispatch for the console evaluation of an instruction, shared by every mode that
+    /// evaluates instructions (including execution): the timeout and the size of the destinations are checked and
+    /// the observer is invoked first, if set, then `call` and `call.view` are handled by the call logic, and the
+    /// other instructions are evaluated directly (in constant time, if the call stack requires it).
+    pub(crate) fn evaluate_instruction<A: circuit::Aleo<Network = N>>(
+        &self,
+        registers: &mut Registers<N, A>,
+        resource: &Identifier<N>,
+        index: usize,
+        instruction: &Instruction<N>,
+    ) -> Result<()> {
+        // If the call stack has evaluation limits, ensure the evaluation has not exceeded its timeout.
+        registers.call_stack_ref().ensure_within_timeout()?;
+        // If the call stack has evaluation limits, ensure the destinations are within them, before they are built.
+        self.ensure_destinations_within_limits(registers, instruction)?;
+        // If an observer is set, invoke it on the instruction.
+        if let Some(observer) = registers.call_stack_ref().evaluation_observer() {
+            self.observe_instruction(observer, registers, resource, index, instruction)?;
+        }
+        // Determine if the instruction is evaluated in constant time.
+        let is_constant_time = registers.call_stack_ref().is_constant_time();
+        // Evaluate the instruction.
+        let result = match instruction {
+            // If the instruction is a `call` instruction, we need to handle it separately.
+            Instruction::Call(call) => CallTrait::evaluate(call, self, registers),
+            // If the instruction is a `call.view` instruction, we need to handle it separately.
+            Instruction::CallView(call_view) => CallTrait::evaluate(call_view, self, registers),
+            // If the call stack is in constant-time mode, evaluate the instruction in constant time.
+            _ if is_constant_time => instruction.evaluate_constant_time(self, registers),
+            // Otherwise, evaluate the instruction normally.
+            _ => instruction.evaluate(self, registers),
+        };
+        // If the evaluation fails, bail and return the error.
+        if let Err(error) = result {
+            bail!(
+                "Failed to evaluate instruction ({}): {error}",
+                registers.register_types().describe_instruction(instruction)
+            );
+        }
+        Ok(())
+    }
+}
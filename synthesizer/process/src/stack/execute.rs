@@ -55,8 +55,8 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Store the inputs.
         closure.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
-            // If the circuit is in execute mode, then store the console input.
-            if let CallStack::Execute(..) = registers.call_stack() {
+            // If the circuit is in execute or dry-run mode, then store the console input.
+            if let CallStack::Execute(..) | CallStack::DryRun(..) = registers.call_stack() {
                 use circuit::Eject;
                 // Assign the console input to the register.
                 registers.store(self, register, input.eject_value())?;
@@ -67,13 +67,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Execute the instructions.
-        for instruction in closure.instructions() {
-            // If the circuit is in execute mode, then evaluate the instructions.
-            if let CallStack::Execute(..) = registers.call_stack() {
-                // If the evaluation fails, bail and return the error.
-                if let Err(error) = instruction.evaluate(self, &mut registers) {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
-                }
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            // If the circuit is in execute or dry-run mode, then evaluate the instructions.
+            if matches!(registers.call_stack_ref(), CallStack::Execute(..) | CallStack::DryRun(..)) {
+                self.evaluate_instruction(&mut registers, closure.name(), index, instruction)?;
             }
             // Execute the instruction.
             instruction.execute(self, &mut registers)?;
@@ -149,6 +146,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Ensure the circuit environment is clean.
         A::reset();
 
+        // Start the synthesis timer, and retrieve the number of calls recorded before this call.
+        let synthesis_start = Instant::now();
+        let num_prior_calls = Self::recorded_call_metrics(&call_stack).len();
+
         // If in 'CheckDeployment' mode, set the constraint limit and variable limit.
         // We do not have to reset it after function calls because `CheckDeployment` mode does not execute those.
         if let CallStack::CheckDeployment(_, _, _, constraint_limit, variable_limit) = &call_stack {
@@ -182,8 +183,8 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             Some(console_caller) => console_caller.to_address()?,
         };
 
-        // Retrieve the function from the program.
-        let function = self.get_function(console_request.function_name())?;
+        // Retrieve the function from the program, and its register types.
+        let (function, register_types) = self.get_executable_function(console_request.function_name())?;
         // Retrieve the number of inputs.
         let num_inputs = function.inputs().len();
         // Ensure the number of inputs matches the number of input statements.
@@ -208,7 +209,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         lap!(timer, "Verify the console request");
 
         // Initialize the registers.
-        let mut registers = Registers::new(call_stack, self.get_register_types(function.name())?.clone());
+        let mut registers = Registers::new(call_stack, register_types.clone());
 
         // Set the root tvk, from a parent request or the current request.
         // inject the `root_tvk` as `Mode::Private`.
@@ -268,8 +269,8 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Store the inputs.
         function.inputs().iter().map(|i| i.register()).zip_eq(request.inputs()).try_for_each(|(register, input)| {
-            // If the circuit is in execute mode, then store the console input.
-            if let CallStack::Execute(..) = registers.call_stack() {
+            // If the circuit is in execute or dry-run mode, then store the console input.
+            if let CallStack::Execute(..) | CallStack::DryRun(..) = registers.call_stack() {
                 // Assign the console input to the register.
                 registers.store(self, register, input.eject_value())?;
             }
@@ -280,34 +281,36 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
+        // Retrieve the cancellation token.
+        let cancellation = registers.call_stack().cancellation_token();
 
         // Execute the instructions.
-        for instruction in function.instructions() {
-            // If the circuit is in execute mode, then evaluate the instructions.
-            if let CallStack::Execute(..) = registers.call_stack() {
-                // Evaluate the instruction.
-                let result = match instruction {
-                    // If the instruction is a `call` instruction, we need to handle it separately.
-                    Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
-                    // Otherwise, evaluate the instruction normally.
-                    _ => instruction.evaluate(self, &mut registers),
-                };
-                // If the evaluation fails, bail and return the error.
-                if let Err(error) = result {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
-                }
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // Ensure the execution was not cancelled.
+            cancellation.ensure_not_cancelled()?;
+
+            // If the circuit is in execute or dry-run mode, then evaluate the instructions.
+            if matches!(registers.call_stack_ref(), CallStack::Execute(..) | CallStack::DryRun(..)) {
+                self.evaluate_instruction(&mut registers, function.name(), index, instruction)?;
             }
 
             // Execute the instruction.
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
+                // If the instruction is a `call.view` instruction, we need to handle it separately.
+                Instruction::CallView(call_view) => CallTrait::execute(call_view, self, &mut registers, rng),
                 // Otherwise, execute the instruction normally.
                 _ => instruction.execute(self, &mut registers),
             };
             // If the execution fails, bail and return the error.
             if let Err(error) = result {
-                bail!("Failed to execute instruction ({instruction}): {error}");
+                // If a nested call was cancelled, return the cancellation as is.
+                cancellation.ensure_not_cancelled()?;
+                bail!(
+                    "Failed to execute instruction ({}): {error}",
+                    registers.register_types().describe_instruction(instruction)
+                );
             }
 
             // If the instruction was a function call, then set the tracker to `true`.
@@ -413,8 +416,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             self.matches_value_type(output, output_type)
         })?;
 
-        // If the circuit is in `Execute` or `PackageRun` mode, then ensure the circuit is satisfied.
-        if matches!(registers.call_stack(), CallStack::Execute(..) | CallStack::PackageRun(..)) {
+        // If the circuit is in `Execute`, `DryRun`, or `PackageRun` mode, then ensure the circuit is satisfied.
+        if matches!(registers.call_stack(), CallStack::Execute(..) | CallStack::DryRun(..) | CallStack::PackageRun(..))
+        {
             // If the circuit is empty or not satisfied, then throw an error.
             ensure!(
                 A::num_constraints() > 0 && A::is_satisfied(),
@@ -428,6 +432,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Eject the circuit assignment and reset the circuit.
         let assignment = A::eject_assignment_and_reset();
 
+        // Compute the synthesis time, excluding the synthesis time of the nested calls.
+        let nested_synthesis_time = Self::recorded_call_metrics(&registers.call_stack())
+            .iter()
+            .skip(num_prior_calls)
+            .map(|metrics| metrics.synthesis_time)
+            .sum::<Duration>();
+        let synthesis_time = synthesis_start.elapsed().saturating_sub(nested_synthesis_time);
+
         // If the circuit is in `Synthesize` or `Execute` mode, synthesize the circuit key, if it does not exist.
         if matches!(registers.call_stack(), CallStack::Synthesize(..))
             || matches!(registers.call_stack(), CallStack::Execute(..))
@@ -457,6 +469,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                synthesis_time,
             };
             // Add the assignment to the assignments.
             assignments.write().push((assignment, metrics));
@@ -479,6 +492,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                synthesis_time,
             };
 
             // Add the transition to the trace.
@@ -489,6 +503,24 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 metrics,
             )?;
         }
+        // If the circuit is in `DryRun` mode, then save the assignment, without synthesizing the circuit key.
+        else if let CallStack::DryRun(_, ref assignments) = registers.call_stack() {
+            registers.ensure_console_and_circuit_registers_match()?;
+
+            // Construct the call metrics.
+            let metrics = CallMetrics {
+                program_id: *self.program_id(),
+                function_name: *function.name(),
+                num_instructions: function.instructions().len(),
+                num_request_constraints,
+                num_function_constraints,
+                num_response_constraints,
+                synthesis_time,
+            };
+            // Add the assignment to the assignments.
+            assignments.write().push((assignment, metrics));
+            lap!(timer, "Save the circuit assignment");
+        }
         // If the circuit is in `PackageRun` mode, then save the assignment.
         else if let CallStack::PackageRun(_, _, ref assignments) = registers.call_stack() {
             // Construct the call metrics.
@@ -499,6 +531,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                synthesis_time,
             };
             // Add the assignment to the assignments.
             assignments.write().push((assignment, metrics));
@@ -513,6 +546,19 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Returns the call metrics recorded so far by the given call stack.
+    fn recorded_call_metrics(call_stack: &CallStack<N>) -> Vec<CallMetrics<N>> {
+        match call_stack {
+            CallStack::Execute(_, trace) => trace.read().call_metrics().to_vec(),
+            CallStack::CheckDeployment(_, _, assignments, _, _)
+            | CallStack::DryRun(_, assignments)
+            | CallStack::PackageRun(_, _, assignments) => {
+                assignments.read().iter().map(|(_, metrics)| *metrics).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
     /// Prints the current state of the circuit.
     #[cfg(debug_assertions)]
     pub(crate) fn log_circuit<A: circuit::Aleo<Network = N>, S: Into<String>>(scope: S) {
@@ -636,6 +636,12 @@ impl<N: Network> FinalizeTypes<N> {
             Opcode::Call => {
                 bail!("Instruction 'call' is not allowed in 'finalize'");
             }
+            Opcode::CallView => {
+                bail!("Instruction 'call.view' is not allowed in 'finalize'");
+            }
+            Opcode::CallHost => {
+                bail!("Instruction 'call.host' is not allowed in 'finalize'");
+            }
             Opcode::Cast(opcode) => match opcode {
                 "cast" => {
                     // Retrieve the cast operation.
@@ -663,7 +669,7 @@ impl<N: Network> FinalizeTypes<N> {
                                 bail!("Struct '{struct_name}' is not defined.")
                             }
                             // Retrieve the struct.
-                            let struct_ = stack.program().get_struct(struct_name)?;
+                            let struct_ = stack.get_struct(struct_name)?;
                             // Ensure the operand types match the struct.
                             self.matches_struct(stack, instruction.operands(), struct_)?;
                         }
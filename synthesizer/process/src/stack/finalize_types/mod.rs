@@ -156,7 +156,7 @@ impl<N: Network> FinalizeTypes<N> {
                 // Access the member on the path to output the register type.
                 (FinalizeRefType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(identifier)) => {
                     // Retrieve the member type from the struct and check that it exists.
-                    match stack.program().get_struct(struct_name)?.members().get(identifier) {
+                    match stack.get_struct(struct_name)?.members().get(identifier) {
                         // Retrieve the member and update `finalize_type` for the next iteration.
                         Some(member_type) => finalize_type = FinalizeRefType::Plaintext(member_type),
                         // Halts if the member does not exist.
@@ -213,4 +213,18 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeRefType::Future(locator) => FinalizeType::Future(*locator),
         })
     }
+
+    /// Writes the finalize types to the given writer, for use in the stack snapshot hash.
+    pub(crate) fn write_digest_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        for registers in [&self.inputs, &self.destinations] {
+            // Write the number of registers.
+            u64::try_from(registers.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+            // Write the registers and their types.
+            for (locator, register_type) in registers {
+                locator.write_le(&mut writer)?;
+                register_type.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
 }
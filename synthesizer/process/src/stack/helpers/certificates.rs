@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Returns the fingerprint of the cached verifying keys in the stack.
+    ///
+    /// The fingerprint is a hash over the content hash of the program, and the name and verifying key
+    /// of each function with a cached verifying key. Operators may record the fingerprint to detect
+    /// a corrupted or swapped key cache, without re-synthesizing the circuits.
+    #[inline]
+    pub fn fingerprint(&self) -> Result<Field<N>> {
+        // Initialize the preimage with the content hash of the program.
+        let mut preimage = self.content_hash.to_bytes_le()?;
+        // Append the cached verifying keys, in the order of the functions in the program.
+        let verifying_keys = self.verifying_keys.read();
+        for function_name in self.program.functions().keys() {
+            if let Some(verifying_key) = verifying_keys.get(function_name) {
+                preimage.extend(function_name.to_bytes_le()?);
+                preimage.extend(verifying_key.to_bytes_le()?);
+            }
+        }
+        // Return the fingerprint.
+        N::hash_bhp1024(&preimage.to_bits_le())
+    }
+
+    /// Ensures each cached verifying key is certified for its function in the current program.
+    ///
+    /// For each function with a cached verifying key, the certificate is recomputed from the cached proving key,
+    /// and checked against a freshly-synthesized circuit for the function. This fails if a verifying key
+    /// does not have a cached proving key, as its certificate cannot be recomputed.
+    #[inline]
+    pub fn verify_certificates<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<()> {
+        let timer = timer!("Stack::verify_certificates");
+
+        // Retrieve the program ID.
+        let program_id = self.program.id();
+
+        for function_name in self.program.functions().keys() {
+            // Skip functions without a cached verifying key.
+            if !self.contains_verifying_key(function_name) {
+                continue;
+            }
+            // Retrieve the verifying key.
+            let verifying_key = self.get_verifying_key(function_name)?;
+            // Retrieve the proving key.
+            let proving_key = self.get_proving_key(function_name).map_err(|_| {
+                anyhow!("Cannot certify the verifying key for '{program_id}/{function_name}' without its proving key")
+            })?;
+
+            // Certify the circuit.
+            let certificate = Certificate::certify(&function_name.to_string(), &proving_key, &verifying_key)
+                .map_err(|error| anyhow!("Failed to certify '{program_id}/{function_name}': {error}"))?;
+            lap!(timer, "Certify the circuit for {function_name}");
+
+            // Synthesize the circuit.
+            let assignment = self.synthesize_assignment::<A, R>(function_name, rng)?;
+            lap!(timer, "Synthesize the circuit for {function_name}");
+
+            // Ensure the certificate is valid for the circuit.
+            ensure!(
+                certificate.verify(&function_name.to_string(), &assignment, &verifying_key),
+                "The verifying key for '{program_id}/{function_name}' is not certified for the program"
+            );
+        }
+
+        finish!(timer);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_verify_certificates() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program with two different circuits.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program certificates.aleo;
+
+function add_one:
+    input r0 as u8.private;
+    add r0 1u8 into r1;
+    output r1 as u8.private;
+
+function mul_two:
+    input r0 as u64.private;
+    mul r0 2u64 into r1;
+    output r1 as u64.private;",
+        )?;
+        let add_one = Identifier::from_str("add_one")?;
+        let mul_two = Identifier::from_str("mul_two")?;
+
+        // Initialize the stack.
+        let process = Process::<CurrentNetwork>::load()?;
+        let stack = Stack::new(&process, &program)?;
+        // Ensure a stack without cached keys is trivially certified.
+        let empty_fingerprint = stack.fingerprint()?;
+        stack.verify_certificates::<CurrentAleo, _>(rng)?;
+
+        // Synthesize the keys.
+        stack.synthesize_key::<CurrentAleo, _>(&add_one, rng)?;
+        stack.synthesize_key::<CurrentAleo, _>(&mul_two, rng)?;
+        stack.verify_certificates::<CurrentAleo, _>(rng)?;
+        // Ensure the fingerprint commits to the verifying keys, and is deterministic.
+        let fingerprint = stack.fingerprint()?;
+        assert_ne!(fingerprint, empty_fingerprint);
+        assert_eq!(fingerprint, stack.fingerprint()?);
+
+        // Swap the verifying keys of the functions.
+        let add_one_verifying_key = stack.get_verifying_key(&add_one)?;
+        let mul_two_verifying_key = stack.get_verifying_key(&mul_two)?;
+        stack.insert_verifying_key(&add_one, mul_two_verifying_key.clone())?;
+        stack.insert_verifying_key(&mul_two, add_one_verifying_key.clone())?;
+        // Ensure the swapped keys are detected.
+        assert_ne!(stack.fingerprint()?, fingerprint);
+        assert!(stack.verify_certificates::<CurrentAleo, _>(rng).is_err());
+
+        // Restore the verifying keys, and ensure a verifying key without a proving key is rejected.
+        stack.insert_verifying_key(&add_one, add_one_verifying_key)?;
+        stack.insert_verifying_key(&mul_two, mul_two_verifying_key)?;
+        assert_eq!(stack.fingerprint()?, fingerprint);
+        stack.remove_proving_key(&add_one);
+        assert!(stack.verify_certificates::<CurrentAleo, _>(rng).is_err());
+        Ok(())
+    }
+}
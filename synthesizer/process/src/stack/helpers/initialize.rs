@@ -22,14 +22,20 @@ impl<N: Network> Stack<N> {
         let mut stack = Self {
             program: program.clone(),
             external_stacks: Default::default(),
+            imported_structs: Default::default(),
             register_types: Default::default(),
             finalize_types: Default::default(),
+            inlined_functions: Default::default(),
             universal_srs: process.universal_srs().clone(),
-            proving_keys: Default::default(),
+            proving_keys: Arc::new(process.proving_key_cache().new_scope()),
+            host_functions: process.host_functions().clone(),
             verifying_keys: Default::default(),
+            key_synthesis_locks: Default::default(),
             number_of_calls: Default::default(),
             finalize_costs: Default::default(),
             program_depth: 0,
+            snapshot_hash: Default::default(),
+            content_hash: program.to_content_hash()?,
         };
 
         // Add all the imports into the stack.
@@ -49,6 +55,20 @@ impl<N: Network> Stack<N> {
                 "Program depth exceeds the maximum allowed call depth"
             );
         }
+        // Resolve the struct imports.
+        for (struct_name, locator) in program.struct_imports() {
+            // Retrieve the external stack for the struct import.
+            let external_stack = stack.get_external_stack(locator.program_id())?;
+            // Retrieve the imported struct.
+            let struct_ = external_stack.get_struct(locator.resource())?.clone();
+            // Add the imported struct to the stack.
+            stack.imported_structs.insert(*struct_name, struct_);
+        }
+        // Ensure each imported struct is structurally identical to its definition in the external program.
+        for (struct_name, locator) in program.struct_imports() {
+            stack.check_struct_import(struct_name, stack.get_external_stack(locator.program_id())?)?;
+        }
+
         // Add the program closures to the stack.
         for closure in program.closures().values() {
             // Add the closure to the stack.
@@ -59,6 +79,8 @@ impl<N: Network> Stack<N> {
         for function in program.functions().values() {
             // Add the function to the stack.
             stack.insert_function(function)?;
+            // Ensure the call chains of the function are acyclic, and within the maximum call depth.
+            stack.check_call_chains(function.name(), &mut Vec::new(), process.max_call_depth())?;
             // Determine the number of calls for the function.
             let mut num_calls = 1;
             for instruction in function.instructions() {
@@ -96,6 +118,11 @@ impl<N: Network> Stack<N> {
             stack.finalize_costs.insert(*function.name(), finalize_cost);
         }
 
+        // Inline the small closures into the functions, if enabled.
+        if let Some(max_closure_instructions) = process.closure_inlining() {
+            stack.inline_closures(max_closure_instructions)?;
+        }
+
         // Return the stack.
         Ok(stack)
     }
@@ -113,12 +140,96 @@ impl<N: Network> Stack<N> {
         ensure!(self.program.contains_import(&program_id), "'{program_id}' does not exist in the main program imports");
         // Ensure the external stack is not for the main program.
         ensure!(self.program.id() != external_stack.program_id(), "External stack program cannot be the main program");
+        // Ensure the stack can track another external stack.
+        self.check_external_stack_limit()?;
         // Add the external stack to the stack.
         self.external_stacks.insert(program_id, external_stack);
         // Return success.
         Ok(())
     }
 
+    /// Ensures the given struct resolves to a structurally identical struct in this stack and the external stack,
+    /// including all structs that are nested in its members.
+    fn check_struct_import(&self, struct_name: &Identifier<N>, external_stack: &Stack<N>) -> Result<()> {
+        // Retrieve the struct from this stack.
+        let struct_ = self.get_struct(struct_name)?;
+        // Retrieve the struct from the external stack.
+        let Ok(external_struct) = external_stack.get_struct(struct_name) else {
+            bail!("Struct '{struct_name}' is not defined in '{}'", external_stack.program_id())
+        };
+        // Ensure the structs are identical.
+        ensure!(
+            struct_ == external_struct,
+            "Struct '{struct_name}' in '{}' does not match its definition in '{}'",
+            self.program_id(),
+            external_stack.program_id()
+        );
+        // Ensure the nested structs are structurally identical.
+        for member_type in struct_.members().values() {
+            let nested_name = match member_type {
+                PlaintextType::Literal(_) => continue,
+                PlaintextType::Struct(nested_name) => nested_name,
+                PlaintextType::Array(array_type) => match array_type.base_element_type() {
+                    PlaintextType::Struct(nested_name) => nested_name,
+                    _ => continue,
+                },
+            };
+            // Ensure the nested struct is defined in this program.
+            ensure!(
+                self.program.contains_struct(nested_name),
+                "Struct '{nested_name}' in '{struct_name}' must be declared in '{}'",
+                self.program_id()
+            );
+            self.check_struct_import(nested_name, external_stack)?;
+        }
+        Ok(())
+    }
+
+    /// Ensures the call chains starting from the given function are acyclic, and do not exceed the maximum call depth.
+    /// The given chain contains the calls leading to the function, and is restored once the check succeeds.
+    fn check_call_chains(
+        &self,
+        function_name: &Identifier<N>,
+        chain: &mut Vec<Locator<N>>,
+        max_call_depth: usize,
+    ) -> Result<()> {
+        // Returns the call chain as a string.
+        let display = |chain: &[Locator<N>]| chain.iter().map(|locator| locator.to_string()).join(" -> ");
+
+        // Construct the locator of the function.
+        let locator = Locator::new(*self.program_id(), *function_name);
+        // Ensure the function is not already in the call chain.
+        if chain.contains(&locator) {
+            chain.push(locator);
+            bail!("Detected a cycle in the call chain '{}'", display(chain))
+        }
+        chain.push(locator);
+        // Ensure the call chain does not exceed the maximum call depth.
+        ensure!(
+            chain.len() <= max_call_depth,
+            "The call chain '{}' exceeds the maximum call depth of {max_call_depth}",
+            display(chain)
+        );
+
+        // Check the call chains of each function that is called.
+        for instruction in self.get_function_ref(function_name)?.instructions() {
+            if let Instruction::Call(call) = instruction {
+                if call.is_function_call(self)? {
+                    match call.operator() {
+                        CallOperator::Locator(locator) => self
+                            .get_external_stack(locator.program_id())?
+                            .check_call_chains(locator.resource(), chain, max_call_depth)?,
+                        CallOperator::Resource(resource) => self.check_call_chains(resource, chain, max_call_depth)?,
+                    }
+                }
+            }
+        }
+
+        // Restore the call chain.
+        chain.pop();
+        Ok(())
+    }
+
     /// Inserts the given closure to the stack.
     #[inline]
     fn insert_closure(&mut self, closure: &Closure<N>) -> Result<()> {
@@ -126,6 +237,8 @@ impl<N: Network> Stack<N> {
         let name = closure.name();
         // Ensure the closure name is not already added.
         ensure!(!self.register_types.contains_key(name), "Closure '{name}' already exists");
+        // Ensure the stack can track another closure.
+        self.check_function_limit()?;
 
         // Compute the register types.
         let register_types = RegisterTypes::from_closure(self, closure)?;
@@ -142,6 +255,8 @@ impl<N: Network> Stack<N> {
         let name = function.name();
         // Ensure the function name is not already added.
         ensure!(!self.register_types.contains_key(name), "Function '{name}' already exists");
+        // Ensure the stack can track another function.
+        self.check_function_limit()?;
 
         // Compute the register types.
         let register_types = RegisterTypes::from_function(self, function)?;
@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::Register;
+
+/// A function, with its calls to small closures replaced by the instructions of the closures.
+#[derive(Clone)]
+pub(crate) struct InlinedFunction<N: Network> {
+    /// The function, with its closures inlined.
+    function: Function<N>,
+    /// The register types of the inlined function.
+    register_types: RegisterTypes<N>,
+    /// The mapping of the register locators of the function to the register locators of the inlined function.
+    locators: IndexMap<u64, u64>,
+}
+
+impl<N: Network> InlinedFunction<N> {
+    /// Attaches the given developer-facing names of the registers of the function to the registers of the inlined function.
+    /// Note: A register that is renamed to an already-named register (e.g. a closure output that is one of its inputs)
+    /// keeps the existing name.
+    pub(crate) fn add_aliases(&mut self, aliases: &IndexMap<u64, Identifier<N>>) -> Result<()> {
+        for (locator, alias) in aliases {
+            if let Some(locator) = self.locators.get(locator) {
+                if self.register_types.get_alias(&Register::Locator(*locator)).is_none() {
+                    self.register_types.add_alias(*locator, *alias)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Inlines the calls to closures with at most `max_closure_instructions` instructions into the functions of the program,
+    /// and returns the number of functions with inlined closures. The inlined functions are used in place of the functions
+    /// when evaluating and executing the program, which avoids initializing the registers of each closure call.
+    ///
+    /// The instructions of an inlined function are executed in the same order, on the same operands, as those of the
+    /// function, so the inlined function synthesizes the same circuit, and its proofs verify against the same keys.
+    /// In particular, inlining saves no constraints. To inline the closures of every program that is added to a process,
+    /// use `Process::set_closure_inlining`. The program itself (and thus its deployment and identifiers) is unchanged.
+    /// Note: Evaluations with an observer use the functions as written, as an observer is invoked on the program instructions.
+    pub fn inline_closures(&mut self, max_closure_instructions: usize) -> Result<usize> {
+        // Retrieve the closures that are small enough to inline.
+        let closures = self
+            .program
+            .closures()
+            .values()
+            .filter(|closure| closure.instructions().len() <= max_closure_instructions)
+            .map(|closure| (*closure.name(), closure))
+            .collect::<IndexMap<_, _>>();
+
+        // Inline the closures into the functions.
+        self.inlined_functions.clear();
+        for function in self.program.functions().values() {
+            let outputs = function.outputs().iter().map(|output| output.operand().clone()).collect::<Vec<_>>();
+            let Some((instructions, outputs, operands)) =
+                inline_body(function.inputs().len(), function.instructions(), &outputs, &closures)?
+            else {
+                continue;
+            };
+            // Skip the function if the inlined function exceeds the maximum number of instructions,
+            // or if its output statements are no longer distinct.
+            let output_statements = outputs.iter().zip_eq(function.output_types());
+            if instructions.len() > N::MAX_INSTRUCTIONS || has_duplicates(output_statements) {
+                continue;
+            }
+
+            // Construct the inlined function, and check its register types.
+            let inlined = function.with_body(instructions, outputs)?;
+            let register_types = RegisterTypes::from_function(&*self, &inlined)?;
+            // Retrieve the registers of the function that are renamed to registers of the inlined function.
+            let locators = operands
+                .into_iter()
+                .filter_map(|(locator, operand)| match operand {
+                    Operand::Register(Register::Locator(inlined_locator)) => Some((locator, inlined_locator)),
+                    _ => None,
+                })
+                .collect();
+            let mut inlined = InlinedFunction { function: inlined, register_types, locators };
+            // Carry over the developer-facing names of the registers.
+            inlined.add_aliases(self.get_register_types(function.name())?.aliases())?;
+            self.inlined_functions.insert(*function.name(), inlined);
+        }
+        Ok(self.inlined_functions.len())
+    }
+
+    /// Returns the function with the given name, with its closures inlined, if they have been inlined.
+    pub fn get_inlined_function(&self, name: &Identifier<N>) -> Option<&Function<N>> {
+        self.inlined_functions.get(name).map(|inlined| &inlined.function)
+    }
+
+    /// Returns the function to evaluate or execute, and its register types.
+    /// If the closures of the function have been inlined, this returns the inlined function.
+    pub(crate) fn get_executable_function(&self, name: &Identifier<N>) -> Result<(Function<N>, &RegisterTypes<N>)> {
+        match self.inlined_functions.get(name) {
+            Some(inlined) => Ok((inlined.function.clone(), &inlined.register_types)),
+            None => Ok((self.get_function(name)?, self.get_register_types(name)?)),
+        }
+    }
+}
+
+/// Inlines the calls to the given closures in the given instructions, and returns the inlined instructions,
+/// the inlined output operands, and the mapping of the original registers to their operands in the inlined instructions.
+/// The destination registers are renumbered in order, starting after the inputs. If no call is inlined, this returns `None`.
+#[allow(clippy::type_complexity)]
+fn inline_body<N: Network>(
+    num_inputs: usize,
+    instructions: &[Instruction<N>],
+    outputs: &[Operand<N>],
+    closures: &IndexMap<Identifier<N>, &Closure<N>>,
+) -> Result<Option<(Vec<Instruction<N>>, Vec<Operand<N>>, IndexMap<u64, Operand<N>>)>> {
+    // Initialize the mapping of registers to operands, where the inputs are unchanged.
+    let mut operands: IndexMap<u64, Operand<N>> =
+        (0..num_inputs as u64).map(|locator| (locator, Operand::Register(Register::Locator(locator)))).collect();
+    // Initialize the locator of the next destination register.
+    let mut next_locator = num_inputs as u64;
+
+    let mut inlined = Vec::with_capacity(instructions.len());
+    let mut is_inlined = false;
+    for instruction in instructions {
+        // If the instruction calls a local closure that may be inlined, inline the instructions of the closure.
+        if let Instruction::Call(call) = instruction {
+            if let CallOperator::Resource(name) = call.operator() {
+                if let Some(closure) = closures.get(name) {
+                    // Map the closure inputs to the operands of the call.
+                    let mut closure_operands = call
+                        .operands()
+                        .iter()
+                        .enumerate()
+                        .map(|(locator, operand)| Ok((locator as u64, substitute(&operands, operand)?)))
+                        .collect::<Result<IndexMap<_, _>>>()?;
+                    // Inline the closure instructions.
+                    for instruction in closure.instructions() {
+                        inlined.push(rename(instruction, &mut closure_operands, &mut next_locator)?);
+                    }
+                    // Map the destinations of the call to the closure outputs.
+                    let destinations = call.destinations();
+                    ensure!(destinations.len() == closure.outputs().len(), "Mismatching outputs for closure '{name}'");
+                    for (destination, output) in destinations.iter().zip_eq(closure.outputs()) {
+                        operands.insert(destination.locator(), substitute(&closure_operands, output.operand())?);
+                    }
+                    is_inlined = true;
+                    continue;
+                }
+            }
+        }
+        inlined.push(rename(instruction, &mut operands, &mut next_locator)?);
+    }
+
+    // Map the outputs.
+    let outputs = outputs.iter().map(|operand| substitute(&operands, operand)).collect::<Result<Vec<_>>>()?;
+    Ok(is_inlined.then_some((inlined, outputs, operands)))
+}
+
+/// Returns the given instruction, with its operands substituted, and its destinations renumbered from `next_locator`.
+fn rename<N: Network>(
+    instruction: &Instruction<N>,
+    operands: &mut IndexMap<u64, Operand<N>>,
+    next_locator: &mut u64,
+) -> Result<Instruction<N>> {
+    let mut destinations = Vec::new();
+    let renamed = instruction.map_registers(
+        |operand| substitute(operands, operand),
+        |destination| {
+            let renamed = Register::Locator(*next_locator);
+            *next_locator += 1;
+            destinations.push((destination.locator(), renamed.clone()));
+            Ok(renamed)
+        },
+    )?;
+    // Note: The destinations are mapped after the operands, as an instruction may not read its own destinations.
+    for (locator, renamed) in destinations {
+        operands.insert(locator, Operand::Register(renamed));
+    }
+    Ok(renamed)
+}
+
+/// Returns the given operand, with its register substituted by the given mapping.
+fn substitute<N: Network>(operands: &IndexMap<u64, Operand<N>>, operand: &Operand<N>) -> Result<Operand<N>> {
+    let Operand::Register(register) = operand else { return Ok(operand.clone()) };
+    let Some(substitute) = operands.get(&register.locator()) else { bail!("Register '{register}' is not defined") };
+    match (register, substitute) {
+        (Register::Locator(..), _) => Ok(substitute.clone()),
+        // If the register accesses a member of a register, access the member of the substituted register.
+        (Register::Access(_, path), Operand::Register(Register::Locator(locator))) => {
+            Ok(Operand::Register(Register::Access(*locator, path.clone())))
+        }
+        (Register::Access(_, path), Operand::Register(Register::Access(locator, prefix))) => {
+            Ok(Operand::Register(Register::Access(*locator, [prefix.as_slice(), path.as_slice()].concat())))
+        }
+        (Register::Access(..), _) => bail!("Cannot access a member of '{substitute}' in '{register}'"),
+    }
+}
@@ -129,14 +129,17 @@ impl<N: Network> Stack<N> {
         ensure!(num_entries <= N::MAX_DATA_ENTRIES, "'{record_name}' cannot exceed {} entries", N::MAX_DATA_ENTRIES);
 
         // Ensure the number of record entries match.
+        // Note: A record may omit the optional entries of its record type, such as a record created
+        // before the optional entries were added to the record type.
+        let num_required_entries = record_type.num_required_entries();
         let expected_num_entries = record_type.entries().len();
-        if expected_num_entries != num_entries {
+        if num_entries < num_required_entries || num_entries > expected_num_entries {
             bail!("'{record_name}' expected {expected_num_entries} entries, found {num_entries} entries")
         }
 
         // Ensure the record data match, in the same order.
         for (i, ((expected_name, expected_type), (entry_name, entry))) in
-            record_type.entries().iter().zip_eq(record.data().iter()).enumerate()
+            record_type.entries().iter().take(num_entries).zip_eq(record.data().iter()).enumerate()
         {
             // Ensure the entry name matches.
             if expected_name != entry_name {
@@ -206,7 +209,7 @@ impl<N: Network> Stack<N> {
                 ensure!(!Program::is_reserved_keyword(struct_name), "Struct '{struct_name}' is reserved");
 
                 // Retrieve the struct from the program.
-                let Ok(struct_) = self.program().get_struct(struct_name) else {
+                let Ok(struct_) = self.get_struct(struct_name) else {
                     bail!("Struct '{struct_name}' is not defined in the program")
                 };
 
@@ -14,7 +14,17 @@
 
 use super::*;
 
+mod certificates;
 mod initialize;
+
+mod inline;
+pub(crate) use inline::InlinedFunction;
+
 mod matches;
+mod normalize;
+mod record_owners;
+mod register_aliases;
+pub use register_aliases::RegisterAliases;
 mod sample;
+mod snapshot;
 mod synthesize;
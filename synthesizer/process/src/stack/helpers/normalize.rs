@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Returns the given input, with its struct members and record entries in the order declared by the input type.
+    /// This allows the members of a struct (or the entries of a record) to be given in any order, by name.
+    /// The optional members of a struct that are omitted are set to their default values.
+    ///
+    /// Note: The members are not otherwise checked here, as the input is subsequently matched against its type.
+    pub fn normalize_input(&self, input: Value<N>, input_type: &ValueType<N>) -> Result<Value<N>> {
+        match (input, input_type) {
+            (
+                Value::Plaintext(plaintext),
+                ValueType::Constant(plaintext_type)
+                | ValueType::Public(plaintext_type)
+                | ValueType::Private(plaintext_type),
+            ) => Ok(Value::Plaintext(self.normalize_plaintext(plaintext, plaintext_type, true, 0)?)),
+            (Value::Record(record), ValueType::Record(record_name)) => {
+                Ok(Value::Record(self.normalize_record(record, record_name)?))
+            }
+            (input, _) => Ok(input),
+        }
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Returns the given record, with its entries in the order declared by the record type.
+    ///
+    /// Note: The commitment of a record covers exactly the entries it was created with, so the omitted entries
+    /// (and omitted struct members) of a record are never set to their default values. Instead, a record may only
+    /// omit the trailing optional entries of its record type, such as a record created before they were added.
+    fn normalize_record(
+        &self,
+        record: Record<N, Plaintext<N>>,
+        record_name: &Identifier<N>,
+    ) -> Result<Record<N, Plaintext<N>>> {
+        // Retrieve the record type.
+        let record_type = self.program().get_record(record_name)?;
+        // Ensure the record does not have unknown entries.
+        if let Some(entry_name) = record.data().keys().find(|name| !record_type.entries().contains_key(*name)) {
+            bail!("Record '{record_name}' does not have an entry named '{entry_name}'")
+        }
+        // Ensure the entries omitted by the record are optional.
+        let num_entries = record.data().len();
+        if let Some(entry_name) =
+            record_type.entries().keys().skip(num_entries).find(|name| record_type.default_for(name).is_none())
+        {
+            bail!("Record '{record_name}' is missing the required entry '{entry_name}'")
+        }
+        // Reorder the entries.
+        let (owner, nonce) = (record.owner().clone(), *record.nonce());
+        let mut data = record.into_data();
+        let mut entries = IndexMap::with_capacity(num_entries);
+        for (entry_name, entry_type) in record_type.entries().iter().take(num_entries) {
+            // Ensure the entry is given, as only the trailing entries of a record may be omitted.
+            let Some(entry) = data.swap_remove(entry_name) else {
+                match record_type.default_for(entry_name) {
+                    Some(_) => bail!("Record '{record_name}' omits the optional entry '{entry_name}' before others"),
+                    None => bail!("Record '{record_name}' is missing the required entry '{entry_name}'"),
+                }
+            };
+            let normalize = |plaintext| self.normalize_plaintext(plaintext, entry_type.plaintext_type(), false, 1);
+            let entry = match entry {
+                Entry::Constant(plaintext) => Entry::Constant(normalize(plaintext)?),
+                Entry::Public(plaintext) => Entry::Public(normalize(plaintext)?),
+                Entry::Private(plaintext) => Entry::Private(normalize(plaintext)?),
+            };
+            entries.insert(*entry_name, entry);
+        }
+        Record::<N, Plaintext<N>>::from_plaintext(owner, entries, nonce)
+    }
+
+    /// Returns the given plaintext, with its struct members in the order declared by the plaintext type.
+    /// If `with_defaults` is set, the optional members of a struct that are omitted are set to their default values.
+    fn normalize_plaintext(
+        &self,
+        plaintext: Plaintext<N>,
+        plaintext_type: &PlaintextType<N>,
+        with_defaults: bool,
+        depth: usize,
+    ) -> Result<Plaintext<N>> {
+        // If the depth exceeds the maximum depth, then the plaintext is invalid.
+        ensure!(depth <= N::MAX_DATA_DEPTH, "Plaintext exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
+
+        match (plaintext, plaintext_type) {
+            (Plaintext::Struct(mut members, _), PlaintextType::Struct(struct_name)) => {
+                // Retrieve the struct type.
+                let struct_type = self.get_struct(struct_name)?;
+                // Ensure the struct does not have unknown members.
+                if let Some(member_name) = members.keys().find(|name| !struct_type.members().contains_key(*name)) {
+                    bail!("Struct '{struct_name}' does not have a member named '{member_name}'")
+                }
+                // Reorder the members, and set the omitted optional members to their default values.
+                let members = struct_type
+                    .members()
+                    .iter()
+                    .map(|(member_name, member_type)| {
+                        let member = match (members.swap_remove(member_name), struct_type.default_for(member_name)) {
+                            (Some(member), _) => {
+                                self.normalize_plaintext(member, member_type, with_defaults, depth + 1)?
+                            }
+                            (None, Some(default)) if with_defaults => Plaintext::from(default.clone()),
+                            (None, _) => bail!("Struct '{struct_name}' is missing the member '{member_name}'"),
+                        };
+                        Ok((*member_name, member))
+                    })
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                Ok(Plaintext::Struct(members, Default::default()))
+            }
+            (Plaintext::Array(elements, _), PlaintextType::Array(array_type)) => {
+                // Reorder the members of each element.
+                let elements = elements
+                    .into_iter()
+                    .map(|element| {
+                        self.normalize_plaintext(element, array_type.next_element_type(), with_defaults, depth + 1)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Plaintext::Array(elements, Default::default()))
+            }
+            (plaintext, _) => Ok(plaintext),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::{LiteralType, Register};
+use synthesizer_program::CastType;
+
+use std::collections::HashMap;
+
+impl<N: Network> Stack<N> {
+    /// Ensures every record created by a function in the program is owned by an address that can spend it.
+    ///
+    /// A record is unspendable if its owner is a program address, as a program cannot sign for its records.
+    /// To burn a record deliberately, the function must explicitly assign it to the zero address instead.
+    pub(crate) fn check_record_owners(&self) -> Result<()> {
+        // Compute the addresses of this program and its imports, which cannot spend records.
+        let program_addresses = std::iter::once(self.program.id())
+            .chain(self.program.imports().keys())
+            .map(|program_id| Ok((program_id.to_address()?, *program_id)))
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        for function in self.program.functions().values() {
+            // Initialize a map of registers that hold an unspendable address, to the program that owns the address.
+            let mut unspendable = HashMap::<Register<N>, ProgramID<N>>::new();
+
+            for instruction in function.instructions() {
+                // Records, and the addresses that own them, are only constructed with the `cast` instruction.
+                let Instruction::Cast(cast) = instruction else { continue };
+                // Determine if the first operand is an unspendable address.
+                let owner = match cast.operands().first() {
+                    Some(Operand::ProgramID(program_id)) => Some(*program_id),
+                    Some(Operand::Literal(Literal::Address(address))) => program_addresses.get(address).copied(),
+                    Some(Operand::Register(register)) => unspendable.get(register).copied(),
+                    _ => None,
+                };
+                let Some(program_id) = owner else { continue };
+
+                match cast.cast_type() {
+                    // Ensure the record is not owned by an unspendable address.
+                    CastType::Record(..) | CastType::ExternalRecord(..) => bail!(
+                        "Function '{}' in '{}' creates a record owned by the address of '{program_id}', which cannot \
+                         spend it (to burn the record, assign it to the zero address)",
+                        function.name(),
+                        self.program.id()
+                    ),
+                    // Track the unspendable address through the destination register.
+                    CastType::Plaintext(PlaintextType::Literal(LiteralType::Address)) => {
+                        for destination in cast.destinations() {
+                            unspendable.insert(destination, program_id);
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns a program that creates a record owned by the given operand.
+    fn sample_program(owner: &str) -> Program<CurrentNetwork> {
+        Program::from_str(&format!(
+            r"
+program unspendable.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast unspendable.aleo into r2 as address;
+    cast {owner} r1 into r3 as token.record;
+    output r3 as token.record;"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_check_record_owners() {
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Retrieve the address of the program.
+        let program_address = ProgramID::<CurrentNetwork>::from_str("unspendable.aleo").unwrap().to_address().unwrap();
+
+        // Ensure records owned by a declared operand are accepted.
+        for owner in ["r0", "self.signer", "self.caller", &Address::<CurrentNetwork>::zero().to_string()] {
+            let stack = Stack::new(&process, &sample_program(owner)).unwrap();
+            assert!(stack.check_record_owners().is_ok(), "Expected '{owner}' to be a spendable owner");
+        }
+
+        // Ensure records owned by the program address are rejected.
+        for owner in ["unspendable.aleo", "r2", &program_address.to_string()] {
+            let stack = Stack::new(&process, &sample_program(owner)).unwrap();
+            assert!(stack.check_record_owners().is_err(), "Expected '{owner}' to be an unspendable owner");
+        }
+    }
+}
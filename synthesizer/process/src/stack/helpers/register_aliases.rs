@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The developer-facing names of registers, as a mapping of function and closure names
+/// to a mapping of register locators to their names.
+/// These are local metadata, which is not part of a deployment (see `Process::set_register_aliases`).
+pub type RegisterAliases<N> = IndexMap<Identifier<N>, IndexMap<u64, Identifier<N>>>;
+
+impl<N: Network> Stack<N> {
+    /// Attaches the given developer-facing names to the registers of the functions and closures in the program.
+    /// The names are reported in error messages, and may be inspected by the stepper.
+    pub fn set_register_aliases(&mut self, register_aliases: &RegisterAliases<N>) -> Result<()> {
+        for (name, aliases) in register_aliases {
+            // Retrieve the register types of the function or closure.
+            let Some(register_types) = self.register_types.get_mut(name) else {
+                bail!("Cannot name the registers of '{}/{name}', as it does not exist", self.program.id())
+            };
+            // Attach the names.
+            for (locator, alias) in aliases {
+                register_types.add_alias(*locator, *alias)?;
+            }
+            // Attach the names to the function with its closures inlined, if it exists.
+            if let Some(inlined) = self.inlined_functions.get_mut(name) {
+                inlined.add_aliases(aliases)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::program::Register;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_register_aliases() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program aliases.aleo;
+
+function deposit:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    add r0 r1 into r2;
+    assert.neq r2 r0;
+    output r2 as u64.public;",
+        )?;
+        let function_name = Identifier::from_str("deposit")?;
+
+        // Initialize the stack, and name the registers.
+        let mut process = Process::<CurrentNetwork>::load()?;
+        let mut stack = Stack::new(&process, &program)?;
+        let aliases = IndexMap::from([(
+            function_name,
+            IndexMap::from([(0, Identifier::from_str("balance")?), (2, Identifier::from_str("balance_after")?)]),
+        )]);
+        stack.set_register_aliases(&aliases)?;
+        let register_types = stack.get_register_types(&function_name)?;
+        assert_eq!(register_types.describe(&Register::Locator(2)), "balance_after (r2)");
+        assert_eq!(register_types.describe(&Register::Locator(1)), "r1");
+
+        // Ensure a failing instruction is reported with the register names.
+        let private_key = PrivateKey::new(rng)?;
+        let authorization =
+            stack.authorize::<CurrentAleo, _>(&private_key, function_name, ["3u64", "0u64"].into_iter(), rng)?;
+        let error = stack.step_function::<CurrentAleo>(authorization)?.finish().unwrap_err();
+        assert!(error.to_string().contains("(where r2 = balance_after, r0 = balance)"), "{error}");
+
+        // Ensure the stepper may inspect a register by name.
+        let authorization =
+            stack.authorize::<CurrentAleo, _>(&private_key, function_name, ["3u64", "2u64"].into_iter(), rng)?;
+        let mut stepper = stack.step_function::<CurrentAleo>(authorization)?;
+        stepper.step()?;
+        let balance_after = Identifier::from_str("balance_after")?;
+        assert_eq!(stepper.peek_named_register(&balance_after)?, Value::from_str("5u64")?);
+        assert!(stepper.peek_named_register(&Identifier::from_str("missing")?).is_err());
+
+        // Ensure the register names may be attached to a program in the process.
+        process.add_program(&program)?;
+        process.set_register_aliases(program.id(), &aliases)?;
+        let register_types = process.get_stack(program.id())?.get_register_types(&function_name)?;
+        assert_eq!(register_types.describe(&Register::Locator(2)), "balance_after (r2)");
+
+        // Ensure a register may not be named twice, and names must refer to existing registers.
+        assert!(stack.set_register_aliases(&aliases).is_err());
+        let invalid = IndexMap::from([(function_name, IndexMap::from([(7, Identifier::from_str("missing")?)]))]);
+        assert!(stack.set_register_aliases(&invalid).is_err());
+        Ok(())
+    }
+}
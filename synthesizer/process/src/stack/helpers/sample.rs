@@ -121,7 +121,7 @@ impl<N: Network> Stack<N> {
             // Sample a struct.
             PlaintextType::Struct(struct_name) => {
                 // Retrieve the struct.
-                let struct_ = self.program.get_struct(struct_name)?;
+                let struct_ = self.get_struct(struct_name)?;
                 // Sample each member of the struct.
                 let members = struct_
                     .members()
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Returns the snapshot hash of the stack, computing it on first use.
+    /// The snapshot hash commits to the program, the external stacks, and the register types,
+    /// and allows two stacks to be compared in constant time.
+    #[inline]
+    pub fn snapshot_hash(&self) -> Result<Field<N>> {
+        self.snapshot_hash.get_or_try_init(|| self.compute_snapshot_hash()).copied()
+    }
+
+    /// Returns the content hash of the program in the stack.
+    #[inline]
+    pub const fn content_hash(&self) -> Field<N> {
+        self.content_hash
+    }
+
+    /// Computes the snapshot hash of the stack, as the hash of
+    /// the program hash, the sorted external snapshot hashes, and the register types digest.
+    fn compute_snapshot_hash(&self) -> Result<Field<N>> {
+        // Retrieve the program hash.
+        let program_hash = self.content_hash;
+
+        // Retrieve the external snapshot hashes, sorted to be independent of the import order.
+        let mut external_hashes =
+            self.external_stacks.values().map(|stack| stack.snapshot_hash()).collect::<Result<Vec<_>>>()?;
+        external_hashes.sort_unstable();
+
+        // Serialize the register types and finalize types.
+        let mut types_bytes = Vec::new();
+        for (name, register_types) in &self.register_types {
+            name.write_le(&mut types_bytes)?;
+            register_types.write_digest_le(&mut types_bytes)?;
+        }
+        for (name, finalize_types) in &self.finalize_types {
+            name.write_le(&mut types_bytes)?;
+            finalize_types.write_digest_le(&mut types_bytes)?;
+        }
+        // Compute the register types digest.
+        let types_digest = N::hash_bhp1024(&types_bytes.to_bits_le())?;
+
+        // Construct the preimage.
+        let mut preimage = Vec::with_capacity(external_hashes.len() + 3);
+        preimage.push(program_hash);
+        preimage.push(Field::from_u64(external_hashes.len() as u64));
+        preimage.extend(external_hashes);
+        preimage.push(types_digest);
+        // Compute the snapshot hash.
+        N::hash_psd8(&preimage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_snapshot_hash() -> Result<()> {
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load()?;
+
+        // Initialize two programs that differ only in their register types.
+        let program_a = Program::<CurrentNetwork>::from_str(
+            r"
+program snapshot.aleo;
+
+function compute:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+        )?;
+        let program_b = Program::<CurrentNetwork>::from_str(
+            r"
+program snapshot.aleo;
+
+function compute:
+    input r0 as u16.private;
+    output r0 as u16.private;",
+        )?;
+
+        // Ensure the snapshot hash is deterministic.
+        let stack_a = Stack::new(&process, &program_a)?;
+        assert_ne!(stack_a.snapshot_hash()?, Field::zero());
+        assert_eq!(stack_a.snapshot_hash()?, Stack::new(&process, &program_a)?.snapshot_hash()?);
+        assert_eq!(stack_a, Stack::new(&process, &program_a)?);
+
+        // Ensure different programs have different snapshot hashes.
+        let stack_b = Stack::new(&process, &program_b)?;
+        assert_ne!(stack_a.snapshot_hash()?, stack_b.snapshot_hash()?);
+        assert_ne!(stack_a, stack_b);
+
+        // Ensure the snapshot hash commits to the external stacks.
+        let credits = process.get_stack("credits.aleo")?;
+        assert_ne!(credits.snapshot_hash()?, stack_a.snapshot_hash()?);
+        Ok(())
+    }
+}
@@ -83,6 +83,73 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Synthesizes the circuit for the given function name on sampled inputs, and returns its assignment.
+    /// Note: This method does *not* synthesize the proving key or verifying key.
+    #[inline]
+    pub fn synthesize_assignment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<circuit::Assignment<N::Field>> {
+        // Retrieve the program ID.
+        let program_id = self.program_id();
+        // Retrieve the function input types.
+        let input_types = self.get_function(function_name)?.input_types();
+
+        // Initialize a burner private key.
+        let burner_private_key = PrivateKey::new(rng)?;
+        // Compute the burner address.
+        let burner_address = Address::try_from(&burner_private_key)?;
+        // Sample the inputs.
+        let inputs = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                ValueType::ExternalRecord(locator) => {
+                    // Retrieve the external stack.
+                    let stack = self.get_external_stack(locator.program_id())?;
+                    // Sample the input.
+                    stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
+                }
+                _ => self.sample_value(&burner_address, input_type, rng),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Sample 'is_root'.
+        let is_root = true;
+
+        // The `root_tvk` is `None` when synthesizing an individual circuit.
+        let root_tvk = None;
+
+        // The caller is `None` when synthesizing an individual circuit.
+        let caller = None;
+
+        // Compute the request, with a burner private key.
+        let request = Request::sign(
+            &burner_private_key,
+            *program_id,
+            *function_name,
+            inputs.into_iter(),
+            &input_types,
+            root_tvk,
+            is_root,
+            rng,
+        )?;
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the call stack, without a constraint limit or variable limit.
+        let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone(), None, None);
+        // Synthesize the circuit.
+        if let Err(err) = self.execute_function::<A, R>(call_stack, caller, root_tvk, rng) {
+            bail!("Failed to synthesize the circuit for '{program_id}/{function_name}': {err}")
+        }
+
+        // Retrieve the assignment.
+        let assignments = assignments.read();
+        match assignments.last() {
+            Some((assignment, _metrics)) => Ok(assignment.clone()),
+            None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
+        }
+    }
+
     /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
     #[inline]
     pub fn synthesize_from_assignment(
@@ -95,6 +162,14 @@ impl<N: Network> Stack<N> {
             return Ok(());
         }
 
+        // Acquire the synthesis lock for this function, so that concurrent synthesis (e.g. in the background) is not repeated.
+        let lock = self.key_synthesis_locks.lock().entry(*function_name).or_default().clone();
+        let _guard = lock.lock();
+        // If the proving and verifying key were synthesized while waiting for the lock, skip the synthesis for this function.
+        if self.contains_proving_key(function_name) && self.contains_verifying_key(function_name) {
+            return Ok(());
+        }
+
         // Synthesize the proving and verifying key.
         let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?;
         // Insert the proving key.
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::plaintext_size_in_bytes;
+use console::program::LiteralType;
+
+use std::time::{Duration, Instant};
+
+/// The resource limits for the console evaluation of an untrusted program.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EvaluationLimits {
+    /// The maximum number of bytes held in the registers of a single closure or function.
+    max_register_bytes: usize,
+    /// The maximum number of bytes of a single value stored in a register.
+    max_value_size: usize,
+    /// The maximum wall-clock time of the evaluation.
+    timeout: Duration,
+}
+
+impl EvaluationLimits {
+    /// Initializes new evaluation limits.
+    pub const fn new(max_register_bytes: usize, max_value_size: usize, timeout: Duration) -> Self {
+        Self { max_register_bytes, max_value_size, timeout }
+    }
+
+    /// Returns the maximum number of bytes held in the registers of a single closure or function.
+    pub const fn max_register_bytes(&self) -> usize {
+        self.max_register_bytes
+    }
+
+    /// Returns the maximum number of bytes of a single value stored in a register.
+    pub const fn max_value_size(&self) -> usize {
+        self.max_value_size
+    }
+
+    /// Returns the maximum wall-clock time of the evaluation.
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+impl<N: Network> CallStack<N> {
+    /// Initializes a call stack as `Self::Evaluate` with the given limits, starting the clock for the timeout.
+    pub fn evaluate_with_limits(authorization: Authorization<N>, limits: EvaluationLimits) -> Result<Self> {
+        Self::evaluate_with_options(authorization, EvaluateOptions::default().with_limits(limits))
+    }
+
+    /// Returns the evaluation limits, if the call stack is `Self::Evaluate` with limits.
+    pub fn evaluation_limits(&self) -> Option<&EvaluationLimits> {
+        self.evaluate_options().and_then(|options| options.limits()).map(|(limits, _)| limits)
+    }
+
+    /// Ensures the evaluation has not exceeded its timeout.
+    /// This is checked before each instruction is evaluated (see `Stack::evaluate_instruction`).
+    pub fn ensure_within_timeout(&self) -> Result<()> {
+        if let Some((limits, start)) = self.evaluate_options().and_then(|options| options.limits()) {
+            ensure!(
+                start.elapsed() <= limits.timeout(),
+                "Evaluation exceeded the timeout of {} ms",
+                limits.timeout().as_millis()
+            );
+        }
+        Ok(())
+    }
+
+    /// Ensures a value of the given size may be stored in registers already holding the given number of bytes.
+    pub fn ensure_within_register_limits(&self, value_size: usize, register_bytes: usize) -> Result<()> {
+        if let Some(limits) = self.evaluation_limits() {
+            // Ensure the value does not exceed the maximum value size.
+            ensure!(
+                value_size <= limits.max_value_size(),
+                "Value of {value_size} bytes exceeds the maximum value size of {} bytes",
+                limits.max_value_size()
+            );
+            // Ensure the registers do not exceed the maximum register bytes.
+            ensure!(
+                register_bytes.saturating_add(value_size) <= limits.max_register_bytes(),
+                "Registers exceed the maximum of {} bytes",
+                limits.max_register_bytes()
+            );
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Ensures the destinations of the given instruction are within the evaluation limits, before it is evaluated.
+    ///
+    /// The size of each destination is bounded by its register type, so an instruction that would produce an
+    /// oversized value halts before the value is built. As every operand was stored within the limits, this also
+    /// bounds the work of a single instruction, and thus how far it may overrun the timeout.
+    /// Note: The bound is conservative (e.g. a string is counted at its maximum size), and the exact size of
+    /// each value is still checked when it is stored (see `RegistersStore::store`).
+    pub(crate) fn ensure_destinations_within_limits<A: circuit::Aleo<Network = N>>(
+        &self,
+        registers: &Registers<N, A>,
+        instruction: &Instruction<N>,
+    ) -> Result<()> {
+        // If the call stack has no evaluation limits, return early.
+        if registers.call_stack_ref().evaluation_limits().is_none() {
+            return Ok(());
+        }
+        // Initialize a tracker for the number of bytes held in the registers.
+        let mut register_bytes = registers.console_register_bytes();
+        for destination in instruction.destinations() {
+            // Bound the size of the destination by its register type.
+            let size = match registers.register_types().get_type(self, &destination)? {
+                RegisterType::Plaintext(plaintext_type) => plaintext_size_in_bytes(self, &plaintext_type)?,
+                RegisterType::Record(record_name) => {
+                    // Retrieve the record type from the program.
+                    let record_type = self.program().get_record(&record_name)?;
+                    // Add the size of the owner to the size of the entries.
+                    let owner_size = LiteralType::Address.size_in_bytes::<N>() as u64;
+                    record_type.entries().values().try_fold(owner_size, |acc, entry_type| -> Result<u64> {
+                        Ok(acc.saturating_add(plaintext_size_in_bytes(self, entry_type.plaintext_type())?))
+                    })?
+                }
+                // External records and futures are only output by calls, and are checked when they are stored.
+                RegisterType::ExternalRecord(..) | RegisterType::Future(..) => continue,
+            };
+            let size = usize::try_from(size).unwrap_or(usize::MAX);
+            // Ensure the destination is within the limits.
+            registers.call_stack_ref().ensure_within_register_limits(size, register_bytes)?;
+            register_bytes = register_bytes.saturating_add(size);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::Register;
+use synthesizer_program::Command;
+
+use std::collections::HashSet;
+
+/// The scope of a program lint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LintScope<N: Network> {
+    /// The body of a closure.
+    Closure(Identifier<N>),
+    /// The body of a function.
+    Function(Identifier<N>),
+    /// The finalize logic of a function.
+    Finalize(Identifier<N>),
+}
+
+impl<N: Network> Display for LintScope<N> {
+    /// Prints the scope as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Closure(name) => write!(f, "closure '{name}'"),
+            Self::Function(name) => write!(f, "function '{name}'"),
+            Self::Finalize(name) => write!(f, "finalize '{name}'"),
+        }
+    }
+}
+
+/// A diagnostic of a program, which does not prevent the program from being deployed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProgramLint<N: Network> {
+    /// A register is assigned, but its value is never read.
+    UnusedRegister { scope: LintScope<N>, register: Register<N>, description: String },
+    /// A finalize command can never be reached, as it follows a branch that is always taken.
+    UnreachableCommand { scope: LintScope<N>, index: usize, command: String },
+    /// A closure is never called by the program.
+    UnusedClosure { closure: Identifier<N> },
+}
+
+impl<N: Network> Display for ProgramLint<N> {
+    /// Prints the lint as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::UnusedRegister { scope, description, .. } => {
+                write!(f, "In {scope}, register {description} is assigned, but never read")
+            }
+            Self::UnreachableCommand { scope, index, command } => {
+                write!(f, "In {scope}, command {index} ('{command}') is unreachable")
+            }
+            Self::UnusedClosure { closure } => write!(f, "Closure '{closure}' is never called"),
+        }
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Returns the lints of the program: registers that are assigned but never read,
+    /// finalize commands that can never be reached, and closures that are never called.
+    ///
+    /// The lints are diagnostics for the developer, and do not affect whether the program may be deployed.
+    /// Note: The instructions of a function or closure precede its outputs, so only finalize logic,
+    /// which may branch, can contain unreachable code.
+    pub fn lint(&self) -> Result<Vec<ProgramLint<N>>> {
+        let mut lints = Vec::new();
+        // Initialize the set of closures that are called by the program.
+        let mut called_closures = HashSet::new();
+
+        // Lint the closures.
+        for closure in self.program.closures().values() {
+            let register_types = self.get_register_types(closure.name())?;
+            let writes = closure
+                .inputs()
+                .iter()
+                .map(|input| input.register().clone())
+                .chain(closure.instructions().iter().flat_map(|instruction| instruction.destinations()));
+            let reads = closure
+                .instructions()
+                .iter()
+                .flat_map(|instruction| instruction.operands().iter().cloned())
+                .chain(closure.outputs().iter().map(|output| output.operand().clone()));
+            let scope = LintScope::Closure(*closure.name());
+            lints.extend(unused_registers(scope, writes, reads, Some(register_types)));
+            called_closures.extend(closure.instructions().iter().filter_map(local_call));
+        }
+
+        // Lint the functions.
+        for function in self.program.functions().values() {
+            let register_types = self.get_register_types(function.name())?;
+            let writes = function
+                .inputs()
+                .iter()
+                .map(|input| input.register().clone())
+                .chain(function.instructions().iter().flat_map(|instruction| instruction.destinations()));
+            let reads = function
+                .instructions()
+                .iter()
+                .flat_map(|instruction| instruction.operands().iter().cloned())
+                .chain(function.outputs().iter().map(|output| output.operand().clone()));
+            let scope = LintScope::Function(*function.name());
+            lints.extend(unused_registers(scope, writes, reads, Some(register_types)));
+            called_closures.extend(function.instructions().iter().filter_map(local_call));
+
+            // Lint the finalize logic.
+            if let Some(finalize) = function.finalize_logic() {
+                let writes = finalize
+                    .inputs()
+                    .iter()
+                    .map(|input| input.register().clone())
+                    .chain(finalize.commands().iter().flat_map(|command| command.destinations()));
+                let reads = finalize.commands().iter().flat_map(|command| command.operands());
+                let scope = LintScope::Finalize(*function.name());
+                lints.extend(unused_registers(scope.clone(), writes, reads, None));
+                lints.extend(unreachable_commands(scope, finalize.commands()));
+            }
+        }
+
+        // Lint the closures that are never called.
+        for name in self.program.closures().keys() {
+            if !called_closures.contains(name) {
+                lints.push(ProgramLint::UnusedClosure { closure: *name });
+            }
+        }
+        Ok(lints)
+    }
+}
+
+/// Returns the name of the closure or function called by the given instruction, if it is a local call.
+fn local_call<N: Network>(instruction: &Instruction<N>) -> Option<Identifier<N>> {
+    match instruction {
+        Instruction::Call(call) => match call.operator() {
+            CallOperator::Resource(name) => Some(*name),
+            CallOperator::Locator(_) => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns a lint for each of the given written registers that is not read by any of the given operands.
+fn unused_registers<N: Network>(
+    scope: LintScope<N>,
+    writes: impl Iterator<Item = Register<N>>,
+    reads: impl Iterator<Item = Operand<N>>,
+    register_types: Option<&RegisterTypes<N>>,
+) -> Vec<ProgramLint<N>> {
+    // Collect the locators of the registers that are read, including those that are accessed by a path.
+    let reads = reads
+        .filter_map(|operand| match operand {
+            Operand::Register(register) => Some(register.locator()),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+    writes
+        .filter(|register| !reads.contains(&register.locator()))
+        .map(|register| {
+            let description = match register_types {
+                Some(register_types) => register_types.describe(&register),
+                None => register.to_string(),
+            };
+            ProgramLint::UnusedRegister { scope: scope.clone(), register, description }
+        })
+        .collect()
+}
+
+/// Returns a lint for each of the given finalize commands that follows a branch that is always taken,
+/// and precedes the position of that branch.
+fn unreachable_commands<N: Network>(scope: LintScope<N>, commands: &[Command<N>]) -> Vec<ProgramLint<N>> {
+    let mut lints = Vec::new();
+    // Initialize the positions that are branched to by a reachable command.
+    let mut targets = HashSet::new();
+    // Note: Branches may only jump forward, so a single pass suffices.
+    let mut is_reachable = true;
+    for (index, command) in commands.iter().enumerate() {
+        match command {
+            // A position is reachable if the previous command falls through to it, or if a reachable branch targets it.
+            Command::Position(position) => is_reachable |= targets.contains(position.name()),
+            _ if !is_reachable => lints.push(ProgramLint::UnreachableCommand {
+                scope: scope.clone(),
+                index,
+                command: command.to_string(),
+            }),
+            // A `branch.eq` on identical operands is always taken.
+            Command::BranchEq(branch) => {
+                targets.insert(*branch.position());
+                is_reachable = branch.first() != branch.second();
+            }
+            // A `branch.neq` on distinct literals is always taken.
+            Command::BranchNeq(branch) => {
+                targets.insert(*branch.position());
+                is_reachable = !matches!(
+                    (branch.first(), branch.second()),
+                    (Operand::Literal(first), Operand::Literal(second)) if first != second
+                );
+            }
+            _ => {}
+        }
+    }
+    lints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_lint() -> Result<()> {
+        // Initialize a program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program lint.aleo;
+
+mapping counts:
+    key as u8.public;
+    value as u64.public;
+
+closure double:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+closure triple:
+    input r0 as u64;
+    mul r0 3u64 into r1;
+    output r1 as u64;
+
+function tally:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    call double r0 into r2;
+    add r2 1u64 into r3;
+    async tally r2 into r4;
+    output r4 as lint.aleo/tally.future;
+
+finalize tally:
+    input r0 as u64.public;
+    branch.eq r0 r0 to end;
+    set r0 into counts[0u8];
+    position end;
+    set r0 into counts[1u8];",
+        )?;
+
+        // Initialize the stack.
+        let process = Process::<CurrentNetwork>::load()?;
+        let stack = Stack::new(&process, &program)?;
+        let function_name = Identifier::from_str("tally")?;
+
+        // Ensure the unused registers, unreachable command, and uncalled closure are reported.
+        let lints = stack.lint()?;
+        assert_eq!(lints.len(), 4, "{lints:?}");
+        assert_eq!(lints[0], ProgramLint::UnusedRegister {
+            scope: LintScope::Function(function_name),
+            register: Register::Locator(1),
+            description: "r1".to_string(),
+        });
+        assert!(matches!(&lints[1], ProgramLint::UnusedRegister { register: Register::Locator(3), .. }));
+        assert!(matches!(&lints[2], ProgramLint::UnreachableCommand { index: 1, .. }));
+        assert_eq!(lints[3], ProgramLint::UnusedClosure { closure: Identifier::from_str("triple")? });
+        assert_eq!(lints[3].to_string(), "Closure 'triple' is never called");
+        Ok(())
+    }
+}
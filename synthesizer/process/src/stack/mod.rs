@@ -12,31 +12,52 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod abi;
+pub use abi::*;
+
 mod authorization;
 pub use authorization::*;
 
 mod call;
 pub use call::*;
 
+mod cancellation;
+pub use cancellation::*;
+
 mod finalize_registers;
 pub use finalize_registers::*;
 
 mod finalize_types;
 pub use finalize_types::*;
 
+mod limits;
+pub use limits::*;
+
+mod lint;
+pub use lint::*;
+
+mod observer;
+pub use observer::*;
+
 mod register_types;
 pub use register_types::*;
 
 mod registers;
 pub use registers::*;
 
+mod step;
+pub use step::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
 mod helpers;
+pub use helpers::RegisterAliases;
+use helpers::InlinedFunction;
+mod query;
 
-use crate::{cost_in_microcredits, traits::*, CallMetrics, Process, Trace};
+use crate::{cost_in_microcredits, traits::*, CallMetrics, HostFunctions, Process, StackProvingKeys, Trace};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -58,19 +79,24 @@ use console::{
         RegisterType,
         Request,
         Response,
+        StructType,
         Value,
         ValueType,
     },
     types::{Field, Group},
 };
 use ledger_block::{Deployment, Transition};
-use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
-use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
+use synthesizer_program::{traits::*, CallOperator, Closure, Function, HostFunction, Instruction, Operand, Program};
+use synthesizer_snark::{Certificate, ProvingKey, ProvingKeyBundle, SynthesizerBinding, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
-use parking_lot::RwLock;
-use std::sync::Arc;
+use once_cell::sync::OnceCell;
+use parking_lot::{Mutex, RwLock};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -82,21 +108,32 @@ pub enum CallStack<N: Network> {
     Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>, Option<u64>, Option<u64>),
-    Evaluate(Authorization<N>),
+    Evaluate(Authorization<N>, EvaluateOptions<N>),
     Execute(Authorization<N>, Arc<RwLock<Trace<N>>>),
+    DryRun(Authorization<N>, Assignments<N>),
     PackageRun(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
 }
 
 impl<N: Network> CallStack<N> {
     /// Initializes a call stack as `Self::Evaluate`.
     pub fn evaluate(authorization: Authorization<N>) -> Result<Self> {
-        Ok(CallStack::Evaluate(authorization))
+        Self::evaluate_with_options(authorization, EvaluateOptions::default())
+    }
+
+    /// Initializes a call stack as `Self::Evaluate`, with the given evaluation options.
+    pub fn evaluate_with_options(authorization: Authorization<N>, options: EvaluateOptions<N>) -> Result<Self> {
+        Ok(CallStack::Evaluate(authorization, options))
     }
 
     /// Initializes a call stack as `Self::Execute`.
     pub fn execute(authorization: Authorization<N>, trace: Arc<RwLock<Trace<N>>>) -> Result<Self> {
         Ok(CallStack::Execute(authorization, trace))
     }
+
+    /// Initializes a call stack as `Self::DryRun`.
+    pub fn dry_run(authorization: Authorization<N>, assignments: Assignments<N>) -> Result<Self> {
+        Ok(CallStack::DryRun(authorization, assignments))
+    }
 }
 
 impl<N: Network> CallStack<N> {
@@ -118,10 +155,15 @@ impl<N: Network> CallStack<N> {
                     *variable_limit,
                 )
             }
-            CallStack::Evaluate(authorization) => CallStack::Evaluate(authorization.replicate()),
+            CallStack::Evaluate(authorization, options) => {
+                CallStack::Evaluate(authorization.replicate(), options.clone())
+            }
             CallStack::Execute(authorization, trace) => {
                 CallStack::Execute(authorization.replicate(), Arc::new(RwLock::new(trace.read().clone())))
             }
+            CallStack::DryRun(authorization, assignments) => {
+                CallStack::DryRun(authorization.replicate(), Arc::new(RwLock::new(assignments.read().clone())))
+            }
             CallStack::PackageRun(requests, private_key, assignments) => {
                 CallStack::PackageRun(requests.clone(), *private_key, Arc::new(RwLock::new(assignments.read().clone())))
             }
@@ -135,8 +177,9 @@ impl<N: Network> CallStack<N> {
             | CallStack::Synthesize(requests, ..)
             | CallStack::CheckDeployment(requests, ..)
             | CallStack::PackageRun(requests, ..) => requests.push(request),
-            CallStack::Evaluate(authorization) => authorization.push(request),
-            CallStack::Execute(authorization, ..) => authorization.push(request),
+            CallStack::Evaluate(authorization, ..)
+            | CallStack::Execute(authorization, ..)
+            | CallStack::DryRun(authorization, ..) => authorization.push(request),
         }
         Ok(())
     }
@@ -150,8 +193,9 @@ impl<N: Network> CallStack<N> {
             | CallStack::PackageRun(requests, ..) => {
                 requests.pop().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.next(),
-            CallStack::Execute(authorization, ..) => authorization.next(),
+            CallStack::Evaluate(authorization, ..)
+            | CallStack::Execute(authorization, ..)
+            | CallStack::DryRun(authorization, ..) => authorization.next(),
         }
     }
 
@@ -164,10 +208,77 @@ impl<N: Network> CallStack<N> {
             | CallStack::PackageRun(requests, ..) => {
                 requests.last().cloned().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.peek_next(),
-            CallStack::Execute(authorization, ..) => authorization.peek_next(),
+            CallStack::Evaluate(authorization, ..)
+            | CallStack::Execute(authorization, ..)
+            | CallStack::DryRun(authorization, ..) => authorization.peek_next(),
         }
     }
+
+    /// Returns the evaluation options, if the call stack is `Self::Evaluate`.
+    pub const fn evaluate_options(&self) -> Option<&EvaluateOptions<N>> {
+        match self {
+            CallStack::Evaluate(_, options) => Some(options),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the instructions are evaluated in constant time.
+    pub const fn is_constant_time(&self) -> bool {
+        matches!(self, CallStack::Evaluate(_, options) if options.is_constant_time())
+    }
+}
+
+/// The options for the console evaluation of a call stack.
+#[derive(Clone)]
+pub struct EvaluateOptions<N: Network> {
+    /// Whether the instructions are evaluated in constant time.
+    constant_time: bool,
+    /// The resource limits, with the instant at which the clock for the timeout started.
+    limits: Option<(EvaluationLimits, Instant)>,
+    /// The observer, which is invoked before each instruction is evaluated.
+    observer: Option<Arc<dyn EvaluationObserver<N>>>,
+}
+
+impl<N: Network> Default for EvaluateOptions<N> {
+    /// Returns the default options, which evaluate without constant time, limits, or an observer.
+    fn default() -> Self {
+        Self { constant_time: false, limits: None, observer: None }
+    }
+}
+
+impl<N: Network> EvaluateOptions<N> {
+    /// Returns the options, with the instructions evaluated in constant time.
+    pub fn with_constant_time(mut self) -> Self {
+        self.constant_time = true;
+        self
+    }
+
+    /// Returns the options, with the given resource limits, starting the clock for the timeout.
+    pub fn with_limits(mut self, limits: EvaluationLimits) -> Self {
+        self.limits = Some((limits, Instant::now()));
+        self
+    }
+
+    /// Returns the options, with the given observer.
+    pub fn with_observer(mut self, observer: Arc<dyn EvaluationObserver<N>>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Returns `true` if the instructions are evaluated in constant time.
+    pub const fn is_constant_time(&self) -> bool {
+        self.constant_time
+    }
+
+    /// Returns the resource limits, with the instant at which the clock for the timeout started.
+    pub const fn limits(&self) -> Option<&(EvaluationLimits, Instant)> {
+        self.limits.as_ref()
+    }
+
+    /// Returns the observer.
+    pub const fn observer(&self) -> Option<&Arc<dyn EvaluationObserver<N>>> {
+        self.observer.as_ref()
+    }
 }
 
 #[derive(Clone)]
@@ -176,22 +287,35 @@ pub struct Stack<N: Network> {
     program: Program<N>,
     /// The mapping of external stacks as `(program ID, stack)`.
     external_stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The mapping of imported struct names to their resolved struct types.
+    imported_structs: IndexMap<Identifier<N>, StructType<N>>,
     /// The mapping of closure and function names to their register types.
     register_types: IndexMap<Identifier<N>, RegisterTypes<N>>,
     /// The mapping of finalize names to their register types.
     finalize_types: IndexMap<Identifier<N>, FinalizeTypes<N>>,
+    /// The mapping of function names to the functions with their closures inlined.
+    inlined_functions: IndexMap<Identifier<N>, InlinedFunction<N>>,
     /// The universal SRS.
     universal_srs: Arc<UniversalSRS<N>>,
-    /// The mapping of function name to proving key.
-    proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
+    /// The proving keys, stored in the proving key cache of the process.
+    proving_keys: Arc<StackProvingKeys<N>>,
+    /// The host functions of the process.
+    host_functions: HostFunctions<N>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The mapping of function name to the lock held while its circuit keys are synthesized.
+    key_synthesis_locks: Arc<Mutex<IndexMap<Identifier<N>, Arc<Mutex<()>>>>>,
     /// The mapping of function names to the number of calls.
     number_of_calls: IndexMap<Identifier<N>, usize>,
     /// The mapping of function names to finalize cost.
     finalize_costs: IndexMap<Identifier<N>, u64>,
     /// The program depth.
     program_depth: usize,
+    /// The snapshot hash, which commits to the program, external stacks, and register types.
+    /// It is computed on first use, as most stacks are never compared by their snapshot hash.
+    snapshot_hash: OnceCell<Field<N>>,
+    /// The content hash of the program.
+    content_hash: Field<N>,
 }
 
 impl<N: Network> Stack<N> {
@@ -277,6 +401,15 @@ impl<N: Network> StackProgram<N> for Stack<N> {
         external_program.get_record(locator.resource())
     }
 
+    /// Returns the struct with the given name, resolving structs imported from other programs.
+    #[inline]
+    fn get_struct(&self, struct_name: &Identifier<N>) -> Result<&StructType<N>> {
+        match self.imported_structs.get(struct_name) {
+            Some(struct_) => Ok(struct_),
+            None => self.program.get_struct(struct_name),
+        }
+    }
+
     /// Returns the expected finalize cost for the given function name.
     #[inline]
     fn get_finalize_cost(&self, function_name: &Identifier<N>) -> Result<u64> {
@@ -307,6 +440,12 @@ impl<N: Network> StackProgram<N> for Stack<N> {
             .ok_or_else(|| anyhow!("Function '{function_name}' does not exist"))
     }
 
+    /// Returns the host function with the given name.
+    #[inline]
+    fn get_host_function(&self, name: &Identifier<N>) -> Result<Arc<HostFunction<N>>> {
+        self.host_functions.get(name)
+    }
+
     /// Returns a value for the given value type.
     fn sample_value<R: Rng + CryptoRng>(
         &self,
@@ -368,7 +507,7 @@ impl<N: Network> Stack<N> {
     /// Returns `true` if the proving key for the given function name exists.
     #[inline]
     pub fn contains_proving_key(&self, function_name: &Identifier<N>) -> bool {
-        self.proving_keys.read().contains_key(function_name)
+        self.proving_keys.contains(function_name)
     }
 
     /// Returns `true` if the verifying key for the given function name exists.
@@ -383,8 +522,8 @@ impl<N: Network> Stack<N> {
         // If the program is 'credits.aleo', try to load the proving key, if it does not exist.
         self.try_insert_credits_function_proving_key(function_name)?;
         // Return the proving key, if it exists.
-        match self.proving_keys.read().get(function_name) {
-            Some(proving_key) => Ok(proving_key.clone()),
+        match self.proving_keys.get(function_name)? {
+            Some(proving_key) => Ok(proving_key),
             None => bail!("Proving key not found for: {}/{function_name}", self.program.id()),
         }
     }
@@ -399,6 +538,25 @@ impl<N: Network> Stack<N> {
         }
     }
 
+    /// Returns the proving keys in the stack as a bundle, with identical blocks shared across functions.
+    /// The function names are returned in the order of the proving keys in the bundle.
+    #[inline]
+    pub fn to_proving_key_bundle(&self) -> Result<(Vec<Identifier<N>>, ProvingKeyBundle<N>)> {
+        // Retrieve the proving keys, in the order of the functions in the program.
+        let mut function_names = Vec::new();
+        let mut proving_keys = Vec::new();
+        for function_name in self.program.functions().keys() {
+            if let Some(proving_key) = self.proving_keys.get(function_name)? {
+                function_names.push(*function_name);
+                proving_keys.push(proving_key);
+            }
+        }
+        // Construct the bundle.
+        let bundle = ProvingKeyBundle::new(&proving_keys)?;
+        // Return the function names and bundle.
+        Ok((function_names, bundle))
+    }
+
     /// Inserts the given proving key for the given function name.
     #[inline]
     pub fn insert_proving_key(&self, function_name: &Identifier<N>, proving_key: ProvingKey<N>) -> Result<()> {
@@ -409,8 +567,7 @@ impl<N: Network> Stack<N> {
             self.program.id()
         );
         // Insert the proving key.
-        self.proving_keys.write().insert(*function_name, proving_key);
-        Ok(())
+        self.proving_keys.insert(function_name, proving_key)
     }
 
     /// Inserts the given verifying key for the given function name.
@@ -430,7 +587,7 @@ impl<N: Network> Stack<N> {
     /// Removes the proving key for the given function name.
     #[inline]
     pub fn remove_proving_key(&self, function_name: &Identifier<N>) {
-        self.proving_keys.write().shift_remove(function_name);
+        self.proving_keys.remove(function_name);
     }
 
     /// Removes the verifying key for the given function name.
@@ -444,9 +601,7 @@ impl<N: Network> Stack<N> {
     /// Inserts the proving key if the program ID is 'credits.aleo'.
     fn try_insert_credits_function_proving_key(&self, function_name: &Identifier<N>) -> Result<()> {
         // If the program is 'credits.aleo' and it does not exist yet, load the proving key directly.
-        if self.program_id() == &ProgramID::from_str("credits.aleo")?
-            && !self.proving_keys.read().contains_key(function_name)
-        {
+        if self.program_id() == &ProgramID::from_str("credits.aleo")? && !self.proving_keys.contains(function_name) {
             // Load the 'credits.aleo' function proving key.
             let proving_key = N::get_credits_proving_key(function_name.to_string())?;
             // Insert the 'credits.aleo' function proving key.
@@ -457,6 +612,8 @@ impl<N: Network> Stack<N> {
 }
 
 impl<N: Network> PartialEq for Stack<N> {
+    /// Returns `true` if the stacks are equal.
+    /// Note: To compare stacks in constant time, compare their snapshot hashes (see `Stack::snapshot_hash`).
     fn eq(&self, other: &Self) -> bool {
         self.program == other.program
             && self.external_stacks == other.external_stacks
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::Register;
+
+/// An observer of the console evaluation of a program, for tooling such as an instruction debugger.
+pub trait EvaluationObserver<N: Network>: Send + Sync {
+    /// Invoked before the instruction at the given index of the given closure or function is evaluated,
+    /// with the destination registers of the instruction and the resolved values of its operands.
+    /// If an error is returned, the evaluation halts with the error.
+    fn on_instruction(
+        &self,
+        program_id: &ProgramID<N>,
+        resource: &Identifier<N>,
+        index: usize,
+        instruction: &Instruction<N>,
+        destinations: &[Register<N>],
+        operands: &[Value<N>],
+    ) -> Result<()>;
+}
+
+impl<N: Network> CallStack<N> {
+    /// Initializes a call stack as `Self::Evaluate` with the given observer.
+    pub fn evaluate_with_observer(
+        authorization: Authorization<N>,
+        observer: Arc<dyn EvaluationObserver<N>>,
+    ) -> Result<Self> {
+        Self::evaluate_with_options(authorization, EvaluateOptions::default().with_observer(observer))
+    }
+
+    /// Returns the evaluation observer, if the call stack is `Self::Evaluate` with an observer.
+    pub fn evaluation_observer(&self) -> Option<&Arc<dyn EvaluationObserver<N>>> {
+        self.evaluate_options().and_then(|options| options.observer())
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Resolves the operands of the given instruction, and invokes the observer on the instruction.
+    pub(crate) fn observe_instruction<A: circuit::Aleo<Network = N>>(
+        &self,
+        observer: &Arc<dyn EvaluationObserver<N>>,
+        registers: &Registers<N, A>,
+        resource: &Identifier<N>,
+        index: usize,
+        instruction: &Instruction<N>,
+    ) -> Result<()> {
+        // Resolve the operand values.
+        let operands =
+            instruction.operands().iter().map(|operand| registers.load(self, operand)).collect::<Result<Vec<_>>>()?;
+        // Invoke the observer.
+        observer.on_instruction(self.program.id(), resource, index, instruction, &instruction.destinations(), &operands)
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Ensures the given function is pure, so that it may be queried without a signer, records, or keys.
+    ///
+    /// A function is pure if it neither consumes nor produces records, nor touches mappings (see `Function::is_pure`).
+    /// In addition, the function and the closures it calls must not read the signer or caller.
+    pub fn ensure_pure_function(&self, function_name: &Identifier<N>) -> Result<()> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        // Ensure the function is pure.
+        ensure!(function.is_pure(), "Function '{function_name}' is not pure");
+        // Ensure the outputs do not read the signer or caller.
+        for output in function.outputs() {
+            match output.operand() {
+                Operand::Literal(..) | Operand::Register(..) | Operand::ProgramID(..) => (),
+                operand => bail!("'{function_name}' is not pure: output '{operand}' is not allowed"),
+            }
+        }
+        // Ensure the instructions do not read the signer or caller.
+        self.ensure_signer_independent(function_name, function.instructions())
+    }
+
+    /// Ensures the given instructions do not read the signer or caller, following calls into closures.
+    fn ensure_signer_independent(&self, name: &Identifier<N>, instructions: &[Instruction<N>]) -> Result<()> {
+        for instruction in instructions {
+            // Ensure the instruction does not read the signer or caller.
+            for operand in instruction.operands() {
+                match operand {
+                    Operand::Signer | Operand::Caller | Operand::BlockHeight | Operand::NetworkID => {
+                        bail!("'{name}' is not pure: instruction '{instruction}' reads '{operand}'")
+                    }
+                    Operand::Literal(..) | Operand::Register(..) | Operand::ProgramID(..) => (),
+                }
+            }
+            // Ensure the called closures do not read the signer or caller.
+            // Note: Pure instructions only call local closures.
+            if let Instruction::Call(call) = instruction {
+                if let CallOperator::Resource(resource) = call.operator() {
+                    let closure = self.program().get_closure(resource)?;
+                    self.ensure_signer_independent(resource, closure.instructions())?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates the given pure function on the given inputs, and returns the outputs.
+    /// Unlike `evaluate_function`, this does not require a request, as the function cannot
+    /// depend on the signer or caller (see `Stack::ensure_pure_function`).
+    pub fn query_function<A: circuit::Aleo<Network = N>>(
+        &self,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+    ) -> Result<Vec<Value<N>>> {
+        let timer = timer!("Stack::query_function");
+
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Ensure the function is pure.
+        self.ensure_pure_function(&function_name)?;
+        // Retrieve the function.
+        let function = self.get_function(&function_name)?;
+        lap!(timer, "Ensure the function is pure");
+
+        // Prepare the inputs.
+        let inputs = inputs
+            .map(|input| input.try_into().map_err(|_| anyhow!("Failed to parse input for '{function_name}'")))
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure the number of inputs matches the number of input statements.
+        if function.inputs().len() != inputs.len() {
+            bail!("Expected {} inputs, found {}", function.inputs().len(), inputs.len())
+        }
+
+        // Initialize the registers, with an empty call stack.
+        // Note: The signer, caller, and transition view key are unobservable in a pure function,
+        // and are only set for the closures that the function calls.
+        let call_stack = CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?;
+        let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(&function_name)?.clone());
+        let program_address = self.program_id().to_address()?;
+        registers.set_signer(program_address);
+        registers.set_caller(program_address);
+        registers.set_tvk(Field::zero());
+        lap!(timer, "Initialize the registers");
+
+        // Store the inputs.
+        function.inputs().iter().zip_eq(inputs).try_for_each(|(input, value)| {
+            // Order the struct members of the input as declared.
+            let value = self.normalize_input(value, input.value_type())?;
+            // Ensure the input matches the input type.
+            self.matches_value_type(&value, input.value_type())?;
+            // Assign the input value to the register.
+            registers.store(self, input.register(), value)
+        })?;
+        lap!(timer, "Store the inputs");
+
+        // Evaluate the instructions.
+        // Note: The viewed function of a `call.view` cannot read the signer or caller (see `CallView::to_closure`),
+        // and pure functions only `call` local closures (see `Stack::ensure_signer_independent`).
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            self.evaluate_instruction(&mut registers, &function_name, index, instruction)?;
+        }
+        lap!(timer, "Evaluate the instructions");
+
+        // Load the outputs.
+        let outputs = function
+            .outputs()
+            .iter()
+            .map(|output| match output.operand() {
+                // If the operand is a literal, use the literal directly.
+                Operand::Literal(literal) => Ok(Value::Plaintext(Plaintext::from(literal))),
+                // If the operand is a register, retrieve the stack value from the register.
+                Operand::Register(register) => registers.load(self, &Operand::Register(register.clone())),
+                // If the operand is the program ID, convert the program ID into an address.
+                Operand::ProgramID(program_id) => {
+                    Ok(Value::Plaintext(Plaintext::from(Literal::Address(program_id.to_address()?))))
+                }
+                // Note: The remaining operands are rejected by `ensure_pure_function`.
+                operand => bail!("Cannot output '{operand}' from a pure function"),
+            })
+            .collect();
+        lap!(timer, "Load the outputs");
+
+        finish!(timer);
+        outputs
+    }
+}
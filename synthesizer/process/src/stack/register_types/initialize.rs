@@ -23,7 +23,8 @@ impl<N: Network> RegisterTypes<N> {
         closure: &Closure<N>,
     ) -> Result<Self> {
         // Initialize a map of registers to their types.
-        let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+        let mut register_types =
+            Self { inputs: IndexMap::new(), destinations: IndexMap::new(), aliases: IndexMap::new() };
 
         // Step 1. Check the inputs are well-formed.
         for input in closure.inputs() {
@@ -37,6 +38,8 @@ impl<N: Network> RegisterTypes<N> {
             ensure!(instruction.opcode() != Opcode::Async, "An 'async' instruction is not allowed in closures");
             // Ensure the closure contains no call instructions.
             ensure!(instruction.opcode() != Opcode::Call, "A 'call' instruction is not allowed in closures");
+            // Ensure the closure contains no view call instructions.
+            ensure!(instruction.opcode() != Opcode::CallView, "A 'call.view' instruction is not allowed in closures");
             // Check the instruction opcode, operands, and destinations.
             register_types.check_instruction(stack, closure.name(), instruction)?;
         }
@@ -64,7 +67,8 @@ impl<N: Network> RegisterTypes<N> {
         function: &Function<N>,
     ) -> Result<Self> {
         // Initialize a map of registers to their types.
-        let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+        let mut register_types =
+            Self { inputs: IndexMap::new(), destinations: IndexMap::new(), aliases: IndexMap::new() };
 
         /* Step 1. Check the inputs are well-formed. */
 
@@ -497,6 +501,38 @@ impl<N: Network> RegisterTypes<N> {
                     }
                 }
             }
+            Opcode::CallView => {
+                // Retrieve the view call operation.
+                let call_view = match instruction {
+                    Instruction::CallView(call_view) => call_view,
+                    _ => bail!("Instruction '{instruction}' is not a view call operation."),
+                };
+
+                // Retrieve the program ID.
+                let program_id = call_view.locator().program_id();
+                // Ensure the locator does not reference the current program.
+                if stack.program_id() == program_id {
+                    bail!("Locator '{}' does not reference an external program.", call_view.locator());
+                }
+                // Ensure the current program contains an import for this external program.
+                if !stack.program().imports().keys().contains(program_id) {
+                    bail!("External program '{program_id}' is not imported by '{}'.", stack.program_id());
+                }
+                // Note: The external function is checked to be viewable when computing the output types.
+            }
+            Opcode::CallHost => {
+                // Retrieve the host call operation.
+                let call_host = match instruction {
+                    Instruction::CallHost(call_host) => call_host,
+                    _ => bail!("Instruction '{instruction}' is not a host call operation."),
+                };
+
+                // Ensure host functions are enabled.
+                crate::ensure_host_functions_are_enabled::<N>()?;
+                // Ensure the host function is registered.
+                stack.get_host_function(call_host.name())?;
+                // Note: The operands and destinations are checked against the signature when computing the output types.
+            }
             Opcode::Cast(opcode) => match opcode {
                 "cast" => {
                     // Retrieve the cast operation.
@@ -524,7 +560,7 @@ impl<N: Network> RegisterTypes<N> {
                                 bail!("Struct '{struct_name}' is not defined.")
                             }
                             // Retrieve the struct.
-                            let struct_ = stack.program().get_struct(struct_name)?;
+                            let struct_ = stack.get_struct(struct_name)?;
                             // Ensure the operand types match the struct.
                             self.matches_struct(stack, instruction.operands(), struct_)?;
                         }
@@ -654,7 +690,7 @@ impl<N: Network> RegisterTypes<N> {
         struct_name: &Identifier<N>,
     ) -> Result<()> {
         // Retrieve the struct from the program.
-        let Ok(struct_) = stack.program().get_struct(struct_name) else {
+        let Ok(struct_) = stack.get_struct(struct_name) else {
             bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
         };
 
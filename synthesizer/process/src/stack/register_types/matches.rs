@@ -195,9 +195,10 @@ impl<N: Network> RegisterTypes<N> {
         }
 
         // Ensure the number of record entries match.
+        // Note: The optional entries of the record may be omitted, in which case their default values are used.
         let num_entries = operands.len().saturating_sub(N::MIN_RECORD_ENTRIES);
         let expected_num_entries = record_type.entries().len();
-        if expected_num_entries != num_entries {
+        if num_entries < record_type.num_required_entries() || num_entries > expected_num_entries {
             bail!("'{record_name}' expected {expected_num_entries} entries, found {num_entries} entries")
         }
 
@@ -237,7 +238,7 @@ impl<N: Network> RegisterTypes<N> {
 
         // Ensure the operand types match the record entry types.
         for (operand, (entry_name, entry_type)) in
-            operands.iter().skip(N::MIN_RECORD_ENTRIES).zip_eq(record_type.entries())
+            operands.iter().skip(N::MIN_RECORD_ENTRIES).zip_eq(record_type.entries().iter().take(num_entries))
         {
             match entry_type {
                 EntryType::Constant(plaintext_type)
@@ -54,6 +54,8 @@ pub struct RegisterTypes<N: Network> {
     inputs: IndexMap<u64, RegisterType<N>>,
     /// The mapping of all destination registers to their defined types.
     destinations: IndexMap<u64, RegisterType<N>>,
+    /// The mapping of registers to their developer-facing names.
+    aliases: IndexMap<u64, Identifier<N>>,
 }
 
 impl<N: Network> RegisterTypes<N> {
@@ -85,6 +87,69 @@ impl<N: Network> RegisterTypes<N> {
         self.inputs.contains_key(&register.locator())
     }
 
+    /// Returns the mapping of registers to their developer-facing names.
+    pub const fn aliases(&self) -> &IndexMap<u64, Identifier<N>> {
+        &self.aliases
+    }
+
+    /// Returns the developer-facing name of the given register, if one is defined.
+    pub fn get_alias(&self, register: &Register<N>) -> Option<&Identifier<N>> {
+        self.aliases.get(&register.locator())
+    }
+
+    /// Attaches the given developer-facing name to the given register.
+    pub fn add_alias(&mut self, locator: u64, alias: Identifier<N>) -> Result<()> {
+        // Ensure the register exists.
+        ensure!(
+            self.contains(&Register::Locator(locator)),
+            "Cannot name the register 'r{locator}', as it does not exist"
+        );
+        // Ensure the register is not already named.
+        if let Some(existing) = self.aliases.get(&locator) {
+            bail!("Cannot name the register 'r{locator}' as '{alias}', as it is already named '{existing}'")
+        }
+        // Ensure the name is not already used.
+        ensure!(!self.aliases.values().any(|name| name == &alias), "The register name '{alias}' is already in use");
+        // Attach the name.
+        self.aliases.insert(locator, alias);
+        Ok(())
+    }
+
+    /// Returns the given register as a string, including its developer-facing name, if one is defined.
+    /// For example, `r7` is described as `balance_after (r7)`, and `r7.owner` as `balance_after.owner (r7.owner)`.
+    pub fn describe(&self, register: &Register<N>) -> String {
+        match (self.get_alias(register), register) {
+            (Some(alias), Register::Locator(..)) => format!("{alias} ({register})"),
+            (Some(alias), Register::Access(_, path)) => {
+                format!("{alias}{} ({register})", path.iter().map(|access| access.to_string()).collect::<String>())
+            }
+            (None, _) => register.to_string(),
+        }
+    }
+
+    /// Returns the given instruction as a string, followed by the developer-facing names of its registers, if any are defined.
+    /// For example, `sub r3 r4 into r7;` is described as `sub r3 r4 into r7; (where r7 = balance_after)`.
+    pub fn describe_instruction(&self, instruction: &Instruction<N>) -> String {
+        // Collect the named registers of the instruction, in order of appearance.
+        let registers = instruction
+            .operands()
+            .iter()
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => Some(register.locator()),
+                _ => None,
+            })
+            .chain(instruction.destinations().iter().map(|register| register.locator()))
+            .filter_map(|locator| Some((locator, self.aliases.get(&locator)?)))
+            .collect::<IndexMap<_, _>>()
+            .into_iter()
+            .map(|(locator, alias)| format!("r{locator} = {alias}"))
+            .collect::<Vec<_>>();
+        match registers.is_empty() {
+            true => instruction.to_string(),
+            false => format!("{instruction} (where {})", registers.join(", ")),
+        }
+    }
+
     /// Returns the register type of the given operand.
     pub fn get_type_from_operand(
         &self,
@@ -209,7 +274,7 @@ impl<N: Network> RegisterTypes<N> {
                 // Traverse the path to output the register type.
                 (RegisterRefType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(identifier)) => {
                     // Retrieve the member type from the struct.
-                    match stack.program().get_struct(struct_name)?.members().get(identifier) {
+                    match stack.get_struct(struct_name)?.members().get(identifier) {
                         // Update the member type.
                         Some(member_type) => register_type = RegisterRefType::Plaintext(member_type),
                         None => bail!("'{identifier}' does not exist in struct '{struct_name}'"),
@@ -263,4 +328,18 @@ impl<N: Network> RegisterTypes<N> {
             RegisterRefType::Future(locator) => RegisterType::Future(*locator),
         })
     }
+
+    /// Writes the register types to the given writer, for use in the stack snapshot hash.
+    pub(crate) fn write_digest_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        for registers in [&self.inputs, &self.destinations] {
+            // Write the number of registers.
+            u64::try_from(registers.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+            // Write the registers and their types.
+            for (locator, register_type) in registers {
+                locator.write_le(&mut writer)?;
+                register_type.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use console::program::{Access, Identifier, RegisterType};
 
 impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N, A> {
     /// Loads the value of a given operand from the registers.
@@ -43,8 +44,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
         };
 
         // Retrieve the stack value.
-        let stack_value =
-            self.console_registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
+        let stack_value = self
+            .console_registers
+            .get(&register.locator())
+            .ok_or_else(|| anyhow!("'{}' does not exist", self.register_types.describe(register)))?;
 
         // Return the value for the given register or register access.
         let stack_value = match register {
@@ -56,10 +59,17 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
                     // Retrieve the plaintext member from the path.
                     Value::Plaintext(plaintext) => Value::Plaintext(plaintext.find(path)?),
                     // Retrieve the record entry from the path.
-                    Value::Record(record) => match record.find(path)? {
-                        Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                    Value::Record(record) => match record.find(path) {
+                        Ok(Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext)) => {
                             Value::Plaintext(plaintext)
                         }
+                        // If the entry is missing, use the default value of the optional entry.
+                        Err(error) => match self.load_record_entry_default(stack, register, |entry_name| {
+                            record.data().contains_key(entry_name)
+                        }) {
+                            Some(default) => Value::Plaintext(Plaintext::from(default)),
+                            None => return Err(error),
+                        },
                     },
                     // Retrieve the argument from the future.
                     Value::Future(future) => future.find(path)?,
@@ -72,7 +82,9 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             // Ensure the stack value matches the register type.
             Ok(register_type) => stack.matches_register_type(&stack_value, &register_type)?,
             // Ensure the register is defined.
-            Err(error) => bail!("Register '{register}' is not a member of the function: {error}"),
+            Err(error) => {
+                bail!("Register '{}' is not a member of the function: {error}", self.register_types.describe(register))
+            }
         };
 
         Ok(stack_value)
@@ -128,8 +140,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
         };
 
         // Retrieve the circuit value.
-        let circuit_value =
-            self.circuit_registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
+        let circuit_value = self
+            .circuit_registers
+            .get(&register.locator())
+            .ok_or_else(|| anyhow!("'{}' does not exist", self.register_types.describe(register)))?;
 
         // Return the value for the given register or register access.
         let circuit_value = match register {
@@ -144,10 +158,21 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
                     // Retrieve the plaintext member from the path.
                     circuit::Value::Plaintext(plaintext) => circuit::Value::Plaintext(plaintext.find(&path)?),
                     // Retrieve the record entry from the path.
-                    circuit::Value::Record(record) => match record.find(&path)? {
-                        circuit::Entry::Constant(plaintext)
-                        | circuit::Entry::Public(plaintext)
-                        | circuit::Entry::Private(plaintext) => circuit::Value::Plaintext(plaintext),
+                    circuit::Value::Record(record) => match record.find(&path) {
+                        Ok(
+                            circuit::Entry::Constant(plaintext)
+                            | circuit::Entry::Public(plaintext)
+                            | circuit::Entry::Private(plaintext),
+                        ) => circuit::Value::Plaintext(plaintext),
+                        // If the entry is missing, use the default value of the optional entry.
+                        Err(error) => match self.load_record_entry_default(stack, register, |entry_name| {
+                            record.data().keys().any(|candidate| circuit::Eject::eject_value(candidate) == *entry_name)
+                        }) {
+                            Some(default) => {
+                                circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::constant(default)))
+                            }
+                            None => return Err(error),
+                        },
                     },
                     // Retrieve the argument from the future.
                     circuit::Value::Future(future) => future.find(&path)?,
@@ -162,9 +187,39 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
                 stack.matches_register_type(&circuit::Eject::eject_value(&circuit_value), &register_type)?
             }
             // Ensure the register is defined.
-            Err(error) => bail!("Register '{register}' is not a member of the function: {error}"),
+            Err(error) => {
+                bail!("Register '{}' is not a member of the function: {error}", self.register_types.describe(register))
+            }
         };
 
         Ok(circuit_value)
     }
 }
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Returns the default value of the optional record entry at the given register access,
+    /// if the entry is missing from the record (as determined by `contains_entry`).
+    /// This allows records that omit optional entries (i.e. records created before the entries were added) to be read.
+    fn load_record_entry_default(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        register: &Register<N>,
+        contains_entry: impl Fn(&Identifier<N>) -> bool,
+    ) -> Option<Literal<N>> {
+        // Retrieve the entry name.
+        let Register::Access(locator, path) = register else { return None };
+        let [Access::Member(entry_name)] = path.as_slice() else { return None };
+        // Ensure the entry is missing, as an entry that is present must not be replaced by its default value.
+        if contains_entry(entry_name) {
+            return None;
+        }
+        // Retrieve the record type.
+        let record_type = match self.register_types.get_type(stack, &Register::Locator(*locator)).ok()? {
+            RegisterType::Record(record_name) => stack.program().get_record(&record_name).ok()?,
+            RegisterType::ExternalRecord(locator) => stack.get_external_record(&locator).ok()?,
+            RegisterType::Plaintext(..) | RegisterType::Future(..) => return None,
+        };
+        // Return the default value of the entry.
+        record_type.default_for(entry_name).cloned()
+    }
+}
@@ -47,6 +47,8 @@ pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     console_registers: IndexMap<u64, Value<N>>,
     /// The mapping of assigned circuit registers to their values.
     circuit_registers: IndexMap<u64, circuit::Value<A>>,
+    /// The number of bytes held in the console registers, if the call stack has evaluation limits.
+    console_register_bytes: usize,
     /// The transition signer.
     signer: Option<Address<N>>,
     /// The transition signer, as a circuit.
@@ -74,6 +76,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             register_types,
             console_registers: IndexMap::new(),
             circuit_registers: IndexMap::new(),
+            console_register_bytes: 0,
             signer: None,
             signer_circuit: None,
             root_tvk: None,
@@ -85,6 +88,24 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         }
     }
 
+    /// Returns the register types, including the developer-facing names of the registers.
+    #[inline]
+    pub const fn register_types(&self) -> &RegisterTypes<N> {
+        &self.register_types
+    }
+
+    /// Returns a reference to the call stack, without cloning it.
+    #[inline]
+    pub(crate) const fn call_stack_ref(&self) -> &CallStack<N> {
+        &self.call_stack
+    }
+
+    /// Returns the number of bytes held in the console registers, if the call stack has evaluation limits.
+    #[inline]
+    pub(crate) const fn console_register_bytes(&self) -> usize {
+        self.console_register_bytes
+    }
+
     /// Ensure the console and circuit registers match.
     #[inline]
     pub fn ensure_console_and_circuit_registers_match(&self) -> Result<()> {
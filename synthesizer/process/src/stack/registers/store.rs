@@ -47,6 +47,14 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStore<N> for Registers<
                     Err(error) => bail!("Register '{register}' is missing a type definition: {error}"),
                 };
 
+                // If the call stack has evaluation limits, ensure the stack value is within them.
+                // Note: The destination is also bounded by its type before the instruction is evaluated.
+                if self.call_stack.evaluation_limits().is_some() {
+                    let value_size = stack_value.to_bytes_le()?.len();
+                    self.call_stack.ensure_within_register_limits(value_size, self.console_register_bytes)?;
+                    self.console_register_bytes += value_size;
+                }
+
                 // Store the stack value.
                 match self.console_registers.insert(*locator, stack_value) {
                     // Ensure the register has not been previously stored.
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::Register;
+
+/// A stepper evaluates a function one instruction at a time, in console mode,
+/// allowing the intermediate register values to be inspected between instructions.
+///
+/// Each step goes through the same dispatch as `evaluate_function` (see `Stack::evaluate_instruction`).
+pub struct Stepper<'a, N: Network, A: circuit::Aleo<Network = N>> {
+    /// The stack of the function being evaluated.
+    stack: &'a Stack<N>,
+    /// The function being evaluated.
+    function: Function<N>,
+    /// The registers of the function.
+    registers: Registers<N, A>,
+    /// The index of the next instruction to evaluate.
+    counter: usize,
+}
+
+impl<N: Network> Stack<N> {
+    /// Returns a stepper for the top-level function in the given authorization.
+    /// The inputs are stored in the registers, and no instructions are evaluated.
+    pub fn step_function<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+    ) -> Result<Stepper<'_, N, A>> {
+        self.initialize_stepper(authorization, None)
+    }
+
+    /// Returns a stepper for the top-level function in the given authorization,
+    /// which invokes the given observer on each instruction it evaluates, including those of nested calls.
+    pub fn step_function_with_observer<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        observer: Arc<dyn EvaluationObserver<N>>,
+    ) -> Result<Stepper<'_, N, A>> {
+        self.initialize_stepper(authorization, Some(observer))
+    }
+
+    /// Returns a stepper for the top-level function in the given authorization, with the given observer, if any.
+    fn initialize_stepper<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        observer: Option<Arc<dyn EvaluationObserver<N>>>,
+    ) -> Result<Stepper<'_, N, A>> {
+        // Retrieve the next request.
+        // Note: The request is consumed from the authorization, so that any `call` instructions
+        // are evaluated on the subsequent requests.
+        let request = authorization.next()?;
+        // Ensure the request is for this program.
+        ensure!(
+            request.program_id() == self.program.id(),
+            "Cannot step '{}' in the program '{}'",
+            request.program_id(),
+            self.program.id()
+        );
+        // Ensure the network ID matches.
+        ensure!(
+            **request.network_id() == N::ID,
+            "Network ID mismatch. Expected {}, but found {}",
+            N::ID,
+            request.network_id()
+        );
+
+        // Retrieve the function and inputs.
+        let function = self.get_function(request.function_name())?;
+        let inputs = request.inputs();
+
+        // Ensure the number of inputs matches.
+        if function.inputs().len() != inputs.len() {
+            bail!(
+                "Function '{}' in the program '{}' expects {} inputs, but {} were provided.",
+                function.name(),
+                self.program.id(),
+                function.inputs().len(),
+                inputs.len()
+            )
+        }
+        // Ensure the request is well-formed.
+        ensure!(request.verify(&function.input_types(), true), "Request is invalid");
+
+        // Initialize the call stack.
+        let call_stack = match observer {
+            Some(observer) => CallStack::evaluate_with_observer(authorization, observer)?,
+            None => CallStack::evaluate(authorization)?,
+        };
+        // Initialize the registers.
+        let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(function.name())?.clone());
+        // Set the transition signer.
+        registers.set_signer(*request.signer());
+        // Set the transition caller.
+        registers.set_caller(*request.signer());
+        // Set the transition view key.
+        registers.set_tvk(*request.tvk());
+
+        // Store the inputs.
+        function.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
+            // Assign the input value to the register.
+            registers.store(self, register, input.clone())
+        })?;
+
+        Ok(Stepper { stack: self, function, registers, counter: 0 })
+    }
+}
+
+impl<'a, N: Network, A: circuit::Aleo<Network = N>> Stepper<'a, N, A> {
+    /// Returns the function being evaluated.
+    pub const fn function(&self) -> &Function<N> {
+        &self.function
+    }
+
+    /// Returns the index of the next instruction to evaluate.
+    pub const fn counter(&self) -> usize {
+        self.counter
+    }
+
+    /// Returns the next instruction to evaluate, if one exists.
+    pub fn next_instruction(&self) -> Option<&Instruction<N>> {
+        self.function.instructions().get(self.counter)
+    }
+
+    /// Returns `true` if all instructions have been evaluated.
+    pub fn is_finished(&self) -> bool {
+        self.counter >= self.function.instructions().len()
+    }
+
+    /// Evaluates the next instruction, and returns the evaluated instruction.
+    /// Returns `None` if all instructions have already been evaluated.
+    ///
+    /// Note: A `call` instruction is evaluated in its entirety, as a single step.
+    pub fn step(&mut self) -> Result<Option<&Instruction<N>>> {
+        // Retrieve the next instruction.
+        let Some(instruction) = self.function.instructions().get(self.counter) else {
+            return Ok(None);
+        };
+        // Evaluate the instruction.
+        self.stack.evaluate_instruction(&mut self.registers, self.function.name(), self.counter, instruction)?;
+        // Increment the counter.
+        self.counter += 1;
+        Ok(Some(instruction))
+    }
+
+    /// Returns the current value of the given register.
+    ///
+    /// # Errors
+    /// This method will halt if the register has not been assigned yet.
+    pub fn peek_register(&self, register: &Register<N>) -> Result<Value<N>> {
+        self.registers.load(self.stack, &Operand::Register(register.clone()))
+    }
+
+    /// Returns the current value of the register with the given developer-facing name.
+    ///
+    /// # Errors
+    /// This method will halt if no register has the given name, or if the register has not been assigned yet.
+    pub fn peek_named_register(&self, name: &Identifier<N>) -> Result<Value<N>> {
+        // Retrieve the register with the given name.
+        let Some((locator, _)) = self.registers.register_types().aliases().iter().find(|(_, alias)| *alias == name)
+        else {
+            bail!("Function '{}' has no register named '{name}'", self.function.name())
+        };
+        self.peek_register(&Register::Locator(*locator))
+    }
+
+    /// Evaluates the remaining instructions, and returns the outputs of the function.
+    pub fn finish(mut self) -> Result<Vec<Value<N>>> {
+        // Evaluate the remaining instructions.
+        while self.step()?.is_some() {}
+        // Load the outputs.
+        self.function.outputs().iter().map(|output| self.registers.load(self.stack, output.operand())).collect()
+    }
+}
@@ -14,19 +14,25 @@
 
 use crate::{
     traits::{StackEvaluate, StackExecute},
+    Authorization,
     CallStack,
+    CancellationToken,
+    EvaluationLimits,
+    EvaluationObserver,
+    ExecutionCancelled,
     Process,
     Stack,
     Trace,
+    WarmUpTarget,
 };
 use circuit::{network::AleoV0, Aleo};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, MainnetV0},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
+    program::{Identifier, Literal, Locator, Plaintext, PlaintextType, ProgramID, Record, Register, Value},
     types::{Field, U64},
 };
-use ledger_block::{Fee, Transaction};
+use ledger_block::{Execution, ExecutionWriter, FailedExecution, Fee, Transaction};
 use ledger_query::Query;
 use ledger_store::{
     helpers::memory::{BlockMemory, FinalizeMemory},
@@ -35,12 +41,20 @@ use ledger_store::{
     FinalizeStorage,
     FinalizeStore,
 };
-use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program, StackProgram};
+use synthesizer_program::{
+    FinalizeGlobalState,
+    FinalizeStoreTrait,
+    HostFunction,
+    Instruction,
+    KnownAnswer,
+    Program,
+    StackProgram,
+};
 use synthesizer_snark::UniversalSRS;
 
 use indexmap::IndexMap;
-use parking_lot::RwLock;
-use std::sync::Arc;
+use parking_lot::{Mutex, RwLock};
+use std::{sync::Arc, time::Duration};
 
 type CurrentNetwork = MainnetV0;
 type CurrentAleo = AleoV0;
@@ -155,6 +169,285 @@ function foo:
     assert_eq!(expected, candidate[0]);
 }
 
+#[test]
+fn test_program_evaluate_with_limits() {
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+struct pair:
+    first as field;
+    second as field;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    cast r2 r2 into r3 as pair;
+    output r3 as pair.private;
+",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("foo").unwrap();
+    // Declare the function inputs.
+    let inputs = [
+        Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("2field").unwrap()),
+        Value::Plaintext(Plaintext::from_str("3field").unwrap()),
+    ];
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Authorizes the function call.
+    let mut authorize = || {
+        process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap()
+    };
+
+    // Ensure the evaluation succeeds within generous limits.
+    let limits = EvaluationLimits::new(1 << 20, 1 << 16, Duration::from_secs(60));
+    let response = process.evaluate_with_limits::<CurrentAleo>(authorize(), limits).unwrap();
+    let expected = process.evaluate::<CurrentAleo>(authorize()).unwrap();
+    assert_eq!(response.outputs(), expected.outputs());
+
+    // Ensure the evaluation fails if a value exceeds the maximum value size.
+    // Note: The `pair` is bounded by its type (a 5-byte name and two 32-byte fields), before it is built.
+    let limits = EvaluationLimits::new(1 << 20, 64, Duration::from_secs(60));
+    let error = process.evaluate_with_limits::<CurrentAleo>(authorize(), limits).unwrap_err();
+    assert!(error.to_string().contains("Value of 69 bytes"), "{error}");
+
+    // Ensure the evaluation fails if the registers exceed the maximum register bytes.
+    let limits = EvaluationLimits::new(128, 1 << 16, Duration::from_secs(60));
+    assert!(process.evaluate_with_limits::<CurrentAleo>(authorize(), limits).is_err());
+
+    // Ensure the evaluation fails if it exceeds the timeout, which is checked before each instruction.
+    let limits = EvaluationLimits::new(1 << 20, 1 << 16, Duration::ZERO);
+    let error = process.evaluate_with_limits::<CurrentAleo>(authorize(), limits).unwrap_err();
+    assert!(error.to_string().contains("timeout"), "{error}");
+}
+
+#[test]
+fn test_program_evaluate_with_observer() {
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r2 r1 into r3;
+    output r3 as field.private;
+",
+    )
+    .unwrap();
+
+    /// An observer that records each observed instruction, its destinations, and its operands.
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<(usize, String, Vec<Register<CurrentNetwork>>, Vec<Value<CurrentNetwork>>)>>);
+
+    impl EvaluationObserver<CurrentNetwork> for Recorder {
+        fn on_instruction(
+            &self,
+            _program_id: &ProgramID<CurrentNetwork>,
+            _resource: &Identifier<CurrentNetwork>,
+            index: usize,
+            instruction: &Instruction<CurrentNetwork>,
+            destinations: &[Register<CurrentNetwork>],
+            operands: &[Value<CurrentNetwork>],
+        ) -> Result<()> {
+            self.0.lock().push((index, instruction.to_string(), destinations.to_vec(), operands.to_vec()));
+            Ok(())
+        }
+    }
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("foo").unwrap();
+    // Declare the function inputs.
+    let inputs = [
+        Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("2field").unwrap()),
+        Value::Plaintext(Plaintext::from_str("3field").unwrap()),
+    ];
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Authorizes the function call.
+    let mut authorize = || {
+        process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap()
+    };
+
+    // Evaluate the function with the observer.
+    let recorder = Arc::new(Recorder::default());
+    let response = process.evaluate_with_observer::<CurrentAleo>(authorize(), recorder.clone()).unwrap();
+    let expected = process.evaluate::<CurrentAleo>(authorize()).unwrap();
+    assert_eq!(response.outputs(), expected.outputs());
+
+    // Ensure each instruction was observed, with its destinations and resolved operands.
+    let steps = recorder.0.lock();
+    assert_eq!(steps.len(), 2);
+    assert_eq!(steps[0].0, 0);
+    assert_eq!(steps[0].1, "add r0 r1 into r2");
+    assert_eq!(steps[0].2, vec![Register::Locator(2)]);
+    assert_eq!(steps[0].3, inputs.to_vec());
+    assert_eq!(steps[1].0, 1);
+    assert_eq!(steps[1].1, "mul r2 r1 into r3");
+    assert_eq!(steps[1].2, vec![Register::Locator(3)]);
+    assert_eq!(steps[1].3, vec![Value::Plaintext(Plaintext::from_str("5field").unwrap()), inputs[1].clone()]);
+}
+
+#[test]
+fn test_program_step_function() {
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r2 r2 into r3;
+    output r3 as field.private;
+",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("foo").unwrap();
+    // Declare the function inputs.
+    let inputs = [
+        Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("2field").unwrap()),
+        Value::Plaintext(Plaintext::from_str("3field").unwrap()),
+    ];
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Retrieve the stack.
+    let stack = process.get_stack(program.id()).unwrap();
+
+    // Initialize the stepper.
+    let mut stepper = stack.step_function::<CurrentAleo>(authorization).unwrap();
+    let r0 = Register::Locator(0);
+    let r2 = Register::Locator(2);
+    let r3 = Register::Locator(3);
+    assert_eq!(stepper.peek_register(&r0).unwrap(), inputs[0]);
+    assert!(stepper.peek_register(&r2).is_err());
+
+    // Step through the first instruction.
+    let instruction = stepper.step().unwrap().unwrap();
+    assert_eq!(instruction.to_string(), "add r0 r1 into r2;");
+    assert_eq!(stepper.peek_register(&r2).unwrap(), Value::Plaintext(Plaintext::from_str("5field").unwrap()));
+    assert!(stepper.peek_register(&r3).is_err());
+
+    // Step through the second instruction.
+    let instruction = stepper.step().unwrap().unwrap();
+    assert_eq!(instruction.to_string(), "mul r2 r2 into r3;");
+    assert_eq!(stepper.peek_register(&r3).unwrap(), Value::Plaintext(Plaintext::from_str("25field").unwrap()));
+
+    // Ensure there are no more instructions.
+    assert!(stepper.is_finished());
+    assert!(stepper.step().unwrap().is_none());
+
+    // Ensure the outputs are correct.
+    let outputs = stepper.finish().unwrap();
+    assert_eq!(outputs, vec![Value::Plaintext(Plaintext::from_str("25field").unwrap())]);
+
+    /// An observer that records the index of each observed instruction.
+    #[derive(Default)]
+    struct Indices(Mutex<Vec<usize>>);
+
+    impl EvaluationObserver<CurrentNetwork> for Indices {
+        fn on_instruction(
+            &self,
+            _program_id: &ProgramID<CurrentNetwork>,
+            _resource: &Identifier<CurrentNetwork>,
+            index: usize,
+            _instruction: &Instruction<CurrentNetwork>,
+            _destinations: &[Register<CurrentNetwork>],
+            _operands: &[Value<CurrentNetwork>],
+        ) -> Result<()> {
+            self.0.lock().push(index);
+            Ok(())
+        }
+    }
+
+    // Ensure the stepper invokes the observer on each step.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    let indices = Arc::new(Indices::default());
+    let mut stepper = stack.step_function_with_observer::<CurrentAleo>(authorization, indices.clone()).unwrap();
+    stepper.step().unwrap();
+    assert_eq!(*indices.0.lock(), vec![0]);
+    assert_eq!(stepper.finish().unwrap(), outputs);
+    assert_eq!(*indices.0.lock(), vec![0, 1]);
+}
+
+#[test]
+fn test_program_evaluate_known_answers() {
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("main").unwrap();
+
+    // Initialize the process.
+    let base_process = Process::<CurrentNetwork>::load().unwrap();
+
+    for known_answer in KnownAnswer::<CurrentNetwork>::load().unwrap() {
+        // Construct the program for the known-answer test.
+        let program = known_answer.to_program().unwrap();
+        // Construct the process.
+        let mut process = base_process.clone();
+        process.add_program(&program).unwrap();
+
+        // Declare the function inputs.
+        let inputs =
+            known_answer.inputs().iter().map(|input| Value::Plaintext(Plaintext::from(input))).collect::<Vec<_>>();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+
+        // Evaluate the function.
+        let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(
+            Value::Plaintext(Plaintext::from(known_answer.output())),
+            candidate[0],
+            "Known-answer test failed for '{}'",
+            known_answer.instruction()
+        );
+    }
+}
+
 #[test]
 fn test_program_evaluate_struct_and_function() {
     // Initialize a new program.
@@ -217,6 +510,150 @@ output r1 as field.private;",
     assert_eq!(expected, candidate[0]);
 }
 
+#[test]
+fn test_program_evaluate_struct_with_members_in_any_order() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+struct segment:
+    start as point;
+    end as point;
+
+function length_x:
+    input r0 as segment.private;
+    sub r0.end.x r0.start.x into r1;
+    output r1 as field.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    let stack = process.get_stack(program.id()).unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the inputs, with the members in the declared order and in any order.
+    let ordered = "{ start: { x: 1field, y: 2field }, end: { x: 5field, y: 3field } }";
+    let unordered = "{ end: { y: 3field, x: 5field }, start: { y: 2field, x: 1field } }";
+
+    // Ensure both inputs authorize the same request inputs, and evaluate to the same output.
+    let mut requests = vec![];
+    for input in [ordered, unordered] {
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "length_x", [input].into_iter(), rng)
+            .unwrap();
+        let request = authorization.peek_next().unwrap();
+        requests.push(request.inputs().to_vec());
+        let response =
+            stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap(), None).unwrap();
+        assert_eq!(response.outputs(), &[Value::from_str("4field").unwrap()]);
+    }
+    assert_eq!(requests[0], requests[1]);
+
+    // Ensure unknown, missing, and duplicate members are rejected.
+    for input in [
+        "{ start: { x: 1field, y: 2field }, end: { x: 5field, y: 3field, z: 0field } }",
+        "{ start: { x: 1field }, end: { x: 5field, y: 3field } }",
+        "{ start: { x: 1field, y: 2field, x: 1field }, end: { x: 5field, y: 3field } }",
+    ] {
+        assert!(process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "length_x", [input].into_iter(), rng)
+            .is_err());
+    }
+}
+
+#[test]
+fn test_program_evaluate_with_omitted_members_and_entries() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+struct point:
+    x as field;
+    y as field default 0field;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+    memo as field.private default 0field;
+    tag as u8.private default 0u8;
+
+function difference:
+    input r0 as point.private;
+    sub r0.x r0.y into r1;
+    output r1 as field.private;
+
+function double:
+    input r0 as token.record;
+    add r0.amount r0.amount into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    let stack = process.get_stack(program.id()).unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Ensure an omitted optional struct member is set to its default value.
+    let mut requests = vec![];
+    for input in ["{ x: 5field }", "{ x: 5field, y: 0field }"] {
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "difference", [input].into_iter(), rng)
+            .unwrap();
+        requests.push(authorization.peek_next().unwrap().inputs().to_vec());
+        let response =
+            stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap(), None).unwrap();
+        assert_eq!(response.outputs(), &[Value::from_str("5field").unwrap()]);
+    }
+    assert_eq!(requests[0], requests[1]);
+    // Ensure an omitted required struct member is rejected.
+    let input = "{ y: 1field }";
+    assert!(process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "difference", [input].into_iter(), rng)
+        .is_err());
+
+    // Ensure a record may omit its trailing optional entries, which are not set to their default values.
+    let input = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+        "{{ owner: {caller}.private, memo: 1field.private, amount: 100u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "double", [Value::Record(input)].iter(), rng)
+        .unwrap();
+    let Value::Record(record) = &authorization.peek_next().unwrap().inputs()[0] else { panic!("Expected a record") };
+    let entry_names = record.data().keys().map(|name| name.to_string()).collect::<Vec<_>>();
+    assert_eq!(entry_names, ["amount", "memo"]);
+    let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap(), None).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("200u64").unwrap()]);
+
+    // Ensure a record that omits a required entry, or an optional entry before another entry, is rejected.
+    for input in [
+        format!("{{ owner: {caller}.private, memo: 1field.private, _nonce: 0group.public }}"),
+        format!("{{ owner: {caller}.private, amount: 100u64.private, tag: 1u8.private, _nonce: 0group.public }}"),
+    ] {
+        let input = Value::Record(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&input).unwrap());
+        assert!(process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "double", [input].iter(), rng)
+            .is_err());
+    }
+}
+
 #[test]
 fn test_program_evaluate_record_and_function() {
     // Initialize a new program.
@@ -1949,38 +2386,48 @@ function a:
 
     // Verify the execution.
     process.verify_execution(&execution).unwrap();
+
+    // Reconstruct the call tree.
+    let call_tree = process.construct_call_tree(&execution).unwrap();
+    assert_eq!(call_tree.len(), 3);
+    assert_eq!(call_tree.program_id(), program2.id());
+    assert_eq!(call_tree.children().len(), 1);
+    assert_eq!(call_tree.children()[0].program_id(), program1.id());
+    assert_eq!(call_tree.children()[0].children()[0].program_id(), program0.id());
+    // Ensure the canonical order is the execution order.
+    assert_eq!(
+        call_tree.to_post_order(),
+        execution.transitions().map(|transition| *transition.id()).collect::<Vec<_>>()
+    );
+
+    // Ensure an execution in non-canonical order is rejected.
+    let transitions = execution.transitions().cloned().collect::<Vec<_>>();
+    let reordered = [transitions[1].clone(), transitions[0].clone(), transitions[2].clone()];
+    let candidate =
+        Execution::from(reordered.into_iter(), execution.global_state_root(), execution.proof().cloned()).unwrap();
+    assert!(process.construct_call_tree(&candidate).is_err());
+    assert!(process.verify_execution(&candidate).is_err());
+
+    // Ensure a proof with more instances than the execution is rejected before verification.
+    let candidate =
+        Execution::from(transitions[..1].iter().cloned(), execution.global_state_root(), execution.proof().cloned())
+            .unwrap();
+    let error = process.verify_execution(&candidate).unwrap_err();
+    assert!(error.to_string().contains("incorrect number of instances"), "{error}");
 }
 
 #[test]
-fn test_complex_execution_order() {
-    // This test checks that the execution order is correct.
-    // The functions are invoked in the following order:
-    // "four::a"
-    //   --> "two::b"
-    //        --> "zero::c"
-    //        --> "one::d"
-    //   --> "three::e"
-    //        --> "two::b"
-    //             --> "zero::c"
-    //             --> "one::d"
-    //        --> "one::d"
-    //        --> "zero::c"
-    // The linearized order is:
-    //  - [a, b, c, d, e, b, c, d, d, c]
-    // The transitions must be included in the `Execution` in the order they finish.
-    // The execution order is:
-    //  - [c, d, b, c, d, b, d, c, e, a]
-
+fn test_execute_bundled_authorization() {
     // Initialize a new program.
     let (string, program0) = Program::<CurrentNetwork>::parse(
         r"
-    program zero.aleo;
+program zero.aleo;
 
-    function c:
-        input r0 as u8.private;
-        input r1 as u8.private;
-        add r0 r1 into r2;
-        output r2 as u8.private;",
+function c:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.private;",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
@@ -1991,13 +2438,15 @@ fn test_complex_execution_order() {
     // Initialize another program.
     let (string, program1) = Program::<CurrentNetwork>::parse(
         r"
-    program one.aleo;
+import zero.aleo;
 
-    function d:
-        input r0 as u8.private;
-        input r1 as u8.private;
-        add r0 r1 into r2;
-        output r2 as u8.private;",
+program one.aleo;
+
+function b:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    call zero.aleo/c r0 r1 into r2;
+    output r2 as u8.private;",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
@@ -2005,11 +2454,171 @@ fn test_complex_execution_order() {
     // Add the program to the process.
     process.add_program(&program1).unwrap();
 
-    // Initialize another program.
-    let (string, program2) = Program::<CurrentNetwork>::parse(
-        r"
-    import zero.aleo;
-    import one.aleo;
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the first top-level call.
+    let inputs = [Value::<CurrentNetwork>::from_str("1u8").unwrap(), Value::from_str("2u8").unwrap()];
+    let first = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program0.id(),
+            Identifier::from_str("c").unwrap(),
+            inputs.iter(),
+            rng,
+        )
+        .unwrap();
+    // Authorize the second top-level call.
+    let inputs = [Value::<CurrentNetwork>::from_str("3u8").unwrap(), Value::from_str("4u8").unwrap()];
+    let second = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program1.id(),
+            Identifier::from_str("b").unwrap(),
+            inputs.iter(),
+            rng,
+        )
+        .unwrap();
+
+    // Bundle the authorizations.
+    let authorization = Authorization::bundle([first.replicate(), second.replicate()]).unwrap();
+    assert_eq!(authorization.len(), 3);
+    // Ensure an authorization cannot be bundled twice.
+    assert!(Authorization::bundle([first.replicate(), first.replicate()]).is_err());
+    // Ensure an empty bundle is rejected.
+    assert!(Authorization::<CurrentNetwork>::bundle([]).is_err());
+    // Ensure a bundle of authorizations from different signers is rejected.
+    let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("3u8").unwrap(), Value::from_str("4u8").unwrap()];
+    let other = process
+        .authorize::<CurrentAleo, _>(
+            &other_private_key,
+            program1.id(),
+            Identifier::from_str("b").unwrap(),
+            inputs.iter(),
+            rng,
+        )
+        .unwrap();
+    assert!(Authorization::bundle([first.replicate(), other]).is_err());
+
+    // Ensure the calls may be authorized together into an equivalent bundle.
+    let bundle = process
+        .authorize_bundle::<CurrentAleo, _>(
+            &caller_private_key,
+            [
+                (*program0.id(), Identifier::from_str("c").unwrap(), vec![
+                    Value::from_str("1u8").unwrap(),
+                    Value::from_str("2u8").unwrap(),
+                ]),
+                (*program1.id(), Identifier::from_str("b").unwrap(), vec![
+                    Value::from_str("3u8").unwrap(),
+                    Value::from_str("4u8").unwrap(),
+                ]),
+            ],
+            rng,
+        )
+        .unwrap();
+    let function_names = bundle.to_vec_deque().iter().map(|request| *request.function_name()).collect::<Vec<_>>();
+    assert_eq!(
+        function_names,
+        authorization.to_vec_deque().iter().map(|request| *request.function_name()).collect::<Vec<_>>()
+    );
+    let response = process.evaluate::<CurrentAleo>(bundle).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("7u8").unwrap()]);
+    // Ensure an empty list of calls is rejected.
+    assert!(process.authorize_bundle::<CurrentAleo, _>(&caller_private_key, [], rng).is_err());
+
+    // Evaluate the bundle, which returns the response of the last top-level call.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("7u8").unwrap()]);
+
+    // Execute the bundle.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("7u8").unwrap()]);
+    assert_eq!(trace.transitions().len(), 3);
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("one.aleo/b", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Ensure the call trees are reconstructed for each top-level call.
+    let call_trees = process.construct_call_trees(&execution).unwrap();
+    assert_eq!(call_trees.len(), 2);
+    assert_eq!(call_trees[0].program_id(), program0.id());
+    assert_eq!(call_trees[0].len(), 1);
+    assert_eq!(call_trees[1].program_id(), program1.id());
+    assert_eq!(call_trees[1].len(), 2);
+    // Ensure a single call tree is not reconstructed for the bundle.
+    assert!(process.construct_call_tree(&execution).is_err());
+}
+
+#[test]
+fn test_complex_execution_order() {
+    // This test checks that the execution order is correct.
+    // The functions are invoked in the following order:
+    // "four::a"
+    //   --> "two::b"
+    //        --> "zero::c"
+    //        --> "one::d"
+    //   --> "three::e"
+    //        --> "two::b"
+    //             --> "zero::c"
+    //             --> "one::d"
+    //        --> "one::d"
+    //        --> "zero::c"
+    // The linearized order is:
+    //  - [a, b, c, d, e, b, c, d, d, c]
+    // The transitions must be included in the `Execution` in the order they finish.
+    // The execution order is:
+    //  - [c, d, b, c, d, b, d, c, e, a]
+
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+    program zero.aleo;
+
+    function c:
+        input r0 as u8.private;
+        input r1 as u8.private;
+        add r0 r1 into r2;
+        output r2 as u8.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+    program one.aleo;
+
+    function d:
+        input r0 as u8.private;
+        input r1 as u8.private;
+        add r0 r1 into r2;
+        output r2 as u8.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Initialize another program.
+    let (string, program2) = Program::<CurrentNetwork>::parse(
+        r"
+    import zero.aleo;
+    import one.aleo;
 
     program two.aleo;
 
@@ -2358,13 +2967,124 @@ function compute:
     process.verify_execution(&execution).unwrap();
 }
 
+#[test]
+fn test_process_profile_deployment() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    mul r2 r2 into r3;
+    output r3 as u32.public;
+
+function hash:
+    input r0 as field.private;
+    hash.bhp256 r0 into r1 as field;
+    output r1 as field.public;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = Process::load().unwrap();
+
+    // Profile the deployment.
+    let profile = process.profile_deployment::<CurrentAleo, _>(&program, rng).unwrap();
+    assert_eq!(profile.functions().len(), 2);
+    assert!(profile.is_within_limits());
+
+    // Create a deployment for the program.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+    // Ensure the profile matches the deployed verifying keys.
+    for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+        let function_profile = profile.get_function(function_name).unwrap();
+        assert_eq!(function_profile.num_constraints(), verifying_key.circuit_info.num_constraints as u64);
+        assert_eq!(function_profile.num_variables(), verifying_key.num_variables());
+        assert!(function_profile.estimated_proving_key_size() > function_profile.estimated_verifying_key_size());
+    }
+    assert_eq!(profile.num_combined_constraints(), deployment.num_combined_constraints().unwrap());
+    assert_eq!(profile.num_combined_variables(), deployment.num_combined_variables().unwrap());
+
+    // Ensure the larger circuit has the larger profile.
+    let compute = profile.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+    let hash = profile.get_function(&Identifier::from_str("hash").unwrap()).unwrap();
+    assert!(hash.num_constraints() > compute.num_constraints());
+    assert!(hash.estimated_proving_key_size() > compute.estimated_proving_key_size());
+}
+
+#[test]
+fn test_process_estimate_deployment() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing_estimate.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    mul r2 r2 into r3;
+    output r3 as u32.public;
+
+function hash:
+    input r0 as field.private;
+    hash.bhp256 r0 into r1 as field;
+    output r1 as field.public;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = Process::load().unwrap();
+
+    // Estimate the deployment.
+    let estimate = process.estimate_deployment::<CurrentAleo>(&program).unwrap();
+    assert_eq!(estimate.profile().functions().len(), 2);
+
+    // Create a deployment for the program, and compute its cost.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    let (_, (storage_cost, synthesis_cost, namespace_cost)) = crate::deployment_cost(&deployment).unwrap();
+
+    // Ensure the estimated counts and the synthesis and namespace costs are exact.
+    let (total, (estimated_storage_cost, estimated_synthesis_cost, estimated_namespace_cost)) = estimate.cost();
+    assert_eq!(estimated_synthesis_cost, synthesis_cost);
+    assert_eq!(estimated_namespace_cost, namespace_cost);
+    assert_eq!(total, estimated_storage_cost + estimated_synthesis_cost + estimated_namespace_cost);
+    for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+        let function = estimate.profile().get_function(function_name).unwrap();
+        assert_eq!(function.num_constraints(), verifying_key.circuit_info.num_constraints as u64);
+        assert_eq!(function.num_variables(), verifying_key.num_variables());
+    }
+    // Ensure the estimated size and storage cost are within a quarter of the deployment.
+    let size_in_bytes = deployment.size_in_bytes().unwrap();
+    assert!(estimate.size_in_bytes().abs_diff(size_in_bytes) <= size_in_bytes / 4);
+    assert!(estimated_storage_cost.abs_diff(storage_cost) <= storage_cost / 4);
+
+    // Ensure the storage cost is charged on the persisted deployment.
+    assert_eq!(storage_cost, size_in_bytes * CurrentNetwork::DEPLOYMENT_FEE_MULTIPLIER);
+
+    // Ensure the function costs cover the synthesis cost, and the larger circuit costs more.
+    let function_costs = estimate.function_costs();
+    let combined_synthesis_cost = function_costs.values().map(|(_, (_, synthesis_cost))| synthesis_cost).sum::<u64>();
+    assert_eq!(combined_synthesis_cost, synthesis_cost);
+    let compute = function_costs.get(&Identifier::from_str("compute").unwrap()).unwrap();
+    let hash = function_costs.get(&Identifier::from_str("hash").unwrap()).unwrap();
+    assert!(hash.0 > compute.0);
+}
+
 #[test]
 fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process::<CurrentNetwork>::new_empty(UniversalSRS::load().unwrap());
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2581,6 +3301,49 @@ fn test_long_import_chain_with_calls() {
     assert!(result.is_err())
 }
 
+#[test]
+fn test_process_max_call_depth() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+    program test0.aleo;
+    function c:",
+    )
+    .unwrap();
+
+    // Construct the process, with a maximum call depth of 3.
+    let mut process = crate::test_helpers::sample_process(&program);
+    process.set_max_call_depth(3);
+    assert_eq!(process.max_call_depth(), 3);
+
+    // Returns the program that calls the function in the previous program.
+    let program = |i: usize| {
+        Program::<CurrentNetwork>::from_str(&format!(
+            "
+        import test{}.aleo;
+        program test{i}.aleo;
+        function c:
+            call test{}.aleo/c;",
+            i - 1,
+            i - 1
+        ))
+        .unwrap()
+    };
+
+    // Ensure the programs within the maximum call depth are added.
+    process.add_program(&program(1)).unwrap();
+    process.add_program(&program(2)).unwrap();
+
+    // Ensure the program exceeding the maximum call depth is rejected, naming the offending call chain.
+    let error = process.add_program(&program(3)).unwrap_err();
+    assert!(error.to_string().contains("test3.aleo/c -> test2.aleo/c -> test1.aleo/c -> test0.aleo/c"));
+    assert!(!process.contains_program(program(3).id()));
+
+    // Ensure the program is added once the maximum call depth is raised.
+    process.set_max_call_depth(Process::<CurrentNetwork>::MAX_CALL_DEPTH);
+    process.add_program(&program(3)).unwrap();
+}
+
 #[test]
 fn test_max_imports() {
     // Construct the process.
@@ -2645,3 +3408,924 @@ fn test_program_exceeding_transaction_spend_limit() {
     let result = Stack::initialize(&process, &program);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_process_lookup_by_content_hash() {
+    // Initialize the programs.
+    let program_a = Program::<CurrentNetwork>::from_str(
+        r"
+program child.aleo;
+
+function compute:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+    )
+    .unwrap();
+    let program_b = Program::<CurrentNetwork>::from_str(
+        r"
+import child.aleo;
+
+program parent.aleo;
+
+function compute:
+    input r0 as u8.private;
+    call child.aleo/compute r0 into r1;
+    output r1 as u8.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program_a);
+
+    // Ensure the program can be retrieved by its content hash.
+    let hash_a = program_a.to_content_hash().unwrap();
+    assert!(process.contains_program_hash(&hash_a));
+    assert_eq!(process.get_program_by_hash(&hash_a).unwrap(), &program_a);
+    assert_eq!(process.get_stack_by_hash(&hash_a).unwrap().program_id(), program_a.id());
+
+    // Ensure an unknown content hash is not found.
+    let hash_b = program_b.to_content_hash().unwrap();
+    assert_ne!(hash_a, hash_b);
+    assert!(!process.contains_program_hash(&hash_b));
+    assert!(process.get_program_by_hash(&hash_b).is_err());
+
+    // Ensure the program fails to be added if its import is pinned to a different content hash.
+    let import_hashes = IndexMap::from([(*program_a.id(), hash_b)]);
+    assert!(process.add_program_with_pinned_imports(&program_b, &import_hashes).is_err());
+    assert!(!process.contains_program(program_b.id()));
+
+    // Ensure the program is added if its import is pinned to the correct content hash.
+    let import_hashes = IndexMap::from([(*program_a.id(), hash_a)]);
+    process.add_program_with_pinned_imports(&program_b, &import_hashes).unwrap();
+    assert_eq!(process.get_program_by_hash(&hash_b).unwrap(), &program_b);
+}
+
+#[test]
+fn test_process_shares_external_stacks() {
+    // Initialize a program, and two programs that import it.
+    let child = Program::<CurrentNetwork>::from_str(
+        r"
+program child.aleo;
+
+function compute:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+    )
+    .unwrap();
+    let parent = |name: &str| {
+        Program::<CurrentNetwork>::from_str(&format!(
+            r"
+import child.aleo;
+
+program {name}.aleo;
+
+function compute:
+    input r0 as u8.private;
+    call child.aleo/compute r0 into r1;
+    output r1 as u8.private;"
+        ))
+        .unwrap()
+    };
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&child);
+    process.add_program(&parent("parent_a")).unwrap();
+    process.add_program(&parent("parent_b")).unwrap();
+
+    // Ensure each importer holds the stack of the child in the process, rather than a copy of it.
+    let stack = process.get_stack(child.id()).unwrap();
+    for name in ["parent_a.aleo", "parent_b.aleo"] {
+        let external_stack = process.get_stack(name).unwrap().get_external_stack(child.id()).unwrap();
+        assert!(Arc::ptr_eq(stack, external_stack));
+    }
+    assert_eq!(Arc::strong_count(stack), 3);
+}
+
+#[test]
+fn test_process_remove_program() {
+    // Initialize the programs.
+    let program_a = Program::<CurrentNetwork>::from_str(
+        r"
+program child.aleo;
+
+function compute:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+    )
+    .unwrap();
+    let program_b = Program::<CurrentNetwork>::from_str(
+        r"
+import child.aleo;
+
+program parent.aleo;
+
+function compute:
+    input r0 as u8.private;
+    call child.aleo/compute r0 into r1;
+    output r1 as u8.private;",
+    )
+    .unwrap();
+    let program_c = Program::<CurrentNetwork>::from_str(
+        r"
+import child.aleo;
+
+program sibling.aleo;
+
+function compute:
+    input r0 as u8.private;
+    call child.aleo/compute r0 into r1;
+    output r1 as u8.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program_a);
+    process.add_program(&program_b).unwrap();
+    process.add_program(&program_c).unwrap();
+
+    // Synthesize the keys of the parent program.
+    let function_name = Identifier::from_str("compute").unwrap();
+    process.synthesize_key::<CurrentAleo, _>(program_b.id(), &function_name, &mut TestRng::default()).unwrap();
+    let parent = process.get_stack(program_b.id()).unwrap().clone();
+    assert!(parent.contains_proving_key(&function_name));
+
+    // Ensure 'credits.aleo', unknown programs, and imported programs cannot be removed.
+    assert!(process.remove_program(&ProgramID::from_str("credits.aleo").unwrap()).is_err());
+    assert!(process.remove_program(&ProgramID::from_str("unknown.aleo").unwrap()).is_err());
+    assert!(process.remove_program(program_a.id()).is_err());
+
+    // Ensure the parent is evicted with its keys, but the child is kept while it is still imported.
+    assert_eq!(process.remove_program(program_b.id()).unwrap(), vec![*program_b.id()]);
+    assert!(!process.contains_program(program_b.id()));
+    assert!(!process.contains_program_hash(&program_b.to_content_hash().unwrap()));
+    assert!(!parent.contains_proving_key(&function_name));
+    assert!(!parent.contains_verifying_key(&function_name));
+    assert!(process.contains_program(program_a.id()));
+
+    // Ensure the child is evicted with the last program that imports it.
+    assert_eq!(process.remove_program(program_c.id()).unwrap(), vec![*program_c.id(), *program_a.id()]);
+    assert!(!process.contains_program(program_a.id()));
+    assert!(process.contains_program(&ProgramID::from_str("credits.aleo").unwrap()));
+
+    // Ensure a removed program can be added again.
+    process.add_program(&program_a).unwrap();
+    assert!(process.contains_program(program_a.id()));
+}
+
+#[test]
+fn test_process_struct_imports() {
+    // Initialize the programs.
+    let shapes = Program::<CurrentNetwork>::from_str(
+        r"
+program shapes.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+struct segment:
+    start as point;
+    end as point;
+
+function noop:
+    input r0 as field.private;
+    output r0 as field.private;",
+    )
+    .unwrap();
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+import shapes.aleo;
+
+program drawing.aleo;
+
+struct shapes.aleo/point;
+
+struct shapes.aleo/segment;
+
+function draw:
+    input r0 as field.private;
+    input r1 as field.private;
+    cast r0 r1 into r2 as point;
+    cast r2 r2 into r3 as segment;
+    output r3 as segment.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&shapes);
+    process.add_program(&program).unwrap();
+
+    // Ensure the imported structs are resolved by the stack.
+    let stack = process.get_stack(program.id()).unwrap();
+    let point = Identifier::from_str("point").unwrap();
+    assert_eq!(stack.get_struct(&point).unwrap(), shapes.get_struct(&point).unwrap());
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Declare the function inputs.
+    let inputs = [
+        Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("1field").unwrap()),
+        Value::Plaintext(Plaintext::from_str("2field").unwrap()),
+    ];
+    // Authorize and evaluate the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            Identifier::from_str("draw").unwrap(),
+            inputs.iter(),
+            rng,
+        )
+        .unwrap();
+    let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+    let expected = Value::Plaintext(
+        Plaintext::from_str("{ start: { x: 1field, y: 2field }, end: { x: 1field, y: 2field } }").unwrap(),
+    );
+    assert_eq!(response.outputs(), &[expected]);
+
+    // Ensure a nested struct that does not match its imported definition is rejected.
+    let mismatched = Program::<CurrentNetwork>::from_str(
+        r"
+import shapes.aleo;
+
+program mismatched.aleo;
+
+struct point:
+    x as field;
+    y as u8;
+
+struct shapes.aleo/segment;
+
+function noop:
+    input r0 as field.private;
+    output r0 as field.private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&mismatched).is_err());
+
+    // Ensure a nested struct that is not declared is rejected.
+    let undeclared = Program::<CurrentNetwork>::from_str(
+        r"
+import shapes.aleo;
+
+program undeclared.aleo;
+
+struct shapes.aleo/segment;
+
+function noop:
+    input r0 as field.private;
+    output r0 as field.private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&undeclared).is_err());
+}
+
+#[test]
+fn test_process_warm_up() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program warm_up.aleo;
+
+function double:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+
+function square:
+    input r0 as u32.private;
+    mul r0 r0 into r1;
+    output r1 as u32.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    let stack = process.get_stack(program.id()).unwrap();
+    let double = Identifier::from_str("double").unwrap();
+    let square = Identifier::from_str("square").unwrap();
+    assert!(!stack.contains_proving_key(&double));
+    assert!(!stack.contains_proving_key(&square));
+
+    // Ensure invalid targets and parallelism are rejected.
+    let unknown = WarmUpTarget::Function(Locator::from_str("warm_up.aleo/unknown").unwrap());
+    assert!(process.warm_up::<CurrentAleo, _>(&[unknown], 1, rng).is_err());
+    assert!(process.warm_up::<CurrentAleo, _>(&[WarmUpTarget::Program(*program.id())], 0, rng).is_err());
+
+    // Warm up the program, prioritizing 'square'.
+    let targets = [WarmUpTarget::Function(Locator::new(*program.id(), square)), WarmUpTarget::Program(*program.id())];
+    let warm_up = process.warm_up::<CurrentAleo, _>(&targets, 2, rng).unwrap();
+    let progress = warm_up.wait().unwrap();
+    assert!(progress.is_finished());
+    assert_eq!(progress.functions(), &[Locator::new(*program.id(), square), Locator::new(*program.id(), double)]);
+    assert_eq!(progress.num_completed(), 2);
+    assert!(progress.failures().is_empty());
+
+    // Ensure the circuit keys are available to the process.
+    assert!(stack.contains_proving_key(&double));
+    assert!(stack.contains_verifying_key(&square));
+}
+
+#[test]
+fn test_stack_synthesize_all_keys() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program synthesize_all.aleo;
+
+function double:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+
+function square:
+    input r0 as u32.private;
+    mul r0 r0 into r1;
+    output r1 as u32.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    let stack = process.get_stack(program.id()).unwrap();
+
+    // Synthesize the circuit keys for every function, recording the reported progress.
+    let reports = Mutex::new(Vec::new());
+    let progress =
+        stack.synthesize_all_keys::<CurrentAleo, _>(|progress| reports.lock().push(progress.num_completed()), rng);
+    assert!(progress.is_finished());
+    assert_eq!(progress.num_total(), 2);
+    assert_eq!(progress.num_completed(), 2);
+    assert!(progress.failures().is_empty());
+    assert_eq!(reports.into_inner(), vec![1, 2]);
+
+    // Ensure the circuit keys are available to the process.
+    for function_name in program.functions().keys() {
+        assert!(stack.contains_proving_key(function_name));
+        assert!(stack.contains_verifying_key(function_name));
+    }
+}
+
+#[test]
+fn test_process_execute_and_finalize_failed_execution() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async compute r0 r1 into r2;
+    output r2 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r1 r2 into r3;
+    set r3 into account[r0];
+    assert.eq r1 0u64;
+    hash.bhp256 r3 into r4 as field;
+    set r3 into account[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("account").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a new process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Prepare an execution of `compute` that aborts in finalize.
+    let inputs = [Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(), Value::from_str("3u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, inputs.iter(), rng)
+        .unwrap();
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    trace.prepare(Query::from(&block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Ensure the execution aborts, and the state change is rejected.
+    assert!(process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).is_err());
+    let key = Plaintext::from(Literal::Address(caller));
+    assert!(finalize_store.get_value_speculative(*program_id, mapping_name, &key).unwrap().is_none());
+
+    // Compute a fee for the execution, which exceeds its cost.
+    let (total_cost, (storage_cost, finalize_cost)) = crate::execution_cost(&process, &execution).unwrap();
+    let execution_id = execution.to_execution_id().unwrap();
+    let authorization = process
+        .authorize_fee_public::<CurrentAleo, _>(&caller_private_key, total_cost, 1_000, execution_id, rng)
+        .unwrap();
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    trace.prepare(Query::from(&block_store)).unwrap();
+    let fee = trace.prove_fee::<CurrentAleo, _>(rng).unwrap();
+
+    // Ensure the failed execution holds the cost of the execution.
+    let failed = crate::failed_execution(&process, &execution, &fee).unwrap();
+    assert_eq!(failed.storage_cost(), storage_cost);
+    assert_eq!(failed.finalize_cost(), finalize_cost);
+    assert_eq!(failed.total_cost(), total_cost);
+    assert!(crate::check_failed_execution(&process, &failed).is_ok());
+
+    // Ensure a failed execution that consumes a different finalize cost is rejected.
+    let invalid = FailedExecution::new(execution.clone(), fee.clone(), storage_cost, finalize_cost - 1).unwrap();
+    assert!(crate::check_failed_execution(&process, &invalid).is_err());
+    // Ensure a failed execution with a fee for another execution is rejected.
+    let other_fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    assert!(crate::failed_execution(&process, &execution, &other_fee).is_err());
+}
+
+#[test]
+fn test_process_execution_metrics() {
+    // Initialize a program, and a program that calls it.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program metrics_inner.aleo;
+
+function square:
+    input r0 as u64.private;
+    mul r0 r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import metrics_inner.aleo;
+
+program metrics_outer.aleo;
+
+function compute:
+    input r0 as u64.private;
+    call metrics_inner.aleo/square r0 into r1;
+    add r1 r0 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Execute the function.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), "compute", ["3u64"].into_iter(), rng)
+        .unwrap();
+    let (response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("12u64").unwrap()]);
+
+    // Ensure the metrics are returned for each transition, in order.
+    let metrics = trace.execution_metrics(rng).unwrap();
+    assert_eq!(metrics.len(), 2);
+    assert_eq!(metrics[0].program_id, *program0.id());
+    assert_eq!(metrics[1].program_id, *program1.id());
+    for (metrics, call_metrics) in metrics.iter().zip_eq(trace.call_metrics()) {
+        assert_eq!(metrics.function_name, call_metrics.function_name);
+        assert_eq!(metrics.synthesis_time, call_metrics.synthesis_time);
+        assert!(metrics.num_constraints > 0);
+        assert!(metrics.proof_size > 0);
+        // Ensure the circuit accounts for the request, function, and response constraints.
+        let num_call_constraints = call_metrics.num_request_constraints
+            + call_metrics.num_function_constraints
+            + call_metrics.num_response_constraints;
+        assert!(metrics.num_constraints >= num_call_constraints);
+    }
+}
+
+#[test]
+fn test_process_execute_with_cancellation() {
+    // Initialize a program, and a program that calls it.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program cancel_inner.aleo;
+
+function square:
+    input r0 as u64.private;
+    mul r0 r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import cancel_inner.aleo;
+
+program cancel_outer.aleo;
+
+function compute:
+    input r0 as u64.private;
+    call cancel_inner.aleo/square r0 into r1;
+    add r1 r0 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Authorize the function.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), "compute", ["3u64"].into_iter(), rng)
+        .unwrap();
+
+    // Ensure a cancelled execution is halted.
+    let cancellation = CancellationToken::new();
+    cancellation.cancel();
+    let error = process.execute_with_cancellation::<CurrentAleo, _>(authorization.replicate(), &cancellation, rng);
+    assert_eq!(error.unwrap_err().downcast_ref::<ExecutionCancelled>(), Some(&ExecutionCancelled));
+
+    // Ensure an execution that is not cancelled completes.
+    let cancellation = CancellationToken::new();
+    let (response, mut trace) =
+        process.execute_with_cancellation::<CurrentAleo, _>(authorization, &cancellation, rng).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("12u64").unwrap()]);
+    assert!(!trace.cancellation_token().is_cancelled());
+
+    // Ensure the proving of the trace is halted once cancelled.
+    trace.prepare(Query::from(block_store)).unwrap();
+    cancellation.cancel();
+    let error = trace.prove_execution::<CurrentAleo, _>("cancel_outer.aleo/compute", rng);
+    assert_eq!(error.unwrap_err().downcast_ref::<ExecutionCancelled>(), Some(&ExecutionCancelled));
+}
+
+#[test]
+fn test_process_execute_with_inlined_closures() {
+    // Initialize a program with closure calls.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program inline_closures.aleo;
+
+struct pair:
+    left as u64;
+    right as u64;
+
+closure sum:
+    input r0 as pair;
+    add r0.left r0.right into r1;
+    output r1 as u64;
+    output r0.left as u64;
+
+closure scale:
+    input r0 as u64;
+    input r1 as u64;
+    mul r1 r0 into r2;
+    output r2 as u64;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as u64.public;
+    cast r0 r1 into r2 as pair;
+    call sum r2 into r3 r4;
+    call scale 3u64 r3 into r5;
+    add r5 r4 into r6;
+    output r6 as u64.private;
+    output r4 as u64.public;",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Construct a process with the closures inlined.
+    let mut process = Process::load().unwrap();
+    let mut stack = Stack::new(&process, &program).unwrap();
+    assert_eq!(stack.inline_closures(0).unwrap(), 0);
+    assert_eq!(stack.inline_closures(16).unwrap(), 1);
+    let inlined = stack.get_inlined_function(&function_name).unwrap().clone();
+    assert_eq!(inlined.instructions().iter().map(|instruction| instruction.to_string()).collect::<Vec<_>>(), [
+        "cast r0 r1 into r2 as pair;",
+        "add r2.left r2.right into r3;",
+        "mul r3 3u64 into r4;",
+        "add r4 r2.left into r5;",
+    ]);
+    process.add_stack(stack);
+
+    // Ensure the process inlines the closures of the programs it adds, once enabled.
+    let mut candidate_process = Process::load().unwrap();
+    assert_eq!(candidate_process.closure_inlining(), None);
+    candidate_process.set_closure_inlining(Some(16));
+    candidate_process.add_program(&program).unwrap();
+    let candidate_stack = candidate_process.get_stack(program.id()).unwrap();
+    assert_eq!(candidate_stack.get_inlined_function(&function_name), Some(&inlined));
+
+    // Construct a process with the program as written.
+    let expected_process = crate::test_helpers::sample_process(&program);
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Authorize the function.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            function_name,
+            ["2u64", "5u64"].into_iter(),
+            rng,
+        )
+        .unwrap();
+    let expected = [Value::from_str("23u64").unwrap(), Value::from_str("2u64").unwrap()];
+
+    // Ensure the inlined function is evaluated and executed.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), &expected);
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), &expected);
+
+    // Ensure the execution verifies against the keys of the program as written.
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("inline_closures.aleo/compute", rng).unwrap();
+    expected_process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+    expected_process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_process_execute_call_view() {
+    // Initialize a program with a pure function, and a program that views it.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program view_inner.aleo;
+
+function quote:
+    input r0 as u64.public;
+    input r1 as u64.private;
+    mul r0 r1 into r2;
+    add r2 1u64 into r3;
+    output r3 as u64.private;
+
+function whoami:
+    input r0 as u64.public;
+    is.eq self.caller self.signer into r1;
+    output r1 as boolean.public;",
+    )
+    .unwrap();
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import view_inner.aleo;
+
+program view_outer.aleo;
+
+function compute:
+    input r0 as u64.private;
+    call.view view_inner.aleo/quote r0 2u64 into r1;
+    add r1 r0 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    process.add_program(&program1).unwrap();
+
+    // Ensure a function that reads the caller cannot be viewed.
+    let program2 = Program::<CurrentNetwork>::from_str(
+        r"
+import view_inner.aleo;
+
+program view_caller.aleo;
+
+function compute:
+    input r0 as u64.private;
+    call.view view_inner.aleo/whoami r0 into r1;
+    output r1 as boolean.private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program2).is_err());
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Authorize the function.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), "compute", ["3u64"].into_iter(), rng)
+        .unwrap();
+    // Ensure the view call does not add a request.
+    assert_eq!(authorization.len(), 1);
+    let expected = [Value::from_str("10u64").unwrap()];
+
+    // Ensure the view call is evaluated and executed.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), &expected);
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), &expected);
+
+    // Ensure the view call does not produce a transition of its own.
+    assert_eq!(trace.transitions().len(), 1);
+    assert_eq!(trace.transitions()[0].program_id(), program1.id());
+
+    // Ensure the execution verifies.
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("view_outer.aleo/compute", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+}
+
+#[cfg(feature = "host-functions")]
+#[test]
+fn test_process_execute_call_host() {
+    // Note: Host functions are not enabled on mainnet.
+    type CurrentNetwork = console::network::TestnetV0;
+    type CurrentAleo = circuit::network::AleoTestnetV0;
+
+    // Initialize a program that calls a host function.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program host_call.aleo;
+
+function compute:
+    input r0 as u64.private;
+    call.host double r0 into r1;
+    add r1 1u64 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    // Ensure the program cannot be added before the host function is registered.
+    assert!(process.add_program(&program).is_err());
+
+    // Register a host function that doubles a u64.
+    let u64_type = PlaintextType::from_str("u64").unwrap();
+    let host_function =
+        HostFunction::new(Identifier::from_str("double").unwrap(), vec![u64_type.clone()], vec![u64_type], |inputs| {
+            match &inputs[0] {
+                Value::Plaintext(Plaintext::Literal(Literal::U64(value), _)) => {
+                    let value = (**value).checked_add(**value).ok_or_else(|| anyhow!("Overflow"))?;
+                    Ok(vec![Value::from(Literal::U64(U64::new(value)))])
+                }
+                _ => bail!("Expected a u64"),
+            }
+        })
+        .unwrap()
+        .with_synthesizer::<CurrentAleo>(|inputs| match &inputs[0] {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::U64(value), _)) => {
+                Ok(vec![circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::U64(
+                    value.clone() + value.clone(),
+                )))])
+            }
+            _ => bail!("Expected a u64"),
+        });
+    process.register_host_function(host_function).unwrap();
+    process.add_program(&program).unwrap();
+
+    // Ensure a call that does not match the signature of the host function is rejected.
+    let mismatched = Program::<CurrentNetwork>::from_str(
+        r"
+program host_mismatch.aleo;
+
+function compute:
+    input r0 as field.private;
+    call.host double r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&mismatched).is_err());
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Authorize the function.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", ["20u64"].into_iter(), rng)
+        .unwrap();
+    let expected = [Value::from_str("41u64").unwrap()];
+
+    // Ensure the host call is evaluated and executed.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), &expected);
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), &expected);
+
+    // Ensure the execution verifies.
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("host_call.aleo/compute", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_process_execute_with_sink() {
+    // Initialize a program, and a program that calls it twice.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program sink_inner.aleo;
+
+function double:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import sink_inner.aleo;
+
+program sink_outer.aleo;
+
+function quadruple:
+    input r0 as u64.private;
+    call sink_inner.aleo/double r0 into r1;
+    call sink_inner.aleo/double r1 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Authorize the function.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), "quadruple", ["3u64"].into_iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 3);
+
+    // Execute the function, streaming the transitions to an execution writer.
+    let writer = Arc::new(Mutex::new(ExecutionWriter::new(Vec::new(), authorization.len()).unwrap()));
+    let (response, mut trace) =
+        process.execute_with_sink::<CurrentAleo, _>(authorization, writer.clone(), rng).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("12u64").unwrap()]);
+    // Ensure each transition was streamed, and the trace does not keep the writer.
+    assert_eq!(writer.lock().num_written(), 3);
+    let writer = Arc::try_unwrap(writer).ok().unwrap().into_inner();
+
+    // Ensure proving fails before the trace is prepared, leaving the writer to be finished or aborted.
+    let locator = "sink_outer.aleo/quadruple";
+    assert!(trace.prove_execution_for_sink::<CurrentAleo, _, _>(locator, &writer, rng).is_err());
+
+    // Prove the execution, and finish the writer.
+    trace.prepare(Query::from(block_store)).unwrap();
+    let (global_state_root, proof) =
+        trace.prove_execution_for_sink::<CurrentAleo, _, _>(locator, &writer, rng).unwrap();
+    let bytes = writer.finish(global_state_root, Some(&proof)).unwrap();
+
+    // Ensure the streamed execution matches the trace, and verifies.
+    let execution = Execution::<CurrentNetwork>::read_le(&bytes[..]).unwrap();
+    assert_eq!(execution.transitions().cloned().collect::<Vec<_>>(), trace.transitions());
+    process.verify_execution(&execution).unwrap();
+}
@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use console::{
-    network::Network,
+    network::prelude::*,
     program::{Identifier, ProgramID},
 };
 
+use std::time::Duration;
+
 #[derive(Copy, Clone, Debug)]
 pub struct CallMetrics<N: Network> {
     pub program_id: ProgramID<N>,
@@ -25,4 +27,48 @@ pub struct CallMetrics<N: Network> {
     pub num_request_constraints: u64,
     pub num_function_constraints: u64,
     pub num_response_constraints: u64,
+    /// The time to synthesize the circuit assignment, excluding the time of its nested calls.
+    pub synthesis_time: Duration,
+}
+
+impl<N: Network> FromBytes for CallMetrics<N> {
+    /// Reads the call metrics from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let program_id = ProgramID::read_le(&mut reader)?;
+        let function_name = Identifier::read_le(&mut reader)?;
+        let num_instructions = usize::try_from(u64::read_le(&mut reader)?).map_err(error)?;
+        let num_request_constraints = u64::read_le(&mut reader)?;
+        let num_function_constraints = u64::read_le(&mut reader)?;
+        let num_response_constraints = u64::read_le(&mut reader)?;
+        // Read the synthesis time.
+        let seconds = u64::read_le(&mut reader)?;
+        let nanoseconds = u32::read_le(&mut reader)?;
+        if nanoseconds >= 1_000_000_000 {
+            return Err(error("Invalid synthesis time in the call metrics"));
+        }
+        Ok(Self {
+            program_id,
+            function_name,
+            num_instructions,
+            num_request_constraints,
+            num_function_constraints,
+            num_response_constraints,
+            synthesis_time: Duration::new(seconds, nanoseconds),
+        })
+    }
+}
+
+impl<N: Network> ToBytes for CallMetrics<N> {
+    /// Writes the call metrics to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.program_id.write_le(&mut writer)?;
+        self.function_name.write_le(&mut writer)?;
+        (self.num_instructions as u64).write_le(&mut writer)?;
+        self.num_request_constraints.write_le(&mut writer)?;
+        self.num_function_constraints.write_le(&mut writer)?;
+        self.num_response_constraints.write_le(&mut writer)?;
+        // Write the synthesis time.
+        self.synthesis_time.as_secs().write_le(&mut writer)?;
+        self.synthesis_time.subsec_nanos().write_le(&mut writer)
+    }
 }
@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::Network,
+    program::{Identifier, ProgramID},
+};
+
+use std::time::Duration;
+
+/// The metrics of a single transition in an execution.
+#[derive(Copy, Clone, Debug)]
+pub struct ExecutionMetrics<N: Network> {
+    /// The program ID of the transition.
+    pub program_id: ProgramID<N>,
+    /// The function name of the transition.
+    pub function_name: Identifier<N>,
+    /// The number of constraints in the circuit of the transition.
+    pub num_constraints: u64,
+    /// The time to synthesize the circuit assignment, excluding the time of its nested calls.
+    pub synthesis_time: Duration,
+    /// The time to prove the transition on its own.
+    pub proving_time: Duration,
+    /// The size of the proof of the transition on its own, in bytes.
+    pub proof_size: usize,
+}
@@ -15,25 +15,36 @@
 mod call_metrics;
 pub use call_metrics::*;
 
+mod execution_metrics;
+pub use execution_metrics::*;
+
 mod inclusion;
 pub use inclusion::*;
 
+mod transition_sink;
+use transition_sink::SharedTransitionSink;
+pub use transition_sink::*;
+
+use crate::{CancellationToken, ExecutionCancelled, ProvingParallelism};
 use circuit::Assignment;
 use console::{
     network::prelude::*,
     program::{InputID, Locator},
 };
-use ledger_block::{Execution, Fee, Transition};
+use ledger_block::{Execution, ExecutionWriter, Fee, Transition};
 use ledger_query::QueryTrait;
 use synthesizer_snark::{Proof, ProvingKey, VerifyingKey};
 
 use once_cell::sync::OnceCell;
-use std::collections::HashMap;
+use parking_lot::Mutex;
+use std::{collections::HashMap, sync::Arc, time::Instant};
 
 #[derive(Clone, Debug, Default)]
 pub struct Trace<N: Network> {
     /// The list of transitions.
     transitions: Vec<Transition<N>>,
+    /// The list of input IDs, for each transition.
+    input_ids: Vec<Vec<InputID<N>>>,
     /// A map of locators to (proving key, assignments) pairs.
     transition_tasks: HashMap<Locator<N>, (ProvingKey<N>, Vec<Assignment<N::Field>>)>,
     /// A tracker for all inclusion tasks.
@@ -45,6 +56,10 @@ pub struct Trace<N: Network> {
     inclusion_assignments: OnceCell<Vec<InclusionAssignment<N>>>,
     /// A tracker for the global state root.
     global_state_root: OnceCell<N::StateRoot>,
+    /// The token to cancel the execution and proving of the trace.
+    cancellation: CancellationToken,
+    /// The sink to stream each transition to, as it is inserted into the trace.
+    transition_sink: Option<SharedTransitionSink<N>>,
 }
 
 impl<N: Network> Trace<N> {
@@ -52,11 +67,14 @@ impl<N: Network> Trace<N> {
     pub fn new() -> Self {
         Self {
             transitions: Vec::new(),
+            input_ids: Vec::new(),
             transition_tasks: HashMap::new(),
             inclusion_tasks: Inclusion::new(),
             inclusion_assignments: OnceCell::new(),
             global_state_root: OnceCell::new(),
             call_metrics: Vec::new(),
+            cancellation: CancellationToken::default(),
+            transition_sink: None,
         }
     }
 
@@ -69,6 +87,69 @@ impl<N: Network> Trace<N> {
     pub fn call_metrics(&self) -> &[CallMetrics<N>] {
         &self.call_metrics
     }
+
+    /// Returns the cancellation token of the trace.
+    pub const fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    /// Sets the cancellation token of the trace, which halts its execution and proving once cancelled.
+    pub fn set_cancellation_token(&mut self, cancellation: CancellationToken) {
+        self.cancellation = cancellation;
+    }
+
+    /// Sets the sink of the trace, to which each transition is streamed as it is inserted into the trace.
+    pub(crate) fn set_transition_sink(&mut self, sink: Arc<Mutex<dyn TransitionSink<N>>>) {
+        self.transition_sink = Some(SharedTransitionSink(sink));
+    }
+
+    /// Removes the sink of the trace, so that the caller holds the only handle on the sink.
+    pub(crate) fn clear_transition_sink(&mut self) {
+        self.transition_sink = None;
+    }
+
+    /// Returns the metrics of each transition in the trace, in order.
+    ///
+    /// As the transitions of an execution are proven in a single batch, each transition is proven again
+    /// on its own to measure its proving time and proof size. This is intended for profiling, and is as
+    /// expensive as proving the execution. The inclusion proof is not included in the metrics.
+    pub fn execution_metrics<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Vec<ExecutionMetrics<N>>> {
+        // Initialize a counter for the assignments that were measured, for each locator.
+        let mut num_measured = HashMap::<Locator<N>, usize>::new();
+        // Measure each transition.
+        self.transitions
+            .iter()
+            .zip_eq(&self.call_metrics)
+            .map(|(transition, metrics)| {
+                // Construct the locator.
+                let locator = Locator::new(*transition.program_id(), *transition.function_name());
+                // Retrieve the proving key and the assignment of the transition.
+                let index = num_measured.entry(locator).or_default();
+                let Some((proving_key, assignment)) = self
+                    .transition_tasks
+                    .get(&locator)
+                    .and_then(|(proving_key, assignments)| Some((proving_key, assignments.get(*index)?)))
+                else {
+                    bail!("Missing the assignment for '{locator}' in the trace")
+                };
+                *index += 1;
+
+                // Prove the transition.
+                let start = Instant::now();
+                let proof = proving_key.prove(&transition.function_name().to_string(), assignment, rng)?;
+                let proving_time = start.elapsed();
+
+                Ok(ExecutionMetrics {
+                    program_id: metrics.program_id,
+                    function_name: metrics.function_name,
+                    num_constraints: assignment.num_constraints(),
+                    synthesis_time: metrics.synthesis_time,
+                    proving_time,
+                    proof_size: proof.to_bytes_le()?.len(),
+                })
+            })
+            .collect()
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -93,11 +174,52 @@ impl<N: Network> Trace<N> {
         self.transition_tasks.entry(locator).or_insert((proving_key, vec![])).1.push(assignment);
         // Insert the transition into the list.
         self.transitions.push(transition.clone());
+        // Insert the input IDs into the list.
+        self.input_ids.push(input_ids.to_vec());
         // Insert the call metrics into the list.
         self.call_metrics.push(metrics);
+        // Stream the transition to the sink, if one is set.
+        if let Some(sink) = &self.transition_sink {
+            sink.0.lock().write_transition(transition)?;
+        }
 
         Ok(())
     }
+
+    /// Appends the transitions of the given trace, in order, as if they were inserted into this trace.
+    pub(crate) fn append(&mut self, other: Trace<N>) -> Result<()> {
+        for (input_ids, transition, task, metrics) in other.into_transitions()? {
+            self.insert_transition(&input_ids, &transition, task, metrics)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the transitions of the trace, in order, with their input IDs, proving task, and call metrics.
+    pub(crate) fn into_transitions(
+        self,
+    ) -> Result<Vec<(Vec<InputID<N>>, Transition<N>, (ProvingKey<N>, Assignment<N::Field>), CallMetrics<N>)>> {
+        let Trace { transitions, input_ids, mut transition_tasks, call_metrics, .. } = self;
+        // Reverse the assignments for each locator, so that they are popped in order.
+        transition_tasks.values_mut().for_each(|(_, assignments)| assignments.reverse());
+        // Pair each transition with its assignment.
+        transitions
+            .into_iter()
+            .zip_eq(input_ids)
+            .zip_eq(call_metrics)
+            .map(|((transition, input_ids), metrics)| {
+                // Construct the locator.
+                let locator = Locator::new(*transition.program_id(), *transition.function_name());
+                // Retrieve the proving key and the next assignment for the locator.
+                let Some((proving_key, assignments)) = transition_tasks.get_mut(&locator) else {
+                    bail!("Missing the proving key for '{locator}' in the trace")
+                };
+                let Some(assignment) = assignments.pop() else {
+                    bail!("Missing an assignment for '{locator}' in the trace")
+                };
+                Ok((input_ids, transition, (proving_key.clone(), assignment), metrics))
+            })
+            .collect()
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -152,28 +274,67 @@ impl<N: Network> Trace<N> {
         locator: &str,
         rng: &mut R,
     ) -> Result<Execution<N>> {
-        // Ensure this is not a fee.
-        ensure!(!self.is_fee(), "The trace cannot call 'prove_execution' for a fee type");
-        // Ensure there are no fee transitions.
-        ensure!(
-            self.transitions.iter().all(|transition| !(transition.is_fee_private() || transition.is_fee_public())),
-            "The trace cannot prove execution for a fee, call 'prove_fee' instead"
-        );
-        // Retrieve the inclusion assignments.
-        let inclusion_assignments =
-            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
-        // Retrieve the global state root.
-        let global_state_root =
-            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
-        // Construct the proving tasks.
-        let proving_tasks = self.transition_tasks.values().cloned().collect();
         // Compute the proof.
-        let (global_state_root, proof) =
-            Self::prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, rng)?;
+        let (global_state_root, proof) = self.prove_execution_batch::<A, R>(locator, rng)?;
         // Return the execution.
         Execution::from(self.transitions.iter().cloned(), global_state_root, Some(proof))
     }
 
+    /// Writes a new execution with a proof to the given sink, for the current inclusion assignments and global state root.
+    /// The transitions are streamed to the sink without constructing the full `Execution` in memory.
+    ///
+    /// The execution is proven before anything is written, so nothing is written to the sink if proving fails.
+    pub fn prove_execution_to_writer<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng, W: Write>(
+        &self,
+        locator: &str,
+        writer: W,
+        rng: &mut R,
+    ) -> Result<W> {
+        // Compute the proof.
+        let (global_state_root, proof) = self.prove_execution_batch::<A, R>(locator, rng)?;
+        // Initialize the execution writer.
+        let mut writer = ExecutionWriter::<N, W>::new(writer, self.transitions.len())?;
+        // Write the transitions.
+        for transition in &self.transitions {
+            writer.write_transition(transition)?;
+        }
+        // Write the global state root and proof.
+        Ok(writer.finish(global_state_root, Some(&proof))?)
+    }
+
+    /// Returns the global state root and proof for the given execution writer, for the current inclusion assignments
+    /// and global state root. The transitions must have already been streamed to the writer during execution
+    /// (see `Process::execute_with_sink`).
+    ///
+    /// On success, the caller completes the execution with `ExecutionWriter::finish`.
+    /// On failure, the caller abandons the execution with `ExecutionWriter::abort`, and discards the bytes written.
+    pub fn prove_execution_for_sink<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng, W: Write>(
+        &self,
+        locator: &str,
+        writer: &ExecutionWriter<N, W>,
+        rng: &mut R,
+    ) -> Result<(N::StateRoot, Proof<N>)> {
+        // Ensure the transitions have been streamed to the writer.
+        ensure!(
+            writer.num_written() == self.transitions.len(),
+            "Expected {} transitions in the execution writer, found {}",
+            self.transitions.len(),
+            writer.num_written()
+        );
+        // Compute the proof.
+        self.prove_execution_batch::<A, R>(locator, rng)
+    }
+
+    /// Returns a new execution with a proof, computed in the thread pool of the given parallelism settings.
+    pub fn prove_execution_with_parallelism<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng + Send>(
+        &self,
+        locator: &str,
+        parallelism: &ProvingParallelism,
+        rng: &mut R,
+    ) -> Result<Execution<N>> {
+        parallelism.install(|| self.prove_execution::<A, R>(locator, rng))
+    }
+
     /// Returns a new fee with a proof, for the current inclusion assignment and global state root.
     pub fn prove_fee<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Fee<N>> {
         // Ensure this is a fee.
@@ -196,7 +357,7 @@ impl<N: Network> Trace<N> {
         // Construct the proving tasks.
         let proving_tasks = self.transition_tasks.values().cloned().collect();
         // Compute the proof.
-        let (global_state_root, proof) = Self::prove_batch::<A, R>(
+        let (global_state_root, proof) = self.prove_batch::<A, R>(
             "credits.aleo/fee (private or public)",
             proving_tasks,
             inclusion_assignments,
@@ -229,6 +390,35 @@ impl<N: Network> Trace<N> {
         }
     }
 
+    /// Checks the proofs for the given executions, together.
+    /// Note: This does *not* check that the global state roots exist in the ledger.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_execution_proofs<R: Rng + CryptoRng>(
+        executions: Vec<(Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, &Execution<N>)>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Construct the verifier inputs and proof for each execution.
+        let mut batches = Vec::with_capacity(executions.len());
+        for (mut verifier_inputs, execution) in executions {
+            // Retrieve the global state root.
+            let global_state_root = execution.global_state_root();
+            // Ensure the global state root is not zero.
+            if global_state_root == N::StateRoot::default() {
+                bail!("Inclusion expected the global state root in the execution to *not* be zero")
+            }
+            // Retrieve the proof.
+            let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
+            // Insert the inclusion verifier inputs.
+            Self::add_inclusion_verifier_inputs(&mut verifier_inputs, global_state_root, execution.transitions())?;
+            batches.push((verifier_inputs, proof));
+        }
+        // Verify the execution proofs.
+        match VerifyingKey::verify_batches("executions", batches, rng) {
+            Ok(()) => Ok(()),
+            Err(e) => bail!("Executions are invalid - {e}"),
+        }
+    }
+
     /// Checks the proof for the fee.
     /// Note: This does *not* check that the global state root exists in the ledger.
     pub fn verify_fee_proof(verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>), fee: &Fee<N>) -> Result<()> {
@@ -255,8 +445,34 @@ impl<N: Network> Trace<N> {
 }
 
 impl<N: Network> Trace<N> {
+    /// Returns the global state root and execution proof, for the current inclusion assignments and global state root.
+    fn prove_execution_batch<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        locator: &str,
+        rng: &mut R,
+    ) -> Result<(N::StateRoot, Proof<N>)> {
+        // Ensure this is not a fee.
+        ensure!(!self.is_fee(), "The trace cannot call 'prove_execution' for a fee type");
+        // Ensure there are no fee transitions.
+        ensure!(
+            self.transitions.iter().all(|transition| !(transition.is_fee_private() || transition.is_fee_public())),
+            "The trace cannot prove execution for a fee, call 'prove_fee' instead"
+        );
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Retrieve the global state root.
+        let global_state_root =
+            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+        // Construct the proving tasks.
+        let proving_tasks = self.transition_tasks.values().cloned().collect();
+        // Compute the proof.
+        self.prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, rng)
+    }
+
     /// Returns the global state root and proof for the given assignments.
     fn prove_batch<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
         locator: &str,
         mut proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
         inclusion_assignments: &[InclusionAssignment<N>],
@@ -289,8 +505,15 @@ impl<N: Network> Trace<N> {
             proving_tasks.push((proving_key, batch_inclusions));
         }
 
-        // Compute the proof.
-        let proof = ProvingKey::prove_batch(locator, &proving_tasks, rng)?;
+        // Ensure the proving was not cancelled.
+        self.cancellation.ensure_not_cancelled()?;
+        // Compute the proof, halting between the prover rounds if the proving is cancelled.
+        let proof =
+            ProvingKey::prove_batch_with_terminator(locator, &proving_tasks, self.cancellation.terminator(), rng)
+                .map_err(|error| match self.cancellation.is_cancelled() {
+                    true => ExecutionCancelled.into(),
+                    false => error,
+                })?;
         // Return the global state root and proof.
         Ok((global_state_root, proof))
     }
@@ -303,6 +526,18 @@ impl<N: Network> Trace<N> {
         global_state_root: N::StateRoot,
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
         proof: &Proof<N>,
+    ) -> Result<()> {
+        // Insert the inclusion verifier inputs.
+        Self::add_inclusion_verifier_inputs(&mut verifier_inputs, global_state_root, transitions)?;
+        // Verify the proof.
+        VerifyingKey::verify_batch(locator, verifier_inputs, proof).map_err(|e| anyhow!("Failed to verify proof - {e}"))
+    }
+
+    /// Inserts the batch of inclusion verifier inputs for the given transitions into the verifier inputs.
+    fn add_inclusion_verifier_inputs<'a>(
+        verifier_inputs: &mut Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        global_state_root: N::StateRoot,
+        transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
     ) -> Result<()> {
         // Construct the batch of inclusion verifier inputs.
         let batch_inclusion_inputs = Inclusion::prepare_verifier_inputs(global_state_root, transitions)?;
@@ -317,7 +552,6 @@ impl<N: Network> Trace<N> {
             // Insert the inclusion verifier inputs.
             verifier_inputs.push((VerifyingKey::<N>::new(verifying_key, num_variables), batch_inclusion_inputs));
         }
-        // Verify the proof.
-        VerifyingKey::verify_batch(locator, verifier_inputs, proof).map_err(|e| anyhow!("Failed to verify proof - {e}"))
+        Ok(())
     }
 }
@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_block::{ExecutionWriter, Transition};
+
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// A sink for the transitions of an execution, which receives each transition as soon as it is completed,
+/// in the order of the execution (i.e. each nested call before its caller).
+///
+/// As the transitions of an execution are proven in a single batch, the sink receives the transitions
+/// before the execution is proven; for an `ExecutionWriter`, the proof is computed with
+/// `Trace::prove_execution_for_sink`, after which the writer is finished, or aborted if proving fails.
+/// The trace keeps its own copy of each transition for proving (see `Process::execute_with_sink`).
+pub trait TransitionSink<N: Network>: Send {
+    /// Writes the given transition to the sink.
+    fn write_transition(&mut self, transition: &Transition<N>) -> Result<()>;
+}
+
+impl<N: Network, W: Write + Send> TransitionSink<N> for ExecutionWriter<N, W> {
+    /// Writes the given transition to the execution writer.
+    fn write_transition(&mut self, transition: &Transition<N>) -> Result<()> {
+        Ok(ExecutionWriter::write_transition(self, transition)?)
+    }
+}
+
+/// A transition sink, shared between the caller and the trace.
+#[derive(Clone)]
+pub(super) struct SharedTransitionSink<N: Network>(pub(super) Arc<Mutex<dyn TransitionSink<N>>>);
+
+impl<N: Network> Debug for SharedTransitionSink<N> {
+    /// Prints the transition sink, which is opaque.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "TransitionSink")
+    }
+}
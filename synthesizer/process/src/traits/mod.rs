@@ -50,6 +50,9 @@ pub trait StackEvaluate<N: Network>: Clone {
 pub trait StackExecute<N: Network> {
     /// Executes a program closure on the given inputs.
     ///
+    /// The inputs are the caller's circuit values, which are assigned to the closure's registers
+    /// without allocating new variables, so the call boundary adds no constraints.
+    ///
     /// # Errors
     /// This method will halt if the given inputs are not the same length as the input statements.
     fn execute_closure<A: circuit::Aleo<Network = N>>(
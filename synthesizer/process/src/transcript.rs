@@ -0,0 +1,273 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+/// The transcript of the key synthesis for a deployment.
+///
+/// A deployer publishes the transcript alongside a deployment, so that third parties can re-derive
+/// the proving and verifying keys bit-for-bit, and check them against the deployment certificates.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct KeySynthesisTranscript<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The deployment edition.
+    edition: u16,
+    /// The seed of the RNG used to synthesize the keys.
+    seed: [u8; 32],
+}
+
+impl<N: Network> KeySynthesisTranscript<N> {
+    /// Initializes a new key synthesis transcript.
+    pub const fn new(program_id: ProgramID<N>, edition: u16, seed: [u8; 32]) -> Self {
+        Self { program_id, edition, seed }
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the deployment edition.
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
+    /// Returns the seed of the RNG used to synthesize the keys.
+    pub const fn seed(&self) -> &[u8; 32] {
+        &self.seed
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Deploys the given program, and returns the deployment with the transcript of its key synthesis.
+    #[inline]
+    pub fn deploy_with_transcript<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<(Deployment<N>, KeySynthesisTranscript<N>)> {
+        // Sample the seed of the key synthesis.
+        let seed: [u8; 32] = rng.gen();
+        // Deploy the program, with an RNG derived from the seed.
+        let deployment = Stack::new(self, program)?.deploy::<A, _>(&mut StdRng::from_seed(seed))?;
+        // Construct the transcript.
+        let transcript = KeySynthesisTranscript::new(*program.id(), deployment.edition(), seed);
+        Ok((deployment, transcript))
+    }
+
+    /// Re-derives the proving and verifying keys of the given deployment from the given transcript,
+    /// and ensures the verifying keys and certificates match the deployment bit-for-bit.
+    #[inline]
+    pub fn reproduce_and_verify_keys<A: circuit::Aleo<Network = N>>(
+        &self,
+        deployment: &Deployment<N>,
+        transcript: &KeySynthesisTranscript<N>,
+    ) -> Result<()> {
+        let timer = timer!("Process::reproduce_and_verify_keys");
+
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Ensure the transcript is for the deployment.
+        ensure!(transcript.program_id() == program_id, "The transcript is not for program '{program_id}'");
+        ensure!(
+            transcript.edition() == deployment.edition(),
+            "The transcript edition ({}) does not match the deployment edition ({})",
+            transcript.edition(),
+            deployment.edition()
+        );
+
+        // Re-derive the deployment, with an RNG derived from the seed.
+        let stack = Stack::new(self, deployment.program())?;
+        let reproduced = stack.deploy::<A, _>(&mut StdRng::from_seed(*transcript.seed()))?;
+        lap!(timer, "Reproduce the deployment");
+
+        // Ensure the number of verifying keys matches.
+        ensure!(
+            reproduced.verifying_keys().len() == deployment.verifying_keys().len(),
+            "The number of reproduced verifying keys does not match the deployment of '{program_id}'"
+        );
+        // Ensure the verifying keys and certificates match bit-for-bit.
+        for ((expected_name, (expected_key, expected_certificate)), (name, (key, certificate))) in
+            deployment.verifying_keys().iter().zip_eq(reproduced.verifying_keys())
+        {
+            ensure!(expected_name == name, "Expected function '{expected_name}' in '{program_id}', found '{name}'");
+            ensure!(
+                expected_key.to_bytes_le()? == key.to_bytes_le()?,
+                "The reproduced verifying key for '{program_id}/{name}' does not match the deployment"
+            );
+            ensure!(
+                expected_certificate.to_bytes_le()? == certificate.to_bytes_le()?,
+                "The reproduced certificate for '{program_id}/{name}' does not match the deployment"
+            );
+        }
+        lap!(timer, "Compare the verifying keys and certificates");
+
+        finish!(timer);
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for KeySynthesisTranscript<N> {
+    /// Reads the key synthesis transcript from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid key synthesis transcript version"));
+        }
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the edition.
+        let edition = u16::read_le(&mut reader)?;
+        // Read the seed.
+        let seed = <[u8; 32]>::read_le(&mut reader)?;
+        // Return the key synthesis transcript.
+        Ok(Self::new(program_id, edition, seed))
+    }
+}
+
+impl<N: Network> ToBytes for KeySynthesisTranscript<N> {
+    /// Writes the key synthesis transcript to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the edition.
+        self.edition.write_le(&mut writer)?;
+        // Write the seed.
+        self.seed.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> Serialize for KeySynthesisTranscript<N> {
+    /// Serializes the key synthesis transcript into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut transcript = serializer.serialize_struct("KeySynthesisTranscript", 3)?;
+                transcript.serialize_field("program_id", &self.program_id)?;
+                transcript.serialize_field("edition", &self.edition)?;
+                transcript.serialize_field("seed", &self.seed)?;
+                transcript.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for KeySynthesisTranscript<N> {
+    /// Deserializes the key synthesis transcript from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the key synthesis transcript from a string into a value.
+                let mut transcript = serde_json::Value::deserialize(deserializer)?;
+                // Recover the key synthesis transcript.
+                Ok(Self::new(
+                    // Retrieve the program ID.
+                    DeserializeExt::take_from_value::<D>(&mut transcript, "program_id")?,
+                    // Retrieve the edition.
+                    DeserializeExt::take_from_value::<D>(&mut transcript, "edition")?,
+                    // Retrieve the seed.
+                    DeserializeExt::take_from_value::<D>(&mut transcript, "seed")?,
+                ))
+            }
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "key synthesis transcript")
+            }
+        }
+    }
+}
+
+impl<N: Network> FromStr for KeySynthesisTranscript<N> {
+    type Err = Error;
+
+    /// Initializes the key synthesis transcript from a JSON-string.
+    fn from_str(transcript: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(transcript)?)
+    }
+}
+
+impl<N: Network> Debug for KeySynthesisTranscript<N> {
+    /// Prints the key synthesis transcript as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for KeySynthesisTranscript<N> {
+    /// Displays the key synthesis transcript as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_reproduce_and_verify_keys() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+
+        // Deploy the program, with a transcript.
+        let (deployment, transcript) = process.deploy_with_transcript::<CurrentAleo, _>(&program, rng).unwrap();
+        assert_eq!(transcript.program_id(), program.id());
+
+        // Ensure a third party reproduces the keys.
+        assert!(process.reproduce_and_verify_keys::<CurrentAleo>(&deployment, &transcript).is_ok());
+
+        // Ensure the transcript round-trips through bytes and JSON.
+        assert_eq!(transcript, KeySynthesisTranscript::read_le(&transcript.to_bytes_le().unwrap()[..]).unwrap());
+        assert_eq!(transcript, KeySynthesisTranscript::from_str(&transcript.to_string()).unwrap());
+
+        // Ensure a transcript for a different edition is rejected.
+        let invalid = KeySynthesisTranscript::new(*program.id(), transcript.edition() + 1, *transcript.seed());
+        assert!(process.reproduce_and_verify_keys::<CurrentAleo>(&deployment, &invalid).is_err());
+
+        // Ensure a deployment with a mismatched verifying key is rejected.
+        let other = process.deploy::<CurrentAleo, _>(&Program::credits().unwrap(), rng).unwrap();
+        let (_, (other_key, other_certificate)) = other.verifying_keys()[0].clone();
+        let name = deployment.verifying_keys()[0].0;
+        let tampered = Deployment::new(deployment.edition(), deployment.program().clone(), vec![(
+            name,
+            (other_key, other_certificate),
+        )])
+        .unwrap();
+        assert!(process.reproduce_and_verify_keys::<CurrentAleo>(&tampered, &transcript).is_err());
+    }
+}
@@ -0,0 +1,336 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Process<N> {
+    /// Returns the current edition of the given program.
+    /// Programs that were never upgraded are at the network edition (`N::EDITION`).
+    #[inline]
+    pub fn program_edition(&self, program_id: &ProgramID<N>) -> Result<u16> {
+        // Ensure the program exists.
+        ensure!(self.contains_program(program_id), "Program '{program_id}' does not exist");
+        // Return the edition.
+        Ok(self.editions.get(program_id).copied().unwrap_or(N::EDITION))
+    }
+
+    /// Upgrades the given program to the given edition.
+    ///
+    /// The stack of the current edition is retained, so that historical executions can be verified
+    /// (see `Process::at_edition`), while new authorizations are routed to the new edition.
+    /// The new edition must satisfy the upgrade rules (see `Process::check_program_upgrade`).
+    ///
+    /// The stacks of the programs that import the upgraded program, directly or transitively, are rebuilt
+    /// to call the new edition, so that their new executions verify against the current stacks.
+    #[inline]
+    pub fn upgrade_program(&mut self, program: &Program<N>, edition: u16) -> Result<()> {
+        let timer = timer!("Process::upgrade_program");
+
+        // Retrieve the program ID.
+        let program_id = *program.id();
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != ProgramID::from_str("credits.aleo")?, "Cannot upgrade the program '{program_id}'");
+        // Retrieve the current edition.
+        let current_edition = self.program_edition(&program_id)?;
+        // Ensure the new edition succeeds the current edition.
+        ensure!(
+            edition > current_edition,
+            "Cannot upgrade '{program_id}' to edition {edition}, as it is already at edition {current_edition}"
+        );
+        // Retrieve the stack of the current edition.
+        let current_stack = self.get_stack(program_id)?.clone();
+        // Ensure the upgrade satisfies the upgrade rules.
+        Self::check_program_upgrade(current_stack.program(), program)?;
+        lap!(timer, "Check the upgrade rules");
+
+        // Compute the stack of the new edition, against a view of the process without the current edition.
+        let mut view = self.clone();
+        view.stacks.shift_remove(&program_id);
+        let stack = Stack::new(&view, program)?;
+        lap!(timer, "Compute the stack");
+
+        // Apply the upgrade to a copy of the process, so that the process is unchanged if the upgrade fails.
+        let mut process = self.clone();
+        // Retain the stack of the current edition.
+        process.historical_stacks.insert((program_id, current_edition), current_stack);
+        // Replace the stack with the new edition.
+        process.add_stack(stack);
+        process.editions.insert(program_id, edition);
+        // Rebuild the importers, so that they call the new edition.
+        process.rebuild_importers(&program_id)?;
+        lap!(timer, "Rebuild the importers");
+        *self = process;
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Returns the stack of the given program at the given edition.
+    #[inline]
+    pub fn get_stack_at_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<&Arc<Stack<N>>> {
+        // If the edition is the current edition, return the current stack.
+        if self.program_edition(program_id)? == edition {
+            return self.get_stack(*program_id);
+        }
+        // Otherwise, return the historical stack.
+        self.historical_stacks
+            .get(&(*program_id, edition))
+            .ok_or_else(|| anyhow!("Program '{program_id}' does not have an edition {edition}"))
+    }
+
+    /// Returns a view of the process, where the given program is at the given edition.
+    ///
+    /// This allows historical executions to be verified, such as with `view.verify_execution(..)`.
+    /// The importers of the program are rebuilt in the view, to call the given edition.
+    /// The view shares its circuit keys with the process, but not its memoized queries.
+    #[inline]
+    pub fn at_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Self> {
+        // Retrieve the stack of the given edition.
+        let stack = self.get_stack_at_edition(program_id, edition)?.clone();
+        // Construct the view.
+        let mut view = self.clone();
+        view.query_cache = Default::default();
+        view.insert_stack(stack);
+        view.editions.insert(*program_id, edition);
+        // Rebuild the importers, so that they call the given edition.
+        view.rebuild_importers(program_id)?;
+        Ok(view)
+    }
+
+    /// Rebuilds the stacks of the programs that import the given program, directly or transitively,
+    /// so that they call its current stack.
+    ///
+    /// The circuit keys of the importers are retained, as the upgrade rules keep the signatures
+    /// of the functions they call unchanged.
+    fn rebuild_importers(&mut self, program_id: &ProgramID<N>) -> Result<()> {
+        // Track the programs whose stacks have changed.
+        let mut changed = IndexSet::from([*program_id]);
+        // Rebuild the stacks in the order they were added, so that each import is rebuilt before its importers.
+        for stack in self.stacks.values().cloned().collect::<Vec<_>>() {
+            // Skip the programs that do not import a changed program.
+            if !stack.program().imports().keys().any(|import| changed.contains(import)) {
+                continue;
+            }
+            // Compute the stack against the changed imports.
+            let rebuilt = Stack::new(self, stack.program())?;
+            // Retain the circuit keys.
+            for function_name in stack.program().functions().keys() {
+                if stack.contains_proving_key(function_name) {
+                    rebuilt.insert_proving_key(function_name, stack.get_proving_key(function_name)?)?;
+                }
+                if stack.contains_verifying_key(function_name) {
+                    rebuilt.insert_verifying_key(function_name, stack.get_verifying_key(function_name)?)?;
+                }
+            }
+            changed.insert(*stack.program_id());
+            self.add_stack(rebuilt);
+        }
+        Ok(())
+    }
+
+    /// Ensures the given program may replace the given current program, as a new edition.
+    ///
+    /// The rules for an upgrade are:
+    ///   1. The mappings, structs, and records of the current program must be unchanged.
+    ///   2. The functions of the current program must exist, with unchanged input and output types,
+    ///      and unchanged finalize input types. Their instructions and finalize logic may change.
+    ///   3. New mappings, structs, records, closures, functions, and imports may be added.
+    ///   4. Closures may change, or be removed.
+    #[inline]
+    pub fn check_program_upgrade(current: &Program<N>, program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = current.id();
+        // Ensure the program IDs match.
+        ensure!(program.id() == program_id, "Cannot upgrade '{program_id}' with the program '{}'", program.id());
+
+        // Ensure the mappings are unchanged.
+        for (name, mapping) in current.mappings() {
+            ensure!(
+                program.mappings().get(name) == Some(mapping),
+                "The upgrade of '{program_id}' cannot remove or change the mapping '{name}'"
+            );
+        }
+        // Ensure the structs are unchanged.
+        for (name, struct_) in current.structs() {
+            ensure!(
+                program.structs().get(name) == Some(struct_),
+                "The upgrade of '{program_id}' cannot remove or change the struct '{name}'"
+            );
+        }
+        // Ensure the records are unchanged.
+        for (name, record) in current.records() {
+            ensure!(
+                program.records().get(name) == Some(record),
+                "The upgrade of '{program_id}' cannot remove or change the record '{name}'"
+            );
+        }
+        // Ensure the function signatures are unchanged.
+        for (name, function) in current.functions() {
+            let Some(candidate) = program.functions().get(name) else {
+                bail!("The upgrade of '{program_id}' cannot remove the function '{name}'")
+            };
+            ensure!(
+                candidate.input_types() == function.input_types(),
+                "The upgrade of '{program_id}' cannot change the input types of '{name}'"
+            );
+            ensure!(
+                candidate.output_types() == function.output_types(),
+                "The upgrade of '{program_id}' cannot change the output types of '{name}'"
+            );
+            ensure!(
+                candidate.finalize_logic().map(|finalize| finalize.input_types())
+                    == function.finalize_logic().map(|finalize| finalize.input_types()),
+                "The upgrade of '{program_id}' cannot change the finalize input types of '{name}'"
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::PrivateKey;
+    use ledger_query::Query;
+    use ledger_store::{helpers::memory::BlockMemory, BlockStore};
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns a program with a `compute` function, whose body is given.
+    fn sample_program(body: &str) -> Program<CurrentNetwork> {
+        Program::from_str(&format!(
+            r"
+program upgrade.aleo;
+
+mapping counts:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u64.public;
+{body}"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_upgrade_program() {
+        // Initialize the editions of the program.
+        let edition_0 = sample_program("    add r0 1u64 into r1;\n    output r1 as u64.public;");
+        let edition_1 = sample_program(
+            "    add r0 2u64 into r1;\n    output r1 as u64.public;\n\nfunction extra:\n    input r0 as u8.public;\n    output r0 as u8.public;",
+        );
+        let program_id = *edition_0.id();
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&edition_0).unwrap();
+        assert_eq!(process.program_edition(&program_id).unwrap(), CurrentNetwork::EDITION);
+
+        // Ensure an upgrade that changes a function signature is rejected.
+        let invalid = sample_program("    cast r0 into r1 as u128;\n    output r1 as u128.public;");
+        assert!(process.upgrade_program(&invalid, 1).is_err());
+        // Ensure an upgrade that does not increase the edition is rejected.
+        assert!(process.upgrade_program(&edition_1, CurrentNetwork::EDITION).is_err());
+
+        // Upgrade the program.
+        process.upgrade_program(&edition_1, 1).unwrap();
+        assert_eq!(process.program_edition(&program_id).unwrap(), 1);
+        assert_eq!(process.get_program(program_id).unwrap(), &edition_1);
+        // Ensure new queries are routed to the new edition.
+        let outputs = process.query_function::<circuit::AleoV0>(program_id, function_name, ["1u64"].into_iter());
+        assert_eq!(outputs.unwrap(), vec![Value::from_str("3u64").unwrap()]);
+
+        // Ensure the previous edition is retained.
+        let view = process.at_edition(&program_id, CurrentNetwork::EDITION).unwrap();
+        assert_eq!(view.get_program(program_id).unwrap(), &edition_0);
+        let outputs = view.query_function::<circuit::AleoV0>(program_id, function_name, ["1u64"].into_iter());
+        assert_eq!(outputs.unwrap(), vec![Value::from_str("2u64").unwrap()]);
+        assert!(process.at_edition(&program_id, 2).is_err());
+
+        // Ensure removing the program removes its editions.
+        process.remove_program(&program_id).unwrap();
+        assert!(process.get_stack_at_edition(&program_id, CurrentNetwork::EDITION).is_err());
+        assert!(process.historical_stacks.is_empty());
+    }
+
+    #[test]
+    fn test_upgrade_program_with_importer() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the editions of the program.
+        let edition_0 = sample_program("    add r0 1u64 into r1;\n    output r1 as u64.public;");
+        let edition_1 = sample_program("    add r0 2u64 into r1;\n    output r1 as u64.public;");
+        let program_id = *edition_0.id();
+        // Initialize a program that imports the program.
+        let importer = Program::<CurrentNetwork>::from_str(
+            r"
+import upgrade.aleo;
+
+program importer.aleo;
+
+function compute:
+    input r0 as u64.public;
+    call upgrade.aleo/compute r0 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&edition_0).unwrap();
+        process.add_program(&importer).unwrap();
+
+        // Initialize a new caller account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+        // Returns the output and execution of the importer, for the given process.
+        let mut execute = |process: &Process<CurrentNetwork>| {
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&private_key, importer.id(), "compute", ["1u64"].into_iter(), rng)
+                .unwrap();
+            let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            trace.prepare(Query::from(block_store.clone())).unwrap();
+            let execution = trace.prove_execution::<CurrentAleo, _>("importer.aleo/compute", rng).unwrap();
+            (response.outputs().to_vec(), execution)
+        };
+
+        // Execute the importer, before the upgrade.
+        let (outputs, historical) = execute(&process);
+        assert_eq!(outputs, vec![Value::from_str("2u64").unwrap()]);
+        process.verify_execution(&historical).unwrap();
+
+        // Upgrade the program.
+        process.upgrade_program(&edition_1, 1).unwrap();
+        // Ensure the content hashes are of the current stacks.
+        let content_hash = process.get_stack(program_id).unwrap().content_hash();
+        assert_eq!(process.get_stack_by_hash(&content_hash).unwrap().program(), &edition_1);
+        assert_eq!(process.content_hashes.len(), process.stacks.len());
+
+        // Ensure the importer calls the new edition, and its execution verifies.
+        let (outputs, execution) = execute(&process);
+        assert_eq!(outputs, vec![Value::from_str("3u64").unwrap()]);
+        process.verify_execution(&execution).unwrap();
+
+        // Ensure the historical execution verifies against the previous edition.
+        let view = process.at_edition(&program_id, CurrentNetwork::EDITION).unwrap();
+        view.verify_execution(&historical).unwrap();
+        let content_hash = view.get_stack(program_id).unwrap().content_hash();
+        assert_eq!(view.get_stack_by_hash(&content_hash).unwrap().program(), &edition_0);
+    }
+}
@@ -45,9 +45,55 @@ impl<N: Network> Process<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::MainnetV0;
+    use synthesizer_snark::SynthesizerBinding;
 
+    type CurrentNetwork = MainnetV0;
     type CurrentAleo = circuit::network::AleoV0;
 
+    #[test]
+    fn test_verify_deployment_reports_synthesizer_on_invalid_certificate() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;
+
+function double:
+    input r0 as u32.private;
+    mul r0 2u32 into r1;
+    output r1 as u32.public;",
+        )
+        .unwrap();
+
+        // Create a deployment for the program.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_ok());
+
+        // Swap the certificates of the functions.
+        let (first_name, (first_key, first_certificate)) = deployment.verifying_keys()[0].clone();
+        let (second_name, (second_key, second_certificate)) = deployment.verifying_keys()[1].clone();
+        let deployment = Deployment::new(deployment.edition(), program, vec![
+            (first_name, (first_key, second_certificate)),
+            (second_name, (second_key, first_certificate)),
+        ])
+        .unwrap();
+
+        // Ensure the error reports the synthesizer that the certificate was verified with.
+        let error = process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap_err();
+        let expected = SynthesizerBinding::current::<CurrentNetwork>().to_string();
+        assert!(error.to_string().contains(&expected), "{error}");
+    }
+
     /// Use `cargo test profiler --features timer` to run this test.
     #[ignore]
     #[test]
@@ -14,6 +14,11 @@
 
 use super::*;
 
+use rand::rngs::OsRng;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 impl<N: Network> Process<N> {
     /// Verifies the given execution is valid.
     /// Note: This does *not* check that the global state root exists in the ledger.
@@ -21,29 +26,100 @@ impl<N: Network> Process<N> {
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
+        // Verify the execution, and construct the verifier inputs for its proof.
+        let (locator, verifier_inputs) = self.to_execution_verifier_inputs(execution)?;
+        lap!(timer, "Verify the transitions");
+
+        // Verify the execution proof.
+        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
+        lap!(timer, "Verify the proof");
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Verifies the given executions are valid.
+    /// The proofs of the executions are verified together, with a single product of pairings.
+    /// If the executions are invalid, the error identifies the first invalid execution.
+    /// Note: This does *not* check that the global state roots exist in the ledger.
+    #[inline]
+    pub fn verify_executions(&self, executions: &[Execution<N>]) -> Result<()> {
+        let timer = timer!("Process::verify_executions");
+
+        // Verify each execution, and construct the verifier inputs for its proof.
+        let verifier_inputs = cfg_iter!(executions)
+            .enumerate()
+            .map(|(index, execution)| {
+                self.to_execution_verifier_inputs(execution)
+                    .map_err(|error| anyhow!("Execution {index} is invalid - {error}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Verify the transitions");
+
+        // If there are no executions, there are no proofs to verify.
+        if executions.is_empty() {
+            finish!(timer);
+            return Ok(());
+        }
+
+        // Verify the execution proofs together.
+        let batches = verifier_inputs.iter().map(|(_, inputs)| inputs.clone()).zip_eq(executions).collect();
+        if Trace::verify_execution_proofs(batches, &mut OsRng).is_err() {
+            // Verify the execution proofs individually, to identify the first invalid execution.
+            for (index, ((locator, verifier_inputs), execution)) in
+                verifier_inputs.into_iter().zip_eq(executions).enumerate()
+            {
+                if let Err(error) = Trace::verify_execution_proof(&locator, verifier_inputs, execution) {
+                    bail!("Execution {index} is invalid - {error}")
+                }
+            }
+            bail!("The executions are invalid")
+        }
+        lap!(timer, "Verify the proofs");
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Verifies the given execution, except for its proof, and returns the locator of the main function
+    /// and the verifier inputs for the proof.
+    fn to_execution_verifier_inputs(
+        &self,
+        execution: &Execution<N>,
+    ) -> Result<(String, Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>)> {
+        let timer = timer!("Process::to_execution_verifier_inputs");
+
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
+        // Ensure the execution proof is within the verifier limits, before performing any cryptography.
+        Self::ensure_proof_is_within_limits(execution)?;
 
-        // Ensure the number of transitions matches the program function.
-        let locator = {
-            // Retrieve the transition (without popping it).
-            let transition = execution.peek()?;
+        // Construct the call graph of the execution.
+        let transitions = execution.transitions().collect::<Vec<_>>();
+        let call_graph = self.construct_call_graph_from(&transitions)?;
+        // Construct the call trees of the execution, one for each top-level call.
+        // Note: This ensures the transitions are in canonical (call-tree post-order) order.
+        let call_trees = Self::call_trees_from_graph(&transitions, &call_graph)?;
+
+        // Ensure the number of transitions matches the program function, for each top-level call.
+        for call_tree in &call_trees {
             // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
+            let stack = self.get_stack(call_tree.program_id())?;
             // Ensure the number of calls matches the number of transitions.
-            let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
+            let number_of_calls = stack.get_number_of_calls(call_tree.function_name())?;
             ensure!(
-                number_of_calls == execution.len(),
+                number_of_calls == call_tree.len(),
                 "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
-                execution.len()
+                call_tree.len()
             );
-            // Output the locator of the main function.
-            Locator::new(*transition.program_id(), *transition.function_name()).to_string()
+        }
+        // Output the locator of the main function, which is the last top-level call.
+        let locator = match call_trees.last() {
+            Some(call_tree) => Locator::new(*call_tree.program_id(), *call_tree.function_name()).to_string(),
+            None => bail!("There are no top-level calls in the execution"),
         };
         lap!(timer, "Verify the number of transitions");
 
-        // Construct the call graph of the execution.
-        let call_graph = self.construct_call_graph(execution)?;
         // Construct the reverse call graph of the execution.
         // Note: This is a mapping of the child transition ID to the parent transition ID.
         let reverse_call_graph = Self::reverse_call_graph(&call_graph);
@@ -131,30 +207,78 @@ impl<N: Network> Process<N> {
         let num_instances = verifier_inputs.values().map(|(_, inputs)| inputs.len()).sum::<usize>();
         // Ensure the number of instances matches the number of transitions.
         ensure!(num_instances == execution.transitions().len(), "The number of verifier instances is incorrect");
-        // Ensure the same signer is used for all transitions.
-        execution.transitions().try_fold(None, |signer, transition| {
-            Ok(match signer {
-                None => Some(transition.scm()),
-                Some(signer) => {
-                    ensure!(signer == transition.scm(), "The transitions did not use the same signer");
-                    Some(signer)
-                }
-            })
-        })?;
+        // Ensure the same signer is used for all transitions of each top-level call.
+        // Note: The signer commitment is bound to the top-level call, so it differs across top-level calls,
+        // and a single signer across the bundle cannot be enforced here. Mixed signers are therefore allowed:
+        // each top-level call is signed by its own signer, so it only runs calls that their signers authorized,
+        // as they would in separate executions.
+        for call_tree in &call_trees {
+            call_tree.to_post_order().iter().try_fold(None, |signer, transition_id| {
+                // Retrieve the transition.
+                let Some(transition) = execution.get_transition(transition_id) else {
+                    bail!("Transition '{transition_id}' is missing from the execution")
+                };
+                Ok(match signer {
+                    None => Some(transition.scm()),
+                    Some(signer) => {
+                        ensure!(signer == transition.scm(), "The transitions did not use the same signer");
+                        Some(signer)
+                    }
+                })
+            })?;
+        }
 
         // Construct the list of verifier inputs.
-        let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
-        // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
-
-        lap!(timer, "Verify the proof");
+        let verifier_inputs: Vec<_> = verifier_inputs.into_values().collect();
 
         finish!(timer);
-        Ok(())
+        Ok((locator, verifier_inputs))
     }
 }
 
 impl<N: Network> Process<N> {
+    /// Ensures the proof of the given execution is within the verifier limits.
+    /// These checks are inexpensive, and bound the work spent on maliciously inflated proofs.
+    fn ensure_proof_is_within_limits(execution: &Execution<N>) -> Result<()> {
+        // Retrieve the proof.
+        let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
+
+        // Compute the expected number of instances, which is one per transition, and one per input record.
+        let num_record_inputs = execution
+            .transitions()
+            .map(|transition| transition.inputs().iter().filter(|input| matches!(input, Input::Record(..))).count())
+            .sum::<usize>();
+        let expected_instances = execution.len().saturating_add(num_record_inputs);
+
+        // Ensure the number of circuits is within range, which bounds the number of circuit commitments and evaluations.
+        // Note: Each transition contributes at most one circuit, and the inclusion circuit may contribute one more.
+        let num_circuits = proof.batch_sizes().len();
+        ensure!(
+            num_circuits > 0 && num_circuits <= execution.len().saturating_add(1),
+            "The execution proof contains {num_circuits} circuits, which exceeds the limit for {} transitions",
+            execution.len()
+        );
+        // Ensure the number of instances matches, which bounds the number of instance commitments and evaluations.
+        let num_instances = proof.batch_sizes().iter().try_fold(0usize, |acc, size| acc.checked_add(*size));
+        ensure!(
+            num_instances == Some(expected_instances),
+            "The execution proof contains an incorrect number of instances. Expected {expected_instances}"
+        );
+        // Ensure the proof messages are consistent with the batch sizes.
+        if proof.check_batch_sizes().is_err() {
+            bail!("The execution proof messages are inconsistent with its batch sizes")
+        }
+
+        // Ensure the proof size is within range.
+        let proof_size = proof.to_bytes_le()?.len();
+        let max_proof_size = execution.len().saturating_mul(N::MAX_PROOF_BYTES_PER_TRANSITION);
+        ensure!(
+            proof_size <= max_proof_size,
+            "The execution proof is {proof_size} bytes, which exceeds the limit of {max_proof_size} bytes"
+        );
+        Ok(())
+    }
+
     /// Returns the public inputs to verify the proof for the given transition.
     fn to_transition_verifier_inputs(
         &self,
@@ -231,6 +355,14 @@ impl<N: Network> Process<N> {
     pub fn construct_call_graph(
         &self,
         execution: &Execution<N>,
+    ) -> Result<HashMap<N::TransitionID, Vec<N::TransitionID>>> {
+        self.construct_call_graph_from(&execution.transitions().collect::<Vec<_>>())
+    }
+
+    /// Constructs the call graph of the given transitions, which are in the order of an `Execution`.
+    pub(crate) fn construct_call_graph_from(
+        &self,
+        transitions: &[&Transition<N>],
     ) -> Result<HashMap<N::TransitionID, Vec<N::TransitionID>>> {
         // Metadata for each transition the execution.
         struct TransitionMetadata<N: Network> {
@@ -290,7 +422,7 @@ impl<N: Network> Process<N> {
         let mut counter = 0;
 
         // Iterate over each transition in reverse post-order, and populate the call graph.
-        for transition in execution.transitions().rev() {
+        for transition in transitions.iter().rev() {
             // Now process the current `transition`.
             // At this point, the algorithm must maintain the following invariant:
             // - The stack is either empty, or the top entry is incomplete.
@@ -363,7 +495,7 @@ impl<N: Network> Process<N> {
         // Check that the the traversal completed correctly.
         ensure!(traversal_stack.is_empty(), "Invalid traversal - traversal stack is not empty");
         ensure!(
-            counter == execution.len(),
+            counter == transitions.len(),
             "Invalid traversal - counter does not match the number of transitions in the execution"
         );
 
@@ -392,3 +524,73 @@ impl<N: Network> Process<N> {
         reverse_call_graph
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::MainnetV0};
+    use ledger_query::Query;
+    use ledger_store::{helpers::memory::BlockMemory, BlockStore};
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_verify_executions() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Construct the process.
+        let process = crate::test_helpers::sample_process(&program);
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+        // Returns an execution of the function, for the given inputs.
+        let mut execute = |inputs: [&str; 2]| {
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.into_iter(), rng)
+                .unwrap();
+            let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            trace.prepare(Query::from(block_store.clone())).unwrap();
+            trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap()
+        };
+        let executions = vec![execute(["5u32", "10u32"]), execute(["7u32", "11u32"]), execute(["1u32", "2u32"])];
+
+        // Ensure the executions verify together, and individually.
+        assert!(process.verify_executions(&executions).is_ok());
+        for execution in &executions {
+            assert!(process.verify_execution(execution).is_ok());
+        }
+        // Ensure an empty list of executions verifies.
+        assert!(process.verify_executions(&[]).is_ok());
+
+        // Construct an execution with the proof of another execution.
+        let invalid_execution = Execution::from(
+            executions[1].transitions().cloned(),
+            executions[1].global_state_root(),
+            executions[0].proof().cloned(),
+        )
+        .unwrap();
+        assert!(process.verify_execution(&invalid_execution).is_err());
+
+        // Ensure the executions do not verify together, and the error identifies the invalid execution.
+        let invalid_executions = vec![executions[0].clone(), invalid_execution, executions[2].clone()];
+        let error = process.verify_executions(&invalid_executions).unwrap_err();
+        assert!(error.to_string().starts_with("Execution 1 is invalid"), "{error}");
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use rand::{rngs::StdRng, SeedableRng};
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread::JoinHandle,
+};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A program or function whose circuit keys are synthesized during a warm-up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WarmUpTarget<N: Network> {
+    /// Every function in the program.
+    Program(ProgramID<N>),
+    /// A single function, given as `program_id/function_name`.
+    Function(Locator<N>),
+}
+
+/// The progress of a warm-up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WarmUpProgress<N: Network> {
+    /// The functions to warm up, in order of priority.
+    functions: Vec<Locator<N>>,
+    /// The number of functions whose circuit keys are synthesized.
+    num_completed: usize,
+    /// The functions whose circuit keys failed to synthesize, with the error.
+    failures: Vec<(Locator<N>, String)>,
+}
+
+impl<N: Network> WarmUpProgress<N> {
+    /// Returns the functions to warm up, in order of priority.
+    pub fn functions(&self) -> &[Locator<N>] {
+        &self.functions
+    }
+
+    /// Returns the number of functions to warm up.
+    pub fn num_total(&self) -> usize {
+        self.functions.len()
+    }
+
+    /// Returns the number of functions whose circuit keys are synthesized.
+    pub const fn num_completed(&self) -> usize {
+        self.num_completed
+    }
+
+    /// Returns the functions whose circuit keys failed to synthesize, with the error.
+    pub fn failures(&self) -> &[(Locator<N>, String)] {
+        &self.failures
+    }
+
+    /// Returns `true` if every function has either completed or failed.
+    pub fn is_finished(&self) -> bool {
+        self.num_completed + self.failures.len() == self.functions.len()
+    }
+}
+
+/// A handle to a warm-up that is running in the background.
+pub struct WarmUp<N: Network> {
+    /// The progress of the warm-up.
+    progress: Arc<RwLock<WarmUpProgress<N>>>,
+    /// The worker threads.
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl<N: Network> WarmUp<N> {
+    /// Returns a snapshot of the progress of the warm-up.
+    pub fn progress(&self) -> WarmUpProgress<N> {
+        self.progress.read().clone()
+    }
+
+    /// Returns `true` if the warm-up is finished.
+    pub fn is_finished(&self) -> bool {
+        self.progress.read().is_finished()
+    }
+
+    /// Blocks until the warm-up is finished, and returns its final progress.
+    pub fn wait(self) -> Result<WarmUpProgress<N>> {
+        for worker in self.workers {
+            if worker.join().is_err() {
+                bail!("A warm-up worker panicked")
+            }
+        }
+        Ok(self.progress.read().clone())
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Synthesizes the circuit keys for every function in the program in parallel, blocking until all
+    /// of them are synthesized, and returns the final progress.
+    ///
+    /// The given callback is invoked with the progress after each function completes or fails,
+    /// so that an operator may warm the keys once at startup, instead of on the first call to each function.
+    /// Functions whose circuit keys already exist are skipped, and a failure does not stop the other functions.
+    /// Note: The callback is invoked under a lock, and should not block.
+    pub fn synthesize_all_keys<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        callback: impl Fn(&WarmUpProgress<N>) + Send + Sync,
+        rng: &mut R,
+    ) -> WarmUpProgress<N> {
+        // Retrieve the functions in the program.
+        let functions =
+            self.program().functions().keys().map(|name| Locator::new(*self.program_id(), *name)).collect::<Vec<_>>();
+        // Initialize the progress.
+        let progress =
+            RwLock::new(WarmUpProgress { functions: functions.clone(), num_completed: 0, failures: Vec::new() });
+
+        // Synthesize the circuit keys for each function.
+        let rngs = (0..functions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        cfg_into_iter!(functions).zip_eq(rngs).for_each(|(locator, mut rng)| {
+            let result = self.synthesize_key::<A, _>(locator.resource(), &mut rng);
+            // Update the progress, and report it.
+            let mut progress = progress.write();
+            match result {
+                Ok(()) => progress.num_completed += 1,
+                Err(error) => progress.failures.push((locator, error.to_string())),
+            }
+            callback(&progress);
+        });
+
+        progress.into_inner()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Synthesizes the circuit keys for the given programs and functions in the background,
+    /// in the given order of priority, using at most `parallelism` threads.
+    ///
+    /// Functions whose circuit keys already exist are skipped, and keys synthesized by the
+    /// warm-up are available to the process (and its clones) as soon as each function completes.
+    pub fn warm_up<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        targets: &[WarmUpTarget<N>],
+        parallelism: usize,
+        rng: &mut R,
+    ) -> Result<WarmUp<N>> {
+        // Ensure the parallelism is nonzero.
+        ensure!(parallelism > 0, "The warm-up parallelism must be nonzero");
+
+        // Resolve the targets into functions, in order of priority.
+        let mut functions = IndexSet::new();
+        for target in targets {
+            match target {
+                WarmUpTarget::Program(program_id) => {
+                    // Retrieve the program.
+                    let program = self.get_program(program_id)?;
+                    // Add every function in the program.
+                    functions.extend(program.functions().keys().map(|name| Locator::new(*program_id, *name)));
+                }
+                WarmUpTarget::Function(locator) => {
+                    // Ensure the function exists.
+                    ensure!(
+                        self.get_program(locator.program_id())?.contains_function(locator.resource()),
+                        "Function '{locator}' does not exist"
+                    );
+                    // Add the function.
+                    functions.insert(*locator);
+                }
+            }
+        }
+        let functions = Arc::new(functions.into_iter().collect::<Vec<_>>());
+
+        // Initialize the progress.
+        let progress = Arc::new(RwLock::new(WarmUpProgress {
+            functions: functions.to_vec(),
+            num_completed: 0,
+            failures: Vec::new(),
+        }));
+        // Initialize the index of the next function to warm up.
+        let next = Arc::new(AtomicUsize::new(0));
+
+        // Spawn the workers.
+        let workers = (0..parallelism.min(functions.len()))
+            .map(|_| {
+                let process = self.clone();
+                let functions = functions.clone();
+                let progress = progress.clone();
+                let next = next.clone();
+                let mut rng = StdRng::from_seed(rng.gen());
+                std::thread::spawn(move || {
+                    // Synthesize the circuit keys for the next function, until none remain.
+                    while let Some(locator) = functions.get(next.fetch_add(1, Ordering::SeqCst)) {
+                        match process.synthesize_key::<A, _>(locator.program_id(), locator.resource(), &mut rng) {
+                            Ok(()) => progress.write().num_completed += 1,
+                            Err(error) => progress.write().failures.push((*locator, error.to_string())),
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        Ok(WarmUp { progress, workers })
+    }
+}
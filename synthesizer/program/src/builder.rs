@@ -0,0 +1,385 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    closure::{input::Input as ClosureInput, output::Output as ClosureOutput},
+    finalize::input::Input as FinalizeInput,
+    function::{input::Input as FunctionInput, output::Output as FunctionOutput},
+    mapping::{key::MapKey, value::MapValue},
+    Closure,
+    Command,
+    Finalize,
+    Function,
+    Import,
+    Instruction,
+    Mapping,
+    Operand,
+    Program,
+};
+use console::{
+    network::prelude::*,
+    program::{
+        EntryType,
+        FinalizeType,
+        Identifier,
+        Literal,
+        Locator,
+        PlaintextType,
+        ProgramID,
+        PublicOrPrivate,
+        RecordType,
+        Register,
+        RegisterType,
+        StructType,
+        ValueType,
+    },
+};
+
+/// A builder for constructing a program from Rust, component by component.
+/// Each component is validated as it is added, with the same checks that are applied when parsing a program.
+#[derive(Clone)]
+pub struct ProgramBuilder<N: Network> {
+    /// The program under construction.
+    program: Program<N>,
+    /// The declared imports, which are added to the program last (as in parsing).
+    imports: Vec<Import<N>>,
+}
+
+impl<N: Network> ProgramBuilder<N> {
+    /// Initializes a new builder for a program with the given program ID.
+    pub fn new(program_id: ProgramID<N>) -> Result<Self> {
+        Ok(Self { program: Program::new(program_id)?, imports: Vec::new() })
+    }
+
+    /// Adds an import of the given program ID.
+    /// Note: Imports are validated in `build`, after all of the components have been added.
+    pub fn add_import(mut self, program_id: ProgramID<N>) -> Self {
+        self.imports.push(Import::new(program_id));
+        self
+    }
+
+    /// Adds a mapping with the given name, key type, and value type.
+    pub fn add_mapping(
+        mut self,
+        name: Identifier<N>,
+        key_type: PlaintextType<N>,
+        value_type: PlaintextType<N>,
+    ) -> Result<Self> {
+        self.program.add_mapping(Mapping::new(name, MapKey::new(key_type), MapValue::new(value_type)))?;
+        Ok(self)
+    }
+
+    /// Adds a struct type with the given name and members.
+    pub fn add_struct_type(
+        mut self,
+        name: Identifier<N>,
+        members: Vec<(Identifier<N>, PlaintextType<N>)>,
+    ) -> Result<Self> {
+        self.program.add_struct(StructType::new(name, members)?)?;
+        Ok(self)
+    }
+
+    /// Adds a struct type that is imported from the program in the given locator.
+    pub fn add_struct_import(mut self, locator: Locator<N>) -> Result<Self> {
+        self.program.add_struct_import(locator)?;
+        Ok(self)
+    }
+
+    /// Adds a record type with the given name, owner visibility, and entries.
+    /// Each entry is given as `(name, entry type, optional default value)`.
+    pub fn add_record_type(
+        mut self,
+        name: Identifier<N>,
+        owner: PublicOrPrivate,
+        entries: Vec<(Identifier<N>, EntryType<N>, Option<Literal<N>>)>,
+    ) -> Result<Self> {
+        self.program.add_record(RecordType::new(name, owner, entries)?)?;
+        Ok(self)
+    }
+
+    /// Adds the given closure, which may be constructed with a `ClosureBuilder`.
+    pub fn add_closure(mut self, closure: Closure<N>) -> Result<Self> {
+        self.program.add_closure(closure)?;
+        Ok(self)
+    }
+
+    /// Adds the given function, which may be constructed with a `FunctionBuilder`.
+    pub fn add_function(mut self, function: Function<N>) -> Result<Self> {
+        self.program.add_function(function)?;
+        Ok(self)
+    }
+
+    /// Returns the program, after adding the imports and checking the program-level constraints.
+    pub fn build(self) -> Result<Program<N>> {
+        let Self { mut program, imports } = self;
+
+        // Ensure the program contains at least one component.
+        ensure!(!program.identifiers.is_empty(), "Program '{}' must contain at least one component", program.id());
+        // Lastly, add the imports (if any) to the program.
+        for import in imports {
+            program.add_import(import)?;
+        }
+        // Ensure the program string is less than MAX_PROGRAM_SIZE.
+        ensure!(program.to_string().len() <= N::MAX_PROGRAM_SIZE, "Program length exceeds N::MAX_PROGRAM_SIZE.");
+
+        Ok(program)
+    }
+}
+
+/// A builder for constructing a closure from Rust, statement by statement.
+#[derive(Clone)]
+pub struct ClosureBuilder<N: Network> {
+    /// The closure under construction.
+    closure: Closure<N>,
+}
+
+impl<N: Network> ClosureBuilder<N> {
+    /// Initializes a new builder for a closure with the given name.
+    pub fn new(name: Identifier<N>) -> Self {
+        Self { closure: Closure::new(name) }
+    }
+
+    /// Adds an input statement of the form `input {register} as {register_type};`.
+    pub fn add_input(mut self, register: Register<N>, register_type: RegisterType<N>) -> Result<Self> {
+        self.closure.add_input(ClosureInput::new(register, register_type))?;
+        Ok(self)
+    }
+
+    /// Adds the given instruction.
+    pub fn add_instruction(mut self, instruction: Instruction<N>) -> Result<Self> {
+        self.closure.add_instruction(instruction)?;
+        Ok(self)
+    }
+
+    /// Adds an output statement of the form `output {operand} as {register_type};`.
+    pub fn add_output(mut self, operand: Operand<N>, register_type: RegisterType<N>) -> Result<Self> {
+        self.closure.add_output(ClosureOutput::new(operand, register_type))?;
+        Ok(self)
+    }
+
+    /// Returns the closure.
+    pub fn build(self) -> Closure<N> {
+        self.closure
+    }
+}
+
+/// A builder for constructing a function from Rust, statement by statement.
+#[derive(Clone)]
+pub struct FunctionBuilder<N: Network> {
+    /// The function under construction.
+    function: Function<N>,
+}
+
+impl<N: Network> FunctionBuilder<N> {
+    /// Initializes a new builder for a function with the given name.
+    pub fn new(name: Identifier<N>) -> Self {
+        Self { function: Function::new(name) }
+    }
+
+    /// Adds an input statement of the form `input {register} as {value_type};`.
+    pub fn add_input(mut self, register: Register<N>, value_type: ValueType<N>) -> Result<Self> {
+        self.function.add_input(FunctionInput::new(register, value_type))?;
+        Ok(self)
+    }
+
+    /// Adds the given instruction.
+    pub fn add_instruction(mut self, instruction: Instruction<N>) -> Result<Self> {
+        self.function.add_instruction(instruction)?;
+        Ok(self)
+    }
+
+    /// Adds an output statement of the form `output {operand} as {value_type};`.
+    pub fn add_output(mut self, operand: Operand<N>, value_type: ValueType<N>) -> Result<Self> {
+        self.function.add_output(FunctionOutput::new(operand, value_type))?;
+        Ok(self)
+    }
+
+    /// Adds the given finalize scope, which may be constructed with a `FinalizeBuilder`.
+    pub fn add_finalize(mut self, finalize: Finalize<N>) -> Result<Self> {
+        self.function.add_finalize(finalize)?;
+        Ok(self)
+    }
+
+    /// Returns the function.
+    pub fn build(self) -> Function<N> {
+        self.function
+    }
+}
+
+/// A builder for constructing a finalize scope from Rust, statement by statement.
+#[derive(Clone)]
+pub struct FinalizeBuilder<N: Network> {
+    /// The finalize scope under construction.
+    finalize: Finalize<N>,
+}
+
+impl<N: Network> FinalizeBuilder<N> {
+    /// Initializes a new builder for a finalize scope with the given name.
+    pub fn new(name: Identifier<N>) -> Self {
+        Self { finalize: Finalize::new(name) }
+    }
+
+    /// Adds an input statement of the form `input {register} as {finalize_type};`.
+    pub fn add_input(mut self, register: Register<N>, finalize_type: FinalizeType<N>) -> Result<Self> {
+        self.finalize.add_input(FinalizeInput::new(register, finalize_type))?;
+        Ok(self)
+    }
+
+    /// Adds the given command.
+    pub fn add_command(mut self, command: Command<N>) -> Result<Self> {
+        self.finalize.add_command(command)?;
+        Ok(self)
+    }
+
+    /// Returns the finalize scope.
+    pub fn build(self) -> Finalize<N> {
+        self.finalize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Add, Mul};
+    use console::{network::MainnetV0, program::LiteralType, types::U64};
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns the identifier for the given string.
+    fn id(name: &str) -> Identifier<CurrentNetwork> {
+        Identifier::from_str(name).unwrap()
+    }
+
+    /// Returns the `u64` plaintext type.
+    fn u64_type() -> PlaintextType<CurrentNetwork> {
+        PlaintextType::Literal(LiteralType::U64)
+    }
+
+    /// Returns the operand for the register at the given locator.
+    fn r(locator: u64) -> Operand<CurrentNetwork> {
+        Operand::Register(Register::Locator(locator))
+    }
+
+    #[test]
+    fn test_program_builder_matches_parsed_program() -> Result<()> {
+        // Build the program.
+        let closure = ClosureBuilder::new(id("double"))
+            .add_input(Register::Locator(0), RegisterType::Plaintext(u64_type()))?
+            .add_instruction(Instruction::Add(Add::new(vec![r(0), r(0)], Register::Locator(1))?))?
+            .add_output(r(1), RegisterType::Plaintext(u64_type()))?
+            .build();
+        let function = FunctionBuilder::new(id("mint"))
+            .add_input(Register::Locator(0), ValueType::Private(PlaintextType::Literal(LiteralType::Address)))?
+            .add_input(Register::Locator(1), ValueType::Private(u64_type()))?
+            .add_instruction(Instruction::Mul(Mul::new(
+                vec![r(1), Operand::Literal(Literal::U64(U64::new(2)))],
+                Register::Locator(2),
+            )?))?
+            .add_output(r(2), ValueType::Private(u64_type()))?
+            .build();
+        let program = ProgramBuilder::new(ProgramID::from_str("builder.aleo")?)?
+            .add_import(ProgramID::from_str("credits.aleo")?)
+            .add_mapping(id("balances"), PlaintextType::Literal(LiteralType::Address), u64_type())?
+            .add_struct_type(id("point"), vec![(id("x"), u64_type()), (id("y"), u64_type())])?
+            .add_record_type(id("token"), PublicOrPrivate::Private, vec![(
+                id("amount"),
+                EntryType::Private(u64_type()),
+                None,
+            )])?
+            .add_closure(closure)?
+            .add_function(function)?
+            .build()?;
+
+        // Parse the equivalent program.
+        let expected = Program::<CurrentNetwork>::from_str(
+            r"
+import credits.aleo;
+
+program builder.aleo;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+struct point:
+    x as u64;
+    y as u64;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+closure double:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    mul r1 2u64 into r2;
+    output r2 as u64.private;",
+        )?;
+
+        // Ensure the built program matches the parsed program.
+        assert_eq!(expected.to_string(), program.to_string());
+        assert!(expected == program);
+        // Ensure the built program round-trips through parsing.
+        assert!(Program::<CurrentNetwork>::from_str(&program.to_string())? == program);
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_builder_validation() -> Result<()> {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("builder.aleo")?;
+
+        // Ensure an instruction with the wrong number of operands is rejected.
+        assert!(Add::<CurrentNetwork>::new(vec![r(0)], Register::Locator(1)).is_err());
+        // Ensure a struct with duplicate members is rejected.
+        assert!(ProgramBuilder::new(program_id)?
+            .add_struct_type(id("point"), vec![(id("x"), u64_type()), (id("x"), u64_type())])
+            .is_err());
+        // Ensure a record that redeclares its owner is rejected.
+        assert!(ProgramBuilder::new(program_id)?
+            .add_record_type(id("token"), PublicOrPrivate::Private, vec![(
+                id("owner"),
+                EntryType::Private(u64_type()),
+                None
+            )])
+            .is_err());
+        // Ensure an input cannot be added after an instruction.
+        let builder = FunctionBuilder::<CurrentNetwork>::new(id("mint"))
+            .add_input(Register::Locator(0), ValueType::Public(u64_type()))?
+            .add_instruction(Instruction::Add(Add::new(vec![r(0), r(0)], Register::Locator(1))?))?;
+        assert!(builder.add_input(Register::Locator(2), ValueType::Public(u64_type())).is_err());
+
+        // Ensure a duplicate function name is rejected.
+        let function = FunctionBuilder::new(id("mint"))
+            .add_input(Register::Locator(0), ValueType::Public(u64_type()))?
+            .add_output(r(0), ValueType::Public(u64_type()))?
+            .build();
+        let builder = ProgramBuilder::new(program_id)?.add_function(function.clone())?;
+        assert!(builder.clone().add_function(function).is_err());
+        // Ensure a mapping cannot reuse the name of a function.
+        assert!(builder.clone().add_mapping(id("mint"), u64_type(), u64_type()).is_err());
+
+        // Ensure an import cannot reuse the name of a component.
+        assert!(builder.clone().add_import(ProgramID::from_str("mint.aleo")?).build().is_err());
+        // Ensure a program without components is rejected.
+        assert!(ProgramBuilder::new(program_id)?.build().is_err());
+        // Ensure a well-formed program is accepted.
+        assert!(builder.build().is_ok());
+        Ok(())
+    }
+}
@@ -21,7 +21,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        // Note: Version 2 is only used for programs that use features of `ConsensusVersion::V2`.
+        if version != 1 && version != 2 {
             return Err(error("Invalid program version"));
         }
 
@@ -48,13 +49,38 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
                 // Read the mapping.
                 0 => program.add_mapping(Mapping::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Read the struct.
-                1 => program.add_struct(StructType::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                1 => {
+                    let struct_ = StructType::read_le(&mut reader)?;
+                    // Ensure the struct type only has optional members in version 2.
+                    if version == 1 && !struct_.defaults().is_empty() {
+                        let name = struct_.name();
+                        return Err(error(format!("Struct '{name}' has optional members in a version 1 program")));
+                    }
+                    program.add_struct(struct_).map_err(|e| error(e.to_string()))?
+                }
                 // Read the record.
-                2 => program.add_record(RecordType::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                2 => {
+                    let record = RecordType::read_le(&mut reader)?;
+                    // Ensure the record type only has optional entries in version 2.
+                    if version == 1 && !record.defaults().is_empty() {
+                        let name = record.name();
+                        return Err(error(format!("Record '{name}' has optional entries in a version 1 program")));
+                    }
+                    program.add_record(record).map_err(|e| error(e.to_string()))?
+                }
                 // Read the closure.
                 3 => program.add_closure(ClosureCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Read the function.
                 4 => program.add_function(FunctionCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                // Read the struct import.
+                5 => {
+                    let locator = Locator::read_le(&mut reader)?;
+                    // Ensure the struct import is only present in version 2.
+                    if version == 1 {
+                        return Err(error(format!("Struct import '{locator}' is in a version 1 program")));
+                    }
+                    program.add_struct_import(locator).map_err(|e| error(e.to_string()))?
+                }
                 // Invalid variant.
                 _ => return Err(error(format!("Failed to parse program. Invalid component variant '{variant}'"))),
             }
@@ -69,7 +95,12 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
 {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Note: Version 2 is only used for programs that use features of `ConsensusVersion::V2`,
+        // so that the encoding of every other program is unchanged.
+        match self.consensus_version() {
+            ConsensusVersion::V1 => 1u8.write_le(&mut writer)?,
+            ConsensusVersion::V2 => 2u8.write_le(&mut writer)?,
+        }
 
         // Write the program ID.
         self.id.write_le(&mut writer)?;
@@ -104,6 +135,15 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
                     }
                     None => return Err(error(format!("Struct '{identifier}' is not defined."))),
                 },
+                ProgramDefinition::StructImport => match self.struct_imports.get(identifier) {
+                    Some(locator) => {
+                        // Write the variant.
+                        5u8.write_le(&mut writer)?;
+                        // Write the struct import.
+                        locator.write_le(&mut writer)?;
+                    }
+                    None => return Err(error(format!("Struct import '{identifier}' is not defined."))),
+                },
                 ProgramDefinition::Record => match self.records.get(identifier) {
                     Some(record) => {
                         // Write the variant.
@@ -172,4 +212,46 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_version() -> Result<()> {
+        // Ensure a program without optional record entries is written as version 1.
+        let program = Program::<CurrentNetwork>::from_str(
+            "program token.aleo;\n\nrecord token:\n    owner as address.private;\n    amount as u64.private;",
+        )?;
+        assert_eq!(program.to_bytes_le()?[0], 1);
+        assert_eq!(program.consensus_version(), ConsensusVersion::V1);
+
+        // Ensure a program with optional record entries is written as version 2.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private default 0u64;",
+        )?;
+        let mut bytes = program.to_bytes_le()?;
+        assert_eq!(bytes[0], 2);
+        assert_eq!(program.consensus_version(), ConsensusVersion::V2);
+        assert_eq!(program, Program::from_bytes_le(&bytes)?);
+
+        // Ensure optional record entries are rejected in version 1.
+        bytes[0] = 1;
+        assert!(Program::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+
+        // Ensure a program with optional struct members is written as version 2.
+        let program = Program::<CurrentNetwork>::from_str(
+            "program token.aleo;\n\nstruct point:\n    x as u64;\n    y as u64 default 0u64;",
+        )?;
+        let mut bytes = program.to_bytes_le()?;
+        assert_eq!(bytes[0], 2);
+        assert_eq!(program.consensus_version(), ConsensusVersion::V2);
+        assert_eq!(program, Program::from_bytes_le(&bytes)?);
+
+        // Ensure optional struct members are rejected in version 1.
+        bytes[0] = 1;
+        assert!(Program::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+        Ok(())
+    }
 }
@@ -12,10 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod input;
+pub(crate) mod input;
 use input::*;
 
-mod output;
+pub(crate) mod output;
 use output::*;
 
 mod bytes;
@@ -77,7 +77,7 @@ impl<N: Network, Instruction: InstructionTrait<N>> ClosureCore<N, Instruction> {
     /// This method will halt if the maximum number of inputs has been reached.
     /// This method will halt if the input statement was previously added.
     #[inline]
-    fn add_input(&mut self, input: Input<N>) -> Result<()> {
+    pub(crate) fn add_input(&mut self, input: Input<N>) -> Result<()> {
         // Ensure there are no instructions or output statements in memory.
         ensure!(self.instructions.is_empty(), "Cannot add inputs after instructions have been added");
         ensure!(self.outputs.is_empty(), "Cannot add inputs after outputs have been added");
@@ -127,7 +127,7 @@ impl<N: Network, Instruction: InstructionTrait<N>> ClosureCore<N, Instruction> {
     /// # Errors
     /// This method will halt if the maximum number of outputs has been reached.
     #[inline]
-    fn add_output(&mut self, output: Output<N>) -> Result<()> {
+    pub(crate) fn add_output(&mut self, output: Output<N>) -> Result<()> {
         // Ensure the maximum number of outputs has not been exceeded.
         ensure!(self.outputs.len() < N::MAX_OUTPUTS, "Cannot add more than {} outputs", N::MAX_OUTPUTS);
 
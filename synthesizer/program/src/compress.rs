@@ -0,0 +1,387 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The marker for the first dictionary token in a compressed program.
+/// Program text is ASCII, so every byte from this marker up to `REFERENCE_MARKER` refers to a dictionary token.
+const TOKEN_MARKER: u8 = 0x80;
+
+/// The marker for a back-reference in a compressed program, followed by its distance (`u16`) and length (`u8`).
+const REFERENCE_MARKER: u8 = 0xFF;
+/// The minimum length of a back-reference, as shorter runs are smaller to copy than to reference.
+const MIN_REFERENCE_LENGTH: usize = 5;
+/// The maximum number of earlier positions searched for the longest back-reference at each position.
+/// Note: This bound is part of the canonical deployment encoding and must never be changed.
+const MAX_REFERENCE_CANDIDATES: usize = 256;
+
+/// The dictionary of tokens used to compress program text, tuned on the distribution of opcodes and keywords.
+/// The token at index `i` is encoded as the byte `TOKEN_MARKER + i`.
+/// Note: This dictionary is part of the canonical deployment encoding and must never be reordered.
+const DICTIONARY: &[&str] = &[
+    // Registers and statements.
+    "\n    input r",
+    "\n    output r",
+    " into r",
+    " as ",
+    " r",
+    "\n    ",
+    ";\n\n",
+    // Visibilities.
+    ".private;",
+    ".public;",
+    ".constant;",
+    ".record;",
+    ".future;",
+    // Definitions.
+    "function ",
+    "finalize ",
+    "closure ",
+    "struct ",
+    "record ",
+    "mapping ",
+    "program ",
+    "import ",
+    ".aleo",
+    "key as ",
+    "value as ",
+    "owner as address.private;",
+    // Literal types.
+    "address",
+    "boolean",
+    "field",
+    "group",
+    "scalar",
+    "signature",
+    "string",
+    "u128",
+    "u64",
+    "u32",
+    "u16",
+    "u8",
+    "i128",
+    "i64",
+    "i32",
+    "i16",
+    "i8",
+    // Arithmetic opcodes.
+    "abs.w ",
+    "abs ",
+    "add.w ",
+    "add ",
+    "sub.w ",
+    "sub ",
+    "mul.w ",
+    "mul ",
+    "div.w ",
+    "div ",
+    "rem.w ",
+    "rem ",
+    "mod ",
+    "pow.w ",
+    "pow ",
+    "shl.w ",
+    "shl ",
+    "shr.w ",
+    "shr ",
+    "neg ",
+    "inv ",
+    "square ",
+    "sqrt ",
+    "double ",
+    // Logical and comparison opcodes.
+    "and ",
+    "or ",
+    "xor ",
+    "nand ",
+    "nor ",
+    "not ",
+    "is.eq ",
+    "is.neq ",
+    "gte ",
+    "gt ",
+    "lte ",
+    "lt ",
+    "ternary ",
+    "assert.eq ",
+    "assert.neq ",
+    // Cryptographic opcodes.
+    "hash.bhp256 ",
+    "hash.bhp512 ",
+    "hash.bhp768 ",
+    "hash.bhp1024 ",
+    "hash.ped64 ",
+    "hash.ped128 ",
+    "hash.psd2 ",
+    "hash.psd4 ",
+    "hash.psd8 ",
+    "hash.keccak256 ",
+    "hash.sha3_256 ",
+    "commit.bhp256 ",
+    "commit.ped64 ",
+    "sign.verify ",
+    "rand.chacha ",
+    // Control opcodes.
+    "cast ",
+    "call ",
+    "async ",
+    "await r",
+    "contains ",
+    "get.or_use ",
+    "get ",
+    "set ",
+    "remove ",
+    "branch.eq ",
+    "branch.neq ",
+    "position ",
+    // Operands.
+    "self.caller",
+    "self.signer",
+    "block.height",
+    "network.id",
+    "microcredits",
+    "true",
+    "false",
+];
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns the canonical compressed bytes of the program.
+    pub fn to_compressed_bytes(&self) -> Result<Vec<u8>> {
+        compress_program_text(&self.to_string())
+    }
+
+    /// Returns a program from its canonical compressed bytes.
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Self> {
+        // Decompress the program text.
+        let text = decompress_program_text::<N>(bytes)?;
+        // Parse the program.
+        let program = Self::from_str(&text)?;
+        // Ensure the compressed bytes are canonical.
+        ensure!(program.to_compressed_bytes()? == bytes, "The compressed program is not in canonical form");
+        // Return the program.
+        Ok(program)
+    }
+}
+
+/// Compresses the given program text, by replacing dictionary tokens, and then repeated runs with back-references.
+fn compress_program_text(text: &str) -> Result<Vec<u8>> {
+    // Ensure the program text is ASCII.
+    ensure!(text.is_ascii(), "The program text must be ASCII to be compressed");
+    // Replace the dictionary tokens, and then the repeated runs.
+    Ok(encode_references(&encode_tokens(text.as_bytes())))
+}
+
+/// Decompresses the given bytes into program text, ensuring the text does not exceed `N::MAX_PROGRAM_SIZE`.
+fn decompress_program_text<N: Network>(bytes: &[u8]) -> Result<String> {
+    decode_tokens::<N>(&decode_references::<N>(bytes)?)
+}
+
+/// Encodes the given program text, by greedily replacing the longest matching dictionary token at each position.
+fn encode_tokens(text: &[u8]) -> Vec<u8> {
+    let mut compressed = Vec::with_capacity(text.len());
+    let mut position = 0;
+    while position < text.len() {
+        // Find the longest dictionary token at the current position.
+        let token = DICTIONARY
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| text[position..].starts_with(token.as_bytes()))
+            .max_by_key(|(_, token)| token.len());
+        match token {
+            // Write the token index.
+            Some((index, token)) => {
+                compressed.push(TOKEN_MARKER + index as u8);
+                position += token.len();
+            }
+            // Write the byte.
+            None => {
+                compressed.push(text[position]);
+                position += 1;
+            }
+        }
+    }
+    compressed
+}
+
+/// Encodes the given bytes, by greedily replacing the longest earlier run at each position with a back-reference.
+/// Among runs of the same length, the nearest run is referenced.
+fn encode_references(bytes: &[u8]) -> Vec<u8> {
+    let mut compressed = Vec::with_capacity(bytes.len());
+    // The earlier positions of each run of `MIN_REFERENCE_LENGTH` bytes, in increasing order.
+    let mut positions: IndexMap<&[u8], Vec<usize>> = IndexMap::new();
+    let mut position = 0;
+    while position < bytes.len() {
+        // Find the longest earlier run that matches at the current position, as `(distance, length)`.
+        let mut reference: Option<(usize, usize)> = None;
+        let candidates = bytes.get(position..position + MIN_REFERENCE_LENGTH).and_then(|run| positions.get(run));
+        for candidate in candidates.into_iter().flatten().rev().take(MAX_REFERENCE_CANDIDATES) {
+            // Ensure the distance fits in a back-reference.
+            let distance = position - candidate;
+            if distance > u16::MAX as usize {
+                break;
+            }
+            // Note: The run may overlap the current position, as it is expanded byte by byte.
+            let length = bytes[*candidate..]
+                .iter()
+                .zip(&bytes[position..])
+                .take(u8::MAX as usize)
+                .take_while(|(byte, other)| byte == other)
+                .count();
+            if reference.map_or(true, |(_, longest)| length > longest) {
+                reference = Some((distance, length));
+            }
+        }
+        // Write the back-reference, or the byte.
+        let step = match reference {
+            Some((distance, length)) => {
+                compressed.push(REFERENCE_MARKER);
+                compressed.extend_from_slice(&(distance as u16).to_le_bytes());
+                compressed.push(length as u8);
+                length
+            }
+            None => {
+                compressed.push(bytes[position]);
+                1
+            }
+        };
+        // Index the runs that start in the written bytes.
+        for start in position..position + step {
+            if let Some(run) = bytes.get(start..start + MIN_REFERENCE_LENGTH) {
+                positions.entry(run).or_default().push(start);
+            }
+        }
+        position += step;
+    }
+    compressed
+}
+
+/// Decodes the given bytes by expanding the back-references, ensuring the result does not exceed `N::MAX_PROGRAM_SIZE`.
+/// Note: Every decoded byte expands to at least one character of program text, so the bound also applies to the text.
+fn decode_references<N: Network>(compressed: &[u8]) -> Result<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(compressed.len().saturating_mul(2).min(N::MAX_PROGRAM_SIZE));
+    let mut iter = compressed.iter();
+    while let Some(byte) = iter.next() {
+        match *byte {
+            // Expand the back-reference.
+            REFERENCE_MARKER => {
+                let (Some(d0), Some(d1), Some(length)) = (iter.next(), iter.next(), iter.next()) else {
+                    bail!("Truncated back-reference in the compressed program")
+                };
+                let distance = u16::from_le_bytes([*d0, *d1]) as usize;
+                let length = *length as usize;
+                // Ensure the back-reference is valid.
+                ensure!(length >= MIN_REFERENCE_LENGTH, "Invalid back-reference length '{length}'");
+                ensure!((1..=bytes.len()).contains(&distance), "Invalid back-reference distance '{distance}'");
+                // Copy the run byte by byte, as it may overlap the bytes it writes.
+                let start = bytes.len() - distance;
+                for index in start..start + length {
+                    bytes.push(bytes[index]);
+                }
+            }
+            // Copy the byte.
+            byte => bytes.push(byte),
+        }
+        // Ensure the decoded bytes do not exceed the maximum size.
+        ensure!(bytes.len() <= N::MAX_PROGRAM_SIZE, "Decompressed program exceeds N::MAX_PROGRAM_SIZE");
+    }
+    Ok(bytes)
+}
+
+/// Decodes the given bytes into program text, by expanding the dictionary tokens,
+/// ensuring the text does not exceed `N::MAX_PROGRAM_SIZE`.
+fn decode_tokens<N: Network>(bytes: &[u8]) -> Result<String> {
+    let mut text = String::with_capacity(bytes.len().saturating_mul(2).min(N::MAX_PROGRAM_SIZE));
+    for byte in bytes {
+        match byte.checked_sub(TOKEN_MARKER) {
+            // Expand the token.
+            Some(index) => match DICTIONARY.get(index as usize) {
+                Some(token) => text.push_str(token),
+                None => bail!("Invalid token '{byte}' in the compressed program"),
+            },
+            // Copy the ASCII byte.
+            None => text.push(*byte as char),
+        }
+        // Ensure the program text does not exceed the maximum size.
+        ensure!(text.len() <= N::MAX_PROGRAM_SIZE, "Decompressed program exceeds N::MAX_PROGRAM_SIZE");
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Program;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_dictionary() {
+        // Ensure every token fits between the markers.
+        assert!(DICTIONARY.len() <= (REFERENCE_MARKER - TOKEN_MARKER) as usize);
+        // Ensure the tokens are unique and ASCII.
+        for (index, token) in DICTIONARY.iter().enumerate() {
+            assert!(token.is_ascii());
+            assert!(!DICTIONARY[..index].contains(token), "Duplicate token '{token}'");
+        }
+    }
+
+    #[test]
+    fn test_compressed_bytes() -> Result<()> {
+        // Compress the credits program.
+        let program = Program::<CurrentNetwork>::credits()?;
+        let compressed = program.to_compressed_bytes()?;
+        // Ensure the program round-trips.
+        assert_eq!(program, Program::from_compressed_bytes(&compressed)?);
+        // Ensure the compressed bytes are smaller than the program bytes,
+        // and at least 60% smaller than the program text.
+        assert!(compressed.len() < program.to_bytes_le()?.len());
+        assert!(compressed.len() * 10 <= program.to_string().len() * 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compressed_bytes_are_canonical() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program compress.aleo;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        )?;
+        let compressed = program.to_compressed_bytes()?;
+
+        // Ensure a non-canonical encoding of the same text is rejected.
+        let uncompressed = program.to_string().into_bytes();
+        assert!(Program::<CurrentNetwork>::from_compressed_bytes(&uncompressed).is_err());
+        // Ensure an invalid token is rejected.
+        let mut invalid = compressed.clone();
+        invalid.push(REFERENCE_MARKER - 1);
+        assert!(Program::<CurrentNetwork>::from_compressed_bytes(&invalid).is_err());
+        // Ensure invalid back-references are rejected.
+        for reference in [[REFERENCE_MARKER, 0, 0, 8], [REFERENCE_MARKER, 1, 0, 1], [REFERENCE_MARKER, 0xFF, 0xFF, 8]] {
+            let mut invalid = compressed.clone();
+            invalid.extend(reference);
+            assert!(Program::<CurrentNetwork>::from_compressed_bytes(&invalid).is_err());
+        }
+        let mut invalid = compressed.clone();
+        invalid.extend([REFERENCE_MARKER, 1]);
+        assert!(Program::<CurrentNetwork>::from_compressed_bytes(&invalid).is_err());
+        // Ensure truncated bytes are rejected.
+        assert!(Program::<CurrentNetwork>::from_compressed_bytes(&compressed[..compressed.len() - 1]).is_err());
+        Ok(())
+    }
+}
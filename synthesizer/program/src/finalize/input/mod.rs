@@ -31,6 +31,12 @@ pub struct Input<N: Network> {
 }
 
 impl<N: Network> Input<N> {
+    /// Initializes a new input statement with the given register and finalize type.
+    #[inline]
+    pub const fn new(register: Register<N>, finalize_type: FinalizeType<N>) -> Self {
+        Self { register, finalize_type }
+    }
+
     /// Returns the input register.
     #[inline]
     pub const fn register(&self) -> &Register<N> {
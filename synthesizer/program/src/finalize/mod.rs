@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod input;
+pub(crate) mod input;
 use input::*;
 
 mod bytes;
@@ -87,7 +87,7 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     /// This method will halt if the maximum number of inputs has been reached.
     /// This method will halt if the input statement was previously added.
     #[inline]
-    fn add_input(&mut self, input: Input<N>) -> Result<()> {
+    pub(crate) fn add_input(&mut self, input: Input<N>) -> Result<()> {
         // Ensure there are no commands in memory.
         ensure!(self.commands.is_empty(), "Cannot add inputs after commands have been added");
 
@@ -12,10 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod input;
+pub(crate) mod input;
 use input::*;
 
-mod output;
+pub(crate) mod output;
 use output::*;
 
 mod bytes;
@@ -24,6 +24,7 @@ mod parse;
 use crate::{
     finalize::FinalizeCore,
     traits::{CommandTrait, InstructionTrait},
+    Operand,
 };
 use console::{
     network::prelude::*,
@@ -87,6 +88,22 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fun
     pub const fn finalize_logic(&self) -> Option<&FinalizeCore<N, Command>> {
         self.finalize_logic.as_ref()
     }
+
+    /// Returns `true` if the function is pure, i.e. it neither consumes nor produces records, nor touches mappings.
+    /// A pure function may be queried without authorization or proving.
+    pub fn is_pure(&self) -> bool {
+        // Ensure the function does not have finalize logic.
+        let has_no_finalize = self.finalize_logic.is_none();
+        // Ensure the inputs and outputs are plaintexts.
+        let is_plaintext = |value_type: &ValueType<N>| {
+            matches!(value_type, ValueType::Constant(..) | ValueType::Public(..) | ValueType::Private(..))
+        };
+        let has_plaintext_io = self.inputs.iter().all(|input| is_plaintext(input.value_type()))
+            && self.outputs.iter().all(|output| is_plaintext(output.value_type()));
+        // Ensure the instructions are pure.
+        let has_pure_instructions = self.instructions.iter().all(|instruction| instruction.is_pure());
+        has_no_finalize && has_plaintext_io && has_pure_instructions
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> FunctionCore<N, Instruction, Command> {
@@ -98,7 +115,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fun
     /// This method will halt if the input statement was previously added.
     /// This method will halt if a finalize logic has been added.
     #[inline]
-    fn add_input(&mut self, input: Input<N>) -> Result<()> {
+    pub(crate) fn add_input(&mut self, input: Input<N>) -> Result<()> {
         // Ensure there are no instructions or output statements in memory.
         ensure!(self.instructions.is_empty(), "Cannot add inputs after instructions have been added");
         ensure!(self.outputs.is_empty(), "Cannot add inputs after outputs have been added");
@@ -156,7 +173,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fun
     /// This method will halt if the maximum number of outputs has been reached.
     /// This method will halt if a finalize logic has been added.
     #[inline]
-    fn add_output(&mut self, output: Output<N>) -> Result<()> {
+    pub(crate) fn add_output(&mut self, output: Output<N>) -> Result<()> {
         // Ensure the maximum number of outputs has not been exceeded.
         ensure!(self.outputs.len() < N::MAX_OUTPUTS, "Cannot add more than {} outputs", N::MAX_OUTPUTS);
         // Ensure the output statement was not previously added.
@@ -178,7 +195,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fun
     /// This method will halt if the maximum number of finalize inputs has been reached.
     /// This method will halt if the number of finalize operands does not match the number of finalize inputs.
     #[inline]
-    fn add_finalize(&mut self, finalize: FinalizeCore<N, Command>) -> Result<()> {
+    pub(crate) fn add_finalize(&mut self, finalize: FinalizeCore<N, Command>) -> Result<()> {
         // Ensure there is no finalize scope in memory.
         ensure!(self.finalize_logic.is_none(), "Cannot add multiple finalize scopes to function '{}'", self.name);
         // Ensure the finalize scope name matches the function name.
@@ -190,6 +207,45 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fun
         self.finalize_logic = Some(finalize);
         Ok(())
     }
+
+    /// Returns a copy of the function, with the given instructions and output operands in place of its own.
+    /// The inputs, output types, and finalize scope of the function are unchanged.
+    ///
+    /// # Errors
+    /// This method will halt if the number of output operands does not match the number of outputs.
+    /// This method will halt if the instructions or output statements are invalid for the function.
+    #[inline]
+    pub fn with_body(&self, instructions: Vec<Instruction>, output_operands: Vec<Operand<N>>) -> Result<Self> {
+        // Ensure the number of output operands matches the number of outputs.
+        ensure!(
+            self.outputs.len() == output_operands.len(),
+            "Expected {} output operands, found {}",
+            self.outputs.len(),
+            output_operands.len()
+        );
+
+        // Initialize the function, with the same inputs.
+        let mut function = Self {
+            name: self.name,
+            inputs: self.inputs.clone(),
+            instructions: Vec::new(),
+            outputs: IndexSet::new(),
+            finalize_logic: None,
+        };
+        // Add the instructions.
+        for instruction in instructions {
+            function.add_instruction(instruction)?;
+        }
+        // Add the output statements, with the same output types.
+        for (output, operand) in self.outputs.iter().zip_eq(output_operands) {
+            function.add_output(Output::new(operand, output.value_type().clone()))?;
+        }
+        // Add the finalize scope.
+        if let Some(finalize) = &self.finalize_logic {
+            function.add_finalize(finalize.clone())?;
+        }
+        Ok(function)
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> TypeName
@@ -234,6 +290,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_pure() {
+        // Ensure a function over plaintexts is pure.
+        let function = Function::<CurrentNetwork>::from_str(
+            "function pure:\n    input r0 as u64.public;\n    add r0 r0 into r1;\n    output r1 as u64.private;",
+        )
+        .unwrap();
+        assert!(function.is_pure());
+
+        // Ensure a function that produces a record is not pure.
+        let function = Function::<CurrentNetwork>::from_str(
+            "function mint:\n    input r0 as u64.public;\n    cast self.signer r0 into r1 as token.record;\n    output r1 as token.record;",
+        )
+        .unwrap();
+        assert!(!function.is_pure());
+
+        // Ensure a function that consumes a record is not pure.
+        let function = Function::<CurrentNetwork>::from_str("function burn:\n    input r0 as token.record;").unwrap();
+        assert!(!function.is_pure());
+
+        // Ensure a function that calls an external program is not pure.
+        let function = Function::<CurrentNetwork>::from_str(
+            "function relay:\n    input r0 as u64.public;\n    call other.aleo/double r0 into r1;\n    output r1 as u64.public;",
+        )
+        .unwrap();
+        assert!(!function.is_pure());
+
+        // Ensure a function with finalize logic is not pure.
+        let function = Function::<CurrentNetwork>::from_str(
+            "function store:\n    input r0 as u64.public;\n    async store r0 into r1;\n    output r1 as store.aleo/store.future;\n\nfinalize store:\n    input r0 as u64.public;\n    set r0 into values[0u8];",
+        )
+        .unwrap();
+        assert!(!function.is_pure());
+    }
+
     #[test]
     fn test_add_instruction() {
         // Initialize a new function instance.
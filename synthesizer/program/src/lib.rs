@@ -21,6 +21,9 @@ pub type Function<N> = crate::FunctionCore<N, Instruction<N>, Command<N>>;
 pub type Finalize<N> = crate::FinalizeCore<N, Command<N>>;
 pub type Closure<N> = crate::ClosureCore<N, Instruction<N>>;
 
+mod builder;
+pub use builder::*;
+
 mod closure;
 pub use closure::*;
 
@@ -43,48 +46,54 @@ pub mod traits;
 pub use traits::*;
 
 mod bytes;
+mod compress;
 mod parse;
 mod serialize;
 
 use console::{
-    network::prelude::{
-        alt,
-        anyhow,
-        bail,
-        de,
-        ensure,
-        error,
-        fmt,
-        many0,
-        many1,
-        map,
-        map_res,
-        tag,
-        take,
-        Debug,
-        Deserialize,
-        Deserializer,
-        Display,
-        Error,
-        Formatter,
-        FromBytes,
-        FromBytesDeserializer,
-        FromStr,
-        IoResult,
-        Network,
-        Parser,
-        ParserResult,
-        Read,
-        Result,
-        Sanitizer,
-        Serialize,
-        Serializer,
-        ToBytes,
-        ToBytesSerializer,
-        TypeName,
-        Write,
+    network::{
+        prelude::{
+            alt,
+            anyhow,
+            bail,
+            de,
+            ensure,
+            error,
+            fmt,
+            many0,
+            many1,
+            map,
+            map_res,
+            tag,
+            take,
+            Debug,
+            Deserialize,
+            Deserializer,
+            Display,
+            Error,
+            Formatter,
+            FromBytes,
+            FromBytesDeserializer,
+            FromStr,
+            IoResult,
+            Network,
+            Parser,
+            ParserResult,
+            Read,
+            Result,
+            Sanitizer,
+            Serialize,
+            Serializer,
+            ToBits,
+            ToBytes,
+            ToBytesSerializer,
+            TypeName,
+            Write,
+        },
+        ConsensusVersion,
     },
-    program::{Identifier, PlaintextType, ProgramID, RecordType, StructType},
+    program::{Identifier, Locator, PlaintextType, ProgramID, RecordType, StructType},
+    types::Field,
 };
 
 use indexmap::IndexMap;
@@ -95,6 +104,8 @@ enum ProgramDefinition {
     Mapping,
     /// A program struct.
     Struct,
+    /// A program struct, imported from another program.
+    StructImport,
     /// A program record.
     Record,
     /// A program closure.
@@ -115,6 +126,8 @@ pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: Co
     mappings: IndexMap<Identifier<N>, Mapping<N>>,
     /// A map of the declared structs for the program.
     structs: IndexMap<Identifier<N>, StructType<N>>,
+    /// A map of the declared struct imports for the program, as `(struct name, locator)`.
+    struct_imports: IndexMap<Identifier<N>, Locator<N>>,
     /// A map of the declared record types for the program.
     records: IndexMap<Identifier<N>, RecordType<N>>,
     /// A map of the declared closures for the program.
@@ -136,6 +149,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
             identifiers: IndexMap::new(),
             mappings: IndexMap::new(),
             structs: IndexMap::new(),
+            struct_imports: IndexMap::new(),
             records: IndexMap::new(),
             closures: IndexMap::new(),
             functions: IndexMap::new(),
@@ -168,6 +182,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.structs
     }
 
+    /// Returns the struct imports in the program.
+    pub const fn struct_imports(&self) -> &IndexMap<Identifier<N>, Locator<N>> {
+        &self.struct_imports
+    }
+
     /// Returns the records in the program.
     pub const fn records(&self) -> &IndexMap<Identifier<N>, RecordType<N>> {
         &self.records
@@ -194,8 +213,14 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
     }
 
     /// Returns `true` if the program contains a struct with the given name.
+    /// Note: This includes structs that are imported from other programs.
     pub fn contains_struct(&self, name: &Identifier<N>) -> bool {
-        self.structs.contains_key(name)
+        self.structs.contains_key(name) || self.struct_imports.contains_key(name)
+    }
+
+    /// Returns `true` if the program imports a struct with the given name.
+    pub fn contains_struct_import(&self, name: &Identifier<N>) -> bool {
+        self.struct_imports.contains_key(name)
     }
 
     /// Returns `true` if the program contains a record with the given name.
@@ -224,7 +249,12 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
     }
 
     /// Returns the struct with the given name.
+    /// Note: Imported structs are resolved by the stack, see `StackProgram::get_struct`.
     pub fn get_struct(&self, name: &Identifier<N>) -> Result<&StructType<N>> {
+        // Ensure the struct is not imported.
+        if let Some(locator) = self.struct_imports.get(name) {
+            bail!("Struct '{name}' is imported from '{locator}', and must be resolved by the stack.")
+        }
         // Attempt to retrieve the struct.
         let struct_ = self.structs.get(name).ok_or_else(|| anyhow!("Struct '{name}' is not defined."))?;
         // Ensure the struct name matches.
@@ -283,6 +313,24 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         // Return the function.
         Ok(function)
     }
+
+    /// Returns the earliest consensus version in which the program may be deployed,
+    /// which is the latest consensus version that introduced a feature used by the program.
+    pub fn consensus_version(&self) -> ConsensusVersion {
+        // Struct and record types with default values, and struct imports, are introduced in `ConsensusVersion::V2`.
+        let has_defaults = self.structs.values().any(|struct_| !struct_.defaults().is_empty())
+            || self.records.values().any(|record| !record.defaults().is_empty());
+        match has_defaults || !self.struct_imports.is_empty() {
+            true => ConsensusVersion::V2,
+            false => ConsensusVersion::V1,
+        }
+    }
+
+    /// Returns the content hash of the program, which is the BHP-1024 hash of its byte representation.
+    /// Unlike the program ID, the content hash is an immutable reference to the exact contents of the program.
+    pub fn to_content_hash(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -363,7 +411,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         let struct_name = *struct_.name();
 
         // Ensure the program has not exceeded the maximum number of structs.
-        ensure!(self.structs.len() < N::MAX_STRUCTS, "Program exceeds the maximum number of structs.");
+        ensure!(
+            self.structs.len() + self.struct_imports.len() < N::MAX_STRUCTS,
+            "Program exceeds the maximum number of structs."
+        );
 
         // Ensure the struct name is new.
         ensure!(self.is_unique_name(&struct_name), "'{struct_name}' is already in use.");
@@ -385,14 +436,14 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                 PlaintextType::Literal(_) => continue,
                 PlaintextType::Struct(member_identifier) => {
                     // Ensure the member struct name exists in the program.
-                    if !self.structs.contains_key(member_identifier) {
+                    if !self.contains_struct(member_identifier) {
                         bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
                     }
                 }
                 PlaintextType::Array(array_type) => {
                     if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
                         // Ensure the member struct name exists in the program.
-                        if !self.structs.contains_key(struct_name) {
+                        if !self.contains_struct(struct_name) {
                             bail!("'{struct_name}' in array '{array_type}' is not defined.")
                         }
                     }
@@ -411,6 +462,43 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         Ok(())
     }
 
+    /// Adds a new struct import to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the struct name is already in use in the program.
+    /// This method will halt if the struct name is a reserved opcode or keyword.
+    /// This method will halt if the struct is imported from this program.
+    #[inline]
+    fn add_struct_import(&mut self, locator: Locator<N>) -> Result<()> {
+        // Retrieve the struct name.
+        let struct_name = *locator.resource();
+
+        // Ensure the program has not exceeded the maximum number of structs.
+        ensure!(
+            self.structs.len() + self.struct_imports.len() < N::MAX_STRUCTS,
+            "Program exceeds the maximum number of structs."
+        );
+
+        // Ensure the struct name is new.
+        ensure!(self.is_unique_name(&struct_name), "'{struct_name}' is already in use.");
+        // Ensure the struct name is not a reserved opcode.
+        ensure!(!Self::is_reserved_opcode(&struct_name.to_string()), "'{struct_name}' is a reserved opcode.");
+        // Ensure the struct name is not a reserved keyword.
+        ensure!(!Self::is_reserved_keyword(&struct_name), "'{struct_name}' is a reserved keyword.");
+        // Ensure the struct is not imported from this program.
+        ensure!(locator.program_id() != self.id(), "Struct '{struct_name}' cannot be imported from its own program.");
+
+        // Add the struct name to the identifiers.
+        if self.identifiers.insert(struct_name, ProgramDefinition::StructImport).is_some() {
+            bail!("'{}' already exists in the program.", struct_name)
+        }
+        // Add the struct import to the program.
+        if self.struct_imports.insert(struct_name, locator).is_some() {
+            bail!("'{}' already exists in the program.", struct_name)
+        }
+        Ok(())
+    }
+
     /// Adds a new record to the program.
     ///
     /// # Errors
@@ -442,14 +530,14 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
             match entry_type.plaintext_type() {
                 PlaintextType::Literal(_) => continue,
                 PlaintextType::Struct(identifier) => {
-                    if !self.structs.contains_key(identifier) {
+                    if !self.contains_struct(identifier) {
                         bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
                     }
                 }
                 PlaintextType::Array(array_type) => {
                     if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
                         // Ensure the member struct name exists in the program.
-                        if !self.structs.contains_key(struct_name) {
+                        if !self.contains_struct(struct_name) {
                             bail!("'{struct_name}' in array '{array_type}' is not defined.")
                         }
                     }
@@ -719,6 +807,51 @@ struct message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_struct_import() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import shapes.aleo;
+
+program unknown.aleo;
+
+struct shapes.aleo/point;
+
+struct segment:
+    start as point;
+    end as point;",
+        )?;
+        let name = Identifier::from_str("point")?;
+        // Ensure the struct import was added.
+        assert!(program.contains_struct(&name));
+        assert!(program.contains_struct_import(&name));
+        assert_eq!(program.struct_imports().get(&name), Some(&Locator::from_str("shapes.aleo/point")?));
+        // Ensure the imported struct must be resolved by the stack.
+        assert!(program.get_struct(&name).is_err());
+
+        // Ensure the program round-trips through strings and bytes.
+        assert_eq!(program, Program::from_str(&program.to_string())?);
+        assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le()?)?);
+
+        // Ensure the struct import is a feature of `ConsensusVersion::V2`, and is rejected in version 1 bytes.
+        assert_eq!(program.consensus_version(), ConsensusVersion::V2);
+        let mut bytes = program.to_bytes_le()?;
+        assert_eq!(bytes[0], 2);
+        bytes[0] = 1;
+        assert!(Program::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+
+        // Ensure a struct cannot be imported from its own program.
+        assert!(Program::<CurrentNetwork>::from_str("program unknown.aleo; struct unknown.aleo/point;").is_err());
+        // Ensure an imported struct name cannot be redefined.
+        assert!(Program::<CurrentNetwork>::from_str(
+            "program unknown.aleo; struct shapes.aleo/point; struct point: x as field;"
+        )
+        .is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_record() -> Result<()> {
         // Create a new record.
@@ -53,6 +53,7 @@ use crate::{
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Operand,
 };
 use console::{
     network::prelude::*,
@@ -129,7 +130,7 @@ impl<N: Network> CommandTrait<N> for Command<N> {
     /// Returns `true` if the command is a call instruction.
     #[inline]
     fn is_call(&self) -> bool {
-        matches!(self, Command::Instruction(Instruction::Call(_)))
+        matches!(self, Command::Instruction(Instruction::Call(_) | Instruction::CallView(_)))
     }
 
     /// Returns `true` if the command is a cast to record instruction.
@@ -145,6 +146,23 @@ impl<N: Network> CommandTrait<N> for Command<N> {
 }
 
 impl<N: Network> Command<N> {
+    /// Returns the operands of the command.
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        match self {
+            Command::Instruction(instruction) => instruction.operands().to_vec(),
+            Command::Await(await_) => vec![Operand::Register(await_.register().clone())],
+            Command::Contains(contains) => contains.operands(),
+            Command::Get(get) => get.operands(),
+            Command::GetOrUse(get_or_use) => get_or_use.operands(),
+            Command::RandChaCha(rand_chacha) => rand_chacha.operands(),
+            Command::Remove(remove) => remove.operands(),
+            Command::Set(set) => set.operands(),
+            Command::BranchEq(branch_eq) => vec![branch_eq.first().clone(), branch_eq.second().clone()],
+            Command::BranchNeq(branch_neq) => vec![branch_neq.first().clone(), branch_neq.second().clone()],
+            Command::Position(_) => vec![],
+        }
+    }
+
     /// Finalizes the command.
     #[inline]
     pub fn finalize(
@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::{
+    prelude::ToBits,
+    program::{Literal, Plaintext, Value},
+    types::Boolean,
+};
+
+impl<N: Network> Instruction<N> {
+    /// The opcodes of the instructions that can be evaluated in constant time.
+    ///
+    /// Note: The `call` and `call.view` instructions are handled by the stack, and are constant time in their control flow.
+    pub const CONSTANT_TIME_OPCODES: &'static [Opcode] = &[
+        AddWrapped::<N>::opcode(),
+        And::<N>::opcode(),
+        AssertEq::<N>::opcode(),
+        AssertNeq::<N>::opcode(),
+        Async::<N>::opcode(),
+        Call::<N>::opcode(),
+        CallView::<N>::opcode(),
+        IsEq::<N>::opcode(),
+        IsNeq::<N>::opcode(),
+        Nand::<N>::opcode(),
+        Nor::<N>::opcode(),
+        Not::<N>::opcode(),
+        Or::<N>::opcode(),
+        SubWrapped::<N>::opcode(),
+        Ternary::<N>::opcode(),
+        Xor::<N>::opcode(),
+    ];
+
+    /// Returns `true` if the instruction can be evaluated in constant time, i.e. in time that is
+    /// independent of the values of its operands (the types of the operands are public).
+    #[inline]
+    pub fn is_constant_time(&self) -> bool {
+        Self::CONSTANT_TIME_OPCODES.contains(&self.opcode())
+    }
+
+    /// Returns the opcodes that cannot yet be evaluated in constant time.
+    pub fn non_constant_time_opcodes() -> Vec<Opcode> {
+        Self::OPCODES.iter().copied().filter(|opcode| !Self::CONSTANT_TIME_OPCODES.contains(opcode)).collect()
+    }
+
+    /// Evaluates the instruction in constant time, i.e. in time that is independent of the values of its operands.
+    ///
+    /// The equality and selection instructions use dedicated branchless implementations.
+    /// The remaining constant-time instructions are already data-independent in their console implementation.
+    /// All other instructions are rejected, as they cannot yet be evaluated in constant time.
+    #[inline]
+    pub fn evaluate_constant_time(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersSigner<N> + RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        match self {
+            Instruction::AssertEq(instruction) => {
+                // Assert the inputs, without revealing them in the error.
+                let is_equal = evaluate_is_equal(stack, &*registers, instruction.operands())?;
+                ensure!(is_equal, "'{}' failed: the operands are not equal (should be equal)", self.opcode());
+                Ok(())
+            }
+            Instruction::AssertNeq(instruction) => {
+                // Assert the inputs, without revealing them in the error.
+                let is_equal = evaluate_is_equal(stack, &*registers, instruction.operands())?;
+                ensure!(!is_equal, "'{}' failed: the operands are equal (should not be equal)", self.opcode());
+                Ok(())
+            }
+            Instruction::IsEq(instruction) => {
+                // Compute the equality of the inputs.
+                let output =
+                    Literal::Boolean(Boolean::new(evaluate_is_equal(stack, &*registers, instruction.operands())?));
+                // Store the output.
+                registers.store(stack, &instruction.destinations()[0], Value::Plaintext(Plaintext::from(output)))
+            }
+            Instruction::IsNeq(instruction) => {
+                // Compute the inequality of the inputs.
+                let output =
+                    Literal::Boolean(Boolean::new(!evaluate_is_equal(stack, &*registers, instruction.operands())?));
+                // Store the output.
+                registers.store(stack, &instruction.destinations()[0], Value::Plaintext(Plaintext::from(output)))
+            }
+            Instruction::Ternary(instruction) => {
+                // Ensure the number of operands is correct.
+                let operands = instruction.operands();
+                ensure!(operands.len() == 3, "Instruction '{}' expects 3 operands", self.opcode());
+                // Load the operands literals.
+                let condition = registers.load_literal(stack, &operands[0])?;
+                let first = registers.load_literal(stack, &operands[1])?;
+                let second = registers.load_literal(stack, &operands[2])?;
+                // Select the output.
+                let output = select(&condition, &first, &second)?;
+                // Store the output.
+                registers.store_literal(stack, &instruction.destinations()[0], output)
+            }
+            // Note: `call` is rejected by `evaluate`, as it must be evaluated by the stack.
+            _ if self.is_constant_time() => self.evaluate(stack, registers),
+            _ => bail!("Instruction '{}' cannot yet be evaluated in constant time", self.opcode()),
+        }
+    }
+}
+
+/// Loads the two given operands, and returns `true` if they are equal.
+/// The comparison runs in time that is independent of the values (but not the types) of the operands.
+fn evaluate_is_equal<N: Network>(
+    stack: &(impl StackMatches<N> + StackProgram<N>),
+    registers: &impl RegistersLoad<N>,
+    operands: &[Operand<N>],
+) -> Result<bool> {
+    // Ensure the number of operands is correct.
+    ensure!(operands.len() == 2, "Expected 2 operands, found {} operands", operands.len());
+    // Retrieve the inputs.
+    let input_a = registers.load(stack, &operands[0])?;
+    let input_b = registers.load(stack, &operands[1])?;
+    // Compare the inputs.
+    Ok(is_equal_bits(&input_a.to_bits_le(), &input_b.to_bits_le()))
+}
+
+/// Returns `true` if the given bits are equal.
+/// The comparison runs in time that is independent of the bits, but not of their lengths.
+fn is_equal_bits(a: &[bool], b: &[bool]) -> bool {
+    // Note: The bits encode the types of the values, which are public, so their lengths may be compared directly.
+    if a.len() != b.len() {
+        return false;
+    }
+    // Accumulate the difference of the bits, without short-circuiting.
+    let difference = a.iter().zip(b).fold(0u8, |difference, (a, b)| difference | (u8::from(*a) ^ u8::from(*b)));
+    // Note: `black_box` prevents the compiler from reintroducing an early exit.
+    core::hint::black_box(difference) == 0
+}
+
+/// Returns `first` if `condition` is `true`, and `second` otherwise.
+/// The selection is branchless in the value of the condition and the operands.
+fn select<N: Network>(condition: &Literal<N>, first: &Literal<N>, second: &Literal<N>) -> Result<Literal<N>> {
+    // Retrieve the condition.
+    let condition = match condition {
+        Literal::Boolean(condition) => **condition,
+        _ => bail!("Instruction 'ternary' expects a boolean condition, found '{}'", condition.to_type()),
+    };
+    // Ensure the operands are of the same type.
+    ensure!(first.variant() == second.variant(), "Instruction 'ternary' expects operands of the same type");
+    // Ensure the type can be reconstructed from its bits in constant time.
+    // Note: Reconstructing an address, group, or signature decompresses a point, which is not yet constant time.
+    ensure!(
+        !matches!(first, Literal::Address(..) | Literal::Group(..) | Literal::Signature(..) | Literal::String(..)),
+        "Instruction 'ternary' cannot yet select a '{}' in constant time",
+        first.to_type()
+    );
+
+    // Compute the selection mask.
+    let mask = core::hint::black_box(condition);
+    // Select each bit of the output.
+    let bits_le = first
+        .to_bits_le()
+        .into_iter()
+        .zip(second.to_bits_le())
+        .map(|(first, second)| (mask & first) | (!mask & second))
+        .collect::<Vec<_>>();
+    // Reconstruct the output.
+    Literal::from_bits_le(first.variant(), &bits_le)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_is_equal_bits() {
+        assert!(is_equal_bits(&[], &[]));
+        assert!(is_equal_bits(&[true, false, true], &[true, false, true]));
+        assert!(!is_equal_bits(&[true, false, true], &[true, false, false]));
+        assert!(!is_equal_bits(&[true, false, true], &[true, false]));
+    }
+
+    #[test]
+    fn test_select() {
+        let first = Literal::<CurrentNetwork>::from_str("5u64").unwrap();
+        let second = Literal::<CurrentNetwork>::from_str("7u64").unwrap();
+        let yes = Literal::from_str("true").unwrap();
+        let no = Literal::from_str("false").unwrap();
+
+        // Ensure the correct operand is selected.
+        assert_eq!(select(&yes, &first, &second).unwrap(), first);
+        assert_eq!(select(&no, &first, &second).unwrap(), second);
+        // Ensure the operands must be of the same type.
+        assert!(select(&yes, &first, &Literal::from_str("7u32").unwrap()).is_err());
+        // Ensure the condition must be a boolean.
+        assert!(select(&first, &first, &second).is_err());
+        // Ensure the unsupported types are rejected.
+        let group = Literal::from_str("0group").unwrap();
+        assert!(select(&yes, &group, &group).is_err());
+    }
+
+    #[test]
+    fn test_non_constant_time_opcodes() {
+        let opcodes = Instruction::<CurrentNetwork>::non_constant_time_opcodes();
+        // Ensure the constant-time opcodes are excluded.
+        assert!(!opcodes.contains(&IsEq::<CurrentNetwork>::opcode()));
+        assert!(!opcodes.contains(&Ternary::<CurrentNetwork>::opcode()));
+        // Ensure the other opcodes are included.
+        assert!(opcodes.contains(&Add::<CurrentNetwork>::opcode()));
+        assert!(opcodes.contains(&SignVerify::<CurrentNetwork>::opcode()));
+        assert_eq!(
+            opcodes.len(),
+            Instruction::<CurrentNetwork>::OPCODES.len() - Instruction::<CurrentNetwork>::CONSTANT_TIME_OPCODES.len()
+        );
+    }
+}
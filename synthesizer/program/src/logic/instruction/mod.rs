@@ -22,6 +22,7 @@ mod operation;
 pub use operation::*;
 
 mod bytes;
+mod constant_time;
 mod parse;
 
 use crate::traits::{
@@ -83,6 +84,10 @@ pub enum Instruction<N: Network> {
     Async(Async<N>),
     /// Calls a closure or function on the operands.
     Call(Call<N>),
+    /// Calls an external function in read-only mode on the operands, without a transition of its own.
+    CallView(CallView<N>),
+    /// Calls a host function, registered by the embedder of the process, on the operands.
+    CallHost(CallHost<N>),
     /// Casts the operands into the declared type.
     Cast(Cast<N>),
     /// Casts the operands into the declared type, with lossy truncation if applicable.
@@ -300,6 +305,9 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            // Note: New instructions are appended to the end, to preserve the opcode indices of the existing instructions.
+            CallView,
+            CallHost,
         }}
     };
     // A variant **without** curly braces:
@@ -385,6 +393,20 @@ impl<N: Network> InstructionTrait<N> for Instruction<N> {
         // Check if the given name matches any opcode (in its entirety; including past the first '.' if it exists).
         Instruction::<N>::OPCODES.iter().any(|opcode| **opcode == name)
     }
+
+    /// Returns `true` if the instruction neither produces records nor futures, nor calls an external program.
+    /// Note: A call to a local resource is pure, as local functions cannot be called, and closures cannot output records.
+    /// A `call.view` is pure, as it may only invoke pure functions, without a transition of their own.
+    /// A `call.host` is pure, as host functions may only input and output plaintexts.
+    #[inline]
+    fn is_pure(&self) -> bool {
+        match self {
+            Self::Async(..) => false,
+            Self::Call(call) => matches!(call.operator(), CallOperator::Resource(..)),
+            Self::Cast(cast) => !matches!(cast.cast_type(), CastType::Record(..) | CastType::ExternalRecord(..)),
+            _ => true,
+        }
+    }
 }
 
 impl<N: Network> Instruction<N> {
@@ -403,6 +425,16 @@ impl<N: Network> Instruction<N> {
         instruction!(self, |instruction| instruction.operands())
     }
 
+    /// Returns the instruction, with its operands and destination registers replaced by the given mappings.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: impl FnMut(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl FnMut(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        instruction!(self, |instruction| Ok(instruction.map_registers(operand, destination)?.into()))
+    }
+
     /// Evaluates the instruction.
     #[inline]
     pub fn evaluate(
@@ -469,7 +501,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            70,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
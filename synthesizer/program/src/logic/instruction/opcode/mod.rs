@@ -23,6 +23,10 @@ pub enum Opcode {
     Async,
     /// The opcode is for a call operation (i.e. `call`).
     Call,
+    /// The opcode is for a read-only call operation (i.e. `call.view`).
+    CallView,
+    /// The opcode is for a host function call operation (i.e. `call.host`).
+    CallHost,
     /// The opcode is for a cast operation (i.e. `cast`).
     Cast(&'static str),
     /// The opcode is for a finalize command (i.e. `increment`).
@@ -48,6 +52,8 @@ impl Deref for Opcode {
             Opcode::Assert(opcode) => opcode,
             Opcode::Async => &"async",
             Opcode::Call => &"call",
+            Opcode::CallView => &"call.view",
+            Opcode::CallHost => &"call.host",
             Opcode::Cast(opcode) => opcode,
             Opcode::Command(opcode) => opcode,
             Opcode::Commit(opcode) => opcode,
@@ -73,6 +79,8 @@ impl Display for Opcode {
             Self::Assert(opcode) => write!(f, "{opcode}"),
             Self::Async => write!(f, "{}", self.deref()),
             Self::Call => write!(f, "{}", self.deref()),
+            Self::CallView => write!(f, "{}", self.deref()),
+            Self::CallHost => write!(f, "{}", self.deref()),
             Self::Cast(opcode) => write!(f, "{opcode}"),
             Self::Command(opcode) => write!(f, "{opcode}"),
             Self::Commit(opcode) => write!(f, "{opcode}"),
@@ -73,6 +73,17 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![]
     }
+
+    /// Returns the instruction, with its operands replaced by the given mapping.
+    /// Note: This instruction has no destination registers, so `_destination` is never invoked.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: impl FnMut(&Operand<N>) -> Result<Operand<N>>,
+        _destination: impl FnMut(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self { operands: self.operands.iter().map(operand).collect::<Result<_>>()? })
+    }
 }
 
 impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
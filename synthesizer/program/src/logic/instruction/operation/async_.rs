@@ -66,6 +66,20 @@ impl<N: Network> Async<N> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the instruction, with its operands and destination register replaced by the given mappings.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: impl FnMut(&Operand<N>) -> Result<Operand<N>>,
+        mut destination: impl FnMut(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destination: destination(&self.destination)?,
+            ..self.clone()
+        })
+    }
 }
 
 impl<N: Network> Async<N> {
@@ -144,6 +144,20 @@ impl<N: Network> Call<N> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         self.destinations.clone()
     }
+
+    /// Returns the instruction, with its operands and destination registers replaced by the given mappings.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: impl FnMut(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl FnMut(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operator: self.operator.clone(),
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destinations: self.destinations.iter().map(destination).collect::<Result<_>>()?,
+        })
+    }
 }
 
 impl<N: Network> Call<N> {
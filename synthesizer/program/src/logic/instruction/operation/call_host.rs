@@ -0,0 +1,531 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, PlaintextType, Register, RegisterType, Value},
+};
+
+use indexmap::IndexMap;
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+/// The evaluator of a host function, on console values.
+type HostEvaluator<N> = dyn Fn(&[Value<N>]) -> Result<Vec<Value<N>>> + Send + Sync;
+/// The synthesizer of a host function, on circuit values.
+type HostSynthesizer<A> = dyn Fn(&[circuit::Value<A>]) -> Result<Vec<circuit::Value<A>>> + Send + Sync;
+
+/// A function that is provided by the embedder of the process, and is invoked with `call.host`.
+///
+/// A host function has a name, a signature of plaintext types, an evaluator on console values,
+/// and a synthesizer on circuit values for each circuit environment it may be executed in.
+/// The embedder is responsible for the synthesizer enforcing the same relation as the evaluator,
+/// and for every node of the network registering the same host functions.
+#[derive(Clone)]
+pub struct HostFunction<N: Network> {
+    /// The name of the host function.
+    name: Identifier<N>,
+    /// The input types of the host function.
+    input_types: Vec<PlaintextType<N>>,
+    /// The output types of the host function.
+    output_types: Vec<PlaintextType<N>>,
+    /// The evaluator of the host function.
+    evaluator: Arc<HostEvaluator<N>>,
+    /// The synthesizers of the host function, for each circuit environment.
+    synthesizers: IndexMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl<N: Network> HostFunction<N> {
+    /// Initializes a new host function, with the given name, signature, and evaluator.
+    pub fn new(
+        name: Identifier<N>,
+        input_types: Vec<PlaintextType<N>>,
+        output_types: Vec<PlaintextType<N>>,
+        evaluator: impl Fn(&[Value<N>]) -> Result<Vec<Value<N>>> + Send + Sync + 'static,
+    ) -> Result<Self> {
+        // Ensure the number of inputs and outputs is within the bounds.
+        ensure!(input_types.len() <= N::MAX_OPERANDS, "Host function '{name}' has too many inputs");
+        ensure!(output_types.len() <= N::MAX_OPERANDS, "Host function '{name}' has too many outputs");
+        Ok(Self { name, input_types, output_types, evaluator: Arc::new(evaluator), synthesizers: IndexMap::new() })
+    }
+
+    /// Sets the synthesizer of the host function, for the given circuit environment.
+    pub fn with_synthesizer<A: circuit::Aleo<Network = N>>(
+        mut self,
+        synthesizer: impl Fn(&[circuit::Value<A>]) -> Result<Vec<circuit::Value<A>>> + Send + Sync + 'static,
+    ) -> Self {
+        let synthesizer: Arc<HostSynthesizer<A>> = Arc::new(synthesizer);
+        self.synthesizers.insert(TypeId::of::<A>(), Arc::new(synthesizer));
+        self
+    }
+
+    /// Returns the name of the host function.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the input types of the host function.
+    pub fn input_types(&self) -> &[PlaintextType<N>] {
+        &self.input_types
+    }
+
+    /// Returns the output types of the host function.
+    pub fn output_types(&self) -> &[PlaintextType<N>] {
+        &self.output_types
+    }
+
+    /// Evaluates the host function on the given inputs.
+    pub fn evaluate(&self, inputs: &[Value<N>]) -> Result<Vec<Value<N>>> {
+        // Ensure the number of inputs matches the signature.
+        ensure!(
+            inputs.len() == self.input_types.len(),
+            "Host function '{}' expects {} inputs",
+            self.name,
+            self.input_types.len()
+        );
+        // Evaluate the host function.
+        let outputs = (self.evaluator)(inputs)?;
+        // Ensure the number of outputs matches the signature.
+        ensure!(
+            outputs.len() == self.output_types.len(),
+            "Host function '{}' returned {} outputs",
+            self.name,
+            outputs.len()
+        );
+        Ok(outputs)
+    }
+
+    /// Synthesizes the host function on the given inputs, in the given circuit environment.
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        inputs: &[circuit::Value<A>],
+    ) -> Result<Vec<circuit::Value<A>>> {
+        // Retrieve the synthesizer for the circuit environment.
+        let Some(synthesizer) = self
+            .synthesizers
+            .get(&TypeId::of::<A>())
+            .and_then(|synthesizer| synthesizer.downcast_ref::<Arc<HostSynthesizer<A>>>())
+        else {
+            bail!("Host function '{}' has no synthesizer for this circuit environment", self.name)
+        };
+        // Ensure the number of inputs matches the signature.
+        ensure!(
+            inputs.len() == self.input_types.len(),
+            "Host function '{}' expects {} inputs",
+            self.name,
+            self.input_types.len()
+        );
+        // Synthesize the host function.
+        let outputs = synthesizer(inputs)?;
+        // Ensure the number of outputs matches the signature.
+        ensure!(
+            outputs.len() == self.output_types.len(),
+            "Host function '{}' returned {} outputs",
+            self.name,
+            outputs.len()
+        );
+        Ok(outputs)
+    }
+}
+
+/// Calls a host function on the operands, storing the outcomes in the destination registers.
+/// i.e. `call.host poseidon_xl r0 r1 into r2;`
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CallHost<N: Network> {
+    /// The name of the host function.
+    name: Identifier<N>,
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination registers.
+    destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> CallHost<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::CallHost
+    }
+
+    /// Returns the name of the host function.
+    #[inline]
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the destination registers.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        self.destinations.clone()
+    }
+
+    /// Returns the instruction, with its operands and destination registers replaced by the given mappings.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: impl FnMut(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl FnMut(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            name: self.name,
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destinations: self.destinations.iter().map(destination).collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl<N: Network> CallHost<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Retrieve the host function.
+        let host_function = stack.get_host_function(&self.name)?;
+        // Load the operands values.
+        let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+        // Evaluate the host function.
+        let outputs = host_function.evaluate(&inputs)?;
+        // Ensure the number of outputs matches the number of destinations.
+        ensure!(
+            outputs.len() == self.destinations.len(),
+            "Expected {} outputs, found {}",
+            self.destinations.len(),
+            outputs.len()
+        );
+        // Store the outputs.
+        for (output, destination) in outputs.into_iter().zip_eq(&self.destinations) {
+            registers.store(stack, destination, output)?;
+        }
+        Ok(())
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Retrieve the host function.
+        let host_function = stack.get_host_function(&self.name)?;
+        // Load the operands values.
+        let inputs: Vec<_> =
+            self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+        // Synthesize the host function.
+        let outputs = host_function.execute::<A>(&inputs)?;
+        // Ensure the number of outputs matches the number of destinations.
+        ensure!(
+            outputs.len() == self.destinations.len(),
+            "Expected {} outputs, found {}",
+            self.destinations.len(),
+            outputs.len()
+        );
+        // Store the outputs.
+        for (output, destination) in outputs.into_iter().zip_eq(&self.destinations) {
+            registers.store_circuit(stack, destination, output)?;
+        }
+        Ok(())
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke a 'call.host'.")
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Retrieve the host function.
+        let host_function = stack.get_host_function(&self.name)?;
+
+        // Ensure the number of operands matches the signature.
+        if host_function.input_types().len() != self.operands.len() {
+            bail!("Expected {} inputs, found {}", host_function.input_types().len(), self.operands.len())
+        }
+        // Ensure the number of input types matches the signature.
+        if host_function.input_types().len() != input_types.len() {
+            bail!("Expected {} input types, found {}", host_function.input_types().len(), input_types.len())
+        }
+        // Ensure the input types match the signature.
+        for (expected, input_type) in host_function.input_types().iter().zip_eq(input_types) {
+            if &RegisterType::Plaintext(expected.clone()) != input_type {
+                bail!("Expected an input of type '{expected}', found '{input_type}'")
+            }
+        }
+        // Ensure the number of destinations matches the signature.
+        if host_function.output_types().len() != self.destinations.len() {
+            bail!("Expected {} outputs, found {}", host_function.output_types().len(), self.destinations.len())
+        }
+        // Return the output register types.
+        Ok(host_function.output_types().iter().cloned().map(RegisterType::Plaintext).collect())
+    }
+}
+
+impl<N: Network> Parser for CallHost<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the name of the host function from the string.
+        let (string, name) = Identifier::parse(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = map_res(many0(complete(parse_operand)), |operands: Vec<Operand<N>>| {
+            // Ensure the number of operands is within the bounds.
+            match operands.len() <= N::MAX_OPERANDS {
+                true => Ok(operands),
+                false => Err(error("Failed to parse 'call.host' opcode: too many operands")),
+            }
+        })(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Optionally parse the "into" from the string.
+        let (string, destinations) = match opt(tag("into"))(string)? {
+            // If the "into" was not parsed, return the string and an empty vector of destinations.
+            (string, None) => (string, vec![]),
+            // If the "into" was parsed, parse the destinations from the string.
+            (string, Some(_)) => {
+                // Parse the destinations from the string.
+                map_res(many1(complete(parse_destination)), |destinations: Vec<Register<N>>| {
+                    // Ensure the number of destinations is within the bounds.
+                    match destinations.len() <= N::MAX_OPERANDS {
+                        true => Ok(destinations),
+                        false => Err(error("Failed to parse 'call.host' opcode: too many destinations")),
+                    }
+                })(string)?
+            }
+        };
+
+        Ok((string, Self { name, operands, destinations }))
+    }
+}
+
+impl<N: Network> FromStr for CallHost<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for CallHost<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for CallHost<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {}", Self::opcode(), self.name)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        if !self.destinations.is_empty() {
+            write!(f, " into")?;
+            self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for CallHost<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the name of the host function.
+        let name = Identifier::read_le(&mut reader)?;
+
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of operands is within the bounds.
+        if num_operands > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_OPERANDS)));
+        }
+        // Read the operands.
+        let operands = (0..num_operands).map(|_| Operand::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Read the number of destination registers.
+        let num_destinations = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of destinations is within the bounds.
+        if num_destinations > N::MAX_OPERANDS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OPERANDS)));
+        }
+        // Read the destination registers.
+        let destinations =
+            (0..num_destinations).map(|_| Register::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Return the operation.
+        Ok(Self { name, operands, destinations })
+    }
+}
+
+impl<N: Network> ToBytes for CallHost<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_OPERANDS)));
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Write the name of the host function.
+        self.name.write_le(&mut writer)?;
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the number of destination register.
+        u8::try_from(self.destinations.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the destination registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::Literal, types::U64};
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    const TEST_CASES: &[&str] = &[
+        "call.host random",
+        "call.host random into r0",
+        "call.host double r0 into r1",
+        "call.host mix r0.owner 1u64 into r1 r2",
+    ];
+
+    #[test]
+    fn test_parse() {
+        let call = CallHost::<CurrentNetwork>::from_str("call.host mix r0 1u64 into r1 r2").unwrap();
+        assert_eq!(call.name(), &Identifier::from_str("mix").unwrap());
+        assert_eq!(call.operands(), &[Operand::from_str("r0").unwrap(), Operand::from_str("1u64").unwrap()]);
+        assert_eq!(call.destinations(), vec![Register::Locator(1), Register::Locator(2)]);
+
+        // Ensure a locator is rejected.
+        assert!(CallHost::<CurrentNetwork>::from_str("call.host token.aleo/mix r0 into r1").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for expected in TEST_CASES {
+            assert_eq!(CallHost::<CurrentNetwork>::from_str(expected).unwrap().to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        for case in TEST_CASES {
+            let expected = CallHost::<CurrentNetwork>::from_str(case).unwrap();
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, CallHost::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_host_function() {
+        let u64_type = PlaintextType::from_str("u64").unwrap();
+        let host_function = HostFunction::<CurrentNetwork>::new(
+            Identifier::from_str("double").unwrap(),
+            vec![u64_type.clone()],
+            vec![u64_type],
+            |inputs| match &inputs[0] {
+                Value::Plaintext(plaintext) => match plaintext {
+                    console::program::Plaintext::Literal(Literal::U64(value), _) => {
+                        Ok(vec![Value::from(Literal::U64(U64::new(**value * 2)))])
+                    }
+                    _ => bail!("Expected a u64"),
+                },
+                _ => bail!("Expected a plaintext"),
+            },
+        )
+        .unwrap();
+
+        // Ensure the host function is evaluated.
+        let input = Value::from(Literal::U64(U64::new(21)));
+        assert_eq!(host_function.evaluate(&[input.clone()]).unwrap(), vec![Value::from(Literal::U64(U64::new(42)))]);
+        // Ensure the number of inputs is checked.
+        assert!(host_function.evaluate(&[input.clone(), input]).is_err());
+        // Ensure the host function cannot be executed without a synthesizer.
+        assert!(host_function.execute::<CurrentAleo>(&[]).is_err());
+    }
+}
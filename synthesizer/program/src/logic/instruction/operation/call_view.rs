@@ -0,0 +1,414 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    closure::{input::Input, output::Output},
+    traits::{RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram},
+    Closure,
+    Function,
+    Instruction,
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Locator, Register, RegisterType},
+};
+
+/// Calls an external function in read-only mode, i.e. without a transition of its own.
+/// The function must be pure, and must not read the signer or caller, nor call other functions or closures,
+/// so that its outputs only depend on its inputs.
+/// i.e. `call.view token.aleo/balance_of r0 r1 into r2;`
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CallView<N: Network> {
+    /// The locator of the external function.
+    locator: Locator<N>,
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination registers.
+    destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> CallView<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::CallView
+    }
+
+    /// Returns the locator of the external function.
+    #[inline]
+    pub const fn locator(&self) -> &Locator<N> {
+        &self.locator
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the destination registers.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        self.destinations.clone()
+    }
+
+    /// Returns the instruction, with its operands and destination registers replaced by the given mappings.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: impl FnMut(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl FnMut(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            locator: self.locator,
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destinations: self.destinations.iter().map(destination).collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl<N: Network> CallView<N> {
+    /// Returns the given function as a closure with the same inputs, instructions, and outputs,
+    /// so that it may be evaluated and executed in the circuit of the caller.
+    ///
+    /// # Errors
+    /// This method will halt if the function is not pure (see `Function::is_pure`).
+    /// This method will halt if the function reads the signer or caller.
+    /// This method will halt if the function calls a closure or function.
+    pub fn to_closure(function: &Function<N>) -> Result<Closure<N>> {
+        let name = function.name();
+        // Ensure the function is pure.
+        ensure!(function.is_pure(), "Cannot view '{name}', as it is not pure");
+
+        let mut closure = Closure::new(*name);
+        // Add the inputs, as plaintexts.
+        for input in function.inputs() {
+            closure.add_input(Input::new(input.register().clone(), RegisterType::from(input.value_type().clone())))?;
+        }
+        // Add the instructions.
+        for instruction in function.instructions() {
+            // Ensure the instruction does not call a closure or function.
+            if matches!(instruction, Instruction::Call(..) | Instruction::CallView(..)) {
+                bail!("Cannot view '{name}', as instruction '{instruction}' is a call")
+            }
+            // Ensure the instruction does not read the signer or caller.
+            if let Some(operand) =
+                instruction.operands().iter().find(|operand| matches!(operand, Operand::Signer | Operand::Caller))
+            {
+                bail!("Cannot view '{name}', as instruction '{instruction}' reads '{operand}'")
+            }
+            closure.add_instruction(instruction.clone())?;
+        }
+        // Add the outputs, as plaintexts.
+        for output in function.outputs() {
+            // Ensure the output does not read the signer or caller.
+            if matches!(output.operand(), Operand::Signer | Operand::Caller) {
+                bail!("Cannot view '{name}', as it outputs '{}'", output.operand())
+            }
+            closure
+                .add_output(Output::new(output.operand().clone(), RegisterType::from(output.value_type().clone())))?;
+        }
+        Ok(closure)
+    }
+
+    /// Evaluates the instruction.
+    pub fn evaluate(&self, _stack: &impl StackProgram<N>, _registers: &mut impl RegistersLoad<N>) -> Result<()> {
+        bail!("Forbidden operation: Evaluate cannot invoke a 'call.view' directly. Use 'call.view' in 'Stack' instead.")
+    }
+
+    /// Executes the instruction.
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &impl StackProgram<N>,
+        _registers: &mut impl RegistersLoadCircuit<N, A>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Execute cannot invoke a 'call.view' directly. Use 'call.view' in 'Stack' instead.")
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut impl RegistersLoad<N>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke a 'call.view'.")
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Retrieve the external function, as a closure.
+        let program = stack.get_external_program(self.locator.program_id())?;
+        let closure = Self::to_closure(program.get_function_ref(self.locator.resource())?)?;
+
+        // Ensure the number of operands matches the number of input statements.
+        if closure.inputs().len() != self.operands.len() {
+            bail!("Expected {} inputs, found {}", closure.inputs().len(), self.operands.len())
+        }
+        // Ensure the number of inputs matches the number of input statements.
+        if closure.inputs().len() != input_types.len() {
+            bail!("Expected {} input types, found {}", closure.inputs().len(), input_types.len())
+        }
+        // Ensure the input types match the input statements.
+        for (input, input_type) in closure.inputs().iter().zip_eq(input_types) {
+            if input.register_type() != input_type {
+                bail!("Expected an input of type '{}', found '{input_type}'", input.register_type())
+            }
+        }
+        // Ensure the number of destinations matches the number of output statements.
+        if closure.outputs().len() != self.destinations.len() {
+            bail!("Expected {} outputs, found {}", closure.outputs().len(), self.destinations.len())
+        }
+        // Return the output register types.
+        Ok(closure.outputs().iter().map(|output| output.register_type()).cloned().collect())
+    }
+}
+
+impl<N: Network> Parser for CallView<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the locator of the external function from the string.
+        let (string, locator) = Locator::parse(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = map_res(many0(complete(parse_operand)), |operands: Vec<Operand<N>>| {
+            // Ensure the number of operands is within the bounds.
+            match operands.len() <= N::MAX_OPERANDS {
+                true => Ok(operands),
+                false => Err(error("Failed to parse 'call.view' opcode: too many operands")),
+            }
+        })(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Optionally parse the "into" from the string.
+        let (string, destinations) = match opt(tag("into"))(string)? {
+            // If the "into" was not parsed, return the string and an empty vector of destinations.
+            (string, None) => (string, vec![]),
+            // If the "into" was parsed, parse the destinations from the string.
+            (string, Some(_)) => {
+                // Parse the destinations from the string.
+                map_res(many1(complete(parse_destination)), |destinations: Vec<Register<N>>| {
+                    // Ensure the number of destinations is within the bounds.
+                    match destinations.len() <= N::MAX_OPERANDS {
+                        true => Ok(destinations),
+                        false => Err(error("Failed to parse 'call.view' opcode: too many destinations")),
+                    }
+                })(string)?
+            }
+        };
+
+        Ok((string, Self { locator, operands, destinations }))
+    }
+}
+
+impl<N: Network> FromStr for CallView<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for CallView<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for CallView<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {}", Self::opcode(), self.locator)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        if !self.destinations.is_empty() {
+            write!(f, " into")?;
+            self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for CallView<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the locator of the external function.
+        let locator = Locator::read_le(&mut reader)?;
+
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of operands is within the bounds.
+        if num_operands > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_OPERANDS)));
+        }
+        // Read the operands.
+        let operands = (0..num_operands).map(|_| Operand::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Read the number of destination registers.
+        let num_destinations = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of destinations is within the bounds.
+        if num_destinations > N::MAX_OPERANDS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OPERANDS)));
+        }
+        // Read the destination registers.
+        let destinations =
+            (0..num_destinations).map(|_| Register::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Return the operation.
+        Ok(Self { locator, operands, destinations })
+    }
+}
+
+impl<N: Network> ToBytes for CallView<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_OPERANDS)));
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Write the locator of the external function.
+        self.locator.write_le(&mut writer)?;
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the number of destination register.
+        u8::try_from(self.destinations.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the destination registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const TEST_CASES: &[&str] = &[
+        "call.view token.aleo/total_supply",
+        "call.view token.aleo/total_supply into r0",
+        "call.view token.aleo/balance_of r0 into r1",
+        "call.view token.aleo/balance_of r0.owner 1u64 into r1 r2",
+    ];
+
+    #[test]
+    fn test_parse() {
+        let call = CallView::<CurrentNetwork>::from_str("call.view token.aleo/balance_of r0 1u64 into r1 r2").unwrap();
+        assert_eq!(call.locator(), &Locator::from_str("token.aleo/balance_of").unwrap());
+        assert_eq!(call.operands(), &[Operand::from_str("r0").unwrap(), Operand::from_str("1u64").unwrap()]);
+        assert_eq!(call.destinations(), vec![Register::Locator(1), Register::Locator(2)]);
+
+        // Ensure a local resource is rejected.
+        assert!(CallView::<CurrentNetwork>::from_str("call.view balance_of r0 into r1").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for expected in TEST_CASES {
+            assert_eq!(CallView::<CurrentNetwork>::from_str(expected).unwrap().to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        for case in TEST_CASES {
+            let expected = CallView::<CurrentNetwork>::from_str(case).unwrap();
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, CallView::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_to_closure() {
+        // Ensure a pure function is converted into a closure.
+        let function = Function::<CurrentNetwork>::from_str(
+            "function double:\n    input r0 as u64.public;\n    add r0 r0 into r1;\n    output r1 as u64.private;",
+        )
+        .unwrap();
+        let closure = CallView::to_closure(&function).unwrap();
+        assert_eq!(closure.name(), function.name());
+        assert_eq!(closure.instructions(), function.instructions());
+        assert_eq!(closure.outputs()[0].register_type(), &RegisterType::from_str("u64").unwrap());
+
+        // Ensure a function that reads the signer cannot be viewed.
+        let function = Function::<CurrentNetwork>::from_str(
+            "function owner:\n    input r0 as u64.public;\n    is.eq self.signer self.caller into r1;\n    output r1 as boolean.public;",
+        )
+        .unwrap();
+        assert!(CallView::to_closure(&function).is_err());
+
+        // Ensure a function that calls a closure cannot be viewed.
+        let function = Function::<CurrentNetwork>::from_str(
+            "function relay:\n    input r0 as u64.public;\n    call double r0 into r1;\n    output r1 as u64.public;",
+        )
+        .unwrap();
+        assert!(CallView::to_closure(&function).is_err());
+    }
+}
@@ -191,6 +191,20 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
         vec![self.destination.clone()]
     }
 
+    /// Returns the instruction, with its operands and destination register replaced by the given mappings.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: impl FnMut(&Operand<N>) -> Result<Operand<N>>,
+        mut destination: impl FnMut(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destination: destination(&self.destination)?,
+            ..self.clone()
+        })
+    }
+
     /// Returns the cast type.
     #[inline]
     pub const fn cast_type(&self) -> &CastType<N> {
@@ -262,10 +276,14 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 let record_type = stack.program().get_record(record_name)?;
 
                 // Ensure that the number of operands is equal to the number of record entries, including the `owner`.
-                if inputs.len() != record_type.entries().len() + 1 {
+                // Note: The optional entries of the record may be omitted, in which case their default values are used.
+                if inputs.len() < record_type.num_required_entries() + 1
+                    || inputs.len() > record_type.entries().len() + 1
+                {
                     bail!(
-                        "Casting to the record {} requires {} operands, but {} were provided",
+                        "Casting to the record {} requires {} to {} operands, but {} were provided",
                         record_type.name(),
+                        record_type.num_required_entries() + 1,
                         record_type.entries().len() + 1,
                         inputs.len()
                     )
@@ -285,23 +303,28 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
 
                 // Initialize the record entries.
                 let mut entries = IndexMap::new();
-                for (entry, (entry_name, entry_type)) in
-                    inputs.iter().skip(N::MIN_RECORD_ENTRIES).zip_eq(record_type.entries())
-                {
+                for (i, (entry_name, entry_type)) in record_type.entries().iter().enumerate() {
                     // Compute the plaintext type.
                     let plaintext_type = entry_type.plaintext_type();
                     // Retrieve the plaintext value from the entry.
-                    let plaintext = match entry {
-                        Value::Plaintext(plaintext) => {
+                    let plaintext = match inputs.get(N::MIN_RECORD_ENTRIES + i) {
+                        Some(Value::Plaintext(plaintext)) => {
                             // Ensure the entry matches the register type.
                             stack.matches_plaintext(plaintext, plaintext_type)?;
                             // Output the plaintext.
                             plaintext.clone()
                         }
                         // Ensure the record entry is not a record.
-                        Value::Record(..) => bail!("Casting a record into a record entry is illegal"),
+                        Some(Value::Record(..)) => bail!("Casting a record into a record entry is illegal"),
                         // Ensure the record entry is not a future.
-                        Value::Future(..) => bail!("Casting a future into a record entry is illegal"),
+                        Some(Value::Future(..)) => bail!("Casting a future into a record entry is illegal"),
+                        // If the optional entry is omitted, use its default value.
+                        None => match record_type.default_for(entry_name) {
+                            Some(default) => Plaintext::from(default),
+                            None => {
+                                bail!("Casting to the record {} requires the entry '{entry_name}'", record_type.name())
+                            }
+                        },
                     };
                     // Append the entry to the record entries.
                     match entry_type {
@@ -406,7 +429,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 }
 
                 // Retrieve the struct and ensure it is defined in the program.
-                let struct_ = stack.program().get_struct(struct_)?;
+                let struct_ = stack.get_struct(struct_)?;
 
                 // Ensure that the number of operands is equal to the number of struct members.
                 if inputs.len() != struct_.members().len() {
@@ -506,10 +529,14 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 let record_type = stack.program().get_record(record_name)?;
 
                 // Ensure that the number of operands is equal to the number of record entries, including the `owner`.
-                if inputs.len() != record_type.entries().len() + 1 {
+                // Note: The optional entries of the record may be omitted, in which case their default values are used.
+                if inputs.len() < record_type.num_required_entries() + 1
+                    || inputs.len() > record_type.entries().len() + 1
+                {
                     bail!(
-                        "Casting to the record {} requires {} operands, but {} were provided",
+                        "Casting to the record {} requires {} to {} operands, but {} were provided",
                         record_type.name(),
+                        record_type.num_required_entries() + 1,
                         record_type.entries().len() + 1,
                         inputs.len()
                     )
@@ -532,14 +559,12 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
 
                 // Initialize the record entries.
                 let mut entries = IndexMap::new();
-                for (entry, (entry_name, entry_type)) in
-                    inputs.iter().skip(N::MIN_RECORD_ENTRIES).zip_eq(record_type.entries())
-                {
+                for (i, (entry_name, entry_type)) in record_type.entries().iter().enumerate() {
                     // Compute the register type.
                     let register_type = RegisterType::from(ValueType::from(entry_type.clone()));
                     // Retrieve the plaintext value from the entry.
-                    let plaintext = match entry {
-                        circuit::Value::Plaintext(plaintext) => {
+                    let plaintext = match inputs.get(N::MIN_RECORD_ENTRIES + i) {
+                        Some(circuit::Value::Plaintext(plaintext)) => {
                             // Ensure the entry matches the register type.
                             stack.matches_register_type(
                                 &circuit::Value::Plaintext(plaintext.clone()).eject_value(),
@@ -549,9 +574,16 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                             plaintext.clone()
                         }
                         // Ensure the record entry is not a record.
-                        circuit::Value::Record(..) => bail!("Casting a record into a record entry is illegal"),
+                        Some(circuit::Value::Record(..)) => bail!("Casting a record into a record entry is illegal"),
                         // Ensure the record entry is not a future.
-                        circuit::Value::Future(..) => bail!("Casting a future into a record entry is illegal"),
+                        Some(circuit::Value::Future(..)) => bail!("Casting a future into a record entry is illegal"),
+                        // If the optional entry is omitted, use its default value.
+                        None => match record_type.default_for(entry_name) {
+                            Some(default) => circuit::Plaintext::from(circuit::Literal::constant(default.clone())),
+                            None => {
+                                bail!("Casting to the record {} requires the entry '{entry_name}'", record_type.name())
+                            }
+                        },
                     };
                     // Construct the entry name constant circuit.
                     let entry_name = circuit::Identifier::constant(*entry_name);
@@ -682,7 +714,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             }
             CastType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Retrieve the struct and ensure it is defined in the program.
-                let struct_ = stack.program().get_struct(struct_name)?;
+                let struct_ = stack.get_struct(struct_name)?;
 
                 // Ensure the input types length is at least the minimum.
                 if input_types.len() < N::MIN_STRUCT_ENTRIES {
@@ -789,10 +821,13 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 }
 
                 // Ensure that the number of input types is equal to the number of record entries, including the `owner`.
+                // Note: The optional entries of the record may be omitted, in which case their default values are used.
                 ensure!(
-                    input_types.len() == record.entries().len() + 1,
-                    "Casting to the record {} requires {} operands, but {} were provided",
+                    input_types.len() >= record.num_required_entries() + 1
+                        && input_types.len() <= record.entries().len() + 1,
+                    "Casting to the record {} requires {} to {} operands, but {} were provided",
                     record.name(),
+                    record.num_required_entries() + 1,
                     record.entries().len() + 1,
                     input_types.len()
                 );
@@ -804,7 +839,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
 
                 // Ensure the input types match the record.
                 for (input_type, (_, entry_type)) in
-                    input_types.iter().skip(N::MIN_RECORD_ENTRIES).zip_eq(record.entries())
+                    input_types.iter().skip(N::MIN_RECORD_ENTRIES).zip(record.entries())
                 {
                     match input_type {
                         // Ensure the plaintext type matches the entry type.
@@ -863,7 +898,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
         }
 
         // Retrieve the struct and ensure it is defined in the program.
-        let struct_ = stack.program().get_struct(&struct_name)?;
+        let struct_ = stack.get_struct(&struct_name)?;
 
         // Ensure that the number of operands is equal to the number of struct members.
         if inputs.len() != struct_.members().len() {
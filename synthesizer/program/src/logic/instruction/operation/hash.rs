@@ -188,6 +188,20 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         vec![self.destination.clone()]
     }
 
+    /// Returns the instruction, with its operands and destination register replaced by the given mappings.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: impl FnMut(&Operand<N>) -> Result<Operand<N>>,
+        mut destination: impl FnMut(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destination: destination(&self.destination)?,
+            ..self.clone()
+        })
+    }
+
     /// Returns the destination register type.
     #[inline]
     pub const fn destination_type(&self) -> &PlaintextType<N> {
@@ -45,6 +45,22 @@ pub struct Literals<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPE
 impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const NUM_OPERANDS: usize>
     Literals<N, O, NUM_OPERANDS>
 {
+    /// Initializes a new literals operation with the given operands and destination register.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Ensure the number of operands is within the bounds.
+        ensure!(NUM_OPERANDS <= N::MAX_OPERANDS, "The number of operands must be <= {}", N::MAX_OPERANDS);
+        // Ensure the number of operands is correct.
+        ensure!(
+            operands.len() == NUM_OPERANDS,
+            "Instruction '{}' expects {NUM_OPERANDS} operands, found {} operands",
+            O::OPCODE,
+            operands.len()
+        );
+        // Return the operation.
+        Ok(Self { operands, destination, _phantom: PhantomData })
+    }
+
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
@@ -62,6 +78,20 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the instruction, with its operands and destination register replaced by the given mappings.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: impl FnMut(&Operand<N>) -> Result<Operand<N>>,
+        mut destination: impl FnMut(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destination: destination(&self.destination)?,
+            ..self.clone()
+        })
+    }
 }
 
 impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const NUM_OPERANDS: usize>
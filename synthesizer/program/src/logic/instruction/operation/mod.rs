@@ -21,6 +21,12 @@ pub use async_::*;
 mod call;
 pub use call::*;
 
+mod call_host;
+pub use call_host::*;
+
+mod call_view;
+pub use call_view::*;
+
 mod cast;
 pub use cast::*;
 
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Instruction, Program};
+use console::{network::prelude::*, program::Literal};
+
+use serde_json::Value as JsonValue;
+
+/// The known-answer tests for the instruction set.
+const KNOWN_ANSWERS: &str = include_str!("../resources/known_answers.json");
+
+/// A known-answer test, which pins the output of an instruction on the given inputs.
+///
+/// The instruction reads its inputs from the registers `r0, r1, ...` in order,
+/// and stores its output in the register that follows the last input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KnownAnswer<N: Network> {
+    /// The instruction.
+    instruction: Instruction<N>,
+    /// The inputs to the instruction.
+    inputs: Vec<Literal<N>>,
+    /// The expected output of the instruction.
+    output: Literal<N>,
+}
+
+impl<N: Network> KnownAnswer<N> {
+    /// Returns the known-answer tests for the instruction set.
+    pub fn load() -> Result<Vec<Self>> {
+        // Parse the known-answer tests.
+        let cases: Vec<JsonValue> = serde_json::from_str(KNOWN_ANSWERS)?;
+        // Parse each known-answer test.
+        cases
+            .iter()
+            .map(|case| {
+                // Retrieve the given field as a string.
+                let field = |name: &str| {
+                    case[name].as_str().ok_or_else(|| anyhow!("Known-answer test is missing the '{name}' field"))
+                };
+                // Parse the instruction.
+                let instruction = Instruction::from_str(field("instruction")?)?;
+                // Parse the inputs.
+                let inputs = case["inputs"]
+                    .as_array()
+                    .ok_or_else(|| anyhow!("Known-answer test is missing the 'inputs' field"))?
+                    .iter()
+                    .map(|input| match input.as_str() {
+                        Some(input) => Literal::from_str(input),
+                        None => bail!("Known-answer test has a malformed input"),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                // Parse the output.
+                let output = Literal::from_str(field("output")?)?;
+                // Ensure the instruction reads from the inputs, and writes to the output register.
+                ensure!(
+                    instruction.operands().len() == inputs.len(),
+                    "Known-answer test for '{instruction}' expects {} inputs, found {}",
+                    instruction.operands().len(),
+                    inputs.len()
+                );
+                Ok(Self { instruction, inputs, output })
+            })
+            .collect()
+    }
+
+    /// Returns the instruction.
+    pub const fn instruction(&self) -> &Instruction<N> {
+        &self.instruction
+    }
+
+    /// Returns the inputs to the instruction.
+    pub fn inputs(&self) -> &[Literal<N>] {
+        &self.inputs
+    }
+
+    /// Returns the expected output of the instruction.
+    pub const fn output(&self) -> &Literal<N> {
+        &self.output
+    }
+
+    /// Returns a program with a single function `main`, which takes the inputs as public inputs,
+    /// evaluates the instruction, and returns the output as a public output.
+    pub fn to_program(&self) -> Result<Program<N>> {
+        // Declare the inputs.
+        let inputs = self
+            .inputs
+            .iter()
+            .enumerate()
+            .map(|(index, input)| format!("    input r{index} as {}.public;\n", input.to_type()))
+            .collect::<String>();
+        // Construct the program.
+        Program::from_str(&format!(
+            "program known_answer.aleo;\n\nfunction main:\n{inputs}    {}\n    output r{} as {}.public;\n",
+            self.instruction,
+            self.inputs.len(),
+            self.output.to_type()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Opcode;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_load_known_answers() {
+        let known_answers = KnownAnswer::<CurrentNetwork>::load().unwrap();
+        assert!(!known_answers.is_empty());
+        for known_answer in &known_answers {
+            // Ensure the program for each known-answer test is well-formed.
+            let program = known_answer.to_program().unwrap();
+            assert_eq!(program.functions().len(), 1);
+        }
+        // Ensure every hash and commit variant is covered, except the unimplemented `hash_many` variants.
+        for opcode in Instruction::<CurrentNetwork>::OPCODES {
+            if matches!(opcode, Opcode::Commit(..) | Opcode::Hash(..)) && !opcode.starts_with("hash_many") {
+                assert!(
+                    known_answers.iter().any(|known_answer| known_answer.instruction().opcode() == *opcode),
+                    "Missing a known-answer test for '{opcode}'"
+                );
+            }
+        }
+    }
+}
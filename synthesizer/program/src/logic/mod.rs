@@ -23,3 +23,6 @@ pub use finalize_operation::*;
 
 pub mod instruction;
 pub use instruction::*;
+
+mod known_answers;
+pub use known_answers::*;
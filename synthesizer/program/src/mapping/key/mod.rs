@@ -25,6 +25,12 @@ pub struct MapKey<N: Network> {
 }
 
 impl<N: Network> MapKey<N> {
+    /// Initializes a new key statement with the given plaintext type.
+    #[inline]
+    pub const fn new(plaintext_type: PlaintextType<N>) -> Self {
+        Self { plaintext_type }
+    }
+
     /// Returns the key plaintext type.
     #[inline]
     pub const fn plaintext_type(&self) -> &PlaintextType<N> {
@@ -12,10 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod key;
+pub(crate) mod key;
 use key::*;
 
-mod value;
+pub(crate) mod value;
 use value::*;
 
 mod bytes;
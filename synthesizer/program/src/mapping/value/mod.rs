@@ -25,6 +25,12 @@ pub struct MapValue<N: Network> {
 }
 
 impl<N: Network> MapValue<N> {
+    /// Initializes a new value statement with the given plaintext type.
+    #[inline]
+    pub const fn new(plaintext_type: PlaintextType<N>) -> Self {
+        Self { plaintext_type }
+    }
+
     /// Returns the value plaintext type.
     #[inline]
     pub const fn plaintext_type(&self) -> &PlaintextType<N> {
@@ -24,11 +24,30 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
         enum P<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
             M(Mapping<N>),
             I(StructType<N>),
+            S(Locator<N>),
             R(RecordType<N>),
             C(ClosureCore<N, Instruction>),
             F(FunctionCore<N, Instruction, Command>),
         }
 
+        /// Parses a string into a struct import, of the form `struct {program_id}/{struct_name};`.
+        fn parse_struct_import<N: Network>(string: &str) -> ParserResult<Locator<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the type name from the string.
+            let (string, _) = tag(StructType::<N>::type_name())(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the locator from the string.
+            let (string, locator) = Locator::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the semicolon ';' keyword from the string.
+            let (string, _) = tag(";")(string)?;
+            // Return the locator.
+            Ok((string, locator))
+        }
+
         // Parse the imports from the string.
         let (string, imports) = many0(Import::parse)(string)?;
         // Parse the whitespace and comments from the string.
@@ -47,6 +66,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
         // Parse the struct or function from the string.
         let (string, components) = many1(alt((
             map(Mapping::parse, |mapping| P::<N, Instruction, Command>::M(mapping)),
+            map(parse_struct_import, |locator| P::<N, Instruction, Command>::S(locator)),
             map(StructType::parse, |struct_| P::<N, Instruction, Command>::I(struct_)),
             map(RecordType::parse, |record| P::<N, Instruction, Command>::R(record)),
             map(ClosureCore::parse, |closure| P::<N, Instruction, Command>::C(closure)),
@@ -70,6 +90,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                 let result = match component {
                     P::M(mapping) => program.add_mapping(mapping.clone()),
                     P::I(struct_) => program.add_struct(struct_.clone()),
+                    P::S(locator) => program.add_struct_import(*locator),
                     P::R(record) => program.add_record(record.clone()),
                     P::C(closure) => program.add_closure(closure.clone()),
                     P::F(function) => program.add_function(function.clone()),
@@ -162,6 +183,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
                     Some(struct_) => program.push_str(&format!("{struct_}\n\n")),
                     None => return Err(fmt::Error),
                 },
+                ProgramDefinition::StructImport => match self.struct_imports.get(identifier) {
+                    Some(locator) => program.push_str(&format!("{} {locator};\n\n", StructType::<N>::type_name())),
+                    None => return Err(fmt::Error),
+                },
                 ProgramDefinition::Record => match self.records.get(identifier) {
                     Some(record) => program.push_str(&format!("{record}\n\n")),
                     None => return Err(fmt::Error),
@@ -23,4 +23,6 @@ pub trait InstructionTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     fn destinations(&self) -> Vec<Register<N>>;
     /// Returns `true` if the given name is a reserved opcode.
     fn is_reserved_opcode(name: &str) -> bool;
+    /// Returns `true` if the instruction neither produces records nor futures, nor calls an external program.
+    fn is_pure(&self) -> bool;
 }
@@ -14,7 +14,7 @@
 
 use std::sync::Arc;
 
-use crate::{FinalizeGlobalState, Function, Operand, Program};
+use crate::{FinalizeGlobalState, Function, HostFunction, Operand, Program};
 use console::{
     account::Group,
     network::Network,
@@ -31,6 +31,7 @@ use console::{
         RecordType,
         Register,
         RegisterType,
+        StructType,
         Value,
         ValueType,
     },
@@ -80,6 +81,9 @@ pub trait StackProgram<N: Network> {
     /// Returns `true` if the stack contains the external record.
     fn get_external_record(&self, locator: &Locator<N>) -> Result<&RecordType<N>>;
 
+    /// Returns the struct with the given name, resolving structs imported from other programs.
+    fn get_struct(&self, struct_name: &Identifier<N>) -> Result<&StructType<N>>;
+
     /// Returns the expected finalize cost for the given function name.
     fn get_finalize_cost(&self, function_name: &Identifier<N>) -> Result<u64>;
 
@@ -92,6 +96,12 @@ pub trait StackProgram<N: Network> {
     /// Returns the expected number of calls for the given function name.
     fn get_number_of_calls(&self, function_name: &Identifier<N>) -> Result<usize>;
 
+    /// Returns the host function with the given name.
+    /// By default, no host functions are registered.
+    fn get_host_function(&self, name: &Identifier<N>) -> Result<Arc<HostFunction<N>>> {
+        bail!("Host function '{name}' is not registered")
+    }
+
     /// Samples a value for the given value_type.
     fn sample_value<R: Rng + CryptoRng>(
         &self,
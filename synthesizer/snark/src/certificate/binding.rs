@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The version of the synthesizer that produces the circuits for the certificates.
+/// This version must be incremented whenever a change to circuit synthesis changes the circuit keys.
+pub const SYNTHESIZER_VERSION: u16 = 1;
+
+/// The synthesizer version and parameters that this library produces certificates with.
+///
+/// This is local-only metadata, which is reported when a certificate fails to verify. It is not serialized
+/// with the certificate, as nothing in a deployment commits to it, so it could be altered in transit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SynthesizerBinding {
+    /// The version of the synthesizer.
+    version: u16,
+    /// The ID of the network, whose Varuna parameters were used.
+    network_id: u16,
+}
+
+impl SynthesizerBinding {
+    /// Initializes a new synthesizer binding.
+    pub const fn new(version: u16, network_id: u16) -> Self {
+        Self { version, network_id }
+    }
+
+    /// Returns the synthesizer binding of this library, for the given network.
+    pub const fn current<N: Network>() -> Self {
+        Self::new(SYNTHESIZER_VERSION, N::ID)
+    }
+
+    /// Returns the version of the synthesizer.
+    pub const fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the ID of the network, whose Varuna parameters were used.
+    pub const fn network_id(&self) -> u16 {
+        self.network_id
+    }
+}
+
+impl Display for SynthesizerBinding {
+    /// Displays the synthesizer binding.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "synthesizer v{} (network {})", self.version, self.network_id)
+    }
+}
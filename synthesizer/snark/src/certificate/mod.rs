@@ -14,6 +14,9 @@
 
 use super::*;
 
+mod binding;
+pub use binding::*;
+
 mod bytes;
 mod parse;
 mod serialize;
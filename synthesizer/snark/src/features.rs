@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The proof-system features that a verifying key or proof requires of its verifier.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProofFeatures(u32);
+
+impl ProofFeatures {
+    /// The features of verifying keys and proofs produced before the introduction of feature flags.
+    /// Note: Verifying keys and proofs with exactly these features are serialized without a bitmask.
+    pub const BASELINE: Self = Self(Self::BATCHING_V1.0 | Self::POSEIDON_FIAT_SHAMIR.0);
+    /// The feature for proofs that are batched with Varuna batching version 1.
+    pub const BATCHING_V1: Self = Self(1 << 0);
+    /// The feature for circuits that use lookup arguments.
+    pub const LOOKUPS: Self = Self(1 << 2);
+    /// The names of the known features, in bit order.
+    const NAMES: [(Self, &'static str); 3] =
+        [(Self::BATCHING_V1, "batching-v1"), (Self::POSEIDON_FIAT_SHAMIR, "poseidon-fs"), (Self::LOOKUPS, "lookups")];
+    /// The feature for proofs that are derived with the Poseidon Fiat-Shamir sponge.
+    pub const POSEIDON_FIAT_SHAMIR: Self = Self(1 << 1);
+    /// The features that are supported by this library.
+    pub const SUPPORTED: Self = Self::BASELINE;
+
+    /// Initializes the proof features from the given bitmask.
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the bitmask of the proof features.
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the union of the given proof features.
+    pub const fn union(&self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns `true` if all of the given proof features are set.
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the proof features that are not supported by this library.
+    pub const fn unsupported(&self) -> Self {
+        Self(self.0 & !Self::SUPPORTED.0)
+    }
+
+    /// Ensures all of the proof features are supported by this library.
+    pub fn check_supported(&self) -> Result<()> {
+        let unsupported = self.unsupported();
+        ensure!(unsupported.0 == 0, "Requires unsupported proof-system features ({unsupported})");
+        Ok(())
+    }
+}
+
+impl Default for ProofFeatures {
+    /// Returns the baseline proof features.
+    fn default() -> Self {
+        Self::BASELINE
+    }
+}
+
+impl FromBytes for ProofFeatures {
+    /// Reads the proof features from a buffer.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self(u32::read_le(reader)?))
+    }
+}
+
+impl ToBytes for ProofFeatures {
+    /// Writes the proof features to a buffer.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write_le(writer)
+    }
+}
+
+impl Display for ProofFeatures {
+    /// Displays the proof features as a comma-separated list of names.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Collect the names of the known features.
+        let mut names = Self::NAMES
+            .iter()
+            .filter(|(feature, _)| self.contains(*feature))
+            .map(|(_, name)| name.to_string())
+            .collect::<Vec<_>>();
+        // Collect the bits of the unknown features.
+        let known = Self::NAMES.iter().fold(0, |bits, (feature, _)| bits | feature.0);
+        names.extend((0..u32::BITS).filter(|i| (self.0 & !known) & (1 << i) != 0).map(|i| format!("bit {i}")));
+        write!(f, "{}", names.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_supported() {
+        // Ensure the baseline features are supported.
+        assert!(ProofFeatures::BASELINE.check_supported().is_ok());
+        assert!(ProofFeatures::default().contains(ProofFeatures::BATCHING_V1));
+
+        // Ensure lookups and unknown features are rejected.
+        let features = ProofFeatures::BASELINE.union(ProofFeatures::LOOKUPS);
+        assert_eq!(features.unsupported(), ProofFeatures::LOOKUPS);
+        assert!(features.check_supported().is_err());
+        let features = ProofFeatures::from_bits(ProofFeatures::BASELINE.bits() | (1 << 9));
+        assert_eq!(features.unsupported().to_string(), "bit 9");
+        assert!(features.check_supported().is_err());
+    }
+}
@@ -29,13 +29,16 @@ use colored::Colorize;
 type Varuna<N> = varuna::VarunaSNARK<<N as Environment>::PairingCurve, FiatShamir<N>, varuna::VarunaHidingMode>;
 
 mod certificate;
-pub use certificate::Certificate;
+pub use certificate::{Certificate, SynthesizerBinding, SYNTHESIZER_VERSION};
+
+mod features;
+pub use features::ProofFeatures;
 
 mod proof;
 pub use proof::Proof;
 
 mod proving_key;
-pub use proving_key::ProvingKey;
+pub use proving_key::{ProvingKey, ProvingKeyBundle};
 
 mod universal_srs;
 pub use universal_srs::UniversalSRS;
@@ -161,6 +164,44 @@ mod test {
         assert!(!verifying_key.verify("test", &[one, one + one], &proof));
     }
 
+    #[test]
+    fn test_varuna_verify_unsupported_features() {
+        let (_, verifying_key) = crate::test_helpers::sample_keys();
+        let proof = crate::test_helpers::sample_proof();
+        let one = <Circuit as Environment>::BaseField::one();
+
+        // Ensure the baseline proof verifies.
+        assert!(verifying_key.verify("test", &[one, one], &proof));
+
+        // Ensure a proof that requires unsupported features does not verify.
+        let features = ProofFeatures::BASELINE.union(ProofFeatures::LOOKUPS);
+        assert!(!verifying_key.verify("test", &[one, one], &proof.clone().with_features(features)));
+        // Ensure a verifying key that requires unsupported features does not verify.
+        assert!(!verifying_key.clone().with_features(features).verify("test", &[one, one], &proof));
+        // Ensure the batch verifier reports the unsupported features.
+        let inputs = vec![(verifying_key.with_features(features), vec![vec![one, one]])];
+        let error = VerifyingKey::verify_batch("test", inputs, &proof).unwrap_err();
+        assert!(error.to_string().contains("lookups"));
+    }
+
+    #[test]
+    fn test_varuna_verify_batches() {
+        let (_, verifying_key) = crate::test_helpers::sample_keys();
+        let proof = crate::test_helpers::sample_proof();
+        let one = <Circuit as Environment>::BaseField::one();
+        let rng = &mut TestRng::default();
+
+        // Ensure the proofs verify together.
+        let inputs = vec![(verifying_key.clone(), vec![vec![one, one]])];
+        let batches = vec![(inputs.clone(), &proof), (inputs.clone(), &proof)];
+        assert!(VerifyingKey::verify_batches("test", batches, rng).is_ok());
+
+        // Ensure the proofs do not verify together, if one of them has an incorrect public input.
+        let invalid_inputs = vec![(verifying_key, vec![vec![one, one + one]])];
+        let batches = vec![(inputs, &proof), (invalid_inputs, &proof)];
+        assert!(VerifyingKey::verify_batches("test", batches, rng).is_err());
+    }
+
     #[test]
     fn test_varuna_verify_public_input_size() {
         /// Creates a simple circuit: a * b.
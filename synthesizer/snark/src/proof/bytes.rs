@@ -20,23 +20,43 @@ impl<N: Network> FromBytes for Proof<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid proof version"));
         }
         // Read the proof.
         let proof = FromBytes::read_le(&mut reader)?;
+        // Read the proof features, if the version includes them.
+        let features = match version {
+            1 => ProofFeatures::BASELINE,
+            _ => match ProofFeatures::read_le(&mut reader)? {
+                ProofFeatures::BASELINE => return Err(error("Baseline proof features must use proof version 1")),
+                features => features,
+            },
+        };
         // Return the proof.
-        Ok(Self { proof })
+        Ok(Self { proof, features })
     }
 }
 
 impl<N: Network> ToBytes for Proof<N> {
     /// Writes the proof to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
-        // Write the bytes.
-        self.proof.write_le(&mut writer)
+        match self.features {
+            ProofFeatures::BASELINE => {
+                // Write the version.
+                1u8.write_le(&mut writer)?;
+                // Write the bytes.
+                self.proof.write_le(&mut writer)
+            }
+            features => {
+                // Write the version.
+                2u8.write_le(&mut writer)?;
+                // Write the bytes.
+                self.proof.write_le(&mut writer)?;
+                // Write the proof features.
+                features.write_le(&mut writer)
+            }
+        }
     }
 }
 
@@ -52,6 +72,13 @@ mod tests {
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Proof::read_le(&expected_bytes[..])?);
+        assert_eq!(1, expected_bytes[0]);
+
+        // Check the byte representation of a proof with additional features.
+        let expected = expected.with_features(ProofFeatures::BASELINE.union(ProofFeatures::LOOKUPS));
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Proof::read_le(&expected_bytes[..])?);
+        assert_eq!(2, expected_bytes[0]);
 
         Ok(())
     }
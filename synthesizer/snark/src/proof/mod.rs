@@ -22,12 +22,24 @@ mod serialize;
 pub struct Proof<N: Network> {
     /// The proof.
     proof: varuna::Proof<N::PairingCurve>,
+    /// The proof-system features required to verify the proof.
+    features: ProofFeatures,
 }
 
 impl<N: Network> Proof<N> {
     /// Initializes a new proof.
     pub const fn new(proof: varuna::Proof<N::PairingCurve>) -> Self {
-        Self { proof }
+        Self { proof, features: ProofFeatures::BASELINE }
+    }
+
+    /// Returns the proof with the given required proof-system features.
+    pub fn with_features(self, features: ProofFeatures) -> Self {
+        Self { proof: self.proof, features }
+    }
+
+    /// Returns the proof-system features required to verify the proof.
+    pub const fn features(&self) -> ProofFeatures {
+        self.features
     }
 }
 
@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::{collections::HashMap, marker::PhantomData};
+
+/// The minimum size of a block, in bytes.
+const MIN_BLOCK_SIZE: usize = 4 * 1024;
+/// The maximum size of a block, in bytes.
+const MAX_BLOCK_SIZE: usize = 64 * 1024;
+/// The mask applied to the rolling hash to find a block boundary, for an average block size of 16 KiB.
+const BOUNDARY_MASK: u64 = (1 << 14) - 1;
+
+/// A set of proving keys, stored with their identical blocks shared across keys.
+///
+/// Functions in a program often contain identical sub-circuits (e.g. request verification or record encryption),
+/// which index to identical regions in their proving keys. The bundle splits each serialized proving key into
+/// content-defined blocks, so that identical regions fall into identical blocks regardless of their offsets,
+/// and stores each distinct block once.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProvingKeyBundle<N: Network> {
+    /// The distinct blocks.
+    blocks: Vec<Vec<u8>>,
+    /// The block indices for each proving key, in order.
+    keys: Vec<Vec<u32>>,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> ProvingKeyBundle<N> {
+    /// Initializes a new bundle from the given proving keys.
+    pub fn new<'a>(proving_keys: impl IntoIterator<Item = &'a ProvingKey<N>>) -> Result<Self> {
+        let mut blocks = Vec::new();
+        let mut block_indices = HashMap::new();
+        let mut keys = Vec::new();
+
+        for proving_key in proving_keys {
+            // Serialize the proving key.
+            let bytes = proving_key.to_bytes_le()?;
+            // Split the proving key into blocks, and store each distinct block once.
+            let mut indices = Vec::new();
+            for block in split_into_blocks(&bytes) {
+                let index = match block_indices.get(block) {
+                    Some(index) => *index,
+                    None => {
+                        let index = u32::try_from(blocks.len())?;
+                        block_indices.insert(block, index);
+                        blocks.push(block.to_vec());
+                        index
+                    }
+                };
+                indices.push(index);
+            }
+            keys.push(indices);
+        }
+
+        Ok(Self { blocks, keys, _phantom: PhantomData })
+    }
+
+    /// Returns the number of proving keys in the bundle.
+    pub fn num_keys(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns the number of distinct blocks in the bundle.
+    pub fn num_blocks(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Returns the total size of the distinct blocks, in bytes.
+    pub fn num_block_bytes(&self) -> usize {
+        self.blocks.iter().map(Vec::len).sum()
+    }
+
+    /// Returns the proving key at the given index.
+    pub fn get(&self, index: usize) -> Result<ProvingKey<N>> {
+        // Retrieve the block indices.
+        let indices =
+            self.keys.get(index).ok_or_else(|| anyhow!("Proving key {index} does not exist in the bundle"))?;
+        // Reassemble the proving key.
+        let mut bytes = Vec::new();
+        for block_index in indices {
+            match self.blocks.get(*block_index as usize) {
+                Some(block) => bytes.extend_from_slice(block),
+                None => bail!("Block {block_index} does not exist in the bundle"),
+            }
+        }
+        ProvingKey::from_bytes_le(&bytes)
+    }
+
+    /// Returns the proving keys in the bundle, in order.
+    pub fn to_proving_keys(&self) -> Result<Vec<ProvingKey<N>>> {
+        (0..self.keys.len()).map(|index| self.get(index)).collect()
+    }
+}
+
+/// Splits the given bytes into content-defined blocks, using a gear rolling hash to find the boundaries.
+fn split_into_blocks(bytes: &[u8]) -> Vec<&[u8]> {
+    let mut blocks = Vec::new();
+    let mut start = 0;
+    let mut hash = 0u64;
+    for (position, byte) in bytes.iter().enumerate() {
+        // Update the rolling hash.
+        hash = (hash << 1).wrapping_add(gear(*byte));
+        // Determine the size of the current block.
+        let size = position + 1 - start;
+        // End the block at a boundary, or once it reaches the maximum size.
+        if (size >= MIN_BLOCK_SIZE && hash & BOUNDARY_MASK == 0) || size >= MAX_BLOCK_SIZE {
+            blocks.push(&bytes[start..=position]);
+            start = position + 1;
+            hash = 0;
+        }
+    }
+    // Add the final block.
+    if start < bytes.len() {
+        blocks.push(&bytes[start..]);
+    }
+    blocks
+}
+
+/// Returns a fixed pseudorandom value for the given byte, for use in the rolling hash.
+const fn gear(byte: u8) -> u64 {
+    // Apply the SplitMix64 finalizer to the byte.
+    let mut z = (byte as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+impl<N: Network> FromBytes for ProvingKeyBundle<N> {
+    /// Reads the bundle from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid proving key bundle version"));
+        }
+        // Read the number of blocks.
+        let num_blocks = u32::read_le(&mut reader)?;
+        // Read the blocks.
+        let mut blocks = Vec::new();
+        for _ in 0..num_blocks {
+            // Read the block size.
+            let size = u32::read_le(&mut reader)? as usize;
+            // Ensure the block size is valid.
+            if size == 0 || size > MAX_BLOCK_SIZE {
+                return Err(error(format!("Invalid block size '{size}' in the proving key bundle")));
+            }
+            // Read the block.
+            let mut block = vec![0u8; size];
+            reader.read_exact(&mut block)?;
+            blocks.push(block);
+        }
+        // Read the number of proving keys.
+        let num_keys = u32::read_le(&mut reader)?;
+        // Read the block indices for each proving key.
+        let mut keys = Vec::new();
+        for _ in 0..num_keys {
+            let num_indices = u32::read_le(&mut reader)?;
+            let mut indices = Vec::new();
+            for _ in 0..num_indices {
+                let index = u32::read_le(&mut reader)?;
+                // Ensure the block exists.
+                if index >= num_blocks {
+                    return Err(error(format!("Block {index} does not exist in the proving key bundle")));
+                }
+                indices.push(index);
+            }
+            keys.push(indices);
+        }
+        Ok(Self { blocks, keys, _phantom: PhantomData })
+    }
+}
+
+impl<N: Network> ToBytes for ProvingKeyBundle<N> {
+    /// Writes the bundle to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of blocks.
+        u32::try_from(self.blocks.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the blocks.
+        for block in &self.blocks {
+            u32::try_from(block.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+            writer.write_all(block)?;
+        }
+        // Write the number of proving keys.
+        u32::try_from(self.keys.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the block indices for each proving key.
+        for indices in &self.keys {
+            u32::try_from(indices.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+            for index in indices {
+                index.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_split_into_blocks() {
+        let rng = &mut TestRng::default();
+
+        // Sample random bytes.
+        let bytes = (0..1_000_000).map(|_| rng.gen()).collect::<Vec<u8>>();
+        // Ensure the blocks cover the bytes, within the size bounds.
+        let blocks = split_into_blocks(&bytes);
+        assert_eq!(bytes, blocks.concat());
+        assert!(blocks[..blocks.len() - 1]
+            .iter()
+            .all(|block| (MIN_BLOCK_SIZE..=MAX_BLOCK_SIZE).contains(&block.len())));
+
+        // Ensure shifting the bytes preserves most of the blocks.
+        let shifted = [&[0u8; 100][..], &bytes].concat();
+        let shifted_blocks = split_into_blocks(&shifted);
+        let num_shared = shifted_blocks.iter().filter(|block| blocks.contains(block)).count();
+        assert!(num_shared + 2 >= blocks.len());
+    }
+
+    #[test]
+    fn test_bundle() -> Result<()> {
+        // Retrieve the proving key.
+        let (proving_key, _) = crate::test_helpers::sample_keys();
+
+        // Ensure identical proving keys share their blocks.
+        let single = ProvingKeyBundle::<CurrentNetwork>::new([&proving_key])?;
+        let bundle = ProvingKeyBundle::<CurrentNetwork>::new([&proving_key, &proving_key, &proving_key])?;
+        assert_eq!(bundle.num_keys(), 3);
+        assert_eq!(bundle.num_blocks(), single.num_blocks());
+        assert!(bundle.num_block_bytes() <= proving_key.to_bytes_le()?.len());
+
+        // Ensure the proving keys are recovered.
+        for candidate in bundle.to_proving_keys()? {
+            assert_eq!(proving_key.to_bytes_le()?, candidate.to_bytes_le()?);
+        }
+        assert!(bundle.get(3).is_err());
+
+        // Ensure the bundle round-trips through bytes.
+        let bytes = bundle.to_bytes_le()?;
+        assert_eq!(bundle, ProvingKeyBundle::read_le(&bytes[..])?);
+        assert!(bytes.len() < 2 * proving_key.to_bytes_le()?.len());
+        Ok(())
+    }
+}
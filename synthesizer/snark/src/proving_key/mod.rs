@@ -14,11 +14,14 @@
 
 use super::*;
 
+mod bundle;
+pub use bundle::ProvingKeyBundle;
+
 mod bytes;
 mod parse;
 mod serialize;
 
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, sync::atomic::AtomicBool};
 
 #[derive(Clone)]
 pub struct ProvingKey<N: Network> {
@@ -60,6 +63,18 @@ impl<N: Network> ProvingKey<N> {
         locator: &str,
         assignments: &[(ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)],
         rng: &mut R,
+    ) -> Result<Proof<N>> {
+        Self::prove_batch_with_terminator(locator, assignments, &AtomicBool::new(false), rng)
+    }
+
+    /// Returns a proof for the given batch of proving keys and assignments,
+    /// halting between prover rounds once the terminator is set.
+    #[allow(clippy::type_complexity)]
+    pub fn prove_batch_with_terminator<R: Rng + CryptoRng>(
+        locator: &str,
+        assignments: &[(ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)],
+        terminator: &AtomicBool,
+        rng: &mut R,
     ) -> Result<Proof<N>> {
         #[cfg(feature = "aleo-cli")]
         let timer = std::time::Instant::now();
@@ -77,7 +92,13 @@ impl<N: Network> ProvingKey<N> {
         let fiat_shamir = N::varuna_fs_parameters();
 
         // Compute the proof.
-        let batch_proof = Proof::new(Varuna::<N>::prove_batch(universal_prover, fiat_shamir, &instances, rng)?);
+        let batch_proof = Proof::new(Varuna::<N>::prove_batch_with_terminator(
+            universal_prover,
+            fiat_shamir,
+            &instances,
+            terminator,
+            rng,
+        )?);
 
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Executed '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
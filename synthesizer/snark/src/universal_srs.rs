@@ -26,6 +26,15 @@ impl<N: Network> UniversalSRS<N> {
         Ok(Self { srs: Arc::new(OnceCell::new()) })
     }
 
+    /// Samples a fresh universal SRS with `num_powers` powers, from the given `seed`.
+    ///
+    /// WARNING: This universal SRS is **insecure**, as its trapdoor is derived from the seed.
+    /// It must only be used for local development networks and testing, never in production.
+    pub fn setup_insecure(num_powers: usize, seed: u64) -> Result<Self> {
+        let srs = varuna::UniversalSRS::setup_insecure(num_powers, seed)?;
+        Ok(Self { srs: Arc::new(OnceCell::with_value(srs)) })
+    }
+
     /// Returns the circuit proving and verifying key.
     pub fn to_circuit_key(
         &self,
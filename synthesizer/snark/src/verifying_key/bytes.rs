@@ -20,15 +20,25 @@ impl<N: Network> FromBytes for VerifyingKey<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid verifying key version"));
         }
         // Read the verifying key.
         let verifying_key = Arc::new(FromBytes::read_le(&mut reader)?);
         // Read the number of variables.
         let num_variables = u64::read_le(&mut reader)?;
+        // Read the proof features, if the version includes them.
+        let features = match version {
+            1 => ProofFeatures::BASELINE,
+            _ => match ProofFeatures::read_le(&mut reader)? {
+                ProofFeatures::BASELINE => {
+                    return Err(error("Baseline proof features must use verifying key version 1"));
+                }
+                features => features,
+            },
+        };
         // Return the verifying key.
-        Ok(Self { verifying_key, num_variables })
+        Ok(Self { verifying_key, num_variables, features })
     }
 }
 
@@ -36,10 +46,18 @@ impl<N: Network> ToBytes for VerifyingKey<N> {
     /// Writes the verifying key to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        match self.features {
+            ProofFeatures::BASELINE => 1u8.write_le(&mut writer)?,
+            _ => 2u8.write_le(&mut writer)?,
+        }
         // Write the verifying key.
         self.verifying_key.write_le(&mut writer)?;
         // Write the number of variables.
-        self.num_variables.write_le(&mut writer)
+        self.num_variables.write_le(&mut writer)?;
+        // Write the proof features, if they differ from the baseline.
+        match self.features {
+            ProofFeatures::BASELINE => Ok(()),
+            features => features.write_le(&mut writer),
+        }
     }
 }
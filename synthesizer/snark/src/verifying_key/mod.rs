@@ -26,12 +26,24 @@ pub struct VerifyingKey<N: Network> {
     verifying_key: Arc<varuna::CircuitVerifyingKey<N::PairingCurve>>,
     /// The number of constant, public, and private variables for the circuit.
     num_variables: u64,
+    /// The proof-system features required to verify proofs for the circuit.
+    features: ProofFeatures,
 }
 
 impl<N: Network> VerifyingKey<N> {
     /// Initializes a new verifying key.
     pub const fn new(verifying_key: Arc<varuna::CircuitVerifyingKey<N::PairingCurve>>, num_variables: u64) -> Self {
-        Self { verifying_key, num_variables }
+        Self { verifying_key, num_variables, features: ProofFeatures::BASELINE }
+    }
+
+    /// Returns the verifying key with the given required proof-system features.
+    pub fn with_features(self, features: ProofFeatures) -> Self {
+        Self { features, ..self }
+    }
+
+    /// Returns the proof-system features required to verify proofs for the circuit.
+    pub const fn features(&self) -> ProofFeatures {
+        self.features
     }
 
     /// Returns the number of constant, public, and private variables for the circuit.
@@ -44,6 +56,13 @@ impl<N: Network> VerifyingKey<N> {
         #[cfg(feature = "aleo-cli")]
         let timer = std::time::Instant::now();
 
+        // Ensure the verifying key and proof do not require unsupported proof-system features.
+        if let Err(error) = self.features.check_supported().and_then(|_| proof.features().check_supported()) {
+            #[cfg(feature = "aleo-cli")]
+            println!("{}", format!(" • Verifier failed: {error}").dimmed());
+            return false;
+        }
+
         // Retrieve the verification parameters.
         let universal_verifier = N::varuna_universal_verifier();
         let fiat_shamir = N::varuna_fs_parameters();
@@ -82,6 +101,12 @@ impl<N: Network> VerifyingKey<N> {
             inputs.iter().map(|(verifying_key, inputs)| (verifying_key.deref(), inputs.as_slice())).collect();
         ensure!(keys_to_inputs.len() == num_expected_keys, "Incorrect number of verifying keys for batch proof");
 
+        // Ensure the verifying keys and proof do not require unsupported proof-system features.
+        for (verifying_key, _) in &inputs {
+            verifying_key.features.check_supported()?;
+        }
+        proof.features().check_supported()?;
+
         // Retrieve the verification parameters.
         let universal_verifier = N::varuna_universal_verifier();
         let fiat_shamir = N::varuna_fs_parameters();
@@ -103,6 +128,63 @@ impl<N: Network> VerifyingKey<N> {
             }
         }
     }
+
+    /// Returns `Ok(())` if all of the batch proofs are valid for their public inputs.
+    /// The final pairing checks of the proofs are combined, so the proofs are verified together,
+    /// and a failure does not identify which of the proofs is invalid.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batches<R: Rng + CryptoRng>(
+        locator: &str,
+        batches: Vec<(Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, &Proof<N>)>,
+        rng: &mut R,
+    ) -> Result<()> {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Convert the instances.
+        let mut keys_to_inputs = Vec::with_capacity(batches.len());
+        for &(ref inputs, proof) in &batches {
+            let num_expected_keys = inputs.len();
+            let batch: BTreeMap<_, _> =
+                inputs.iter().map(|(verifying_key, inputs)| (verifying_key.deref(), inputs.as_slice())).collect();
+            ensure!(batch.len() == num_expected_keys, "Incorrect number of verifying keys for batch proof");
+
+            // Ensure the verifying keys and proof do not require unsupported proof-system features.
+            for (verifying_key, _) in inputs {
+                verifying_key.features.check_supported()?;
+            }
+            proof.features().check_supported()?;
+
+            keys_to_inputs.push((batch, proof.deref()));
+        }
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Verify the batch proofs.
+        match Varuna::<N>::verify_proofs_with_failure_reason(universal_verifier, fiat_shamir, &keys_to_inputs, rng) {
+            Ok(Ok(())) => {
+                #[cfg(feature = "aleo-cli")]
+                println!(
+                    "{}",
+                    format!(
+                        " • Verified '{locator}' ({} proofs, in {} ms)",
+                        batches.len(),
+                        timer.elapsed().as_millis()
+                    )
+                    .dimmed()
+                );
+                Ok(())
+            }
+            Ok(Err(failure)) => bail!("'verify_batches' failed - {failure}"),
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                bail!(error)
+            }
+        }
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {
@@ -49,6 +49,32 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         result
     }
 
+    /// Authorizes the given top-level calls, as `(program ID, function name, inputs)`, into a single authorization,
+    /// which executes the calls atomically into a single execution, paid for by a single fee.
+    #[inline]
+    pub fn authorize_bundle<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        calls: impl IntoIterator<Item = (ProgramID<N>, Identifier<N>, Vec<Value<N>>)>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        let timer = timer!("VM::authorize_bundle");
+
+        // Authorize each call.
+        let authorizations = calls
+            .into_iter()
+            .map(|(program_id, function_name, inputs)| {
+                self.authorize_raw(private_key, program_id, function_name, inputs, rng)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Authorize the calls");
+
+        // Bundle the authorizations.
+        let result = Authorization::bundle(authorizations);
+        finish!(timer, "Bundle the authorizations");
+        result
+    }
+
     /// Authorizes the fee given the credits record, the fee amount (in microcredits),
     /// and the deployment or execution ID.
     #[inline]
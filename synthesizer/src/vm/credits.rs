@@ -0,0 +1,387 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::too_many_arguments)]
+
+use super::*;
+
+use console::program::Entry;
+
+/// The amount of microcredits deducted by the `credits.aleo/split` function.
+const SPLIT_FEE_IN_MICROCREDITS: u64 = 10_000;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns a new execute transaction that splits the given `credits.aleo` record into two records,
+    /// where the first record holds `amount` microcredits, and the second holds the remainder (minus the split fee).
+    ///
+    /// A transaction containing only a split does not require a fee, so none is included.
+    pub fn execute_split<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        record: Record<N, Plaintext<N>>,
+        amount_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the record has sufficient microcredits for the amount and the split fee.
+        let balance = credits_record_balance(&record)?;
+        match amount_in_microcredits.checked_add(SPLIT_FEE_IN_MICROCREDITS) {
+            Some(required) if required <= balance => (),
+            _ => bail!(
+                "Insufficient balance to split {amount_in_microcredits} microcredits from a record with {balance} microcredits"
+            ),
+        }
+        // Prepare the inputs.
+        let inputs = [Value::Record(record), Value::from(Literal::U64(U64::new(amount_in_microcredits)))];
+        // Construct the execute transaction.
+        self.execute(private_key, ("credits.aleo", "split"), inputs.into_iter(), None, 0, query, rng)
+    }
+
+    /// Returns a new execute transaction that joins the given `credits.aleo` records into one record.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    pub fn execute_join<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        first: Record<N, Plaintext<N>>,
+        second: Record<N, Plaintext<N>>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the records are distinct.
+        ensure!(first != second, "Cannot join a record with itself");
+        // Ensure the fee record is not one of the joined records.
+        ensure_distinct_fee_record(&[&first, &second], &fee_record)?;
+        // Ensure the combined balance does not overflow.
+        credits_record_balance(&first)?
+            .checked_add(credits_record_balance(&second)?)
+            .ok_or_else(|| anyhow!("The combined balance of the records overflows"))?;
+        // Prepare the inputs.
+        let inputs = [Value::Record(first), Value::Record(second)];
+        // Construct the execute transaction.
+        self.execute(
+            private_key,
+            ("credits.aleo", "join"),
+            inputs.into_iter(),
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
+    }
+
+    /// Returns a new execute transaction that converts `amount` microcredits from the given `credits.aleo` record
+    /// into the public balance of the `recipient`, returning the change to the owner in a new record.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    pub fn execute_transfer_private_to_public<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        record: Record<N, Plaintext<N>>,
+        recipient: Address<N>,
+        amount_in_microcredits: u64,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the fee record is not the transferred record.
+        ensure_distinct_fee_record(&[&record], &fee_record)?;
+        // Ensure the record has sufficient microcredits for the amount.
+        let balance = credits_record_balance(&record)?;
+        ensure!(
+            amount_in_microcredits <= balance,
+            "Insufficient balance to transfer {amount_in_microcredits} microcredits from a record with {balance} microcredits"
+        );
+        // Prepare the inputs.
+        let inputs = [
+            Value::Record(record),
+            Value::from(Literal::Address(recipient)),
+            Value::from(Literal::U64(U64::new(amount_in_microcredits))),
+        ];
+        // Construct the execute transaction.
+        self.execute(
+            private_key,
+            ("credits.aleo", "transfer_private_to_public"),
+            inputs.into_iter(),
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
+    }
+
+    /// Returns a new execute transaction that converts `amount` microcredits from the public balance of the caller
+    /// into a new `credits.aleo` record for the `recipient`.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    pub fn execute_transfer_public_to_private<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        recipient: Address<N>,
+        amount_in_microcredits: u64,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Prepare the inputs.
+        let inputs =
+            [Value::from(Literal::Address(recipient)), Value::from(Literal::U64(U64::new(amount_in_microcredits)))];
+        // Construct the execute transaction.
+        self.execute(
+            private_key,
+            ("credits.aleo", "transfer_public_to_private"),
+            inputs.into_iter(),
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
+    }
+
+    /// Returns a selection of the given `credits.aleo` records that funds `amount_in_microcredits`,
+    /// using the given coin selection `strategy`. Records that have already been spent are skipped.
+    ///
+    /// The selected records are locked, and are not selected again until they are spent in a block,
+    /// released with `release_credits_records`, or their lock expires.
+    ///
+    /// The change of the selection is returned to the owner as a new record by the credits function that spends it.
+    pub fn select_credits_records(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: impl IntoIterator<Item = Record<N, Plaintext<N>>>,
+        amount_in_microcredits: u64,
+        strategy: CoinSelectionStrategy,
+    ) -> Result<CoinSelection<Record<N, Plaintext<N>>>> {
+        // Collect the unspent records.
+        let candidates = self.unspent_credits_records(private_key, records)?;
+        // Select and lock the unlocked records.
+        self.record_locks.lock_selection(candidates, |candidates| {
+            select_coins(with_credits_record_balances(candidates)?, amount_in_microcredits, strategy)
+        })
+    }
+
+    /// Returns the `credits.aleo` record with the least balance that pays `fee_in_microcredits` on its own,
+    /// as a private fee is paid from a single record. Records that have already been spent are skipped.
+    ///
+    /// The selected record is locked, as in `select_credits_records`.
+    pub fn select_fee_record(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: impl IntoIterator<Item = Record<N, Plaintext<N>>>,
+        fee_in_microcredits: u64,
+    ) -> Result<Record<N, Plaintext<N>>> {
+        // Collect the unspent records.
+        let candidates = self.unspent_credits_records(private_key, records)?;
+        // Select and lock an unlocked record.
+        let selection = self.record_locks.lock_selection(candidates, |candidates| {
+            select_coin(with_credits_record_balances(candidates)?, fee_in_microcredits)
+        })?;
+        // Return the selected record.
+        selection.into_inputs().pop().ok_or_else(|| anyhow!("Failed to select a fee record"))
+    }
+
+    /// Releases the locks on the given `credits.aleo` records, e.g. if their execution was not broadcast.
+    pub fn release_credits_records<'a>(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: impl IntoIterator<Item = &'a Record<N, Plaintext<N>>>,
+    ) -> Result<()> {
+        // Compute the serial numbers of the records.
+        let serial_numbers = records
+            .into_iter()
+            .map(|record| credits_record_serial_number(private_key, record))
+            .collect::<Result<Vec<_>>>()?;
+        // Unlock the serial numbers.
+        self.record_locks.unlock(&serial_numbers);
+        Ok(())
+    }
+
+    /// Returns the given `credits.aleo` records that have not been spent, along with their serial numbers.
+    fn unspent_credits_records(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: impl IntoIterator<Item = Record<N, Plaintext<N>>>,
+    ) -> Result<Vec<(Field<N>, Record<N, Plaintext<N>>)>> {
+        let mut candidates = Vec::new();
+        for record in records {
+            // Compute the serial number of the record.
+            let serial_number = credits_record_serial_number(private_key, &record)?;
+            // Skip the record if it has already been spent.
+            if self.transition_store().contains_serial_number(&serial_number)? {
+                continue;
+            }
+            candidates.push((serial_number, record));
+        }
+        Ok(candidates)
+    }
+}
+
+/// Returns the given candidates, along with the balances of their `credits.aleo` records.
+fn with_credits_record_balances<N: Network>(
+    candidates: Vec<(Field<N>, Record<N, Plaintext<N>>)>,
+) -> Result<Vec<((Field<N>, Record<N, Plaintext<N>>), u64)>> {
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            let balance = credits_record_balance(&candidate.1)?;
+            Ok((candidate, balance))
+        })
+        .collect()
+}
+
+/// Returns the number of microcredits in the given `credits.aleo` record.
+fn credits_record_balance<N: Network>(record: &Record<N, Plaintext<N>>) -> Result<u64> {
+    match record.data().get(&Identifier::from_str("microcredits")?) {
+        Some(Entry::Private(Plaintext::Literal(Literal::U64(microcredits), _))) => Ok(**microcredits),
+        _ => bail!("Invalid 'credits.aleo' record (missing microcredits)"),
+    }
+}
+
+/// Returns the serial number of the given `credits.aleo` record.
+fn credits_record_serial_number<N: Network>(
+    private_key: &PrivateKey<N>,
+    record: &Record<N, Plaintext<N>>,
+) -> Result<Field<N>> {
+    let commitment = record.to_commitment(&ProgramID::from_str("credits.aleo")?, &Identifier::from_str("credits")?)?;
+    Record::<N, Plaintext<N>>::serial_number(*private_key, commitment)
+}
+
+/// Ensures the given fee record (if any) is not one of the records spent by the execution.
+fn ensure_distinct_fee_record<N: Network>(
+    records: &[&Record<N, Plaintext<N>>],
+    fee_record: &Option<Record<N, Plaintext<N>>>,
+) -> Result<()> {
+    if let Some(fee_record) = fee_record {
+        ensure!(!records.contains(&fee_record), "The fee record cannot also be spent in the execution");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::ViewKey, network::MainnetV0};
+    use ledger_block::Transition;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_credits_helpers() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Fetch the unspent records.
+        let records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record.decrypt(&caller_view_key).unwrap())
+            .collect::<Vec<_>>();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Ensure splitting more than the balance fails.
+        let balance = credits_record_balance(&records[0]).unwrap();
+        assert!(vm.execute_split(&caller_private_key, records[0].clone(), balance, None, rng).is_err());
+        // Ensure joining a record with itself fails.
+        assert!(vm
+            .execute_join(&caller_private_key, records[0].clone(), records[0].clone(), None, 0, None, rng)
+            .is_err());
+        // Ensure the fee record cannot be spent in the execution.
+        assert!(vm
+            .execute_transfer_private_to_public(
+                &caller_private_key,
+                records[0].clone(),
+                caller_address,
+                1,
+                Some(records[0].clone()),
+                0,
+                None,
+                rng
+            )
+            .is_err());
+
+        // Split a record.
+        let transaction = vm.execute_split(&caller_private_key, records[0].clone(), 1, None, rng).unwrap();
+        assert!(transaction.transitions().next().unwrap().is_split());
+        vm.check_transaction(&transaction, None, rng).unwrap();
+
+        // Join two records.
+        let transaction =
+            vm.execute_join(&caller_private_key, records[0].clone(), records[1].clone(), None, 0, None, rng).unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+
+        // Convert a private balance to a public balance.
+        let transaction = vm
+            .execute_transfer_private_to_public(
+                &caller_private_key,
+                records[0].clone(),
+                caller_address,
+                1,
+                Some(records[1].clone()),
+                0,
+                None,
+                rng,
+            )
+            .unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+
+        // Select records to fund an amount spanning multiple records.
+        let amount = credits_record_balance(&records[0]).unwrap() + 1;
+        let selection = vm
+            .select_credits_records(&caller_private_key, records.clone(), amount, CoinSelectionStrategy::LargestFirst)
+            .unwrap();
+        assert!(selection.inputs().len() > 1);
+        assert_eq!(selection.total() - selection.change(), amount);
+
+        // Ensure locked records are not selected again, until they are released.
+        let other = vm
+            .select_credits_records(&caller_private_key, records.clone(), amount, CoinSelectionStrategy::LargestFirst)
+            .unwrap();
+        assert!(other.inputs().iter().all(|record| !selection.inputs().contains(record)));
+        vm.release_credits_records(&caller_private_key, other.inputs()).unwrap();
+        vm.release_credits_records(&caller_private_key, selection.inputs()).unwrap();
+        assert_eq!(vm.record_locks().num_locked(), 0);
+
+        // Ensure spent records are not selected.
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        let selection = vm
+            .select_credits_records(&caller_private_key, records.clone(), 1, CoinSelectionStrategy::BranchAndBound)
+            .unwrap();
+        assert!(!selection.inputs().contains(&records[0]));
+
+        // Convert a public balance to a private balance.
+        let transaction =
+            vm.execute_transfer_public_to_private(&caller_private_key, caller_address, 1, None, 0, None, rng).unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+    }
+}
@@ -0,0 +1,278 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A builder for an execute transaction, which computes the fee from the size of the execution,
+/// and appends a fee transition that pays it.
+///
+/// If several calls are added, they are authorized together, and execute atomically into one execution,
+/// so that a single fee covers all of them.
+///
+/// If candidate fee records are given, the fee is paid privately from the candidate with the least balance
+/// that covers it; otherwise, the fee is paid from the public balance of the signer.
+pub struct ExecuteBuilder<'a, N: Network, C: ConsensusStorage<N>> {
+    /// The VM.
+    vm: &'a VM<N, C>,
+    /// The private key of the signer.
+    private_key: PrivateKey<N>,
+    /// The top-level calls, as `(program ID, function name, inputs)`.
+    calls: Vec<(ProgramID<N>, Identifier<N>, Vec<Value<N>>)>,
+    /// The candidate `credits.aleo` records to pay the fee with.
+    fee_records: Vec<Record<N, Plaintext<N>>>,
+    /// The priority fee, **on top** of the execution fee.
+    priority_fee_in_microcredits: u64,
+    /// The query, if the VM should not be queried for the state root.
+    query: Option<Query<N, C::BlockStorage>>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns a builder for an execute transaction of the given function on the given inputs.
+    pub fn execute_builder(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl IntoIterator<Item = impl TryInto<Value<N>>>,
+    ) -> Result<ExecuteBuilder<'_, N, C>> {
+        ExecuteBuilder {
+            vm: self,
+            private_key: *private_key,
+            calls: Vec::new(),
+            fee_records: Vec::new(),
+            priority_fee_in_microcredits: 0,
+            query: None,
+        }
+        .with_call((program_id, function_name), inputs)
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> ExecuteBuilder<'_, N, C> {
+    /// Appends a call of the given function on the given inputs, which executes after the previous calls.
+    pub fn with_call(
+        mut self,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl IntoIterator<Item = impl TryInto<Value<N>>>,
+    ) -> Result<Self> {
+        // Prepare the program ID.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Prepare the inputs.
+        let inputs = inputs
+            .into_iter()
+            .map(|input| input.try_into().map_err(|_| anyhow!("Failed to parse input for '{function_name}'")))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.calls.push((program_id, function_name, inputs));
+        Ok(self)
+    }
+
+    /// Sets the candidate `credits.aleo` records to pay the fee with.
+    /// Records that are spent by the execution itself, or that have already been spent, are not selected.
+    pub fn with_fee_records(mut self, records: impl IntoIterator<Item = Record<N, Plaintext<N>>>) -> Self {
+        self.fee_records = records.into_iter().collect();
+        self
+    }
+
+    /// Sets the priority fee, which is paid **on top** of the execution fee.
+    pub fn with_priority_fee(mut self, priority_fee_in_microcredits: u64) -> Self {
+        self.priority_fee_in_microcredits = priority_fee_in_microcredits;
+        self
+    }
+
+    /// Sets the query for the state root, in place of the VM.
+    pub fn with_query(mut self, query: Query<N, C::BlockStorage>) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Returns the execute transaction, with its fee.
+    ///
+    /// The selected fee record is locked, as in `VM::select_fee_record`, and is released if the fee fails.
+    pub fn build<R: Rng + CryptoRng>(self, rng: &mut R) -> Result<Transaction<N>> {
+        let Self { vm, private_key, calls, fee_records, priority_fee_in_microcredits, query } = self;
+
+        // Collect the records that are spent by the execution.
+        let spent_records = calls
+            .iter()
+            .flat_map(|(_, _, inputs)| inputs)
+            .filter_map(|input| match input {
+                Value::Record(record) => Some(record.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Compute the authorization.
+        let authorization = match <[_; 1]>::try_from(calls) {
+            Ok([(program_id, function_name, inputs)]) => {
+                vm.authorize(&private_key, program_id, function_name, inputs, rng)?
+            }
+            Err(calls) => vm.authorize_bundle(&private_key, calls, rng)?,
+        };
+        // Determine if a fee is required.
+        let is_fee_required = !authorization.is_split();
+        // Determine if a priority fee is declared.
+        let is_priority_fee_declared = priority_fee_in_microcredits > 0;
+        // Compute the execution.
+        let execution = vm.execute_authorization_raw(authorization, query.clone(), rng)?;
+        // If no fee is required or declared, return the execute transaction without a fee.
+        if !is_fee_required && !is_priority_fee_declared {
+            return Transaction::from_execution(execution, None);
+        }
+
+        // Compute the minimum execution cost.
+        let (minimum_execution_cost, (_, _)) = execution_cost(&vm.process().read(), &execution)?;
+        // Compute the execution ID.
+        let execution_id = execution.to_execution_id()?;
+
+        // If there are no candidate fee records, pay the fee publicly.
+        let fee = if fee_records.is_empty() {
+            let authorization = vm.authorize_fee_public(
+                &private_key,
+                minimum_execution_cost,
+                priority_fee_in_microcredits,
+                execution_id,
+                rng,
+            )?;
+            vm.execute_fee_authorization_raw(authorization, query, rng)?
+        }
+        // Otherwise, select a record that pays the fee, and pay the fee privately.
+        else {
+            let fee_in_microcredits = minimum_execution_cost
+                .checked_add(priority_fee_in_microcredits)
+                .ok_or_else(|| anyhow!("The fee overflows"))?;
+            let candidates = fee_records.into_iter().filter(|record| !spent_records.contains(record));
+            let record = vm.select_fee_record(&private_key, candidates, fee_in_microcredits)?;
+            // Authorize and execute the fee.
+            let fee = vm
+                .authorize_fee_private(
+                    &private_key,
+                    record.clone(),
+                    minimum_execution_cost,
+                    priority_fee_in_microcredits,
+                    execution_id,
+                    rng,
+                )
+                .and_then(|authorization| vm.execute_fee_authorization_raw(authorization, query, rng));
+            // If the fee failed, release the fee record.
+            if fee.is_err() {
+                vm.release_credits_records(&private_key, [&record])?;
+            }
+            fee?
+        };
+        // Return the execute transaction.
+        Transaction::from_execution(execution, Some(fee))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::ViewKey;
+    use ledger_block::Transition;
+
+    #[test]
+    fn test_execute_builder() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Fetch the unspent records.
+        let records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record.decrypt(&caller_view_key).unwrap())
+            .collect::<Vec<_>>();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Build a transaction that spends a record, with the fee paid from the other records.
+        let inputs = [
+            Value::Record(records[0].clone()),
+            Value::from(Literal::Address(caller_address)),
+            Value::from(Literal::U64(U64::new(1))),
+        ];
+        let transaction = vm
+            .execute_builder(&caller_private_key, ("credits.aleo", "transfer_private_to_public"), inputs)
+            .unwrap()
+            .with_fee_records(records.clone())
+            .with_priority_fee(1)
+            .build(rng)
+            .unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+
+        // Ensure the fee is paid privately, from a record that is not spent by the execution.
+        let fee = transaction.fee_transition().unwrap();
+        assert!(fee.is_fee_private());
+        assert_eq!(*fee.priority_amount().unwrap(), 1);
+        assert_eq!(vm.record_locks().num_locked(), 1);
+        let execution = transaction.execution().unwrap();
+        let spent = execution.transitions().flat_map(Transition::serial_numbers).collect::<Vec<_>>();
+        assert!(fee.transition().serial_numbers().all(|serial_number| !spent.contains(&serial_number)));
+
+        // Ensure the build fails if no record pays the fee on its own.
+        let result = vm
+            .execute_builder(&caller_private_key, ("credits.aleo", "transfer_public"), [
+                Value::from(Literal::Address(caller_address)),
+                Value::from(Literal::U64(U64::new(1))),
+            ])
+            .unwrap()
+            .with_fee_records([records[0].clone()])
+            .with_priority_fee(u64::MAX / 2)
+            .build(rng);
+        assert!(result.is_err());
+
+        // Build a transaction with the fee paid publicly.
+        let transaction = vm
+            .execute_builder(&caller_private_key, ("credits.aleo", "transfer_public"), [
+                Value::from(Literal::Address(caller_address)),
+                Value::from(Literal::U64(U64::new(1))),
+            ])
+            .unwrap()
+            .build(rng)
+            .unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        assert!(transaction.fee_transition().unwrap().is_fee_public());
+
+        // Build a transaction with two calls, paid for by a single fee.
+        let transaction = vm
+            .execute_builder(&caller_private_key, ("credits.aleo", "transfer_public"), [
+                Value::from(Literal::Address(caller_address)),
+                Value::from(Literal::U64(U64::new(1))),
+            ])
+            .unwrap()
+            .with_call(("credits.aleo", "transfer_public"), [
+                Value::from(Literal::Address(caller_address)),
+                Value::from(Literal::U64(U64::new(2))),
+            ])
+            .unwrap()
+            .build(rng)
+            .unwrap();
+        assert_eq!(transaction.execution().unwrap().len(), 2);
+        assert!(transaction.fee_transition().unwrap().is_fee_public());
+        // Ensure the bundled calls are rejected, until `ConsensusVersion::V2` is in effect.
+        assert!(vm.next_consensus_version() < ConsensusVersion::V2);
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+    }
+}
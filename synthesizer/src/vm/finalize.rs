@@ -168,6 +168,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         finish!(timer, "Finished real-run of finalize");
         Ok(ratified_finalize_operations)
     }
+
+    /// Returns the failed execution of the given confirmed transaction, if it is a rejected execute transaction.
+    /// The failed execution holds the storage cost and the finalize cost of the execution.
+    #[inline]
+    pub fn to_failed_execution(&self, transaction: &ConfirmedTransaction<N>) -> Result<Option<FailedExecution<N>>> {
+        match transaction {
+            ConfirmedTransaction::RejectedExecute(_, Transaction::Fee(_, fee), rejected, _) => {
+                // Retrieve the rejected execution.
+                let Some(execution) = rejected.execution() else { bail!("Expected a rejected execution") };
+                // Construct the failed execution.
+                Ok(Some(failed_execution(&self.process().read(), execution, fee)?))
+            }
+            _ => Ok(None),
+        }
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -2068,7 +2083,12 @@ finalize compute:
             sample_next_block(&vm, &private_key, &[first], &splits_block, &mut unspent_records, rng).unwrap();
 
         // Check that the transaction was rejected.
-        assert!(next_block.transactions().iter().next().unwrap().is_rejected());
+        let rejected = next_block.transactions().iter().next().unwrap();
+        assert!(rejected.is_rejected());
+        // Ensure the failed execution holds the cost of the execution.
+        let failed = vm.to_failed_execution(rejected).unwrap().unwrap();
+        let (cost, _) = execution_cost(&vm.process().read(), failed.execution()).unwrap();
+        assert_eq!(failed.total_cost(), cost);
 
         // Add the next block to the VM.
         vm.add_next_block(&next_block).unwrap();
@@ -0,0 +1,254 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+/// The maximum number of branches explored by the branch-and-bound strategy, before falling back to largest-first.
+const MAX_BRANCH_AND_BOUND_TRIES: usize = 100_000;
+
+/// The strategy used to select the inputs that fund a given amount.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CoinSelectionStrategy {
+    /// Selects the largest inputs first, minimizing the number of inputs.
+    LargestFirst,
+    /// Searches for the selection with the least change, falling back to largest-first if the search is exhausted.
+    BranchAndBound,
+}
+
+/// The inputs selected to fund a given amount, along with the resulting change.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinSelection<T> {
+    /// The selected inputs.
+    inputs: Vec<T>,
+    /// The total value of the selected inputs.
+    total: u64,
+    /// The value of the selected inputs in excess of the target amount.
+    change: u64,
+}
+
+impl<T> CoinSelection<T> {
+    /// Returns the selected inputs.
+    pub fn inputs(&self) -> &[T] {
+        &self.inputs
+    }
+
+    /// Returns the total value of the selected inputs.
+    pub const fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the value of the selected inputs in excess of the target amount.
+    pub const fn change(&self) -> u64 {
+        self.change
+    }
+
+    /// Returns the selected inputs.
+    pub fn into_inputs(self) -> Vec<T> {
+        self.inputs
+    }
+
+    /// Returns the selection with the given function applied to each input.
+    pub fn map_inputs<U>(self, f: impl FnMut(T) -> U) -> CoinSelection<U> {
+        CoinSelection { inputs: self.inputs.into_iter().map(f).collect(), total: self.total, change: self.change }
+    }
+}
+
+/// Returns a selection of the given `(input, value)` candidates whose total value is at least `target`.
+pub fn select_coins<T>(
+    mut candidates: Vec<(T, u64)>,
+    target: u64,
+    strategy: CoinSelectionStrategy,
+) -> Result<CoinSelection<T>> {
+    // Ensure the target is nonzero.
+    ensure!(target > 0, "The amount to fund must be greater than zero");
+    // Ensure the candidates have sufficient value to fund the target.
+    let available = candidates.iter().map(|(_, value)| *value as u128).sum::<u128>();
+    ensure!(available >= target as u128, "Insufficient funds - found {available}, but {target} is required");
+
+    // Sort the candidates in decreasing order of value.
+    candidates.sort_by(|(_, a), (_, b)| b.cmp(a));
+    let values = candidates.iter().map(|(_, value)| *value).collect::<Vec<_>>();
+
+    // Select the inputs.
+    let selection = match strategy {
+        CoinSelectionStrategy::LargestFirst => largest_first(&values, target),
+        CoinSelectionStrategy::BranchAndBound => {
+            branch_and_bound(&values, target).unwrap_or_else(|| largest_first(&values, target))
+        }
+    };
+
+    // Collect the selected inputs.
+    let mut inputs = Vec::new();
+    let mut total = 0u128;
+    for ((input, value), is_selected) in candidates.into_iter().zip_eq(selection) {
+        if is_selected {
+            inputs.push(input);
+            total += value as u128;
+        }
+    }
+    // Compute the change.
+    let total = u64::try_from(total).map_err(|_| anyhow!("The total value of the selected inputs overflows"))?;
+    let change = total.checked_sub(target).ok_or_else(|| anyhow!("The selected inputs do not fund the target"))?;
+    // Return the selection.
+    Ok(CoinSelection { inputs, total, change })
+}
+
+/// Returns a selection of the single candidate with the least value that is at least `target`,
+/// i.e. for a fee, which is paid from one input.
+pub fn select_coin<T>(candidates: Vec<(T, u64)>, target: u64) -> Result<CoinSelection<T>> {
+    // Ensure the target is nonzero.
+    ensure!(target > 0, "The amount to fund must be greater than zero");
+    // Select the candidate with the least value that funds the target.
+    let Some((input, total)) =
+        candidates.into_iter().filter(|(_, value)| *value >= target).min_by_key(|(_, value)| *value)
+    else {
+        bail!("Insufficient funds - no single input holds the {target} that is required")
+    };
+    // Return the selection.
+    Ok(CoinSelection { inputs: vec![input], total, change: total - target })
+}
+
+/// Returns the selection of the largest values (given in decreasing order) that reaches the target.
+fn largest_first(values: &[u64], target: u64) -> Vec<bool> {
+    let mut total = 0u128;
+    values
+        .iter()
+        .map(|value| {
+            let is_selected = total < target as u128;
+            if is_selected {
+                total += *value as u128;
+            }
+            is_selected
+        })
+        .collect()
+}
+
+/// Returns the selection of values (given in decreasing order) that reaches the target with the least change,
+/// or `None` if no selection was found within the search bound.
+fn branch_and_bound(values: &[u64], target: u64) -> Option<Vec<bool>> {
+    // Compute the suffix sums, to bound the value still available at each depth.
+    let mut remaining = vec![0u128; values.len() + 1];
+    for index in (0..values.len()).rev() {
+        remaining[index] = remaining[index + 1] + values[index] as u128;
+    }
+    // Search for the best selection.
+    let mut search = BranchAndBound {
+        values,
+        remaining: &remaining,
+        target: target as u128,
+        selection: vec![false; values.len()],
+        best: None,
+        tries: 0,
+    };
+    search.explore(0, 0);
+    search.best.map(|(_, selection)| selection)
+}
+
+/// The state of a branch-and-bound search.
+struct BranchAndBound<'a> {
+    /// The candidate values, in decreasing order.
+    values: &'a [u64],
+    /// The sum of the candidate values from each index onwards.
+    remaining: &'a [u128],
+    /// The target amount.
+    target: u128,
+    /// The current selection.
+    selection: Vec<bool>,
+    /// The change and selection of the best selection found so far.
+    best: Option<(u128, Vec<bool>)>,
+    /// The number of branches explored so far.
+    tries: usize,
+}
+
+impl BranchAndBound<'_> {
+    /// Explores the selections of the values from `depth` onwards, given the `total` selected so far.
+    fn explore(&mut self, depth: usize, total: u128) {
+        self.tries += 1;
+        // Stop if the search bound is exhausted, or if a selection without change was found.
+        if self.tries > MAX_BRANCH_AND_BOUND_TRIES || matches!(self.best, Some((0, _))) {
+            return;
+        }
+        // If the target is reached, keep the selection if it has less change than the best so far.
+        if total >= self.target {
+            let change = total - self.target;
+            if self.best.as_ref().map_or(true, |(best, _)| change < *best) {
+                self.best = Some((change, self.selection.clone()));
+            }
+            return;
+        }
+        // Prune the branch if the target can no longer be reached.
+        if depth == self.values.len() || total + self.remaining[depth] < self.target {
+            return;
+        }
+        // Explore the branch that includes the value at this depth.
+        self.selection[depth] = true;
+        self.explore(depth + 1, total + self.values[depth] as u128);
+        // Explore the branch that excludes the value at this depth.
+        self.selection[depth] = false;
+        self.explore(depth + 1, total);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the values of the selected inputs.
+    fn select(values: &[u64], target: u64, strategy: CoinSelectionStrategy) -> Result<CoinSelection<u64>> {
+        select_coins(values.iter().map(|value| (*value, *value)).collect(), target, strategy)
+    }
+
+    #[test]
+    fn test_largest_first() {
+        let selection = select(&[5, 20, 10, 1], 25, CoinSelectionStrategy::LargestFirst).unwrap();
+        assert_eq!(selection.inputs(), &[20, 10]);
+        assert_eq!(selection.total(), 30);
+        assert_eq!(selection.change(), 5);
+    }
+
+    #[test]
+    fn test_branch_and_bound() {
+        // Ensure an exact match is found.
+        let selection = select(&[5, 20, 10, 1], 25, CoinSelectionStrategy::BranchAndBound).unwrap();
+        assert_eq!(selection.inputs(), &[20, 5]);
+        assert_eq!(selection.change(), 0);
+
+        // Ensure the selection with the least change is found.
+        let selection = select(&[50, 30, 30], 55, CoinSelectionStrategy::BranchAndBound).unwrap();
+        assert_eq!(selection.inputs(), &[30, 30]);
+        assert_eq!(selection.change(), 5);
+    }
+
+    #[test]
+    fn test_select_coin() {
+        let candidates = [5, 20, 10, 1].iter().map(|value| (*value, *value)).collect::<Vec<_>>();
+        // Ensure the least sufficient input is selected.
+        let selection = select_coin(candidates.clone(), 6).unwrap();
+        assert_eq!(selection.inputs(), &[10]);
+        assert_eq!(selection.change(), 4);
+        // Ensure the inputs are not combined.
+        assert!(select_coin(candidates.clone(), 21).is_err());
+        assert!(select_coin(candidates, 0).is_err());
+    }
+
+    #[test]
+    fn test_insufficient_funds() {
+        for strategy in [CoinSelectionStrategy::LargestFirst, CoinSelectionStrategy::BranchAndBound] {
+            assert!(select(&[5, 20, 10, 1], 37, strategy).is_err());
+            assert!(select(&[5, 20, 10, 1], 0, strategy).is_err());
+            assert!(select(&[], 1, strategy).is_err());
+            assert!(select(&[u64::MAX, u64::MAX], u64::MAX, strategy).is_ok());
+        }
+    }
+}
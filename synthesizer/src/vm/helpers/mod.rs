@@ -15,7 +15,16 @@
 pub(crate) mod committee;
 pub use committee::*;
 
+mod coin_selection;
+pub use coin_selection::*;
+
 mod macros;
 
+mod record_locks;
+pub use record_locks::*;
+
 mod rewards;
 pub use rewards::*;
+
+mod verification;
+pub use verification::*;
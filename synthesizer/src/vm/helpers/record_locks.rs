@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::CoinSelection;
+use console::{network::prelude::*, types::Field};
+
+use indexmap::IndexMap;
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+
+/// The default duration after which the lock on a record of an unconfirmed execution expires.
+pub const DEFAULT_RECORD_LOCK_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// The record lock manager reserves the records selected by in-flight executions (by serial number),
+/// to prevent concurrent executions from selecting the same records. A lock is released once the record
+/// is spent in a confirmed block, when it is explicitly unlocked, or once it expires.
+pub struct RecordLocks<N: Network> {
+    /// The duration after which a lock expires.
+    timeout: Duration,
+    /// The map of locked serial numbers to the time they were locked.
+    locks: Mutex<IndexMap<Field<N>, Instant>>,
+}
+
+impl<N: Network> Default for RecordLocks<N> {
+    /// Initializes a new record lock manager with the default timeout.
+    fn default() -> Self {
+        Self::new(DEFAULT_RECORD_LOCK_TIMEOUT)
+    }
+}
+
+impl<N: Network> RecordLocks<N> {
+    /// Initializes a new record lock manager, with locks that expire after the given `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout, locks: Default::default() }
+    }
+
+    /// Returns the duration after which a lock expires.
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the number of active locks.
+    pub fn num_locked(&self) -> usize {
+        let mut locks = self.locks.lock();
+        self.prune_expired(&mut locks);
+        locks.len()
+    }
+
+    /// Returns `true` if the given serial number is locked.
+    pub fn is_locked(&self, serial_number: &Field<N>) -> bool {
+        let mut locks = self.locks.lock();
+        self.prune_expired(&mut locks);
+        locks.contains_key(serial_number)
+    }
+
+    /// Locks the given serial numbers, failing if any of them is already locked.
+    pub fn lock(&self, serial_numbers: &[Field<N>]) -> Result<()> {
+        let mut locks = self.locks.lock();
+        self.prune_expired(&mut locks);
+        // Ensure none of the serial numbers are locked.
+        if let Some(serial_number) = serial_numbers.iter().find(|serial_number| locks.contains_key(*serial_number)) {
+            bail!("The record with serial number '{serial_number}' is already locked by an in-flight execution");
+        }
+        // Lock the serial numbers.
+        let now = Instant::now();
+        locks.extend(serial_numbers.iter().map(|serial_number| (*serial_number, now)));
+        Ok(())
+    }
+
+    /// Selects from the candidates that are not locked, and locks the selected candidates, atomically.
+    pub fn lock_selection<T>(
+        &self,
+        candidates: Vec<(Field<N>, T)>,
+        select: impl FnOnce(Vec<(Field<N>, T)>) -> Result<CoinSelection<(Field<N>, T)>>,
+    ) -> Result<CoinSelection<T>> {
+        let mut locks = self.locks.lock();
+        self.prune_expired(&mut locks);
+        // Select from the unlocked candidates.
+        let candidates = candidates.into_iter().filter(|(serial_number, _)| !locks.contains_key(serial_number));
+        let selection = select(candidates.collect())?;
+        // Lock the selected candidates.
+        let now = Instant::now();
+        locks.extend(selection.inputs().iter().map(|(serial_number, _)| (*serial_number, now)));
+        // Return the selection.
+        Ok(selection.map_inputs(|(_, input)| input))
+    }
+
+    /// Unlocks the given serial numbers.
+    pub fn unlock<'a>(&self, serial_numbers: impl IntoIterator<Item = &'a Field<N>>) {
+        let mut locks = self.locks.lock();
+        for serial_number in serial_numbers {
+            locks.swap_remove(serial_number);
+        }
+    }
+
+    /// Removes the expired locks.
+    fn prune_expired(&self, locks: &mut IndexMap<Field<N>, Instant>) {
+        locks.retain(|_, locked_at| locked_at.elapsed() < self.timeout);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::{select_coins, CoinSelectionStrategy};
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_lock_and_unlock() {
+        let rng = &mut TestRng::default();
+
+        let locks = RecordLocks::<CurrentNetwork>::default();
+        let (first, second) = (Field::rand(rng), Field::rand(rng));
+
+        // Lock the first serial number.
+        locks.lock(&[first]).unwrap();
+        assert!(locks.is_locked(&first));
+        assert!(!locks.is_locked(&second));
+        // Ensure the first serial number cannot be locked again, even alongside an unlocked one.
+        assert!(locks.lock(&[second, first]).is_err());
+        assert!(!locks.is_locked(&second));
+        assert_eq!(locks.num_locked(), 1);
+
+        // Unlock the first serial number.
+        locks.unlock(&[first]);
+        assert!(!locks.is_locked(&first));
+        locks.lock(&[second, first]).unwrap();
+        assert_eq!(locks.num_locked(), 2);
+    }
+
+    #[test]
+    fn test_lock_expiry() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a lock manager whose locks expire immediately.
+        let locks = RecordLocks::<CurrentNetwork>::new(Duration::ZERO);
+        let serial_number = Field::rand(rng);
+        locks.lock(&[serial_number]).unwrap();
+        assert!(!locks.is_locked(&serial_number));
+        assert_eq!(locks.num_locked(), 0);
+    }
+
+    #[test]
+    fn test_lock_selection() {
+        let rng = &mut TestRng::default();
+
+        let locks = RecordLocks::<CurrentNetwork>::default();
+        let candidates = (0..4u64).map(|value| (Field::rand(rng), value + 1)).collect::<Vec<_>>();
+        let select = |candidates: Vec<(Field<CurrentNetwork>, u64)>| {
+            let candidates = candidates.into_iter().map(|candidate| (candidate, candidate.1)).collect();
+            select_coins(candidates, 4, CoinSelectionStrategy::LargestFirst)
+        };
+
+        // Ensure consecutive selections are disjoint.
+        let first = locks.lock_selection(candidates.clone(), select).unwrap();
+        assert_eq!(first.inputs(), &[4]);
+        let second = locks.lock_selection(candidates.clone(), select).unwrap();
+        assert_eq!(second.inputs(), &[3, 2]);
+        // Ensure the remaining candidates are insufficient.
+        assert!(locks.lock_selection(candidates, select).is_err());
+        assert_eq!(locks.num_locked(), 3);
+    }
+}
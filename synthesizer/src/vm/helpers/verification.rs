@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, types::Field};
+use ledger_block::Transaction;
+
+use std::time::Duration;
+
+/// The time spent verifying the transitions of a single transaction.
+///
+/// Note: The transitions of an execution share a single batch proof, and are therefore
+/// verified (and timed) together with the fee transition of the same transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationTiming<N: Network> {
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+    /// The IDs of the transitions that were verified.
+    transition_ids: Vec<N::TransitionID>,
+    /// The wall-clock time spent on verification.
+    elapsed: Duration,
+}
+
+impl<N: Network> VerificationTiming<N> {
+    /// Initializes a new verification timing for the given transaction.
+    pub fn new(transaction: &Transaction<N>, elapsed: Duration) -> Self {
+        Self {
+            transaction_id: transaction.id(),
+            transition_ids: transaction.transition_ids().copied().collect(),
+            elapsed,
+        }
+    }
+
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the IDs of the transitions that were verified.
+    pub fn transition_ids(&self) -> &[N::TransitionID] {
+        &self.transition_ids
+    }
+
+    /// Returns the wall-clock time spent on verification.
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the average wall-clock time spent per transition.
+    pub fn elapsed_per_transition(&self) -> Duration {
+        match u32::try_from(self.transition_ids.len()) {
+            Ok(0) | Err(_) => self.elapsed,
+            Ok(num_transitions) => self.elapsed / num_transitions,
+        }
+    }
+}
+
+/// A report of the verification times for a list of transactions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationReport<N: Network> {
+    /// The verification timings, in the order the transactions were given.
+    timings: Vec<VerificationTiming<N>>,
+}
+
+impl<N: Network> VerificationReport<N> {
+    /// Initializes a new verification report from the given timings.
+    pub fn new(timings: Vec<VerificationTiming<N>>) -> Self {
+        Self { timings }
+    }
+
+    /// Returns the verification timings.
+    pub fn timings(&self) -> &[VerificationTiming<N>] {
+        &self.timings
+    }
+
+    /// Returns the sum of the verification times across all transactions.
+    pub fn total_elapsed(&self) -> Duration {
+        self.timings.iter().map(VerificationTiming::elapsed).sum()
+    }
+
+    /// Returns the verification timing of the slowest transaction, if any.
+    pub fn slowest(&self) -> Option<&VerificationTiming<N>> {
+        self.timings.iter().max_by_key(|timing| timing.elapsed())
+    }
+
+    /// Returns the timings of all transactions whose average time per transition exceeds the given threshold.
+    /// This is useful for surfacing slow (and potentially adversarial) proofs.
+    pub fn exceeding(&self, threshold: Duration) -> impl '_ + Iterator<Item = &VerificationTiming<N>> {
+        self.timings.iter().filter(move |timing| timing.elapsed_per_transition() > threshold)
+    }
+}
+
+/// Returns the order in which the given transactions should be scheduled for verification.
+///
+/// The transactions are ordered from the most expensive to the least expensive (by number of transitions),
+/// such that the work-stealing pool starts on the longest tasks first, which keeps the tail latency bounded.
+pub fn verification_schedule<N: Network>(transactions: &[(&Transaction<N>, Option<Field<N>>)]) -> Vec<usize> {
+    let mut schedule = (0..transactions.len()).collect::<Vec<_>>();
+    // Note: `sort_by_key` is stable, so transactions of equal cost retain their original order.
+    schedule.sort_by_key(|index| std::cmp::Reverse(transactions[*index].0.transitions().count()));
+    schedule
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_verification_report() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transactions.
+        let deployment = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+
+        // Construct the report.
+        let report = VerificationReport::<CurrentNetwork>::new(vec![
+            VerificationTiming::new(&deployment, Duration::from_millis(10)),
+            VerificationTiming::new(&execution, Duration::from_millis(30)),
+        ]);
+        assert_eq!(report.total_elapsed(), Duration::from_millis(40));
+        assert_eq!(report.slowest().unwrap().transaction_id(), &execution.id());
+        assert_eq!(report.timings()[1].transition_ids().len(), execution.transitions().count());
+
+        // Ensure the slow transaction is surfaced.
+        let threshold = report.timings()[1].elapsed_per_transition() - Duration::from_nanos(1);
+        assert!(report.exceeding(threshold).any(|timing| timing.transaction_id() == &execution.id()));
+        assert_eq!(report.exceeding(Duration::from_secs(1)).count(), 0);
+    }
+
+    #[test]
+    fn test_verification_schedule() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transactions.
+        let fee = ledger_test_helpers::sample_fee_public_transaction(rng);
+        let execution = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+
+        // Ensure the transactions with more transitions are scheduled first.
+        let transactions = [(&fee, Some(Field::rand(rng))), (&execution, None)];
+        let schedule = verification_schedule::<CurrentNetwork>(&transactions);
+        assert_eq!(schedule, vec![1, 0]);
+    }
+}
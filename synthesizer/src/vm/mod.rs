@@ -16,15 +16,19 @@ mod helpers;
 pub use helpers::*;
 
 mod authorize;
+mod credits;
 mod deploy;
 mod execute;
+mod execute_builder;
+pub use execute_builder::*;
+
 mod finalize;
 mod verify;
 
 use crate::{cast_mut_ref, cast_ref, convert, process};
 use console::{
     account::{Address, PrivateKey},
-    network::prelude::*,
+    network::{prelude::*, ConsensusVersion},
     program::{Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Value},
     types::{Field, Group, U64},
 };
@@ -33,6 +37,7 @@ use ledger_block::{
     ConfirmedTransaction,
     Deployment,
     Execution,
+    FailedExecution,
     Fee,
     Header,
     Input,
@@ -57,7 +62,7 @@ use ledger_store::{
     TransactionStore,
     TransitionStore,
 };
-use synthesizer_process::{deployment_cost, execution_cost, Authorization, Process, Trace};
+use synthesizer_process::{deployment_cost, execution_cost, failed_execution, Authorization, Process, Trace};
 use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
 use utilities::try_vm_runtime;
 
@@ -86,6 +91,8 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     block_lock: Arc<Mutex<()>>,
     /// A cache containing the list of recent partially-verified transactions.
     partially_verified_transactions: Arc<RwLock<LruCache<N::TransactionID, ()>>>,
+    /// The locks on the records selected by in-flight executions.
+    record_locks: Arc<RecordLocks<N>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -182,6 +189,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
 
+        // Set the latest block height, against which authorization expirations are checked.
+        process.set_latest_block_height(store.block_store().current_block_height());
+
         // Return the new VM.
         Ok(Self {
             process: Arc::new(RwLock::new(process)),
@@ -192,6 +202,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             partially_verified_transactions: Arc::new(RwLock::new(LruCache::new(
                 NonZeroUsize::new(Transactions::<N>::MAX_TRANSACTIONS).unwrap(),
             ))),
+            record_locks: Default::default(),
         })
     }
 
@@ -221,6 +232,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns the record lock manager.
+    #[inline]
+    pub fn record_locks(&self) -> &RecordLocks<N> {
+        &self.record_locks
+    }
+
     /// Returns the finalize store.
     #[inline]
     pub fn finalize_store(&self) -> &FinalizeStore<N, C::FinalizeStorage> {
@@ -244,6 +261,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn transition_store(&self) -> &TransitionStore<N, C::TransitionStorage> {
         self.store.transition_store()
     }
+
+    /// Returns the consensus version in effect for the next block.
+    #[inline]
+    pub fn next_consensus_version(&self) -> ConsensusVersion {
+        N::consensus_version(self.block_store().current_block_height().saturating_add(1))
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -405,6 +428,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Unpause the atomic writes, executing the ones queued from block insertion and finalization.
                 #[cfg(feature = "rocks")]
                 self.block_store().unpause_atomic_writes::<false>()?;
+                // Release the locks on the records spent in the block.
+                self.record_locks.unlock(block.serial_numbers());
+                // Update the latest block height, against which authorization expirations are checked.
+                self.process.read().set_latest_block_height(block.height());
                 Ok(())
             }
             Err(finalize_error) => {
@@ -1037,8 +1064,12 @@ function a:
             );
         }
 
+        // Ensure the latest block height is tracked for authorization expirations.
+        assert_eq!(vm.process().read().latest_block_height(), Some(3));
+
         // Enforce that the VM can load properly with the imports.
-        assert!(VM::from(vm.store.clone()).is_ok());
+        let vm = VM::from(vm.store.clone()).unwrap();
+        assert_eq!(vm.process().read().latest_block_height(), Some(3));
     }
 
     #[test]
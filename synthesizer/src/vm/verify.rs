@@ -61,6 +61,51 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         Ok(())
     }
+
+    /// Verifies the list of transactions in the VM, and returns the verification time of each transaction.
+    /// On failure, returns an error.
+    ///
+    /// The transactions are scheduled from the most expensive to the least expensive onto the work-stealing
+    /// thread pool, so that a slow (or adversarial) proof does not hold back the remainder of its batch.
+    pub fn check_transactions_with_report<R: CryptoRng + Rng>(
+        &self,
+        transactions: &[(&Transaction<N>, Option<Field<N>>)],
+        rng: &mut R,
+    ) -> Result<VerificationReport<N>> {
+        // Determine the verification schedule.
+        let schedule = verification_schedule(transactions);
+        // Separate the scheduled transactions into deploys and executions.
+        let (deployments, executions): (Vec<_>, Vec<_>) =
+            schedule.into_iter().partition(|index| transactions[*index].0.is_deploy());
+        // Chunk the deploys and executions into groups for parallel verification.
+        let deployments_for_verification = deployments.chunks(Self::MAX_PARALLEL_DEPLOY_VERIFICATIONS);
+        let executions_for_verification = executions.chunks(Self::MAX_PARALLEL_EXECUTE_VERIFICATIONS);
+
+        // Initialize a vector for the verification timings.
+        let mut timings = Vec::with_capacity(transactions.len());
+
+        // Verify the transactions in batches.
+        for indices in deployments_for_verification.chain(executions_for_verification) {
+            // Ensure each transaction is well-formed and unique, and record the time spent on it.
+            let rngs = (0..indices.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+            let batch = cfg_iter!(indices)
+                .zip(rngs)
+                .map(|(index, mut rng)| {
+                    let (transaction, rejected_id) = transactions[*index];
+                    let start = std::time::Instant::now();
+                    self.check_transaction(transaction, rejected_id, &mut rng)
+                        .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))?;
+                    Ok((*index, VerificationTiming::new(transaction, start.elapsed())))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            timings.extend(batch);
+        }
+
+        // Restore the original order of the transactions.
+        timings.sort_by_key(|(index, _)| *index);
+        // Return the verification report.
+        Ok(VerificationReport::new(timings.into_iter().map(|(_, timing)| timing).collect()))
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -158,6 +203,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if self.contains_program(deployment.program_id()) {
                     bail!("Program ID '{}' already exists", deployment.program_id());
                 }
+                // Ensure the features used by the program are in effect.
+                if deployment.program().consensus_version() > self.next_consensus_version() {
+                    bail!("Program ID '{}' uses features that are not yet in effect", deployment.program_id());
+                }
                 // Verify the deployment if it has not been verified before.
                 if !is_partially_verified {
                     // Verify the deployment.
@@ -176,6 +225,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if self.block_store().contains_rejected_deployment_or_execution_id(&execution_id)? {
                     bail!("Transaction '{id}' contains a previously rejected execution")
                 }
+                // Ensure the execution has a single top-level call, until bundled calls are in effect.
+                // Note: Bundled calls take effect at `ConsensusVersion::V2`, which no network schedules yet.
+                if self.next_consensus_version() < ConsensusVersion::V2 {
+                    let num_top_level_calls = self.process().read().construct_call_trees(execution)?.len();
+                    ensure!(
+                        num_top_level_calls == 1,
+                        "Transaction '{id}' bundles {num_top_level_calls} top-level calls, before they are in effect"
+                    );
+                }
                 // Verify the execution.
                 match try_vm_runtime!(|| self.check_execution_internal(execution, is_partially_verified)) {
                     Ok(result) => result?,
@@ -400,6 +458,26 @@ mod tests {
         vm.check_transaction(&execution_transaction, None, rng).unwrap();
     }
 
+    #[test]
+    fn test_check_transactions_with_report() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch a deployment transaction and an execution transaction.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let execution_transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+
+        // Ensure the transactions verify.
+        let transactions = [(&deployment_transaction, None), (&execution_transaction, None)];
+        let report = vm.check_transactions_with_report(&transactions, rng).unwrap();
+
+        // Ensure the report contains a timing for each transaction, in the original order.
+        assert_eq!(report.timings().len(), 2);
+        assert_eq!(report.timings()[0].transaction_id(), &deployment_transaction.id());
+        assert_eq!(report.timings()[1].transaction_id(), &execution_transaction.id());
+        assert_eq!(report.timings()[1].transition_ids().len(), execution_transaction.transitions().count());
+    }
+
     #[test]
     fn test_verify_deployment() {
         let rng = &mut TestRng::default();
@@ -420,6 +498,35 @@ mod tests {
         vm.check_deployment_internal(&deployment_transaction, rng).unwrap();
     }
 
+    #[test]
+    fn test_verify_deployment_before_consensus_version() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Initialize a program with a struct member that has a default value.
+        let program = Program::from_str(
+            r"
+program defaults.aleo;
+
+struct point:
+    x as field;
+    y as field default 0field;
+
+function compute:
+    input r0 as point.private;
+    add r0.x r0.y into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+        assert_eq!(program.consensus_version(), ConsensusVersion::V2);
+
+        // Ensure the deployment is rejected, until `ConsensusVersion::V2` is in effect.
+        let transaction = vm.deploy(&caller_private_key, &program, None, 0, None, rng).unwrap();
+        assert!(vm.next_consensus_version() < ConsensusVersion::V2);
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+    }
+
     #[test]
     fn test_verify_execution() {
         let rng = &mut TestRng::default();
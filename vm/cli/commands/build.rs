@@ -37,9 +37,9 @@ impl Build {
         println!("⚠️  Attention - This command is deprecated. Use the {} command.\n", "'run'".to_string().bold());
 
         // Build the package, if the package requires building.
-        package.build::<Aleo>(self.endpoint)?;
+        package.build::<Aleo, _>(self.endpoint, &mut rand::thread_rng())?;
 
-        // package.build::<Aleo>(match self.offline {
+        // package.build::<Aleo, _>(match self.offline {
         //     true => None,
         //     false => Some(endpoint.unwrap_or("https://api.explorer.aleo.org/v0/mainnet/build".to_string())),
         // })?;
@@ -149,9 +149,10 @@ impl<'de, N: Network> Deserialize<'de> for BuildResponse<N> {
 
 impl<N: Network> Package<N> {
     /// Builds the package.
-    pub fn build<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+    pub fn build<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
         &self,
         endpoint: Option<String>,
+        rng: &mut R,
     ) -> Result<()> {
         // Skip the 'build' if the program is already built.
         if !self.is_build_required::<A>() {
@@ -212,7 +213,7 @@ impl<N: Network> Package<N> {
                         response.verifying_key().clone(),
                     )?;
                 }
-                None => process.synthesize_key::<A, _>(program_id, function_name, &mut rand::thread_rng())?,
+                None => process.synthesize_key::<A, R>(program_id, function_name, rng)?,
             }
         }
 
@@ -285,6 +286,8 @@ impl<N: Network> Package<N> {
 
 #[cfg(test)]
 mod tests {
+    use snarkvm_utilities::TestRng;
+
     type CurrentAleo = snarkvm_circuit::network::AleoV0;
 
     #[test]
@@ -295,7 +298,7 @@ mod tests {
         // Ensure the build directory does *not* exist.
         assert!(!package.build_directory().exists());
         // Build the package.
-        package.build::<CurrentAleo>(None).unwrap();
+        package.build::<CurrentAleo, _>(None, &mut TestRng::default()).unwrap();
         // Ensure the build directory exists.
         assert!(package.build_directory().exists());
 
@@ -311,7 +314,7 @@ mod tests {
         // Ensure the build directory does *not* exist.
         assert!(!package.build_directory().exists());
         // Build the package.
-        package.build::<CurrentAleo>(None).unwrap();
+        package.build::<CurrentAleo, _>(None, &mut TestRng::default()).unwrap();
         // Ensure the build directory exists.
         assert!(package.build_directory().exists());
 
@@ -328,7 +331,7 @@ mod tests {
         // Ensure the build directory does *not* exist.
         assert!(!package.build_directory().exists());
         // Build the package.
-        package.build::<CurrentAleo>(None).unwrap();
+        package.build::<CurrentAleo, _>(None, &mut TestRng::default()).unwrap();
         // Ensure the build directory exists.
         assert!(package.build_directory().exists());
 
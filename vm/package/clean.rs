@@ -48,6 +48,7 @@ impl<N: Network> Package<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use snarkvm_utilities::TestRng;
 
     type CurrentNetwork = snarkvm_console::network::MainnetV0;
     type CurrentAleo = snarkvm_circuit::network::AleoV0;
@@ -65,7 +66,7 @@ mod tests {
         assert!(!package.build_directory().exists());
 
         // Build the package.
-        package.build::<CurrentAleo>(None).unwrap();
+        package.build::<CurrentAleo, _>(None, &mut TestRng::default()).unwrap();
 
         // Ensure the build directory exists.
         assert!(package.build_directory().exists());
@@ -91,7 +92,7 @@ mod tests {
         assert!(!package.build_directory().exists());
 
         // Build the package.
-        package.build::<CurrentAleo>(None).unwrap();
+        package.build::<CurrentAleo, _>(None, &mut TestRng::default()).unwrap();
 
         // Ensure the build directory exists.
         assert!(package.build_directory().exists());
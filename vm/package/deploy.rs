@@ -110,9 +110,10 @@ impl<'de, N: Network> Deserialize<'de> for DeployResponse<N> {
 }
 
 impl<N: Network> Package<N> {
-    pub fn deploy<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+    pub fn deploy<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
         &self,
         endpoint: Option<String>,
+        rng: &mut R,
     ) -> Result<Deployment<N>> {
         // Retrieve the main program.
         let program = self.program();
@@ -140,10 +141,8 @@ impl<N: Network> Package<N> {
             Ok::<_, Error>(())
         })?;
 
-        // Initialize the RNG.
-        let rng = &mut rand::thread_rng();
         // Compute the deployment.
-        let deployment = process.deploy::<A, _>(program, rng).unwrap();
+        let deployment = process.deploy::<A, R>(program, rng).unwrap();
 
         match endpoint {
             Some(ref endpoint) => {
@@ -167,6 +166,7 @@ impl<N: Network> Package<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use snarkvm_utilities::TestRng;
 
     type CurrentNetwork = snarkvm_console::network::MainnetV0;
     type CurrentAleo = snarkvm_circuit::network::AleoV0;
@@ -177,7 +177,7 @@ mod tests {
         let (directory, package) = crate::package::test_helpers::sample_token_package();
 
         // Deploy the package.
-        let deployment = package.deploy::<CurrentAleo>(None).unwrap();
+        let deployment = package.deploy::<CurrentAleo, _>(None, &mut TestRng::default()).unwrap();
 
         // Ensure the deployment edition matches.
         assert_eq!(<CurrentNetwork as Network>::EDITION, deployment.edition());
@@ -196,7 +196,7 @@ mod tests {
         let (directory, package) = crate::package::test_helpers::sample_wallet_package();
 
         // Deploy the package.
-        let deployment = package.deploy::<CurrentAleo>(None).unwrap();
+        let deployment = package.deploy::<CurrentAleo, _>(None, &mut TestRng::default()).unwrap();
 
         // Ensure the deployment edition matches.
         assert_eq!(<CurrentNetwork as Network>::EDITION, deployment.edition());
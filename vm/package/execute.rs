@@ -36,8 +36,8 @@ impl<N: Network> Package<N> {
 
         // Build the package, if the package requires building.
         // TODO (howardwu): We currently choose only to support local synthesis of keys due to performance.
-        // self.build::<A>(Some(endpoint.clone()))?;
-        self.build::<A>(None)?;
+        // self.build::<A, R>(Some(endpoint.clone()), rng)?;
+        self.build::<A, R>(None, rng)?;
 
         // Prepare the locator (even if logging is disabled, to sanity check the locator is well-formed).
         let locator = Locator::<N>::from_str(&format!("{program_id}/{function_name}"))?;
@@ -127,7 +127,7 @@ mod tests {
         // Ensure the build directory does *not* exist.
         assert!(!package.build_directory().exists());
         // Build the package.
-        package.build::<CurrentAleo>(None).unwrap();
+        package.build::<CurrentAleo, _>(None, &mut TestRng::default()).unwrap();
         // Ensure the build directory exists.
         assert!(package.build_directory().exists());
 
@@ -156,7 +156,7 @@ mod tests {
         // Ensure the build directory does *not* exist.
         assert!(!package.build_directory().exists());
         // Build the package.
-        package.build::<CurrentAleo>(None).unwrap();
+        package.build::<CurrentAleo, _>(None, &mut TestRng::default()).unwrap();
         // Ensure the build directory exists.
         assert!(package.build_directory().exists());
 
@@ -185,7 +185,7 @@ mod tests {
         // Ensure the build directory does *not* exist.
         assert!(!package.build_directory().exists());
         // Build the package.
-        package.build::<CurrentAleo>(None).unwrap();
+        package.build::<CurrentAleo, _>(None, &mut TestRng::default()).unwrap();
         // Ensure the build directory exists.
         assert!(package.build_directory().exists());
 
@@ -66,6 +66,7 @@ impl<N: Network> Package<N> {
 mod tests {
     use super::*;
     use snarkvm_console::network::MainnetV0;
+    use snarkvm_utilities::TestRng;
     use std::{fs::File, io::Write};
 
     type CurrentNetwork = MainnetV0;
@@ -172,7 +173,7 @@ function compute:
         let package = initialize_unbuilt_package(true).unwrap();
         assert!(package.is_build_required::<Aleo>());
 
-        package.build::<Aleo>(None).unwrap();
+        package.build::<Aleo, _>(None, &mut TestRng::default()).unwrap();
         assert!(!package.is_build_required::<Aleo>());
     }
 }
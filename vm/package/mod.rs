@@ -544,7 +544,7 @@ function bar:
         // Ensure the build directory does *not* exist.
         assert!(!package.build_directory().exists());
         // Build the package.
-        package.build::<CurrentAleo>(None).unwrap();
+        package.build::<CurrentAleo, _>(None, &mut TestRng::default()).unwrap();
         // Ensure the build directory exists.
         assert!(package.build_directory().exists());
 